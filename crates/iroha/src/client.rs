@@ -2,11 +2,12 @@
 //! add any custom end-point related logic.
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
     fmt::Debug,
     num::{NonZeroU32, NonZeroU64},
+    sync::{Arc, Mutex},
     thread,
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 use derive_more::{DebugCustom, Display};
@@ -17,7 +18,13 @@ pub use iroha_config::client_api::ConfigGetDTO;
 use iroha_config::client_api::ConfigUpdateDTO;
 use iroha_logger::prelude::*;
 pub use iroha_telemetry::metrics::{Status, Uptime};
-use iroha_torii_shared::{uri as torii_uri, Version};
+pub use iroha_torii_shared::{
+    compression::Compression as StreamCompression, DrainPhase, ExecutorSchema, GenesisOutcome,
+    PeerTraffic, PermissionSchema, SnapshotOutcome, StartupReport,
+};
+use iroha_torii_shared::{
+    compression::HEADER as STREAM_COMPRESSION_HEADER, uri as torii_uri, Version,
+};
 use iroha_version::prelude::*;
 use parity_scale_codec::DecodeAll;
 use rand::Rng;
@@ -27,7 +34,7 @@ use self::{blocks_api::AsyncBlockStream, events_api::AsyncEventStream};
 pub use crate::query::QueryError;
 use crate::{
     config::Config,
-    crypto::{HashOf, KeyPair},
+    crypto::{HashOf, KeyPair, PublicKey},
     data_model::{
         block::SignedBlock,
         events::pipeline::{
@@ -41,10 +48,14 @@ use crate::{
     },
     http::{Method as HttpMethod, RequestBuilder, Response, StatusCode},
     http_default::{self, DefaultRequestBuilder, WebSocketError, WebSocketMessage},
+    tape::Tape,
 };
 
 const APPLICATION_JSON: &str = "application/json";
 
+/// Time for peers to accept an event subscription request after it is signed.
+const EVENT_SUBSCRIPTION_TTL: Duration = Duration::from_secs(60);
+
 /// `Result` with [`QueryError`] as an error
 pub type QueryResult<T> = core::result::Result<T, QueryError>;
 
@@ -113,6 +124,59 @@ impl From<ResponseReport> for eyre::Report {
     }
 }
 
+/// Position of a transaction in the blockchain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransactionLocation {
+    /// Height of the block containing the transaction
+    pub block_height: NonZeroU64,
+    /// Index of the transaction among the external transactions of the block,
+    /// or among its excluded transactions if the transaction was left out of it
+    pub index: usize,
+}
+
+/// How a transaction included into a block was executed, see [`Client::wait_for_commit`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionOutcome {
+    /// Transaction was executed successfully
+    Committed(TransactionLocation),
+    /// Transaction was rejected, leaving the state unchanged
+    Rejected {
+        /// Where the rejected transaction was recorded
+        location: TransactionLocation,
+        /// Why the transaction was rejected
+        reason: Box<TransactionRejectionReason>,
+    },
+    /// Transaction was rejected and left out of the block, only its hash was recorded
+    Excluded {
+        /// Where the hash of the rejected transaction was recorded
+        location: TransactionLocation,
+        /// Why the transaction was rejected
+        reason: Box<TransactionRejectionReason>,
+    },
+}
+
+impl TransactionOutcome {
+    /// Position of the transaction in the blockchain
+    pub fn location(&self) -> TransactionLocation {
+        match self {
+            Self::Committed(location)
+            | Self::Rejected { location, .. }
+            | Self::Excluded { location, .. } => *location,
+        }
+    }
+
+    /// Convert into the location of a committed transaction or its rejection reason
+    ///
+    /// # Errors
+    /// If the transaction was rejected
+    pub fn into_result(self) -> Result<TransactionLocation, TransactionRejectionReason> {
+        match self {
+            Self::Committed(location) => Ok(location),
+            Self::Rejected { reason, .. } | Self::Excluded { reason, .. } => Err(*reason),
+        }
+    }
+}
+
 /// Iroha client
 #[derive(Clone, DebugCustom, Display)]
 #[debug(
@@ -138,6 +202,58 @@ pub struct Client {
     /// If `true` add nonce, which makes different hashes for
     /// transactions which occur repeatedly and/or simultaneously
     pub add_transaction_nonce: bool,
+    /// If `true`, submit transactions directly to the current leader when its url is known
+    pub route_transactions_to_leader: bool,
+    /// Torii urls of peers by their public keys, used to reach the leader
+    pub torii_peers: HashMap<PublicKey, Url>,
+    /// Leader transactions are routed to, shared between clones of the client
+    pub leader_cache: LeaderCache,
+    /// Tape to record the session to or to replay it from, see [`Tape`]
+    pub tape: Option<Tape>,
+    /// Compression Torii should apply to event and block streams, none by default
+    pub stream_compression: Option<StreamCompression>,
+}
+
+/// Leader of the round transactions are routed to by [`Client::submit_transaction`],
+/// see [`Client::route_transactions_to_leader`].
+///
+/// The leader changes after every block, so it is looked up again once the client
+/// sees a block applied while waiting for a transaction, or fails to submit to it.
+/// After a view change, transactions keep going to the former leader until then,
+/// which forwards them to the other peers. Cloned caches are shared.
+#[derive(Debug, Clone, Default)]
+pub struct LeaderCache(Arc<Mutex<Option<CachedLeader>>>);
+
+#[derive(Debug)]
+struct CachedLeader {
+    /// Height of the chain when the leader was looked up
+    height: u64,
+    /// Torii url of the leader, `None` if it isn't known or is [`Client::torii_url`]
+    torii_url: Option<Url>,
+}
+
+impl LeaderCache {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<CachedLeader>> {
+        self.0
+            .lock()
+            .expect("leader cache lock shouldn't be poisoned")
+    }
+
+    /// Forget the leader, so that it is looked up before the next submission
+    fn invalidate(&self) {
+        *self.lock() = None;
+    }
+
+    /// Forget the leader if the block at `height` ended its round
+    fn block_applied(&self, height: NonZeroU64) {
+        let mut cached = self.lock();
+        if cached
+            .as_ref()
+            .is_some_and(|leader| leader.height < height.get())
+        {
+            *cached = None;
+        }
+    }
 }
 
 /// Representation of `Iroha` client.
@@ -162,6 +278,8 @@ impl Client {
             transaction_add_nonce,
             transaction_ttl,
             transaction_status_timeout,
+            transaction_route_to_leader,
+            torii_peers,
         }: Config,
         mut headers: HashMap<String, String>,
     ) -> Self {
@@ -185,6 +303,11 @@ impl Client {
             account,
             headers,
             add_transaction_nonce: transaction_add_nonce,
+            route_transactions_to_leader: transaction_route_to_leader,
+            torii_peers,
+            leader_cache: LeaderCache::default(),
+            tape: None,
+            stream_compression: None,
         }
     }
 
@@ -284,8 +407,30 @@ impl Client {
         transaction: &SignedTransaction,
     ) -> Result<HashOf<SignedTransaction>> {
         iroha_logger::trace!(tx=?transaction, "Submitting");
-        let (req, hash) = self.prepare_transaction_request::<DefaultRequestBuilder>(transaction);
+        if let Some(leader_url) = self.leader_torii_url() {
+            match self.submit_transaction_to(&leader_url, transaction) {
+                Ok(hash) => return Ok(hash),
+                Err(error) => {
+                    iroha_logger::debug!(
+                        %leader_url, ?error,
+                        "Failed to submit transaction to the leader, falling back"
+                    );
+                    self.leader_cache.invalidate();
+                }
+            }
+        }
+        self.submit_transaction_to(&self.torii_url, transaction)
+    }
+
+    fn submit_transaction_to(
+        &self,
+        torii_url: &Url,
+        transaction: &SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let (req, hash) =
+            self.prepare_transaction_request::<DefaultRequestBuilder>(torii_url, transaction);
         let response = req
+            .tape(self.tape.clone())
             .build()?
             .send()
             .wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
@@ -293,6 +438,34 @@ impl Client {
         Ok(hash)
     }
 
+    /// Torii url of the current leader, if routing to the leader is enabled and
+    /// the leader is reachable through a url other than [`Self::torii_url`].
+    ///
+    /// The leader is looked up with [`Self::get_status`] once per round, see [`LeaderCache`].
+    fn leader_torii_url(&self) -> Option<Url> {
+        if !self.route_transactions_to_leader {
+            return None;
+        }
+
+        // Held during the lookup, so that concurrent submissions don't repeat it
+        let mut cached = self.leader_cache.lock();
+        if let Some(leader) = &*cached {
+            return leader.torii_url.clone();
+        }
+        let status = self.get_status().ok()?;
+        let torii_url = status
+            .leader
+            .and_then(|leader| self.torii_peers.get(&leader))
+            .filter(|url| **url != self.torii_url)
+            .cloned();
+        *cached = Some(CachedLeader {
+            height: status.blocks,
+            torii_url: torii_url.clone(),
+        });
+
+        torii_url
+    }
+
     /// Submit the prebuilt transaction and wait until it is either rejected or committed.
     /// If rejected, return the rejection reason.
     ///
@@ -359,7 +532,11 @@ impl Client {
 
             let result = tokio::time::timeout_at(
                 deadline,
-                Self::listen_for_tx_confirmation_loop(&mut event_iterator, hash),
+                Self::listen_for_tx_confirmation_loop(
+                    &mut event_iterator,
+                    hash,
+                    &self.leader_cache,
+                ),
             )
             .await
             .wrap_err_with(|| {
@@ -377,6 +554,7 @@ impl Client {
     async fn listen_for_tx_confirmation_loop(
         event_iterator: &mut AsyncEventStream,
         hash: HashOf<SignedTransaction>,
+        leader_cache: &LeaderCache,
     ) -> Result<HashOf<SignedTransaction>> {
         let mut block_height = None;
 
@@ -396,12 +574,14 @@ impl Client {
                         }
                     }
                     PipelineEventBox::Block(block_event) => {
-                        if Some(block_event.header().height()) == block_height {
-                            if let BlockStatus::Applied = block_event.status() {
+                        if let BlockStatus::Applied = block_event.status() {
+                            leader_cache.block_applied(block_event.header().height());
+                            if Some(block_event.header().height()) == block_height {
                                 return Ok(hash);
                             }
                         }
                     }
+                    PipelineEventBox::Queue(_) => {}
                 }
             }
         }
@@ -411,6 +591,164 @@ impl Client {
         ))
     }
 
+    /// Wait until the transaction with the given `hash` is included into a block,
+    /// whether it is committed or rejected.
+    ///
+    /// Transactions already in the blockchain are resolved right away, so the transaction
+    /// can be submitted before calling this method.
+    ///
+    /// # Errors
+    /// - if the transaction expires in the queue
+    /// - if the transaction is rejected without being included into a block, e.g. by the
+    ///   executor before it is queued; the error is the [`TransactionRejectionReason`]
+    /// - if it isn't included into a block within `timeout`
+    /// - if connecting to the peer or querying it fails
+    pub fn wait_for_commit(
+        &self,
+        hash: HashOf<SignedTransaction>,
+        timeout: Duration,
+    ) -> Result<TransactionOutcome> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        let filters = vec![
+            TransactionEventFilter::default().for_hash(hash).into(),
+            PipelineEventFilterBox::from(
+                BlockEventFilter::default().for_status(BlockStatus::Applied),
+            ),
+        ];
+        let mut event_iterator = rt
+            .block_on(tokio::time::timeout_at(
+                deadline,
+                self.listen_for_events_async(filters),
+            ))
+            .map_err(Into::into)
+            .and_then(std::convert::identity)
+            .wrap_err("Failed to establish event listener connection")?;
+
+        // The transaction might have been included before the subscription
+        let entrypoint_hash: HashOf<TransactionEntrypoint> =
+            HashOf::from_untyped_unchecked(hash.into());
+        let committed = self
+            .query(FindTransactions::new())
+            .filter_with(|tx| tx.entrypoint_hash.eq(entrypoint_hash))
+            .execute_single_opt()
+            .wrap_err("Failed to look up the transaction")?
+            .map(|committed| *committed.block_hash());
+        let included = match committed {
+            Some(block_hash) => Some(block_hash),
+            None => self
+                .query(FindExcludedTransactions::new())
+                .filter_with(|tx| tx.entrypoint_hash.eq(entrypoint_hash))
+                .execute_single_opt()
+                .wrap_err("Failed to look up the excluded transaction")?
+                .map(|excluded| *excluded.block_hash()),
+        };
+
+        let block_hash = match included {
+            Some(block_hash) => Ok(block_hash),
+            None => rt
+                .block_on(tokio::time::timeout_at(
+                    deadline,
+                    Self::wait_for_commit_loop(&mut event_iterator, &self.leader_cache),
+                ))
+                .wrap_err_with(|| {
+                    eyre!("transaction {hash} wasn't included into a block within {timeout:?}")
+                })
+                .and_then(std::convert::identity),
+        };
+        rt.block_on(event_iterator.close());
+
+        self.transaction_outcome(hash, block_hash?)
+    }
+
+    /// Hash of the applied block containing the transaction the events are filtered for
+    ///
+    /// Resolves right away with the rejection reason if the transaction is rejected
+    /// without being included into a block.
+    async fn wait_for_commit_loop(
+        event_iterator: &mut AsyncEventStream,
+        leader_cache: &LeaderCache,
+    ) -> Result<HashOf<BlockHeader>> {
+        let mut block_height = None;
+
+        while let Some(event) = event_iterator.next().await {
+            if let EventBox::Pipeline(this_event) = event? {
+                match this_event {
+                    PipelineEventBox::Transaction(transaction_event) => {
+                        match transaction_event.status() {
+                            TransactionStatus::Queued => {}
+                            TransactionStatus::Approved => {
+                                block_height = transaction_event.block_height();
+                            }
+                            TransactionStatus::Rejected(reason) => {
+                                match transaction_event.block_height() {
+                                    Some(height) => block_height = Some(height),
+                                    None => return Err((Clone::clone(&**reason)).into()),
+                                }
+                            }
+                            TransactionStatus::Expired => return Err(eyre!("Transaction expired")),
+                        }
+                    }
+                    PipelineEventBox::Block(block_event) => {
+                        leader_cache.block_applied(block_event.header().height());
+                        if Some(block_event.header().height()) == block_height {
+                            return Ok(block_event.header().hash());
+                        }
+                    }
+                    PipelineEventBox::Queue(_) => {}
+                }
+            }
+        }
+
+        Err(eyre!(
+            "Connection dropped before the transaction was included"
+        ))
+    }
+
+    fn transaction_outcome(
+        &self,
+        hash: HashOf<SignedTransaction>,
+        block_hash: HashOf<BlockHeader>,
+    ) -> Result<TransactionOutcome> {
+        let block = self
+            .query(FindBlocks)
+            .filter_with(|block| block.header.hash.eq(block_hash))
+            .execute_single()
+            .wrap_err("Failed to fetch the block containing the transaction")?;
+        let Some(index) = block
+            .external_transactions()
+            .position(|tx| tx.hash() == hash)
+        else {
+            let (index, excluded) = block
+                .excluded_transactions()
+                .enumerate()
+                .find(|(_, excluded)| excluded.hash == hash)
+                .ok_or_else(|| eyre!("Transaction {hash} is missing from block {block_hash}"))?;
+            return Ok(TransactionOutcome::Excluded {
+                location: TransactionLocation {
+                    block_height: block.header().height(),
+                    index,
+                },
+                reason: Box::new(excluded.reason.clone()),
+            });
+        };
+        let location = TransactionLocation {
+            block_height: block.header().height(),
+            index,
+        };
+
+        Ok(match block.error(index) {
+            None => TransactionOutcome::Committed(location),
+            Some(reason) => TransactionOutcome::Rejected {
+                location,
+                reason: Box::new(reason.clone()),
+            },
+        })
+    }
+
     /// Lower-level Instructions API entry point.
     ///
     /// Returns a tuple with a provided request builder, a hash of the transaction, and a response handler.
@@ -420,6 +758,7 @@ impl Client {
     /// For general usage example see [`Client::prepare_query_request`].
     fn prepare_transaction_request<B: RequestBuilder>(
         &self,
+        torii_url: &Url,
         transaction: &SignedTransaction,
     ) -> (B, HashOf<SignedTransaction>) {
         let transaction_bytes: Vec<u8> = transaction.encode_versioned();
@@ -427,7 +766,7 @@ impl Client {
         (
             B::new(
                 HttpMethod::POST,
-                join_torii_url(&self.torii_url, torii_uri::TRANSACTION),
+                join_torii_url(torii_url, torii_uri::TRANSACTION),
             )
             .headers(self.headers.clone())
             .body(transaction_bytes),
@@ -488,6 +827,153 @@ impl Client {
         self.submit_transaction_blocking(&transaction)
     }
 
+    /// Check whether the executor would accept `transaction` against the current state,
+    /// without submitting it.
+    ///
+    /// Returns the reason the transaction would be rejected for, such as the precise
+    /// [`ValidationFail`], in the inner result.
+    ///
+    /// # Errors
+    /// Fails if sending the request or decoding the response fails,
+    /// or if the transaction is malformed
+    pub fn check_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<Result<(), TransactionRejectionReason>> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.torii_url, torii_uri::TRANSACTION_VALIDATE),
+        )
+        .headers(&self.headers)
+        .body(transaction.encode_versioned())
+        .tape(self.tape.clone())
+        .build()?
+        .send()
+        .wrap_err("Failed to send transaction check")?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to check transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Preview the entities and asset balances `transaction` would change, without submitting it.
+    ///
+    /// Nothing is reported as changed if the transaction would be rejected.
+    ///
+    /// # Errors
+    /// Fails if sending the request or decoding the response fails,
+    /// or if the transaction is malformed
+    pub fn dry_run_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionDryRun> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.torii_url, torii_uri::TRANSACTION_DRY_RUN),
+        )
+        .headers(&self.headers)
+        .body(transaction.encode_versioned())
+        .tape(self.tape.clone())
+        .build()?
+        .send()
+        .wrap_err("Failed to send transaction dry run")?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to dry run transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Check whether upgrading to `executor` would succeed, without committing anything.
+    ///
+    /// The peer validates the [`Upgrade`] transaction as if it were in the next block,
+    /// including the migration of the new executor, and reports the outcome.
+    ///
+    /// # Errors
+    /// Fails if sending the request or decoding the response fails, if the account
+    /// can't upgrade the executor, or if another upgrade was dry run too recently
+    pub fn dry_run_executor_upgrade(&self, executor: Executor) -> Result<ExecutorUpgradeDryRun> {
+        let transaction = self.build_transaction([Upgrade::new(executor)], Metadata::default());
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.torii_url, torii_uri::EXECUTOR_DRY_RUN),
+        )
+        .headers(&self.headers)
+        .body(transaction.encode_versioned())
+        .tape(self.tape.clone())
+        .build()?
+        .send()
+        .wrap_err("Failed to send executor upgrade dry run")?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to dry run executor upgrade with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Get every committed transaction involving `account` in blocks from height `from`,
+    /// the genesis block by default, up to `to`, the latest block by default,
+    /// with proofs of inclusion into the chain.
+    ///
+    /// Peers search a limited number of blocks per request. If the range is longer,
+    /// the rest of it is returned by the request from [`AccountHistory::next`].
+    ///
+    /// # Errors
+    /// Fails if sending the request or decoding the response fails,
+    /// or if there is no block at height `from`
+    pub fn get_account_history(
+        &self,
+        account: &AccountId,
+        from: Option<NonZeroU64>,
+        to: Option<NonZeroU64>,
+    ) -> Result<AccountHistory> {
+        let mut req = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(
+                &self.torii_url,
+                &format!(
+                    "{}/{account}{}",
+                    torii_uri::ACCOUNTS,
+                    torii_uri::ACCOUNT_HISTORY
+                ),
+            ),
+        )
+        .headers(&self.headers);
+        if let Some(from) = from {
+            req = req.param("from", &from);
+        }
+        if let Some(to) = to {
+            req = req.param("to", &to);
+        }
+        let resp = req
+            .tape(self.tape.clone())
+            .build()?
+            .send()
+            .wrap_err("Failed to send account history request")?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get account history with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
     /// Connect (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
     ///
     /// # Errors
@@ -521,10 +1007,22 @@ impl Client {
         &self,
         event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
     ) -> Result<events_api::flow::Init> {
-        events_api::flow::Init::new(
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Failed to get the current system time");
+        let request = EventSubscriptionRequest::signed(
             event_filters.into_iter().map(Into::into).collect(),
+            self.chain.clone(),
+            self.account.clone(),
+            now + EVENT_SUBSCRIPTION_TTL,
+            rand::random::<NonZeroU32>(),
+            self.key_pair.private_key(),
+        );
+        events_api::flow::Init::new(
+            request,
             self.headers.clone(),
             join_torii_url(&self.torii_url, torii_uri::SUBSCRIPTION),
+            self.stream_compression,
         )
     }
 
@@ -559,6 +1057,7 @@ impl Client {
             height,
             self.headers.clone(),
             join_torii_url(&self.torii_url, torii_uri::BLOCKS_STREAM),
+            self.stream_compression,
         )
     }
 
@@ -573,6 +1072,7 @@ impl Client {
         )
         .headers(&self.headers)
         .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
+        .tape(self.tape.clone())
         .build()?
         .send()?;
 
@@ -597,6 +1097,7 @@ impl Client {
             .headers(&self.headers)
             .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
             .body(body)
+            .tape(self.tape.clone())
             .build()?
             .send()?;
 
@@ -618,7 +1119,8 @@ impl Client {
     pub fn get_status(&self) -> Result<Status> {
         let req = self
             .prepare_status_request::<DefaultRequestBuilder>()
-            .header(http::header::ACCEPT, "application/x-parity-scale");
+            .header(http::header::ACCEPT, "application/x-parity-scale")
+            .tape(self.tape.clone());
         let resp = req.build()?.send()?;
         let scaled_resp = StatusResponseHandler::handle(&resp).cloned()?;
         DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
@@ -647,6 +1149,7 @@ impl Client {
         )
         .headers(&self.headers)
         .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
+        .tape(self.tape.clone())
         .build()?
         .send()?;
 
@@ -659,6 +1162,189 @@ impl Client {
         }
         Ok(serde_json::from_slice(resp.body())?)
     }
+
+    /// Get the results of the peer startup phases
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_startup_report(&self) -> Result<StartupReport> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(&self.torii_url, torii_uri::STARTUP),
+        )
+        .headers(&self.headers)
+        .tape(self.tape.clone())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get startup report with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Ok(serde_json::from_slice(resp.body())?)
+    }
+
+    /// Request the peer to drain: stop accepting transactions, commit those already
+    /// in its queue and shut down. Repeated requests report the current phase.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn drain(&self) -> Result<DrainPhase> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.torii_url, torii_uri::DRAIN),
+        )
+        .headers(&self.headers)
+        .tape(self.tape.clone())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::ACCEPTED {
+            return Err(eyre!(
+                "Failed to request drain with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Ok(serde_json::from_slice(resp.body())?)
+    }
+
+    /// Get the traffic exchanged by the peer with each of its connected peers, busiest first
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_peers_traffic(&self) -> Result<Vec<PeerTraffic>> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(&self.torii_url, torii_uri::PEERS_TRAFFIC),
+        )
+        .headers(&self.headers)
+        .tape(self.tape.clone())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get peers traffic with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Ok(serde_json::from_slice(resp.body())?)
+    }
+
+    /// Get the schema of permission tokens supported by the executor installed on the peer
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_executor_schema(&self) -> Result<ExecutorSchema> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(&self.torii_url, torii_uri::EXECUTOR_SCHEMA),
+        )
+        .headers(&self.headers)
+        .tape(self.tape.clone())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get executor schema with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Ok(serde_json::from_slice(resp.body())?)
+    }
+
+    /// Check that the executor installed on the peer supports the permission token
+    /// and that its payload matches the token definition, before granting it.
+    ///
+    /// # Errors
+    /// - If the executor schema can't be fetched
+    /// - If the executor doesn't support the token or its payload doesn't match the definition
+    pub fn validate_permission(&self, permission: &Permission) -> Result<()> {
+        check_permission(&self.get_executor_schema()?, permission)
+    }
+}
+
+/// Check `permission` against the token definitions of the executor.
+///
+/// Only the shape of the payload is checked, values are left to the executor.
+fn check_permission(schema: &ExecutorSchema, permission: &Permission) -> Result<()> {
+    let name = permission.name();
+    let token = schema.permissions.get(name).ok_or_else(|| {
+        eyre!(
+            "Permission `{name}` is not supported by the executor, supported ones are: {}",
+            schema
+                .permissions
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    })?;
+    let definition: serde_json::Value = token
+        .schema
+        .try_into_any()
+        .wrap_err("Failed to decode the token definition")?;
+    let payload: serde_json::Value = permission
+        .payload()
+        .try_into_any()
+        .wrap_err("Failed to decode the permission payload")?;
+
+    if definition.is_null() {
+        if !payload.is_null() {
+            return Err(eyre!(
+                "Permission `{name}` (version {}) has no payload, got `{payload}`",
+                token.version
+            ));
+        }
+        return Ok(());
+    }
+    let Some(fields) = definition
+        .get("Struct")
+        .and_then(serde_json::Value::as_array)
+    else {
+        // Other definitions can't be told apart by the JSON shape, the executor checks them
+        return Ok(());
+    };
+
+    let expected: BTreeSet<&str> = fields
+        .iter()
+        .filter_map(|field| field.get("name").and_then(serde_json::Value::as_str))
+        .collect();
+    let Some(payload) = payload.as_object() else {
+        return Err(eyre!(
+            "Permission `{name}` (version {}) expects an object with fields: {}, got `{payload}`",
+            token.version,
+            expected.iter().copied().collect::<Vec<_>>().join(", ")
+        ));
+    };
+    let given: BTreeSet<&str> = payload.keys().map(String::as_str).collect();
+    if given != expected {
+        return Err(eyre!(
+            "Permission `{name}` (version {}) expects fields: {}, got: {}",
+            token.version,
+            expected.iter().copied().collect::<Vec<_>>().join(", "),
+            given.iter().copied().collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Decompress a stream message if compression was requested for the stream
+fn decompress(compression: Option<StreamCompression>, message: Vec<u8>) -> Result<Vec<u8>> {
+    match compression {
+        Some(compression) => compression
+            .decompress(&message)
+            .wrap_err("Failed to decompress stream message"),
+        None => Ok(message),
+    }
 }
 
 pub(crate) fn join_torii_url(url: &Url, path: &str) -> Url {
@@ -862,25 +1548,29 @@ pub mod events_api {
             url: Url,
             /// HTTP request headers
             headers: HashMap<String, String>,
-            /// Event filter
-            filters: Vec<EventFilterBox>,
+            /// Subscription request
+            request: EventSubscriptionRequest,
+            /// Compression requested for the stream
+            compression: Option<StreamCompression>,
         }
 
         impl Init {
-            /// Construct new item with provided filter, headers and url.
+            /// Construct new item with provided subscription request, headers and url.
             ///
             /// # Errors
             /// Fails if [`transform_ws_url`] fails.
             #[inline]
             pub(in super::super) fn new(
-                filters: Vec<EventFilterBox>,
+                request: EventSubscriptionRequest,
                 headers: HashMap<String, String>,
                 url: Url,
+                compression: Option<StreamCompression>,
             ) -> Result<Self> {
                 Ok(Self {
                     url: transform_ws_url(url)?,
                     headers,
-                    filters,
+                    request,
+                    compression,
                 })
             }
         }
@@ -892,22 +1582,30 @@ pub mod events_api {
                 let Self {
                     url,
                     headers,
-                    filters,
+                    request,
+                    compression,
                 } = self;
 
-                let msg = EventSubscriptionRequest::new(filters).encode();
-                InitData::new(R::new(HttpMethod::GET, url).headers(headers), msg, Events)
+                let msg = request.encode();
+                let mut builder = R::new(HttpMethod::GET, url).headers(headers);
+                if let Some(compression) = compression {
+                    builder = builder.header(STREAM_COMPRESSION_HEADER, &compression);
+                }
+                InitData::new(builder, msg, Events { compression })
             }
         }
 
         /// Events handler for Events API flow
         #[derive(Debug, Copy, Clone)]
-        pub struct Events;
+        pub struct Events {
+            compression: Option<StreamCompression>,
+        }
 
         impl FlowEvents for Events {
             type Event = crate::data_model::prelude::EventBox;
 
             fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                let message = decompress(self.compression, message)?;
                 let event_socket_message = EventMessage::decode_all(&mut message.as_slice())?;
                 Ok(event_socket_message.into())
             }
@@ -943,6 +1641,8 @@ mod blocks_api {
             headers: HashMap<String, String>,
             /// TORII URL
             url: Url,
+            /// Compression requested for the stream
+            compression: Option<StreamCompression>,
         }
 
         impl Init {
@@ -955,11 +1655,13 @@ mod blocks_api {
                 height: NonZeroU64,
                 headers: HashMap<String, String>,
                 url: Url,
+                compression: Option<StreamCompression>,
             ) -> Result<Self> {
                 Ok(Self {
                     height,
                     headers,
                     url: transform_ws_url(url)?,
+                    compression,
                 })
             }
         }
@@ -972,21 +1674,29 @@ mod blocks_api {
                     height,
                     headers,
                     url,
+                    compression,
                 } = self;
 
                 let msg = BlockSubscriptionRequest::new(height).encode();
-                InitData::new(R::new(HttpMethod::GET, url).headers(headers), msg, Events)
+                let mut builder = R::new(HttpMethod::GET, url).headers(headers);
+                if let Some(compression) = compression {
+                    builder = builder.header(STREAM_COMPRESSION_HEADER, &compression);
+                }
+                InitData::new(builder, msg, Events { compression })
             }
         }
 
         /// Events handler for Blocks API flow
         #[derive(Debug, Copy, Clone)]
-        pub struct Events;
+        pub struct Events {
+            compression: Option<StreamCompression>,
+        }
 
         impl FlowEvents for Events {
             type Event = SignedBlock;
 
             fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                let message = decompress(self.compression, message)?;
                 Ok(BlockMessage::decode_all(&mut message.as_slice()).map(Into::into)?)
             }
         }
@@ -1025,6 +1735,8 @@ mod tests {
             transaction_add_nonce: false,
             transaction_ttl: Duration::from_secs(5),
             transaction_status_timeout: Duration::from_secs(10),
+            transaction_route_to_leader: false,
+            torii_peers: HashMap::new(),
         }
     }
 
@@ -1059,6 +1771,26 @@ mod tests {
         assert_eq!(tx1.hash(), tx2.hash());
     }
 
+    #[test]
+    fn leader_is_looked_up_again_after_its_round() {
+        let client = Client::new(config_factory());
+        let leader = Some("http://127.0.0.1:8081".parse().unwrap());
+        *client.leader_cache.lock() = Some(CachedLeader {
+            height: 2,
+            torii_url: leader.clone(),
+        });
+
+        let cache = client.clone().leader_cache;
+        cache.block_applied(NonZeroU64::new(2).unwrap());
+        assert_eq!(
+            client.leader_cache.lock().as_ref().map(|l| &l.torii_url),
+            Some(&leader)
+        );
+
+        cache.block_applied(NonZeroU64::new(3).unwrap());
+        assert!(client.leader_cache.lock().is_none());
+    }
+
     #[test]
     fn authorization_header() {
         let client = Client::new(Config {
@@ -1077,6 +1809,46 @@ mod tests {
         assert_eq!(value, &expected_value);
     }
 
+    #[test]
+    fn permissions_are_checked_against_executor_schema() {
+        let token = |schema: &str| PermissionSchema {
+            version: "1".to_owned(),
+            schema: schema.parse().unwrap(),
+        };
+        let schema = ExecutorSchema {
+            permissions: [
+                ("CanSetParameters".to_owned(), token("null")),
+                (
+                    "CanModifyDomainMetadata".to_owned(),
+                    token(r#"{"Struct": [{"name": "domain", "type": "DomainId"}]}"#),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            types: Json::default(),
+        };
+        let permission = |name: &str, payload: &str| {
+            Permission::new(name.to_owned(), payload.parse::<Json>().unwrap())
+        };
+
+        check_permission(&schema, &permission("CanSetParameters", "null")).unwrap();
+        check_permission(
+            &schema,
+            &permission("CanModifyDomainMetadata", r#"{"domain": "wonderland"}"#),
+        )
+        .unwrap();
+
+        let _err = check_permission(&schema, &permission("CanManagePeers", "null"))
+            .expect_err("token isn't supported");
+        let _err = check_permission(&schema, &permission("CanSetParameters", "{}"))
+            .expect_err("token has no payload");
+        let _err = check_permission(
+            &schema,
+            &permission("CanModifyDomainMetadata", r#"{"domain_id": "wonderland"}"#),
+        )
+        .expect_err("field is misnamed");
+    }
+
     #[cfg(test)]
     mod join_torii_url {
         use url::Url;
@@ -1,7 +1,7 @@
 //! Module for client-related configuration and structs
 
 use core::str::FromStr;
-use std::{path::Path, time::Duration};
+use std::{collections::HashMap, path::Path, time::Duration};
 
 use derive_more::Display;
 use error_stack::ResultExt;
@@ -13,7 +13,7 @@ use serde_with::{DeserializeFromStr, SerializeDisplay};
 use url::Url;
 
 use crate::{
-    crypto::KeyPair,
+    crypto::{KeyPair, PublicKey},
     data_model::{prelude::*, ChainId},
 };
 
@@ -29,6 +29,8 @@ pub const DEFAULT_TRANSACTION_TIME_TO_LIVE: Duration = Duration::from_secs(100);
 pub const DEFAULT_TRANSACTION_STATUS_TIMEOUT: Duration = Duration::from_secs(15);
 #[allow(missing_docs)]
 pub const DEFAULT_TRANSACTION_NONCE: bool = false;
+#[allow(missing_docs)]
+pub const DEFAULT_TRANSACTION_ROUTE_TO_LEADER: bool = false;
 
 /// Valid web auth login string. See [`WebLogin::from_str`]
 #[derive(Debug, Display, Clone, PartialEq, Eq, DeserializeFromStr, SerializeDisplay)]
@@ -71,6 +73,8 @@ pub struct Config {
     pub transaction_ttl: Duration,
     pub transaction_status_timeout: Duration,
     pub transaction_add_nonce: bool,
+    pub transaction_route_to_leader: bool,
+    pub torii_peers: HashMap<PublicKey, Url>,
 }
 
 /// An error type for [`Config::load`]
@@ -171,6 +175,10 @@ mod tests {
             time_to_live_ms = 100_000
             status_timeout_ms = 100_000
             nonce = false
+            route_to_leader = true
+
+            [torii_peers]
+            ed0120A98BAFB0663CE08D75EBD506FEC38A84E576A7C9B0897693ED4B04FD9EF2D18D = "http://127.0.0.1:8081"
         }
     }
 
@@ -214,6 +222,26 @@ mod tests {
         assert_eq!(config.torii_api_url.as_str(), "http://127.0.0.1/peer-1/");
     }
 
+    #[test]
+    fn torii_peers_are_parsed() {
+        let config = ConfigReader::new()
+            .with_toml_source(TomlSource::inline(config_sample()))
+            .read_and_complete::<user::Root>()
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        assert!(config.transaction_route_to_leader);
+        let public_key: PublicKey =
+            "ed0120A98BAFB0663CE08D75EBD506FEC38A84E576A7C9B0897693ED4B04FD9EF2D18D"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            config.torii_peers[&public_key].as_str(),
+            "http://127.0.0.1:8081/"
+        );
+    }
+
     #[test]
     fn invalid_toml_file_is_handled_properly() {
         use std::io::Write;
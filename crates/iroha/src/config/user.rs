@@ -1,5 +1,7 @@
 //! User configuration view.
 
+use std::collections::HashMap;
+
 use error_stack::{Report, ResultExt};
 use iroha_config_base::{
     attach::ConfigValueAndOrigin,
@@ -23,6 +25,9 @@ pub struct Root {
     #[config(env = "TORII_URL")]
     pub torii_url: WithOrigin<Url>,
     pub basic_auth: Option<BasicAuth>,
+    /// Torii URLs of peers by their public keys, see [`Transaction::route_to_leader`]
+    #[config(default)]
+    pub torii_peers: HashMap<PublicKey, Url>,
     #[config(nested)]
     pub account: Account,
     #[config(nested)]
@@ -50,6 +55,7 @@ impl Root {
             chain: chain_id,
             torii_url,
             basic_auth,
+            torii_peers,
             account:
                 Account {
                     domain: domain_id,
@@ -61,6 +67,7 @@ impl Root {
                     time_to_live_ms: tx_ttl,
                     status_timeout_ms: tx_timeout,
                     nonce: tx_add_nonce,
+                    route_to_leader: tx_route_to_leader,
                 },
         } = self;
 
@@ -88,16 +95,11 @@ impl Root {
                 .attach_printable("Note: only `http` and `https` protocols are supported"),
             ),
         }
-        let torii_api_url = {
-            let mut url = torii_url.into_value();
-            let path = url.path();
-            // Ensure torii url ends with a trailing slash
-            if !path.ends_with('/') {
-                let path = path.to_owned() + "/";
-                url.set_path(&path)
-            }
-            url
-        };
+        let torii_api_url = with_trailing_slash(torii_url.into_value());
+        let torii_peers = torii_peers
+            .into_iter()
+            .map(|(public_key, url)| (public_key, with_trailing_slash(url)))
+            .collect();
 
         let (public_key, public_key_origin) = public_key.into_tuple();
         let (private_key, private_key_origin) = private_key.into_tuple();
@@ -119,10 +121,22 @@ impl Root {
             transaction_ttl: tx_ttl.into_value().get(),
             transaction_status_timeout: tx_timeout.into_value().get(),
             transaction_add_nonce: tx_add_nonce,
+            transaction_route_to_leader: tx_route_to_leader,
+            torii_peers,
         })
     }
 }
 
+/// Ensure torii url ends with a trailing slash
+fn with_trailing_slash(mut url: Url) -> Url {
+    let path = url.path();
+    if !path.ends_with('/') {
+        let path = path.to_owned() + "/";
+        url.set_path(&path)
+    }
+    url
+}
+
 #[derive(Debug, Clone, ReadConfig)]
 #[allow(missing_docs)]
 pub struct Account {
@@ -143,4 +157,8 @@ pub struct Transaction {
     pub status_timeout_ms: WithOrigin<DurationMs>,
     #[config(default = "super::DEFAULT_TRANSACTION_NONCE")]
     pub nonce: bool,
+    /// Submit transactions directly to the current leader if its URL is listed in
+    /// [`Root::torii_peers`], falling back to `torii_url`
+    #[config(default = "super::DEFAULT_TRANSACTION_ROUTE_TO_LEADER")]
+    pub route_to_leader: bool,
 }
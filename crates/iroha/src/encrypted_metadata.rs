@@ -0,0 +1,287 @@
+//! End-to-end encryption of metadata values.
+//!
+//! [`seal`] encrypts a value for a set of recipients into an [`Envelope`], which is
+//! stored on-chain as a regular metadata [`Json`] value. Peers and other clients only
+//! see the envelope; holders of a recipient [`EncryptionKeyPair`] can [`open`] it.
+//!
+//! The value is encrypted with a random content key using ChaCha20Poly1305, with the
+//! metadata key as associated data, so an envelope moved to another key fails to open.
+//! The content key is wrapped for each recipient with a key derived by X25519 from an
+//! ephemeral key of the envelope and the public key of the recipient.
+
+use std::{fmt, str::FromStr};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use iroha_crypto::{
+    encryption::{self, ChaCha20Poly1305, SymmetricEncryptor},
+    kex::{KeyExchangeScheme, X25519Sha256},
+    KeyGenOption, SessionKey,
+};
+use iroha_data_model::name::Name;
+use iroha_primitives::json::Json;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::tape::base64_bytes;
+
+/// Version of the [`Envelope`] format produced by [`seal`].
+pub const ENVELOPE_VERSION: u8 = 1;
+/// Algorithms used by [`seal`], recorded in every [`Envelope`].
+pub const ENVELOPE_SCHEME: &str = "x25519-sha256/chacha20poly1305";
+
+const CONTENT_KEY_SIZE: usize = 32;
+
+type PrivateKey = <X25519Sha256 as KeyExchangeScheme>::PrivateKey;
+
+/// Errors of [`seal`] and [`open`].
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum Error {
+    /// Value must be encrypted for at least one recipient
+    NoRecipients,
+    /// Value is not an encrypted metadata envelope
+    Envelope(#[from] serde_json::Error),
+    /// Envelope version `{0}` with scheme `{1}` is not supported
+    Unsupported(u8, String),
+    /// Key pair is not among the recipients of the envelope
+    NotARecipient,
+    /// Failed to encrypt or decrypt the value
+    Crypto(#[from] encryption::Error),
+    /// Wrapped content key has invalid length
+    InvalidContentKey,
+}
+
+/// X25519 key pair used to receive encrypted metadata.
+///
+/// It is independent of the signing keys of an account.
+/// Use [`Self::from_seed`] to derive it deterministically, e.g. from the account private key.
+pub struct EncryptionKeyPair {
+    public_key: EncryptionPublicKey,
+    private_key: PrivateKey,
+}
+
+impl EncryptionKeyPair {
+    /// Generate a random key pair.
+    pub fn random() -> Self {
+        Self::from_seed(rand::thread_rng().gen::<[u8; 32]>().to_vec())
+    }
+
+    /// Derive the key pair from `seed`.
+    pub fn from_seed(seed: Vec<u8>) -> Self {
+        let (public_key, private_key) = X25519Sha256::new().keypair(KeyGenOption::UseSeed(seed));
+        Self {
+            public_key: EncryptionPublicKey(public_key.to_bytes()),
+            private_key,
+        }
+    }
+
+    /// Public key to share with senders.
+    pub fn public_key(&self) -> &EncryptionPublicKey {
+        &self.public_key
+    }
+
+    fn shared_key(&self, other: &EncryptionPublicKey) -> SessionKey {
+        X25519Sha256::new().compute_shared_secret(&self.private_key, &other.to_x25519())
+    }
+}
+
+impl fmt::Debug for EncryptionKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptionKeyPair")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Public key of an [`EncryptionKeyPair`], displayed as base64.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct EncryptionPublicKey([u8; 32]);
+
+impl EncryptionPublicKey {
+    /// Create from raw bytes.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Raw bytes of the key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    fn to_x25519(self) -> <X25519Sha256 as KeyExchangeScheme>::PublicKey {
+        X25519Sha256::decode_public_key(self.0.to_vec()).expect("Key size is checked by type")
+    }
+}
+
+impl fmt::Display for EncryptionPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&STANDARD.encode(self.0))
+    }
+}
+
+/// Error of parsing [`EncryptionPublicKey`]
+#[derive(Debug, Clone, Copy, thiserror::Error, displaydoc::Display)]
+pub enum ParsePublicKeyError {
+    /// Public key must be valid base64
+    Base64,
+    /// Public key must be 32 bytes long
+    Length,
+}
+
+impl FromStr for EncryptionPublicKey {
+    type Err = ParsePublicKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = STANDARD
+            .decode(s)
+            .map_err(|_| ParsePublicKeyError::Base64)?;
+        let bytes = bytes.try_into().map_err(|_| ParsePublicKeyError::Length)?;
+        Ok(Self(bytes))
+    }
+}
+
+/// Encrypted metadata value, serialized as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Envelope {
+    /// Format version, see [`ENVELOPE_VERSION`]
+    pub version: u8,
+    /// Algorithms used, see [`ENVELOPE_SCHEME`]
+    pub scheme: String,
+    /// Public part of the ephemeral key used to wrap content keys
+    pub ephemeral_key: EncryptionPublicKey,
+    /// Content key wrapped for each recipient
+    pub recipients: Vec<WrappedKey>,
+    /// Nonce followed by the encrypted value
+    #[serde(with = "base64_bytes")]
+    pub ciphertext: Vec<u8>,
+}
+
+/// Content key of an [`Envelope`] encrypted for a single recipient.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WrappedKey {
+    /// Public key of the recipient
+    pub recipient: EncryptionPublicKey,
+    /// Nonce followed by the encrypted content key
+    #[serde(with = "base64_bytes")]
+    pub key: Vec<u8>,
+}
+
+/// Encrypt `value` of the metadata `key` for `recipients`.
+///
+/// The result is meant to be set as the value of `key`, e.g. with
+/// [`SetKeyValue`](iroha_data_model::isi::SetKeyValue).
+///
+/// # Errors
+/// - If `recipients` is empty
+/// - If encryption fails
+pub fn seal(key: &Name, value: &[u8], recipients: &[EncryptionPublicKey]) -> Result<Json, Error> {
+    if recipients.is_empty() {
+        return Err(Error::NoRecipients);
+    }
+
+    let content_key = rand::thread_rng().gen::<[u8; CONTENT_KEY_SIZE]>();
+    let ciphertext = SymmetricEncryptor::<ChaCha20Poly1305>::new_with_key(content_key)
+        .encrypt_easy(key.as_ref().as_bytes(), value)?;
+
+    let ephemeral = EncryptionKeyPair::random();
+    let recipients = recipients
+        .iter()
+        .map(|recipient| -> Result<WrappedKey, Error> {
+            let key = SymmetricEncryptor::<ChaCha20Poly1305>::new_from_session_key(
+                &ephemeral.shared_key(recipient),
+            )
+            .encrypt_easy(
+                wrapping_aad(&ephemeral.public_key, recipient).as_slice(),
+                content_key.as_slice(),
+            )?;
+            Ok(WrappedKey {
+                recipient: *recipient,
+                key,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Json::new(Envelope {
+        version: ENVELOPE_VERSION,
+        scheme: ENVELOPE_SCHEME.to_owned(),
+        ephemeral_key: ephemeral.public_key,
+        recipients,
+        ciphertext,
+    }))
+}
+
+/// Decrypt `value` of the metadata `key` with the `key_pair` of a recipient.
+///
+/// # Errors
+/// - If `value` is not an [`Envelope`] or its version is not supported
+/// - If `key_pair` is not among the recipients
+/// - If decryption fails, e.g. the envelope was tampered with or belongs to another key
+pub fn open(key: &Name, value: &Json, key_pair: &EncryptionKeyPair) -> Result<Vec<u8>, Error> {
+    let envelope: Envelope = value.try_into_any()?;
+    if envelope.version != ENVELOPE_VERSION || envelope.scheme != ENVELOPE_SCHEME {
+        return Err(Error::Unsupported(envelope.version, envelope.scheme));
+    }
+    let wrapped = envelope
+        .recipients
+        .iter()
+        .find(|wrapped| wrapped.recipient == key_pair.public_key)
+        .ok_or(Error::NotARecipient)?;
+
+    let content_key = SymmetricEncryptor::<ChaCha20Poly1305>::new_from_session_key(
+        &key_pair.shared_key(&envelope.ephemeral_key),
+    )
+    .decrypt_easy(
+        wrapping_aad(&envelope.ephemeral_key, &key_pair.public_key).as_slice(),
+        wrapped.key.as_slice(),
+    )?;
+    if content_key.len() != CONTENT_KEY_SIZE {
+        return Err(Error::InvalidContentKey);
+    }
+
+    Ok(
+        SymmetricEncryptor::<ChaCha20Poly1305>::new_with_key(content_key)
+            .decrypt_easy(key.as_ref().as_bytes(), envelope.ciphertext.as_slice())?,
+    )
+}
+
+/// Binds a wrapped content key to both parties of the key exchange.
+fn wrapping_aad(ephemeral_key: &EncryptionPublicKey, recipient: &EncryptionPublicKey) -> Vec<u8> {
+    [ephemeral_key.as_bytes().as_slice(), recipient.as_bytes()].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recipients_open_sealed_value() {
+        let key: Name = "invoice".parse().unwrap();
+        let alice = EncryptionKeyPair::random();
+        let bob = EncryptionKeyPair::from_seed(b"bob".to_vec());
+        let eve = EncryptionKeyPair::random();
+
+        let sealed = seal(&key, b"PO-1234", &[*alice.public_key(), *bob.public_key()]).unwrap();
+
+        assert_eq!(open(&key, &sealed, &alice).unwrap(), b"PO-1234");
+        assert_eq!(open(&key, &sealed, &bob).unwrap(), b"PO-1234");
+        assert!(matches!(
+            open(&key, &sealed, &eve),
+            Err(Error::NotARecipient)
+        ));
+        let other_key: Name = "receipt".parse().unwrap();
+        assert!(matches!(
+            open(&other_key, &sealed, &alice),
+            Err(Error::Crypto(_))
+        ));
+    }
+
+    #[test]
+    fn public_key_roundtrips_through_string() {
+        let key = *EncryptionKeyPair::random().public_key();
+        assert_eq!(key.to_string().parse::<EncryptionPublicKey>().unwrap(), key);
+        assert!(matches!(
+            "AAAA".parse::<EncryptionPublicKey>(),
+            Err(ParsePublicKeyError::Length)
+        ));
+    }
+}
@@ -10,7 +10,10 @@ use tungstenite::{client::IntoClientRequest, stream::MaybeTlsStream, WebSocket};
 pub use tungstenite::{Error as WebSocketError, Message as WebSocketMessage};
 use url::Url;
 
-use crate::http::{Method, RequestBuilder, Response};
+use crate::{
+    http::{Method, RequestBuilder, Response},
+    tape::Tape,
+};
 
 type Bytes = Vec<u8>;
 type AttoHttpRequestBuilderWithBytes = AttoHttpRequestBuilder<atto_body::Bytes<Bytes>>;
@@ -25,6 +28,7 @@ fn header_name_from_str(str: &str) -> Result<HeaderName> {
 pub struct DefaultRequestBuilder {
     inner: Result<AttoHttpRequestBuilder>,
     body: Option<Vec<u8>>,
+    tape: Option<Tape>,
 }
 
 impl DefaultRequestBuilder {
@@ -39,34 +43,54 @@ impl DefaultRequestBuilder {
         }
     }
 
+    /// Pass the request through `tape`, see [`Tape`].
+    pub fn tape(self, tape: Option<Tape>) -> Self {
+        Self { tape, ..self }
+    }
+
     /// Build request by consuming self.
     pub fn build(self) -> Result<DefaultRequest> {
-        self.inner
-            .map(|b| DefaultRequest(b.bytes(self.body.map_or_else(Vec::new, |vec| vec))))
+        let body = self.body.unwrap_or_default();
+        let tape = self.tape.map(|tape| (tape, body.clone()));
+        self.inner.map(|b| DefaultRequest {
+            inner: b.bytes(body),
+            tape,
+        })
     }
 }
 
 /// Request built by [`DefaultRequestBuilder`].
 #[derive(Debug)]
-pub struct DefaultRequest(AttoHttpRequestBuilderWithBytes);
+pub struct DefaultRequest {
+    inner: AttoHttpRequestBuilderWithBytes,
+    /// Tape with the copy of request body
+    tape: Option<(Tape, Bytes)>,
+}
 
 impl DefaultRequest {
     /// Sends itself and returns byte response
     ///
     /// # Errors
     /// Fails if request building and sending fails or response transformation fails
-    pub fn send(mut self) -> Result<Response<Bytes>> {
+    pub fn send(self) -> Result<Response<Bytes>> {
+        let Self { mut inner, tape } = self;
         let (method, url) = {
-            let inspect = self.0.inspect();
+            let inspect = inner.inspect();
             (inspect.method().clone(), inspect.url().clone())
         };
 
-        let response = self
-            .0
-            .send()
-            .wrap_err_with(|| format!("Failed to send http {method} request to {url}"))?;
+        let send = || {
+            let response = inner
+                .send()
+                .wrap_err_with(|| format!("Failed to send http {method} request to {url}"))?;
+
+            ClientResponse(response).try_into()
+        };
 
-        ClientResponse(response).try_into()
+        match tape {
+            Some((tape, body)) => tape.exchange(&method, &url, &body, send),
+            None => send(),
+        }
     }
 }
 
@@ -75,6 +99,7 @@ impl RequestBuilder for DefaultRequestBuilder {
         Self {
             inner: Ok(AttoHttpRequestBuilder::new(method, url)),
             body: None,
+            tape: None,
         }
     }
 
@@ -0,0 +1,240 @@
+//! Double-entry bookkeeping view of asset transfers.
+//!
+//! Every transfer found in an [`AccountStatement`] is presented as a [`JournalEntry`]
+//! which debits the receiving account and credits the sending one, following the
+//! convention for asset accounts. The [`Posting`]s of an [`AccountLedger`] are the legs
+//! of the entries touching a single account and asset, each carrying the running balance
+//! right after it, so they can be reconciled line by line against external books.
+//!
+//! Note that [`MovementDirection`] of statements follows the bank statement convention
+//! instead: an incoming transfer is a [`MovementDirection::Credit`] in a statement, but a
+//! [`Side::Debit`] posting here.
+//!
+//! Mints and burns have no counterparty, so they don't produce entries.
+//! They are still reflected in the running balances.
+
+use std::time::Duration;
+
+use iroha_crypto::HashOf;
+use iroha_data_model::{
+    account::AccountId,
+    asset::AssetDefinitionId,
+    query::account::FindAccountStatement,
+    statement::{AccountStatement, AssetMovement, AssetStatement, MovementDirection},
+    transaction::TransactionEntrypoint,
+};
+use iroha_primitives::numeric::Numeric;
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, query::QueryError};
+
+/// Side of the account a [`Posting`] is booked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Side {
+    /// Account received the asset.
+    Debit,
+    /// Account gave the asset away.
+    Credit,
+}
+
+/// Transfer of an asset between two accounts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Hash of the transaction (or time trigger) which made the transfer.
+    pub transaction: HashOf<TransactionEntrypoint>,
+    /// Creation time of the block containing the transfer, as Unix time in milliseconds.
+    pub timestamp_ms: u64,
+    /// Definition of the transferred asset.
+    pub asset_definition: AssetDefinitionId,
+    /// Transferred quantity.
+    pub amount: Numeric,
+    /// Receiving account.
+    pub debit: AccountId,
+    /// Sending account.
+    pub credit: AccountId,
+}
+
+impl JournalEntry {
+    /// Creation time of the block containing the transfer.
+    pub const fn timestamp(&self) -> Duration {
+        Duration::from_millis(self.timestamp_ms)
+    }
+}
+
+/// Leg of a [`JournalEntry`] booked on a single account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Posting {
+    /// Side of the account the posting is booked on.
+    pub side: Side,
+    /// Balance of the account right after the posting.
+    pub balance: Numeric,
+    /// Transfer the posting is a leg of.
+    pub entry: JournalEntry,
+}
+
+impl Posting {
+    /// Build the posting of `account` from a statement movement.
+    ///
+    /// Returns `None` for mints and burns.
+    pub fn from_movement(account: &AccountId, movement: &AssetMovement) -> Option<Self> {
+        let counterparty = movement.counterparty().clone()?;
+        let (side, debit, credit) = match movement.direction() {
+            MovementDirection::Credit => (Side::Debit, account.clone(), counterparty),
+            MovementDirection::Debit => (Side::Credit, counterparty, account.clone()),
+        };
+
+        Some(Self {
+            side,
+            balance: *movement.balance(),
+            entry: JournalEntry {
+                transaction: *movement.transaction(),
+                timestamp_ms: movement
+                    .timestamp()
+                    .as_millis()
+                    .try_into()
+                    .expect("INTERNAL BUG: timestamp was already stored as u64"),
+                asset_definition: movement.asset_definition().clone(),
+                amount: *movement.amount(),
+                debit,
+                credit,
+            },
+        })
+    }
+}
+
+/// Postings of an account for a single asset over a period of time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountLedger {
+    /// Account the ledger is kept for.
+    pub account: AccountId,
+    /// Definition of the asset.
+    pub asset_definition: AssetDefinitionId,
+    /// Balance at the start of the period.
+    pub opening_balance: Numeric,
+    /// Postings within the period, oldest first.
+    pub postings: Vec<Posting>,
+    /// Balance at the end of the period.
+    pub closing_balance: Numeric,
+}
+
+impl AccountLedger {
+    /// Build the ledger of `account` from the statement of one of its assets.
+    pub fn from_statement(account: &AccountId, statement: &AssetStatement) -> Self {
+        Self {
+            account: account.clone(),
+            asset_definition: statement.asset_definition().clone(),
+            opening_balance: *statement.opening_balance(),
+            postings: statement
+                .movements()
+                .iter()
+                .filter_map(|movement| Posting::from_movement(account, movement))
+                .collect(),
+            closing_balance: *statement.closing_balance(),
+        }
+    }
+
+    /// Build the ledgers of all assets of an account statement.
+    pub fn all_from_statement(statement: &AccountStatement) -> impl Iterator<Item = Self> + '_ {
+        statement
+            .assets()
+            .iter()
+            .map(|asset| Self::from_statement(statement.account(), asset))
+    }
+}
+
+impl Client {
+    /// Ledger of `account` for `asset_definition` within `[since, until)`.
+    ///
+    /// If the account neither held nor moved the asset within the period,
+    /// the ledger is empty with zero balances.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the [`FindAccountStatement`] query fails.
+    pub fn account_ledger(
+        &self,
+        account: AccountId,
+        asset_definition: AssetDefinitionId,
+        since: Duration,
+        until: Duration,
+    ) -> Result<AccountLedger, QueryError> {
+        let statement =
+            self.query_single(FindAccountStatement::period(account.clone(), since, until))?;
+
+        Ok(statement
+            .assets()
+            .iter()
+            .find(|asset| *asset.asset_definition() == asset_definition)
+            .map_or_else(
+                || AccountLedger {
+                    account: account.clone(),
+                    asset_definition: asset_definition.clone(),
+                    opening_balance: Numeric::ZERO,
+                    postings: Vec::new(),
+                    closing_balance: Numeric::ZERO,
+                },
+                |asset| AccountLedger::from_statement(&account, asset),
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::Hash;
+    use iroha_test_samples::{ALICE_ID, BOB_ID};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn movement(
+        direction: MovementDirection,
+        amount: u32,
+        balance: u32,
+        counterparty: Option<&AccountId>,
+    ) -> Value {
+        let transaction = HashOf::<TransactionEntrypoint>::from_untyped_unchecked(Hash::new(
+            balance.to_le_bytes(),
+        ));
+        json!({
+            "asset_definition": "rose#wonderland",
+            "direction": direction,
+            "amount": Numeric::from(amount),
+            "balance": Numeric::from(balance),
+            "counterparty": counterparty,
+            "transaction": transaction,
+            "timestamp_ms": 1000,
+        })
+    }
+
+    #[test]
+    fn transfers_are_booked_on_both_sides() {
+        let statement: AssetStatement = serde_json::from_value(json!({
+            "asset_definition": "rose#wonderland",
+            "opening_balance": Numeric::from(10_u32),
+            "movements": [
+                movement(MovementDirection::Credit, 5, 15, None),
+                movement(MovementDirection::Debit, 3, 12, Some(&BOB_ID)),
+                movement(MovementDirection::Credit, 7, 19, Some(&BOB_ID)),
+            ],
+            "closing_balance": Numeric::from(19_u32),
+        }))
+        .unwrap();
+
+        let ledger = AccountLedger::from_statement(&ALICE_ID, &statement);
+
+        // The mint has no counterparty, but its effect is in the running balances
+        assert_eq!(ledger.postings.len(), 2);
+        let [sent, received] = [&ledger.postings[0], &ledger.postings[1]];
+        assert_eq!(sent.side, Side::Credit);
+        assert_eq!(sent.balance, Numeric::from(12_u32));
+        assert_eq!(sent.entry.debit, *BOB_ID);
+        assert_eq!(sent.entry.credit, *ALICE_ID);
+        assert_eq!(sent.entry.amount, Numeric::from(3_u32));
+        assert_eq!(received.side, Side::Debit);
+        assert_eq!(received.balance, Numeric::from(19_u32));
+        assert_eq!(received.entry.debit, *ALICE_ID);
+        assert_eq!(received.entry.credit, *BOB_ID);
+        assert_eq!(ledger.opening_balance, Numeric::from(10_u32));
+        assert_eq!(ledger.closing_balance, Numeric::from(19_u32));
+    }
+}
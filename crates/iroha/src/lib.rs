@@ -2,10 +2,14 @@
 
 pub mod client;
 pub mod config;
+pub mod encrypted_metadata;
 pub mod http;
 mod http_default;
+#[cfg(feature = "ledger")]
+pub mod ledger;
 pub mod query;
 pub mod secrecy;
+pub mod tape;
 
 pub use iroha_crypto as crypto;
 pub use iroha_data_model as data_model;
@@ -1,6 +1,11 @@
 //! Functions and types to make queries to the Iroha peer.
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    num::NonZeroU32,
+    time::{Duration, SystemTime},
+};
 
 use eyre::{eyre, Context, Result};
 use http::StatusCode;
@@ -17,39 +22,68 @@ use crate::{
         query::{
             builder::{QueryBuilder, QueryExecutor},
             parameters::ForwardCursor,
-            Query, QueryOutput, QueryRequest, QueryResponse, QueryWithParams, SingularQuery,
-            SingularQueryBox, SingularQueryOutputBox,
+            read_token::ReadToken,
+            Query, QueryOutput, QueryRequest, QueryResponse, QueryWithParams, SignedQuery,
+            SingularQuery, SingularQueryBox, SingularQueryOutputBox,
         },
         ValidationFail,
     },
     http::{Method as HttpMethod, RequestBuilder},
     http_default::DefaultRequestBuilder,
+    tape::Tape,
 };
 
+/// Time after which peers reject queries signed by the client.
+///
+/// Peers remember signed queries until they expire to reject their replays.
+pub const QUERY_TTL: Duration = Duration::from_secs(60);
+
 #[derive(Debug)]
 struct ClientQueryRequestHead {
     torii_url: Url,
     headers: HashMap<String, String>,
     account_id: AccountId,
     key_pair: KeyPair,
+    tape: Option<Tape>,
 }
 
 impl ClientQueryRequestHead {
     fn assemble(&self, query: QueryRequest) -> DefaultRequestBuilder {
         // authorize and sign the query
-        let query = query
-            .with_authority(self.account_id.clone())
-            .sign(&self.key_pair);
+        let query = sign_query(query, self.account_id.clone(), &self.key_pair, QUERY_TTL);
 
+        self.assemble_signed(&query)
+    }
+
+    fn assemble_signed(&self, query: &SignedQuery) -> DefaultRequestBuilder {
         DefaultRequestBuilder::new(
             HttpMethod::POST,
             join_torii_url(&self.torii_url, torii_uri::QUERY),
         )
         .headers(self.headers.clone())
         .body(query.encode())
+        .tape(self.tape.clone())
     }
 }
 
+/// Sign `query` on behalf of `authority`, to be executed once within `ttl` from now.
+fn sign_query(
+    query: QueryRequest,
+    authority: AccountId,
+    key_pair: &KeyPair,
+    ttl: Duration,
+) -> SignedQuery {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Failed to get the current system time");
+
+    query
+        .with_authority(authority)
+        .with_expiry(now + ttl)
+        .with_nonce(rand::random::<NonZeroU32>())
+        .sign(key_pair)
+}
+
 /// Decode a raw response from the node's query endpoint
 fn decode_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult<QueryResponse> {
     match resp.status() {
@@ -210,6 +244,7 @@ impl Client {
             headers: self.headers.clone(),
             account_id: self.account.clone(),
             key_pair: self.key_pair.clone(),
+            tape: self.tape.clone(),
         }
     }
 
@@ -262,6 +297,80 @@ impl Client {
 
         Ok(response)
     }
+
+    /// Sign `query` on behalf of the account of this client, so that a third party
+    /// can execute it with [`Self::execute_presigned_query`].
+    ///
+    /// Peers reject the query after `ttl`, which must not exceed the limit configured on
+    /// the peers, and reject it when it is executed again on the same peer.
+    /// An iterable query is continued by the third party with [`Self::raw_continue_iterable_query`].
+    pub fn presign_query(&self, query: QueryRequest, ttl: Duration) -> SignedQuery {
+        sign_query(query, self.account.clone(), &self.key_pair, ttl)
+    }
+
+    /// Create a [`ReadToken`] for anyone holding it to execute `query` on behalf of
+    /// the account of this client until `ttl` passes.
+    ///
+    /// Unlike [`Self::presign_query`], the token can be redeemed any number of times,
+    /// but only returns the first batch of an iterable query.
+    /// Peers reject tokens whose `ttl` exceeds the limit configured on them.
+    pub fn mint_read_token(&self, query: QueryRequest, ttl: Duration) -> ReadToken {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Failed to get the current system time");
+
+        ReadToken::new(
+            self.chain.clone(),
+            query,
+            self.account.clone(),
+            now + ttl,
+            &self.key_pair,
+        )
+    }
+
+    /// URL at which the peer of this client executes `token`, e.g. to share with a counterparty.
+    pub fn read_token_url(&self, token: &ReadToken) -> Url {
+        join_torii_url(
+            &self.torii_url,
+            &format!("{}/{token}", torii_uri::READ_TOKEN),
+        )
+    }
+
+    /// Execute a [`ReadToken`] minted by any account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails, e.g. because the token has expired.
+    pub fn redeem_read_token(&self, token: &ReadToken) -> Result<QueryResponse, QueryError> {
+        let response = DefaultRequestBuilder::new(HttpMethod::GET, self.read_token_url(token))
+            .headers(self.headers.clone())
+            .tape(self.tape.clone())
+            .build()?
+            .send()?;
+        let response = decode_query_response(&response)?;
+
+        Ok(response)
+    }
+
+    /// Execute a `query` signed by another account with [`Self::presign_query`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails, e.g. because it has expired
+    /// or has already been executed.
+    pub fn execute_presigned_query(
+        &self,
+        query: &SignedQuery,
+    ) -> Result<QueryResponse, QueryError> {
+        let response = self
+            .get_query_request_head()
+            .assemble_signed(query)
+            .build()?
+            .send()?;
+        let response = decode_query_response(&response)?;
+
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,235 @@
+//! Recording and replaying of client sessions.
+//!
+//! In [`Tape::record`] mode every HTTP request sent by the client, together with
+//! the peer's response, is appended to a tape file as a line of JSON. In
+//! [`Tape::replay`] mode the client doesn't touch the network and serves responses
+//! from the tape instead, in the order they were recorded. This makes bug reports
+//! reproducible and allows hermetic tests of application logic against recorded
+//! peer behavior.
+//!
+//! Only request-response endpoints are taped. `WebSocket` streams (events, blocks)
+//! are not, so e.g. [`Client::submit_blocking`](crate::client::Client::submit_blocking)
+//! can't be replayed.
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::http::{Method, Response, StatusCode};
+
+/// Tape to record the client session to or to replay it from.
+///
+/// Cloned tapes share the same underlying file or recording.
+#[derive(Debug, Clone)]
+pub struct Tape(Arc<Mutex<Mode>>);
+
+#[derive(Debug)]
+enum Mode {
+    Record(BufWriter<File>),
+    Replay(VecDeque<Exchange>),
+}
+
+/// Single request with the response to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Exchange {
+    method: String,
+    url: String,
+    #[serde(with = "base64_bytes")]
+    request: Vec<u8>,
+    status: u16,
+    #[serde(with = "base64_bytes")]
+    response: Vec<u8>,
+}
+
+impl Tape {
+    /// Record the session to the file at `path`, overwriting it.
+    ///
+    /// # Errors
+    /// If the file can't be created.
+    pub fn record(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::create(path)
+            .wrap_err_with(|| format!("Failed to create tape file {}", path.display()))?;
+
+        Ok(Self(Arc::new(Mutex::new(Mode::Record(BufWriter::new(
+            file,
+        ))))))
+    }
+
+    /// Replay the session recorded to the file at `path`.
+    ///
+    /// # Errors
+    /// If the file can't be read or isn't a valid tape.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .wrap_err_with(|| format!("Failed to open tape file {}", path.display()))?;
+        let exchanges = BufReader::new(file)
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+            .map(|(idx, line)| {
+                let line = line.wrap_err("Failed to read tape file")?;
+                serde_json::from_str(&line)
+                    .wrap_err_with(|| format!("Invalid tape entry at line {}", idx + 1))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self(Arc::new(Mutex::new(Mode::Replay(exchanges)))))
+    }
+
+    /// Number of exchanges left to replay, `None` if the tape is being recorded.
+    pub fn remaining(&self) -> Option<usize> {
+        match &*self.lock() {
+            Mode::Record(_) => None,
+            Mode::Replay(exchanges) => Some(exchanges.len()),
+        }
+    }
+
+    /// Pass the request through the tape.
+    ///
+    /// When recording, `send` is called and its response is written to the tape.
+    /// When replaying, the next recorded response is returned instead.
+    pub(crate) fn exchange(
+        &self,
+        method: &Method,
+        url: &Url,
+        request: &[u8],
+        send: impl FnOnce() -> Result<Response<Vec<u8>>>,
+    ) -> Result<Response<Vec<u8>>> {
+        let mut mode = self.lock();
+
+        match &mut *mode {
+            Mode::Record(writer) => {
+                let response = send()?;
+                let exchange = Exchange {
+                    method: method.to_string(),
+                    url: url.to_string(),
+                    request: request.to_vec(),
+                    status: response.status().as_u16(),
+                    response: response.body().clone(),
+                };
+                serde_json::to_writer(&mut *writer, &exchange)
+                    .wrap_err("Failed to write to tape")?;
+                writeln!(writer).wrap_err("Failed to write to tape")?;
+                writer.flush().wrap_err("Failed to write to tape")?;
+
+                Ok(response)
+            }
+            Mode::Replay(exchanges) => {
+                let exchange = exchanges.pop_front().ok_or_else(|| {
+                    eyre!("Tape is exhausted, unexpected {method} request to {url}")
+                })?;
+                // Peer url may differ between recording and replay, as well as
+                // request bodies which contain signatures and timestamps
+                let recorded_url = Url::parse(&exchange.url).wrap_err("Invalid url on tape")?;
+                if exchange.method != method.as_str() || recorded_url.path() != url.path() {
+                    return Err(eyre!(
+                        "Tape mismatch: expected {} request to {}, got {method} request to {url}",
+                        exchange.method,
+                        exchange.url,
+                    ));
+                }
+
+                Response::builder()
+                    .status(
+                        StatusCode::from_u16(exchange.status)
+                            .wrap_err("Invalid status code on tape")?,
+                    )
+                    .body(exchange.response)
+                    .wrap_err("Failed to construct response from tape")
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Mode> {
+        self.0.lock().expect("tape lock shouldn't be poisoned")
+    }
+}
+
+/// (De)serialization of bytes as base64 strings.
+pub(crate) mod base64_bytes {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+    use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(body: &[u8]) -> Result<Response<Vec<u8>>> {
+        Ok(Response::builder()
+            .status(StatusCode::OK)
+            .body(body.to_vec())
+            .unwrap())
+    }
+
+    #[test]
+    fn replays_recorded_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.tape");
+        let status: Url = "http://127.0.0.1:8080/status".parse().unwrap();
+        let query: Url = "http://127.0.0.1:8080/query".parse().unwrap();
+
+        let tape = Tape::record(&path).unwrap();
+        tape.exchange(&Method::GET, &status, &[], || ok(b"up"))
+            .unwrap();
+        tape.exchange(&Method::POST, &query, b"q", || ok(b"result"))
+            .unwrap();
+        drop(tape);
+
+        let tape = Tape::replay(&path).unwrap();
+        assert_eq!(tape.remaining(), Some(2));
+        // Replay against another peer
+        let status: Url = "http://peer:1337/status".parse().unwrap();
+        let response = tape
+            .exchange(&Method::GET, &status, &[], || panic!("network is used"))
+            .unwrap();
+        assert_eq!(response.body(), b"up");
+        let response = tape
+            .exchange(&Method::POST, &query, b"other", || {
+                panic!("network is used")
+            })
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), b"result");
+        assert!(tape
+            .exchange(&Method::GET, &status, &[], || panic!("network is used"))
+            .is_err());
+    }
+
+    #[test]
+    fn replay_detects_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.tape");
+        let status: Url = "http://127.0.0.1:8080/status".parse().unwrap();
+        let query: Url = "http://127.0.0.1:8080/query".parse().unwrap();
+
+        Tape::record(&path)
+            .unwrap()
+            .exchange(&Method::GET, &status, &[], || ok(b"up"))
+            .unwrap();
+
+        let tape = Tape::replay(&path).unwrap();
+        assert!(tape
+            .exchange(&Method::POST, &query, &[], || panic!("network is used"))
+            .is_err());
+    }
+}
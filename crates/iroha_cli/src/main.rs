@@ -386,7 +386,9 @@ mod filter {
 
 mod events {
 
-    use iroha::data_model::events::pipeline::{BlockEventFilter, TransactionEventFilter};
+    use iroha::data_model::events::pipeline::{
+        BlockEventFilter, QueueEventFilter, TransactionEventFilter,
+    };
 
     use super::*;
 
@@ -408,6 +410,8 @@ mod events {
         Transaction,
         /// Notify when a block reaches specific stages
         Block,
+        /// Notify when a transaction submitted to the peer is dropped from its queue
+        Queue,
         /// Notify when a trigger execution is ordered
         TriggerExecute,
         /// Notify when a trigger execution is completed
@@ -423,6 +427,7 @@ mod events {
                 State => listen(DataEventFilter::Any, context, timeout),
                 Transaction => listen(TransactionEventFilter::default(), context, timeout),
                 Block => listen(BlockEventFilter::default(), context, timeout),
+                Queue => listen(QueueEventFilter::new(), context, timeout),
                 TriggerExecute => listen(ExecuteTriggerEventFilter::new(), context, timeout),
                 TriggerComplete => listen(TriggerCompletedEventFilter::new(), context, timeout),
             }
@@ -569,8 +574,10 @@ mod domain {
         Register(Id),
         /// Unregister a domain
         Unregister(Id),
-        /// Transfer ownership of a domain
+        /// Offer ownership of a domain to another account
         Transfer(Transfer),
+        /// Accept the transfer of a domain offered to you
+        AcceptTransfer(Id),
         /// Read and write metadata
         #[command(subcommand)]
         Meta(metadata::domain::Command),
@@ -610,6 +617,12 @@ mod domain {
                         .finish([instruction])
                         .wrap_err("Failed to transfer domain")
                 }
+                AcceptTransfer(args) => {
+                    let instruction = iroha::data_model::isi::AcceptDomainTransfer::new(args.id);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to accept domain transfer")
+                }
                 Meta(cmd) => cmd.run(context),
             }
         }
@@ -639,7 +652,7 @@ mod domain {
 }
 
 mod account {
-    use std::fmt::Debug;
+    use std::{fmt::Debug, num::NonZeroU64};
 
     use super::*;
 
@@ -656,6 +669,8 @@ mod account {
         List(List),
         /// Retrieve details of a specific account
         Get(Id),
+        /// Export every committed transaction involving an account, with inclusion proofs
+        History(History),
         /// Register an account
         Register(Id),
         /// Unregister an account
@@ -681,6 +696,13 @@ mod account {
                         .wrap_err("Failed to get account")?;
                     context.print_data(&entry)
                 }
+                History(args) => {
+                    let client = context.client_from_config();
+                    let history = client
+                        .get_account_history(&args.id, args.from, args.to)
+                        .wrap_err("Failed to get account history")?;
+                    context.print_data(&history)
+                }
                 Register(args) => {
                     let instruction =
                         iroha::data_model::isi::Register::account(Account::new(args.id));
@@ -761,6 +783,11 @@ mod account {
                 }
                 Grant(args) => {
                     let permission: Permission = parse_json5_stdin(context)?;
+                    if !context.output_instructions() {
+                        context
+                            .client_from_config()
+                            .validate_permission(&permission)?;
+                    }
                     let instruction =
                         iroha::data_model::isi::Grant::account_permission(permission, args.id);
                     context
@@ -786,6 +813,19 @@ mod account {
         id: AccountId,
     }
 
+    #[derive(clap::Args, Debug)]
+    pub struct History {
+        /// Account in the format "multihash@domain"
+        #[arg(short, long)]
+        id: AccountId,
+        /// Height of the first block to search, the genesis block by default
+        #[arg(long)]
+        from: Option<NonZeroU64>,
+        /// Height of the last block to search, the latest block by default
+        #[arg(long)]
+        to: Option<NonZeroU64>,
+    }
+
     #[derive(clap::Args, Debug)]
     pub struct IdRole {
         /// Account in the format "multihash@domain"
@@ -1693,6 +1733,8 @@ mod role {
         Register(Id),
         /// Unregister a role
         Unregister(Id),
+        /// Transfer ownership of a role
+        Transfer(Transfer),
     }
 
     impl Run for Command {
@@ -1716,10 +1758,30 @@ mod role {
                         .finish([instruction])
                         .wrap_err("Failed to unregister role")
                 }
+                Transfer(args) => {
+                    let instruction =
+                        iroha::data_model::isi::Transfer::role(args.from, args.id, args.to);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to transfer role")
+                }
             }
         }
     }
 
+    #[derive(clap::Args, Debug)]
+    pub struct Transfer {
+        /// Role name
+        #[arg(short, long)]
+        pub id: RoleId,
+        /// Current owner, in the format "multihash@domain"
+        #[arg(short, long)]
+        pub from: AccountId,
+        /// New owner, in the format "multihash@domain"
+        #[arg(short, long)]
+        pub to: AccountId,
+    }
+
     #[derive(clap::Subcommand, Debug)]
     pub enum PermissionCommand {
         /// List role permissions
@@ -1747,6 +1809,11 @@ mod role {
                 }
                 Grant(args) => {
                     let permission: Permission = parse_json5_stdin(context)?;
+                    if !context.output_instructions() {
+                        context
+                            .client_from_config()
+                            .validate_permission(&permission)?;
+                    }
                     let instruction =
                         iroha::data_model::isi::Grant::role_permission(permission, args.id);
                     context
@@ -1948,8 +2015,12 @@ mod executor {
     pub enum Command {
         /// Retrieve the executor data model
         DataModel,
+        /// Retrieve the schemas of the permission tokens accepted by the executor
+        Permissions,
         /// Upgrade the executor
         Upgrade(Upgrade),
+        /// Abort an executor upgrade scheduled for activation at a later block
+        AbortUpgrade(AbortUpgrade),
     }
 
     impl Run for Command {
@@ -1961,6 +2032,11 @@ mod executor {
                     let model = client.query_single(FindExecutorDataModel)?;
                     context.print_data(&model)
                 }
+                Permissions => {
+                    let client = context.client_from_config();
+                    let schemas = client.query_single(FindPermissionSchemas)?;
+                    context.print_data(&schemas)
+                }
                 Upgrade(args) => {
                     let instruction = fs::read(args.path)
                         .map(WasmSmartContract::from_compiled)
@@ -1969,6 +2045,11 @@ mod executor {
                         .wrap_err("Failed to read a Wasm from the file")?;
                     context.finish([instruction])
                 }
+                AbortUpgrade(args) => {
+                    context.finish([iroha::data_model::isi::AbortExecutorUpgrade::new(
+                        args.activation_height,
+                    )])
+                }
             }
         }
     }
@@ -1979,6 +2060,13 @@ mod executor {
         #[arg(short, long)]
         path: PathBuf,
     }
+
+    #[derive(clap::Args, Debug)]
+    pub struct AbortUpgrade {
+        /// Height of the block at which the upgrade is scheduled to activate
+        #[arg(short, long)]
+        activation_height: u64,
+    }
 }
 
 mod metadata {
@@ -16,7 +16,7 @@ use iroha_data_model::{
 };
 use iroha_primitives::{addr::SocketAddr, unique_vec::UniqueVec};
 use url::Url;
-pub use user::{DevTelemetry, Logger, Snapshot};
+pub use user::{Backup, BackupS3, DevTelemetry, Logger, Memory, RedactionSecret, Snapshot};
 
 use crate::{
     kura::InitMode,
@@ -39,6 +39,8 @@ pub struct Root {
     pub logger: Logger,
     pub queue: Queue,
     pub snapshot: Snapshot,
+    pub memory: Memory,
+    pub backup: Backup,
     pub telemetry: Option<Telemetry>,
     pub dev_telemetry: DevTelemetry,
 }
@@ -71,6 +73,9 @@ pub struct Common {
     pub key_pair: KeyPair,
     pub peer: Peer,
     pub trusted_peers: WithOrigin<TrustedPeers>,
+    /// Secret the salts of redacted metadata values are derived from, see
+    /// [`Redact`](iroha_data_model::isi::Redact).
+    pub redaction_secret: Option<RedactionSecret>,
 }
 
 /// Network options
@@ -80,6 +85,8 @@ pub struct Network {
     pub address: WithOrigin<SocketAddr>,
     pub public_address: WithOrigin<SocketAddr>,
     pub idle_timeout: Duration,
+    pub dns_refresh_interval: Duration,
+    pub peer_bandwidth_limit: Option<Bytes<u64>>,
 }
 
 /// Parsed genesis configuration
@@ -99,6 +106,7 @@ pub struct Queue {
     pub capacity: NonZeroUsize,
     pub capacity_per_user: NonZeroUsize,
     pub transaction_time_to_live: Duration,
+    pub replay_window: Duration,
 }
 
 #[allow(missing_docs)]
@@ -116,6 +124,7 @@ impl Default for Queue {
             transaction_time_to_live: defaults::queue::TRANSACTION_TIME_TO_LIVE,
             capacity: defaults::queue::CAPACITY,
             capacity_per_user: defaults::queue::CAPACITY_PER_USER,
+            replay_window: defaults::queue::REPLAY_WINDOW,
         }
     }
 }
@@ -186,6 +195,43 @@ pub struct TransactionGossiper {
 pub struct Torii {
     pub address: WithOrigin<SocketAddr>,
     pub max_content_len: Bytes<u64>,
+    pub query_threads: NonZeroUsize,
+    pub ingress_validation: bool,
+    pub restrict_account_events: bool,
+    pub query_max_ttl: Duration,
+    pub require_query_expiry: bool,
+    pub read_token_max_ttl: Duration,
+    pub tls: Option<ToriiTls>,
+    pub unix_socket: Option<ToriiUnixSocket>,
+}
+
+/// Native TLS termination of the Torii listener
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct ToriiTls {
+    pub certificates: Vec<ToriiTlsCertificate>,
+    /// How often the certificate files are checked for changes
+    pub reload_interval: Duration,
+}
+
+/// Certificate chain and private key served by Torii
+#[derive(Debug, Clone)]
+pub struct ToriiTlsCertificate {
+    /// Server name (SNI) the certificate is served for, or `None` for the default certificate
+    pub server_name: Option<String>,
+    /// PEM file with the certificate chain
+    pub cert_file: PathBuf,
+    /// PEM file with the private key
+    pub key_file: PathBuf,
+}
+
+/// Unix domain socket listener of Torii for co-located clients
+#[derive(Debug, Clone)]
+pub struct ToriiUnixSocket {
+    /// Path of the socket file
+    pub path: PathBuf,
+    /// Permissions of the socket file, which control who can connect
+    pub mode: u32,
 }
 
 /// Complete configuration needed to start regular telemetry.
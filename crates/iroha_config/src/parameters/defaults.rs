@@ -17,6 +17,8 @@ pub mod queue {
     pub const CAPACITY_PER_USER: NonZeroUsize = nonzero!(2_usize.pow(16));
     // 24 hours
     pub const TRANSACTION_TIME_TO_LIVE: Duration = Duration::from_secs(24 * 60 * 60);
+    // 24 hours
+    pub const REPLAY_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
 }
 
 pub mod kura {
@@ -38,14 +40,36 @@ pub mod network {
     pub const BLOCK_GOSSIP_SIZE: NonZeroU32 = nonzero!(4u32);
 
     pub const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+    pub const DNS_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
 }
 
 pub mod snapshot {
+    use iroha_config_base::util::Bytes;
+
     use super::*;
 
     pub const STORE_DIR: &str = "./storage/snapshot";
     // 10 mins
     pub const CREATE_EVERY: Duration = Duration::from_secs(10 * 60);
+    pub const CREATE_EVERY_BLOCKS: u64 = 0;
+    // 64 MiB/s
+    pub const WRITE_RATE_LIMIT: Bytes<u64> = Bytes(2_u64.pow(20) * 64);
+}
+
+pub mod memory {
+    use super::*;
+
+    pub const SAMPLE_EVERY: Duration = Duration::from_secs(10);
+}
+
+pub mod backup {
+    use super::*;
+
+    // 24 hours
+    pub const CREATE_EVERY: Duration = Duration::from_secs(24 * 60 * 60);
+    pub const KEEP: NonZeroUsize = nonzero!(7_usize);
+    pub const S3_REGION: &str = "us-east-1";
 }
 
 pub mod torii {
@@ -58,6 +82,15 @@ pub mod torii {
     pub const QUERY_IDLE_TIME: Duration = Duration::from_secs(10);
     pub const QUERY_STORE_CAPACITY: NonZeroUsize = nonzero!(128usize);
     pub const QUERY_STORE_CAPACITY_PER_USER: NonZeroUsize = nonzero!(128usize);
+    pub const QUERY_THREADS: NonZeroUsize = nonzero!(4usize);
+    pub const INGRESS_VALIDATION: bool = false;
+    pub const RESTRICT_ACCOUNT_EVENTS: bool = false;
+    pub const QUERY_MAX_TTL: Duration = Duration::from_secs(5 * 60);
+    pub const REQUIRE_QUERY_EXPIRY: bool = false;
+    pub const READ_TOKEN_MAX_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+    pub const TLS_RELOAD_INTERVAL: Duration = Duration::from_secs(60);
+    /// Owner and group may connect
+    pub const UNIX_SOCKET_MODE: u32 = 0o660;
 }
 
 pub mod telemetry {
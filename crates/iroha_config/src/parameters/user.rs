@@ -17,7 +17,7 @@ use std::{
     path::PathBuf,
 };
 
-use error_stack::{Result, ResultExt};
+use error_stack::{Report, Result, ResultExt};
 use iroha_config_base::{
     attach::ConfigValueAndOrigin,
     env::FromEnvStr,
@@ -34,6 +34,7 @@ use crate::{
     kura::InitMode as KuraInitMode,
     logger::{Directives, Format as LoggerFormat},
     parameters::{actual, defaults},
+    secret::{read_secret, SecretSource},
     snapshot::Mode as SnapshotMode,
 };
 
@@ -58,7 +59,13 @@ pub struct Root {
     #[config(env = "PUBLIC_KEY")]
     public_key: WithOrigin<PublicKey>,
     #[config(env = "PRIVATE_KEY")]
-    private_key: WithOrigin<PrivateKey>,
+    private_key: Option<WithOrigin<PrivateKey>>,
+    #[config(env = "PRIVATE_KEY_SOURCE")]
+    private_key_source: Option<WithOrigin<SecretSource>>,
+    #[config(env = "REDACTION_SECRET")]
+    redaction_secret: Option<WithOrigin<RedactionSecret>>,
+    #[config(env = "REDACTION_SECRET_SOURCE")]
+    redaction_secret_source: Option<WithOrigin<SecretSource>>,
     #[config(env = "TRUSTED_PEERS", default)]
     trusted_peers: WithOrigin<TrustedPeers>,
     #[config(nested)]
@@ -75,6 +82,10 @@ pub struct Root {
     queue: Queue,
     #[config(nested)]
     snapshot: Snapshot,
+    #[config(nested)]
+    memory: Memory,
+    #[config(nested)]
+    backup: Backup,
     telemetry: Option<Telemetry>,
     #[config(nested)]
     dev_telemetry: DevTelemetry,
@@ -86,6 +97,16 @@ pub struct Root {
 pub enum ParseError {
     #[error("Failed to construct the key pair")]
     BadKeyPair,
+    #[error("Either `private_key` or `private_key_source` should be set")]
+    MissingPrivateKey,
+    #[error("Only one of `private_key` and `private_key_source` can be set")]
+    AmbiguousPrivateKey,
+    #[error("Failed to read the private key from `private_key_source`")]
+    PrivateKeySource,
+    #[error("Only one of `redaction_secret` and `redaction_secret_source` can be set")]
+    AmbiguousRedactionSecret,
+    #[error("Failed to read the redaction secret from `redaction_secret_source`")]
+    RedactionSecretSource,
 }
 
 impl Root {
@@ -97,13 +118,18 @@ impl Root {
     pub fn parse(self) -> Result<actual::Root, ParseError> {
         let mut emitter = Emitter::new();
 
-        let (private_key, private_key_origin) = self.private_key.into_tuple();
+        let (private_key, private_key_origin) =
+            resolve_private_key(self.private_key, self.private_key_source)?.into_tuple();
         let (public_key, public_key_origin) = self.public_key.into_tuple();
         let key_pair = iroha_crypto::KeyPair::new(public_key, private_key)
             .attach_printable(ConfigValueAndOrigin::new("[REDACTED]", public_key_origin))
             .attach_printable(ConfigValueAndOrigin::new("[REDACTED]", private_key_origin))
             .change_context(ParseError::BadKeyPair)
             .ok_or_emit(&mut emitter);
+        let redaction_secret =
+            resolve_redaction_secret(self.redaction_secret, self.redaction_secret_source)
+                .ok_or_emit(&mut emitter)
+                .flatten();
 
         let (network, block_sync, transaction_gossiper) = self.network.parse();
         let Some((peer, trusted_peers)) = key_pair.as_ref().map(|key_pair| {
@@ -130,6 +156,8 @@ impl Root {
         let logger = self.logger;
         let queue = self.queue;
         let snapshot = self.snapshot;
+        let memory = self.memory;
+        let backup = self.backup;
         let dev_telemetry = self.dev_telemetry;
         let (torii, live_query_store) = self.torii.parse();
         let telemetry = self.telemetry.map(actual::Telemetry::from);
@@ -144,6 +172,7 @@ impl Root {
             key_pair,
             peer,
             trusted_peers,
+            redaction_secret,
         };
 
         Ok(actual::Root {
@@ -159,12 +188,94 @@ impl Root {
             logger,
             queue: queue.parse(),
             snapshot,
+            memory,
+            backup,
             telemetry,
             dev_telemetry,
         })
     }
 }
 
+/// Take the private key set either inline or in an external source
+fn resolve_private_key(
+    inline: Option<WithOrigin<PrivateKey>>,
+    source: Option<WithOrigin<SecretSource>>,
+) -> Result<WithOrigin<PrivateKey>, ParseError> {
+    match (inline, source) {
+        (Some(private_key), None) => Ok(private_key),
+        (None, Some(source)) => {
+            let private_key = read_secret(&source)
+                .change_context(ParseError::PrivateKeySource)
+                .attach_printable_lazy(|| source.clone().into_attachment())?
+                .parse::<PrivateKey>()
+                .change_context(ParseError::PrivateKeySource)
+                .attach_printable_lazy(|| source.clone().into_attachment())?;
+            Ok(source.map(|_| private_key))
+        }
+        (None, None) => Err(Report::new(ParseError::MissingPrivateKey)),
+        (Some(private_key), Some(source)) => Err(Report::new(ParseError::AmbiguousPrivateKey)
+            .attach_printable(ConfigValueAndOrigin::new(
+                "[REDACTED]",
+                private_key.origin().clone(),
+            ))
+            .attach_printable(source.into_attachment())),
+    }
+}
+
+/// Take the redaction secret set either inline or in an external source, if any
+fn resolve_redaction_secret(
+    inline: Option<WithOrigin<RedactionSecret>>,
+    source: Option<WithOrigin<SecretSource>>,
+) -> Result<Option<RedactionSecret>, ParseError> {
+    match (inline, source) {
+        (Some(secret), None) => Ok(Some(secret.into_value())),
+        (None, Some(source)) => read_secret(&source)
+            .map(|secret| Some(RedactionSecret(secret)))
+            .change_context(ParseError::RedactionSecretSource)
+            .attach_printable_lazy(|| source.clone().into_attachment()),
+        (None, None) => Ok(None),
+        (Some(secret), Some(source)) => Err(Report::new(ParseError::AmbiguousRedactionSecret)
+            .attach_printable(ConfigValueAndOrigin::new(
+                "[REDACTED]",
+                secret.origin().clone(),
+            ))
+            .attach_printable(source.into_attachment())),
+    }
+}
+
+/// Secret the peers derive the salts of redacted metadata values from.
+///
+/// It has to be the same on all peers and must not change, as blocks with redactions
+/// are applied only with the secret they were made with.
+/// Never printed, since redacted values could be recovered by guessing with it.
+#[derive(Clone, Deserialize)]
+#[serde(transparent)]
+pub struct RedactionSecret(String);
+
+impl RedactionSecret {
+    /// The secret itself.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromEnvStr for RedactionSecret {
+    type Error = Infallible;
+
+    fn from_env_str(value: Cow<'_, str>) -> std::result::Result<Self, Self::Error>
+    where
+        Self: Sized,
+    {
+        Ok(Self(value.into_owned()))
+    }
+}
+
+impl Debug for RedactionSecret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
 #[derive(Debug, ReadConfig)]
 pub struct Genesis {
     #[config(env = "GENESIS_PUBLIC_KEY")]
@@ -291,6 +402,14 @@ pub struct Network {
     /// Duration of time after which connection with peer is terminated if peer is idle
     #[config(default = "defaults::network::IDLE_TIMEOUT.into()")]
     pub idle_timeout_ms: DurationMs,
+    /// Interval of re-resolving peer addresses specified by host name.
+    /// Connections to peers whose host name resolves to new IP addresses are re-established.
+    #[config(default = "defaults::network::DNS_REFRESH_INTERVAL.into()")]
+    pub dns_refresh_interval_ms: DurationMs,
+    /// Upper limit of bytes per second sent to and received from each of the peers.
+    ///
+    /// Each direction of each connection is limited separately. Unlimited if not set.
+    pub peer_bandwidth_limit: Option<Bytes<u64>>,
 }
 
 impl Network {
@@ -309,6 +428,8 @@ impl Network {
             transaction_gossip_size,
             transaction_gossip_period_ms: transaction_gossip_period,
             idle_timeout_ms: idle_timeout,
+            dns_refresh_interval_ms: dns_refresh_interval,
+            peer_bandwidth_limit,
         } = self;
 
         (
@@ -316,6 +437,8 @@ impl Network {
                 address,
                 public_address,
                 idle_timeout: idle_timeout.get(),
+                dns_refresh_interval: dns_refresh_interval.get(),
+                peer_bandwidth_limit,
             },
             actual::BlockSync {
                 gossip_period: block_gossip_period.get(),
@@ -341,6 +464,10 @@ pub struct Queue {
     /// The transaction will be dropped after this time if it is still in the queue.
     #[config(default = "defaults::queue::TRANSACTION_TIME_TO_LIVE.into()")]
     pub transaction_time_to_live_ms: DurationMs,
+    /// Transactions created earlier than this are rejected by the queue regardless of their own TTL.
+    /// It only limits what a peer accepts from clients, blocks are not checked against it.
+    #[config(default = "defaults::queue::REPLAY_WINDOW.into()")]
+    pub replay_window_ms: DurationMs,
 }
 
 impl Queue {
@@ -349,11 +476,13 @@ impl Queue {
             capacity,
             capacity_per_user,
             transaction_time_to_live_ms: transaction_time_to_live,
+            replay_window_ms: replay_window,
         } = self;
         actual::Queue {
             capacity,
             capacity_per_user,
             transaction_time_to_live: transaction_time_to_live.0,
+            replay_window: replay_window.0,
         }
     }
 }
@@ -460,11 +589,90 @@ pub struct Snapshot {
     pub mode: SnapshotMode,
     #[config(default = "defaults::snapshot::CREATE_EVERY.into()")]
     pub create_every_ms: DurationMs,
+    /// Number of committed blocks after which a snapshot is created,
+    /// in addition to the periodic ones made every `create_every_ms`.
+    ///
+    /// Bounds the number of blocks a restarted peer has to apply on top of the snapshot
+    /// on busy chains. `0` disables block-based snapshots.
+    #[config(default = "defaults::snapshot::CREATE_EVERY_BLOCKS")]
+    pub create_every_blocks: u64,
     #[config(
         default = "PathBuf::from(defaults::snapshot::STORE_DIR)",
         env = "SNAPSHOT_STORE_DIR"
     )]
     pub store_dir: WithOrigin<PathBuf>,
+    /// Upper limit of bytes per second written while creating a snapshot.
+    ///
+    /// Keeps snapshot IO from competing with block storage. `0` disables the limit.
+    /// Not applied to the snapshot taken on shutdown.
+    #[config(default = "defaults::snapshot::WRITE_RATE_LIMIT")]
+    pub write_rate_limit: Bytes<u64>,
+}
+
+#[derive(Debug, Clone, ReadConfig)]
+pub struct Memory {
+    /// How often memory held by the peer subsystems is estimated.
+    #[config(default = "defaults::memory::SAMPLE_EVERY.into()")]
+    pub sample_every_ms: DurationMs,
+    /// Bytes held by queued transactions above which new queries are rejected.
+    pub queue_limit: Option<Bytes<u64>>,
+    /// Bytes held by live query cursors above which new queries are rejected.
+    pub live_queries_limit: Option<Bytes<u64>>,
+    /// Bytes held by undelivered events above which new queries are rejected.
+    pub events_limit: Option<Bytes<u64>>,
+}
+
+#[derive(Debug, Clone, ReadConfig)]
+pub struct Backup {
+    /// Directory where backups of the block store and state snapshot are created.
+    ///
+    /// Backups are disabled if not set.
+    pub store_dir: Option<WithOrigin<PathBuf>>,
+    #[config(default = "defaults::backup::CREATE_EVERY.into()")]
+    pub create_every_ms: DurationMs,
+    /// Number of the most recent backups to keep. Older backups are removed.
+    #[config(default = "defaults::backup::KEEP")]
+    pub keep: NonZeroUsize,
+    /// S3-compatible object store to which backups are uploaded once they are
+    /// created in `store_dir`. The same number of backups is kept there.
+    pub s3: Option<BackupS3>,
+}
+
+/// Bucket of an S3-compatible object store, addressed by path as `<endpoint>/<bucket>`.
+#[derive(Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BackupS3 {
+    /// URL of the store, e.g. `https://s3.eu-central-1.amazonaws.com`
+    pub endpoint: Url,
+    /// Bucket to store backups in
+    pub bucket: String,
+    /// Region of the bucket, which requests are signed for
+    #[serde(default = "default_backup_s3_region")]
+    pub region: String,
+    /// Prefix of the keys of backup objects, e.g. `peer-0/`
+    #[serde(default)]
+    pub prefix: String,
+    /// Id of the access key requests are signed with
+    pub access_key_id: String,
+    /// Secret of the access key requests are signed with
+    pub secret_access_key: String,
+}
+
+fn default_backup_s3_region() -> String {
+    defaults::backup::S3_REGION.to_owned()
+}
+
+impl Debug for BackupS3 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BackupS3")
+            .field("endpoint", &self.endpoint)
+            .field("bucket", &self.bucket)
+            .field("region", &self.region)
+            .field("prefix", &self.prefix)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"[REDACTED]")
+            .finish()
+    }
 }
 
 #[derive(Debug, ReadConfig)]
@@ -481,6 +689,122 @@ pub struct Torii {
     /// The upper limit of the number of live queries for a single user.
     #[config(default = "defaults::torii::QUERY_STORE_CAPACITY_PER_USER")]
     pub query_store_capacity_per_user: NonZeroUsize,
+    /// The number of threads dedicated to query execution.
+    #[config(default = "defaults::torii::QUERY_THREADS")]
+    pub query_threads: NonZeroUsize,
+    /// Reject transactions that the executor would reject against the current state
+    /// before they are put into the queue.
+    #[config(default = "defaults::torii::INGRESS_VALIDATION")]
+    pub ingress_validation: bool,
+    /// Only send events about an account to subscribers authenticated as the account
+    /// or holding the permission to subscribe to its events.
+    #[config(default = "defaults::torii::RESTRICT_ACCOUNT_EVENTS")]
+    pub restrict_account_events: bool,
+    /// The upper limit of how far in the future a signed query or event subscription may expire.
+    ///
+    /// Signed queries and subscriptions are remembered until they expire,
+    /// so that they can't be replayed.
+    #[config(default = "defaults::torii::QUERY_MAX_TTL.into()")]
+    pub query_max_ttl_ms: DurationMs,
+    /// Reject signed queries without an expiry time, which can't be protected from replays.
+    #[config(default = "defaults::torii::REQUIRE_QUERY_EXPIRY")]
+    pub require_query_expiry: bool,
+    /// The upper limit of how far in the future a read token may expire.
+    #[config(default = "defaults::torii::READ_TOKEN_MAX_TTL.into()")]
+    pub read_token_max_ttl_ms: DurationMs,
+    /// Serve the API over TLS instead of plain HTTP.
+    pub tls: Option<WithOrigin<ToriiTls>>,
+    /// Additionally serve the API on a Unix domain socket.
+    pub unix_socket: Option<WithOrigin<ToriiUnixSocket>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToriiTls {
+    certificates: Vec<ToriiTlsCertificate>,
+    #[serde(default)]
+    reload_interval_ms: ToriiTlsReloadInterval,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToriiTlsCertificate {
+    #[serde(default)]
+    server_name: Option<String>,
+    cert_file: PathBuf,
+    key_file: PathBuf,
+}
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+struct ToriiTlsReloadInterval(DurationMs);
+
+impl Default for ToriiTlsReloadInterval {
+    fn default() -> Self {
+        Self(DurationMs(defaults::torii::TLS_RELOAD_INTERVAL))
+    }
+}
+
+impl From<WithOrigin<ToriiTls>> for actual::ToriiTls {
+    fn from(tls: WithOrigin<ToriiTls>) -> Self {
+        // Paths are relative to the file the TLS section is set in
+        let (tls, origin) = tls.into_tuple();
+        let resolve = |path: PathBuf| WithOrigin::new(path, origin.clone()).resolve_relative_path();
+
+        Self {
+            certificates: tls
+                .certificates
+                .into_iter()
+                .map(|certificate| actual::ToriiTlsCertificate {
+                    server_name: certificate.server_name,
+                    cert_file: resolve(certificate.cert_file),
+                    key_file: resolve(certificate.key_file),
+                })
+                .collect(),
+            reload_interval: tls.reload_interval_ms.0.get(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToriiUnixSocket {
+    path: PathBuf,
+    #[serde(default)]
+    mode: FileMode,
+}
+
+/// Octal permissions of a file, e.g. `"660"`
+#[derive(Deserialize, Debug, Copy, Clone)]
+#[serde(try_from = "String")]
+struct FileMode(u32);
+
+impl Default for FileMode {
+    fn default() -> Self {
+        Self(defaults::torii::UNIX_SOCKET_MODE)
+    }
+}
+
+impl TryFrom<String> for FileMode {
+    type Error = String;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        u32::from_str_radix(&value, 8)
+            .ok()
+            .filter(|mode| *mode <= 0o777)
+            .map(Self)
+            .ok_or_else(|| format!("expected octal permissions, e.g. \"660\", got `{value}`"))
+    }
+}
+
+impl From<WithOrigin<ToriiUnixSocket>> for actual::ToriiUnixSocket {
+    fn from(unix_socket: WithOrigin<ToriiUnixSocket>) -> Self {
+        let (unix_socket, origin) = unix_socket.into_tuple();
+
+        Self {
+            path: WithOrigin::new(unix_socket.path, origin).resolve_relative_path(),
+            mode: unix_socket.mode.0,
+        }
+    }
 }
 
 impl Torii {
@@ -488,6 +812,14 @@ impl Torii {
         let torii = actual::Torii {
             address: self.address,
             max_content_len: self.max_content_len,
+            query_threads: self.query_threads,
+            ingress_validation: self.ingress_validation,
+            restrict_account_events: self.restrict_account_events,
+            query_max_ttl: self.query_max_ttl_ms.get(),
+            require_query_expiry: self.require_query_expiry,
+            read_token_max_ttl: self.read_token_max_ttl_ms.get(),
+            tls: self.tls.map(actual::ToriiTls::from),
+            unix_socket: self.unix_socket.map(actual::ToriiUnixSocket::from),
         };
 
         let query = actual::LiveQueryStore {
@@ -0,0 +1,263 @@
+//! External sources of secrets referenced from the configuration.
+//!
+//! Instead of putting a secret inline into the main TOML file, the configuration may point to
+//! the place the secret is stored in, e.g. `private_key_source = "file:./peer.key"`.
+
+use std::{
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use error_stack::{Report, Result, ResultExt};
+use iroha_config_base::WithOrigin;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// Environment variable with the address of the Vault server
+pub const VAULT_ADDR_ENV: &str = "VAULT_ADDR";
+/// Environment variable with the token used to authenticate in Vault
+pub const VAULT_TOKEN_ENV: &str = "VAULT_TOKEN";
+
+const VAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Place a secret is read from
+#[derive(Debug, Clone, PartialEq, Eq, DeserializeFromStr, SerializeDisplay)]
+pub enum SecretSource {
+    /// `file:<path>`, the whole file content except surrounding whitespace.
+    ///
+    /// The file must not be accessible by group or others.
+    File(PathBuf),
+    /// `env:<name>`, value of the environment variable
+    Env(String),
+    /// `vault:<path>#<field>`, field of the secret stored in the Vault KV engine.
+    ///
+    /// The server is taken from [`VAULT_ADDR_ENV`] and the token from [`VAULT_TOKEN_ENV`].
+    Vault {
+        /// Path of the secret, including the mount, e.g. `secret/data/iroha`
+        path: String,
+        /// Field of the secret
+        field: String,
+    },
+}
+
+/// Error of parsing [`SecretSource`]
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum ParseSecretSourceError {
+    #[error("expected `<kind>:<reference>`, where kind is one of: file, env, vault")]
+    MissingKind,
+    #[error("unknown kind of the secret source: `{0}`")]
+    UnknownKind(String),
+    #[error("reference to the secret is empty")]
+    EmptyReference,
+    #[error("vault reference should be `<path>#<field>`")]
+    MissingVaultField,
+}
+
+impl FromStr for SecretSource {
+    type Err = ParseSecretSourceError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (kind, reference) = s
+            .split_once(':')
+            .ok_or(ParseSecretSourceError::MissingKind)?;
+        if reference.is_empty() {
+            return Err(ParseSecretSourceError::EmptyReference);
+        }
+
+        match kind {
+            "file" => Ok(Self::File(PathBuf::from(reference))),
+            "env" => Ok(Self::Env(reference.to_owned())),
+            "vault" => {
+                let (path, field) = reference
+                    .split_once('#')
+                    .filter(|(path, field)| !path.is_empty() && !field.is_empty())
+                    .ok_or(ParseSecretSourceError::MissingVaultField)?;
+                Ok(Self::Vault {
+                    path: path.trim_matches('/').to_owned(),
+                    field: field.to_owned(),
+                })
+            }
+            other => Err(ParseSecretSourceError::UnknownKind(other.to_owned())),
+        }
+    }
+}
+
+impl Display for SecretSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "file:{}", path.display()),
+            Self::Env(name) => write!(f, "env:{name}"),
+            Self::Vault { path, field } => write!(f, "vault:{path}#{field}"),
+        }
+    }
+}
+
+/// Error of reading a secret from its [`SecretSource`]
+#[derive(Debug, thiserror::Error, Copy, Clone)]
+#[allow(missing_docs)]
+pub enum ReadSecretError {
+    #[error("Failed to read the secret file")]
+    File,
+    #[error("Secret file is accessible by group or others")]
+    InsecurePermissions,
+    #[error("Environment variable with the secret is not set")]
+    Env,
+    #[error("Failed to fetch the secret from Vault")]
+    Vault,
+    #[error("Secret doesn't have the requested field")]
+    MissingField,
+}
+
+/// Read the secret.
+///
+/// Path of a [`SecretSource::File`] is resolved relative to the file the source is set in.
+///
+/// # Errors
+/// - If the secret can't be read from the source
+/// - If the secret file permissions are too open
+pub fn read_secret(source: &WithOrigin<SecretSource>) -> Result<String, ReadSecretError> {
+    let secret = match source.value() {
+        SecretSource::File(path) => {
+            let path =
+                WithOrigin::new(path.clone(), source.origin().clone()).resolve_relative_path();
+            read_file(&path)?
+        }
+        SecretSource::Env(name) => std::env::var(name)
+            .change_context(ReadSecretError::Env)
+            .attach_printable_lazy(|| format!("variable: {name}"))?,
+        SecretSource::Vault { path, field } => read_vault(path, field)?,
+    };
+
+    Ok(secret.trim().to_owned())
+}
+
+fn read_file(path: &Path) -> Result<String, ReadSecretError> {
+    check_permissions(path)?;
+    std::fs::read_to_string(path)
+        .change_context(ReadSecretError::File)
+        .attach_printable_lazy(|| format!("path: {}", path.display()))
+}
+
+#[cfg(unix)]
+fn check_permissions(path: &Path) -> Result<(), ReadSecretError> {
+    use std::os::unix::fs::PermissionsExt as _;
+
+    let mode = std::fs::metadata(path)
+        .change_context(ReadSecretError::File)
+        .attach_printable_lazy(|| format!("path: {}", path.display()))?
+        .permissions()
+        .mode();
+    if mode & 0o077 != 0 {
+        return Err(
+            Report::new(ReadSecretError::InsecurePermissions).attach_printable(format!(
+                "mode of {} is {:o}, expected no access for group and others (e.g. 600)",
+                path.display(),
+                mode & 0o777
+            )),
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path) -> Result<(), ReadSecretError> {
+    Ok(())
+}
+
+fn read_vault(path: &str, field: &str) -> Result<String, ReadSecretError> {
+    let addr = std::env::var(VAULT_ADDR_ENV)
+        .change_context(ReadSecretError::Vault)
+        .attach_printable_lazy(|| format!("{VAULT_ADDR_ENV} is not set"))?;
+    let token = std::env::var(VAULT_TOKEN_ENV)
+        .change_context(ReadSecretError::Vault)
+        .attach_printable_lazy(|| format!("{VAULT_TOKEN_ENV} is not set"))?;
+
+    let response = attohttpc::get(format!("{}/v1/{path}", addr.trim_end_matches('/')))
+        .timeout(VAULT_REQUEST_TIMEOUT)
+        .header("X-Vault-Token", token)
+        .send()
+        .change_context(ReadSecretError::Vault)?;
+    if !response.is_success() {
+        return Err(Report::new(ReadSecretError::Vault)
+            .attach_printable(format!("response status: {}", response.status())));
+    }
+    let body: serde_json::Value = response
+        .text()
+        .change_context(ReadSecretError::Vault)
+        .and_then(|text| serde_json::from_str(&text).change_context(ReadSecretError::Vault))?;
+
+    // KV version 2 nests the secret into one more `data` object
+    let data = &body["data"];
+    let data = if data["data"].is_object() {
+        &data["data"]
+    } else {
+        data
+    };
+    data[field].as_str().map(ToOwned::to_owned).ok_or_else(|| {
+        Report::new(ReadSecretError::MissingField).attach_printable(format!("field: {field}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_config_base::ParameterOrigin;
+
+    use super::*;
+
+    #[test]
+    fn parse_sources() {
+        assert_eq!(
+            "file:./peer.key".parse::<SecretSource>().unwrap(),
+            SecretSource::File("./peer.key".into())
+        );
+        assert_eq!(
+            "env:PEER_KEY".parse::<SecretSource>().unwrap(),
+            SecretSource::Env("PEER_KEY".to_owned())
+        );
+        assert_eq!(
+            "vault:/secret/data/iroha/#private_key"
+                .parse::<SecretSource>()
+                .unwrap(),
+            SecretSource::Vault {
+                path: "secret/data/iroha".to_owned(),
+                field: "private_key".to_owned()
+            }
+        );
+        assert!(matches!(
+            "vault:secret/data/iroha".parse::<SecretSource>(),
+            Err(ParseSecretSourceError::MissingVaultField)
+        ));
+        assert!(matches!(
+            "8026208F4C15".parse::<SecretSource>(),
+            Err(ParseSecretSourceError::MissingKind)
+        ));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn file_permissions_are_checked() {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let dir = tempfile::tempdir().unwrap();
+        let key = dir.path().join("peer.key");
+        std::fs::write(&key, "secret\n").unwrap();
+        let config = dir.path().join("config.toml");
+        let source = WithOrigin::new(
+            SecretSource::File("peer.key".into()),
+            ParameterOrigin::file(["private_key_source"].into(), config),
+        );
+
+        std::fs::set_permissions(&key, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let report = read_secret(&source).unwrap_err();
+        assert!(matches!(
+            report.current_context(),
+            ReadSecretError::InsecurePermissions
+        ));
+
+        std::fs::set_permissions(&key, std::fs::Permissions::from_mode(0o600)).unwrap();
+        assert_eq!(read_secret(&source).unwrap(), "secret");
+    }
+}
@@ -106,6 +106,7 @@ fn minimal_config_snapshot() {
                         path: "tests/fixtures/base_trusted_peers.toml",
                     },
                 },
+                redaction_secret: None,
             },
             network: Network {
                 address: WithOrigin {
@@ -123,6 +124,8 @@ fn minimal_config_snapshot() {
                     },
                 },
                 idle_timeout: 60s,
+                dns_refresh_interval: 30s,
+                peer_bandwidth_limit: None,
             },
             genesis: Genesis {
                 public_key: PublicKey(
@@ -143,6 +146,14 @@ fn minimal_config_snapshot() {
                 max_content_len: Bytes(
                     16777216,
                 ),
+                query_threads: 4,
+                ingress_validation: false,
+                restrict_account_events: false,
+                query_max_ttl: 300s,
+                require_query_expiry: false,
+                read_token_max_ttl: 86400s,
+                tls: None,
+                unix_socket: None,
             },
             kura: Kura {
                 init_mode: Strict,
@@ -180,18 +191,39 @@ fn minimal_config_snapshot() {
                 capacity: 65536,
                 capacity_per_user: 65536,
                 transaction_time_to_live: 86400s,
+                replay_window: 86400s,
             },
             snapshot: Snapshot {
                 mode: ReadWrite,
                 create_every_ms: DurationMs(
                     600s,
                 ),
+                create_every_blocks: 0,
                 store_dir: WithOrigin {
                     value: "./storage/snapshot",
                     origin: Default {
                         id: ParameterId(snapshot.store_dir),
                     },
                 },
+                write_rate_limit: Bytes(
+                    67108864,
+                ),
+            },
+            memory: Memory {
+                sample_every_ms: DurationMs(
+                    10s,
+                ),
+                queue_limit: None,
+                live_queries_limit: None,
+                events_limit: None,
+            },
+            backup: Backup {
+                store_dir: None,
+                create_every_ms: DurationMs(
+                    86400s,
+                ),
+                keep: 7,
+                s3: None,
             },
             telemetry: None,
             dev_telemetry: DevTelemetry {
@@ -240,6 +272,17 @@ fn extra_fields() {
     assert_contains!(format!("{error:?}"), "unknown parameter: `foo`");
 }
 
+#[test]
+fn ambiguous_private_key() {
+    let error = load_config_from_fixtures("bad.ambiguous_private_key.toml")
+        .expect_err("should fail with both inline private key and its source");
+
+    assert_contains!(
+        format!("{error:?}"),
+        "Only one of `private_key` and `private_key_source` can be set"
+    );
+}
+
 /// Aims the purpose of checking that every single provided env variable is consumed and parsed
 /// into a valid config.
 #[test]
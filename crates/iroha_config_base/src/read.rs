@@ -133,6 +133,11 @@ impl ConfigReader {
         self
     }
 
+    /// Paths of the file sources added so far, in the order they are applied
+    pub fn source_paths(&self) -> impl Iterator<Item = &Path> {
+        self.sources.iter().map(|source| source.path().as_path())
+    }
+
     /// Reads a TOML file and handles its `extends` field, implementing mixins mechanism.
     ///
     /// # Errors
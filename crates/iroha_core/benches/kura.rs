@@ -13,7 +13,7 @@ use iroha_core::{
     state::{State, World},
     sumeragi::network_topology::Topology,
 };
-use iroha_crypto::KeyPair;
+use iroha_crypto::{KeyPair, MerkleMountainRange};
 use iroha_data_model::prelude::*;
 use iroha_futures::supervisor::ShutdownSignal;
 use iroha_test_samples::gen_account_in;
@@ -59,7 +59,7 @@ async fn measure_block_size_for_n_executors(n_executors: u32) {
     let topology = Topology::new(vec![peer_id]);
     let mut block = {
         let unverified_block = BlockBuilder::new(vec![tx])
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(0, &state.view())
             .sign(peer_key_pair.private_key())
             .unpack(|_| {});
 
@@ -95,5 +95,52 @@ fn measure_block_size(_criterion: &mut Criterion) {
     Runtime::new().unwrap().block_on(measure_block_size_async());
 }
 
-criterion_group!(kura, measure_block_size);
+fn kura_init_strict(criterion: &mut Criterion) {
+    const BLOCKS: usize = 100;
+    const TRANSACTIONS_PER_BLOCK: usize = 100;
+
+    let dir = tempfile::tempdir().expect("Could not create tempfile.");
+    let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+    let (alice_id, alice_keypair) = gen_account_in("test");
+    let (bob_id, _bob_keypair) = gen_account_in("test");
+    let xor_id = "xor#test".parse().expect("tested");
+    let alice_xor_id = AssetId::new(xor_id, alice_id.clone());
+    let peer_key_pair = KeyPair::random();
+
+    let mut block_store = BlockStore::new(dir.path());
+    block_store.create_files_if_they_do_not_exist().unwrap();
+    let mut prev_block: Option<SignedBlock> = None;
+    let mut prev_blocks = MerkleMountainRange::default();
+    for _ in 0..BLOCKS {
+        let transactions = (0..TRANSACTIONS_PER_BLOCK)
+            .map(|_| {
+                let transfer = Transfer::asset_numeric(alice_xor_id.clone(), 10u32, bob_id.clone());
+                let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                    .with_instructions([transfer])
+                    .sign(alice_keypair.private_key());
+                AcceptedTransaction::new_unchecked(tx)
+            })
+            .collect();
+        let block: SignedBlock = BlockBuilder::new(transactions)
+            .chain_to(0, prev_block.as_ref(), prev_blocks.root())
+            .sign(peer_key_pair.private_key())
+            .unpack(|_| {})
+            .into();
+        block_store.append_block_to_chain(&block).unwrap();
+        prev_blocks.add(block.hash());
+        prev_block = Some(block);
+    }
+
+    let cfg = Config {
+        init_mode: iroha_config::kura::InitMode::Strict,
+        debug_output_new_blocks: false,
+        blocks_in_memory: BLOCKS_IN_MEMORY,
+        store_dir: WithOrigin::inline(dir.path().to_path_buf()),
+    };
+    criterion.bench_function("kura_init_strict", |b| {
+        b.iter(|| iroha_core::kura::Kura::new(&cfg).unwrap());
+    });
+}
+
+criterion_group!(kura, measure_block_size, kura_init_strict);
 criterion_main!(kura);
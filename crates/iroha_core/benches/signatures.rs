@@ -0,0 +1,52 @@
+#![allow(missing_docs)]
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use iroha_core::tx::{hash_in_parallel, verify_signatures_in_parallel};
+use iroha_data_model::{isi::InstructionBox, prelude::*, Level};
+use iroha_test_samples::gen_account_in;
+
+const TRANSACTIONS: usize = 4096;
+
+fn build_transactions() -> Vec<SignedTransaction> {
+    let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+    let domain_id: DomainId = "wonderland".parse().unwrap();
+
+    (0..TRANSACTIONS)
+        .map(|_| {
+            let (account_id, key_pair) = gen_account_in(&domain_id);
+            let instruction = Log::new(Level::INFO, account_id.to_string());
+            TransactionBuilder::new(chain_id.clone(), account_id)
+                .with_instructions::<InstructionBox>([instruction.into()])
+                .sign(key_pair.private_key())
+        })
+        .collect()
+}
+
+fn block_transactions(c: &mut Criterion) {
+    let transactions = build_transactions();
+    let refs = transactions.iter().collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("block_transactions");
+    group.sample_size(10);
+    group.bench_function("hash_sequential", |b| {
+        b.iter(|| {
+            transactions
+                .iter()
+                .map(SignedTransaction::hash)
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("hash_parallel", |b| {
+        b.iter(|| hash_in_parallel(&transactions));
+    });
+    group.bench_function("verify_signatures_sequential", |b| {
+        b.iter(|| SignedTransaction::verify_signatures(refs.iter().copied()).unwrap());
+    });
+    group.bench_function("verify_signatures_parallel", |b| {
+        b.iter(|| verify_signatures_in_parallel(&refs, |_| false).unwrap());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, block_transactions);
+criterion_main!(benches);
@@ -8,11 +8,14 @@ use iroha_core::{
     query::store::LiveQueryStore,
     smartcontracts::{isi::Registrable as _, wasm::cache::WasmCache, Execute},
     state::{State, World},
+    sumeragi::network_topology::Topology,
 };
 use iroha_data_model::{
-    account::AccountId, isi::InstructionBox, prelude::*, transaction::TransactionBuilder,
+    account::AccountId, block::SignedBlock, isi::InstructionBox, prelude::*,
+    transaction::TransactionBuilder,
 };
 use iroha_test_samples::gen_account_in;
+use iroha_version::scale::{DecodeVersioned as _, EncodeVersioned as _};
 
 static STARTER_DOMAIN: LazyLock<DomainId> = LazyLock::new(|| "start".parse().unwrap());
 static STARTER_KEYPAIR: LazyLock<KeyPair> = LazyLock::new(KeyPair::random);
@@ -34,14 +37,19 @@ fn build_test_transaction(chain_id: ChainId) -> TransactionBuilder {
 }
 
 fn build_test_and_transient_state() -> State {
+    let (account_id, key_pair) = gen_account_in(&*STARTER_DOMAIN);
+    build_state_with_executor(&account_id, &key_pair)
+}
+
+/// State with the account of `key_pair` and the default executor, after a single block.
+fn build_state_with_executor(account_id: &AccountId, key_pair: &KeyPair) -> State {
     let kura = iroha_core::kura::Kura::blank_kura_for_testing();
     let query_handle = LiveQueryStore::start_test();
-    let (account_id, key_pair) = gen_account_in(&*STARTER_DOMAIN);
 
     let state = State::new(
         {
-            let domain = Domain::new(STARTER_DOMAIN.clone()).build(&account_id);
-            let account = Account::new(account_id.clone()).build(&account_id);
+            let domain = Domain::new(STARTER_DOMAIN.clone()).build(account_id);
+            let account = Account::new(account_id.clone()).build(account_id);
             World::with([domain], [account], [])
         },
         kura,
@@ -60,17 +68,17 @@ fn build_test_and_transient_state() -> State {
             let params = state_view.world.parameters();
             (params.sumeragi().max_clock_drift(), params.transaction)
         };
-        let unverified_block = BlockBuilder::new(vec![AcceptedTransaction::accept(
+        let block = BlockBuilder::new(vec![AcceptedTransaction::accept(
             transaction,
             &chain_id,
             max_clock_drift,
             tx_limits,
         )
         .unwrap()])
-        .chain(0, state.view().latest_block().as_deref())
+        .chain(0, &state.view())
         .sign(key_pair.private_key())
         .unpack(|_| {});
-        let mut state_block = state.block(unverified_block.header());
+        let mut state_block = state.block(block.header());
         let mut state_transaction = state_block.transaction();
         let path_to_executor = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
             .join("../../defaults/executor.wasm");
@@ -83,6 +91,8 @@ fn build_test_and_transient_state() -> State {
             .expect("Failed to load executor");
         state_transaction.apply();
         state_block.commit();
+        // Following blocks are chained to it
+        state.kura().store_block(SignedBlock::from(block));
     }
 
     state
@@ -155,7 +165,7 @@ fn validate_transaction(criterion: &mut Criterion) {
         tx_limits,
     )
     .unwrap()])
-    .chain(0, state.view().latest_block().as_deref())
+    .chain(0, &state.view())
     .sign(key_pair.private_key())
     .unpack(|_| {});
     let transaction = AcceptedTransaction::accept(
@@ -184,6 +194,57 @@ fn validate_transaction(criterion: &mut Criterion) {
     println!("Success count: {success_count}, Failure count: {failure_count}");
 }
 
+/// Decode a block received from a peer and validate it, executing its transactions
+fn ingest_block(criterion: &mut Criterion) {
+    const TRANSACTIONS: u32 = 100;
+
+    let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+    let state = build_state_with_executor(&STARTER_ID, &STARTER_KEYPAIR);
+    let (max_clock_drift, tx_limits) = {
+        let state_view = state.view();
+        let params = state_view.world.parameters();
+        (params.sumeragi().max_clock_drift(), params.transaction)
+    };
+
+    let transactions = (0..TRANSACTIONS)
+        .map(|i| {
+            let set_key_value =
+                SetKeyValue::account(STARTER_ID.clone(), format!("key{i}").parse().unwrap(), i);
+            let transaction = TransactionBuilder::new(chain_id.clone(), STARTER_ID.clone())
+                .with_instructions([set_key_value])
+                .sign(STARTER_KEYPAIR.private_key());
+            AcceptedTransaction::accept(transaction, &chain_id, max_clock_drift, tx_limits)
+                .expect("Failed to accept transaction.")
+        })
+        .collect();
+    let (peer_public_key, peer_private_key) = KeyPair::random().into_parts();
+    let topology = Topology::new([PeerId::new(peer_public_key)]);
+    let block: SignedBlock = BlockBuilder::new(transactions)
+        .chain(0, &state.view())
+        .sign(&peer_private_key)
+        .unpack(|_| {})
+        .into();
+    let bytes = block.encode_versioned();
+    let (genesis_account, _) = gen_account_in("genesis");
+
+    let _ = criterion.bench_function("ingest_block", |b| {
+        b.iter(|| {
+            let block = SignedBlock::decode_all_versioned(&bytes).expect("Failed to decode block.");
+            // Not committed, so that every iteration validates against the same state
+            let mut state_block = state.block(block.header());
+            let valid_block = ValidBlock::validate(
+                block,
+                &topology,
+                &chain_id,
+                &genesis_account,
+                &mut state_block,
+            )
+            .unpack(|_| {});
+            assert!(valid_block.is_ok(), "Block should be valid");
+        });
+    });
+}
+
 fn sign_blocks(criterion: &mut Criterion) {
     let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
     let kura = iroha_core::kura::Kura::blank_kura_for_testing();
@@ -212,8 +273,7 @@ fn sign_blocks(criterion: &mut Criterion) {
 
     let mut count = 0;
 
-    let block =
-        BlockBuilder::new(vec![transaction]).chain(0, state.view().latest_block().as_deref());
+    let block = BlockBuilder::new(vec![transaction]).chain(0, &state.view());
 
     let _ = criterion.bench_function("sign_block", |b| {
         b.iter_batched(
@@ -234,5 +294,5 @@ criterion_group!(
     sign_transaction,
     validate_transaction
 );
-criterion_group!(blocks, sign_blocks);
+criterion_group!(blocks, sign_blocks, ingest_block);
 criterion_main!(transactions, blocks);
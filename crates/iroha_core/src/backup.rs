@@ -0,0 +1,677 @@
+//! This module contains [`BackupMaker`] actor which periodically backs up the block store
+//! and the [`State`] snapshot, together with functions to verify and restore such backups.
+//!
+//! Each backup is a directory with the following layout:
+//!
+//! ```text
+//! backup-<unix time in ms>/
+//! ├── blocks/
+//! │   ├── 00000000000000000001/  # block store with the blocks from height 1
+//! │   └── 00000000000000000042/  # block store with the blocks committed since the previous backup
+//! ├── snapshot/                  # snapshot of the state at the time of backup
+//! └── manifest.json              # sizes and hashes of the files above, see [`Manifest`]
+//! ```
+//!
+//! Backups are incremental: block store segments of the previous backup are hard linked
+//! into the new one, and only the blocks committed since are written to a new segment.
+//! Backups can also be copied to an S3-compatible object store, see [`s3`].
+
+pub mod s3;
+
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use iroha_config::parameters::actual::Backup as Config;
+use iroha_crypto::{Hash, HashOf};
+use iroha_data_model::block::BlockHeader;
+use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
+use iroha_logger::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    kura::{self, BlockStore, Kura},
+    snapshot::{self, TryWriteError},
+    state::{State, StateReadOnly},
+};
+
+/// Prefix of the names of backup directories.
+const BACKUP_DIR_PREFIX: &str = "backup-";
+/// Suffix of backup directories which are still being written.
+const BACKUP_TMP_DIR_SUFFIX: &str = ".tmp";
+/// Name of the manifest file inside of a backup directory.
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+/// Directory with the block store inside of a backup directory.
+const BLOCKS_DIR_NAME: &str = "blocks";
+/// Directory with the state snapshot inside of a backup directory.
+const SNAPSHOT_DIR_NAME: &str = "snapshot";
+
+/// Errors produced while creating, verifying or restoring backups.
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+pub enum Error {
+    /// Failed reading/writing {1:?} from disk
+    IO(#[source] std::io::Error, PathBuf),
+    /// Failed to copy the block store
+    Kura(#[from] kura::Error),
+    /// Failed to write the state snapshot
+    Snapshot(#[from] TryWriteError),
+    /// Error (de)serializing the backup manifest
+    Manifest(#[from] serde_json::Error),
+    /// Block at height {0} is missing from the block store
+    MissingBlock(NonZeroUsize),
+    /// File {0:?} doesn't match the backup manifest
+    Corrupted(PathBuf),
+    /// No backups found in {0:?}
+    NotFound(PathBuf),
+    /// Refusing to overwrite non-empty file {0:?}
+    AlreadyExists(PathBuf),
+    /// Request to the object store failed
+    S3Request(#[source] attohttpc::Error),
+    /// Object store responded with status {0} to the request to {1}
+    S3Status(u16, String),
+    /// Endpoint {0} of the object store has no host
+    S3Endpoint(String),
+}
+
+/// Result of backup operations.
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Description of the contents of a backup, used to check its integrity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Unix time in milliseconds at which the backup was created
+    pub created_at_ms: u64,
+    /// Number of blocks in the backed up block store
+    pub block_count: usize,
+    /// Hash of the latest backed up block
+    pub latest_block: Option<HashOf<BlockHeader>>,
+    /// Backed up files, by path relative to the backup directory
+    pub files: BTreeMap<String, FileDigest>,
+}
+
+/// Size and hash of a backed up file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDigest {
+    /// Size of the file in bytes
+    pub size: u64,
+    /// Hash of the contents of the file
+    pub hash: Hash,
+}
+
+/// Actor responsible for periodic backups of the block store and [`State`] snapshot.
+pub struct BackupMaker {
+    state: Arc<State>,
+    kura: Arc<Kura>,
+    /// Frequency at which backups are made
+    create_every: Duration,
+    /// Path to the directory where backups are stored
+    store_dir: PathBuf,
+    /// Number of the most recent backups to keep
+    keep: NonZeroUsize,
+    /// Bucket backups are copied to
+    bucket: Option<s3::Bucket>,
+    /// Hash of the latest block in the latest backup
+    latest_block_hash: Option<HashOf<BlockHeader>>,
+}
+
+impl BackupMaker {
+    /// Create from [`Config`].
+    ///
+    /// Returns [`None`] if backups are disabled.
+    pub fn from_config(config: &Config, state: Arc<State>, kura: Arc<Kura>) -> Option<Self> {
+        let store_dir = config.store_dir.as_ref()?.resolve_relative_path();
+        Some(Self {
+            state,
+            kura,
+            create_every: config.create_every_ms.get(),
+            store_dir,
+            keep: config.keep,
+            bucket: config.s3.clone().map(s3::Bucket::new),
+            latest_block_hash: None,
+        })
+    }
+
+    /// Start the actor.
+    pub fn start(self, shutdown_signal: ShutdownSignal) -> Child {
+        Child::new(tokio::spawn(self.run(shutdown_signal)), OnShutdown::Abort)
+    }
+
+    async fn run(mut self, shutdown_signal: ShutdownSignal) {
+        let mut create_every = tokio::time::interval(self.create_every);
+        create_every.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // First tick completes immediately, the first backup is made one period after start
+        create_every.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = create_every.tick() => {
+                    self.create_backup().await;
+                },
+                () = shutdown_signal.receive() => {
+                    debug!("Shutting down backup maker");
+                    break;
+                }
+            }
+        }
+    }
+
+    async fn create_backup(&mut self) {
+        let latest_block_hash = self.state.view().latest_block_hash();
+        if latest_block_hash == self.latest_block_hash {
+            return;
+        }
+
+        let state = Arc::clone(&self.state);
+        let kura = Arc::clone(&self.kura);
+        let store_dir = self.store_dir.clone();
+        let keep = self.keep;
+        let bucket = self.bucket.clone();
+        let handle = tokio::task::spawn_blocking(move || -> Result<(PathBuf, Duration)> {
+            let started_at = Instant::now();
+            let path = create_backup(&state, &kura, &store_dir)?;
+            prune_backups(&store_dir, keep)?;
+            if let Some(bucket) = bucket {
+                bucket.upload_backup(&path)?;
+                bucket.prune_backups(keep)?;
+            }
+            Ok((path, started_at.elapsed()))
+        });
+
+        match handle.await {
+            Ok(Ok((path, elapsed))) => {
+                info!(
+                    path = %path.display(),
+                    elapsed_ms = elapsed.as_millis(),
+                    "Successfully created a backup"
+                );
+                self.latest_block_hash = latest_block_hash;
+            }
+            Ok(Err(error)) => {
+                error!(%error, "Failed to create a backup");
+            }
+            Err(panic) => {
+                error!(%panic, "Task panicked during creation of a backup");
+            }
+        }
+    }
+}
+
+/// Back up the block store and a fresh snapshot of `state` into a new directory in `store_dir`.
+///
+/// The snapshot is written first, so the backed up block store always reaches its height.
+/// Block store segments of the most recent backup are reused, unless its latest block
+/// was replaced since, and only the blocks committed after it are written.
+/// The backup directory only appears under its final name once it is complete.
+///
+/// Returns path to the created backup.
+///
+/// # Errors
+/// - IO errors
+/// - If a committed block can't be read from [`Kura`]
+pub fn create_backup(state: &State, kura: &Kura, store_dir: &Path) -> Result<PathBuf> {
+    let created_at_ms = u64::try_from(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Failed to get the current system time")
+            .as_millis(),
+    )
+    .expect("Unix time in milliseconds overflows u64");
+    // Zero-padded so that backups sort by their names
+    let name = format!("{BACKUP_DIR_PREFIX}{created_at_ms:020}");
+    let path = store_dir.join(&name);
+    let tmp_path = store_dir.join(format!("{name}{BACKUP_TMP_DIR_SUFFIX}"));
+
+    snapshot::try_write_snapshot(state, tmp_path.join(SNAPSHOT_DIR_NAME), None)?;
+    let mut files = BTreeMap::new();
+    digest_dir(&tmp_path, SNAPSHOT_DIR_NAME, &mut files)?;
+
+    let blocks_path = tmp_path.join(BLOCKS_DIR_NAME);
+    std::fs::create_dir_all(&blocks_path).map_err(|err| Error::IO(err, blocks_path))?;
+    let mut first_new_height = 1;
+    let mut latest_block = None;
+    if let Some((base_path, base)) = base_backup(store_dir, kura) {
+        let segment_prefix = format!("{BLOCKS_DIR_NAME}/");
+        let segment_files = base
+            .files
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(&segment_prefix));
+        for (name, digest) in segment_files {
+            link_or_copy(&base_path.join(&name), &tmp_path.join(&name))?;
+            files.insert(name, digest);
+        }
+        first_new_height = base.block_count + 1;
+        latest_block = base.latest_block;
+    }
+
+    let block_count = kura.blocks_count();
+    if first_new_height <= block_count {
+        let segment = format!("{BLOCKS_DIR_NAME}/{first_new_height:020}");
+        let mut block_store = BlockStore::new(tmp_path.join(&segment));
+        block_store.create_files_if_they_do_not_exist()?;
+        for height in first_new_height..=block_count {
+            let height = NonZeroUsize::new(height).expect("iterating from 1");
+            let block = kura.get_block(height).ok_or(Error::MissingBlock(height))?;
+            block_store.append_block_to_chain(&block)?;
+            latest_block = Some(block.hash());
+        }
+        digest_dir(&tmp_path, &segment, &mut files)?;
+    }
+
+    let manifest = Manifest {
+        created_at_ms,
+        block_count,
+        latest_block,
+        files,
+    };
+    let manifest_path = tmp_path.join(MANIFEST_FILE_NAME);
+    let bytes = serde_json::to_vec_pretty(&manifest)?;
+    std::fs::write(&manifest_path, bytes).map_err(|err| Error::IO(err, manifest_path))?;
+
+    std::fs::rename(&tmp_path, &path).map_err(|err| Error::IO(err, path.clone()))?;
+    Ok(path)
+}
+
+/// Remove all but the `keep` most recent backups in `store_dir`.
+///
+/// # Errors
+/// IO errors
+pub fn prune_backups(store_dir: &Path, keep: NonZeroUsize) -> Result<()> {
+    let backups = list_backups(store_dir)?;
+    let outdated = backups.len().saturating_sub(keep.get());
+    for path in &backups[..outdated] {
+        std::fs::remove_dir_all(path).map_err(|err| Error::IO(err, path.clone()))?;
+        debug!(path = %path.display(), "Removed outdated backup");
+    }
+    Ok(())
+}
+
+/// Path to the most recent backup in `store_dir`.
+///
+/// # Errors
+/// - IO errors
+/// - If there are no backups in `store_dir`
+pub fn latest_backup(store_dir: &Path) -> Result<PathBuf> {
+    list_backups(store_dir)?
+        .pop()
+        .ok_or_else(|| Error::NotFound(store_dir.to_path_buf()))
+}
+
+/// Check that files of the backup at `path` match its manifest.
+///
+/// # Errors
+/// - IO errors
+/// - If the manifest can't be read
+/// - If any file is missing, unexpected or doesn't match its digest
+/// - If the block store segments don't hold `block_count` blocks from height 1 onward
+pub fn verify_backup(path: &Path) -> Result<Manifest> {
+    let manifest = read_manifest(path)?;
+
+    let mut files = BTreeMap::new();
+    for dir in [BLOCKS_DIR_NAME, SNAPSHOT_DIR_NAME] {
+        digest_dir(path, dir, &mut files)?;
+    }
+    for (name, digest) in &manifest.files {
+        if files.get(name) != Some(digest) {
+            return Err(Error::Corrupted(path.join(name)));
+        }
+    }
+    if let Some(name) = files
+        .keys()
+        .find(|name| !manifest.files.contains_key(*name))
+    {
+        return Err(Error::Corrupted(path.join(name)));
+    }
+
+    let mut next_height = 1;
+    for (first_height, segment_path) in list_segments(path)? {
+        if first_height != next_height {
+            return Err(Error::Corrupted(segment_path));
+        }
+        let count = BlockStore::new(&segment_path).read_index_count()?;
+        next_height += usize::try_from(count).map_err(|_| Error::Corrupted(segment_path))?;
+    }
+    if next_height != manifest.block_count + 1 {
+        return Err(Error::Corrupted(path.join(BLOCKS_DIR_NAME)));
+    }
+
+    Ok(manifest)
+}
+
+/// Verify the backup at `path`, assemble its block store segments in `kura_dir`
+/// and copy its snapshot to `snapshot_dir`.
+///
+/// Unless `force` is set, files which already exist and are not empty are not overwritten.
+///
+/// # Errors
+/// - If the backup fails verification, see [`verify_backup`]
+/// - If a destination file exists and `force` isn't set
+/// - IO errors
+pub fn restore_backup(
+    path: &Path,
+    kura_dir: &Path,
+    snapshot_dir: &Path,
+    force: bool,
+) -> Result<Manifest> {
+    let manifest = verify_backup(path)?;
+
+    let snapshot_copies = manifest
+        .files
+        .keys()
+        .filter_map(|name| {
+            let file = name.strip_prefix(&format!("{SNAPSHOT_DIR_NAME}/"))?;
+            Some((path.join(name), snapshot_dir.join(file)))
+        })
+        .collect::<Vec<_>>();
+    let block_store_files = [
+        kura::INDEX_FILE_NAME,
+        kura::DATA_FILE_NAME,
+        kura::HASHES_FILE_NAME,
+    ]
+    .map(|file| kura_dir.join(file));
+
+    if !force {
+        for to in snapshot_copies
+            .iter()
+            .map(|(_, to)| to)
+            .chain(&block_store_files)
+        {
+            if std::fs::metadata(to).is_ok_and(|metadata| metadata.len() > 0) {
+                return Err(Error::AlreadyExists(to.clone()));
+            }
+        }
+    }
+    for dir in [kura_dir, snapshot_dir] {
+        std::fs::create_dir_all(dir).map_err(|err| Error::IO(err, dir.to_path_buf()))?;
+    }
+    for (from, to) in snapshot_copies {
+        std::fs::copy(&from, &to).map_err(|err| Error::IO(err, to))?;
+    }
+
+    let mut block_store = BlockStore::new(kura_dir);
+    block_store.create_files_if_they_do_not_exist()?;
+    block_store.prune(0)?;
+    for (_, segment_path) in list_segments(path)? {
+        append_segment(&BlockStore::new(segment_path), &mut block_store)?;
+    }
+
+    Ok(manifest)
+}
+
+/// Most recent backup in `store_dir` whose latest block is still in `kura`,
+/// which the next backup can be made on top of.
+fn base_backup(store_dir: &Path, kura: &Kura) -> Option<(PathBuf, Manifest)> {
+    let path = match list_backups(store_dir) {
+        Ok(mut backups) => backups.pop()?,
+        Err(error) => {
+            warn!(%error, "Failed to list backups, making a full backup");
+            return None;
+        }
+    };
+    let manifest = match read_manifest(&path) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            warn!(
+                %error, path = %path.display(),
+                "Failed to read the manifest of the latest backup, making a full backup"
+            );
+            return None;
+        }
+    };
+
+    let latest_block =
+        NonZeroUsize::new(manifest.block_count).and_then(|height| kura.get_block_hash(height));
+    (latest_block == manifest.latest_block).then_some((path, manifest))
+}
+
+fn read_manifest(path: &Path) -> Result<Manifest> {
+    let manifest_path = path.join(MANIFEST_FILE_NAME);
+    let bytes = std::fs::read(&manifest_path).map_err(|err| Error::IO(err, manifest_path))?;
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+/// Hard link `from` to `to`, or copy it if the file system doesn't support hard links.
+fn link_or_copy(from: &Path, to: &Path) -> Result<()> {
+    if let Some(dir) = to.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| Error::IO(err, dir.to_path_buf()))?;
+    }
+    if std::fs::hard_link(from, to).is_err() {
+        std::fs::copy(from, to).map_err(|err| Error::IO(err, to.to_path_buf()))?;
+    }
+    Ok(())
+}
+
+/// Block store segments of the backup at `path`, by the height of their first block.
+fn list_segments(path: &Path) -> Result<BTreeMap<usize, PathBuf>> {
+    let blocks_path = path.join(BLOCKS_DIR_NAME);
+    let entries =
+        std::fs::read_dir(&blocks_path).map_err(|err| Error::IO(err, blocks_path.clone()))?;
+
+    let mut segments = BTreeMap::new();
+    for entry in entries {
+        let entry = entry.map_err(|err| Error::IO(err, blocks_path.clone()))?;
+        let first_height = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse().ok())
+            .ok_or_else(|| Error::Corrupted(entry.path()))?;
+        segments.insert(first_height, entry.path());
+    }
+    Ok(segments)
+}
+
+/// Append the blocks of `segment` to `block_store` as they are stored, without decoding them.
+fn append_segment(segment: &BlockStore, block_store: &mut BlockStore) -> Result<()> {
+    let mut height = block_store.read_index_count()?;
+    let mut start = match height.checked_sub(1) {
+        Some(latest) => {
+            let index = block_store.read_block_index(latest)?;
+            index.start + index.length
+        }
+        None => 0,
+    };
+
+    // Reused between blocks, so that only one block is held in memory at a time
+    let mut data = Vec::new();
+    for segment_height in 0..segment.read_index_count()? {
+        let index = segment.read_block_index(segment_height)?;
+        data.resize(
+            usize::try_from(index.length).expect("Block doesn't fit in memory"),
+            0,
+        );
+        segment.read_block_data(index.start, &mut data)?;
+        let hash = segment.read_block_hashes(segment_height, 1)?[0];
+
+        block_store.write_block_data(start, &data)?;
+        block_store.write_block_index(height, start, index.length)?;
+        block_store.write_block_hash(height, hash)?;
+        start += index.length;
+        height += 1;
+    }
+
+    Ok(())
+}
+
+/// Complete backups in `store_dir`, from the oldest to the most recent.
+fn list_backups(store_dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = match std::fs::read_dir(store_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(Error::IO(err, store_dir.to_path_buf())),
+    };
+
+    let mut backups = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|err| Error::IO(err, store_dir.to_path_buf()))?;
+        let name = entry.file_name();
+        let Some(name) = name.to_str() else {
+            continue;
+        };
+        if name.starts_with(BACKUP_DIR_PREFIX)
+            && !name.ends_with(BACKUP_TMP_DIR_SUFFIX)
+            && entry.path().is_dir()
+        {
+            backups.push(entry.path());
+        }
+    }
+    backups.sort();
+    Ok(backups)
+}
+
+/// Insert digests of files in `dir` of the backup at `path` and its subdirectories into `files`.
+///
+/// Files are hashed as they are read, so that they are never held in memory.
+fn digest_dir(path: &Path, dir: &str, files: &mut BTreeMap<String, FileDigest>) -> Result<()> {
+    let dir_path = path.join(dir);
+    let entries = std::fs::read_dir(&dir_path).map_err(|err| Error::IO(err, dir_path.clone()))?;
+    for entry in entries {
+        let entry = entry.map_err(|err| Error::IO(err, dir_path.clone()))?;
+        let name = format!("{dir}/{}", entry.file_name().to_string_lossy());
+        let file_path = entry.path();
+        if file_path.is_dir() {
+            digest_dir(path, &name, files)?;
+            continue;
+        }
+
+        let file =
+            std::fs::File::open(&file_path).map_err(|err| Error::IO(err, file_path.clone()))?;
+        let size = file
+            .metadata()
+            .map_err(|err| Error::IO(err, file_path.clone()))?
+            .len();
+        let hash = Hash::from_reader(file).map_err(|err| Error::IO(err, file_path))?;
+        files.insert(name, FileDigest { size, hash });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use iroha_crypto::KeyPair;
+    use iroha_data_model::block::SignedBlock;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::{block::ValidBlock, query::store::LiveQueryStore, state::World};
+
+    #[tokio::test]
+    async fn backup_is_verified_and_restored() {
+        let kura = Kura::blank_kura_for_testing();
+        let state = State::new(
+            World::default(),
+            Arc::clone(&kura),
+            LiveQueryStore::start_test(),
+        );
+        let store_dir = tempdir().unwrap();
+
+        let path = create_backup(&state, &kura, store_dir.path()).unwrap();
+        assert_eq!(latest_backup(store_dir.path()).unwrap(), path);
+        let manifest = verify_backup(&path).unwrap();
+        assert_eq!(manifest.block_count, 0);
+        assert!(manifest.files.contains_key(&format!(
+            "{SNAPSHOT_DIR_NAME}/{}",
+            snapshot::SNAPSHOT_FILE_NAME
+        )));
+
+        let restore_dir = tempdir().unwrap();
+        let kura_dir = restore_dir.path().join("storage");
+        let snapshot_dir = kura_dir.join("snapshot");
+        restore_backup(&path, &kura_dir, &snapshot_dir, false).unwrap();
+        assert!(snapshot_dir.join(snapshot::SNAPSHOT_FILE_NAME).is_file());
+        assert!(matches!(
+            restore_backup(&path, &kura_dir, &snapshot_dir, false),
+            Err(Error::AlreadyExists(_))
+        ));
+        restore_backup(&path, &kura_dir, &snapshot_dir, true).unwrap();
+
+        std::fs::write(path.join(SNAPSHOT_DIR_NAME).join("extra"), b"extra").unwrap();
+        assert!(matches!(verify_backup(&path), Err(Error::Corrupted(_))));
+    }
+
+    #[tokio::test]
+    async fn backups_only_write_blocks_committed_since_previous_backup() {
+        let kura = Kura::blank_kura_for_testing();
+        let state = State::new(
+            World::default(),
+            Arc::clone(&kura),
+            LiveQueryStore::start_test(),
+        );
+        let store_dir = tempdir().unwrap();
+        let key_pair = KeyPair::random();
+        let block_at = |height, view_change_index| {
+            SignedBlock::from(ValidBlock::new_dummy_and_modify_header(
+                key_pair.private_key(),
+                |header| {
+                    header.height = NonZeroU64::new(height).unwrap();
+                    header.view_change_index = view_change_index;
+                },
+            ))
+        };
+        let backup = || {
+            // Backups are named after the time they are created at in milliseconds
+            std::thread::sleep(Duration::from_millis(2));
+            create_backup(&state, &kura, store_dir.path()).unwrap()
+        };
+        let segments = |path: &Path| {
+            list_segments(path)
+                .unwrap()
+                .into_iter()
+                .map(|(first_height, segment_path)| {
+                    let count = BlockStore::new(segment_path).read_index_count().unwrap();
+                    (first_height, count)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        kura.store_block(block_at(1, 0));
+        kura.store_block(block_at(2, 0));
+        let first = backup();
+        kura.store_block(block_at(3, 0));
+        let second = backup();
+        assert_eq!(segments(&first), vec![(1, 2)]);
+        assert_eq!(segments(&second), vec![(1, 2), (3, 1)]);
+        assert_eq!(verify_backup(&second).unwrap().block_count, 3);
+
+        let restore_dir = tempdir().unwrap();
+        let kura_dir = restore_dir.path().join("storage");
+        restore_backup(&second, &kura_dir, &kura_dir.join("snapshot"), false).unwrap();
+        let block_store = BlockStore::new(&kura_dir);
+        let hashes = (1..=3)
+            .map(|height| {
+                kura.get_block_hash(NonZeroUsize::new(height).unwrap())
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(block_store.read_block_hashes(0, 3).unwrap(), hashes);
+
+        // Latest block of the previous backup is replaced, so nothing can be reused
+        kura.replace_top_block(block_at(3, 1));
+        let third = backup();
+        assert_eq!(segments(&third), vec![(1, 3)]);
+        verify_backup(&third).unwrap();
+    }
+
+    #[test]
+    fn prune_keeps_most_recent_backups() {
+        let store_dir = tempdir().unwrap();
+        for name in ["backup-1", "backup-2", "backup-3", "backup-4.tmp"] {
+            std::fs::create_dir(store_dir.path().join(name)).unwrap();
+        }
+
+        prune_backups(store_dir.path(), NonZeroUsize::new(2).unwrap()).unwrap();
+
+        assert_eq!(
+            list_backups(store_dir.path()).unwrap(),
+            vec![
+                store_dir.path().join("backup-2"),
+                store_dir.path().join("backup-3")
+            ]
+        );
+        assert!(store_dir.path().join("backup-4.tmp").is_dir());
+    }
+}
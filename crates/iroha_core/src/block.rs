@@ -50,7 +50,7 @@
 //! [`ValidBlock::commit_unchecked`] (infallible)
 use std::{collections::BTreeSet, time::Duration};
 
-use iroha_crypto::{HashOf, KeyPair, MerkleTree};
+use iroha_crypto::{HashOf, KeyPair, MerkleMountainRange, MerkleTree};
 use iroha_data_model::{
     block::*, events::prelude::*, peer::PeerId, transaction::SignedTransaction,
 };
@@ -86,6 +86,14 @@ pub enum BlockValidationError {
     },
     /// The merkle root does not match the computed one.
     MerkleRootMismatch,
+    /// The root of the previous blocks does not match the computed one.
+    PrevBlocksRootMismatch,
+    /// The merkle root of the excluded transactions does not match the computed one.
+    ExcludedMerkleRootMismatch,
+    /// Block leaves out transactions while rejected transactions must be kept in blocks
+    HasExcludedTransactions,
+    /// Block keeps rejected transactions while they must be left out of blocks
+    HasRejectedTransactions,
     /// Cannot accept a transaction
     TransactionAccept(#[from] AcceptTransactionFail),
     /// Mismatch between the actual and expected topology. Expected: {expected:?}, actual: {actual:?}
@@ -194,6 +202,7 @@ mod pending {
         fn make_header(
             &self,
             prev_block: Option<&SignedBlock>,
+            prev_blocks_root: Option<HashOf<MerkleMountainRange<BlockHeader>>>,
             view_change_index: usize,
         ) -> BlockHeader {
             let prev_block_time =
@@ -232,6 +241,7 @@ mod pending {
                     },
                 ),
                 prev_block_hash: prev_block.map(SignedBlock::hash),
+                prev_blocks_root,
                 merkle_root: self
                     .0
                     .transactions
@@ -240,6 +250,7 @@ mod pending {
                     .map(SignedTransaction::hash_as_entrypoint)
                     .collect::<MerkleTree<_>>()
                     .root(),
+                excluded_merkle_root: None,
                 result_merkle_root: None,
                 creation_time_ms: creation_time
                     .as_millis()
@@ -257,10 +268,26 @@ mod pending {
         pub fn chain(
             self,
             view_change_index: usize,
-            latest_block: Option<&SignedBlock>,
+            state: &impl StateReadOnly,
+        ) -> BlockBuilder<Chained> {
+            let latest_block = state.latest_block();
+            let prev_blocks_root = state.kura().blocks_root(state.height());
+
+            self.chain_to(view_change_index, latest_block.as_deref(), prev_blocks_root)
+        }
+
+        /// Chain the block with `prev_block`, committing to `prev_blocks_root`
+        /// of the blocks up to and including it.
+        ///
+        /// Upon executing this method current timestamp is stored in the block header.
+        pub fn chain_to(
+            self,
+            view_change_index: usize,
+            prev_block: Option<&SignedBlock>,
+            prev_blocks_root: Option<HashOf<MerkleMountainRange<BlockHeader>>>,
         ) -> BlockBuilder<Chained> {
             BlockBuilder(Chained {
-                header: self.make_header(latest_block, view_change_index),
+                header: self.make_header(prev_block, prev_blocks_root, view_change_index),
                 transactions: self.0.transactions,
             })
         }
@@ -318,6 +345,21 @@ mod new {
             ValidBlock::validate_unchecked(self.into(), state_block)
         }
 
+        /// Like [`Self::validate_and_record_transactions`], but the rejected transactions are
+        /// left out of the block and the changed header is signed again with `private_key`.
+        ///
+        /// Rejected transactions don't change the state, so the block is executed only once.
+        pub fn validate_and_exclude_rejected_transactions(
+            self,
+            state_block: &mut StateBlock<'_>,
+            private_key: &PrivateKey,
+        ) -> WithEvents<ValidBlock> {
+            let mut block: SignedBlock = self.into();
+            ValidBlock::validate_and_record_transactions(&mut block, state_block);
+            block.exclude_rejected_transactions(private_key);
+            WithEvents::new(ValidBlock(block))
+        }
+
         /// Block signature
         pub fn signature(&self) -> &BlockSignature {
             &self.signature
@@ -355,6 +397,7 @@ mod new {
                 block.header,
                 // FIXME: transmute somehow
                 block.transactions.into_iter().map(Into::into).collect(),
+                Vec::new(),
             )
         }
     }
@@ -365,8 +408,11 @@ mod valid {
 
     use commit::CommittedBlock;
     use iroha_data_model::{
-        account::AccountId, events::pipeline::PipelineEventBox, isi::InstructionBox,
-        prelude::Executable, ChainId,
+        account::AccountId,
+        events::pipeline::PipelineEventBox,
+        isi::InstructionBox,
+        prelude::{Executable, TransactionEntrypoint},
+        ChainId,
     };
 
     use super::*;
@@ -500,7 +546,15 @@ mod valid {
             ) {
                 return WithEvents::new(Err((Box::new(block), error)));
             }
+            let exclude_rejected = state_block
+                .world
+                .parameters
+                .block
+                .exclude_rejected_transactions;
             Self::validate_and_record_transactions(&mut block, state_block);
+            if let Err(error) = Self::verify_no_rejected_transactions(&block, exclude_rejected) {
+                return WithEvents::new(Err((Box::new(block), error)));
+            }
             WithEvents::new(Ok(ValidBlock(block)))
         }
 
@@ -534,10 +588,37 @@ mod valid {
             } else {
                 state.block(block.header())
             };
+            let exclude_rejected = state_block
+                .world
+                .parameters
+                .block
+                .exclude_rejected_transactions;
             Self::validate_and_record_transactions(&mut block, &mut state_block);
+            if let Err(error) = Self::verify_no_rejected_transactions(&block, exclude_rejected) {
+                return WithEvents::new(Err((Box::new(block), error)));
+            }
             WithEvents::new(Ok((ValidBlock(block), state_block)))
         }
 
+        /// Check that rejected transactions are left out of the block if `exclude_rejected` is set,
+        /// see [`BlockParameters::exclude_rejected_transactions`](iroha_data_model::parameter::BlockParameters::exclude_rejected_transactions).
+        fn verify_no_rejected_transactions(
+            block: &SignedBlock,
+            exclude_rejected: bool,
+        ) -> Result<(), BlockValidationError> {
+            let n_transactions = block.external_transactions().len();
+            if exclude_rejected
+                && block
+                    .results()
+                    .take(n_transactions)
+                    .any(|result| result.is_err())
+            {
+                return Err(BlockValidationError::HasRejectedTransactions);
+            }
+
+            Ok(())
+        }
+
         /// All static checks of the block.
         fn validate_static(
             block: &SignedBlock,
@@ -593,17 +674,30 @@ mod valid {
                 });
             }
 
+            let prev_block = if soft_fork {
+                state.prev_block()
+            } else {
+                state.latest_block()
+            };
+            let expected_prev_blocks_root = state.kura().blocks_root(expected_block_height - 1);
+            let actual_prev_blocks_root = block.header().prev_blocks_root();
+            // Blocks of version 1 don't commit to the previous blocks, so a block may leave the root
+            // out after such a block. Blocks created by this version always include it
+            let continues_version_1 = actual_prev_blocks_root.is_none()
+                && prev_block
+                    .as_ref()
+                    .is_some_and(|prev_block| prev_block.header().prev_blocks_root().is_none());
+            if expected_prev_blocks_root != actual_prev_blocks_root && !continues_version_1 {
+                return Err(BlockValidationError::PrevBlocksRootMismatch);
+            }
+
             if block.header().is_genesis() {
                 check_genesis_block(block, genesis_account)?;
             } else {
-                let prev_block_time = if soft_fork {
-                    state.prev_block()
-                } else {
-                    state.latest_block()
-                }
-                .expect("INTERNAL BUG: Genesis not committed")
-                .header()
-                .creation_time();
+                let prev_block_time = prev_block
+                    .expect("INTERNAL BUG: Genesis not committed")
+                    .header()
+                    .creation_time();
 
                 if block.header().creation_time() <= prev_block_time {
                     return Err(BlockValidationError::BlockInThePast);
@@ -619,10 +713,29 @@ mod valid {
                 (params.sumeragi().max_clock_drift(), params.transaction())
             };
 
-            for tx in block.external_transactions() {
+            let tx_hashes = crate::tx::hash_in_parallel(block.transactions_vec());
+
+            // NOTE: Signatures are verified in batches, skipping those of transactions which were
+            // accepted into the queue of this peer. If any of them fails, transactions are
+            // validated one by one to find the invalid one
+            let signatures_verified = block.header().is_genesis() || {
+                let signature_cache = state.signature_cache();
+                let txs = block.external_transactions().collect::<Vec<_>>();
+
+                let verified = crate::tx::verify_signatures_in_parallel(&txs, |i| {
+                    signature_cache.contains(&tx_hashes[i])
+                })
+                .is_ok();
+                if verified {
+                    signature_cache.extend(tx_hashes.iter().copied());
+                }
+                verified
+            };
+
+            for (tx, tx_hash) in block.external_transactions().zip(&tx_hashes) {
                 if state
                     .transactions()
-                    .get(&tx.hash())
+                    .get(tx_hash)
                     // In case of soft-fork transaction is check if it was added at the same height as candidate block
                     .is_some_and(|height| height.get() < expected_block_height)
                 {
@@ -640,16 +753,22 @@ mod valid {
                         max_clock_drift,
                         genesis_account,
                     )?;
+                } else if signatures_verified {
+                    AcceptedTransaction::validate_with_verified_signature(
+                        tx,
+                        chain_id,
+                        max_clock_drift,
+                        tx_params,
+                    )?;
                 } else {
                     AcceptedTransaction::validate(tx, chain_id, max_clock_drift, tx_params)?;
                 }
             }
 
-            // TODO: can it be done in a single iteration over block transactions above?
-            let expected_merkle_root = block
-                .external_transactions()
-                .map(SignedTransaction::hash_as_entrypoint)
-                .collect::<MerkleTree<_>>()
+            let expected_merkle_root = tx_hashes
+                .into_iter()
+                .map(|hash| HashOf::from_untyped_unchecked(hash.into()))
+                .collect::<MerkleTree<TransactionEntrypoint>>()
                 .root();
             let actual_merkle_root = block.header().merkle_root();
 
@@ -657,6 +776,33 @@ mod valid {
                 return Err(BlockValidationError::MerkleRootMismatch);
             }
 
+            if block.excluded_transactions().next().is_some()
+                && !state
+                    .world()
+                    .parameters()
+                    .block
+                    .exclude_rejected_transactions
+            {
+                return Err(BlockValidationError::HasExcludedTransactions);
+            }
+            for excluded in block.excluded_transactions() {
+                if state
+                    .transactions()
+                    .get(&excluded.hash)
+                    .is_some_and(|height| height.get() < expected_block_height)
+                {
+                    return Err(BlockValidationError::HasCommittedTransactions);
+                }
+            }
+            let expected_excluded_merkle_root = block
+                .excluded_transactions()
+                .map(TransactionRejection::hash)
+                .collect::<MerkleTree<_>>()
+                .root();
+            if expected_excluded_merkle_root != block.header().excluded_merkle_root() {
+                return Err(BlockValidationError::ExcludedMerkleRootMismatch);
+            }
+
             Ok(())
         }
 
@@ -664,17 +810,25 @@ mod valid {
         /// and record results back into the block.
         ///
         /// Must be called with a **block that is _assumed_ to be valid**.
-        fn validate_and_record_transactions(
+        pub(super) fn validate_and_record_transactions(
             block: &mut SignedBlock,
             state_block: &mut StateBlock<'_>,
         ) {
+            state_block.activate_executor_upgrade(&block.header());
+            state_block.prune_permission_grants(&block.header());
             let mut wasm_cache = WasmCache::new();
+            // Large genesis may take a while to apply, so its progress is reported
+            let genesis_transactions = block
+                .header()
+                .is_genesis()
+                .then(|| block.external_transactions().len());
+            let block_hash = block.hash();
             let (mut hashes, mut results) = block.external_transactions().cloned().fold(
                 (Vec::new(), Vec::new()),
                 |mut acc, tx| {
                     // NOTE: function is called with the assumption that the transactions are acceptable
                     // FIXME: cloning is unnecessary; use Cow?
-                    let accepted_tx = AcceptedTransaction::new_unchecked(tx.clone());
+                    let accepted_tx = AcceptedTransaction::new_unchecked(tx);
 
                     let (hash, result) =
                         state_block.validate_transaction(accepted_tx, &mut wasm_cache);
@@ -683,7 +837,7 @@ mod valid {
                         Err(reason) => {
                             iroha_logger::debug!(
                                 tx=%hash,
-                                block=%block.hash(),
+                                block=%block_hash,
                                 reason=?reason,
                                 "Transaction rejected"
                             );
@@ -691,7 +845,7 @@ mod valid {
                         Ok(trigger_sequence) => {
                             iroha_logger::debug!(
                                 tx=%hash,
-                                block=%block.hash(),
+                                block=%block_hash,
                                 trigger_sequence=?trigger_sequence,
                                 "Transaction approved"
                             );
@@ -700,6 +854,13 @@ mod valid {
 
                     acc.0.push(hash);
                     acc.1.push(result);
+                    if let Some(total) = genesis_transactions {
+                        iroha_logger::info!(
+                            applied = acc.0.len(),
+                            total,
+                            "Applied genesis transaction"
+                        );
+                    }
                     acc
                 },
             );
@@ -899,7 +1060,9 @@ mod valid {
             let mut header = BlockHeader {
                 height: nonzero_ext::nonzero!(2_u64),
                 prev_block_hash: None,
+                prev_blocks_root: None,
                 merkle_root: Some(merkle_root),
+                excluded_merkle_root: None,
                 result_merkle_root: None,
                 creation_time_ms: 0,
                 view_change_index: 0,
@@ -920,6 +1083,7 @@ mod valid {
                     .into_iter()
                     .map(Into::into)
                     .collect(),
+                Vec::new(),
             ))
         }
     }
@@ -1174,6 +1338,20 @@ mod event {
         }
     }
 
+    impl WithEvents<ValidBlock> {
+        /// Like [`Self::unpack`], but reports the creation of the block first.
+        ///
+        /// Used for blocks whose header is only known once they are validated.
+        pub fn unpack_created<F: Fn(PipelineEventBox)>(self, f: F) -> ValidBlock {
+            f(BlockEvent {
+                header: self.0.as_ref().header(),
+                status: BlockStatus::Created,
+            }
+            .into());
+            self.unpack(f)
+        }
+    }
+
     impl<B, E: EventProducer> WithEvents<(B, E)> {
         pub(crate) fn unpack<F: Fn(PipelineEventBox)>(self, f: F) -> (B, E) {
             self.0 .1.produce_events().for_each(f);
@@ -1211,7 +1389,16 @@ mod event {
                         hash: tx.hash(),
                         status,
                     }
-                });
+                })
+                .chain(
+                    block
+                        .excluded_transactions()
+                        .map(move |excluded| TransactionEvent {
+                            block_height: Some(block_height),
+                            hash: excluded.hash,
+                            status: TransactionStatus::Rejected(Box::new(excluded.reason.clone())),
+                        }),
+                );
 
             let block_event = core::iter::once(BlockEvent {
                 header: self.as_ref().header(),
@@ -1310,7 +1497,7 @@ mod tests {
         // Creating a block of two identical transactions and validating it
         let transactions = vec![tx.clone(), tx];
         let unverified_block = BlockBuilder::new(transactions)
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(0, &state.view())
             .sign(alice_keypair.private_key())
             .unpack(|_| {});
 
@@ -1379,7 +1566,7 @@ mod tests {
         // Creating a block of two identical transactions and validating it
         let transactions = vec![tx0, tx, tx2];
         let unverified_block = BlockBuilder::new(transactions)
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(0, &state.view())
             .sign(alice_keypair.private_key())
             .unpack(|_| {});
         let mut state_block = state.block(unverified_block.header);
@@ -1433,7 +1620,7 @@ mod tests {
         // Creating a block of where first transaction must fail and second one fully executed
         let transactions = vec![tx_fail, tx_accept];
         let unverified_block = BlockBuilder::new(transactions)
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(0, &state.view())
             .sign(alice_keypair.private_key())
             .unpack(|_| {});
 
@@ -1458,6 +1645,71 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn rejected_transactions_are_excluded() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = "wonderland".parse().expect("Valid");
+        let domain = Domain::new(domain_id).build(&alice_id);
+        let world = World::with([domain], [account], []);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world, kura, query_handle);
+        let (max_clock_drift, tx_limits) = {
+            let state_view = state.world.view();
+            let params = state_view.parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction)
+        };
+        let fail_isi = Unregister::domain("dummy".parse().unwrap());
+        let tx_fail = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+            .with_instructions([fail_isi])
+            .sign(alice_keypair.private_key());
+        let tx_fail = AcceptedTransaction::accept(tx_fail, &chain_id, max_clock_drift, tx_limits)
+            .expect("Valid");
+        let create_domain = Register::domain(Domain::new("domain".parse().expect("Valid")));
+        let tx_accept = TransactionBuilder::new(chain_id.clone(), alice_id)
+            .with_instructions([create_domain])
+            .sign(alice_keypair.private_key());
+        let tx_accept =
+            AcceptedTransaction::accept(tx_accept, &chain_id, max_clock_drift, tx_limits)
+                .expect("Valid");
+        let fail_hash = tx_fail.as_ref().hash();
+
+        let transactions = vec![tx_fail, tx_accept];
+        let unverified_block = BlockBuilder::new(transactions)
+            .chain(0, &state.view())
+            .sign(alice_keypair.private_key())
+            .unpack(|_| {});
+        let mut state_block = state.block(unverified_block.header);
+        let valid_block = unverified_block
+            .validate_and_exclude_rejected_transactions(
+                &mut state_block,
+                alice_keypair.private_key(),
+            )
+            .unpack(|_| {});
+        state_block.commit();
+
+        // Only the hash of the rejected transaction is kept
+        let block = valid_block.as_ref();
+        assert!(block.errors().next().is_none());
+        assert_eq!(block.external_transactions().len(), 1);
+        let excluded = block.excluded_transactions().collect::<Vec<_>>();
+        assert_eq!(excluded.len(), 1);
+        assert_eq!(excluded[0].hash, fail_hash);
+        assert!(block.header().excluded_merkle_root().is_some());
+        // The changed header is signed again
+        block
+            .signatures()
+            .next()
+            .unwrap()
+            .signature
+            .verify_hash(alice_keypair.public_key(), block.hash())
+            .expect("Header must be signed again");
+    }
+
     #[tokio::test]
     async fn genesis_public_key_is_checked() {
         let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
@@ -1500,7 +1752,7 @@ mod tests {
         let transactions = vec![tx];
         let topology = test_topology(1);
         let unverified_block = BlockBuilder::new(transactions)
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(0, &state.view())
             .sign(genesis_correct_key.private_key())
             .unpack(|_| {});
 
@@ -290,7 +290,7 @@ pub mod message {
 
                         block_sync.seen_blocks.insert((height, block.hash()));
                         let msg = BlockSyncUpdate::from(&block);
-                        block_sync.sumeragi.incoming_block_message(msg);
+                        block_sync.sumeragi.incoming_block_message(msg, None);
                     }
                 }
             }
@@ -234,6 +234,42 @@ impl Executor {
         }
     }
 
+    /// Validate that `subscriber` may receive events about `account`.
+    ///
+    /// # Errors
+    ///
+    /// - Failed to prepare runtime for WASM execution;
+    /// - Failed to execute the entrypoint of the WASM blob,
+    ///   e.g. because the executor predates the entrypoint;
+    /// - Executor denied the operation.
+    pub fn validate_event_subscription<S: StateReadOnly>(
+        &self,
+        state_ro: &S,
+        subscriber: &AccountId,
+        account: &AccountId,
+    ) -> Result<(), ValidationFail> {
+        trace!("Running event subscription validation");
+
+        match self {
+            Self::Initial => Ok(()),
+            Self::UserProvided(loaded_executor) => {
+                let runtime = wasm::RuntimeBuilder::<
+                    wasm::state::executor::ValidateEventSubscription<S>,
+                >::new()
+                .with_engine(state_ro.engine().clone()) // Cloning engine is cheap, see [`wasmtime::Engine`] docs
+                .with_config(state_ro.world().parameters().executor)
+                .build()?;
+
+                runtime.execute_executor_validate_event_subscription(
+                    state_ro,
+                    subscriber,
+                    &loaded_executor.module,
+                    account.clone(),
+                )?
+            }
+        }
+    }
+
     /// Migrate executor to a new user-provided one.
     ///
     /// Execute `migrate()` entrypoint of the `raw_executor` and set `self` to
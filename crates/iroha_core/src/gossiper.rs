@@ -126,7 +126,7 @@ impl TransactionGossiper {
             };
 
             match AcceptedTransaction::accept(tx, &self.chain_id, max_clock_drift, tx_limits) {
-                Ok(tx) => match self.queue.push(tx, self.state.view()) {
+                Ok(tx) => match self.queue.push_gossiped(tx, self.state.view()) {
                     Ok(()) => {}
                     Err(crate::queue::Failure {
                         tx,
@@ -5,7 +5,7 @@
 use std::{
     fmt::Debug,
     io::{BufWriter, Read, Seek, SeekFrom, Write},
-    num::NonZeroUsize,
+    num::{NonZeroU64, NonZeroUsize},
     path::{Path, PathBuf},
     sync::Arc,
     time::Duration,
@@ -15,12 +15,16 @@ use iroha_config::{
     kura::InitMode,
     parameters::{actual::Kura as Config, defaults::kura::BLOCKS_IN_MEMORY},
 };
-use iroha_crypto::{Hash, HashOf};
-use iroha_data_model::block::{BlockHeader, SignedBlock};
+use iroha_crypto::{Hash, HashOf, MerkleMountainRange};
+use iroha_data_model::{
+    account::AccountId,
+    block::{AccountHistory, AccountHistoryBlock, BlockHeader, BlockInclusionProof, SignedBlock},
+    query::CommittedTransaction,
+};
 use iroha_futures::supervisor::{spawn_os_thread_as_future, Child, OnShutdown, ShutdownSignal};
 use iroha_logger::prelude::*;
-use iroha_version::scale::{DecodeVersioned, EncodeVersioned};
-use parity_scale_codec::DecodeAll;
+use iroha_version::scale::EncodeVersioned;
+use parity_scale_codec::{DecodeAll, Encode};
 use parking_lot::Mutex;
 
 use crate::block::CommittedBlock;
@@ -31,9 +35,9 @@ impl From<CommittedBlock> for Arc<SignedBlock> {
     }
 }
 
-const INDEX_FILE_NAME: &str = "blocks.index";
-const DATA_FILE_NAME: &str = "blocks.data";
-const HASHES_FILE_NAME: &str = "blocks.hashes";
+pub(crate) const INDEX_FILE_NAME: &str = "blocks.index";
+pub(crate) const DATA_FILE_NAME: &str = "blocks.data";
+pub(crate) const HASHES_FILE_NAME: &str = "blocks.hashes";
 
 const SIZE_OF_BLOCK_HASH: u64 = Hash::LENGTH as u64;
 
@@ -44,6 +48,9 @@ pub struct Kura {
     block_store: Mutex<BlockStore>,
     /// The array of block hashes and a slot for an arc of the block. This is normally recovered from the index file.
     block_data: Mutex<BlockData>,
+    /// Accumulator of the block hashes for proving inclusion of blocks into the chain.
+    /// Always locked after `block_data` when both are needed.
+    block_hashes: Mutex<MerkleMountainRange<BlockHeader>>,
     /// Path to file for plain text blocks.
     block_plain_text_path: Option<PathBuf>,
     /// At most N last blocks will be stored in memory.
@@ -76,10 +83,12 @@ impl Kura {
         let block_data = Kura::init(&mut block_store, config.init_mode)?;
         let block_count = block_data.len();
         info!(mode=?config.init_mode, block_count, "Kura init complete");
+        let block_hashes = block_data.iter().map(|(hash, _)| *hash).collect();
 
         let kura = Arc::new(Self {
             block_store: Mutex::new(block_store),
             block_data: Mutex::new(block_data),
+            block_hashes: Mutex::new(block_hashes),
             block_plain_text_path,
             blocks_in_memory: config.blocks_in_memory,
             init_block_count: block_count,
@@ -94,6 +103,7 @@ impl Kura {
         Arc::new(Self {
             block_store: Mutex::new(BlockStore::new(PathBuf::new())),
             block_data: Mutex::new(Vec::new()),
+            block_hashes: Mutex::new(MerkleMountainRange::default()),
             block_plain_text_path: None,
             blocks_in_memory: BLOCKS_IN_MEMORY,
             init_block_count: 0,
@@ -166,12 +176,13 @@ impl Kura {
         block_store.read_block_indices(0, &mut block_indices)?;
 
         let mut prev_block_hash = None;
+        // Reused between the blocks to avoid reallocating it for every block
+        let mut block_data_buffer = Vec::new();
         for block in block_indices {
-            // This is re-allocated every iteration. This could cause a problem.
-            let mut block_data_buffer = vec![0_u8; block.length.try_into()?];
+            block_data_buffer.resize(block.length.try_into()?, 0_u8);
 
             match block_store.read_block_data(block.start, &mut block_data_buffer) {
-                Ok(()) => match SignedBlock::decode_all_versioned(&block_data_buffer) {
+                Ok(()) => match SignedBlock::decode_stored(&block_data_buffer) {
                     Ok(decoded_block) => {
                         if prev_block_hash != decoded_block.header().prev_block_hash {
                             error!(expected=?prev_block_hash, actual=?decoded_block.header().prev_block_hash,
@@ -338,8 +349,8 @@ impl Kura {
         block_store
             .read_block_data(start, &mut block_buf)
             .expect("INTERNAL BUG: Failed to read block data.");
-        let block = SignedBlock::decode_all_versioned(&block_buf)
-            .expect("INTERNAL BUG: Failed to decode block");
+        let block =
+            SignedBlock::decode_stored(&block_buf).expect("INTERNAL BUG: Failed to decode block");
 
         let block_arc = Arc::new(block);
         // Only last N blocks should be kept in memory
@@ -349,18 +360,129 @@ impl Kura {
         Some(block_arc)
     }
 
+    /// Root of the Merkle mountain range of the hashes of the first `block_count` blocks,
+    /// `None` if there are fewer blocks or `block_count` is zero.
+    pub fn blocks_root(
+        &self,
+        block_count: usize,
+    ) -> Option<HashOf<MerkleMountainRange<BlockHeader>>> {
+        self.block_hashes.lock().root_at(block_count as u64)
+    }
+
+    /// Get the proof of inclusion of the block at the provided height into the chain
+    /// committed to by the block at `chain_height`, or by the latest block if it's `None`.
+    ///
+    /// The root of the proof is [`BlockHeader::prev_blocks_root`] of that block,
+    /// so only the blocks preceding it can be proven.
+    pub fn get_block_inclusion_proof(
+        &self,
+        block_height: NonZeroUsize,
+        chain_height: Option<NonZeroUsize>,
+    ) -> Option<BlockInclusionProof> {
+        let block_hashes = self.block_hashes.lock();
+
+        let chain_height =
+            chain_height.map_or(block_hashes.leaf_count(), |height| height.get() as u64);
+        if chain_height > block_hashes.leaf_count() {
+            return None;
+        }
+        let leaf_count = chain_height.checked_sub(1)?;
+        let proof = block_hashes.get_proof(block_height.get() as u64 - 1, leaf_count)?;
+        let root = block_hashes
+            .root_at(leaf_count)
+            .expect("INTERNAL BUG: proof exists, so does the root");
+
+        Some(BlockInclusionProof { root, proof })
+    }
+
+    /// Collect the committed transactions involving `account` in blocks from height `from`
+    /// up to `to`, or the latest block if it's `None`, with proofs made for the chain
+    /// committed to by the latest block.
+    ///
+    /// At most `max_blocks` blocks are searched, the rest of the range is left for the
+    /// request continuing from [`AccountHistory::next`].
+    ///
+    /// # Errors
+    /// If there is no block at height `from`, or a block of the range can't be read
+    pub fn account_history(
+        &self,
+        account: &AccountId,
+        from: NonZeroUsize,
+        to: Option<NonZeroUsize>,
+        max_blocks: NonZeroUsize,
+    ) -> Result<AccountHistory, AccountHistoryError> {
+        // Blocks committed during the search must not change the root of the proofs.
+        // Hashes are added after blocks, so every block counted here is stored too.
+        let chain_height = usize::try_from(self.block_hashes.lock().leaf_count())
+            .ok()
+            .and_then(NonZeroUsize::new)
+            .ok_or(AccountHistoryError::BlockNotFound)?;
+        if from > chain_height {
+            return Err(AccountHistoryError::BlockNotFound);
+        }
+        let requested_to = to.map_or(chain_height, |to| to.min(chain_height));
+        let to = from.saturating_add(max_blocks.get() - 1).min(requested_to);
+        let encoded_account = account.encode();
+
+        let mut blocks = Vec::new();
+        for height in (from.get()..=to.get()).filter_map(NonZeroUsize::new) {
+            let block = self
+                .get_block(height)
+                .ok_or(AccountHistoryError::MissingBlock(height))?;
+            let transactions: Vec<_> = block
+                .committed_transactions()
+                .filter(|tx| refers_to(tx, &encoded_account))
+                .collect();
+            if transactions.is_empty() {
+                continue;
+            }
+            let proof = if height < chain_height {
+                Some(
+                    self.get_block_inclusion_proof(height, Some(chain_height))
+                        .ok_or(AccountHistoryError::MissingBlock(height))?,
+                )
+            } else {
+                None
+            };
+
+            blocks.push(AccountHistoryBlock {
+                header: block.header(),
+                proof,
+                transactions,
+            });
+        }
+
+        let height = |height: NonZeroUsize| {
+            NonZeroU64::try_from(height).expect("INTERNAL BUG: height exceeds u64")
+        };
+        Ok(AccountHistory {
+            account: account.clone(),
+            from: height(from),
+            to: height(to),
+            next: (to < requested_to).then(|| height(to.saturating_add(1))),
+            blocks,
+        })
+    }
+
     /// Put a block in kura's in memory block store.
     pub fn store_block(&self, block: impl Into<Arc<SignedBlock>>) {
         let block = block.into();
-        self.block_data.lock().push((block.hash(), Some(block)));
+        let hash = block.hash();
+        let mut data = self.block_data.lock();
+        data.push((hash, Some(block)));
+        self.block_hashes.lock().add(hash);
     }
 
     /// Replace the block in `Kura`'s in memory block store.
     pub fn replace_top_block(&self, block: impl Into<Arc<SignedBlock>>) {
         let block = block.into();
+        let hash = block.hash();
         let mut data = self.block_data.lock();
         data.pop();
-        data.push((block.hash(), Some(block)));
+        data.push((hash, Some(block)));
+        let mut block_hashes = self.block_hashes.lock();
+        block_hashes.pop();
+        block_hashes.add(hash);
     }
 
     // Drop old block to prevent unbounded memory usage.
@@ -383,6 +505,17 @@ impl Kura {
     }
 }
 
+/// Whether the entrypoint or the result of `tx` contains the SCALE encoding of an id.
+fn refers_to(tx: &CommittedTransaction, encoded_id: &[u8]) -> bool {
+    let contains = |encoded: Vec<u8>| {
+        encoded
+            .windows(encoded_id.len())
+            .any(|window| window == encoded_id)
+    };
+
+    contains(tx.entrypoint.encode()) || contains(tx.result.encode())
+}
+
 /// Loaded block count
 #[derive(Clone, Copy, Debug)]
 pub struct BlockCount(pub usize);
@@ -896,6 +1029,15 @@ pub enum Error {
     HashesFileHeightMismatch,
 }
 
+/// Error of [`Kura::account_history`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+pub enum AccountHistoryError {
+    /// There is no block at the height the search starts from
+    BlockNotFound,
+    /// Block {0} or its inclusion proof can't be read
+    MissingBlock(NonZeroUsize),
+}
+
 trait AddErrContextExt<T> {
     type Context;
 
@@ -1262,7 +1404,7 @@ mod tests {
 
         {
             let unverified_block = BlockBuilder::new(vec![tx1.clone()])
-                .chain(0, state.view().latest_block().as_deref())
+                .chain(0, &state.view())
                 .sign(&leader_private_key)
                 .unpack(|_| {});
 
@@ -1282,7 +1424,7 @@ mod tests {
 
         {
             let unverified_block_soft_fork = BlockBuilder::new(vec![tx1])
-                .chain(1, Some(&genesis.0))
+                .chain_to(1, Some(&genesis.0), kura.blocks_root(1))
                 .sign(&leader_private_key)
                 .unpack(|_| {});
 
@@ -1303,7 +1445,7 @@ mod tests {
 
         {
             let unverified_block_next = BlockBuilder::new(vec![tx2])
-                .chain(0, state.view().latest_block().as_deref())
+                .chain(0, &state.view())
                 .sign(&leader_private_key)
                 .unpack(|_| {});
 
@@ -1356,8 +1498,14 @@ mod tests {
             };
 
             let prev = self.blocks.last().cloned();
+            let prev_blocks_root = self
+                .blocks
+                .iter()
+                .map(|block| block.hash())
+                .collect::<MerkleMountainRange<_>>()
+                .root();
             let block: SignedBlock = BlockBuilder::new(vec![tx])
-                .chain(0, prev.as_ref().map(AsRef::as_ref))
+                .chain_to(0, prev.as_ref().map(AsRef::as_ref), prev_blocks_root)
                 .sign(SAMPLE_GENESIS_ACCOUNT_KEYPAIR.private_key())
                 .unpack(|_| {})
                 .into();
@@ -1376,7 +1524,7 @@ mod tests {
         let BlockIndex { start, length } = store.read_block_index(index as u64)?;
         let mut buff = vec![0_u8; length.try_into().unwrap()];
         store.read_block_data(start, &mut buff)?;
-        let block = SignedBlock::decode_all_versioned(&buff)?;
+        let block = SignedBlock::decode_stored(&buff)?;
         Ok(block)
     }
 
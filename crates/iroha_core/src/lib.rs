@@ -1,12 +1,15 @@
 //! Iroha — A simple, enterprise-grade decentralized ledger.
 
+pub mod backup;
 pub mod block;
 pub mod block_sync;
 pub mod executor;
 pub mod gossiper;
 pub mod kiso;
 pub mod kura;
+pub mod memory;
 pub mod peers_gossiper;
+pub mod plugin;
 pub mod query;
 pub mod queue;
 pub mod smartcontracts;
@@ -61,6 +64,19 @@ pub enum NetworkMessage {
     Health,
 }
 
+impl iroha_p2p::Prioritized for NetworkMessage {
+    fn lane(&self) -> iroha_p2p::Lane {
+        match self {
+            Self::SumeragiBlock(_) | Self::SumeragiControlFlow(_) | Self::Health => {
+                iroha_p2p::Lane::Consensus
+            }
+            Self::BlockSync(_) | Self::TransactionGossiper(_) | Self::PeersGossiper(_) => {
+                iroha_p2p::Lane::Bulk
+            }
+        }
+    }
+}
+
 pub mod role {
     //! Module with extension for [`RoleId`] to be stored inside state.
 
@@ -117,6 +133,388 @@ pub mod role {
     }
 }
 
+pub mod asset_movement {
+    //! Module with the key under which [`AssetMovement`]s are stored inside state.
+
+    use core::ops::RangeInclusive;
+
+    use derive_more::Constructor;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Position of an [`AssetMovement`] of the given account in the chain.
+    ///
+    /// Movements of one account are adjacent and ordered chronologically,
+    /// so statements are produced by a range scan instead of a chain scan.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct AssetMovementId {
+        /// [`AccountId`] whose balance was moved.
+        pub account: AccountId,
+        /// Height of the block containing the movement.
+        pub height: u64,
+        /// Index of the movement within the block.
+        pub index: u32,
+    }
+
+    /// Number of the latest blocks whose asset movements are kept for account statements.
+    ///
+    /// Movements of an account made in earlier blocks are pruned whenever its balance moves
+    /// again, so an account keeps at most the movements of this many blocks.
+    pub const RETAINED_BLOCKS: u64 = 100_000;
+
+    impl AssetMovementId {
+        /// Range covering all movements of the given account.
+        pub fn of_account(account: &AccountId) -> RangeInclusive<Self> {
+            Self::new(account.clone(), 0, 0)..=Self::new(account.clone(), u64::MAX, u32::MAX)
+        }
+
+        /// Range covering the movements of the given account made in the blocks up to the given height.
+        pub fn of_account_up_to_block(account: &AccountId, height: u64) -> RangeInclusive<Self> {
+            Self::new(account.clone(), 0, 0)..=Self::new(account.clone(), height, u32::MAX)
+        }
+    }
+}
+
+pub mod permission_grant {
+    //! Module with the key under which [`PermissionGrantEvent`]s are stored inside state.
+
+    use core::ops::RangeInclusive;
+
+    use derive_more::Constructor;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Position of a [`PermissionGrantEvent`] in the chain.
+    ///
+    /// Keys are ordered chronologically, so the audit log is read in the order of changes.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct PermissionGrantEventId {
+        /// Height of the block containing the change.
+        pub height: u64,
+        /// Index of the change within the block.
+        pub index: u32,
+    }
+
+    /// Number of the latest blocks whose permission changes are kept in the audit log.
+    ///
+    /// Changes made in earlier blocks are pruned as blocks are applied,
+    /// so the log doesn't grow with the length of the chain.
+    pub const RETAINED_BLOCKS: u64 = 100_000;
+
+    impl PermissionGrantEventId {
+        /// Range covering all changes made in the block at the given height.
+        pub fn of_block(height: u64) -> RangeInclusive<Self> {
+            Self::new(height, 0)..=Self::new(height, u32::MAX)
+        }
+
+        /// Range covering all changes made in the blocks up to the given height.
+        pub fn up_to_block(height: u64) -> RangeInclusive<Self> {
+            Self::new(0, 0)..=Self::new(height, u32::MAX)
+        }
+    }
+}
+
+pub mod permission_revocation {
+    //! Module with the conditions on which permissions are revoked from accounts automatically.
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Condition on which a [`Permission`] granted to an account is revoked automatically.
+    #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+    pub struct PermissionRevocation {
+        /// Permission to revoke.
+        pub permission: Permission,
+        /// Events on which the permission is revoked.
+        pub revoke_on: DataEventFilter,
+        /// Account which granted the permission, recorded in the audit log as the author
+        /// of the revocation.
+        pub granted_by: AccountId,
+    }
+
+    /// Revocation conditions of the permissions of a single account.
+    pub type PermissionRevocations = Vec<PermissionRevocation>;
+}
+
+pub mod redaction {
+    //! Module with the records of redactable metadata values inside state,
+    //! and with the redaction of the blocks served to clients.
+
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use derive_more::Constructor;
+    use iroha_config::parameters::actual::RedactionSecret;
+    use iroha_data_model::{block::SignedBlock, query::CommittedTransaction};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::state::{StateReadOnly, WorldReadOnly};
+
+    /// Value in the metadata of an account which can be redacted.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct RedactableKey {
+        /// [`AccountId`] whose metadata holds the value.
+        pub account: AccountId,
+        /// Key of the value in the metadata.
+        pub key: Name,
+    }
+
+    /// Redaction of the values set under a [`RedactableKey`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize)]
+    pub struct Redaction {
+        /// Height of the block up to which the values are redacted.
+        pub height: NonZeroU64,
+    }
+
+    /// Commitment replacing the metadata `value` under `key`, salted as described in [`Redact`].
+    ///
+    /// Without the `secret`, e.g. on a peer which lost it, the value is withheld altogether.
+    pub(crate) fn redacted_value(
+        value: &Json,
+        key: &RedactableKey,
+        secret: Option<&RedactionSecret>,
+    ) -> Json {
+        secret.map_or_else(
+            || Json::new(()),
+            |secret| {
+                let salt = Redact::salt(secret.expose(), &key.account, &key.key);
+                Json::new(Redact::commitment(&salt, value))
+            },
+        )
+    }
+
+    /// `block` as served to clients, with the metadata values redacted after it was committed
+    /// replaced with their commitments.
+    ///
+    /// The header, and thus the block hash, is left intact.
+    pub fn redacted_block(block: Arc<SignedBlock>, state: &impl StateReadOnly) -> Arc<SignedBlock> {
+        let height = block.header().height();
+        if !is_any_redacted_at(height, state.world()) {
+            return block;
+        }
+
+        let mut block = (*block).clone();
+        block.map_instructions(|instruction| redacted_instruction(instruction, height, state));
+        Arc::new(block)
+    }
+
+    /// `transaction` committed at `height` as served to clients, see [`redacted_block`].
+    pub fn redacted_transaction(
+        mut transaction: CommittedTransaction,
+        height: NonZeroU64,
+        state: &impl StateReadOnly,
+    ) -> CommittedTransaction {
+        if !is_any_redacted_at(height, state.world()) {
+            return transaction;
+        }
+
+        if let TransactionEntrypoint::External(entrypoint) = &mut transaction.entrypoint {
+            entrypoint
+                .map_instructions(|instruction| redacted_instruction(instruction, height, state));
+        }
+        transaction
+    }
+
+    fn is_any_redacted_at(height: NonZeroU64, world: &impl WorldReadOnly) -> bool {
+        world
+            .redactions()
+            .iter()
+            .any(|(_, redaction)| redaction.is_some_and(|redaction| height <= redaction.height))
+    }
+
+    fn redacted_instruction(
+        instruction: InstructionBox,
+        height: NonZeroU64,
+        state: &impl StateReadOnly,
+    ) -> InstructionBox {
+        let redacted_key = |account: &AccountId, key: &Name| {
+            let key = RedactableKey::new(account.clone(), key.clone());
+            state
+                .world()
+                .redactions()
+                .get(&key)
+                .copied()
+                .flatten()
+                .is_some_and(|redaction| height <= redaction.height)
+                .then_some(key)
+        };
+        let redact_metadata = |account: &AccountId, metadata: &mut Metadata| {
+            let redacted = metadata
+                .iter()
+                .filter_map(|(key, value)| {
+                    let redacted_key = redacted_key(account, key)?;
+                    let value = redacted_value(value, &redacted_key, state.redaction_secret());
+                    Some((key.clone(), value))
+                })
+                .collect::<Vec<_>>();
+            for (key, value) in redacted {
+                metadata.insert(key, value);
+            }
+        };
+
+        match instruction {
+            InstructionBox::SetKeyValue(SetKeyValueBox::Account(mut isi)) => {
+                if let Some(redacted_key) = redacted_key(&isi.object, &isi.key) {
+                    isi.value = redacted_value(&isi.value, &redacted_key, state.redaction_secret());
+                }
+                isi.into()
+            }
+            InstructionBox::Register(RegisterBox::Account(mut isi)) => {
+                redact_metadata(&isi.object.id, &mut isi.object.metadata);
+                isi.into()
+            }
+            InstructionBox::Register(RegisterBox::Accounts(mut isi)) => {
+                for new_account in &mut isi.objects {
+                    redact_metadata(&new_account.id, &mut new_account.metadata);
+                }
+                isi.into()
+            }
+            instruction => instruction,
+        }
+    }
+}
+
+pub mod nft_content_index {
+    //! Module with the index of substrings found in the content of NFTs, used to search
+    //! NFTs by their content without scanning all of them.
+
+    use std::collections::BTreeSet;
+
+    use derive_more::Constructor;
+
+    use super::*;
+
+    /// Three consecutive characters of a string.
+    pub type Trigram = [char; 3];
+
+    /// Most distinct trigrams indexed for the content of one NFT.
+    ///
+    /// NFTs whose content has more are indexed under [`UNINDEXED`] only,
+    /// so the index grows with the number of NFTs rather than with the size of their content.
+    pub const MAX_TRIGRAMS_PER_NFT: usize = 256;
+
+    /// Trigram under which the NFTs with too many trigrams to index are recorded.
+    ///
+    /// These NFTs are candidates for every lookup and are then matched against their content.
+    pub const UNINDEXED: Trigram = ['\0'; 3];
+
+    /// Entry of the index recording that a string value in the content of [`Self::nft`]
+    /// contains [`Self::trigram`].
+    #[derive(Debug, Clone, Constructor, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct NftContentTrigram {
+        /// Characters found in the content.
+        pub trigram: Trigram,
+        /// NFT whose content contains the characters.
+        pub nft: NftId,
+    }
+
+    /// Trigrams of `text`, in order of appearance.
+    pub fn trigrams(text: &str) -> impl Iterator<Item = Trigram> + '_ {
+        let mut chars = text.chars();
+        let mut window = [chars.next(), chars.next()];
+        chars.filter_map(move |next| {
+            let trigram = [window[0]?, window[1]?, next];
+            window = [window[1], Some(next)];
+            Some(trigram)
+        })
+    }
+
+    /// Trigrams of all string values in `content` to index,
+    /// or only [`UNINDEXED`] if there are more than [`MAX_TRIGRAMS_PER_NFT`] of them.
+    pub fn content_trigrams(content: &Metadata) -> BTreeSet<Trigram> {
+        let trigrams: BTreeSet<_> = content
+            .iter()
+            .flat_map(|(_, value)| value.string_values())
+            .flat_map(|string| trigrams(&string).collect::<Vec<_>>())
+            .collect();
+
+        if trigrams.len() > MAX_TRIGRAMS_PER_NFT {
+            BTreeSet::from([UNINDEXED])
+        } else {
+            trigrams
+        }
+    }
+}
+
+pub mod executor_upgrade {
+    //! Module with the executor upgrades which are delayed until a later block.
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Executor upgrade submitted while [`ExecutorUpgradeParameters::delay_blocks`] was set,
+    /// which is applied at the start of the block at [`Self::activation_height`].
+    ///
+    /// [`ExecutorUpgradeParameters::delay_blocks`]: iroha_data_model::parameter::ExecutorUpgradeParameters::delay_blocks
+    #[derive(Debug, Clone, Deserialize, Serialize)]
+    pub struct PendingExecutorUpgrade {
+        /// Executor replacing the current one.
+        pub executor: iroha_data_model::executor::Executor,
+        /// Account which submitted the upgrade and on behalf of which the migration runs.
+        pub authority: AccountId,
+        /// Height of the block at the start of which the upgrade is applied.
+        pub activation_height: u64,
+    }
+
+    /// Change of [`ExecutorUpgradeParameters`] which loosens the restrictions, e.g. allows
+    /// more accounts to upgrade or shortens the delay. Like an upgrade, it is delayed by
+    /// the [`ExecutorUpgradeParameters::delay_blocks`] in force when it was submitted.
+    ///
+    /// [`ExecutorUpgradeParameters`]: iroha_data_model::parameter::ExecutorUpgradeParameters
+    /// [`ExecutorUpgradeParameters::delay_blocks`]: iroha_data_model::parameter::ExecutorUpgradeParameters::delay_blocks
+    #[derive(Debug, Clone, Deserialize, Serialize)]
+    pub struct PendingExecutorUpgradePolicy {
+        /// Parameters replacing the current ones.
+        pub parameters: iroha_data_model::parameter::ExecutorUpgradeParameters,
+        /// Height of the block at the start of which the parameters are applied.
+        pub activation_height: u64,
+    }
+}
+
 pub mod prelude {
     //! Re-exports important traits and types. Meant to be glob imported when using `Iroha`.
 
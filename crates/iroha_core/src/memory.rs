@@ -0,0 +1,293 @@
+//! This module contains [`MemoryAccountant`] actor which estimates memory held by peer subsystems.
+use std::{num::NonZeroU64, sync::Arc, time::Duration};
+
+use iroha_config::{base::util::Bytes, parameters::actual::Memory as Config};
+use iroha_crypto::HashOf;
+use iroha_data_model::{block::BlockHeader, events::EventBox};
+use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
+use iroha_logger::prelude::*;
+#[cfg(feature = "telemetry")]
+use iroha_telemetry::metrics::Metrics;
+use mv::storage::StorageReadOnly;
+
+use crate::{
+    query::store::LiveQueryStoreHandle,
+    queue::Queue,
+    state::{State, StateReadOnly, WorldReadOnly},
+    EventsSender,
+};
+
+/// Estimated number of bytes held by peer subsystems.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Transactions waiting in the queue
+    pub queue: u64,
+    /// World state and block hashes
+    pub state: u64,
+    /// Live query cursors
+    pub live_queries: u64,
+    /// Events not yet received by all subscribers
+    pub events: u64,
+}
+
+impl MemoryUsage {
+    /// Sum of bytes held by all subsystems.
+    pub fn total(&self) -> u64 {
+        self.queue
+            .saturating_add(self.state)
+            .saturating_add(self.live_queries)
+            .saturating_add(self.events)
+    }
+
+    /// Subsystem names paired with the bytes they hold.
+    pub fn subsystems(&self) -> [(&'static str, u64); 4] {
+        [
+            ("queue", self.queue),
+            ("state", self.state),
+            ("live_queries", self.live_queries),
+            ("events", self.events),
+        ]
+    }
+
+    /// Names of the subsystems holding more bytes than their ceiling.
+    ///
+    /// The world state has no ceiling: rejecting queries doesn't shrink it.
+    pub fn over_ceilings(&self, ceilings: &MemoryCeilings) -> Vec<&'static str> {
+        [
+            ("queue", self.queue, ceilings.queue),
+            ("live_queries", self.live_queries, ceilings.live_queries),
+            ("events", self.events, ceilings.events),
+        ]
+        .into_iter()
+        .filter(|(_, bytes, ceiling)| ceiling.is_some_and(|ceiling| *bytes > ceiling.get()))
+        .map(|(subsystem, _, _)| subsystem)
+        .collect()
+    }
+}
+
+/// Estimated number of bytes per subsystem above which new queries are rejected.
+///
+/// Subsystems without a ceiling never trigger load-shedding.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryCeilings {
+    /// Ceiling for [`MemoryUsage::queue`]
+    pub queue: Option<NonZeroU64>,
+    /// Ceiling for [`MemoryUsage::live_queries`]
+    pub live_queries: Option<NonZeroU64>,
+    /// Ceiling for [`MemoryUsage::events`]
+    pub events: Option<NonZeroU64>,
+}
+
+impl MemoryCeilings {
+    /// Read the ceilings from [`Config`].
+    pub fn from_config(config: &Config) -> Self {
+        let ceiling =
+            |limit: Option<Bytes<u64>>| limit.and_then(|limit| NonZeroU64::new(limit.get()));
+        Self {
+            queue: ceiling(config.queue_limit),
+            live_queries: ceiling(config.live_queries_limit),
+            events: ceiling(config.events_limit),
+        }
+    }
+}
+
+/// Actor responsible for periodic memory accounting and load-shedding.
+///
+/// Estimates are shallow: they are meant to catch growth trends early
+/// rather than to match the resident set size of the process.
+pub struct MemoryAccountant {
+    state: Arc<State>,
+    queue: Arc<Queue>,
+    query_handle: LiveQueryStoreHandle,
+    events_sender: EventsSender,
+    /// Frequency at which memory usage is estimated
+    sample_every: Duration,
+    /// Estimated usage per subsystem above which new queries are rejected
+    ceilings: MemoryCeilings,
+    #[cfg(feature = "telemetry")]
+    metrics: Arc<Metrics>,
+}
+
+impl MemoryAccountant {
+    /// Create from [`Config`].
+    pub fn from_config(
+        config: &Config,
+        state: Arc<State>,
+        queue: Arc<Queue>,
+        query_handle: LiveQueryStoreHandle,
+        events_sender: EventsSender,
+        #[cfg(feature = "telemetry")] metrics: Arc<Metrics>,
+    ) -> Self {
+        Self {
+            state,
+            queue,
+            query_handle,
+            events_sender,
+            sample_every: config.sample_every_ms.get(),
+            ceilings: MemoryCeilings::from_config(config),
+            #[cfg(feature = "telemetry")]
+            metrics,
+        }
+    }
+
+    /// Start the actor.
+    pub fn start(self, shutdown_signal: ShutdownSignal) -> Child {
+        Child::new(tokio::spawn(self.run(shutdown_signal)), OnShutdown::Abort)
+    }
+
+    async fn run(self, shutdown_signal: ShutdownSignal) {
+        let mut sample_every = tokio::time::interval(self.sample_every);
+        sample_every.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut shedding = false;
+
+        loop {
+            tokio::select! {
+                _ = sample_every.tick() => {
+                    let usage = self.sample();
+                    self.report(&usage);
+                    shedding = self.update_load_shedding(&usage, shedding);
+                },
+                () = shutdown_signal.receive() => {
+                    debug!("Shutting down memory accountant");
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Estimate bytes currently held by each subsystem.
+    pub fn sample(&self) -> MemoryUsage {
+        MemoryUsage {
+            queue: self.queue.tx_bytes(),
+            state: estimate_state_bytes(&self.state),
+            live_queries: self.query_handle.memory_bytes(),
+            events: (self.events_sender.len() as u64)
+                .saturating_mul(core::mem::size_of::<EventBox>() as u64),
+        }
+    }
+
+    #[cfg_attr(not(feature = "telemetry"), allow(clippy::unused_self))]
+    fn report(&self, usage: &MemoryUsage) {
+        trace!(?usage, total = usage.total(), "Sampled memory usage");
+        #[cfg(feature = "telemetry")]
+        for (subsystem, bytes) in usage.subsystems() {
+            self.metrics
+                .memory_bytes
+                .with_label_values(&[subsystem])
+                .set(bytes);
+        }
+    }
+
+    fn update_load_shedding(&self, usage: &MemoryUsage, shedding: bool) -> bool {
+        let over_ceilings = usage.over_ceilings(&self.ceilings);
+        let over_limit = !over_ceilings.is_empty();
+        if over_limit != shedding {
+            if over_limit {
+                warn!(
+                    ?over_ceilings,
+                    ?usage,
+                    "Estimated memory usage is over the limit, rejecting new queries"
+                );
+            } else {
+                info!(
+                    ?usage,
+                    "Estimated memory usage is back under the limits, accepting new queries"
+                );
+            }
+            self.query_handle.set_load_shedding(over_limit);
+        }
+        over_limit
+    }
+}
+
+/// Shallow estimate of the world state: entry counts multiplied by in-place sizes.
+fn estimate_state_bytes(state: &State) -> u64 {
+    fn storage_bytes<K: mv::Key, V: mv::Value>(storage: &impl StorageReadOnly<K, V>) -> u64 {
+        (storage.len() as u64)
+            .saturating_mul((core::mem::size_of::<K>() + core::mem::size_of::<V>()) as u64)
+    }
+
+    let state_view = state.view();
+    let world = state_view.world();
+    [
+        storage_bytes(world.domains()),
+        storage_bytes(world.accounts()),
+        storage_bytes(world.asset_definitions()),
+        storage_bytes(world.assets()),
+        storage_bytes(world.nfts()),
+        storage_bytes(world.roles()),
+        (state_view.block_hashes().len() as u64)
+            .saturating_mul(core::mem::size_of::<HashOf<BlockHeader>>() as u64),
+    ]
+    .into_iter()
+    .fold(0, u64::saturating_add)
+}
+
+#[cfg(test)]
+mod tests {
+    use nonzero_ext::nonzero;
+
+    use super::*;
+
+    #[test]
+    fn total_saturates() {
+        let usage = MemoryUsage {
+            queue: u64::MAX,
+            state: 1,
+            live_queries: 2,
+            events: 3,
+        };
+        assert_eq!(usage.total(), u64::MAX);
+    }
+
+    #[test]
+    fn state_is_not_counted_against_ceilings() {
+        let usage = MemoryUsage {
+            state: u64::MAX,
+            ..MemoryUsage::default()
+        };
+        let ceilings = MemoryCeilings {
+            queue: Some(nonzero!(1_u64)),
+            live_queries: Some(nonzero!(1_u64)),
+            events: Some(nonzero!(1_u64)),
+        };
+        assert!(usage.over_ceilings(&ceilings).is_empty());
+    }
+
+    #[test]
+    fn each_subsystem_is_checked_against_its_own_ceiling() {
+        let usage = MemoryUsage {
+            queue: 10,
+            state: 0,
+            live_queries: 20,
+            events: 30,
+        };
+        let ceilings = MemoryCeilings {
+            queue: Some(nonzero!(10_u64)),
+            live_queries: Some(nonzero!(19_u64)),
+            events: Some(nonzero!(100_u64)),
+        };
+        assert_eq!(usage.over_ceilings(&ceilings), ["live_queries"]);
+
+        let ceilings = MemoryCeilings {
+            queue: Some(nonzero!(9_u64)),
+            events: Some(nonzero!(29_u64)),
+            ..ceilings
+        };
+        assert_eq!(
+            usage.over_ceilings(&ceilings),
+            ["queue", "live_queries", "events"]
+        );
+    }
+
+    #[test]
+    fn subsystems_without_ceiling_are_unlimited() {
+        let usage = MemoryUsage {
+            queue: u64::MAX,
+            state: u64::MAX,
+            live_queries: u64::MAX,
+            events: u64::MAX,
+        };
+        assert!(usage.over_ceilings(&MemoryCeilings::default()).is_empty());
+    }
+}
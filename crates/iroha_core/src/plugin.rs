@@ -0,0 +1,194 @@
+//! This module contains [`PluginHost`] actor which invokes [`CommitPlugin`]s after each committed block.
+use std::{
+    num::NonZeroUsize,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
+
+use iroha_data_model::{
+    block::SignedBlock,
+    events::{
+        pipeline::{BlockEvent, BlockStatus, PipelineEventBox},
+        EventBox,
+    },
+};
+use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
+use iroha_logger::prelude::*;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::{
+    kura::Kura,
+    state::{State, StateReadOnly, StateView},
+    EventsSender,
+};
+
+/// Extension of the peer invoked after each committed block.
+///
+/// Plugins are compiled into the peer and registered at startup.
+/// They are called one block at a time, in the order of block heights,
+/// on a thread dedicated to blocking work, so a slow plugin delays
+/// other plugins but never consensus.
+pub trait CommitPlugin: Send + Sync + 'static {
+    /// Name used to identify the plugin in logs
+    fn name(&self) -> &str;
+
+    /// Handle a committed `block`.
+    ///
+    /// `state` is read-only and reflects the world at the height of `block` or later.
+    fn on_block_committed(&self, block: &SignedBlock, state: &StateView<'_>);
+}
+
+/// Plugin which logs a summary of every committed block.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LogCommittedBlocks;
+
+impl CommitPlugin for LogCommittedBlocks {
+    fn name(&self) -> &str {
+        "log_committed_blocks"
+    }
+
+    fn on_block_committed(&self, block: &SignedBlock, state: &StateView<'_>) {
+        info!(
+            height = block.header().height.get(),
+            block = %block.hash(),
+            transactions = block.external_transactions().len(),
+            rejected = block.errors().count(),
+            state_height = state.height(),
+            "Block committed"
+        );
+    }
+}
+
+/// Actor responsible for delivering committed blocks to [`CommitPlugin`]s.
+pub struct PluginHost {
+    plugins: Arc<Vec<Box<dyn CommitPlugin>>>,
+    state: Arc<State>,
+    kura: Arc<Kura>,
+    events: broadcast::Receiver<EventBox>,
+}
+
+impl PluginHost {
+    /// Create [`Self`], or [`None`] if there are no plugins to run.
+    pub fn new(
+        plugins: Vec<Box<dyn CommitPlugin>>,
+        state: Arc<State>,
+        kura: Arc<Kura>,
+        events_sender: &EventsSender,
+    ) -> Option<Self> {
+        if plugins.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            plugins: Arc::new(plugins),
+            state,
+            kura,
+            events: events_sender.subscribe(),
+        })
+    }
+
+    /// Start the actor.
+    pub fn start(self, shutdown_signal: ShutdownSignal) -> Child {
+        Child::new(tokio::spawn(self.run(shutdown_signal)), OnShutdown::Abort)
+    }
+
+    async fn run(mut self, shutdown_signal: ShutdownSignal) {
+        let names = self
+            .plugins
+            .iter()
+            .map(|plugin| plugin.name())
+            .collect::<Vec<_>>();
+        info!(plugins = ?names, "Starting commit plugins");
+
+        // Plugins are only notified about blocks committed after the peer has started
+        let mut delivered = self.state.view().height();
+
+        loop {
+            tokio::select! {
+                event = self.events.recv() => {
+                    let applied = match event {
+                        Ok(EventBox::Pipeline(PipelineEventBox::Block(BlockEvent {
+                            header,
+                            status: BlockStatus::Applied,
+                        }))) => usize::try_from(header.height.get())
+                            .expect("INTERNAL BUG: Block height exceeds usize::MAX"),
+                        Ok(_) => continue,
+                        // Missed events are recovered from the block store
+                        Err(RecvError::Lagged(_)) => self.state.view().height(),
+                        Err(RecvError::Closed) => break,
+                    };
+                    delivered = self.deliver(delivered, applied).await;
+                },
+                () = shutdown_signal.receive() => {
+                    debug!("Shutting down plugin host");
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Invoke plugins for blocks after `delivered` up to `applied` inclusive.
+    /// Returns the height of the last delivered block.
+    async fn deliver(&self, mut delivered: usize, applied: usize) -> usize {
+        while delivered < applied {
+            let height = NonZeroUsize::new(delivered + 1).expect("Can't be zero");
+            let Some(block) = self.kura.get_block(height) else {
+                warn!(%height, "Committed block is missing from the block store");
+                break;
+            };
+
+            let plugins = Arc::clone(&self.plugins);
+            let state = Arc::clone(&self.state);
+            let task = tokio::task::spawn_blocking(move || {
+                let state_view = state.view();
+                for plugin in plugins.iter() {
+                    let result = catch_unwind(AssertUnwindSafe(|| {
+                        plugin.on_block_committed(&block, &state_view);
+                    }));
+                    if result.is_err() {
+                        error!(plugin = plugin.name(), %height, "Commit plugin panicked");
+                    }
+                }
+            });
+            if let Err(error) = task.await {
+                error!(?error, %height, "Failed to run commit plugins");
+            }
+
+            delivered = height.get();
+        }
+
+        delivered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{query::store::LiveQueryStore, state::World};
+
+    #[tokio::test]
+    async fn host_requires_plugins() {
+        let kura = Kura::blank_kura_for_testing();
+        let state = Arc::new(State::new(
+            World::default(),
+            Arc::clone(&kura),
+            LiveQueryStore::start_test(),
+        ));
+        let (events_sender, _) = broadcast::channel(1);
+
+        assert!(PluginHost::new(
+            Vec::new(),
+            Arc::clone(&state),
+            Arc::clone(&kura),
+            &events_sender
+        )
+        .is_none());
+        assert!(PluginHost::new(
+            vec![Box::new(LogCommittedBlocks)],
+            state,
+            kura,
+            &events_sender
+        )
+        .is_some());
+    }
+}
@@ -11,7 +11,7 @@ use iroha_data_model::{
     },
 };
 
-fn evaluate_selector_tuple<T>(
+pub(crate) fn evaluate_selector_tuple<T>(
     batch: Vec<T>,
     selector: &SelectorTuple<T>,
 ) -> Result<QueryOutputBatchBoxTuple, QueryExecutionFail>
@@ -43,6 +43,7 @@ trait BatchedTrait {
         cursor: u64,
     ) -> Result<(QueryOutputBatchBoxTuple, Option<NonZeroU64>), QueryExecutionFail>;
     fn remaining(&self) -> u64;
+    fn remaining_bytes(&self) -> u64;
 }
 
 struct BatchedInner<I>
@@ -120,6 +121,10 @@ where
     fn remaining(&self) -> u64 {
         self.iter.len() as u64
     }
+
+    fn remaining_bytes(&self) -> u64 {
+        (self.iter.len() as u64).saturating_mul(core::mem::size_of::<I::Item>() as u64)
+    }
 }
 
 /// A query output iterator that combines evaluating selectors, batching and type erasure.
@@ -176,4 +181,11 @@ impl ErasedQueryIterator {
     pub fn remaining(&self) -> u64 {
         self.inner.remaining()
     }
+
+    /// Estimates the number of bytes held by the remaining elements.
+    ///
+    /// Heap allocations owned by the elements are not accounted for.
+    pub fn remaining_bytes(&self) -> u64 {
+        self.inner.remaining_bytes()
+    }
 }
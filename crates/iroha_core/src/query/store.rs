@@ -2,7 +2,10 @@
 
 use std::{
     num::{NonZeroU64, NonZeroUsize},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::{Duration, Instant},
 };
 
@@ -37,6 +40,8 @@ pub struct LiveQueryStore {
     capacity_per_user: NonZeroUsize,
     // Queries older then this time will be automatically removed from the store
     idle_time: Duration,
+    // New queries are rejected while the peer is low on memory
+    load_shedding: AtomicBool,
     shutdown_signal: ShutdownSignal,
 }
 
@@ -56,6 +61,7 @@ impl LiveQueryStore {
             idle_time: cfg.idle_time,
             capacity: cfg.capacity,
             capacity_per_user: cfg.capacity_per_user,
+            load_shedding: AtomicBool::new(false),
             shutdown_signal,
         }
     }
@@ -208,13 +214,19 @@ impl LiveQueryStoreHandle {
     ///
     /// # Errors
     ///
-    /// - Returns [`QueryExecutionFail::CapacityLimit`] if [`LiveQueryStore`] capacity is reached,
+    /// - Returns [`QueryExecutionFail::CapacityLimit`] if [`LiveQueryStore`] capacity is reached
+    ///   or new queries are being rejected due to memory pressure,
     /// - Otherwise throws up query output handling errors.
     pub fn handle_iter_start(
         &self,
         mut live_query: ErasedQueryIterator,
         authority: &AccountId,
     ) -> Result<QueryOutput, QueryExecutionFail> {
+        if self.store.load_shedding.load(Ordering::Relaxed) {
+            warn!(%authority, "Rejecting new query due to memory pressure");
+            return Err(QueryExecutionFail::CapacityLimit);
+        }
+
         let query_id = uuid::Uuid::new_v4().to_string();
 
         let curr_cursor = 0;
@@ -262,6 +274,20 @@ impl LiveQueryStoreHandle {
         self.store.remove(query_id);
     }
 
+    /// Estimate the number of bytes held by the stored queries.
+    pub fn memory_bytes(&self) -> u64 {
+        self.store
+            .queries
+            .iter()
+            .map(|query| query.live_query.remaining_bytes())
+            .sum()
+    }
+
+    /// Start or stop rejecting new queries.
+    pub fn set_load_shedding(&self, enabled: bool) {
+        self.store.load_shedding.store(enabled, Ordering::Relaxed);
+    }
+
     fn construct_query_response(
         batch: QueryOutputBatchBoxTuple,
         remaining_items: u64,
@@ -316,6 +342,7 @@ mod tests {
             let query_output = crate::smartcontracts::query::apply_query_postprocessing(
                 query_output,
                 SelectorTuple::default(),
+                None,
                 &query_params,
             )
             .unwrap();
@@ -341,4 +368,40 @@ mod tests {
             assert_eq!(counter, 100, "failed on {i} iteration");
         }
     }
+
+    #[test]
+    fn load_shedding_rejects_new_queries() {
+        let threaded_rt = tokio::runtime::Runtime::new().unwrap();
+        let query_handle = threaded_rt.block_on(async { LiveQueryStore::start_test() });
+
+        let start_query = || {
+            let query_params = QueryParams {
+                fetch_size: FetchSize {
+                    fetch_size: Some(nonzero!(1_u64)),
+                },
+                ..QueryParams::default()
+            };
+            let query_output =
+                (0..100).map(|_| Permission::new(String::default(), Json::from(false)));
+            let query_output = crate::smartcontracts::query::apply_query_postprocessing(
+                query_output,
+                SelectorTuple::default(),
+                None,
+                &query_params,
+            )
+            .unwrap();
+            query_handle.handle_iter_start(query_output, &ALICE_ID)
+        };
+
+        query_handle.set_load_shedding(true);
+        assert!(matches!(
+            start_query(),
+            Err(QueryExecutionFail::CapacityLimit)
+        ));
+        assert_eq!(query_handle.memory_bytes(), 0);
+
+        query_handle.set_load_shedding(false);
+        start_query().unwrap();
+        assert!(query_handle.memory_bytes() > 0);
+    }
 }
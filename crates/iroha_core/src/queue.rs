@@ -1,6 +1,13 @@
 //! Module with queue actor
 use core::time::Duration;
-use std::{num::NonZeroUsize, ops::Deref, sync::Arc};
+use std::{
+    num::NonZeroUsize,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use crossbeam_queue::ArrayQueue;
 use dashmap::{mapref::entry::Entry, DashMap};
@@ -10,11 +17,12 @@ use iroha_config::parameters::actual::Queue as Config;
 use iroha_crypto::HashOf;
 use iroha_data_model::{
     account::AccountId,
-    events::pipeline::{TransactionEvent, TransactionStatus},
+    events::pipeline::{QueueDropReason, QueueEvent, TransactionEvent, TransactionStatus},
     transaction::prelude::*,
 };
 use iroha_logger::{trace, warn};
 use iroha_primitives::time::TimeSource;
+use parity_scale_codec::Encode;
 use thiserror::Error;
 
 use crate::{prelude::*, state::StateReadOnlyWithTransactions, EventsSender};
@@ -49,8 +57,14 @@ pub struct Queue {
     time_source: TimeSource,
     /// Length of time after which transactions are dropped.
     pub tx_time_to_live: Duration,
+    /// Transactions older than this aren't accepted into the queue
+    replay_window: Duration,
     /// Queue to gossip transactions
     tx_gossip: ArrayQueue<HashOf<SignedTransaction>>,
+    /// Number of dropped transactions by reason
+    dropped_txs: DashMap<QueueDropReason, u64>,
+    /// Set once the peer starts draining, after which no transactions are accepted
+    closed: AtomicBool,
 }
 
 /// Queue push error
@@ -61,12 +75,29 @@ pub enum Error {
     Full,
     /// Transaction expired
     Expired,
+    /// Transaction was created before the replay window
+    OutsideReplayWindow,
     /// Transaction is already applied
     InBlockchain,
     /// User reached maximum number of transactions in the queue
     MaximumTransactionsPerUser,
     /// The transaction is already in the queue
     IsInQueue,
+    /// Queue is closed as the peer is draining before shutdown
+    Closed,
+}
+
+impl From<Error> for QueueDropReason {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Full => Self::QueueFull,
+            Error::Expired | Error::OutsideReplayWindow => Self::Expired,
+            Error::InBlockchain => Self::AlreadyInBlockchain,
+            Error::MaximumTransactionsPerUser => Self::AuthorityQueueFull,
+            Error::IsInQueue => Self::Duplicate,
+            Error::Closed => Self::Draining,
+        }
+    }
 }
 
 /// Failure that can pop up when pushing transaction into the queue
@@ -106,6 +137,7 @@ impl Queue {
             capacity,
             capacity_per_user,
             transaction_time_to_live,
+            replay_window,
         }: Config,
         events_sender: EventsSender,
     ) -> Self {
@@ -118,10 +150,24 @@ impl Queue {
             capacity_per_user,
             time_source: TimeSource::new_system(),
             tx_time_to_live: transaction_time_to_live,
+            replay_window,
             tx_gossip: ArrayQueue::new(capacity.get()),
+            dropped_txs: DashMap::new(),
+            closed: AtomicBool::new(false),
         }
     }
 
+    /// Stop accepting transactions, so that the queue empties
+    /// as the transactions already in it are committed or dropped.
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the queue was closed with [`Self::close`].
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
     fn is_pending(&self, tx: &AcceptedTransaction, state_view: &StateView) -> bool {
         !self.is_expired(tx) && !tx.is_in_blockchain(state_view)
     }
@@ -139,6 +185,16 @@ impl Queue {
         curr_time.saturating_sub(tx_creation_time) > time_limit
     }
 
+    /// Checks if the transaction was created before the replay window.
+    ///
+    /// Only the queue enforces the window. Blocks aren't validated against it
+    /// and committed transactions are still looked up in the whole blockchain.
+    pub fn is_outside_replay_window(&self, tx: &AcceptedTransaction) -> bool {
+        let tx_creation_time = tx.as_ref().creation_time();
+        let curr_time = self.time_source.get_unix_time();
+        curr_time.saturating_sub(tx_creation_time) > self.replay_window
+    }
+
     /// Returns all pending transactions.
     pub fn all_transactions<'state>(
         &'state self,
@@ -153,6 +209,11 @@ impl Queue {
         })
     }
 
+    /// Returns the transaction with the given `hash` if it is in the queue.
+    pub fn get(&self, hash: &HashOf<SignedTransaction>) -> Option<AcceptedTransaction> {
+        self.txs.get(hash).map(|tx| tx.value().clone())
+    }
+
     /// Returns `n` transactions in a batch for gossiping
     pub fn gossip_batch(&self, n: u32, state_view: &StateView) -> Vec<AcceptedTransaction> {
         let mut batch = Vec::with_capacity(n as usize);
@@ -174,6 +235,8 @@ impl Queue {
     fn check_tx(&self, tx: &AcceptedTransaction, state_view: &StateView) -> Result<(), Error> {
         if tx.is_in_blockchain(state_view) {
             Err(Error::InBlockchain)
+        } else if self.is_outside_replay_window(tx) {
+            Err(Error::OutsideReplayWindow)
         } else if self.is_expired(tx) {
             Err(Error::Expired)
         } else {
@@ -181,20 +244,47 @@ impl Queue {
         }
     }
 
-    /// Push transaction into queue.
+    /// Push transaction submitted to this peer into queue.
+    ///
+    /// If the transaction isn't enqueued, a [`QueueEvent`] is emitted,
+    /// so that its submitter can learn it won't reach a block.
     ///
     /// # Errors
     /// See [`enum@Error`]
     pub fn push(&self, tx: AcceptedTransaction, state_view: StateView) -> Result<(), Failure> {
+        self.push_gossiped(tx, state_view)
+            .inspect_err(|failure| self.report_dropped(&failure.tx, failure.err.into()))
+    }
+
+    /// Push transaction received from another peer into queue.
+    ///
+    /// Unlike [`Self::push`], failures aren't reported, as the transaction
+    /// is tracked by the peer it was submitted to.
+    ///
+    /// # Errors
+    /// See [`enum@Error`]
+    pub fn push_gossiped(
+        &self,
+        tx: AcceptedTransaction,
+        state_view: StateView,
+    ) -> Result<(), Failure> {
         trace!(tx=%tx.as_ref().hash(), "Pushing to the queue");
+        if self.is_closed() {
+            return Err(Failure {
+                tx: tx.into(),
+                err: Error::Closed,
+            });
+        }
         if let Err(err) = self.check_tx(&tx, &state_view) {
             return Err(Failure { tx: tx.into(), err });
         }
+        let hash = tx.as_ref().hash();
+        // Signature of the accepted transaction is verified, there's no need to verify it again in the block
+        state_view.signature_cache.extend([hash]);
         drop(state_view);
 
         // Get `txs_len` before entry to avoid deadlock
         let txs_len = self.txs.len();
-        let hash = tx.as_ref().hash();
         let entry = match self.txs.entry(hash) {
             Entry::Occupied(_) => {
                 return Err(Failure {
@@ -292,6 +382,14 @@ impl Queue {
         self.txs.len()
     }
 
+    /// Return the encoded size of transactions in the queue.
+    pub fn tx_bytes(&self) -> u64 {
+        self.txs
+            .iter()
+            .map(|tx| tx.as_ref().encoded_size() as u64)
+            .sum()
+    }
+
     /// Gets transactions till they fill whole block or till the end of queue.
     ///
     /// BEWARE: Shouldn't be called in parallel with itself.
@@ -331,16 +429,37 @@ impl Queue {
             .take(max_txs_in_block.get() - transactions.len());
         transactions.extend(txs);
 
-        expired_transactions
-            .into_iter()
-            .map(|tx| TransactionEvent {
+        for tx in expired_transactions {
+            let _ = self.events_sender.send(
+                TransactionEvent {
+                    hash: tx.as_ref().hash(),
+                    block_height: None,
+                    status: TransactionStatus::Expired,
+                }
+                .into(),
+            );
+            self.report_dropped(&tx, QueueDropReason::Expired);
+        }
+    }
+
+    fn report_dropped(&self, tx: &AcceptedTransaction, reason: QueueDropReason) {
+        *self.dropped_txs.entry(reason).or_default() += 1;
+        let _ = self.events_sender.send(
+            QueueEvent {
                 hash: tx.as_ref().hash(),
-                block_height: None,
-                status: TransactionStatus::Expired,
-            })
-            .for_each(|e| {
-                let _ = self.events_sender.send(e.into());
-            });
+                authority: tx.as_ref().authority().clone(),
+                reason,
+            }
+            .into(),
+        );
+    }
+
+    /// Return the number of transactions dropped from the queue, or rejected by it, by reason.
+    pub fn dropped_txs(&self) -> Vec<(QueueDropReason, u64)> {
+        self.dropped_txs
+            .iter()
+            .map(|entry| (*entry.key(), *entry.value()))
+            .collect()
     }
 
     /// Overview:
@@ -427,6 +546,9 @@ pub mod tests {
                 capacity_per_user: cfg.capacity_per_user,
                 time_source: time_source.clone(),
                 tx_time_to_live: cfg.transaction_time_to_live,
+                replay_window: cfg.replay_window,
+                dropped_txs: DashMap::new(),
+                closed: AtomicBool::new(false),
             }
         }
     }
@@ -450,10 +572,7 @@ pub mod tests {
             TransactionBuilder::new_with_time_source(chain_id.clone(), account_id, time_source)
                 .with_instructions(instructions)
                 .sign(key_pair.private_key());
-        let tx_limits = TransactionParameters {
-            max_instructions: nonzero!(4096_u64),
-            smart_contract_size: nonzero!(1024_u64),
-        };
+        let tx_limits = TransactionParameters::new(nonzero!(4096_u64), nonzero!(1024_u64));
         AcceptedTransaction::accept(tx, &chain_id, Duration::from_millis(10), tx_limits)
             .expect("Failed to accept Transaction.")
     }
@@ -578,6 +697,32 @@ pub mod tests {
         assert_eq!(queue.txs.len(), 0);
     }
 
+    #[test]
+    async fn push_tx_outside_replay_window() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_domains(), kura, query_handle);
+        let (time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Queue::test(
+            Config {
+                replay_window: Duration::from_secs(10),
+                ..config_factory()
+            },
+            &time_source,
+        );
+
+        let tx = accepted_tx_by_someone(&time_source);
+        time_handle.advance(Duration::from_secs(11));
+        assert!(matches!(
+            queue.push(tx, state.view()),
+            Err(Failure {
+                err: Error::OutsideReplayWindow,
+                ..
+            })
+        ));
+        assert_eq!(queue.txs.len(), 0);
+    }
+
     #[test]
     async fn push_expired_tx_already_in_blockchain() {
         let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
@@ -717,13 +862,16 @@ pub mod tests {
 
         let (time_handle, time_source) = TimeSource::new_mock(Duration::default());
         let mut queue = Queue::test(config_factory(), &time_source);
-        let (event_sender, mut event_receiver) = tokio::sync::broadcast::channel(1);
+        let (event_sender, mut event_receiver) = tokio::sync::broadcast::channel(2);
         queue.events_sender = event_sender;
         let fail_isi = Unregister::domain("dummy".parse().unwrap());
         let instructions = [fail_isi];
-        let mut tx =
-            TransactionBuilder::new_with_time_source(chain_id.clone(), alice_id, &time_source)
-                .with_instructions(instructions);
+        let mut tx = TransactionBuilder::new_with_time_source(
+            chain_id.clone(),
+            alice_id.clone(),
+            &time_source,
+        )
+        .with_instructions(instructions);
         tx.set_ttl(Duration::from_millis(TTL_MS));
         let tx = tx.sign(alice_keypair.private_key());
         let tx_hash = tx.hash();
@@ -759,7 +907,70 @@ pub mod tests {
                 status: TransactionStatus::Expired,
             }
             .into()
-        )
+        );
+        assert_eq!(
+            event_receiver.recv().await.unwrap(),
+            QueueEvent {
+                hash: tx_hash,
+                authority: alice_id,
+                reason: QueueDropReason::Expired,
+            }
+            .into()
+        );
+    }
+
+    #[test]
+    async fn dropped_transactions_are_reported() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let mut queue = Queue::test(config_factory(), &time_source);
+        let (event_sender, mut event_receiver) = tokio::sync::broadcast::channel(4);
+        queue.events_sender = event_sender;
+
+        let tx = accepted_tx_by_someone(&time_source);
+        queue.push(tx.clone(), state.view()).unwrap();
+        assert!(queue.push_gossiped(tx.clone(), state.view()).is_err());
+        assert!(queue.push(tx.clone(), state.view()).is_err());
+
+        let _queued = event_receiver.recv().await.unwrap();
+        assert_eq!(
+            event_receiver.recv().await.unwrap(),
+            QueueEvent {
+                hash: tx.as_ref().hash(),
+                authority: tx.as_ref().authority().clone(),
+                reason: QueueDropReason::Duplicate,
+            }
+            .into()
+        );
+        assert!(event_receiver.try_recv().is_err());
+        assert_eq!(queue.dropped_txs(), vec![(QueueDropReason::Duplicate, 1)]);
+    }
+
+    #[test]
+    async fn closed_queue_rejects_transactions() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Queue::test(config_factory(), &time_source);
+        queue
+            .push(accepted_tx_by_someone(&time_source), state.view())
+            .unwrap();
+
+        queue.close();
+        assert!(matches!(
+            queue.push(accepted_tx_by_someone(&time_source), state.view()),
+            Err(Failure {
+                err: Error::Closed,
+                ..
+            })
+        ));
+        assert_eq!(queue.tx_len(), 1);
+        assert_eq!(queue.dropped_txs(), vec![(QueueDropReason::Draining, 1)]);
     }
 
     #[test]
@@ -865,6 +1076,7 @@ pub mod tests {
                 transaction_time_to_live: Duration::from_secs(100),
                 capacity: 100.try_into().unwrap(),
                 capacity_per_user: 1.try_into().unwrap(),
+                ..Config::default()
             },
             &time_source,
         );
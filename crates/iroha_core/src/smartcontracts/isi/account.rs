@@ -19,7 +19,12 @@ pub mod isi {
     };
 
     use super::*;
-    use crate::{role::RoleIdWithOwner, state::StateTransaction};
+    use crate::{
+        permission_revocation::PermissionRevocation,
+        redaction::{redacted_value, RedactableKey, Redaction},
+        role::RoleIdWithOwner,
+        state::{StateReadOnly, StateTransaction},
+    };
 
     impl Execute for Transfer<Account, AssetDefinitionId, Account> {
         fn execute(
@@ -118,11 +123,123 @@ pub mod isi {
         }
     }
 
+    impl Execute for MarkRedactable {
+        #[metrics(+"mark_redactable")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            state_transaction.world.account(&self.account)?;
+
+            let key = RedactableKey::new(self.account, self.key);
+            // Keep the height of an earlier redaction so that the values it covers stay redacted
+            if state_transaction.world.redactions.get(&key).is_none() {
+                state_transaction.world.redactions.insert(key, None);
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Redact {
+        #[metrics(+"redact")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let key = RedactableKey::new(self.account.clone(), self.key.clone());
+            if state_transaction.world.redactions.get(&key).is_none() {
+                return Err(Error::InvariantViolation(format!(
+                    "Value under `{}` in the metadata of `{}` is not marked redactable",
+                    self.key, self.account
+                )));
+            }
+
+            // Peers without the same secret would store and serve other commitments
+            let secret = state_transaction.redaction_secret().cloned();
+            let Some(secret) =
+                secret.filter(|secret| Redact::secret_id(secret.expose()) == self.secret_id)
+            else {
+                return Err(Error::InvariantViolation(
+                    "Peer isn't configured with the `redaction_secret` the value is redacted with"
+                        .to_owned(),
+                ));
+            };
+
+            let account = state_transaction.world.account_mut(&self.account)?;
+            // The value may have been removed already while the served history still holds it
+            let redacted = account
+                .metadata
+                .get(&self.key)
+                .map(|value| redacted_value(value, &key, Some(&secret)));
+            if let Some(value) = &redacted {
+                account.metadata.insert(self.key.clone(), value.clone());
+            }
+
+            let redaction = Redaction {
+                height: state_transaction.curr_block.height(),
+            };
+            state_transaction
+                .world
+                .redactions
+                .insert(key, Some(redaction));
+            if let Some(value) = redacted {
+                state_transaction
+                    .world
+                    .emit_events(Some(AccountEvent::MetadataInserted(MetadataChanged {
+                        target: self.account,
+                        key: self.key,
+                        value,
+                    })));
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for SetSpendingCounter {
+        #[metrics(+"set_spending_counter")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self { account, counter } = self;
+            state_transaction.world.account(&account)?;
+            state_transaction
+                .world
+                .asset_definition(&counter.asset_definition)?;
+
+            let mut counters = state_transaction
+                .world
+                .spending_counters
+                .get(&account)
+                .cloned()
+                .unwrap_or_default();
+            counters.retain(|existing| {
+                !existing.counts(
+                    &counter.asset_definition,
+                    counter.spender.as_ref(),
+                    counter.window,
+                )
+            });
+            counters.push(counter);
+            state_transaction
+                .world
+                .spending_counters
+                .insert(account, counters);
+
+            Ok(())
+        }
+    }
+
     impl Execute for Grant<Permission, Account> {
         #[metrics(+"grant_account_permission")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account_id = self.destination;
@@ -145,6 +262,12 @@ pub mod isi {
             state_transaction
                 .world
                 .add_account_permission(&account_id, permission.clone());
+            state_transaction.record_permission_grant(
+                authority,
+                account_id.clone().into(),
+                permission.clone(),
+                PermissionGrantAction::Grant,
+            );
 
             state_transaction
                 .world
@@ -163,7 +286,7 @@ pub mod isi {
         #[metrics(+"revoke_account_permission")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account_id = self.destination;
@@ -178,6 +301,12 @@ pub mod isi {
             {
                 return Err(FindError::Permission(permission.into()).into());
             }
+            state_transaction.record_permission_grant(
+                authority,
+                account_id.clone().into(),
+                permission.clone(),
+                PermissionGrantAction::Revoke,
+            );
 
             state_transaction
                 .world
@@ -192,6 +321,124 @@ pub mod isi {
         }
     }
 
+    impl Execute for Grant<PermissionGroup, Account> {
+        #[metrics(+"grant_account_permission_group")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.destination;
+            let group = self.object;
+
+            if group.permissions.is_empty() {
+                return Err(Error::InvariantViolation(
+                    "Permission group is empty".to_owned(),
+                ));
+            }
+
+            // Fails as a whole if any of the permissions can't be granted,
+            // in which case the transaction is rejected without applying any of them
+            for permission in group.permissions {
+                Grant::account_permission(permission, account_id.clone())
+                    .execute(authority, state_transaction)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Grant<RevocablePermission, Account> {
+        #[metrics(+"grant_account_revocable_permission")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.destination;
+            let RevocablePermission {
+                permission,
+                revoke_on,
+            } = self.object;
+
+            Grant::account_permission(permission.clone(), account_id.clone())
+                .execute(authority, state_transaction)?;
+            state_transaction.world.add_permission_revocation(
+                &account_id,
+                PermissionRevocation {
+                    permission,
+                    revoke_on,
+                    granted_by: authority.clone(),
+                },
+            );
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Revoke<PermissionGroup, Account> {
+        #[metrics(+"revoke_account_permission_group")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.destination;
+            let group = self.object;
+
+            if group.permissions.is_empty() {
+                return Err(Error::InvariantViolation(
+                    "Permission group is empty".to_owned(),
+                ));
+            }
+
+            // Fails as a whole if the account lacks any of the permissions
+            for permission in group.permissions {
+                Revoke::account_permission(permission, account_id.clone())
+                    .execute(authority, state_transaction)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RevokeAll {
+        #[metrics(+"revoke_all_account_permissions")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.destination;
+
+            // Check if account exists
+            state_transaction.world.account(&account_id)?;
+
+            let permissions = state_transaction
+                .world
+                .remove_account_permissions(&account_id);
+            for permission in &permissions {
+                state_transaction.record_permission_grant(
+                    authority,
+                    account_id.clone().into(),
+                    permission.clone(),
+                    PermissionGrantAction::Revoke,
+                );
+            }
+
+            state_transaction
+                .world
+                .emit_events(permissions.into_iter().map(|permission| {
+                    AccountEvent::PermissionRemoved(AccountPermissionChanged {
+                        account: account_id.clone(),
+                        permission,
+                    })
+                }));
+
+            Ok(())
+        }
+    }
+
     impl Execute for Grant<RoleId, Account> {
         #[metrics(+"grant_account_role")]
         fn execute(
@@ -298,6 +545,7 @@ pub mod isi {
 
 /// Implementations for account queries.
 pub mod query {
+    use std::{collections::BTreeMap, num::NonZeroUsize};
 
     use eyre::Result;
     use iroha_data_model::{
@@ -307,7 +555,11 @@ pub mod query {
     };
 
     use super::*;
-    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+    use crate::{
+        asset_movement,
+        smartcontracts::{ValidQuery, ValidSingularQuery},
+        state::StateReadOnly,
+    };
 
     impl ValidQuery for FindRolesByAccountId {
         #[metrics(+"find_roles_by_account_id")]
@@ -342,6 +594,35 @@ pub mod query {
         }
     }
 
+    impl ValidSingularQuery for FindPermissionGrantHistory {
+        #[metrics(+"find_permission_grant_history")]
+        fn execute(
+            &self,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<Vec<PermissionGrantEvent>, Error> {
+            let involves_account = |event: &PermissionGrantEvent| {
+                self.account.as_ref().is_none_or(|account| {
+                    event.author == *account
+                        || matches!(&event.target, PermissionHolder::Account(target) if target == account)
+                })
+            };
+            let has_name = |event: &PermissionGrantEvent| {
+                self.permission
+                    .as_ref()
+                    .is_none_or(|name| event.permission.name() == name)
+            };
+
+            Ok(state_ro
+                .world()
+                .permission_grants()
+                .iter()
+                .map(|(_, event)| event)
+                .filter(|event| involves_account(event) && has_name(event))
+                .cloned()
+                .collect())
+        }
+    }
+
     impl ValidQuery for FindAccounts {
         #[metrics(+"find_accounts")]
         fn execute(
@@ -384,4 +665,119 @@ pub mod query {
                 .map(|account| account.to_owned()))
         }
     }
+
+    /// Movements of a single asset of an account relative to the statement period.
+    #[derive(Default)]
+    struct PeriodMovements<'world> {
+        /// The last movement preceding the period.
+        preceding: Option<&'world AssetMovement>,
+        /// Movements within the period, oldest first.
+        within: Vec<AssetMovement>,
+        /// The first movement following the period.
+        following: Option<&'world AssetMovement>,
+    }
+
+    /// Balance of the asset right before `movement`.
+    fn balance_before(movement: &AssetMovement) -> Numeric {
+        match movement.direction() {
+            MovementDirection::Credit => movement.balance.checked_sub(movement.amount),
+            MovementDirection::Debit => movement.balance.checked_add(movement.amount),
+        }
+        .expect("INTERNAL BUG: movement amount is inconsistent with the balance")
+    }
+
+    /// Creation time of the earliest block whose asset movements are retained,
+    /// or `None` if no movements were pruned yet.
+    fn retained_movements_since_ms(state_ro: &impl StateReadOnly) -> Option<u64> {
+        let last_pruned = state_ro
+            .height()
+            .checked_sub(asset_movement::RETAINED_BLOCKS.try_into().ok()?)?;
+        let first_retained = NonZeroUsize::new(last_pruned)?.checked_add(1)?;
+        let block = state_ro.kura().get_block(first_retained)?;
+        block.header().creation_time().as_millis().try_into().ok()
+    }
+
+    impl ValidSingularQuery for FindAccountStatement {
+        #[metrics(+"find_account_statement")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<AccountStatement, Error> {
+            let account_id = &self.account;
+            state_ro.world().account(account_id)?;
+            // The period can't start before the movements which are still retained
+            let since_ms = retained_movements_since_ms(state_ro)
+                .map_or(self.since_ms, |retained_since_ms| {
+                    self.since_ms.max(retained_since_ms)
+                });
+
+            let mut movements = BTreeMap::<_, PeriodMovements>::new();
+            for (_, movement) in state_ro.world().account_asset_movements_iter(account_id) {
+                let entry = movements
+                    .entry(movement.asset_definition.clone())
+                    .or_default();
+                if movement.timestamp_ms < since_ms {
+                    entry.preceding = Some(movement);
+                } else if movement.timestamp_ms < self.until_ms {
+                    entry.within.push(movement.clone());
+                } else if entry.following.is_none() {
+                    entry.following = Some(movement);
+                }
+            }
+            // Assets which were not moved since the index was introduced
+            let current = state_ro
+                .world()
+                .assets_in_account_iter(account_id)
+                .map(|asset| (asset.id().definition.clone(), *asset.value))
+                .collect::<BTreeMap<_, _>>();
+            for definition in current.keys() {
+                movements.entry(definition.clone()).or_default();
+            }
+
+            let assets = movements
+                .into_iter()
+                .map(|(asset_definition, period)| {
+                    let opening_balance = period
+                        .preceding
+                        .map(|movement| movement.balance)
+                        .or_else(|| period.within.first().map(balance_before))
+                        .or_else(|| period.following.map(balance_before))
+                        .or_else(|| current.get(&asset_definition).copied())
+                        .unwrap_or(Numeric::ZERO);
+                    let closing_balance = period
+                        .within
+                        .last()
+                        .map_or(opening_balance, |movement| movement.balance);
+
+                    AssetStatement {
+                        asset_definition,
+                        opening_balance,
+                        movements: period.within,
+                        closing_balance,
+                    }
+                })
+                .filter(|statement| {
+                    !(statement.movements.is_empty() && statement.opening_balance.is_zero())
+                })
+                .collect();
+
+            Ok(AccountStatement {
+                account: account_id.clone(),
+                since_ms,
+                until_ms: self.until_ms,
+                assets,
+            })
+        }
+    }
+
+    impl ValidSingularQuery for FindSpendingCounters {
+        #[metrics(+"find_spending_counters")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<SpendingCounter>, Error> {
+            state_ro.world().account(&self.account)?;
+
+            Ok(state_ro
+                .world()
+                .spending_counters()
+                .get(&self.account)
+                .cloned()
+                .unwrap_or_default())
+        }
+    }
 }
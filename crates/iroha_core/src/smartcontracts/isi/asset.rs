@@ -41,6 +41,7 @@ pub mod isi {
             *quantity = quantity
                 .checked_add(self.object)
                 .ok_or(MathError::Overflow)?;
+            let balance = *quantity;
 
             #[allow(clippy::float_arithmetic)]
             {
@@ -53,11 +54,19 @@ pub mod isi {
                     .increase_asset_total_amount(&asset_id.definition, self.object)?;
             }
 
+            state_transaction.world.record_asset_movement(
+                &asset_id,
+                MovementDirection::Credit,
+                self.object,
+                balance,
+                None,
+            );
             state_transaction
                 .world
                 .emit_events(Some(AssetEvent::Added(AssetChanged {
                     asset: asset_id,
                     amount: self.object,
+                    balance,
                 })));
 
             Ok(())
@@ -86,8 +95,9 @@ pub mod isi {
             *quantity = quantity
                 .checked_sub(self.object)
                 .ok_or(MathError::NotEnoughQuantity)?;
+            let balance = *quantity;
 
-            if asset.value.is_zero() {
+            if balance.is_zero() {
                 assert!(state_transaction
                     .world
                     .assets
@@ -106,11 +116,19 @@ pub mod isi {
                     .decrease_asset_total_amount(&asset_id.definition, self.object)?;
             }
 
+            state_transaction.world.record_asset_movement(
+                &asset_id,
+                MovementDirection::Debit,
+                self.object,
+                balance,
+                None,
+            );
             state_transaction
                 .world
                 .emit_events(Some(AssetEvent::Removed(AssetChanged {
                     asset: asset_id.clone(),
                     amount: self.object,
+                    balance,
                 })));
 
             Ok(())
@@ -132,7 +150,7 @@ pub mod isi {
                 .asset_definition(&source_id.definition)?;
             assert_numeric_spec(&self.object, &asset_definition)?;
 
-            {
+            let source_balance = {
                 let asset = state_transaction
                     .world
                     .assets
@@ -142,24 +160,27 @@ pub mod isi {
                 *quantity = quantity
                     .checked_sub(self.object)
                     .ok_or(MathError::NotEnoughQuantity)?;
-                if asset.value.is_zero() {
+                let balance = *quantity;
+                if balance.is_zero() {
                     assert!(state_transaction
                         .world
                         .assets
                         .remove(source_id.clone())
                         .is_some());
                 }
-            }
+                balance
+            };
 
             let destination_asset = state_transaction
                 .world
                 .asset_or_insert(&destination_id, Numeric::ZERO)?;
-            {
+            let destination_balance = {
                 let quantity = &mut destination_asset.value;
                 *quantity = quantity
                     .checked_add(self.object)
                     .ok_or(MathError::Overflow)?;
-            }
+                *quantity
+            };
 
             #[allow(clippy::float_arithmetic)]
             #[cfg(feature = "telemetry")]
@@ -167,14 +188,30 @@ pub mod isi {
                 .telemetry
                 .observe_tx_amount(self.object.to_f64());
 
+            state_transaction.world.record_asset_movement(
+                &source_id,
+                MovementDirection::Debit,
+                self.object,
+                source_balance,
+                Some(destination_id.account.clone()),
+            );
+            state_transaction.world.record_asset_movement(
+                &destination_id,
+                MovementDirection::Credit,
+                self.object,
+                destination_balance,
+                Some(source_id.account.clone()),
+            );
             state_transaction.world.emit_events([
                 AssetEvent::Removed(AssetChanged {
                     asset: source_id,
                     amount: self.object,
+                    balance: source_balance,
                 }),
                 AssetEvent::Added(AssetChanged {
                     asset: destination_id,
                     amount: self.object,
+                    balance: destination_balance,
                 }),
             ]);
 
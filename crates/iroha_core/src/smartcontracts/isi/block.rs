@@ -8,7 +8,7 @@ use iroha_telemetry::metrics;
 use nonzero_ext::nonzero;
 
 use super::*;
-use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+use crate::{redaction::redacted_block, smartcontracts::ValidQuery, state::StateReadOnly};
 
 impl ValidQuery for FindBlocks {
     #[metrics(+"find_blocks")]
@@ -20,6 +20,7 @@ impl ValidQuery for FindBlocks {
         Ok(state_ro
             .all_blocks(nonzero!(1_usize))
             .rev()
+            .map(|block| redacted_block(block, state_ro))
             .filter(move |block| filter.applies(block))
             .map(|block| (*block).clone()))
     }
@@ -1,10 +1,59 @@
 //! This module contains [`Domain`] structure and related implementations and trait implementations.
 
+use derive_more::DebugCustom;
 use eyre::Result;
 use iroha_data_model::{prelude::*, query::error::FindError};
 use iroha_telemetry::metrics;
+use serde::{de::DeserializeSeed, Deserialize, Deserializer, Serialize, Serializer};
 
 use super::super::isi::prelude::*;
+use crate::{smartcontracts::wasm, state::deserialize::WasmSeed};
+
+/// Validator of a domain together with its compiled module.
+///
+/// Creating a [`wasmtime::Module`] is expensive, so we do it once on [`SetDomainValidator`]
+/// and reuse it for every instruction the validator checks.
+/// Serialized as the original [`WasmSmartContract`].
+#[derive(DebugCustom, Clone)]
+#[debug(fmt = "LoadedDomainValidator {{ module: <Module is truncated> }}")]
+pub struct LoadedDomainValidator {
+    original: WasmSmartContract,
+    module: wasmtime::Module,
+}
+
+impl LoadedDomainValidator {
+    fn load(
+        engine: &wasmtime::Engine,
+        original: WasmSmartContract,
+    ) -> Result<Self, wasm::error::Error> {
+        Ok(Self {
+            module: wasm::load_module(engine, &original)?,
+            original,
+        })
+    }
+}
+
+impl Serialize for LoadedDomainValidator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.original.serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for WasmSeed<'_, LoadedDomainValidator> {
+    type Value = LoadedDomainValidator;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let original = WasmSmartContract::deserialize(deserializer)?;
+
+        LoadedDomainValidator::load(self.engine, original).map_err(serde::de::Error::custom)
+    }
+}
 
 /// ISI module contains all instructions related to domains:
 /// - creating/changing assets
@@ -12,14 +61,19 @@ use super::super::isi::prelude::*;
 /// - update metadata
 /// - transfer, etc.
 pub mod isi {
+    use std::{collections::BTreeSet, time::Duration};
+
     use iroha_data_model::{
-        isi::error::{InstructionExecutionError, RepetitionError},
+        isi::error::{InstructionExecutionError, InvalidParameterError, RepetitionError},
         IntoKeyValue,
     };
     use iroha_logger::prelude::*;
 
     use super::*;
 
+    /// Period within which the account a domain is offered to has to accept the offer
+    pub const TRANSFER_OFFER_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
     impl Execute for Register<Account> {
         #[metrics(+"register_account")]
         fn execute(
@@ -57,6 +111,47 @@ pub mod isi {
         }
     }
 
+    impl Execute for RegisterAccounts {
+        #[metrics(+"register_accounts")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let mut events = Vec::with_capacity(self.objects.len());
+
+            for new_account in self.objects {
+                let account: Account = new_account.build(authority);
+                let (account_id, account_value) = account.clone().into_key_value();
+
+                if *account_id.domain() == *iroha_genesis::GENESIS_DOMAIN_ID {
+                    return Err(InstructionExecutionError::InvariantViolation(
+                        "Not allowed to register account in genesis domain".to_owned(),
+                    ));
+                }
+
+                let _domain = state_transaction.world.domain_mut(&account_id.domain)?;
+                if state_transaction.world.account(&account_id).is_ok() {
+                    return Err(RepetitionError {
+                        instruction: InstructionType::Register,
+                        id: IdBox::AccountId(account_id),
+                    }
+                    .into());
+                }
+                state_transaction
+                    .world
+                    .accounts
+                    .insert(account_id, account_value);
+
+                events.push(DomainEvent::Account(AccountEvent::Created(account)));
+            }
+
+            state_transaction.world.emit_events(events);
+
+            Ok(())
+        }
+    }
+
     impl Execute for Unregister<Account> {
         #[metrics(+"unregister_account")]
         fn execute(
@@ -88,8 +183,19 @@ pub mod isi {
                 .world
                 .account_permissions
                 .remove(account_id.clone());
+            state_transaction
+                .world
+                .permission_revocations
+                .remove(account_id.clone());
+            state_transaction
+                .world
+                .spending_counters
+                .remove(account_id.clone());
 
             state_transaction.world.remove_account_roles(&account_id);
+            state_transaction
+                .world
+                .remove_account_asset_movements(&account_id);
 
             let remove_assets: Vec<AssetId> = state_transaction
                 .world
@@ -353,17 +459,158 @@ pub mod isi {
                 )));
             }
 
-            domain.owned_by = destination.clone();
+            // Accounts can't accept offers in genesis, so the domain changes hands at once
+            if state_transaction.curr_block.is_genesis() {
+                domain.owned_by = destination.clone();
+                state_transaction
+                    .world
+                    .emit_events(Some(DomainEvent::OwnerChanged(DomainOwnerChanged {
+                        domain: object,
+                        new_owner: destination,
+                    })));
+
+                return Ok(());
+            }
+
+            let expires_at = state_transaction.curr_block.creation_time() + TRANSFER_OFFER_TTL;
+            let offer = DomainTransferOffer {
+                domain: object.clone(),
+                new_owner: destination,
+                expires_at_ms: expires_at
+                    .as_millis()
+                    .try_into()
+                    .expect("INTERNAL BUG: Unix time in milliseconds doesn't fit into u64"),
+            };
+            // A new offer replaces the previous one
+            state_transaction
+                .world
+                .domain_transfer_offers
+                .insert(object, offer.clone());
+            state_transaction
+                .world
+                .emit_events(Some(DomainEvent::TransferOffered(offer)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for AcceptDomainTransfer {
+        #[metrics(+"accept_domain_transfer")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self { domain: domain_id } = self;
+
+            let offer = state_transaction
+                .world
+                .domain_transfer_offers
+                .get(&domain_id)
+                .filter(|offer| offer.new_owner == *authority)
+                .cloned()
+                .ok_or_else(|| {
+                    Error::InvariantViolation(format!(
+                        "Domain {domain_id} isn't offered to {authority}"
+                    ))
+                })?;
+            let now_ms = state_transaction.curr_block.creation_time().as_millis();
+            if u128::from(offer.expires_at_ms) < now_ms {
+                return Err(Error::InvariantViolation(format!(
+                    "Offer of domain {domain_id} to {authority} has expired"
+                )));
+            }
+
+            state_transaction.world.domain_mut(&domain_id)?.owned_by = authority.clone();
+            state_transaction
+                .world
+                .domain_transfer_offers
+                .remove(domain_id.clone());
             state_transaction
                 .world
                 .emit_events(Some(DomainEvent::OwnerChanged(DomainOwnerChanged {
-                    domain: object,
-                    new_owner: destination,
+                    domain: domain_id,
+                    new_owner: authority.clone(),
                 })));
 
             Ok(())
         }
     }
+
+    impl Execute for SetDomainValidator {
+        #[metrics(+"set_domain_validator")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self { domain, validator } = self;
+
+            let _ = state_transaction.world.domain(&domain)?;
+            match validator {
+                Some(validator) => {
+                    // Replacing the validator drops the module compiled for the previous one
+                    let validator =
+                        LoadedDomainValidator::load(state_transaction.engine, validator).map_err(
+                            |err| domain_validator_error(err, "Invalid domain validator"),
+                        )?;
+                    state_transaction
+                        .world
+                        .domain_validators
+                        .insert(domain, validator);
+                }
+                None => {
+                    state_transaction.world.domain_validators.remove(domain);
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Run the validators of `domains`, if any, on the already executed `instruction`.
+    ///
+    /// Validators see the state after the instruction, so they may check its outcome.
+    pub(crate) fn validate_by_domain_validators(
+        instruction: &InstructionBox,
+        authority: &AccountId,
+        domains: BTreeSet<DomainId>,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        for domain in domains {
+            let Some(validator) = state_transaction.world.domain_validators.get(&domain) else {
+                continue;
+            };
+            let runtime = wasm::RuntimeBuilder::<
+                wasm::state::executor::ValidateDomainInstruction<'_, StateTransaction<'_, '_>>,
+            >::new()
+            .with_engine(state_transaction.engine.clone()) // Cloning engine is cheap, see [`wasmtime::Engine`] docs
+            .with_config(state_transaction.world.parameters().executor)
+            .build()
+            .map_err(|err| domain_validator_error(err, "Domain validator failed"))?;
+
+            runtime
+                .execute_domain_validator(
+                    state_transaction,
+                    authority,
+                    &validator.module,
+                    instruction.clone(),
+                    state_transaction.curr_block,
+                )
+                .map_err(|err| domain_validator_error(err, "Domain validator failed"))?
+                .map_err(|err| {
+                    Error::InvariantViolation(format!(
+                        "Validator of domain `{domain}` rejected the instruction: {err}"
+                    ))
+                })?;
+        }
+
+        Ok(())
+    }
+
+    fn domain_validator_error(err: wasm::error::Error, context: &'static str) -> Error {
+        InvalidParameterError::Wasm(format!("{:?}", eyre::eyre!(err).wrap_err(context))).into()
+    }
 }
 
 /// Implementations for domain queries.
@@ -34,6 +34,31 @@ impl Execute for InstructionBox {
     ) -> Result<(), Error> {
         iroha_logger::debug!(isi=%self, "Executing");
 
+        if state_transaction
+            .world
+            .domain_validators
+            .iter()
+            .next()
+            .is_none()
+        {
+            return self.execute_builtin(authority, state_transaction);
+        }
+
+        let events_before = state_transaction.world.emitted_event_count();
+        self.clone().execute_builtin(authority, state_transaction)?;
+        let domains = state_transaction
+            .world
+            .domains_with_events_since(events_before);
+        domain::isi::validate_by_domain_validators(&self, authority, domains, state_transaction)
+    }
+}
+
+impl InstructionBox {
+    fn execute_builtin(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
         match self {
             Self::Register(isi) => isi.execute(authority, state_transaction),
             Self::Unregister(isi) => isi.execute(authority, state_transaction),
@@ -48,6 +73,14 @@ impl Execute for InstructionBox {
             Self::SetParameter(isi) => isi.execute(authority, state_transaction),
             Self::Upgrade(isi) => isi.execute(authority, state_transaction),
             Self::Log(isi) => isi.execute(authority, state_transaction),
+            Self::Anchor(isi) => isi.execute(authority, state_transaction),
+            Self::InstantiateRoleTemplate(isi) => isi.execute(authority, state_transaction),
+            Self::SetDomainValidator(isi) => isi.execute(authority, state_transaction),
+            Self::MarkRedactable(isi) => isi.execute(authority, state_transaction),
+            Self::Redact(isi) => isi.execute(authority, state_transaction),
+            Self::AcceptDomainTransfer(isi) => isi.execute(authority, state_transaction),
+            Self::AbortExecutorUpgrade(isi) => isi.execute(authority, state_transaction),
+            Self::SetSpendingCounter(isi) => isi.execute(authority, state_transaction),
             Self::Custom(_) => {
                 panic!("Custom instructions should be handled in custom executor");
             }
@@ -70,6 +103,8 @@ impl Execute for RegisterBox {
             Self::Nft(isi) => isi.execute(authority, state_transaction),
             Self::Role(isi) => isi.execute(authority, state_transaction),
             Self::Trigger(isi) => isi.execute(authority, state_transaction),
+            Self::Accounts(isi) => isi.execute(authority, state_transaction),
+            Self::RoleTemplate(isi) => isi.execute(authority, state_transaction),
         }
     }
 }
@@ -89,6 +124,7 @@ impl Execute for UnregisterBox {
             Self::Nft(isi) => isi.execute(authority, state_transaction),
             Self::Role(isi) => isi.execute(authority, state_transaction),
             Self::Trigger(isi) => isi.execute(authority, state_transaction),
+            Self::RoleTemplate(isi) => isi.execute(authority, state_transaction),
         }
     }
 }
@@ -133,6 +169,7 @@ impl Execute for TransferBox {
             Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
             Self::Asset(isi) => isi.execute(authority, state_transaction),
             Self::Nft(isi) => isi.execute(authority, state_transaction),
+            Self::Role(isi) => isi.execute(authority, state_transaction),
         }
     }
 }
@@ -180,6 +217,9 @@ impl Execute for GrantBox {
             Self::Permission(sub_isi) => sub_isi.execute(authority, state_transaction),
             Self::Role(sub_isi) => sub_isi.execute(authority, state_transaction),
             Self::RolePermission(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::InheritedRole(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::PermissionGroup(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::RevocablePermission(sub_isi) => sub_isi.execute(authority, state_transaction),
         }
     }
 }
@@ -195,6 +235,9 @@ impl Execute for RevokeBox {
             Self::Permission(sub_isi) => sub_isi.execute(authority, state_transaction),
             Self::Role(sub_isi) => sub_isi.execute(authority, state_transaction),
             Self::RolePermission(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::AllPermissions(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::InheritedRole(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::PermissionGroup(sub_isi) => sub_isi.execute(authority, state_transaction),
         }
     }
 }
@@ -206,9 +249,9 @@ pub mod prelude {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{sync::Arc, time::Duration};
 
-    use iroha_crypto::KeyPair;
+    use iroha_crypto::{HashOf, KeyPair};
     use iroha_test_samples::{
         gen_account_in, ALICE_ID, SAMPLE_GENESIS_ACCOUNT_ID, SAMPLE_GENESIS_ACCOUNT_KEYPAIR,
     };
@@ -219,6 +262,7 @@ mod tests {
         block::ValidBlock,
         kura::Kura,
         query::store::LiveQueryStore,
+        smartcontracts::{ValidQuery, ValidSingularQuery},
         state::{State, World},
         tx::{AcceptTransactionFail, AcceptedTransaction},
     };
@@ -269,6 +313,506 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn register_accounts() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_ids = (0..3)
+            .map(|_| gen_account_in("wonderland").0)
+            .collect::<Vec<_>>();
+        Register::accounts(account_ids.iter().cloned().map(Account::new))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        state_transaction.apply();
+
+        // Batch is rejected if any of the accounts is already registered
+        let mut state_transaction = state_block.transaction();
+        let (new_account_id, _) = gen_account_in("wonderland");
+        let result = Register::accounts([
+            Account::new(new_account_id.clone()),
+            Account::new(account_ids[0].clone()),
+        ])
+        .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::Repetition(_))));
+        drop(state_transaction);
+        state_block.commit();
+
+        let state_view = state.view();
+        for account_id in &account_ids {
+            assert!(state_view.world.account(account_id).is_ok());
+        }
+        assert!(state_view.world.account(&new_account_id).is_err());
+        Ok(())
+    }
+
+    #[test]
+    async fn revoke_all_account_permissions() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let role_id: RoleId = "auditor".parse()?;
+        Register::role(
+            Role::new(role_id.clone(), ALICE_ID.clone())
+                .add_permission(Permission::new("CanAudit".to_owned(), Json::from(true))),
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+        Grant::account_role(role_id.clone(), ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        for name in ["CanMint", "CanBurn"] {
+            Grant::account_permission(
+                Permission::new(name.to_owned(), Json::from(true)),
+                ALICE_ID.clone(),
+            )
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        }
+        RevokeAll::account_permissions(ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        // Permissions granted through roles are kept
+        let state_view = state.view();
+        assert_eq!(
+            state_view
+                .world
+                .account_permissions_iter(&ALICE_ID)?
+                .count(),
+            0
+        );
+        assert!(state_view
+            .world
+            .account_roles_iter(&ALICE_ID)
+            .any(|account_role_id| *account_role_id == role_id));
+        Ok(())
+    }
+
+    #[test]
+    async fn permission_group_is_granted_atomically() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let [can_mint, can_burn] =
+            ["CanMint", "CanBurn"].map(|name| Permission::new(name.to_owned(), Json::from(true)));
+        let group = PermissionGroup::new([can_mint.clone(), can_burn.clone()]);
+        Grant::account_permission(can_burn.clone(), ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+
+        // Alice already has one of the permissions
+        let result = Grant::account_permission_group(group.clone(), ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::Repetition(_))));
+
+        Revoke::account_permission(can_burn.clone(), ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        Grant::account_permission_group(group.clone(), ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .account_has_permission(&ALICE_ID, &can_mint));
+        assert!(state_transaction
+            .world
+            .account_has_permission(&ALICE_ID, &can_burn));
+
+        Revoke::account_permission_group(group, ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let state_view = state.view();
+        assert_eq!(
+            state_view
+                .world
+                .account_permissions_iter(&ALICE_ID)?
+                .count(),
+            0
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn permission_grants_are_pruned() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let header_at = |height: u64| {
+            ValidBlock::new_dummy_and_modify_header(&KeyPair::random().into_parts().1, |header| {
+                header.height = height.try_into().unwrap();
+            })
+            .as_ref()
+            .header()
+        };
+        let can_mint = Permission::new("CanMint".to_owned(), Json::from(true));
+        let granted_at = 2;
+
+        let mut state_block = state.block(header_at(granted_at));
+        let mut state_transaction = state_block.transaction();
+        Grant::account_permission(can_mint, ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let last_retaining = header_at(granted_at + crate::permission_grant::RETAINED_BLOCKS - 1);
+        let mut state_block = state.block(last_retaining);
+        state_block.prune_permission_grants(&last_retaining);
+        state_block.commit();
+        assert_eq!(state.view().world.permission_grants().iter().count(), 1);
+
+        let first_pruning = header_at(granted_at + crate::permission_grant::RETAINED_BLOCKS);
+        let mut state_block = state.block(first_pruning);
+        state_block.prune_permission_grants(&first_pruning);
+        state_block.commit();
+        assert_eq!(state.view().world.permission_grants().iter().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    async fn asset_movements_are_pruned() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let header_at = |height: u64| {
+            ValidBlock::new_dummy_and_modify_header(&KeyPair::random().into_parts().1, |header| {
+                header.height = height.try_into().unwrap();
+            })
+            .as_ref()
+            .header()
+        };
+        let rose = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+        let mint_rose_at = |height: u64| -> Result<()> {
+            let mut state_block = state.block(header_at(height));
+            let mut state_transaction = state_block.transaction();
+            Mint::asset_numeric(1_u32, rose.clone())
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+            state_transaction.index_asset_movements(HashOf::from_untyped_unchecked(Hash::new(
+                height.to_le_bytes(),
+            )));
+            state_transaction.apply();
+            state_block.commit();
+            Ok(())
+        };
+        let movement_heights = || {
+            state
+                .view()
+                .world
+                .account_asset_movements_iter(&ALICE_ID)
+                .map(|(id, _)| id.height)
+                .collect::<Vec<_>>()
+        };
+        let retained = crate::asset_movement::RETAINED_BLOCKS;
+
+        mint_rose_at(2)?;
+        mint_rose_at(2 + retained - 1)?;
+        assert_eq!(movement_heights(), [2, 2 + retained - 1]);
+
+        mint_rose_at(2 + retained)?;
+        assert_eq!(movement_heights(), [2 + retained - 1, 2 + retained]);
+        Ok(())
+    }
+
+    #[test]
+    async fn role_inheritance() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let can_view = Permission::new("CanView".to_owned(), Json::from(true));
+        let [viewer, operator, admin]: [RoleId; 3] =
+            ["viewer".parse()?, "operator".parse()?, "admin".parse()?];
+        Register::role(
+            Role::new(viewer.clone(), ALICE_ID.clone()).add_permission(can_view.clone()),
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+        Register::role(Role::new(operator.clone(), ALICE_ID.clone()).inherit(viewer.clone()))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        Register::role(Role::new(admin.clone(), ALICE_ID.clone()))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        Grant::inherited_role(operator.clone(), admin.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        Grant::account_role(admin.clone(), ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .account_has_permission(&ALICE_ID, &can_view));
+
+        // Inheritance must not form cycles
+        let result = Grant::inherited_role(admin.clone(), viewer.clone())
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::InvariantViolation(_))));
+
+        // Unregistering a role removes it from the roles inheriting it
+        Unregister::role(operator.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let state_view = state.view();
+        assert_eq!(state_view.world.role(&admin)?.inherited_roles().count(), 0);
+        assert!(!state_view
+            .world
+            .account_has_permission(&ALICE_ID, &can_view));
+        Ok(())
+    }
+
+    #[test]
+    async fn anchor() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let tag: Name = "contracts".parse()?;
+        let [contract, invoice, receipt] = ["contract", "invoice", "receipt"].map(Hash::new);
+        Anchor::new(contract)
+            .with_tag(tag.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        Anchor::new(invoice)
+            .with_tag(tag.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        Anchor::new(receipt).execute(&ALICE_ID, &mut state_transaction)?;
+
+        // The first anchoring of a hash is never overwritten
+        let result =
+            Anchor::new(contract).execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::InvariantViolation(_))));
+        state_transaction.apply();
+        state_block.commit();
+
+        let state_view = state.view();
+        let find = |query| -> Result<Vec<AnchoredHash>> {
+            Ok(ValidQuery::execute(query, CompoundPredicate::PASS, &state_view)?.collect())
+        };
+        let anchors = find(FindAnchors::by_hash(contract))?;
+        assert_eq!(anchors.len(), 1);
+        assert_eq!(anchors[0].authority, *ALICE_ID);
+        assert_eq!(anchors[0].anchored_at(), block_header.creation_time());
+        assert_eq!(find(FindAnchors::by_tag(tag.clone()))?.len(), 2);
+        assert_eq!(find(FindAnchors::new(Some(receipt), Some(tag)))?.len(), 0);
+        assert_eq!(find(FindAnchors::default())?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    async fn domain_validator() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let domain_id: DomainId = "wonderland".parse()?;
+        let set_metadata = InstructionBox::from(SetKeyValue::domain(
+            domain_id.clone(),
+            "key".parse()?,
+            "value",
+        ));
+
+        let result = SetDomainValidator::new(
+            domain_id.clone(),
+            WasmSmartContract::from_compiled(vec![0, 1, 2, 3]),
+        )
+        .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::InvalidParameter(_))));
+
+        // A module without entrypoints fails validation of every instruction touching the domain
+        let empty_module = WasmSmartContract::from_compiled(b"\0asm\x01\0\0\0".to_vec());
+        SetDomainValidator::new(domain_id.clone(), empty_module.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        // The module is compiled once and stored with the original contract, which alone is persisted
+        let validator = state_transaction
+            .world
+            .domain_validators
+            .get(&domain_id)
+            .expect("validator was set");
+        assert_eq!(
+            serde_json::to_value(validator)?,
+            serde_json::to_value(&empty_module)?
+        );
+        let result = set_metadata
+            .clone()
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::InvalidParameter(_))));
+        InstructionBox::from(Register::domain(Domain::new("looking_glass".parse()?)))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+
+        SetDomainValidator::remove(domain_id.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .domain_validators
+            .get(&domain_id)
+            .is_none());
+        set_metadata.execute(&ALICE_ID, &mut state_transaction)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn role_template() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let template_id: RoleId = "domain_manager".parse()?;
+        let role_id: RoleId = "wonderland_manager".parse()?;
+        let template = RoleTemplate::new(template_id.clone()).add_permission(Permission::new(
+            "CanManageDomain".to_owned(),
+            Json::from_string_unchecked(r#"{"domain":"{domain}"}"#.to_owned()),
+        ));
+        Register::role_template(template.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        let result = Register::role_template(template).execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::Repetition(_))));
+
+        // Every placeholder must be given a value
+        let instantiate =
+            InstantiateRoleTemplate::new(template_id.clone(), role_id.clone(), ALICE_ID.clone());
+        let result = instantiate
+            .clone()
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::InvariantViolation(_))));
+
+        instantiate
+            .with_argument("domain".parse()?, "wonderland")
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        // Instantiated roles outlive their template
+        Unregister::role_template(template_id.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let state_view = state.view();
+        let can_manage_wonderland = Permission::new(
+            "CanManageDomain".to_owned(),
+            Json::from_string_unchecked(r#"{"domain":"wonderland"}"#.to_owned()),
+        );
+        assert!(state_view
+            .world
+            .account_has_permission(&ALICE_ID, &can_manage_wonderland));
+        assert!(state_view.world.role(&role_id).is_ok());
+        assert!(FindRoleTemplate::new(template_id)
+            .execute(&state_view)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    async fn account_statement() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let rose: AssetDefinitionId = "rose#wonderland".parse()?;
+        let alice_rose = AssetId::new(rose.clone(), ALICE_ID.clone());
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+
+        // Execute instructions in a block created at `creation_time_ms`, one block per second
+        let execute_block = |creation_time_ms: u64, instructions: Vec<InstructionBox>| {
+            let block_header = ValidBlock::new_dummy_and_modify_header(
+                &KeyPair::random().into_parts().1,
+                |header| {
+                    header.height = (creation_time_ms / 1000 + 1).try_into().unwrap();
+                    header.creation_time_ms = creation_time_ms;
+                },
+            )
+            .as_ref()
+            .header();
+            let mut state_block = state.block(block_header);
+            let mut state_transaction = state_block.transaction();
+            for instruction in instructions {
+                instruction.execute(&ALICE_ID, &mut state_transaction)?;
+            }
+            state_transaction.index_asset_movements(HashOf::from_untyped_unchecked(Hash::new(
+                creation_time_ms.to_le_bytes(),
+            )));
+            state_transaction.apply();
+            state_block.commit();
+            Ok::<_, Error>(())
+        };
+        execute_block(
+            1000,
+            vec![
+                Register::account(Account::new(bob_id.clone())).into(),
+                Mint::asset_numeric(10_u32, alice_rose.clone()).into(),
+            ],
+        )?;
+        execute_block(
+            2000,
+            vec![
+                Transfer::asset_numeric(alice_rose.clone(), 3_u32, bob_id.clone()).into(),
+                Burn::asset_numeric(2_u32, alice_rose.clone()).into(),
+            ],
+        )?;
+        execute_block(
+            3000,
+            vec![Mint::asset_numeric(5_u32, alice_rose.clone()).into()],
+        )?;
+
+        let state_view = state.view();
+        let statement =
+            FindAccountStatement::new(ALICE_ID.clone(), 1500, 3000).execute(&state_view)?;
+        assert_eq!(statement.assets.len(), 1);
+        let rose_statement = &statement.assets[0];
+        assert_eq!(rose_statement.asset_definition, rose);
+        assert_eq!(rose_statement.opening_balance, Numeric::from(10_u32));
+        assert_eq!(rose_statement.closing_balance, Numeric::from(5_u32));
+        let movements = rose_statement
+            .movements
+            .iter()
+            .map(|movement| {
+                (
+                    movement.direction(),
+                    movement.amount,
+                    movement.counterparty.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            movements,
+            [
+                (
+                    MovementDirection::Debit,
+                    Numeric::from(3_u32),
+                    Some(bob_id.clone())
+                ),
+                (MovementDirection::Debit, Numeric::from(2_u32), None),
+            ]
+        );
+        assert_eq!(
+            rose_statement.movements[0].timestamp(),
+            Duration::from_secs(2)
+        );
+
+        // Periods without movements carry the balance over
+        let statement =
+            FindAccountStatement::new(ALICE_ID.clone(), 3500, 4000).execute(&state_view)?;
+        assert!(statement.assets[0].movements.is_empty());
+        assert_eq!(statement.assets[0].opening_balance, Numeric::from(10_u32));
+        assert_eq!(statement.assets[0].closing_balance, Numeric::from(10_u32));
+
+        let statement =
+            FindAccountStatement::new(bob_id.clone(), 0, u64::MAX).execute(&state_view)?;
+        assert_eq!(statement.assets[0].opening_balance, Numeric::ZERO);
+        assert_eq!(statement.assets[0].closing_balance, Numeric::from(3_u32));
+        assert_eq!(
+            statement.assets[0].movements[0].counterparty,
+            Some(ALICE_ID.clone())
+        );
+        Ok(())
+    }
+
     #[test]
     async fn account_metadata() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
@@ -292,6 +836,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn spending_counters() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let rose: AssetDefinitionId = "rose#wonderland".parse()?;
+        let mut counter = SpendingCounter::new(rose.clone(), None, SpendingWindow::Day);
+        counter.limit = Some(10_u32.into());
+        SetSpendingCounter::new(ALICE_ID.clone(), counter.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        // Counters are replaced rather than accumulated
+        assert_eq!(
+            counter.spend(4_u32.into(), block_header.creation_time()),
+            Some(4_u32.into())
+        );
+        SetSpendingCounter::new(ALICE_ID.clone(), counter.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let result = SetSpendingCounter::new(
+            ALICE_ID.clone(),
+            SpendingCounter::new("tulip#wonderland".parse()?, None, SpendingWindow::Day),
+        )
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction);
+        assert!(matches!(result, Err(Error::Find(_))));
+        state_transaction.apply();
+        state_block.commit();
+
+        let counters = FindSpendingCounters::new(ALICE_ID.clone()).execute(&state.view())?;
+        assert_eq!(counters, vec![counter]);
+        Ok(())
+    }
+
     #[test]
     async fn asset_definition_metadata() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
@@ -9,10 +9,13 @@ use super::prelude::*;
 /// - update metadata
 /// - transfer, etc.
 pub mod isi {
+    use std::collections::BTreeSet;
+
     use iroha_data_model::{isi::error::RepetitionError, query::error::FindError, IntoKeyValue};
     use iroha_telemetry::metrics;
 
     use super::*;
+    use crate::nft_content_index::content_trigrams;
 
     impl Execute for Register<Nft> {
         #[metrics(+"register_nft")]
@@ -36,6 +39,11 @@ pub mod isi {
                 .domain(&nft_id.domain)
                 .expect("INTERNAL BUG: Can't find domain of NFT to register");
 
+            state_transaction.world.reindex_nft_content(
+                &nft_id,
+                &BTreeSet::new(),
+                &content_trigrams(&nft_value.content),
+            );
             state_transaction.world.nfts.insert(nft_id, nft_value);
 
             state_transaction
@@ -55,11 +63,16 @@ pub mod isi {
         ) -> Result<(), Error> {
             let nft_id = self.object;
 
-            state_transaction
+            let nft = state_transaction
                 .world
                 .nfts
                 .remove(nft_id.clone())
                 .ok_or_else(|| FindError::Nft(nft_id.clone()))?;
+            state_transaction.world.reindex_nft_content(
+                &nft_id,
+                &content_trigrams(&nft.content),
+                &BTreeSet::new(),
+            );
             state_transaction
                 .world
                 .domain(&nft_id.domain)
@@ -82,11 +95,13 @@ pub mod isi {
         ) -> Result<(), Error> {
             let nft_id = self.object;
 
+            let nft = state_transaction.world.nft_mut(&nft_id)?;
+            let prev_trigrams = content_trigrams(&nft.content);
+            nft.content.insert(self.key.clone(), self.value.clone());
+            let next_trigrams = content_trigrams(&nft.content);
             state_transaction
                 .world
-                .nft_mut(&nft_id)
-                .map_err(Error::from)
-                .map(|nft| nft.content.insert(self.key.clone(), self.value.clone()))?;
+                .reindex_nft_content(&nft_id, &prev_trigrams, &next_trigrams);
 
             state_transaction
                 .world
@@ -109,11 +124,16 @@ pub mod isi {
         ) -> Result<(), Error> {
             let nft_id = self.object;
 
-            let value = state_transaction.world.nft_mut(&nft_id).and_then(|nft| {
-                nft.content
-                    .remove(&self.key)
-                    .ok_or_else(|| FindError::MetadataKey(self.key.clone()))
-            })?;
+            let nft = state_transaction.world.nft_mut(&nft_id)?;
+            let prev_trigrams = content_trigrams(&nft.content);
+            let value = nft
+                .content
+                .remove(&self.key)
+                .ok_or_else(|| FindError::MetadataKey(self.key.clone()))?;
+            let next_trigrams = content_trigrams(&nft.content);
+            state_transaction
+                .world
+                .reindex_nft_content(&nft_id, &prev_trigrams, &next_trigrams);
 
             state_transaction
                 .world
@@ -166,11 +186,20 @@ pub mod isi {
 
 /// NFT-related query implementations.
 pub mod query {
+    use std::collections::BTreeSet;
+
     use eyre::Result;
-    use iroha_data_model::query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error};
+    use iroha_data_model::{
+        nft::NftEntry,
+        query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error},
+    };
 
     use super::*;
-    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+    use crate::{
+        nft_content_index::{trigrams, UNINDEXED},
+        smartcontracts::ValidQuery,
+        state::{StateReadOnly, WorldReadOnly},
+    };
 
     impl ValidQuery for FindNfts {
         #[metrics(+"find_nfts")]
@@ -179,11 +208,58 @@ pub mod query {
             filter: CompoundPredicate<Nft>,
             state_ro: &impl StateReadOnly,
         ) -> Result<impl Iterator<Item = Nft>, Error> {
-            Ok(state_ro
-                .world()
-                .nfts_iter()
+            let world = state_ro.world();
+            // Narrow the NFTs down with the index of their contents when the filter allows it
+            let nfts: Box<dyn Iterator<Item = NftEntry<'_>> + '_> =
+                match required_content_substring(&filter)
+                    .and_then(|substring| nfts_containing(world, substring))
+                {
+                    Some(candidates) => {
+                        Box::new(candidates.into_iter().filter_map(|id| world.nft(id).ok()))
+                    }
+                    None => Box::new(world.nfts_iter()),
+                };
+
+            Ok(nfts
                 .filter(move |nft| filter.applies_to_entry(nft))
                 .map(|nft| nft.to_owned()))
         }
     }
+
+    /// Substring which a string value in the content of every NFT satisfying `filter` contains, if any.
+    fn required_content_substring(filter: &CompoundPredicate<Nft>) -> Option<&str> {
+        match filter {
+            CompoundPredicate::Atom(NftProjection::Metadata(content)) => {
+                content.required_substring()
+            }
+            CompoundPredicate::And(filters) => filters
+                .iter()
+                .filter_map(required_content_substring)
+                .max_by_key(|substring| substring.chars().count()),
+            _ => None,
+        }
+    }
+
+    /// IDs of NFTs which may contain `substring` in a string value of their content,
+    /// or `None` if the substring is too short to be looked up in the index.
+    ///
+    /// NFTs whose content has too many trigrams to index are always included.
+    fn nfts_containing<'world>(
+        world: &'world impl WorldReadOnly,
+        substring: &str,
+    ) -> Option<BTreeSet<&'world NftId>> {
+        let mut substring_trigrams = trigrams(substring);
+        let first: BTreeSet<_> = world
+            .nfts_with_trigram(substring_trigrams.next()?)
+            .collect();
+
+        let mut candidates = substring_trigrams.fold(first, |candidates, trigram| {
+            world
+                .nfts_with_trigram(trigram)
+                .filter(|id| candidates.contains(id))
+                .collect()
+        });
+        candidates.extend(world.nfts_with_trigram(UNINDEXED));
+        Some(candidates)
+    }
 }
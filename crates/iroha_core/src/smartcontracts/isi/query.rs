@@ -1,6 +1,6 @@
 //! Query functionality. The common error type is also defined here,
 //! alongside functions for converting them into HTTP responses.
-use std::cmp::Ordering;
+use std::{cmp::Ordering, num::NonZeroU64};
 
 use eyre::Result;
 use iroha_data_model::{
@@ -8,15 +8,20 @@ use iroha_data_model::{
     query::{
         dsl::{EvaluateSelector, HasProjection, SelectorMarker},
         error::QueryExecutionFail as Error,
-        parameters::QueryParams,
-        CommittedTransaction, QueryBox, QueryOutputBatchBox, QueryRequest,
-        QueryRequestWithAuthority, QueryResponse, SingularQueryBox, SingularQueryOutputBox,
+        parameters::{FetchSize, QueryParams, SortOrder, MAX_FETCH_SIZE},
+        AggregateQuery, Aggregation, CommittedTransaction, ExcludedTransaction, QueryBox,
+        QueryOutputBatchBox, QueryOutputBatchBoxTuple, QueryRequest, QueryRequestWithAuthority,
+        QueryResponse, QueryWithParams, SingularQueryBox, SingularQueryOutputBox,
     },
 };
 
 use crate::{
     prelude::ValidSingularQuery,
-    query::{cursor::ErasedQueryIterator, pagination::Paginate as _, store::LiveQueryStoreHandle},
+    query::{
+        cursor::{evaluate_selector_tuple, ErasedQueryIterator},
+        pagination::Paginate as _,
+        store::LiveQueryStoreHandle,
+    },
     smartcontracts::{wasm, ValidQuery},
     state::{StateReadOnly, WorldReadOnly},
 };
@@ -77,6 +82,12 @@ impl SortableQueryOutput for CommittedTransaction {
     }
 }
 
+impl SortableQueryOutput for ExcludedTransaction {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
 impl SortableQueryOutput for PeerId {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
@@ -89,6 +100,12 @@ impl SortableQueryOutput for Permission {
     }
 }
 
+impl SortableQueryOutput for AnchoredHash {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
 impl SortableQueryOutput for Trigger {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
@@ -121,6 +138,7 @@ impl SortableQueryOutput for iroha_data_model::block::BlockHeader {
 pub fn apply_query_postprocessing<I>(
     iter: I,
     selector: SelectorTuple<I::Item>,
+    sort_key: Option<SelectorTuple<I::Item>>,
     &QueryParams {
         pagination,
         ref sorting,
@@ -142,25 +160,41 @@ where
     }
 
     // sort & paginate, erase the iterator with QueryBatchedErasedIterator
-    let output = if let Some(key) = &sorting.sort_by_metadata_key {
-        // if sorting was requested, we need to retrieve all the results first
-        let mut pairs: Vec<(Option<Json>, I::Item)> = iter
-            .map(|value| {
-                let key = value.get_metadata_sorting_key(key);
-                (key, value)
-            })
-            .collect();
-        pairs.sort_by(
-            |(left_key, _), (right_key, _)| match (left_key, right_key) {
-                (Some(l), Some(r)) => l.cmp(r),
-                (Some(_), None) => Ordering::Less,
-                (None, Some(_)) => Ordering::Greater,
-                (None, None) => Ordering::Equal,
+    let output = if let Some(sort_key) = &sort_key {
+        // a field that can't be selected from an output (e.g. a missing metadata key) makes its key missing
+        let sorted = sort_query_output(
+            iter,
+            |value| {
+                sort_key
+                    .iter()
+                    .map(|field| field.project_clone(core::iter::once(value)).ok())
+                    .collect::<Option<Vec<_>>>()
+            },
+            |left, right| {
+                left.iter()
+                    .zip(right)
+                    .fold(Ordering::Equal, |ordering, (l, r)| {
+                        ordering.then_with(|| compare_sort_fields(l, r))
+                    })
             },
+            sorting.order,
         );
 
         ErasedQueryIterator::new(
-            pairs.into_iter().map(|(_, val)| val).paginate(pagination),
+            sorted.into_iter().paginate(pagination),
+            selector,
+            fetch_size,
+        )
+    } else if let Some(key) = &sorting.sort_by_metadata_key {
+        let sorted = sort_query_output(
+            iter,
+            |value| value.get_metadata_sorting_key(key),
+            Json::cmp,
+            sorting.order,
+        );
+
+        ErasedQueryIterator::new(
+            sorted.into_iter().paginate(pagination),
             selector,
             fetch_size,
         )
@@ -182,6 +216,61 @@ where
     Ok(output)
 }
 
+/// Sorts the query output by a key, placing the outputs without one last.
+///
+/// Since the key of every output has to be known, all the results are retrieved first.
+fn sort_query_output<T, K>(
+    iter: impl Iterator<Item = T>,
+    mut key: impl FnMut(&T) -> Option<K>,
+    cmp: impl Fn(&K, &K) -> Ordering,
+    order: SortOrder,
+) -> Vec<T> {
+    let mut pairs: Vec<(Option<K>, T)> = iter.map(|value| (key(&value), value)).collect();
+    pairs.sort_by(
+        |(left_key, _), (right_key, _)| match (left_key, right_key) {
+            (Some(l), Some(r)) => match order {
+                SortOrder::Ascending => cmp(l, r),
+                SortOrder::Descending => cmp(r, l),
+            },
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+    );
+
+    pairs.into_iter().map(|(_, value)| value).collect()
+}
+
+/// Compares a field selected from two query outputs for sorting.
+///
+/// Fields without a natural order (e.g. whole entities) compare as equal, keeping the outputs in their original order.
+fn compare_sort_fields(left: &QueryOutputBatchBox, right: &QueryOutputBatchBox) -> Ordering {
+    macro_rules! compare {
+        ($($variant:ident),* $(,)?) => {
+            match (left, right) {
+                $((QueryOutputBatchBox::$variant(l), QueryOutputBatchBox::$variant(r)) => l.cmp(r),)*
+                _ => Ordering::Equal,
+            }
+        };
+    }
+
+    compare!(
+        PublicKey,
+        String,
+        Json,
+        Numeric,
+        Name,
+        DomainId,
+        AccountId,
+        AssetId,
+        AssetDefinitionId,
+        NftId,
+        Peer,
+        RoleId,
+        TriggerId,
+    )
+}
+
 /// Query Request statefully validated on the Iroha node side.
 #[derive(Debug, Clone)]
 pub struct ValidQueryRequest(QueryRequest);
@@ -229,7 +318,6 @@ impl ValidQueryRequest {
     /// # Errors
     ///
     /// Returns an error if the query execution fails.
-    #[allow(clippy::too_many_lines)] // not much we can do, we _need_ to list all the box types here
     pub fn execute(
         self,
         live_query_store: &LiveQueryStoreHandle,
@@ -245,94 +333,36 @@ impl ValidQueryRequest {
                     SingularQueryBox::FindParameters(q) => {
                         SingularQueryOutputBox::from(q.execute(state)?)
                     }
+                    SingularQueryBox::FindTriggerLoops(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindAccountStatement(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindRoleTemplate(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::IsTransactionKnown(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindPermissionGrantHistory(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindPermissionSchemas(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::Aggregate(q) => {
+                        SingularQueryOutputBox::from(aggregate(q, state)?)
+                    }
+                    SingularQueryBox::FindSpendingCounters(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
                 };
 
                 Ok(QueryResponse::Singular(output))
             }
             QueryRequest::Start(iter_query) => {
-                let output = match iter_query.query {
-                    // dispatch on a concrete query type, erasing the type with `QueryBatchedErasedIterator` in the end
-                    QueryBox::FindDomains(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindAccounts(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindAssets(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindAssetsDefinitions(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindNfts(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindRoles(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindRoleIds(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindPermissionsByAccountId(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindRolesByAccountId(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindAccountsWithAsset(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindPeers(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindActiveTriggerIds(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindTriggers(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindTransactions(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindBlocks(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindBlockHeaders(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                };
+                let output = start_iter_query(iter_query, state)?;
 
                 Ok(QueryResponse::Iterable(
                     live_query_store.handle_iter_start(output, authority)?,
@@ -345,6 +375,286 @@ impl ValidQueryRequest {
     }
 }
 
+/// Processing of the results of an iterable query, whatever their type.
+trait ProcessQueryOutput {
+    type Output;
+
+    fn process<I>(
+        self,
+        iter: I,
+        selector: SelectorTuple<I::Item>,
+        sort_key: Option<SelectorTuple<I::Item>>,
+        params: &QueryParams,
+    ) -> Result<Self::Output, Error>
+    where
+        I: Iterator<Item: SortableQueryOutput + Send + Sync + 'static>,
+        I::Item: HasProjection<SelectorMarker, AtomType = ()> + 'static,
+        <I::Item as HasProjection<SelectorMarker>>::Projection:
+            EvaluateSelector<I::Item> + Send + Sync,
+        QueryOutputBatchBox: From<Vec<I::Item>>;
+}
+
+/// Results of an iterable query erased into batches sent to the client.
+struct Batched;
+
+impl ProcessQueryOutput for Batched {
+    type Output = ErasedQueryIterator;
+
+    fn process<I>(
+        self,
+        iter: I,
+        selector: SelectorTuple<I::Item>,
+        sort_key: Option<SelectorTuple<I::Item>>,
+        params: &QueryParams,
+    ) -> Result<Self::Output, Error>
+    where
+        I: Iterator<Item: SortableQueryOutput + Send + Sync + 'static>,
+        I::Item: HasProjection<SelectorMarker, AtomType = ()> + 'static,
+        <I::Item as HasProjection<SelectorMarker>>::Projection:
+            EvaluateSelector<I::Item> + Send + Sync,
+        QueryOutputBatchBox: From<Vec<I::Item>>,
+    {
+        apply_query_postprocessing(iter, selector, sort_key, params)
+    }
+}
+
+/// Start an iterable query, erasing the type of its results.
+fn start_iter_query(
+    iter_query: QueryWithParams,
+    state: &impl StateReadOnly,
+) -> Result<ErasedQueryIterator, Error> {
+    process_iter_query(iter_query, state, Batched)
+}
+
+/// Execute an iterable query and `process` its results.
+#[allow(clippy::too_many_lines)] // not much we can do, we _need_ to list all the box types here
+fn process_iter_query<P: ProcessQueryOutput>(
+    iter_query: QueryWithParams,
+    state: &impl StateReadOnly,
+    process: P,
+) -> Result<P::Output, Error> {
+    let output = match iter_query.query {
+        // dispatch on a concrete query type, erasing the type with `QueryBatchedErasedIterator` in the end
+        QueryBox::FindDomains(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindAccounts(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindAssets(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindAssetsDefinitions(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindNfts(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindRoles(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindRoleIds(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindPermissionsByAccountId(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindRolesByAccountId(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindAccountsWithAsset(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindPeers(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindActiveTriggerIds(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindTriggers(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindTransactions(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindBlocks(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindBlockHeaders(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindAnchors(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+        QueryBox::FindExcludedTransactions(q) => process.process(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            q.sort_key,
+            &iter_query.params,
+        )?,
+    };
+
+    Ok(output)
+}
+
+/// Reduce the results of an iterable query to a single value.
+fn aggregate(
+    AggregateQuery { query, aggregation }: AggregateQuery,
+    state: &impl StateReadOnly,
+) -> Result<Option<Numeric>, Error> {
+    process_iter_query(query, state, aggregation)
+}
+
+impl ProcessQueryOutput for Aggregation {
+    type Output = Option<Numeric>;
+
+    /// The results are folded as they are found, a batch of them at a time, unless a page of
+    /// sorted results is requested, which can only be found once all of them are sorted.
+    fn process<I>(
+        self,
+        iter: I,
+        selector: SelectorTuple<I::Item>,
+        sort_key: Option<SelectorTuple<I::Item>>,
+        params: &QueryParams,
+    ) -> Result<Self::Output, Error>
+    where
+        I: Iterator<Item: SortableQueryOutput + Send + Sync + 'static>,
+        I::Item: HasProjection<SelectorMarker, AtomType = ()> + 'static,
+        <I::Item as HasProjection<SelectorMarker>>::Projection:
+            EvaluateSelector<I::Item> + Send + Sync,
+        QueryOutputBatchBox: From<Vec<I::Item>>,
+    {
+        let is_sorted = sort_key.is_some() || params.sorting.sort_by_metadata_key.is_some();
+        let is_paginated = params.pagination.offset > 0 || params.pagination.limit.is_some();
+        if is_sorted && is_paginated {
+            let mut params = params.clone();
+            params.fetch_size = FetchSize::new(Some(MAX_FETCH_SIZE));
+            let mut results = apply_query_postprocessing(iter, selector, sort_key, &params)?;
+            if self == Aggregation::Count {
+                return Ok(Some(Numeric::from(results.remaining())));
+            }
+
+            let mut output = Fold::new(self);
+            let mut cursor = Some(0);
+            while let Some(curr_cursor) = cursor {
+                let (batch, next_cursor) = results.next_batch(curr_cursor)?;
+                output.add(batch)?;
+                cursor = next_cursor.map(NonZeroU64::get);
+            }
+            return Ok(output.finish());
+        }
+
+        let mut iter = iter.paginate(params.pagination);
+        if self == Aggregation::Count {
+            return Ok(Some(Numeric::from(iter.count() as u64)));
+        }
+
+        let batch_size = usize::try_from(MAX_FETCH_SIZE.get()).expect("u64 should fit into usize");
+        let mut output = Fold::new(self);
+        loop {
+            let batch = iter.by_ref().take(batch_size).collect::<Vec<_>>();
+            if batch.is_empty() {
+                return Ok(output.finish());
+            }
+            output.add(evaluate_selector_tuple(batch, &selector)?)?;
+        }
+    }
+}
+
+/// Value of an [`Aggregation`] other than [`Aggregation::Count`] folded batch by batch.
+struct Fold {
+    aggregation: Aggregation,
+    output: Option<Numeric>,
+}
+
+impl Fold {
+    fn new(aggregation: Aggregation) -> Self {
+        Self {
+            aggregation,
+            output: (aggregation == Aggregation::Sum).then_some(Numeric::ZERO),
+        }
+    }
+
+    fn add(&mut self, batch: QueryOutputBatchBoxTuple) -> Result<(), Error> {
+        let mut batch = batch.into_iter();
+        let (Some(QueryOutputBatchBox::Numeric(values)), None) = (batch.next(), batch.next())
+        else {
+            return Err(Error::Conversion(
+                "Only a single numeric field can be aggregated".to_owned(),
+            ));
+        };
+
+        for value in values {
+            self.output = Some(match (self.aggregation, self.output.take()) {
+                (_, None) => value,
+                (Aggregation::Sum, Some(sum)) => {
+                    sum.checked_add(value).ok_or(Error::AggregateOverflow)?
+                }
+                (Aggregation::Min, Some(min)) => value.min(min),
+                (Aggregation::Max, Some(max)) => value.max(max),
+                (Aggregation::Count, Some(_)) => unreachable!("count is never folded"),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Option<Numeric> {
+        self.output
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use iroha_crypto::{Hash, KeyPair};
@@ -411,7 +721,7 @@ mod tests {
             let peer_id = PeerId::new(peer_public_key);
             let topology = Topology::new(vec![peer_id]);
             let unverified_first_block = BlockBuilder::new(transactions.clone())
-                .chain(0, state.view().latest_block().as_deref())
+                .chain(0, &state.view())
                 .sign(&peer_private_key)
                 .unpack(|_| {});
             let mut state_block = state.block(unverified_first_block.header());
@@ -428,7 +738,7 @@ mod tests {
 
             for _ in 1u64..blocks {
                 let unverified_block = BlockBuilder::new(transactions.clone())
-                    .chain(0, state.view().latest_block().as_deref())
+                    .chain(0, &state.view())
                     .sign(&peer_private_key)
                     .unpack(|_| {});
                 let mut state_block = state.block(unverified_block.header());
@@ -564,7 +874,7 @@ mod tests {
         let peer_id = PeerId::new(peer_public_key);
         let topology = Topology::new(vec![peer_id]);
         let unverified_block = BlockBuilder::new(vec![va_tx.clone()])
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(0, &state.view())
             .sign(ALICE_KEYPAIR.private_key())
             .unpack(|_| {});
         let mut state_block = state.block(unverified_block.header());
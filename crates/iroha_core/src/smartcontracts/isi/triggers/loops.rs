@@ -0,0 +1,389 @@
+//! Static detection of data triggers which may keep triggering each other.
+//!
+//! A trigger executing instructions is assumed to emit the events its asset and metadata
+//! instructions emit on success, and to invoke every data trigger whose filter accepts
+//! one of these events. WASM triggers are skipped, since their effects aren't known
+//! without running them.
+
+use std::collections::VecDeque;
+
+use iroha_data_model::{events::EventFilter, prelude::*};
+use iroha_primitives::json::Json;
+use mv::storage::StorageReadOnly;
+
+use super::{
+    set::{ExecutableRef, SetReadOnly},
+    specialized::LoadedAction,
+};
+
+/// Data trigger as seen by the analysis
+struct Node {
+    id: TriggerId,
+    filter: DataEventFilter,
+    events: Vec<DataEvent>,
+    bounded: bool,
+}
+
+impl Node {
+    fn new(
+        id: TriggerId,
+        filter: DataEventFilter,
+        repeats: Repeats,
+        instructions: Option<&[InstructionBox]>,
+    ) -> Self {
+        Self {
+            id,
+            filter,
+            events: instructions
+                .into_iter()
+                .flatten()
+                .flat_map(emitted_events)
+                .collect(),
+            bounded: matches!(repeats, Repeats::Exactly(_)),
+        }
+    }
+
+    fn loaded(id: &TriggerId, action: &LoadedAction<DataEventFilter>) -> Self {
+        let instructions = match &action.executable {
+            ExecutableRef::Instructions(instructions) => Some(&instructions[..]),
+            ExecutableRef::Wasm(_) => None,
+        };
+        Self::new(
+            id.clone(),
+            action.filter.clone(),
+            action.repeats,
+            instructions,
+        )
+    }
+}
+
+/// Graph of data triggers with an edge from each trigger to the triggers it may invoke
+struct Graph {
+    nodes: Vec<Node>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    fn new(nodes: Vec<Node>) -> Self {
+        let mut successors = vec![Vec::new(); nodes.len()];
+        let mut predecessors = vec![Vec::new(); nodes.len()];
+        for (from, source) in nodes.iter().enumerate() {
+            for (to, target) in nodes.iter().enumerate() {
+                if source
+                    .events
+                    .iter()
+                    .any(|event| target.filter.matches(event))
+                {
+                    successors[from].push(to);
+                    predecessors[to].push(from);
+                }
+            }
+        }
+
+        Self {
+            nodes,
+            successors,
+            predecessors,
+        }
+    }
+
+    /// Nodes of `subgraph` reachable from `start` in one or more steps
+    fn reachable(
+        &self,
+        start: usize,
+        edges: &[Vec<usize>],
+        subgraph: &impl Fn(usize) -> bool,
+    ) -> Vec<bool> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            for &next in &edges[node] {
+                if subgraph(next) && !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        visited
+    }
+
+    /// Nodes of `subgraph` on a common cycle with `start`, if there is one
+    fn component(&self, start: usize, subgraph: &impl Fn(usize) -> bool) -> Option<Vec<usize>> {
+        let forward = self.reachable(start, &self.successors, subgraph);
+        if !forward[start] {
+            return None;
+        }
+        let backward = self.reachable(start, &self.predecessors, subgraph);
+
+        Some(
+            (0..self.nodes.len())
+                .filter(|&node| forward[node] && backward[node])
+                .collect(),
+        )
+    }
+
+    fn is_endless(&self, start: usize) -> bool {
+        !self.nodes[start].bounded
+            && self
+                .component(start, &|node| !self.nodes[node].bounded)
+                .is_some()
+    }
+
+    fn to_loop(&self, component: &[usize]) -> TriggerLoop {
+        let mut triggers: Vec<_> = component
+            .iter()
+            .map(|&node| self.nodes[node].id.clone())
+            .collect();
+        triggers.sort();
+
+        TriggerLoop {
+            triggers,
+            bounded: !component.iter().any(|&node| self.is_endless(node)),
+        }
+    }
+
+    fn loops(&self) -> Vec<TriggerLoop> {
+        let mut assigned = vec![false; self.nodes.len()];
+        let mut loops = Vec::new();
+        for start in 0..self.nodes.len() {
+            if assigned[start] {
+                continue;
+            }
+            if let Some(component) = self.component(start, &|_| true) {
+                for &node in &component {
+                    assigned[node] = true;
+                }
+                loops.push(self.to_loop(&component));
+            }
+        }
+        loops.sort();
+        loops
+    }
+}
+
+fn data_triggers(triggers: &impl SetReadOnly) -> impl Iterator<Item = Node> + '_ {
+    triggers
+        .data_triggers()
+        .iter()
+        .map(|(id, action)| Node::loaded(id, action))
+}
+
+/// Find all loops among registered data triggers.
+///
+/// A loop is reported as bounded unless some of its triggers repeating indefinitely
+/// may keep invoking each other on their own.
+pub fn find_loops(triggers: &impl SetReadOnly) -> Vec<TriggerLoop> {
+    Graph::new(data_triggers(triggers).collect()).loops()
+}
+
+/// Find the endless loop the data trigger being registered would take part in.
+///
+/// Loops including a trigger which fires a limited number of times are allowed,
+/// as they stop eventually.
+pub fn find_endless_loop(
+    triggers: &impl SetReadOnly,
+    id: &TriggerId,
+    filter: &DataEventFilter,
+    action: &Action,
+) -> Option<TriggerLoop> {
+    let instructions = match &action.executable {
+        Executable::Instructions(instructions) => Some(&instructions[..]),
+        Executable::Wasm(_) => None,
+    };
+    let candidate = Node::new(id.clone(), filter.clone(), action.repeats, instructions);
+    if candidate.bounded || candidate.events.is_empty() {
+        return None;
+    }
+
+    let mut nodes = vec![candidate];
+    nodes.extend(data_triggers(triggers).filter(|node| node.id != *id));
+    let graph = Graph::new(nodes);
+
+    graph
+        .component(0, &|node| !graph.nodes[node].bounded)
+        .map(|component| graph.to_loop(&component))
+}
+
+/// Resulting balance isn't known statically, but data event filters don't look at it
+fn asset_changed(asset: AssetId, amount: Numeric) -> AssetChanged {
+    AssetChanged {
+        asset,
+        amount,
+        balance: Numeric::ZERO,
+    }
+}
+
+/// Events `instruction` emits when it succeeds, as far as they are relevant to data triggers
+fn emitted_events(instruction: &InstructionBox) -> Vec<DataEvent> {
+    match instruction {
+        InstructionBox::Mint(MintBox::Asset(mint)) => {
+            vec![AssetEvent::Added(asset_changed(mint.destination.clone(), mint.object)).into()]
+        }
+        InstructionBox::Burn(BurnBox::Asset(burn)) => {
+            vec![AssetEvent::Removed(asset_changed(burn.destination.clone(), burn.object)).into()]
+        }
+        InstructionBox::Transfer(TransferBox::Asset(transfer)) => vec![
+            AssetEvent::Removed(asset_changed(transfer.source.clone(), transfer.object)).into(),
+            AssetEvent::Added(asset_changed(
+                AssetId::new(
+                    transfer.source.definition.clone(),
+                    transfer.destination.clone(),
+                ),
+                transfer.object,
+            ))
+            .into(),
+        ],
+        InstructionBox::SetKeyValue(set) => vec![match set {
+            SetKeyValueBox::Domain(set) => {
+                DomainEvent::MetadataInserted(metadata_changed(&set.object, &set.key, &set.value))
+                    .into()
+            }
+            SetKeyValueBox::Account(set) => {
+                AccountEvent::MetadataInserted(metadata_changed(&set.object, &set.key, &set.value))
+                    .into()
+            }
+            SetKeyValueBox::AssetDefinition(set) => AssetDefinitionEvent::MetadataInserted(
+                metadata_changed(&set.object, &set.key, &set.value),
+            )
+            .into(),
+            SetKeyValueBox::Nft(set) => {
+                NftEvent::MetadataInserted(metadata_changed(&set.object, &set.key, &set.value))
+                    .into()
+            }
+            SetKeyValueBox::Trigger(set) => {
+                TriggerEvent::MetadataInserted(metadata_changed(&set.object, &set.key, &set.value))
+                    .into()
+            }
+        }],
+        InstructionBox::RemoveKeyValue(remove) => {
+            let value = Json::default();
+            vec![match remove {
+                RemoveKeyValueBox::Domain(remove) => DomainEvent::MetadataRemoved(
+                    metadata_changed(&remove.object, &remove.key, &value),
+                )
+                .into(),
+                RemoveKeyValueBox::Account(remove) => AccountEvent::MetadataRemoved(
+                    metadata_changed(&remove.object, &remove.key, &value),
+                )
+                .into(),
+                RemoveKeyValueBox::AssetDefinition(remove) => {
+                    AssetDefinitionEvent::MetadataRemoved(metadata_changed(
+                        &remove.object,
+                        &remove.key,
+                        &value,
+                    ))
+                    .into()
+                }
+                RemoveKeyValueBox::Nft(remove) => {
+                    NftEvent::MetadataRemoved(metadata_changed(&remove.object, &remove.key, &value))
+                        .into()
+                }
+                RemoveKeyValueBox::Trigger(remove) => TriggerEvent::MetadataRemoved(
+                    metadata_changed(&remove.object, &remove.key, &value),
+                )
+                .into(),
+            }]
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn metadata_changed<Id: Clone>(target: &Id, key: &Name, value: &Json) -> MetadataChanged<Id> {
+    MetadataChanged {
+        target: target.clone(),
+        key: key.clone(),
+        value: value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::{ALICE_ID, BOB_ID};
+
+    use super::*;
+
+    fn rose() -> AssetId {
+        AssetId::new("rose#wonderland".parse().unwrap(), ALICE_ID.clone())
+    }
+
+    fn node(id: &str, filter: DataEventFilter, repeats: Repeats, isi: InstructionBox) -> Node {
+        Node::new(
+            id.parse().unwrap(),
+            filter,
+            repeats,
+            Some(core::slice::from_ref(&isi)),
+        )
+    }
+
+    fn mint_rose() -> InstructionBox {
+        Mint::asset_numeric(1u32, rose()).into()
+    }
+
+    fn on_rose_added() -> DataEventFilter {
+        AssetEventFilter::new()
+            .for_asset(rose())
+            .for_events(AssetEventSet::Added)
+            .into()
+    }
+
+    #[test]
+    fn self_invoking_trigger_is_endless() {
+        let graph = Graph::new(vec![node(
+            "mint_on_mint",
+            on_rose_added(),
+            Repeats::Indefinitely,
+            mint_rose(),
+        )]);
+
+        assert_eq!(
+            graph.loops(),
+            vec![TriggerLoop {
+                triggers: vec!["mint_on_mint".parse().unwrap()],
+                bounded: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn loop_is_bounded_by_limited_trigger() {
+        let on_bob_metadata: DataEventFilter = AccountEventFilter::new()
+            .for_account(BOB_ID.clone())
+            .for_events(AccountEventSet::MetadataInserted)
+            .into();
+        let graph = Graph::new(vec![
+            node(
+                "a",
+                on_rose_added(),
+                Repeats::Indefinitely,
+                SetKeyValue::account(BOB_ID.clone(), "key".parse().unwrap(), "value").into(),
+            ),
+            node(
+                "b",
+                on_bob_metadata,
+                Repeats::Exactly(3),
+                Transfer::asset_numeric(
+                    AssetId::new("rose#wonderland".parse().unwrap(), BOB_ID.clone()),
+                    1u32,
+                    ALICE_ID.clone(),
+                )
+                .into(),
+            ),
+            node(
+                "unrelated",
+                on_rose_added(),
+                Repeats::Indefinitely,
+                Burn::asset_numeric(1u32, rose()).into(),
+            ),
+        ]);
+
+        assert_eq!(
+            graph.loops(),
+            vec![TriggerLoop {
+                triggers: vec!["a".parse().unwrap(), "b".parse().unwrap()],
+                bounded: true,
+            }]
+        );
+        assert!(!graph.is_endless(0));
+    }
+}
@@ -4,6 +4,7 @@
 use iroha_data_model::{isi::error::MathError, prelude::*, query::error::FindError};
 use iroha_telemetry::metrics;
 
+pub mod loops;
 pub mod set;
 pub mod specialized;
 
@@ -39,6 +40,19 @@ pub mod isi {
                 }
             }
 
+            if let EventFilterBox::Data(filter) = &new_trigger.action.filter {
+                if let Some(trigger_loop) = loops::find_endless_loop(
+                    &state_transaction.world.triggers,
+                    new_trigger.id(),
+                    filter,
+                    &new_trigger.action,
+                ) {
+                    return Err(Error::InvalidParameter(InvalidParameterError::TriggerLoop(
+                        trigger_loop,
+                    )));
+                }
+            }
+
             let latest_block_time = state_transaction
                 .latest_block()
                 .map(|block| block.header().creation_time());
@@ -327,15 +341,17 @@ pub mod query {
     //! Queries associated to triggers.
     use iroha_data_model::{
         query::{
-            dsl::CompoundPredicate, error::QueryExecutionFail as Error, trigger::FindTriggers,
+            dsl::CompoundPredicate,
+            error::QueryExecutionFail as Error,
+            trigger::{FindTriggerLoops, FindTriggers},
         },
-        trigger::{Trigger, TriggerId},
+        trigger::{Trigger, TriggerId, TriggerLoop},
     };
 
     use super::*;
     use crate::{
         prelude::*,
-        smartcontracts::{triggers::set::SetReadOnly, ValidQuery},
+        smartcontracts::{triggers::set::SetReadOnly, ValidQuery, ValidSingularQuery},
         state::StateReadOnly,
     };
 
@@ -378,4 +394,11 @@ pub mod query {
                    .filter(move |trigger| filter.applies(trigger)))
         }
     }
+
+    impl ValidSingularQuery for FindTriggerLoops {
+        #[metrics(+"find_trigger_loops")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<TriggerLoop>, Error> {
+            Ok(loops::find_loops(state_ro.world().triggers()))
+        }
+    }
 }
@@ -26,7 +26,8 @@ use mv::storage::{
 };
 use serde::{
     de::{DeserializeSeed, MapAccess, Visitor},
-    Deserialize, Serialize,
+    ser::SerializeStruct as _,
+    Deserialize, Serialize, Serializer,
 };
 use thiserror::Error;
 
@@ -37,7 +38,7 @@ use crate::{
         },
         wasm,
     },
-    state::deserialize::WasmSeed,
+    state::{deserialize::WasmSeed, detached},
 };
 
 /// Error type for [`Set`] operations.
@@ -145,6 +146,40 @@ pub struct WasmSmartContractEntry {
     count: NonZeroU64,
 }
 
+// NB: Serialized exactly as `Set`, so it has to be updated together with it
+impl SetView<'_> {
+    /// Copy the triggers seen by the view into a new set.
+    pub(crate) fn detached(&self) -> Set {
+        Set {
+            data_triggers: detached::storage(&self.data_triggers),
+            pipeline_triggers: detached::storage(&self.pipeline_triggers),
+            time_triggers: detached::storage(&self.time_triggers),
+            by_call_triggers: detached::storage(&self.by_call_triggers),
+            ids: detached::storage(&self.ids),
+            contracts: detached::storage(&self.contracts),
+        }
+    }
+}
+
+impl Serialize for SetView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut set = serializer.serialize_struct("Set", 6)?;
+        set.serialize_field("data_triggers", &detached::storage(&self.data_triggers))?;
+        set.serialize_field(
+            "pipeline_triggers",
+            &detached::storage(&self.pipeline_triggers),
+        )?;
+        set.serialize_field("time_triggers", &detached::storage(&self.time_triggers))?;
+        set.serialize_field(
+            "by_call_triggers",
+            &detached::storage(&self.by_call_triggers),
+        )?;
+        set.serialize_field("ids", &detached::storage(&self.ids))?;
+        set.serialize_field("contracts", &detached::storage(&self.contracts))?;
+        set.end()
+    }
+}
+
 impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Set> {
     type Value = Set;
 
@@ -321,6 +356,7 @@ pub trait SetReadOnly {
             authority,
             filter,
             metadata,
+            priority,
         } = action;
 
         let original_executable = match executable {
@@ -340,6 +376,7 @@ pub trait SetReadOnly {
             authority,
             filter,
             metadata,
+            priority,
         }
     }
 
@@ -552,21 +589,39 @@ impl<'set> SetBlock<'set> {
         self.data_triggers.commit();
     }
 
-    /// Returns an iterator over `(TriggerId, LoadedAction)` pairs for a given time event.
+    /// Returns `(TriggerId, LoadedAction)` pairs for a given time event in the order of execution.
     pub fn match_time_event(
         &self,
         event: TimeEvent,
-    ) -> impl Iterator<Item = (TriggerId, LoadedAction<TimeEventFilter>)> + '_ {
-        self.time_triggers.iter().flat_map(move |(id, action)| {
-            let mut count = action.filter.count_matches(&event);
-            if let Repeats::Exactly(repeats) = action.repeats {
-                count = min(repeats, count);
-            }
-            (0..count).map(move |_| (id.clone(), action.clone()))
-        })
+    ) -> Vec<(TriggerId, LoadedAction<TimeEventFilter>)> {
+        let mut matched: Vec<_> = self
+            .time_triggers
+            .iter()
+            .flat_map(move |(id, action)| {
+                let mut count = action.filter.count_matches(&event);
+                if let Repeats::Exactly(repeats) = action.repeats {
+                    count = min(repeats, count);
+                }
+                (0..count).map(move |_| (id.clone(), action.clone()))
+            })
+            .collect();
+        // Stable, so repeated executions of a trigger stay together
+        matched.sort_by(|(id_a, action_a), (id_b, action_b)| {
+            execution_order((id_a, action_a.priority), (id_b, action_b.priority))
+        });
+        matched
     }
 }
 
+/// Order of execution of triggers matching the same event:
+/// by descending priority, then by ascending trigger id.
+pub(crate) fn execution_order(
+    (id_a, priority_a): (&TriggerId, u8),
+    (id_b, priority_b): (&TriggerId, u8),
+) -> core::cmp::Ordering {
+    priority_b.cmp(&priority_a).then_with(|| id_a.cmp(id_b))
+}
+
 trait TriggeringEventFilter: EventFilter {}
 impl TriggeringEventFilter for DataEventFilter {}
 impl TriggeringEventFilter for PipelineEventFilterBox {}
@@ -680,6 +735,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
                     authority,
                     filter,
                     metadata,
+                    priority,
                 },
         } = trigger;
 
@@ -721,6 +777,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
                 authority,
                 filter,
                 metadata,
+                priority,
             },
         );
         self.ids.insert(trigger_id, event_type);
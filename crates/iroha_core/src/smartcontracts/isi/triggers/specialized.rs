@@ -29,6 +29,9 @@ pub struct SpecializedAction<F> {
     pub filter: F,
     /// Metadata used as persistent storage for trigger data.
     pub metadata: Metadata,
+    /// Order of execution among triggers matching the same event
+    #[serde(default)]
+    pub priority: u8,
 }
 
 impl<F> SpecializedAction<F> {
@@ -46,6 +49,7 @@ impl<F> SpecializedAction<F> {
             authority,
             filter,
             metadata: Metadata::default(),
+            priority: 0,
         }
     }
 }
@@ -61,6 +65,7 @@ where
             authority: value.authority,
             filter: value.filter.into(),
             metadata: value.metadata,
+            priority: value.priority,
         }
     }
 }
@@ -82,12 +87,13 @@ macro_rules! impl_try_from_box {
 
                 fn try_from(boxed: Trigger) -> Result<Self, Self::Error> {
                     if let EventFilterBox::$variant(concrete_filter) = boxed.action.filter {
-                        let action = SpecializedAction::new(
+                        let mut action = SpecializedAction::new(
                             boxed.action.executable,
                             boxed.action.repeats,
                             boxed.action.authority,
                             concrete_filter,
                         );
+                        action.priority = boxed.action.priority;
                         Ok(Self {
                             id: boxed.id,
                             action,
@@ -122,6 +128,9 @@ pub struct LoadedAction<F> {
     pub filter: F,
     /// Arbitrary metadata stored for this trigger.
     pub metadata: Metadata,
+    /// Order of execution among triggers matching the same event
+    #[serde(default)]
+    pub priority: u8,
 }
 
 impl<F> LoadedAction<F> {
@@ -199,6 +208,7 @@ impl<F: EventFilter + Into<EventFilterBox> + Clone> LoadedActionTrait for Loaded
             authority,
             filter,
             metadata,
+            priority,
         } = self;
 
         LoadedAction {
@@ -207,6 +217,7 @@ impl<F: EventFilter + Into<EventFilterBox> + Clone> LoadedActionTrait for Loaded
             authority,
             filter: filter.into(),
             metadata,
+            priority,
         }
     }
 
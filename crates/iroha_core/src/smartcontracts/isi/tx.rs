@@ -3,13 +3,19 @@
 use eyre::Result;
 use iroha_data_model::{
     prelude::*,
-    query::{dsl::CompoundPredicate, error::QueryExecutionFail, CommittedTransaction},
+    query::{
+        dsl::CompoundPredicate, error::QueryExecutionFail, CommittedTransaction,
+        ExcludedTransaction,
+    },
 };
 use iroha_telemetry::metrics;
 use nonzero_ext::nonzero;
 
 use super::*;
-use crate::smartcontracts::ValidQuery;
+use crate::{
+    redaction::redacted_block,
+    smartcontracts::{ValidQuery, ValidSingularQuery},
+};
 
 impl ValidQuery for FindTransactions {
     #[metrics(+"find_transactions")]
@@ -23,47 +29,49 @@ impl ValidQuery for FindTransactions {
             // Iterate over blocks in descending order (most recent first).
             .rev()
             .flat_map(|block| {
-                let block_hash = block.hash();
-
+                let block = redacted_block(block, state_ro);
                 // Iterate over transactions in descending order (most recent first).
-                let entrypoint_hashes = block.entrypoint_hashes().rev();
-                let entrypoint_proofs = block.entrypoint_proofs().rev();
-                let entrypoints = block.entrypoints_cloned().rev();
-                let result_hashes = block.result_hashes().rev();
-                let result_proofs = block.result_proofs().rev();
-                let results = block.results().cloned().rev();
+                block.committed_transactions().rev().collect::<Vec<_>>()
+            })
+            .filter(move |tx| filter.applies(tx)))
+    }
+}
 
-                entrypoint_hashes
-                    .zip(entrypoint_proofs)
-                    .zip(entrypoints)
-                    .zip(result_hashes)
-                    .zip(result_proofs)
-                    .zip(results)
-                    .map(
-                        |(
-                            (
-                                (((entrypoint_hash, entrypoint_proof), entrypoint), result_hash),
-                                result_proof,
-                            ),
-                            result,
-                        )| {
-                            CommittedTransaction {
-                                block_hash,
-                                entrypoint_hash,
-                                entrypoint_proof,
-                                entrypoint,
-                                result_hash,
-                                result_proof,
-                                result,
-                            }
-                        },
-                    )
+impl ValidQuery for FindExcludedTransactions {
+    #[metrics(+"find_excluded_transactions")]
+    fn execute(
+        self,
+        filter: CompoundPredicate<ExcludedTransaction>,
+        state_ro: &impl StateReadOnly,
+    ) -> Result<impl Iterator<Item = Self::Item>, QueryExecutionFail> {
+        Ok(state_ro
+            .all_blocks(nonzero!(1_usize))
+            // Iterate over blocks in descending order (most recent first).
+            .rev()
+            .flat_map(|block| {
+                let block_hash = block.hash();
+                // Iterate over transactions in descending order (most recent first).
+                block
+                    .excluded_transactions()
+                    .rev()
+                    .map(|rejection| ExcludedTransaction {
+                        block_hash,
+                        entrypoint_hash: HashOf::from_untyped_unchecked(rejection.hash.into()),
+                        reason: rejection.reason.clone(),
+                    })
                     .collect::<Vec<_>>()
             })
             .filter(move |tx| filter.applies(tx)))
     }
 }
 
+impl ValidSingularQuery for IsTransactionKnown {
+    #[metrics(+"is_transaction_known")]
+    fn execute(&self, state_ro: &impl StateReadOnly) -> Result<bool, QueryExecutionFail> {
+        Ok(state_ro.committed_transaction_height(&self.hash).is_some())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use iroha_data_model::prelude::{TransactionEntrypoint, TransactionResult};
@@ -7,10 +7,12 @@ use crate::prelude::*;
 
 /// Iroha Special Instructions that have `World` as their target.
 pub mod isi {
+    use std::collections::BTreeSet;
+
     use eyre::Result;
     use iroha_data_model::{
         isi::error::{InstructionExecutionError, InvalidParameterError, RepetitionError},
-        parameter::{CustomParameter, Parameter},
+        parameter::{CustomParameter, DomainTransactionTtl, Parameter},
         prelude::*,
         query::error::FindError,
         Level,
@@ -18,6 +20,10 @@ pub mod isi {
     use iroha_primitives::{json::Json, unique_vec::PushResult};
 
     use super::*;
+    use crate::{
+        executor_upgrade::{PendingExecutorUpgrade, PendingExecutorUpgradePolicy},
+        nft_content_index::content_trigrams,
+    };
 
     impl Execute for Register<Peer> {
         #[metrics(+"register_peer")]
@@ -88,6 +94,11 @@ pub mod isi {
                 }
                 .into());
             }
+            if let Some(role_id) = domain.default_role() {
+                if world.roles.get(role_id).is_none() {
+                    return Err(FindError::Role(role_id.clone()).into());
+                }
+            }
 
             world.domains.insert(domain_id, domain.clone());
             world.emit_events(Some(DomainEvent::Created(domain)));
@@ -133,8 +144,19 @@ pub mod isi {
                     .world
                     .account_permissions
                     .remove(account.clone());
+                state_transaction
+                    .world
+                    .permission_revocations
+                    .remove(account.clone());
+                state_transaction
+                    .world
+                    .spending_counters
+                    .remove(account.clone());
 
                 state_transaction.world.remove_account_roles(&account);
+                state_transaction
+                    .world
+                    .remove_account_asset_movements(&account);
 
                 let remove_assets: Vec<AssetId> = state_transaction
                     .world
@@ -166,8 +188,36 @@ pub mod isi {
                 .map(|nft| nft.id().clone())
                 .collect();
             for nft_id in remove_nfts {
-                state_transaction.world.nfts.remove(nft_id.clone());
+                if let Some(nft) = state_transaction.world.nfts.remove(nft_id.clone()) {
+                    state_transaction.world.reindex_nft_content(
+                        &nft_id,
+                        &content_trigrams(&nft.content),
+                        &BTreeSet::new(),
+                    );
+                }
+            }
+
+            // Only touch the parameters if necessary, not to copy them on every unregistration
+            if state_transaction
+                .world
+                .parameters
+                .domain_transaction_ttl
+                .contains_key(&domain_id)
+            {
+                state_transaction
+                    .world
+                    .parameters
+                    .domain_transaction_ttl
+                    .remove(&domain_id);
             }
+            state_transaction
+                .world
+                .domain_validators
+                .remove(domain_id.clone());
+            state_transaction
+                .world
+                .domain_transfer_offers
+                .remove(domain_id.clone());
 
             if state_transaction
                 .world
@@ -203,6 +253,13 @@ pub mod isi {
                 .into());
             }
 
+            if let Some(inherited_role) = role
+                .inherited_roles()
+                .find(|inherited_role| state_transaction.world.roles.get(inherited_role).is_none())
+            {
+                return Err(FindError::Role(inherited_role.clone()).into());
+            }
+
             let world = &mut state_transaction.world;
             let role_id = role.id().clone();
             world.roles.insert(role_id, role.clone());
@@ -240,6 +297,33 @@ pub mod isi {
                 revoke.execute(authority, state_transaction)?
             }
 
+            let roles_inheriting_role = state_transaction
+                .world
+                .roles
+                .iter()
+                .filter(|(_, role)| role.inherited_roles.contains(&role_id))
+                .map(|(id, _)| id)
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for inheriting_role_id in roles_inheriting_role {
+                let revoke = Revoke::inherited_role(role_id.clone(), inheriting_role_id);
+                revoke.execute(authority, state_transaction)?
+            }
+
+            let domains_with_default_role = state_transaction
+                .world
+                .domains
+                .iter()
+                .filter(|(_, domain)| domain.default_role.as_ref() == Some(&role_id))
+                .map(|(id, _)| id)
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for domain_id in domains_with_default_role {
+                state_transaction.world.domain_mut(&domain_id)?.default_role = None;
+            }
+
             let world = &mut state_transaction.world;
             if world.roles.remove(role_id.clone()).is_none() {
                 return Err(FindError::Role(role_id).into());
@@ -251,11 +335,129 @@ pub mod isi {
         }
     }
 
+    impl Execute for Transfer<Account, RoleId, Account> {
+        #[metrics(+"transfer_role")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Transfer {
+                source,
+                object,
+                destination,
+            } = self;
+
+            state_transaction.world.account(&source)?;
+            state_transaction.world.account(&destination)?;
+
+            let Some(role) = state_transaction.world.roles.get_mut(&object) else {
+                return Err(FindError::Role(object).into());
+            };
+
+            if role.owned_by != source {
+                return Err(InstructionExecutionError::InvariantViolation(format!(
+                    "Can't transfer role {object} since {source} doesn't own it",
+                )));
+            }
+
+            role.owned_by = destination.clone();
+            state_transaction
+                .world
+                .emit_events(Some(RoleEvent::OwnerChanged(RoleOwnerChanged {
+                    role: object,
+                    new_owner: destination,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Register<RoleTemplate> {
+        #[metrics(+"register_role_template")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let template = self.object;
+
+            if state_transaction
+                .world
+                .role_templates
+                .get(&template.id)
+                .is_some()
+            {
+                return Err(RepetitionError {
+                    instruction: InstructionType::Register,
+                    id: IdBox::RoleId(template.id),
+                }
+                .into());
+            }
+
+            state_transaction
+                .world
+                .role_templates
+                .insert(template.id.clone(), template);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unregister<RoleTemplate> {
+        #[metrics(+"unregister_role_template")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let template_id = self.object;
+
+            // Roles instantiated from the template are independent of it and stay registered
+            if state_transaction
+                .world
+                .role_templates
+                .remove(template_id.clone())
+                .is_none()
+            {
+                return Err(FindError::RoleTemplate(template_id).into());
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for InstantiateRoleTemplate {
+        #[metrics(+"instantiate_role_template")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self {
+                template: template_id,
+                role,
+                arguments,
+                grant_to,
+            } = self;
+
+            let Some(template) = state_transaction.world.role_templates.get(&template_id) else {
+                return Err(FindError::RoleTemplate(template_id).into());
+            };
+            let new_role = template
+                .instantiate(role.clone(), grant_to.clone(), &arguments)
+                .map_err(|err| InstructionExecutionError::InvariantViolation(err.to_string()))?;
+
+            Register::role(new_role).execute(authority, state_transaction)?;
+            Grant::account_role(role, grant_to).execute(authority, state_transaction)
+        }
+    }
+
     impl Execute for Grant<Permission, Role> {
         #[metrics(+"grant_role_permission")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let role_id = self.destination;
@@ -272,6 +474,12 @@ pub mod isi {
                 }
                 .into());
             }
+            state_transaction.record_permission_grant(
+                authority,
+                role_id.clone().into(),
+                permission.clone(),
+                PermissionGrantAction::Grant,
+            );
 
             state_transaction
                 .world
@@ -288,7 +496,7 @@ pub mod isi {
         #[metrics(+"grant_role_permission")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let role_id = self.destination;
@@ -301,6 +509,12 @@ pub mod isi {
             if !role.permissions.remove(&permission) {
                 return Err(FindError::Permission(permission.into()).into());
             }
+            state_transaction.record_permission_grant(
+                authority,
+                role_id.clone().into(),
+                permission.clone(),
+                PermissionGrantAction::Revoke,
+            );
 
             state_transaction
                 .world
@@ -313,11 +527,90 @@ pub mod isi {
         }
     }
 
+    impl Execute for Grant<RoleId, Role> {
+        #[metrics(+"grant_inherited_role")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let role_id = self.destination;
+            let inherited_role_id = self.object;
+
+            state_transaction.world.role(&inherited_role_id)?;
+            state_transaction.world.role(&role_id)?;
+
+            if state_transaction
+                .world
+                .roles_with_inherited([&inherited_role_id])
+                .contains(&role_id)
+            {
+                return Err(InstructionExecutionError::InvariantViolation(format!(
+                    "Role `{role_id}` can't inherit `{inherited_role_id}`, which already inherits it"
+                )));
+            }
+
+            let Some(role) = state_transaction.world.roles.get_mut(&role_id) else {
+                return Err(FindError::Role(role_id).into());
+            };
+
+            if !role.inherited_roles.insert(inherited_role_id.clone()) {
+                return Err(RepetitionError {
+                    instruction: InstructionType::Grant,
+                    id: inherited_role_id.into(),
+                }
+                .into());
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(RoleEvent::InheritedRoleAdded(
+                    RoleInheritanceChanged {
+                        role: role_id,
+                        inherited_role: inherited_role_id,
+                    },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Revoke<RoleId, Role> {
+        #[metrics(+"revoke_inherited_role")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let role_id = self.destination;
+            let inherited_role_id = self.object;
+
+            let Some(role) = state_transaction.world.roles.get_mut(&role_id) else {
+                return Err(FindError::Role(role_id).into());
+            };
+
+            if !role.inherited_roles.remove(&inherited_role_id) {
+                return Err(FindError::Role(inherited_role_id).into());
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(RoleEvent::InheritedRoleRemoved(
+                    RoleInheritanceChanged {
+                        role: role_id,
+                        inherited_role: inherited_role_id,
+                    },
+                )));
+
+            Ok(())
+        }
+    }
+
     impl Execute for SetParameter {
         #[metrics(+"set_parameter")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             macro_rules! set_parameter {
@@ -365,6 +658,74 @@ pub mod isi {
                                     new_value: Parameter::Custom(next),
                                 })));
                         }
+                        Parameter::ExecutorUpgrade(next) => {
+                            for account_id in &next.accounts {
+                                state_transaction.world.account(account_id)?;
+                            }
+                            for role_id in &next.roles {
+                                if state_transaction.world.roles.get(role_id).is_none() {
+                                    return Err(FindError::Role(role_id.clone()).into());
+                                }
+                            }
+
+                            if !state_transaction.curr_block.is_genesis() {
+                                if !is_allowed_to_upgrade(authority, &state_transaction.world) {
+                                    return Err(Error::InvariantViolation(format!(
+                                        "{authority} isn't allowed to change the executor upgrade policy"
+                                    )));
+                                }
+                                // Setting the policy again replaces a scheduled loosening, e.g. to cancel it
+                                *state_transaction.world.pending_executor_upgrade_policy.get_mut() = None;
+
+                                let current = &state_transaction.world.parameters.executor_upgrade;
+                                if current.delay_blocks > 0 && next.loosens(current) {
+                                    let activation_height = state_transaction
+                                        .curr_block
+                                        .height()
+                                        .get()
+                                        .saturating_add(current.delay_blocks);
+                                    *state_transaction.world.pending_executor_upgrade_policy.get_mut() =
+                                        Some(PendingExecutorUpgradePolicy {
+                                            parameters: next,
+                                            activation_height,
+                                        });
+                                    return Ok(());
+                                }
+                            }
+
+                            let prev = core::mem::replace(
+                                &mut state_transaction.world.parameters.executor_upgrade,
+                                next.clone(),
+                            );
+
+                            state_transaction
+                                .world
+                                .emit_events(Some(ConfigurationEvent::Changed(ParameterChanged {
+                                    old_value: Parameter::ExecutorUpgrade(prev),
+                                    new_value: Parameter::ExecutorUpgrade(next),
+                                })));
+                        }
+                        Parameter::DomainTransactionTtl(next) => {
+                            state_transaction.world.domain(&next.domain)?;
+
+                            let parameters = &mut state_transaction.world.parameters;
+                            let prev = match next.bounds {
+                                Some(bounds) => parameters
+                                    .domain_transaction_ttl
+                                    .insert(next.domain.clone(), bounds),
+                                None => parameters.domain_transaction_ttl.remove(&next.domain),
+                            };
+
+                            state_transaction
+                                .world
+                                .emit_events(Some(ConfigurationEvent::Changed(ParameterChanged {
+                                    old_value: Parameter::DomainTransactionTtl(DomainTransactionTtl {
+                                        domain: next.domain.clone(),
+                                        bounds: prev,
+                                    }),
+                                    new_value: Parameter::DomainTransactionTtl(next),
+                                })));
+                        }
                     }
                 };
             }
@@ -375,9 +736,12 @@ pub mod isi {
                 Sumeragi(sumeragi.commit_time_ms) => SumeragiParameter::CommitTimeMs,
 
                 Block(block.max_transactions) => BlockParameter::MaxTransactions,
+                Block(block.exclude_rejected_transactions) => BlockParameter::ExcludeRejectedTransactions,
 
                 Transaction(transaction.max_instructions) => TransactionParameter::MaxInstructions,
                 Transaction(transaction.smart_contract_size) => TransactionParameter::SmartContractSize,
+                Transaction(transaction.min_time_to_live_ms) => TransactionParameter::MinTimeToLiveMs,
+                Transaction(transaction.max_time_to_live_ms) => TransactionParameter::MaxTimeToLiveMs,
 
                 SmartContract(smart_contract.fuel) => SmartContractParameter::Fuel,
                 SmartContract(smart_contract.memory) => SmartContractParameter::Memory,
@@ -392,6 +756,47 @@ pub mod isi {
         }
     }
 
+    /// Whether `authority` may upgrade the executor or abort a scheduled upgrade
+    /// under the restrictions set by [`Parameter::ExecutorUpgrade`].
+    fn is_allowed_to_upgrade(authority: &AccountId, world: &impl WorldReadOnly) -> bool {
+        let policy = &world.parameters().executor_upgrade;
+
+        !policy.is_restricted()
+            || policy.accounts.contains(authority)
+            || world
+                .account_roles_iter(authority)
+                .any(|role_id| policy.roles.contains(role_id))
+    }
+
+    /// Replace the executor with `raw_executor`, running its migration on behalf of `authority`.
+    pub(crate) fn upgrade_executor(
+        raw_executor: Executor,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        // Cloning executor to avoid multiple mutable borrows of `state_transaction`.
+        // Also it's a cheap operation.
+        let mut upgraded_executor = state_transaction.world.executor.clone();
+        upgraded_executor
+            .migrate(raw_executor, state_transaction, authority)
+            .map_err(|migration_error| {
+                InvalidParameterError::Wasm(format!(
+                    "{:?}",
+                    eyre::eyre!(migration_error).wrap_err("Migration failed"),
+                ))
+            })?;
+
+        *state_transaction.world.executor.get_mut() = upgraded_executor;
+
+        state_transaction
+            .world
+            .emit_events(Some(ExecutorEvent::Upgraded(ExecutorUpgrade {
+                new_data_model: state_transaction.world.executor_data_model.clone(),
+            })));
+
+        Ok(())
+    }
+
     impl Execute for Upgrade {
         #[metrics(+"upgrade_executor")]
         fn execute(
@@ -399,27 +804,92 @@ pub mod isi {
             authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
-            let raw_executor = self.executor;
-
-            // Cloning executor to avoid multiple mutable borrows of `state_transaction`.
-            // Also it's a cheap operation.
-            let mut upgraded_executor = state_transaction.world.executor.clone();
-            upgraded_executor
-                .migrate(raw_executor, state_transaction, authority)
-                .map_err(|migration_error| {
-                    InvalidParameterError::Wasm(format!(
-                        "{:?}",
-                        eyre::eyre!(migration_error).wrap_err("Migration failed"),
-                    ))
-                })?;
-
-            *state_transaction.world.executor.get_mut() = upgraded_executor;
+            // Genesis sets up the initial executor, before any restriction can be configured
+            if state_transaction.curr_block.is_genesis() {
+                return upgrade_executor(self.executor, authority, state_transaction);
+            }
+
+            if !is_allowed_to_upgrade(authority, &state_transaction.world) {
+                return Err(Error::InvariantViolation(format!(
+                    "{authority} isn't allowed to upgrade the executor"
+                )));
+            }
+            if let Some(pending) = state_transaction.world.pending_executor_upgrade.as_ref() {
+                return Err(Error::InvariantViolation(format!(
+                    "Executor upgrade is already scheduled at height {}",
+                    pending.activation_height
+                )));
+            }
 
+            let delay_blocks = state_transaction
+                .world
+                .parameters
+                .executor_upgrade
+                .delay_blocks;
+            if delay_blocks == 0 {
+                return upgrade_executor(self.executor, authority, state_transaction);
+            }
+
+            let activation_height = state_transaction
+                .curr_block
+                .height()
+                .get()
+                .saturating_add(delay_blocks);
+            *state_transaction.world.pending_executor_upgrade.get_mut() =
+                Some(PendingExecutorUpgrade {
+                    executor: self.executor,
+                    authority: authority.clone(),
+                    activation_height,
+                });
             state_transaction
                 .world
-                .emit_events(Some(ExecutorEvent::Upgraded(ExecutorUpgrade {
-                    new_data_model: state_transaction.world.executor_data_model.clone(),
-                })));
+                .emit_events(Some(ExecutorEvent::UpgradeScheduled(
+                    ExecutorUpgradeScheduled {
+                        authority: authority.clone(),
+                        activation_height,
+                    },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for AbortExecutorUpgrade {
+        #[metrics(+"abort_executor_upgrade")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self { activation_height } = self;
+
+            let Some(pending) = state_transaction
+                .world
+                .pending_executor_upgrade
+                .as_ref()
+                .filter(|pending| pending.activation_height == activation_height)
+            else {
+                return Err(Error::InvariantViolation(format!(
+                    "No executor upgrade is scheduled at height {activation_height}"
+                )));
+            };
+            if pending.authority != *authority
+                && !is_allowed_to_upgrade(authority, &state_transaction.world)
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "{authority} isn't allowed to abort the executor upgrade"
+                )));
+            }
+
+            *state_transaction.world.pending_executor_upgrade.get_mut() = None;
+            state_transaction
+                .world
+                .emit_events(Some(ExecutorEvent::UpgradeAborted(
+                    ExecutorUpgradeAborted {
+                        activation_height,
+                        aborted_by: Some(authority.clone()),
+                    },
+                )));
 
             Ok(())
         }
@@ -445,14 +915,52 @@ pub mod isi {
             Ok(())
         }
     }
+
+    impl Execute for Anchor {
+        #[metrics(+"anchor")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self { hash, tag } = self;
+
+            // The first anchoring is the one proving existence of the content, so it must not be overwritten
+            if state_transaction.world.anchors.get(&hash).is_some() {
+                return Err(InstructionExecutionError::InvariantViolation(format!(
+                    "Hash `{hash}` is already anchored"
+                )));
+            }
+
+            let anchor = AnchoredHash {
+                hash,
+                tag,
+                authority: authority.clone(),
+                anchored_at_ms: state_transaction
+                    .curr_block
+                    .creation_time()
+                    .as_millis()
+                    .try_into()
+                    .expect("INTERNAL BUG: Unix time in milliseconds doesn't fit into u64"),
+            };
+            state_transaction.world.anchors.insert(hash, anchor);
+
+            Ok(())
+        }
+    }
 }
 /// Query module provides `IrohaQuery` Peer related implementations.
 pub mod query {
+    use std::ops::Bound;
+
     use eyre::Result;
     use iroha_data_model::{
         parameter::Parameters,
         prelude::*,
-        query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error},
+        query::{
+            dsl::CompoundPredicate,
+            error::{FindError, QueryExecutionFail as Error},
+        },
         role::Role,
     };
 
@@ -510,6 +1018,30 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAnchors {
+        #[metrics(+"find_anchors")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<AnchoredHash>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Self::Item>, Error> {
+            let Self { hash, tag } = self;
+            // Look the hash up directly instead of scanning all anchors when it is given
+            let bounds = hash.map_or((Bound::Unbounded, Bound::Unbounded), |hash| {
+                (Bound::Included(hash), Bound::Included(hash))
+            });
+
+            Ok(state_ro
+                .world()
+                .anchors()
+                .range::<Hash>(bounds)
+                .map(|(_, anchor)| anchor)
+                .filter(move |anchor| tag.is_none() || anchor.tag == tag)
+                .filter(move |&anchor| filter.applies(anchor))
+                .cloned())
+        }
+    }
+
     impl ValidSingularQuery for FindExecutorDataModel {
         #[metrics(+"find_executor_data_model")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<ExecutorDataModel, Error> {
@@ -517,10 +1049,32 @@ pub mod query {
         }
     }
 
+    impl ValidSingularQuery for FindPermissionSchemas {
+        #[metrics(+"find_permission_schemas")]
+        fn execute(
+            &self,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<Vec<PermissionTokenSchema>, Error> {
+            Ok(state_ro.world().executor_data_model().permission_schemas())
+        }
+    }
+
     impl ValidSingularQuery for FindParameters {
         #[metrics(+"find_parameters")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Parameters, Error> {
             Ok(state_ro.world().parameters().clone())
         }
     }
+
+    impl ValidSingularQuery for FindRoleTemplate {
+        #[metrics(+"find_role_template")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<RoleTemplate, Error> {
+            state_ro
+                .world()
+                .role_templates()
+                .get(&self.id)
+                .cloned()
+                .ok_or_else(|| FindError::RoleTemplate(self.id.clone()).into())
+        }
+    }
 }
@@ -38,7 +38,7 @@ use crate::{
         },
         Execute,
     },
-    state::{StateReadOnly, StateTransaction, WorldReadOnly},
+    state::{permission_cache::PermissionCache, StateReadOnly, StateTransaction, WorldReadOnly},
 };
 
 /// Cache for WASM Runtime
@@ -71,6 +71,8 @@ mod import {
     pub const EXECUTOR_EXECUTE_TRANSACTION: &str = "_iroha_executor_execute_transaction";
     pub const EXECUTOR_EXECUTE_INSTRUCTION: &str = "_iroha_executor_execute_instruction";
     pub const EXECUTOR_VALIDATE_QUERY: &str = "_iroha_executor_validate_query";
+    pub const EXECUTOR_VALIDATE_EVENT_SUBSCRIPTION: &str =
+        "_iroha_executor_validate_event_subscription";
     pub const EXECUTOR_MIGRATE: &str = "_iroha_executor_migrate";
 
     pub mod traits {
@@ -457,6 +459,9 @@ pub mod state {
 
             /// Event which activated this trigger
             pub(in super::super) triggering_event: EventBox,
+
+            /// Instructions successfully executed by the trigger, recorded only if requested
+            pub(in super::super) executed_instructions: Option<Vec<InstructionBox>>,
         }
 
         pub mod executor {
@@ -479,6 +484,10 @@ pub mod state {
             /// State kind for executing `validate_query()` entrypoint of executor
             pub type ValidateQuery = Validate<AnyQueryBox>;
 
+            /// State kind for executing `validate_event_subscription()` entrypoint of executor,
+            /// validating a subscription to events about the account
+            pub type ValidateEventSubscription = Validate<AccountId>;
+
             /// State kind for executing `execute_instruction()` entrypoint of executor
             pub type ExecuteInstruction = Validate<InstructionBox>;
 
@@ -541,12 +550,24 @@ pub mod state {
         pub type ValidateQuery<'wrld, S> =
             CommonState<chain_state::WithConst<'wrld, S>, specific::executor::ValidateQuery>;
 
+        /// State for executing `validate_event_subscription()` entrypoint
+        pub type ValidateEventSubscription<'wrld, S> = CommonState<
+            chain_state::WithConst<'wrld, S>,
+            specific::executor::ValidateEventSubscription,
+        >;
+
         /// State for executing `execute_instruction()` entrypoint
         pub type ExecuteInstruction<'wrld, 'block, 'state> = CommonState<
             chain_state::WithMut<'wrld, 'block, 'state>,
             specific::executor::ExecuteInstruction,
         >;
 
+        /// State for executing `execute_instruction()` entrypoint of a domain validator.
+        ///
+        /// The instruction has already been executed, so the validator only reads the state.
+        pub type ValidateDomainInstruction<'wrld, S> =
+            CommonState<chain_state::WithConst<'wrld, S>, specific::executor::ExecuteInstruction>;
+
         /// State for executing `migrate()` entrypoint
         pub type Migrate<'wrld, 'block, 'state> =
             CommonState<chain_state::WithMut<'wrld, 'block, 'state>, specific::executor::Migrate>;
@@ -580,6 +601,26 @@ pub mod state {
                 Ok(())
             }
         }
+
+        impl<S: StateReadOnly> ValidateQueryOperation for ValidateEventSubscription<'_, S> {
+            fn validate_query(
+                &self,
+                _authority: &AccountId,
+                _query: &QueryRequest,
+            ) -> Result<(), ValidationFail> {
+                Ok(())
+            }
+        }
+
+        impl<S: StateReadOnly> ValidateQueryOperation for ValidateDomainInstruction<'_, S> {
+            fn validate_query(
+                &self,
+                _authority: &AccountId,
+                _query: &QueryRequest,
+            ) -> Result<(), ValidationFail> {
+                Ok(())
+            }
+        }
     }
 }
 
@@ -681,6 +722,11 @@ impl<S> Runtime<S> {
         store: &mut Store<S>,
         payload: T,
     ) -> WasmUsize {
+        Self::write_payload(instance, store, &codec::encode_with_length_prefix(&payload))
+    }
+
+    /// Write the payload encoded with [`codec::encode_with_length_prefix`] into the memory of `instance`
+    fn write_payload(instance: &Instance, store: &mut Store<S>, payload: &[u8]) -> WasmUsize {
         let memory =
             Self::get_memory(&mut (instance, &mut *store)).expect("Checked at instantiation step");
         let alloc_fn = Self::get_typed_func::<WasmUsize, WasmUsize>(
@@ -689,8 +735,8 @@ impl<S> Runtime<S> {
             import::SMART_CONTRACT_ALLOC,
         )
         .expect("Checked at instantiation step");
-        iroha_wasm_codec::encode_into_memory(&payload, &memory, &alloc_fn, &mut *store)
-            .expect("Can't encode payload")
+        codec::write_into_memory(payload, &memory, &alloc_fn, &mut *store)
+            .expect("Can't write payload")
     }
 
     /// Host-defined function which prints the given string. When this function
@@ -779,22 +825,19 @@ impl<W, S> Runtime<Option<CommonState<W, S>>> {
     }
 }
 
-impl<W: state::chain_state::ConstState, T: Clone> Runtime<state::CommonState<W, Validate<T>>>
-where
-    payloads::Validate<T>: Encode,
-{
+impl<W: state::chain_state::ConstState, T: Encode> Runtime<state::CommonState<W, Validate<T>>> {
     fn execute_executor_execute_internal(
         &self,
         module: &wasmtime::Module,
         state: state::CommonState<W, Validate<T>>,
         validate_fn_name: &'static str,
     ) -> Result<executor::Result> {
-        let context = create_validate_context(&state);
+        let context = encode_validate_context(&state);
         let mut store = self.create_store(state);
         let instance = self.instantiate_module(module, &mut store)?;
 
         let validation_res =
-            execute_executor_validate_part1(&mut store, &instance, context, validate_fn_name)?;
+            execute_executor_validate_part1(&mut store, &instance, &context, validate_fn_name)?;
 
         let state = store.into_data();
         execute_executor_validate_part2(state);
@@ -803,22 +846,21 @@ where
     }
 }
 
-impl<W: state::chain_state::ConstState, T: Clone> RuntimeFull<Option<CommonState<W, Validate<T>>>>
-where
-    payloads::Validate<T>: Encode,
+impl<W: state::chain_state::ConstState, T: Encode>
+    RuntimeFull<Option<CommonState<W, Validate<T>>>>
 {
     fn execute_executor_execute_internal(
         &mut self,
         state: CommonState<W, Validate<T>>,
         validate_fn_name: &'static str,
     ) -> Result<executor::Result> {
-        let context = create_validate_context(&state);
+        let context = encode_validate_context(&state);
         self.set_store_state(state);
 
         let validation_res = execute_executor_validate_part1(
             &mut self.store,
             &self.instance,
-            context,
+            &context,
             validate_fn_name,
         )?;
 
@@ -844,17 +886,14 @@ where
     }
 }
 
-fn execute_executor_validate_part1<S, T>(
+fn execute_executor_validate_part1<S>(
     store: &mut Store<S>,
     instance: &Instance,
-    context: payloads::Validate<T>,
+    context: &[u8],
     validate_fn_name: &'static str,
-) -> Result<executor::Result>
-where
-    payloads::Validate<T>: Encode,
-{
+) -> Result<executor::Result> {
     let validate_fn = Runtime::get_typed_func(instance, &mut *store, validate_fn_name)?;
-    let context = Runtime::encode_payload(instance, &mut *store, context);
+    let context = Runtime::write_payload(instance, &mut *store, context);
 
     // NOTE: This function takes ownership of the pointer
     let offset = validate_fn
@@ -879,16 +918,20 @@ fn execute_executor_validate_part2<W: state::chain_state::ConstState, S>(
     );
 }
 
-fn create_validate_context<W: state::chain_state::ConstState, T: Clone>(
+/// Encode the payload of a `validate_*()` entrypoint with [`codec::encode_with_length_prefix`].
+///
+/// The validated operation is encoded by reference, before the state is moved into the store,
+/// so that it isn't cloned.
+fn encode_validate_context<W: state::chain_state::ConstState, T: Encode>(
     state: &CommonState<W, Validate<T>>,
-) -> payloads::Validate<T> {
-    payloads::Validate {
+) -> Vec<u8> {
+    codec::encode_with_length_prefix(&payloads::Validate {
         context: payloads::ExecutorContext {
             authority: state.authority.clone(),
             curr_block: state.specific_state.curr_block,
         },
-        target: state.specific_state.to_validate.clone(),
-    }
+        target: &state.specific_state.to_validate,
+    })
 }
 
 impl<W, S> Runtime<state::CommonState<W, S>>
@@ -936,6 +979,38 @@ where
     }
 }
 
+impl<'wrld, 'block, 'state, S>
+    Runtime<state::CommonState<state::chain_state::WithMut<'wrld, 'block, 'state>, S>>
+where
+    state::CommonState<state::chain_state::WithMut<'wrld, 'block, 'state>, S>:
+        state::ValidateQueryOperation,
+{
+    /// Like [`Self::default_execute_query`], but reuses outputs of permission-resolution
+    /// queries already executed by the executor within the current block.
+    fn executor_execute_query(
+        query_request: QueryRequest,
+        state: &mut state::CommonState<state::chain_state::WithMut<'wrld, 'block, 'state>, S>,
+    ) -> Result<QueryResponse, ValidationFail> {
+        let world = &state.state.0.world;
+        let key = match &query_request {
+            QueryRequest::Start(query) if !world.permissions_changed => PermissionCache::key(query),
+            _ => None,
+        };
+        let Some(key) = key else {
+            return Self::default_execute_query(query_request, state);
+        };
+        if let Some(output) = world.permission_cache.get(&key) {
+            return Ok(QueryResponse::Iterable(output));
+        }
+
+        let response = Self::default_execute_query(query_request, state)?;
+        if let QueryResponse::Iterable(output) = &response {
+            state.state.0.world.permission_cache.insert(key, output);
+        }
+        Ok(response)
+    }
+}
+
 impl<'wrld, 'state, 'block, S>
     Runtime<state::CommonState<state::chain_state::WithMut<'wrld, 'state, 'block>, S>>
 {
@@ -1088,13 +1163,63 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         module: &wasmtime::Module,
         event: EventBox,
     ) -> Result<ExecutionStep> {
+        self.execute_trigger_module_internal(
+            state_transaction,
+            id,
+            authority,
+            module,
+            event,
+            None,
+        )?;
+
+        // FIXME: include actual instructions -- requires #5358 refactoring.
+        Ok(ExecutionStep(ConstVec::new_empty()))
+    }
+
+    /// Same as [`Self::execute_trigger_module`], but records instructions
+    /// successfully executed by the trigger.
+    ///
+    /// Used to test trigger logic in isolation.
+    ///
+    /// # Errors
+    ///
+    /// - if unable to find expected main function export
+    /// - if the execution of the smartcontract fails
+    pub fn execute_trigger_module_recording(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        id: &TriggerId,
+        authority: AccountId,
+        module: &wasmtime::Module,
+        event: EventBox,
+    ) -> Result<Vec<InstructionBox>> {
+        self.execute_trigger_module_internal(
+            state_transaction,
+            id,
+            authority,
+            module,
+            event,
+            Some(Vec::new()),
+        )
+        .map(Option::unwrap_or_default)
+    }
+
+    fn execute_trigger_module_internal(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        id: &TriggerId,
+        authority: AccountId,
+        module: &wasmtime::Module,
+        event: EventBox,
+        executed_instructions: Option<Vec<InstructionBox>>,
+    ) -> Result<Option<Vec<InstructionBox>>> {
         let span = wasm_log_span!("Trigger execution", %id, %authority);
         let state = state::Trigger::new(
             authority,
             self.config,
             span,
             state::chain_state::WithMut(state_transaction),
-            state::specific::Trigger::new(id.clone(), event),
+            state::specific::Trigger::new(id.clone(), event, executed_instructions),
         );
 
         let mut store = self.create_store(state);
@@ -1113,8 +1238,7 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         let executed_queries = state.take_executed_queries();
         forget_all_executed_queries(state.state.0.query_handle, executed_queries);
 
-        // FIXME: include actual instructions -- requires #5358 refactoring.
-        Ok(ExecutionStep(ConstVec::new_empty()))
+        Ok(state.specific_state.executed_instructions)
     }
 
     fn get_trigger_context(
@@ -1148,7 +1272,20 @@ impl<'wrld, 'block, 'state> import::traits::ExecuteOperations<state::Trigger<'wr
         instruction: InstructionBox,
         state: &mut state::Trigger<'wrld, 'block, 'state>,
     ) -> Result<(), ValidationFail> {
-        Self::default_execute_instruction(instruction, state)
+        let recorded = state
+            .specific_state
+            .executed_instructions
+            .is_some()
+            .then(|| instruction.clone());
+        Self::default_execute_instruction(instruction, state)?;
+
+        if let (Some(executed), Some(instruction)) = (
+            state.specific_state.executed_instructions.as_mut(),
+            recorded,
+        ) {
+            executed.push(instruction);
+        }
+        Ok(())
     }
 }
 
@@ -1176,7 +1313,7 @@ where
     ) -> Result<QueryResponse, ValidationFail> {
         debug!(?query_request, "Executing as executor");
 
-        Runtime::default_execute_query(query_request, state)
+        Runtime::executor_execute_query(query_request, state)
     }
 
     #[codec::wrap]
@@ -1206,7 +1343,7 @@ where
         debug!(?query_request, "Executing as executor");
 
         let state = state.as_mut().unwrap();
-        Runtime::default_execute_query(query_request, state)
+        Runtime::executor_execute_query(query_request, state)
     }
 
     #[codec::wrap]
@@ -1428,6 +1565,149 @@ impl<'wrld, S: StateReadOnly> FakeSetExecutorDataModel<state::executor::Validate
     const ENTRYPOINT_FN_NAME: &'static str = "validate_query";
 }
 
+impl<'wrld, S: StateReadOnly> Runtime<state::executor::ValidateEventSubscription<'wrld, S>> {
+    /// Execute `validate_event_subscription()` entrypoint of the given module of runtime executor
+    ///
+    /// # Errors
+    ///
+    /// - if failed to instantiate provided `module`
+    /// - if unable to find expected function export
+    /// - if the execution of the smartcontract fails
+    /// - if unable to decode [`executor::Result`]
+    pub fn execute_executor_validate_event_subscription(
+        &self,
+        state_ro: &'wrld S,
+        subscriber: &AccountId,
+        module: &wasmtime::Module,
+        account: AccountId,
+    ) -> Result<executor::Result> {
+        let span = wasm_log_span!("Running `validate_event_subscription()`");
+
+        let Some(latest_block) = state_ro.latest_block() else {
+            return Ok(Err(ValidationFail::NotPermitted(
+                "Genesis not committed".to_owned(),
+            )));
+        };
+
+        let state = state::executor::ValidateEventSubscription::new(
+            subscriber.clone(),
+            self.config,
+            span,
+            state::chain_state::WithConst(state_ro),
+            state::specific::executor::ValidateEventSubscription::new(
+                account,
+                latest_block.as_ref().header(),
+            ),
+        );
+
+        self.execute_executor_execute_internal(
+            module,
+            state,
+            import::EXECUTOR_VALIDATE_EVENT_SUBSCRIPTION,
+        )
+    }
+}
+
+impl<'wrld, S: StateReadOnly>
+    import::traits::ExecuteOperations<state::executor::ValidateEventSubscription<'wrld, S>>
+    for Runtime<state::executor::ValidateEventSubscription<'wrld, S>>
+{
+    #[codec::wrap]
+    fn execute_query(
+        query_request: QueryRequest,
+        state: &mut state::executor::ValidateEventSubscription<'wrld, S>,
+    ) -> Result<QueryResponse, ValidationFail> {
+        debug!(?query_request, "Executing as executor");
+
+        Runtime::default_execute_query(query_request, state)
+    }
+
+    #[codec::wrap]
+    fn execute_instruction(
+        _instruction: InstructionBox,
+        _state: &mut state::executor::ValidateEventSubscription<'wrld, S>,
+    ) -> Result<(), ValidationFail> {
+        panic!(
+            "Executor `validate_event_subscription()` entrypoint should not execute instructions"
+        )
+    }
+}
+
+impl<'wrld, S: StateReadOnly>
+    FakeSetExecutorDataModel<state::executor::ValidateEventSubscription<'wrld, S>>
+    for Runtime<state::executor::ValidateEventSubscription<'wrld, S>>
+{
+    const ENTRYPOINT_FN_NAME: &'static str = "validate_event_subscription";
+}
+
+impl<'wrld, S: StateReadOnly> Runtime<state::executor::ValidateDomainInstruction<'wrld, S>> {
+    /// Execute `execute_instruction()` entrypoint of the given module of a domain validator
+    ///
+    /// # Errors
+    ///
+    /// - if failed to instantiate provided `module`
+    /// - if unable to find expected function export
+    /// - if the execution of the smartcontract fails
+    /// - if unable to decode [`executor::Result`]
+    pub fn execute_domain_validator(
+        &self,
+        state_ro: &'wrld S,
+        authority: &AccountId,
+        module: &wasmtime::Module,
+        instruction: InstructionBox,
+        curr_block: BlockHeader,
+    ) -> Result<executor::Result> {
+        let span = wasm_log_span!("Running domain validator `execute_instruction()`");
+
+        let state = state::executor::ValidateDomainInstruction::new(
+            authority.clone(),
+            self.config,
+            span,
+            state::chain_state::WithConst(state_ro),
+            state::specific::executor::ExecuteInstruction::new(instruction, curr_block),
+        );
+
+        self.execute_executor_execute_internal(module, state, import::EXECUTOR_EXECUTE_INSTRUCTION)
+    }
+}
+
+impl<'wrld, S: StateReadOnly>
+    import::traits::ExecuteOperations<state::executor::ValidateDomainInstruction<'wrld, S>>
+    for Runtime<state::executor::ValidateDomainInstruction<'wrld, S>>
+{
+    #[codec::wrap]
+    fn execute_query(
+        query_request: QueryRequest,
+        state: &mut state::executor::ValidateDomainInstruction<'wrld, S>,
+    ) -> Result<QueryResponse, ValidationFail> {
+        debug!(?query_request, "Executing as domain validator");
+
+        Runtime::default_execute_query(query_request, state)
+    }
+
+    #[codec::wrap]
+    fn execute_instruction(
+        instruction: InstructionBox,
+        state: &mut state::executor::ValidateDomainInstruction<'wrld, S>,
+    ) -> Result<(), ValidationFail> {
+        // The validated instruction has already been executed, so executing it is a no-op
+        if instruction == state.specific_state.to_validate {
+            return Ok(());
+        }
+
+        Err(ValidationFail::NotPermitted(
+            "Domain validator can't execute instructions other than the validated one".to_owned(),
+        ))
+    }
+}
+
+impl<'wrld, S: StateReadOnly>
+    FakeSetExecutorDataModel<state::executor::ValidateDomainInstruction<'wrld, S>>
+    for Runtime<state::executor::ValidateDomainInstruction<'wrld, S>>
+{
+    const ENTRYPOINT_FN_NAME: &'static str = "execute_instruction";
+}
+
 impl<'wrld, 'block, 'state> Runtime<state::executor::Migrate<'wrld, 'block, 'state>> {
     /// Execute `migrate()` entrypoint of *Executor*
     ///
@@ -1697,6 +1977,50 @@ impl<'wrld, S: StateReadOnly> RuntimeBuilder<state::executor::ValidateQuery<'wrl
     }
 }
 
+impl<'wrld, S: StateReadOnly> RuntimeBuilder<state::executor::ValidateEventSubscription<'wrld, S>> {
+    /// Builds the [`Runtime`] for *Executor* `validate_event_subscription()` execution
+    ///
+    /// # Errors
+    ///
+    /// Fails if failed to create default linker.
+    pub fn build(self) -> Result<Runtime<state::executor::ValidateEventSubscription<'wrld, S>>> {
+        self.finalize(|engine| {
+            let mut linker = Linker::new(engine);
+
+            create_imports!(linker, state::executor::ValidateEventSubscription<'_, S>,
+                export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::executor::ValidateEventSubscription<'_, S>>, offset, len| Runtime::execute_instruction(caller, offset, len),
+                export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::executor::ValidateEventSubscription<'_, S>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::SET_DATA_MODEL => |caller: ::wasmtime::Caller<state::executor::ValidateEventSubscription<'_, S>>, offset, len| Runtime::set_data_model(caller, offset, len),
+                export::ADD_FUEL => |caller: ::wasmtime::Caller<_>, offset, len| Runtime::add_fuel(caller, offset, len),
+                export::CONSUME_FUEL => |caller: ::wasmtime::Caller<_>, offset, len| Runtime::consume_fuel(caller, offset, len),
+            )?;
+            Ok(linker)
+        })
+    }
+}
+
+impl<'wrld, S: StateReadOnly> RuntimeBuilder<state::executor::ValidateDomainInstruction<'wrld, S>> {
+    /// Builds the [`Runtime`] for domain validator `execute_instruction()` execution
+    ///
+    /// # Errors
+    ///
+    /// Fails if failed to create default linker.
+    pub fn build(self) -> Result<Runtime<state::executor::ValidateDomainInstruction<'wrld, S>>> {
+        self.finalize(|engine| {
+            let mut linker = Linker::new(engine);
+
+            create_imports!(linker, state::executor::ValidateDomainInstruction<'_, S>,
+                export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::executor::ValidateDomainInstruction<'_, S>>, offset, len| Runtime::execute_instruction(caller, offset, len),
+                export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::executor::ValidateDomainInstruction<'_, S>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::SET_DATA_MODEL => |caller: ::wasmtime::Caller<state::executor::ValidateDomainInstruction<'_, S>>, offset, len| Runtime::set_data_model(caller, offset, len),
+                export::ADD_FUEL => |caller: ::wasmtime::Caller<_>, offset, len| Runtime::add_fuel(caller, offset, len),
+                export::CONSUME_FUEL => |caller: ::wasmtime::Caller<_>, offset, len| Runtime::consume_fuel(caller, offset, len),
+            )?;
+            Ok(linker)
+        })
+    }
+}
+
 impl<'wrld, 'block, 'state> RuntimeBuilder<state::executor::Migrate<'wrld, 'block, 'state>> {
     // FIXME: outdated doc. I guess it executes `migrate` entrypoint?
     /// Builds the [`Runtime`] to execute `permissions()` entrypoint of *Executor*
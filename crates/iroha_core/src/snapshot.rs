@@ -1,10 +1,10 @@
 //! This module contains [`State`] snapshot actor service.
 use std::{
-    io::Read,
-    num::NonZeroUsize,
+    io::{Read, Write},
+    num::{NonZeroU64, NonZeroUsize},
     path::{Path, PathBuf},
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use iroha_config::{parameters::actual::Snapshot as Config, snapshot::Mode};
@@ -12,7 +12,7 @@ use iroha_crypto::HashOf;
 use iroha_data_model::block::BlockHeader;
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
 use iroha_logger::prelude::*;
-use serde::{de::DeserializeSeed, Serialize};
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
 
 #[cfg(feature = "telemetry")]
 use crate::telemetry::StateTelemetry;
@@ -23,9 +23,13 @@ use crate::{
 };
 
 /// Name of the [`State`] snapshot file.
-const SNAPSHOT_FILE_NAME: &str = "snapshot.data";
+pub(crate) const SNAPSHOT_FILE_NAME: &str = "snapshot.data";
 /// Name of the temporary [`State`] snapshot file.
 const SNAPSHOT_TMP_FILE_NAME: &str = "snapshot.tmp";
+/// Size of the chunks the serialized snapshot is written to the file in, so that throttling is smooth.
+const SNAPSHOT_WRITE_CHUNK_SIZE: usize = 1024 * 1024;
+/// How often the state height is checked for block-based snapshots.
+const BLOCK_COUNT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
 
 // /// Errors produced by [`SnapshotMaker`] actor.
 // pub type Result<T, E = Error> = core::result::Result<T, E>;
@@ -35,10 +39,16 @@ pub struct SnapshotMaker {
     state: Arc<State>,
     /// Frequency at which snapshot is made
     create_every: Duration,
+    /// Number of blocks after which snapshot is made, [`None`] if only made periodically
+    create_every_blocks: Option<NonZeroUsize>,
     /// Path to the directory where snapshots are stored
     store_dir: PathBuf,
+    /// Upper limit of bytes per second written to disk, [`None`] if unlimited
+    write_rate_limit: Option<NonZeroU64>,
     /// Hash of the latest block stored in the state
     latest_block_hash: Option<HashOf<BlockHeader>>,
+    /// Height of the state stored in the latest snapshot
+    latest_height: usize,
 }
 
 impl SnapshotMaker {
@@ -54,16 +64,24 @@ impl SnapshotMaker {
         let mut snapshot_create_every = tokio::time::interval(self.create_every);
         // Don't try to create snapshot more frequently if previous take longer time
         snapshot_create_every.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut block_count_check = tokio::time::interval(BLOCK_COUNT_CHECK_INTERVAL);
+        block_count_check.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
         loop {
             tokio::select! {
                 _ = snapshot_create_every.tick() => {
                     // Offload snapshot creation into blocking thread
-                    self.create_snapshot().await;
+                    self.create_snapshot(self.write_rate_limit).await;
+                },
+                _ = block_count_check.tick(), if self.create_every_blocks.is_some() => {
+                    if self.is_block_count_reached() {
+                        self.create_snapshot(self.write_rate_limit).await;
+                    }
                 },
                 () = shutdown_signal.receive() => {
                     info!("Saving latest snapshot and shutting down");
-                    self.create_snapshot().await;
+                    // Shutdown is time-limited, so don't throttle the last snapshot
+                    self.create_snapshot(None).await;
                     break;
                 }
             }
@@ -71,28 +89,44 @@ impl SnapshotMaker {
         }
     }
 
+    fn is_block_count_reached(&self) -> bool {
+        self.create_every_blocks.is_some_and(|create_every_blocks| {
+            self.state
+                .view()
+                .height()
+                .saturating_sub(self.latest_height)
+                >= create_every_blocks.get()
+        })
+    }
+
     /// Invoke snapshot creation task
-    async fn create_snapshot(&mut self) {
+    async fn create_snapshot(&mut self, write_rate_limit: Option<NonZeroU64>) {
         let store_dir = self.store_dir.clone();
-        let latest_block_hash;
-        let at_height;
-        {
-            let state_view = self.state.view();
-            latest_block_hash = state_view.latest_block_hash();
-            at_height = state_view.height();
-        }
+        let latest_block_hash = self.state.view().latest_block_hash();
 
         if latest_block_hash != self.latest_block_hash {
             let state = self.state.clone();
-            let handle = tokio::task::spawn_blocking(move || -> Result<(), TryWriteError> {
-                // TODO: enhance error by attaching `store_dir` parameter origin
-                try_write_snapshot(&state, store_dir)
-            });
+            let handle = tokio::task::spawn_blocking(
+                move || -> Result<(SnapshotCursor, Duration), TryWriteError> {
+                    let started_at = Instant::now();
+                    // TODO: enhance error by attaching `store_dir` parameter origin
+                    let cursor = try_write_snapshot(&state, store_dir, write_rate_limit)?;
+                    let elapsed = started_at.elapsed();
+                    #[cfg(feature = "telemetry")]
+                    state.telemetry.observe_snapshot_duration(elapsed);
+                    Ok((cursor, elapsed))
+                },
+            );
 
             match handle.await {
-                Ok(Ok(())) => {
-                    iroha_logger::info!(at_height, "Successfully created a snapshot of state");
-                    self.latest_block_hash = latest_block_hash;
+                Ok(Ok((cursor, elapsed))) => {
+                    iroha_logger::info!(
+                        at_height = cursor.height,
+                        elapsed_ms = elapsed.as_millis(),
+                        "Successfully created a snapshot of state"
+                    );
+                    self.latest_block_hash = cursor.block_hash;
+                    self.latest_height = cursor.height;
                 }
                 Ok(Err(error)) => {
                     iroha_logger::error!(%error, "Failed to create a snapshot of state");
@@ -109,12 +143,20 @@ impl SnapshotMaker {
     /// Might return [`None`] if the configuration is not suitable for _making_ snapshots.
     pub fn from_config(config: &Config, state: Arc<State>) -> Option<Self> {
         if let Mode::ReadWrite = config.mode {
-            let latest_block_hash = state.view().latest_block_hash();
+            let (latest_block_hash, latest_height) = {
+                let state_view = state.view();
+                (state_view.latest_block_hash(), state_view.height())
+            };
             Some(Self {
                 state,
                 create_every: config.create_every_ms.get(),
+                create_every_blocks: usize::try_from(config.create_every_blocks)
+                    .ok()
+                    .and_then(NonZeroUsize::new),
                 store_dir: config.store_dir.resolve_relative_path(),
+                write_rate_limit: NonZeroU64::new(config.write_rate_limit.get()),
                 latest_block_hash,
+                latest_height,
             })
         } else {
             None
@@ -122,8 +164,25 @@ impl SnapshotMaker {
     }
 }
 
+/// Position in the chain of the state stored in a snapshot.
+///
+/// It's taken together with the state and written on the first line of the snapshot,
+/// so that a restarted peer can check the snapshot against the block store without
+/// deserializing the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotCursor {
+    /// Height of the state stored in the snapshot
+    pub height: usize,
+    /// Hash of the block at [`Self::height`]
+    pub block_hash: Option<HashOf<BlockHeader>>,
+}
+
 /// Try to deserialize [`State`] from a snapshot file.
 ///
+/// The snapshot is checked against its [`SnapshotCursor`] before the state is deserialized,
+/// if there is one, and against the block hashes held by [`Kura`] afterwards. Only blocks
+/// after the snapshot have to be applied to the returned state.
+///
 /// # Errors
 /// - IO errors
 /// - Deserialization errors
@@ -134,21 +193,12 @@ pub fn try_read_snapshot(
     BlockCount(block_count): BlockCount,
     #[cfg(feature = "telemetry")] telemetry: StateTelemetry,
 ) -> Result<State, TryReadError> {
-    let mut bytes = Vec::new();
-    let path = store_dir.as_ref().join(SNAPSHOT_FILE_NAME);
-    let mut file = match std::fs::OpenOptions::new().read(true).open(&path) {
-        Ok(file) => file,
-        Err(err) => {
-            return if err.kind() == std::io::ErrorKind::NotFound {
-                Err(TryReadError::NotFound)
-            } else {
-                Err(TryReadError::IO(err, path.clone()))
-            }
-        }
-    };
-    file.read_to_end(&mut bytes)
-        .map_err(|err| TryReadError::IO(err, path.clone()))?;
-    let mut deserializer = serde_json::Deserializer::from_slice(&bytes);
+    let bytes = read_snapshot_bytes(store_dir)?;
+    let (cursor, state_bytes) = split_cursor(&bytes)?;
+    if let Some(cursor) = cursor {
+        check_cursor(cursor, kura, block_count)?;
+    }
+    let mut deserializer = serde_json::Deserializer::from_slice(state_bytes);
     let seed = KuraSeed {
         kura: Arc::clone(kura),
         query_handle: live_query_store_lazy(),
@@ -165,22 +215,27 @@ pub fn try_read_snapshot(
         });
     }
     for height in 1..=snapshot_height {
-        let kura_block = kura
-            .get_block(NonZeroUsize::new(height).expect("iterating from 1"))
+        let height_nonzero = NonZeroUsize::new(height).expect("iterating from 1");
+        // Hashes are held in memory, so blocks are only loaded from disk to revert the latest one
+        let kura_block_hash = kura
+            .get_block_hash(height_nonzero)
             .expect("Kura has height at least as large as state height");
         let snapshot_block_hash = state_view.block_hashes[height - 1];
-        if kura_block.hash() != snapshot_block_hash {
+        if kura_block_hash != snapshot_block_hash {
             // If last block hash is different it might mean that snapshot was crated for soft-fork block so just drop changes made by this block
             if height == snapshot_height {
                 iroha_logger::warn!(
                     "Snapshot has incorrect latest block hash, discarding changes made by this block"
                 );
+                let kura_block = kura
+                    .get_block(height_nonzero)
+                    .expect("Kura has height at least as large as state height");
                 state.block_and_revert(kura_block.header()).commit();
             } else {
                 return Err(TryReadError::MismatchedHash {
                     height,
                     snapshot_block_hash,
-                    kura_block_hash: kura_block.hash(),
+                    kura_block_hash,
                 });
             }
         }
@@ -188,30 +243,209 @@ pub fn try_read_snapshot(
     Ok(state)
 }
 
+/// Check that the snapshot at `cursor` can be applied to the blocks stored by [`Kura`].
+fn check_cursor(
+    SnapshotCursor { height, block_hash }: SnapshotCursor,
+    kura: &Kura,
+    block_count: usize,
+) -> Result<(), TryReadError> {
+    if height > block_count {
+        return Err(TryReadError::MismatchedHeight {
+            snapshot_height: height,
+            kura_height: block_count,
+        });
+    }
+    // The latest block might be replaced by a soft-fork, which is handled once the state is read
+    if height == block_count {
+        return Ok(());
+    }
+    let (Some(height), Some(snapshot_block_hash)) = (NonZeroUsize::new(height), block_hash) else {
+        return Ok(());
+    };
+    let kura_block_hash = kura
+        .get_block_hash(height)
+        .expect("Kura has height at least as large as cursor height");
+    if kura_block_hash != snapshot_block_hash {
+        return Err(TryReadError::MismatchedHash {
+            height: height.get(),
+            snapshot_block_hash,
+            kura_block_hash,
+        });
+    }
+    Ok(())
+}
+
+/// Split the snapshot into its [`SnapshotCursor`] and the serialized state.
+///
+/// The cursor is [`None`] if the snapshot was written without one.
+fn split_cursor(bytes: &[u8]) -> Result<(Option<SnapshotCursor>, &[u8]), TryReadError> {
+    // Serialized state contains no line breaks, so the first one ends the cursor
+    match bytes.iter().position(|byte| *byte == b'\n') {
+        Some(end) => Ok((
+            Some(serde_json::from_slice(&bytes[..end])?),
+            &bytes[end + 1..],
+        )),
+        None => Ok((None, bytes)),
+    }
+}
+
+/// Deserialize [`State`] from a snapshot file without checking it against the block store.
+///
+/// Intended for offline inspection of snapshots, e.g. comparing them with
+/// [`WorldDiff`](crate::state::diff::WorldDiff). The returned state is backed by an
+/// in-memory [`Kura`] and must not be used to run a peer.
+///
+/// # Errors
+/// - IO errors
+/// - Deserialization errors
+pub fn read_snapshot_unchecked(
+    store_dir: impl AsRef<Path>,
+    live_query_store_lazy: impl FnOnce() -> LiveQueryStoreHandle,
+) -> Result<State, TryReadError> {
+    let bytes = read_snapshot_bytes(store_dir)?;
+    let (_cursor, state_bytes) = split_cursor(&bytes)?;
+    let mut deserializer = serde_json::Deserializer::from_slice(state_bytes);
+    let seed = KuraSeed {
+        kura: Kura::blank_kura_for_testing(),
+        query_handle: live_query_store_lazy(),
+        #[cfg(feature = "telemetry")]
+        telemetry: StateTelemetry::default(),
+    };
+    Ok(seed.deserialize(&mut deserializer)?)
+}
+
+fn read_snapshot_bytes(store_dir: impl AsRef<Path>) -> Result<Vec<u8>, TryReadError> {
+    let mut bytes = Vec::new();
+    let path = store_dir.as_ref().join(SNAPSHOT_FILE_NAME);
+    let mut file = match std::fs::OpenOptions::new().read(true).open(&path) {
+        Ok(file) => file,
+        Err(err) => {
+            return if err.kind() == std::io::ErrorKind::NotFound {
+                Err(TryReadError::NotFound)
+            } else {
+                Err(TryReadError::IO(err, path.clone()))
+            }
+        }
+    };
+    file.read_to_end(&mut bytes)
+        .map_err(|err| TryReadError::IO(err, path.clone()))?;
+    Ok(bytes)
+}
+
 /// Serialize and write snapshot to file,
 /// overwriting any previously stored data.
 ///
+/// A view of the state is serialized, so every storage is at the same height while blocks
+/// keep being committed. Writing it to disk is throttled to `write_rate_limit` bytes
+/// per second, if set.
+///
+/// The [`SnapshotCursor`] is taken from the same view and written in front of the state. The snapshot is synced to disk before it replaces the previous one,
+/// so a crash at any point leaves a complete snapshot behind.
+///
 /// # Errors
 /// - IO errors
 /// - Serialization errors
-fn try_write_snapshot(state: &State, store_dir: impl AsRef<Path>) -> Result<(), TryWriteError> {
-    std::fs::create_dir_all(store_dir.as_ref())
-        .map_err(|err| TryWriteError::IO(err, store_dir.as_ref().to_path_buf()))?;
-    let path_to_file = store_dir.as_ref().join(SNAPSHOT_FILE_NAME);
-    let path_to_tmp_file = store_dir.as_ref().join(SNAPSHOT_TMP_FILE_NAME);
-    let file = std::fs::OpenOptions::new()
+pub(crate) fn try_write_snapshot(
+    state: &State,
+    store_dir: impl AsRef<Path>,
+    write_rate_limit: Option<NonZeroU64>,
+) -> Result<SnapshotCursor, TryWriteError> {
+    let store_dir = store_dir.as_ref();
+    std::fs::create_dir_all(store_dir)
+        .map_err(|err| TryWriteError::IO(err, store_dir.to_path_buf()))?;
+    let state_view = state.view();
+    let cursor = SnapshotCursor {
+        height: state_view.block_hashes.len(),
+        block_hash: state_view.block_hashes.last().copied(),
+    };
+    let mut bytes = serde_json::to_vec(&cursor)?;
+    bytes.push(b'\n');
+    serde_json::to_writer(&mut bytes, &state_view)?;
+    // Storages keep the versions seen by the view alive, so it shouldn't outlive serialization
+    drop(state_view);
+
+    let path_to_tmp_file = store_dir.join(SNAPSHOT_TMP_FILE_NAME);
+    let file = create_tmp_file(&path_to_tmp_file)?;
+    let mut writer = ThrottledWriter::new(file, write_rate_limit);
+    for chunk in bytes.chunks(SNAPSHOT_WRITE_CHUNK_SIZE) {
+        writer
+            .write_all(chunk)
+            .map_err(|err| TryWriteError::IO(err, path_to_tmp_file.clone()))?;
+    }
+    let file = writer.inner;
+    replace_with_tmp_file(store_dir, &file, path_to_tmp_file, SNAPSHOT_FILE_NAME)?;
+
+    Ok(cursor)
+}
+
+fn create_tmp_file(path: &Path) -> Result<std::fs::File, TryWriteError> {
+    std::fs::OpenOptions::new()
         .create(true)
         .write(true)
         .truncate(true)
-        .open(&path_to_tmp_file)
+        .open(path)
+        .map_err(|err| TryWriteError::IO(err, path.to_path_buf()))
+}
+
+/// Sync the written temporary file and atomically move it to `file_name`.
+fn replace_with_tmp_file(
+    store_dir: &Path,
+    file: &std::fs::File,
+    path_to_tmp_file: PathBuf,
+    file_name: &str,
+) -> Result<(), TryWriteError> {
+    file.sync_all()
         .map_err(|err| TryWriteError::IO(err, path_to_tmp_file.clone()))?;
-    let mut serializer = serde_json::Serializer::new(file);
-    state.serialize(&mut serializer)?;
+    let path_to_file = store_dir.join(file_name);
     std::fs::rename(path_to_tmp_file, &path_to_file)
         .map_err(|err| TryWriteError::IO(err, path_to_file.clone()))?;
+    // Persist the rename itself
+    #[cfg(unix)]
+    std::fs::File::open(store_dir)
+        .and_then(|dir| dir.sync_all())
+        .map_err(|err| TryWriteError::IO(err, store_dir.to_path_buf()))?;
     Ok(())
 }
 
+/// [`Write`] adapter which sleeps to keep the average write rate under a limit.
+struct ThrottledWriter<W> {
+    inner: W,
+    /// Upper limit of bytes per second, [`None`] if unlimited
+    bytes_per_sec: Option<NonZeroU64>,
+    written: u64,
+    started_at: Instant,
+}
+
+impl<W: Write> ThrottledWriter<W> {
+    fn new(inner: W, bytes_per_sec: Option<NonZeroU64>) -> Self {
+        Self {
+            inner,
+            bytes_per_sec,
+            written: 0,
+            started_at: Instant::now(),
+        }
+    }
+}
+
+impl<W: Write> Write for ThrottledWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.written = self.written.saturating_add(written as u64);
+        if let Some(bytes_per_sec) = self.bytes_per_sec {
+            let nanos = u128::from(self.written) * 1_000_000_000 / u128::from(bytes_per_sec.get());
+            let budget = Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX));
+            if let Some(ahead) = budget.checked_sub(self.started_at.elapsed()) {
+                std::thread::sleep(ahead);
+            }
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 /// Error variants for snapshot reading
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
 pub enum TryReadError {
@@ -241,7 +475,7 @@ pub enum TryReadError {
 
 /// Error variants for snapshot writing
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
-enum TryWriteError {
+pub enum TryWriteError {
     /// Failed reading/writing {1:?} from disk
     IO(#[source] std::io::Error, PathBuf),
     /// Error (de)serializing World State View snapshot
@@ -279,7 +513,7 @@ mod tests {
         let snapshot_store_dir = tmp_root.path().join("path/to/snapshot/dir");
         let state = state_factory();
 
-        try_write_snapshot(&state, &snapshot_store_dir).unwrap();
+        try_write_snapshot(&state, &snapshot_store_dir, None).unwrap();
 
         assert!(Path::exists(snapshot_store_dir.as_path()))
     }
@@ -290,7 +524,7 @@ mod tests {
         let store_dir = tmp_root.path().join("snapshot");
         let state = state_factory();
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, None).unwrap();
         let _wsv = try_read_snapshot(
             &store_dir,
             &Kura::blank_kura_for_testing(),
@@ -302,6 +536,67 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    async fn cursor_is_written_with_snapshot() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let state = state_factory();
+
+        let cursor = try_write_snapshot(&state, &store_dir, None).unwrap();
+
+        assert_eq!(cursor.height, state.view().height());
+        let bytes = read_snapshot_bytes(&store_dir).unwrap();
+        assert_eq!(split_cursor(&bytes).unwrap().0, Some(cursor));
+        assert!(!store_dir.join(SNAPSHOT_TMP_FILE_NAME).exists());
+    }
+
+    #[test]
+    async fn snapshot_is_rejected_by_cursor_before_reading() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        std::fs::create_dir(&store_dir).unwrap();
+        let cursor = SnapshotCursor {
+            height: 5,
+            block_hash: None,
+        };
+        let mut bytes = serde_json::to_vec(&cursor).unwrap();
+        // Not a valid state, so the error would differ if it was read
+        bytes.extend_from_slice(&[b'\n', 1, 4, 1, 2]);
+        std::fs::write(store_dir.join(SNAPSHOT_FILE_NAME), bytes).unwrap();
+
+        let Err(error) = try_read_snapshot(
+            &store_dir,
+            &Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test,
+            BlockCount(2),
+            #[cfg(feature = "telemetry")]
+            StateTelemetry::default(),
+        ) else {
+            panic!("should not be ok")
+        };
+
+        assert!(matches!(
+            error,
+            TryReadError::MismatchedHeight {
+                snapshot_height: 5,
+                kura_height: 2
+            }
+        ));
+    }
+
+    #[test]
+    async fn throttled_writer_respects_rate_limit() {
+        let mut writer = ThrottledWriter::new(Vec::new(), NonZeroU64::new(1000));
+        let started_at = Instant::now();
+
+        for chunk in [0_u8; 500].chunks(100) {
+            writer.write_all(chunk).unwrap();
+        }
+
+        assert!(started_at.elapsed() >= Duration::from_millis(500));
+        assert_eq!(writer.inner.len(), 500);
+    }
+
     #[test]
     async fn cannot_find_snapshot_on_read_is_not_found() {
         let tmp_root = tempdir().unwrap();
@@ -391,7 +686,7 @@ mod tests {
         }
         kura.store_block(committed_block);
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, None).unwrap();
 
         let state = try_read_snapshot(
             &store_dir,
@@ -465,7 +760,7 @@ mod tests {
             .unwrap();
         kura.store_block(committed_block);
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, None).unwrap();
 
         let state = try_read_snapshot(
             &store_dir,
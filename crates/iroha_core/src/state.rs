@@ -4,6 +4,7 @@ use std::{
 };
 
 use eyre::Result;
+use iroha_config::parameters::actual::RedactionSecret;
 use iroha_crypto::HashOf;
 use iroha_data_model::{
     account::{AccountEntry, AccountValue},
@@ -45,26 +46,42 @@ pub use storage_transactions::TransactionsReadOnly;
 #[cfg(feature = "telemetry")]
 use crate::telemetry::StateTelemetry;
 use crate::{
+    asset_movement::{self, AssetMovementId},
     block::CommittedBlock,
     executor::Executor,
+    executor_upgrade::{PendingExecutorUpgrade, PendingExecutorUpgradePolicy},
     kura::Kura,
+    nft_content_index::{content_trigrams, NftContentTrigram, Trigram},
+    permission_grant::{self, PermissionGrantEventId},
+    permission_revocation::{PermissionRevocation, PermissionRevocations},
     query::store::LiveQueryStoreHandle,
+    redaction::{RedactableKey, Redaction},
     role::RoleIdWithOwner,
     smartcontracts::{
+        isi::domain::LoadedDomainValidator,
         triggers::{
             set::{
-                ExecutableRef, Set as TriggerSet, SetBlock as TriggerSetBlock,
+                execution_order, ExecutableRef, Set as TriggerSet, SetBlock as TriggerSetBlock,
                 SetReadOnly as TriggerSetReadOnly, SetTransaction as TriggerSetTransaction,
                 SetView as TriggerSetView,
             },
             specialized::{LoadedAction, LoadedActionTrait},
         },
-        wasm,
+        wasm, Execute,
     },
-    state::storage_transactions::{TransactionsBlock, TransactionsStorage, TransactionsView},
+    state::{
+        permission_cache::PermissionCache,
+        storage_transactions::{TransactionsBlock, TransactionsStorage, TransactionsView},
+    },
+    tx::SignatureCache,
     Peers,
 };
 
+pub(crate) mod detached;
+pub mod diff;
+pub mod dry_run;
+pub mod export;
+pub(crate) mod permission_cache;
 pub(crate) mod storage_transactions;
 
 /// The global entity consisting of `domains`, `triggers` and etc.
@@ -85,18 +102,45 @@ pub struct World {
     pub(crate) assets: Storage<AssetId, AssetValue>,
     /// Non fungible assets.
     pub(crate) nfts: Storage<NftId, NftValue>,
+    /// Trigrams of the string values in the content of NFTs, for searching NFTs by content.
+    ///
+    /// Derived from [`Self::nfts`], hence rebuilt instead of being stored in snapshots.
+    #[serde(skip)]
+    pub(crate) nft_content_index: Storage<NftContentTrigram, ()>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: Storage<RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: Storage<AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: Storage<RoleIdWithOwner, ()>,
+    /// Role templates. [`RoleTemplate`] pairs.
+    pub(crate) role_templates: Storage<RoleId, RoleTemplate>,
+    /// Anchored content hashes.
+    pub(crate) anchors: Storage<Hash, AnchoredHash>,
+    /// Asset balance changes indexed by account, for account statements.
+    pub(crate) asset_movements: Storage<AssetMovementId, AssetMovement>,
+    /// Audit log of permission grants and revocations.
+    pub(crate) permission_grants: Storage<PermissionGrantEventId, PermissionGrantEvent>,
+    /// Conditions on which permissions of an account are revoked automatically.
+    pub(crate) permission_revocations: Storage<AccountId, PermissionRevocations>,
+    /// Supplementary validation modules registered by domain owners.
+    pub(crate) domain_validators: Storage<DomainId, LoadedDomainValidator>,
+    /// Metadata values marked redactable, with their redaction once they are redacted.
+    pub(crate) redactions: Storage<RedactableKey, Option<Redaction>>,
+    /// Pending offers of domains to new owners, which take the domain over on acceptance.
+    pub(crate) domain_transfer_offers: Storage<DomainId, DomainTransferOffer>,
+    /// Amounts transferred from an account, maintained by the executor to enforce spending caps.
+    pub(crate) spending_counters: Storage<AccountId, Vec<SpendingCounter>>,
     /// Triggers
     pub(crate) triggers: TriggerSet,
     /// Runtime Executor
     pub(crate) executor: Cell<Executor>,
     /// Executor-defined data model
     pub(crate) executor_data_model: Cell<ExecutorDataModel>,
+    /// Executor upgrade waiting for its activation height.
+    pub(crate) pending_executor_upgrade: Cell<Option<PendingExecutorUpgrade>>,
+    /// Loosening of the executor upgrade policy waiting for its activation height.
+    pub(crate) pending_executor_upgrade_policy: Cell<Option<PendingExecutorUpgradePolicy>>,
     /// Placeholder buffer of events pending publication to external subscribers.
     /// Included for formal correctness, although used only below the block level.
     external_event_buf: Cell<Vec<EventBox>>,
@@ -118,20 +162,48 @@ pub struct WorldBlock<'world> {
     pub(crate) assets: StorageBlock<'world, AssetId, AssetValue>,
     /// Registered NFTs.
     pub(crate) nfts: StorageBlock<'world, NftId, NftValue>,
+    /// Trigrams of the string values in the content of NFTs, for searching NFTs by content.
+    pub(crate) nft_content_index: StorageBlock<'world, NftContentTrigram, ()>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageBlock<'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageBlock<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageBlock<'world, RoleIdWithOwner, ()>,
+    /// Role templates. [`RoleTemplate`] pairs.
+    pub(crate) role_templates: StorageBlock<'world, RoleId, RoleTemplate>,
+    /// Anchored content hashes.
+    pub(crate) anchors: StorageBlock<'world, Hash, AnchoredHash>,
+    /// Asset balance changes indexed by account, for account statements.
+    pub(crate) asset_movements: StorageBlock<'world, AssetMovementId, AssetMovement>,
+    /// Audit log of permission grants and revocations.
+    pub(crate) permission_grants:
+        StorageBlock<'world, PermissionGrantEventId, PermissionGrantEvent>,
+    /// Conditions on which permissions of an account are revoked automatically.
+    pub(crate) permission_revocations: StorageBlock<'world, AccountId, PermissionRevocations>,
+    /// Supplementary validation modules registered by domain owners.
+    pub(crate) domain_validators: StorageBlock<'world, DomainId, LoadedDomainValidator>,
+    /// Metadata values marked redactable, with their redaction once they are redacted.
+    pub(crate) redactions: StorageBlock<'world, RedactableKey, Option<Redaction>>,
+    /// Pending offers of domains to new owners, which take the domain over on acceptance.
+    pub(crate) domain_transfer_offers: StorageBlock<'world, DomainId, DomainTransferOffer>,
+    /// Amounts transferred from an account, maintained by the executor to enforce spending caps.
+    pub(crate) spending_counters: StorageBlock<'world, AccountId, Vec<SpendingCounter>>,
     /// Triggers
     pub(crate) triggers: TriggerSetBlock<'world>,
     /// Runtime Executor
     pub(crate) executor: CellBlock<'world, Executor>,
     /// Executor-defined data model
     pub(crate) executor_data_model: CellBlock<'world, ExecutorDataModel>,
+    /// Executor upgrade waiting for its activation height.
+    pub(crate) pending_executor_upgrade: CellBlock<'world, Option<PendingExecutorUpgrade>>,
+    /// Loosening of the executor upgrade policy waiting for its activation height.
+    pub(crate) pending_executor_upgrade_policy:
+        CellBlock<'world, Option<PendingExecutorUpgradePolicy>>,
     /// Buffer of events pending publication to external subscribers.
     external_event_buf: CellBlock<'world, Vec<EventBox>>,
+    /// Outputs of permission-resolution queries made by the executor during this block
+    pub(crate) permission_cache: PermissionCache,
 }
 
 /// Struct for single transaction's aggregated changes
@@ -151,23 +223,71 @@ pub struct WorldTransaction<'block, 'world> {
     pub(crate) assets: StorageTransaction<'block, 'world, AssetId, AssetValue>,
     /// Registered NFTs.
     pub(crate) nfts: StorageTransaction<'block, 'world, NftId, NftValue>,
+    /// Trigrams of the string values in the content of NFTs, for searching NFTs by content.
+    pub(crate) nft_content_index: StorageTransaction<'block, 'world, NftContentTrigram, ()>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageTransaction<'block, 'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageTransaction<'block, 'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageTransaction<'block, 'world, RoleIdWithOwner, ()>,
+    /// Role templates. [`RoleTemplate`] pairs.
+    pub(crate) role_templates: StorageTransaction<'block, 'world, RoleId, RoleTemplate>,
+    /// Anchored content hashes.
+    pub(crate) anchors: StorageTransaction<'block, 'world, Hash, AnchoredHash>,
+    /// Asset balance changes indexed by account, for account statements.
+    pub(crate) asset_movements: StorageTransaction<'block, 'world, AssetMovementId, AssetMovement>,
+    /// Audit log of permission grants and revocations.
+    pub(crate) permission_grants:
+        StorageTransaction<'block, 'world, PermissionGrantEventId, PermissionGrantEvent>,
+    /// Conditions on which permissions of an account are revoked automatically.
+    pub(crate) permission_revocations:
+        StorageTransaction<'block, 'world, AccountId, PermissionRevocations>,
+    /// Supplementary validation modules registered by domain owners.
+    pub(crate) domain_validators:
+        StorageTransaction<'block, 'world, DomainId, LoadedDomainValidator>,
+    /// Metadata values marked redactable, with their redaction once they are redacted.
+    pub(crate) redactions: StorageTransaction<'block, 'world, RedactableKey, Option<Redaction>>,
+    /// Pending offers of domains to new owners, which take the domain over on acceptance.
+    pub(crate) domain_transfer_offers:
+        StorageTransaction<'block, 'world, DomainId, DomainTransferOffer>,
+    /// Amounts transferred from an account, maintained by the executor to enforce spending caps.
+    pub(crate) spending_counters:
+        StorageTransaction<'block, 'world, AccountId, Vec<SpendingCounter>>,
     /// Triggers
     pub(crate) triggers: TriggerSetTransaction<'block, 'world>,
     /// Runtime Executor
     pub(crate) executor: CellTransaction<'block, 'world, Executor>,
     /// Executor-defined data model
     pub(crate) executor_data_model: CellTransaction<'block, 'world, ExecutorDataModel>,
+    /// Executor upgrade waiting for its activation height.
+    pub(crate) pending_executor_upgrade:
+        CellTransaction<'block, 'world, Option<PendingExecutorUpgrade>>,
+    /// Loosening of the executor upgrade policy waiting for its activation height.
+    pub(crate) pending_executor_upgrade_policy:
+        CellTransaction<'block, 'world, Option<PendingExecutorUpgradePolicy>>,
     /// Buffer of events pending publication to external subscribers.
     external_event_buf: CellTransaction<'block, 'world, Vec<EventBox>>,
     /// Data events buffered during a single execution step
     /// -- either the initial step (transaction or time trigger) or a subsequent step (data trigger).
     internal_event_buf: Vec<DataEvent>,
+    /// Asset balance changes awaiting the hash of their entrypoint to be indexed
+    asset_movement_buf: Vec<PendingAssetMovement>,
+    /// Outputs of permission-resolution queries made by the executor during this block
+    pub(crate) permission_cache: &'block PermissionCache,
+    /// Whether this transaction changed roles, permissions or accounts,
+    /// in which case [`Self::permission_cache`] must be bypassed
+    pub(crate) permissions_changed: bool,
+}
+
+/// Asset balance change made by an entrypoint whose hash is not known yet
+struct PendingAssetMovement {
+    account: AccountId,
+    asset_definition: AssetDefinitionId,
+    direction: MovementDirection,
+    amount: Numeric,
+    balance: Numeric,
+    counterparty: Option<AccountId>,
 }
 
 /// Consistent point in time view of the [`World`]
@@ -186,18 +306,43 @@ pub struct WorldView<'world> {
     pub(crate) assets: StorageView<'world, AssetId, AssetValue>,
     /// Registered NFTs.
     pub(crate) nfts: StorageView<'world, NftId, NftValue>,
+    /// Trigrams of the string values in the content of NFTs, for searching NFTs by content.
+    pub(crate) nft_content_index: StorageView<'world, NftContentTrigram, ()>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageView<'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageView<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageView<'world, RoleIdWithOwner, ()>,
+    /// Role templates. [`RoleTemplate`] pairs.
+    pub(crate) role_templates: StorageView<'world, RoleId, RoleTemplate>,
+    /// Anchored content hashes.
+    pub(crate) anchors: StorageView<'world, Hash, AnchoredHash>,
+    /// Asset balance changes indexed by account, for account statements.
+    pub(crate) asset_movements: StorageView<'world, AssetMovementId, AssetMovement>,
+    /// Audit log of permission grants and revocations.
+    pub(crate) permission_grants: StorageView<'world, PermissionGrantEventId, PermissionGrantEvent>,
+    /// Conditions on which permissions of an account are revoked automatically.
+    pub(crate) permission_revocations: StorageView<'world, AccountId, PermissionRevocations>,
+    /// Supplementary validation modules registered by domain owners.
+    pub(crate) domain_validators: StorageView<'world, DomainId, LoadedDomainValidator>,
+    /// Metadata values marked redactable, with their redaction once they are redacted.
+    pub(crate) redactions: StorageView<'world, RedactableKey, Option<Redaction>>,
+    /// Pending offers of domains to new owners, which take the domain over on acceptance.
+    pub(crate) domain_transfer_offers: StorageView<'world, DomainId, DomainTransferOffer>,
+    /// Amounts transferred from an account, maintained by the executor to enforce spending caps.
+    pub(crate) spending_counters: StorageView<'world, AccountId, Vec<SpendingCounter>>,
     /// Triggers
     pub(crate) triggers: TriggerSetView<'world>,
     /// Runtime Executor
     pub(crate) executor: CellView<'world, Executor>,
     /// Executor-defined data model
     pub(crate) executor_data_model: CellView<'world, ExecutorDataModel>,
+    /// Executor upgrade waiting for its activation height.
+    pub(crate) pending_executor_upgrade: CellView<'world, Option<PendingExecutorUpgrade>>,
+    /// Loosening of the executor upgrade policy waiting for its activation height.
+    pub(crate) pending_executor_upgrade_policy:
+        CellView<'world, Option<PendingExecutorUpgradePolicy>>,
 }
 
 /// Current state of the blockchain
@@ -224,6 +369,12 @@ pub struct State {
     /// Handle to the [`LiveQueryStore`](crate::query::store::LiveQueryStore).
     #[serde(skip)]
     pub query_handle: LiveQueryStoreHandle,
+    /// Hashes of transactions with already verified signatures
+    #[serde(skip)]
+    pub signature_cache: SignatureCache,
+    /// Secret the salts of redacted metadata values are derived from, never put into snapshots.
+    #[serde(skip)]
+    redaction_secret: Option<RedactionSecret>,
     /// State telemetry
     // TODO: this should be done through events
     #[cfg(feature = "telemetry")]
@@ -253,6 +404,10 @@ pub struct StateBlock<'state> {
     kura: &'state Kura,
     /// Handle to the [`LiveQueryStore`](crate::query::store::LiveQueryStore).
     pub query_handle: &'state LiveQueryStoreHandle,
+    /// Hashes of transactions with already verified signatures
+    pub signature_cache: &'state SignatureCache,
+    /// Secret the salts of redacted metadata values are derived from.
+    redaction_secret: Option<&'state RedactionSecret>,
     /// State telemetry
     #[cfg(feature = "telemetry")]
     pub telemetry: &'state StateTelemetry,
@@ -268,6 +423,8 @@ pub struct StateTransaction<'block, 'state> {
     pub world: WorldTransaction<'block, 'state>,
     /// Blockchain.
     pub block_hashes: CellTransaction<'block, 'state, Vec<HashOf<BlockHeader>>>,
+    /// Hashes of transactions mapped onto block height where they stored
+    pub transactions: &'block TransactionsBlock<'state>,
     /// Topology used to commit latest block
     pub commit_topology: CellTransaction<'block, 'state, Vec<PeerId>>,
     /// Topology used to commit previous block
@@ -279,6 +436,8 @@ pub struct StateTransaction<'block, 'state> {
     kura: &'state Kura,
     /// Handle to the [`LiveQueryStore`](crate::query::store::LiveQueryStore).
     pub query_handle: &'state LiveQueryStoreHandle,
+    /// Secret the salts of redacted metadata values are derived from.
+    redaction_secret: Option<&'state RedactionSecret>,
     /// State telemetry
     #[cfg(feature = "telemetry")]
     pub telemetry: &'state StateTelemetry,
@@ -305,6 +464,10 @@ pub struct StateView<'state> {
     kura: &'state Kura,
     /// Handle to the [`LiveQueryStore`](crate::query::store::LiveQueryStore).
     pub query_handle: &'state LiveQueryStoreHandle,
+    /// Hashes of transactions with already verified signatures
+    pub signature_cache: &'state SignatureCache,
+    /// Secret the salts of redacted metadata values are derived from.
+    redaction_secret: Option<&'state RedactionSecret>,
     /// State telemetry
     #[cfg(feature = "telemetry")]
     pub telemetry: &'state StateTelemetry,
@@ -358,16 +521,30 @@ impl World {
             .map(IntoKeyValue::into_key_value)
             .collect();
         let nfts = nfts.into_iter().map(IntoKeyValue::into_key_value).collect();
+        let nft_content_index = Self::index_nft_contents(&nfts);
         Self {
             domains,
             accounts,
             asset_definitions,
             assets,
             nfts,
+            nft_content_index,
             ..Self::new()
         }
     }
 
+    /// Build the index of [`Self::nft_content_index`] from scratch.
+    fn index_nft_contents(nfts: &Storage<NftId, NftValue>) -> Storage<NftContentTrigram, ()> {
+        nfts.view()
+            .iter()
+            .flat_map(|(id, nft)| {
+                content_trigrams(&nft.content)
+                    .into_iter()
+                    .map(|trigram| (NftContentTrigram::new(trigram, id.clone()), ()))
+            })
+            .collect()
+    }
+
     /// Create struct to apply block's changes
     pub fn block(&self) -> WorldBlock {
         WorldBlock {
@@ -378,13 +555,26 @@ impl World {
             asset_definitions: self.asset_definitions.block(),
             assets: self.assets.block(),
             nfts: self.nfts.block(),
+            nft_content_index: self.nft_content_index.block(),
             roles: self.roles.block(),
             account_permissions: self.account_permissions.block(),
             account_roles: self.account_roles.block(),
+            role_templates: self.role_templates.block(),
+            anchors: self.anchors.block(),
+            asset_movements: self.asset_movements.block(),
+            permission_grants: self.permission_grants.block(),
+            permission_revocations: self.permission_revocations.block(),
+            domain_validators: self.domain_validators.block(),
+            redactions: self.redactions.block(),
+            domain_transfer_offers: self.domain_transfer_offers.block(),
+            spending_counters: self.spending_counters.block(),
             triggers: self.triggers.block(),
             executor: self.executor.block(),
             executor_data_model: self.executor_data_model.block(),
+            pending_executor_upgrade: self.pending_executor_upgrade.block(),
+            pending_executor_upgrade_policy: self.pending_executor_upgrade_policy.block(),
             external_event_buf: self.external_event_buf.block(),
+            permission_cache: PermissionCache::default(),
         }
     }
 
@@ -398,13 +588,28 @@ impl World {
             asset_definitions: self.asset_definitions.block_and_revert(),
             assets: self.assets.block_and_revert(),
             nfts: self.nfts.block_and_revert(),
+            nft_content_index: self.nft_content_index.block_and_revert(),
             roles: self.roles.block_and_revert(),
             account_permissions: self.account_permissions.block_and_revert(),
             account_roles: self.account_roles.block_and_revert(),
+            role_templates: self.role_templates.block_and_revert(),
+            anchors: self.anchors.block_and_revert(),
+            asset_movements: self.asset_movements.block_and_revert(),
+            permission_grants: self.permission_grants.block_and_revert(),
+            permission_revocations: self.permission_revocations.block_and_revert(),
+            domain_validators: self.domain_validators.block_and_revert(),
+            redactions: self.redactions.block_and_revert(),
+            domain_transfer_offers: self.domain_transfer_offers.block_and_revert(),
+            spending_counters: self.spending_counters.block_and_revert(),
             triggers: self.triggers.block_and_revert(),
             executor: self.executor.block_and_revert(),
             executor_data_model: self.executor_data_model.block_and_revert(),
+            pending_executor_upgrade: self.pending_executor_upgrade.block_and_revert(),
+            pending_executor_upgrade_policy: self
+                .pending_executor_upgrade_policy
+                .block_and_revert(),
             external_event_buf: self.external_event_buf.block_and_revert(),
+            permission_cache: PermissionCache::default(),
         }
     }
 
@@ -418,12 +623,24 @@ impl World {
             asset_definitions: self.asset_definitions.view(),
             assets: self.assets.view(),
             nfts: self.nfts.view(),
+            nft_content_index: self.nft_content_index.view(),
             roles: self.roles.view(),
             account_permissions: self.account_permissions.view(),
             account_roles: self.account_roles.view(),
+            role_templates: self.role_templates.view(),
+            anchors: self.anchors.view(),
+            asset_movements: self.asset_movements.view(),
+            permission_grants: self.permission_grants.view(),
+            permission_revocations: self.permission_revocations.view(),
+            domain_validators: self.domain_validators.view(),
+            redactions: self.redactions.view(),
+            domain_transfer_offers: self.domain_transfer_offers.view(),
+            spending_counters: self.spending_counters.view(),
             triggers: self.triggers.view(),
             executor: self.executor.view(),
             executor_data_model: self.executor_data_model.view(),
+            pending_executor_upgrade: self.pending_executor_upgrade.view(),
+            pending_executor_upgrade_policy: self.pending_executor_upgrade_policy.view(),
         }
     }
 }
@@ -438,12 +655,26 @@ pub trait WorldReadOnly {
     fn asset_definitions(&self) -> &impl StorageReadOnly<AssetDefinitionId, AssetDefinition>;
     fn assets(&self) -> &impl StorageReadOnly<AssetId, AssetValue>;
     fn nfts(&self) -> &impl StorageReadOnly<NftId, NftValue>;
+    fn nft_content_index(&self) -> &impl StorageReadOnly<NftContentTrigram, ()>;
     fn roles(&self) -> &impl StorageReadOnly<RoleId, Role>;
     fn account_permissions(&self) -> &impl StorageReadOnly<AccountId, Permissions>;
     fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()>;
+    fn role_templates(&self) -> &impl StorageReadOnly<RoleId, RoleTemplate>;
+    fn anchors(&self) -> &impl StorageReadOnly<Hash, AnchoredHash>;
+    fn asset_movements(&self) -> &impl StorageReadOnly<AssetMovementId, AssetMovement>;
+    fn permission_grants(
+        &self,
+    ) -> &impl StorageReadOnly<PermissionGrantEventId, PermissionGrantEvent>;
+    fn permission_revocations(&self) -> &impl StorageReadOnly<AccountId, PermissionRevocations>;
+    fn domain_validators(&self) -> &impl StorageReadOnly<DomainId, LoadedDomainValidator>;
+    fn redactions(&self) -> &impl StorageReadOnly<RedactableKey, Option<Redaction>>;
+    fn domain_transfer_offers(&self) -> &impl StorageReadOnly<DomainId, DomainTransferOffer>;
+    fn spending_counters(&self) -> &impl StorageReadOnly<AccountId, Vec<SpendingCounter>>;
     fn triggers(&self) -> &impl TriggerSetReadOnly;
     fn executor(&self) -> &Executor;
     fn executor_data_model(&self) -> &ExecutorDataModel;
+    fn pending_executor_upgrade(&self) -> Option<&PendingExecutorUpgrade>;
+    fn pending_executor_upgrade_policy(&self) -> Option<&PendingExecutorUpgradePolicy>;
 
     // Domain-related methods
 
@@ -572,6 +803,15 @@ pub trait WorldReadOnly {
             .map(|(role, ())| &role.id)
     }
 
+    /// Get [`AssetMovement`]s of the [`Account`] in chronological order
+    fn account_asset_movements_iter(
+        &self,
+        id: &AccountId,
+    ) -> RangeIter<'_, AssetMovementId, AssetMovement> {
+        self.asset_movements()
+            .range::<AssetMovementId>(AssetMovementId::of_account(id))
+    }
+
     /// Return a set of all permission tokens granted to this account.
     ///
     /// # Errors
@@ -612,6 +852,19 @@ pub trait WorldReadOnly {
             .is_some_and(|permissions| permissions.contains(token))
     }
 
+    /// Return `true` if [`Account`] has a permission token, either directly or through one of its roles.
+    fn account_has_permission(&self, account: &AccountId, token: &Permission) -> bool {
+        self.account_contains_inherent_permission(account, token)
+            || self
+                .roles_with_inherited(self.account_roles_iter(account))
+                .into_iter()
+                .any(|role_id| {
+                    self.roles().get(role_id).is_some_and(|role| {
+                        role.permissions().any(|permission| permission == token)
+                    })
+                })
+    }
+
     // Asset-related methods
 
     /// Get `Asset` by its id
@@ -679,6 +932,13 @@ pub trait WorldReadOnly {
             .map(|(id, value)| NftEntry::new(id, value))
     }
 
+    /// Iterate IDs of NFTs having a string value in their content which contains `trigram`
+    fn nfts_with_trigram(&self, trigram: Trigram) -> impl Iterator<Item = &NftId> {
+        self.nft_content_index()
+            .range::<dyn AsNftContentTrigramCompare>(NftByTrigramBounds::new(trigram))
+            .map(|(entry, ())| &entry.nft)
+    }
+
     // Role-related methods
 
     /// Get `Role` and return reference to it.
@@ -690,6 +950,25 @@ pub trait WorldReadOnly {
             .get(id)
             .ok_or_else(|| FindError::Role(id.clone()))
     }
+
+    /// Return the given roles together with all the roles they inherit, transitively.
+    fn roles_with_inherited<'slf>(
+        &'slf self,
+        role_ids: impl IntoIterator<Item = &'slf RoleId>,
+    ) -> BTreeSet<&'slf RoleId> {
+        let mut roles = BTreeSet::new();
+        let mut pending = role_ids.into_iter().collect::<Vec<_>>();
+
+        while let Some(role_id) = pending.pop() {
+            if roles.insert(role_id) {
+                if let Some(role) = self.roles().get(role_id) {
+                    pending.extend(role.inherited_roles());
+                }
+            }
+        }
+
+        roles
+    }
 }
 
 macro_rules! impl_world_ro {
@@ -716,6 +995,9 @@ macro_rules! impl_world_ro {
             fn nfts(&self) -> &impl StorageReadOnly<NftId, NftValue> {
                 &self.nfts
             }
+            fn nft_content_index(&self) -> &impl StorageReadOnly<NftContentTrigram, ()> {
+                &self.nft_content_index
+            }
             fn roles(&self) -> &impl StorageReadOnly<RoleId, Role> {
                 &self.roles
             }
@@ -725,6 +1007,40 @@ macro_rules! impl_world_ro {
             fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()> {
                 &self.account_roles
             }
+
+            fn role_templates(&self) -> &impl StorageReadOnly<RoleId, RoleTemplate> {
+                &self.role_templates
+            }
+            fn anchors(&self) -> &impl StorageReadOnly<Hash, AnchoredHash> {
+                &self.anchors
+            }
+            fn asset_movements(&self) -> &impl StorageReadOnly<AssetMovementId, AssetMovement> {
+                &self.asset_movements
+            }
+            fn permission_grants(
+                &self,
+            ) -> &impl StorageReadOnly<PermissionGrantEventId, PermissionGrantEvent> {
+                &self.permission_grants
+            }
+            fn permission_revocations(
+                &self,
+            ) -> &impl StorageReadOnly<AccountId, PermissionRevocations> {
+                &self.permission_revocations
+            }
+            fn domain_validators(&self) -> &impl StorageReadOnly<DomainId, LoadedDomainValidator> {
+                &self.domain_validators
+            }
+            fn redactions(&self) -> &impl StorageReadOnly<RedactableKey, Option<Redaction>> {
+                &self.redactions
+            }
+            fn domain_transfer_offers(
+                &self,
+            ) -> &impl StorageReadOnly<DomainId, DomainTransferOffer> {
+                &self.domain_transfer_offers
+            }
+            fn spending_counters(&self) -> &impl StorageReadOnly<AccountId, Vec<SpendingCounter>> {
+                &self.spending_counters
+            }
             fn triggers(&self) -> &impl TriggerSetReadOnly {
                 &self.triggers
             }
@@ -734,6 +1050,12 @@ macro_rules! impl_world_ro {
             fn executor_data_model(&self) -> &ExecutorDataModel {
                 &self.executor_data_model
             }
+            fn pending_executor_upgrade(&self) -> Option<&PendingExecutorUpgrade> {
+                self.pending_executor_upgrade.as_ref()
+            }
+            fn pending_executor_upgrade_policy(&self) -> Option<&PendingExecutorUpgradePolicy> {
+                self.pending_executor_upgrade_policy.as_ref()
+            }
         }
     )*};
 }
@@ -753,14 +1075,29 @@ impl<'world> WorldBlock<'world> {
             asset_definitions: self.asset_definitions.transaction(),
             assets: self.assets.transaction(),
             nfts: self.nfts.transaction(),
+            nft_content_index: self.nft_content_index.transaction(),
             roles: self.roles.transaction(),
             account_permissions: self.account_permissions.transaction(),
             account_roles: self.account_roles.transaction(),
+            role_templates: self.role_templates.transaction(),
+            anchors: self.anchors.transaction(),
+            asset_movements: self.asset_movements.transaction(),
+            permission_grants: self.permission_grants.transaction(),
+            permission_revocations: self.permission_revocations.transaction(),
+            domain_validators: self.domain_validators.transaction(),
+            redactions: self.redactions.transaction(),
+            domain_transfer_offers: self.domain_transfer_offers.transaction(),
+            spending_counters: self.spending_counters.transaction(),
             triggers: self.triggers.transaction(),
             executor: self.executor.transaction(),
             executor_data_model: self.executor_data_model.transaction(),
+            pending_executor_upgrade: self.pending_executor_upgrade.transaction(),
+            pending_executor_upgrade_policy: self.pending_executor_upgrade_policy.transaction(),
             external_event_buf: self.external_event_buf.transaction(),
             internal_event_buf: Vec::new(),
+            asset_movement_buf: Vec::new(),
+            permission_cache: &self.permission_cache,
+            permissions_changed: false,
         }
     }
 
@@ -775,22 +1112,47 @@ impl<'world> WorldBlock<'world> {
             asset_definitions,
             assets,
             nfts,
+            nft_content_index,
             roles,
             account_permissions,
             account_roles,
+            role_templates,
+            anchors,
+            asset_movements,
+            permission_grants,
+            permission_revocations,
+            domain_validators,
+            redactions,
+            domain_transfer_offers,
+            spending_counters,
             triggers,
             executor,
             executor_data_model,
+            pending_executor_upgrade,
+            pending_executor_upgrade_policy,
             // Always drop at the block level.
             external_event_buf: _,
+            permission_cache: _,
         } = self;
         // IMPORTANT!!! Commit fields in reverse order, this way consistent results are insured
+        pending_executor_upgrade_policy.commit();
+        pending_executor_upgrade.commit();
         executor_data_model.commit();
         executor.commit();
         triggers.commit();
+        spending_counters.commit();
+        domain_transfer_offers.commit();
+        redactions.commit();
+        domain_validators.commit();
+        permission_revocations.commit();
+        permission_grants.commit();
+        asset_movements.commit();
+        anchors.commit();
+        role_templates.commit();
         account_roles.commit();
         account_permissions.commit();
         roles.commit();
+        nft_content_index.commit();
         nfts.commit();
         assets.commit();
         asset_definitions.commit();
@@ -813,22 +1175,52 @@ impl WorldTransaction<'_, '_> {
             asset_definitions,
             assets,
             nfts,
+            nft_content_index,
             roles,
             account_permissions,
             account_roles,
+            role_templates,
+            anchors,
+            asset_movements,
+            permission_grants,
+            permission_revocations,
+            domain_validators,
+            redactions,
+            domain_transfer_offers,
+            spending_counters,
             triggers,
             executor,
             executor_data_model,
+            pending_executor_upgrade,
+            pending_executor_upgrade_policy,
             external_event_buf,
             internal_event_buf: _,
+            asset_movement_buf: _,
+            permission_cache,
+            permissions_changed,
         } = self;
+        if permissions_changed {
+            permission_cache.clear();
+        }
         external_event_buf.apply();
+        pending_executor_upgrade_policy.apply();
+        pending_executor_upgrade.apply();
         executor_data_model.apply();
         executor.apply();
         triggers.apply();
+        spending_counters.apply();
+        domain_transfer_offers.apply();
+        redactions.apply();
+        domain_validators.apply();
+        permission_revocations.apply();
+        permission_grants.apply();
+        asset_movements.apply();
+        anchors.apply();
+        role_templates.apply();
         account_roles.apply();
         account_permissions.apply();
         roles.apply();
+        nft_content_index.apply();
         nfts.apply();
         assets.apply();
         asset_definitions.apply();
@@ -864,6 +1256,7 @@ impl WorldTransaction<'_, '_> {
     ///
     /// Return a Boolean value indicating whether or not the  [`Account`] already had this permission.
     pub fn add_account_permission(&mut self, account: &AccountId, token: Permission) -> bool {
+        self.permissions_changed = true;
         // `match` here instead of `map_or_else` to avoid cloning token into each closure
         match self.account_permissions.get_mut(account) {
             None => {
@@ -884,13 +1277,52 @@ impl WorldTransaction<'_, '_> {
     /// Remove a [`permission`](Permission) from the [`Account`] if the account has this permission.
     /// Return a Boolean value indicating whether the [`Account`] had this permission.
     pub fn remove_account_permission(&mut self, account: &AccountId, token: &Permission) -> bool {
+        self.permissions_changed = true;
+        self.remove_permission_revocation(account, token);
         self.account_permissions
             .get_mut(account)
             .is_some_and(|permissions| permissions.remove(token))
     }
 
+    /// Revoke [`permission`](Permission) from the [`Account`] automatically once the condition is met.
+    pub fn add_permission_revocation(
+        &mut self,
+        account: &AccountId,
+        revocation: PermissionRevocation,
+    ) {
+        match self.permission_revocations.get_mut(account) {
+            Some(revocations) => revocations.push(revocation),
+            None => {
+                self.permission_revocations
+                    .insert(account.clone(), vec![revocation]);
+            }
+        }
+    }
+
+    /// Forget the condition on which [`permission`](Permission) is revoked from the [`Account`], if any.
+    fn remove_permission_revocation(&mut self, account: &AccountId, token: &Permission) {
+        let Some(revocations) = self.permission_revocations.get_mut(account) else {
+            return;
+        };
+        revocations.retain(|revocation| revocation.permission != *token);
+        if revocations.is_empty() {
+            self.permission_revocations.remove(account.clone());
+        }
+    }
+
+    /// Remove all [`permissions`](Permission) not associated with any role from the [`Account`].
+    /// Return the removed permissions.
+    pub fn remove_account_permissions(&mut self, account: &AccountId) -> Permissions {
+        self.permissions_changed = true;
+        self.permission_revocations.remove(account.clone());
+        self.account_permissions
+            .remove(account.clone())
+            .unwrap_or_default()
+    }
+
     /// Remove all [`Role`]s from the [`Account`]
     pub fn remove_account_roles(&mut self, account: &AccountId) {
+        self.permissions_changed = true;
         let roles_to_remove = self
             .account_roles_iter(account)
             .cloned()
@@ -902,6 +1334,18 @@ impl WorldTransaction<'_, '_> {
         }
     }
 
+    /// Remove all [`AssetMovement`]s of the [`Account`]
+    pub fn remove_account_asset_movements(&mut self, account: &AccountId) {
+        let movements_to_remove = self
+            .account_asset_movements_iter(account)
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+
+        for movement in movements_to_remove {
+            self.asset_movements.remove(movement);
+        }
+    }
+
     /// Get mutable reference to [`Asset`]
     ///
     /// # Errors
@@ -1017,6 +1461,24 @@ impl WorldTransaction<'_, '_> {
         Ok(())
     }
 
+    /// Replace the trigrams recorded for the content of NFT `id` in the index of NFT contents,
+    /// given the trigrams of its content before and after the change.
+    pub(crate) fn reindex_nft_content(
+        &mut self,
+        id: &NftId,
+        prev: &BTreeSet<Trigram>,
+        next: &BTreeSet<Trigram>,
+    ) {
+        for trigram in prev.difference(next) {
+            self.nft_content_index
+                .remove(NftContentTrigram::new(*trigram, id.clone()));
+        }
+        for trigram in next.difference(prev) {
+            self.nft_content_index
+                .insert(NftContentTrigram::new(*trigram, id.clone()), ());
+        }
+    }
+
     /// Get mutable reference to [`Nft`]
     ///
     /// # Errors
@@ -1063,13 +1525,55 @@ impl WorldTransaction<'_, '_> {
     /// Events should be produced in the order of expanding scope: from specific to general.
     /// Example: account events before domain events.
     pub fn emit_events<I: IntoIterator<Item = T>, T: Into<DataEvent>>(&mut self, world_events: I) {
+        let data_events: Vec<DataEvent> = world_events.into_iter().map(Into::into).collect();
+        self.permissions_changed |= data_events
+            .iter()
+            .any(permission_cache::affects_permissions);
         Self::emit_events_impl(
             &mut self.external_event_buf,
             &mut self.internal_event_buf,
-            world_events,
+            data_events,
         )
     }
 
+    /// Number of events emitted so far, to be passed to [`Self::domains_with_events_since()`].
+    pub(crate) fn emitted_event_count(&self) -> usize {
+        self.internal_event_buf.len()
+    }
+
+    /// Domains that are the origin of any event emitted after the first `since` events.
+    pub(crate) fn domains_with_events_since(&self, since: usize) -> BTreeSet<DomainId> {
+        self.internal_event_buf
+            .iter()
+            .skip(since)
+            .filter_map(|event| match event {
+                DataEvent::Domain(event) => Some(event.origin().clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Buffer a change of the balance of `asset` to be indexed for account statements.
+    ///
+    /// `counterparty` is the other side of a transfer, `None` for mints and burns.
+    pub(crate) fn record_asset_movement(
+        &mut self,
+        asset: &AssetId,
+        direction: MovementDirection,
+        amount: Numeric,
+        balance: Numeric,
+        counterparty: Option<AccountId>,
+    ) {
+        self.asset_movement_buf.push(PendingAssetMovement {
+            account: asset.account.clone(),
+            asset_definition: asset.definition.clone(),
+            direction,
+            amount,
+            balance,
+            counterparty,
+        });
+    }
+
     /// Implementation of [`Self::emit_events()`].
     ///
     /// Usable when you can't call [`Self::emit_events()`] due to mutable reference to self.
@@ -1102,6 +1606,8 @@ impl State {
             engine: wasm::create_engine(),
             kura,
             query_handle,
+            signature_cache: SignatureCache::default(),
+            redaction_secret: None,
             #[cfg(feature = "telemetry")]
             telemetry,
             view_lock: parking_lot::RwLock::new(()),
@@ -1154,6 +1660,16 @@ impl State {
         Self::new_inner(world, kura, query_handle, telemetry)
     }
 
+    /// Set the secret redacted metadata values are salted with, see
+    /// [`Redact`](iroha_data_model::isi::Redact).
+    ///
+    /// Has to be set before blocks with redactions are applied.
+    #[must_use]
+    pub fn with_redaction_secret(mut self, secret: Option<RedactionSecret>) -> Self {
+        self.redaction_secret = secret;
+        self
+    }
+
     /// Create structure to execute a block
     pub fn block(&self, curr_block: BlockHeader) -> StateBlock<'_> {
         StateBlock {
@@ -1165,6 +1681,8 @@ impl State {
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
+            signature_cache: &self.signature_cache,
+            redaction_secret: self.redaction_secret.as_ref(),
             #[cfg(feature = "telemetry")]
             telemetry: &self.telemetry,
             view_lock: &self.view_lock,
@@ -1183,6 +1701,8 @@ impl State {
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
+            signature_cache: &self.signature_cache,
+            redaction_secret: self.redaction_secret.as_ref(),
             #[cfg(feature = "telemetry")]
             telemetry: &self.telemetry,
             view_lock: &self.view_lock,
@@ -1202,6 +1722,8 @@ impl State {
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
+            signature_cache: &self.signature_cache,
+            redaction_secret: self.redaction_secret.as_ref(),
             #[cfg(feature = "telemetry")]
             telemetry: &self.telemetry,
         }
@@ -1218,6 +1740,14 @@ pub trait StateReadOnly {
     fn engine(&self) -> &wasmtime::Engine;
     fn kura(&self) -> &Kura;
     fn query_handle(&self) -> &LiveQueryStoreHandle;
+    /// Secret the salts of redacted metadata values are derived from, if the peer has one
+    fn redaction_secret(&self) -> Option<&RedactionSecret>;
+    /// Height of the block in which the transaction with `hash` is committed,
+    /// not counting the block being currently applied
+    fn committed_transaction_height(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> Option<NonZeroUsize>;
     #[cfg(feature = "telemetry")]
     fn metrics(&self) -> &StateTelemetry;
 
@@ -1315,6 +1845,15 @@ macro_rules! impl_state_ro {
             fn query_handle(&self) -> &LiveQueryStoreHandle {
                 &self.query_handle
             }
+            fn redaction_secret(&self) -> Option<&RedactionSecret> {
+                self.redaction_secret
+            }
+            fn committed_transaction_height(
+                &self,
+                hash: &HashOf<SignedTransaction>,
+            ) -> Option<NonZeroUsize> {
+                self.transactions.get(hash)
+            }
             #[cfg(feature = "telemetry")]
             fn metrics(&self) -> &StateTelemetry {
                 &self.telemetry
@@ -1336,6 +1875,9 @@ pub trait StateReadOnlyWithTransactions: StateReadOnly {
     /// Returns transactions map
     fn transactions(&self) -> &impl TransactionsReadOnly;
 
+    /// Returns hashes of transactions with already verified signatures
+    fn signature_cache(&self) -> &SignatureCache;
+
     /// Check if [`SignedTransaction`] is already committed
     #[inline]
     fn has_transaction(&self, hash: HashOf<SignedTransaction>) -> bool {
@@ -1347,12 +1889,20 @@ impl StateReadOnlyWithTransactions for StateView<'_> {
     fn transactions(&self) -> &impl TransactionsReadOnly {
         &self.transactions
     }
+
+    fn signature_cache(&self) -> &SignatureCache {
+        self.signature_cache
+    }
 }
 
 impl StateReadOnlyWithTransactions for StateBlock<'_> {
     fn transactions(&self) -> &impl TransactionsReadOnly {
         &self.transactions
     }
+
+    fn signature_cache(&self) -> &SignatureCache {
+        self.signature_cache
+    }
 }
 
 impl<'state> StateBlock<'state> {
@@ -1361,11 +1911,13 @@ impl<'state> StateBlock<'state> {
         StateTransaction {
             world: self.world.trasaction(),
             block_hashes: self.block_hashes.transaction(),
+            transactions: &self.transactions,
             commit_topology: self.commit_topology.transaction(),
             prev_commit_topology: self.prev_commit_topology.transaction(),
             engine: self.engine,
             kura: self.kura,
             query_handle: self.query_handle,
+            redaction_secret: self.redaction_secret,
             #[cfg(feature = "telemetry")]
             telemetry: self.telemetry,
             curr_block: self.curr_block,
@@ -1410,10 +1962,17 @@ impl<'state> StateBlock<'state> {
             .height
             .try_into()
             .expect("INTERNAL BUG: Block height exceeds usize::MAX");
+        // NOTE: Excluded transactions are recorded too, so that they aren't resubmitted
         let transactions = block
             .as_ref()
             .external_transactions()
             .map(SignedTransaction::hash)
+            .chain(
+                block
+                    .as_ref()
+                    .excluded_transactions()
+                    .map(|excluded| excluded.hash),
+            )
             .collect();
         self.transactions.insert_block(transactions, block_height);
 
@@ -1432,6 +1991,102 @@ impl<'state> StateBlock<'state> {
         core::mem::take(&mut self.world.external_event_buf)
     }
 
+    /// Apply the scheduled executor upgrade policy change and the scheduled executor upgrade
+    /// if the given block reaches their activation heights.
+    ///
+    /// A failed migration leaves the current executor in place and aborts the upgrade.
+    pub(crate) fn activate_executor_upgrade(&mut self, block_header: &BlockHeader) {
+        let height = block_header.height().get();
+        self.activate_executor_upgrade_policy(height);
+
+        let Some(pending) = self
+            .world
+            .pending_executor_upgrade
+            .as_ref()
+            .filter(|pending| pending.activation_height <= height)
+            .cloned()
+        else {
+            return;
+        };
+        *self.world.pending_executor_upgrade.get_mut() = None;
+
+        let PendingExecutorUpgrade {
+            executor,
+            authority,
+            activation_height,
+        } = pending;
+        let mut transaction = self.transaction();
+        match crate::smartcontracts::isi::world::isi::upgrade_executor(
+            executor,
+            &authority,
+            &mut transaction,
+        ) {
+            Ok(()) => transaction.apply(),
+            Err(reason) => {
+                drop(transaction);
+                warn!(%activation_height, ?reason, "Scheduled executor upgrade failed");
+                let mut transaction = self.transaction();
+                transaction
+                    .world
+                    .emit_events(Some(ExecutorEvent::UpgradeAborted(
+                        ExecutorUpgradeAborted {
+                            activation_height,
+                            aborted_by: None,
+                        },
+                    )));
+                transaction.apply();
+            }
+        }
+    }
+
+    fn activate_executor_upgrade_policy(&mut self, height: u64) {
+        let Some(pending) = self
+            .world
+            .pending_executor_upgrade_policy
+            .as_ref()
+            .filter(|pending| pending.activation_height <= height)
+            .cloned()
+        else {
+            return;
+        };
+        *self.world.pending_executor_upgrade_policy.get_mut() = None;
+
+        let mut transaction = self.transaction();
+        let prev = core::mem::replace(
+            &mut transaction.world.parameters.executor_upgrade,
+            pending.parameters.clone(),
+        );
+        transaction
+            .world
+            .emit_events(Some(ConfigurationEvent::Changed(ParameterChanged {
+                old_value: Parameter::ExecutorUpgrade(prev),
+                new_value: Parameter::ExecutorUpgrade(pending.parameters),
+            })));
+        transaction.apply();
+    }
+
+    /// Prune the permission changes which are no longer retained once the given block is applied.
+    ///
+    /// See [`permission_grant::RETAINED_BLOCKS`].
+    pub(crate) fn prune_permission_grants(&mut self, block_header: &BlockHeader) {
+        let Some(last_pruned) = block_header
+            .height()
+            .get()
+            .checked_sub(permission_grant::RETAINED_BLOCKS)
+        else {
+            return;
+        };
+        let pruned = self
+            .world
+            .permission_grants
+            .range::<PermissionGrantEventId>(PermissionGrantEventId::up_to_block(last_pruned))
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        for id in pruned {
+            self.world.permission_grants.remove(id);
+        }
+    }
+
     /// Execute time-triggered transactions for the given block, applying their state changes on success.
     ///
     /// Returns a triplet of vectors: the first contains the time-triggered entrypoints,
@@ -1447,7 +2102,7 @@ impl<'state> StateBlock<'state> {
     ) {
         let time_event = self.create_time_event(block_header);
         self.world.external_event_buf.push(time_event.into());
-        let matched: Vec<_> = self.world.triggers.match_time_event(time_event).collect();
+        let matched = self.world.triggers.match_time_event(time_event);
 
         matched.iter().fold(
             (Vec::new(), Vec::new(), Vec::new()),
@@ -1526,6 +2181,7 @@ impl<'state> StateBlock<'state> {
             .triggers
             .decrease_repeats([trg_id].into_iter());
 
+        transaction.index_asset_movements(entrypoint.hash_as_entrypoint());
         transaction.apply();
 
         (entrypoint, Ok(trigger_sequence))
@@ -1549,8 +2205,10 @@ impl<'state> StateBlock<'state> {
     /// Apply a committed block to the world state.
     ///
     /// Execution order:
-    /// 1. Transactions (including invoked data triggers)
-    /// 2. Time triggers (including invoked data triggers)
+    /// 1. Scheduled executor upgrade, if activated by the block
+    /// 2. Pruning of permission changes which are no longer retained
+    /// 3. Transactions (including invoked data triggers)
+    /// 4. Time triggers (including invoked data triggers)
     ///
     /// # Panics
     ///
@@ -1558,6 +2216,8 @@ impl<'state> StateBlock<'state> {
     #[cfg(any(test, feature = "bench"))]
     #[iroha_logger::log(skip_all, fields(block_height))]
     pub fn apply(&mut self, block: &CommittedBlock, topology: Vec<PeerId>) -> Vec<EventBox> {
+        self.activate_executor_upgrade(&block.as_ref().header());
+        self.prune_permission_grants(&block.as_ref().header());
         self.apply_transactions(block);
         debug!(height = %self.height(), "Transactions applied");
         self.execute_time_triggers(&block.as_ref().header());
@@ -1578,6 +2238,7 @@ impl<'state> StateBlock<'state> {
                 transaction
                     .execute_data_triggers_dfs(tx.authority())
                     .expect("should be no errors");
+                transaction.index_asset_movements(tx.hash_as_entrypoint());
                 transaction.apply();
             }
         }
@@ -1585,6 +2246,89 @@ impl<'state> StateBlock<'state> {
 }
 
 impl StateTransaction<'_, '_> {
+    /// Index asset balance changes buffered by the entrypoint with the given hash.
+    ///
+    /// Movements of the same accounts which are no longer retained are pruned,
+    /// see [`asset_movement::RETAINED_BLOCKS`].
+    ///
+    /// Must be called before [`Self::apply`] for the movements to be visible in account statements.
+    pub(crate) fn index_asset_movements(&mut self, entrypoint: HashOf<TransactionEntrypoint>) {
+        let height = self.curr_block.height().get();
+        let last_pruned = height.checked_sub(asset_movement::RETAINED_BLOCKS);
+        let timestamp_ms = self
+            .curr_block
+            .creation_time()
+            .as_millis()
+            .try_into()
+            .expect("INTERNAL BUG: Unix time in milliseconds doesn't fit into u64");
+
+        for pending in core::mem::take(&mut self.world.asset_movement_buf) {
+            if let Some(last_pruned) = last_pruned {
+                let pruned = self
+                    .world
+                    .asset_movements
+                    .range::<AssetMovementId>(AssetMovementId::of_account_up_to_block(
+                        &pending.account,
+                        last_pruned,
+                    ))
+                    .map(|(id, _)| id.clone())
+                    .collect::<Vec<_>>();
+                for id in pruned {
+                    self.world.asset_movements.remove(id);
+                }
+            }
+            let index = self
+                .world
+                .asset_movements
+                .range::<AssetMovementId>(
+                    AssetMovementId::new(pending.account.clone(), height, 0)
+                        ..=AssetMovementId::new(pending.account.clone(), height, u32::MAX),
+                )
+                .last()
+                .map_or(0, |(id, _)| id.index + 1);
+            let movement = AssetMovement {
+                asset_definition: pending.asset_definition,
+                direction: pending.direction,
+                amount: pending.amount,
+                balance: pending.balance,
+                counterparty: pending.counterparty,
+                transaction: entrypoint,
+                timestamp_ms,
+            };
+            self.world.asset_movements.insert(
+                AssetMovementId::new(pending.account, height, index),
+                movement,
+            );
+        }
+    }
+
+    /// Append a grant or a revocation of `permission` to the audit log.
+    pub(crate) fn record_permission_grant(
+        &mut self,
+        author: &AccountId,
+        target: PermissionHolder,
+        permission: Permission,
+        action: PermissionGrantAction,
+    ) {
+        let height = self.curr_block.height().get();
+        let index = self
+            .world
+            .permission_grants
+            .range::<PermissionGrantEventId>(PermissionGrantEventId::of_block(height))
+            .last()
+            .map_or(0, |(id, _)| id.index + 1);
+        let event = PermissionGrantEvent {
+            author: author.clone(),
+            target,
+            permission,
+            action,
+            height,
+        };
+        self.world
+            .permission_grants
+            .insert(PermissionGrantEventId::new(height, index), event);
+    }
+
     /// Apply transaction making it's changes visible
     pub fn apply(self) {
         // NOTE: intentionally destruct self not to forget apply some fields
@@ -1696,11 +2440,22 @@ impl StateTransaction<'_, '_> {
         Ok(steps)
     }
 
-    /// Flush the internal event buffer and return pairs of __representative__ matched events and trigger IDs.
+    /// Flush the internal event buffer and return pairs of __representative__ matched events and trigger IDs
+    /// in the order of execution, see [`Action::priority`](iroha_data_model::trigger::action::Action::priority).
+    ///
+    /// Permissions whose revocation condition matches any of the events are revoked beforehand.
     // FIXME: Return the triggering event unions instead of the representatives (#5355 as a prerequisite)
     fn capture_data_events(&mut self) -> Vec<(DataEvent, TriggerId)> {
-        let drained = core::mem::take(&mut self.world.internal_event_buf);
-        self.world
+        let mut drained = Vec::new();
+        let mut pending = core::mem::take(&mut self.world.internal_event_buf);
+        // Revocations emit events of their own, which may fulfill further conditions
+        while !pending.is_empty() {
+            self.revoke_permissions_on(&pending);
+            drained.append(&mut pending);
+            pending = core::mem::take(&mut self.world.internal_event_buf);
+        }
+        let mut matched: Vec<_> = self
+            .world
             .triggers
             .data_triggers()
             .iter()
@@ -1709,12 +2464,53 @@ impl StateTransaction<'_, '_> {
                     action
                         .filter
                         .matches(event)
-                        .then(|| (event.clone(), trg_id.clone()))
+                        .then(|| (event.clone(), trg_id.clone(), action.priority))
                 })
             })
+            .collect();
+        matched.sort_by(|(_, id_a, priority_a), (_, id_b, priority_b)| {
+            execution_order((id_a, *priority_a), (id_b, *priority_b))
+        });
+        matched
+            .into_iter()
+            .map(|(event, trg_id, _)| (event, trg_id))
             .collect()
     }
 
+    /// Revoke the permissions whose revocation condition matches any of `events`.
+    fn revoke_permissions_on(&mut self, events: &[DataEvent]) {
+        let fulfilled = self
+            .world
+            .permission_revocations
+            .iter()
+            .flat_map(|(account, revocations)| {
+                revocations
+                    .iter()
+                    .filter(|revocation| {
+                        events
+                            .iter()
+                            .any(|event| revocation.revoke_on.matches(event))
+                    })
+                    .map(move |revocation| (account.clone(), revocation.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        for (account, revocation) in fulfilled {
+            let revoke = Revoke::account_permission(revocation.permission.clone(), account.clone());
+            if let Err(error) = revoke.execute(&revocation.granted_by, self) {
+                warn!(
+                    %error,
+                    %account,
+                    permission=?revocation.permission,
+                    "Failed to revoke permission on its condition"
+                );
+                // Don't keep checking the condition that can't be fulfilled
+                self.world
+                    .remove_permission_revocation(&account, &revocation.permission);
+            }
+        }
+    }
+
     /// Execute any condition of trigger, staging its state changes.
     ///
     /// Returns the execution step on success, or the rejection reason on failure.
@@ -2032,6 +2828,60 @@ mod range_bounds {
         trait: AsNftIdDomainCompare
     }
 
+    /// `Trigram` wrapper for fetching NFTs whose content contains it from the index of NFT contents
+    #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
+    pub struct NftContentTrigramCompare<'a> {
+        trigram: Trigram,
+        nft: MinMaxExt<&'a NftId>,
+    }
+
+    /// Bounds for range quired over NFTs by trigram
+    pub struct NftByTrigramBounds {
+        start: NftContentTrigramCompare<'static>,
+        end: NftContentTrigramCompare<'static>,
+    }
+
+    impl NftByTrigramBounds {
+        /// Create range bounds for range quires over NFTs by trigram
+        pub fn new(trigram: Trigram) -> Self {
+            Self {
+                start: NftContentTrigramCompare {
+                    trigram,
+                    nft: MinMaxExt::Min,
+                },
+                end: NftContentTrigramCompare {
+                    trigram,
+                    nft: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsNftContentTrigramCompare + 'a> for NftByTrigramBounds {
+        fn start_bound(&self) -> Bound<&(dyn AsNftContentTrigramCompare + 'a)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsNftContentTrigramCompare + 'a)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsNftContentTrigramCompare for NftContentTrigram {
+        fn as_key(&self) -> NftContentTrigramCompare<'_> {
+            NftContentTrigramCompare {
+                trigram: self.trigram,
+                nft: (&self.nft).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: NftContentTrigram,
+        key: NftContentTrigramCompare<'_>,
+        trait: AsNftContentTrigramCompare
+    }
+
     /// `AccountId` wrapper for fetching assets beloning to an account from the global store
     #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
     pub struct AssetIdAccountCompare<'a> {
@@ -2189,9 +3039,20 @@ pub(crate) mod deserialize {
                     let mut roles = None;
                     let mut account_permissions = None;
                     let mut account_roles = None;
+                    let mut role_templates = None;
+                    let mut anchors = None;
+                    let mut asset_movements = None;
+                    let mut permission_grants = None;
+                    let mut permission_revocations = None;
+                    let mut domain_validators = None;
+                    let mut redactions = None;
+                    let mut domain_transfer_offers = None;
+                    let mut spending_counters = None;
                     let mut triggers = None;
                     let mut executor = None;
                     let mut executor_data_model = None;
+                    let mut pending_executor_upgrade = None;
+                    let mut pending_executor_upgrade_policy = None;
                     let mut external_event_buf = None;
 
                     while let Some(key) = map.next_key::<String>()? {
@@ -2226,6 +3087,37 @@ pub(crate) mod deserialize {
                             "account_roles" => {
                                 account_roles = Some(map.next_value()?);
                             }
+                            "role_templates" => {
+                                role_templates = Some(map.next_value()?);
+                            }
+                            "anchors" => {
+                                anchors = Some(map.next_value()?);
+                            }
+                            "asset_movements" => {
+                                asset_movements = Some(map.next_value()?);
+                            }
+                            "permission_grants" => {
+                                permission_grants = Some(map.next_value()?);
+                            }
+                            "permission_revocations" => {
+                                permission_revocations = Some(map.next_value()?);
+                            }
+                            "domain_validators" => {
+                                domain_validators =
+                                    Some(map.next_value_seed(mv::serde::StorageSeeded {
+                                        kseed: PhantomData,
+                                        vseed: self.loader.cast::<LoadedDomainValidator>(),
+                                    })?);
+                            }
+                            "redactions" => {
+                                redactions = Some(map.next_value()?);
+                            }
+                            "domain_transfer_offers" => {
+                                domain_transfer_offers = Some(map.next_value()?);
+                            }
+                            "spending_counters" => {
+                                spending_counters = Some(map.next_value()?);
+                            }
                             "triggers" => {
                                 triggers =
                                     Some(map.next_value_seed(self.loader.cast::<TriggerSet>())?);
@@ -2238,6 +3130,12 @@ pub(crate) mod deserialize {
                             "executor_data_model" => {
                                 executor_data_model = Some(map.next_value()?);
                             }
+                            "pending_executor_upgrade" => {
+                                pending_executor_upgrade = Some(map.next_value()?);
+                            }
+                            "pending_executor_upgrade_policy" => {
+                                pending_executor_upgrade_policy = Some(map.next_value()?);
+                            }
                             "external_event_buf" => {
                                 external_event_buf = Some(map.next_value()?);
                             }
@@ -2246,6 +3144,7 @@ pub(crate) mod deserialize {
                         }
                     }
 
+                    let nfts = nfts.ok_or_else(|| serde::de::Error::missing_field("nfts"))?;
                     Ok(World {
                         parameters: parameters
                             .ok_or_else(|| serde::de::Error::missing_field("parameters"))?,
@@ -2257,13 +3156,32 @@ pub(crate) mod deserialize {
                         asset_definitions: asset_definitions
                             .ok_or_else(|| serde::de::Error::missing_field("asset_definitions"))?,
                         assets: assets.ok_or_else(|| serde::de::Error::missing_field("assets"))?,
-                        nfts: nfts.ok_or_else(|| serde::de::Error::missing_field("nfts"))?,
+                        nft_content_index: World::index_nft_contents(&nfts),
+                        nfts,
                         roles: roles.ok_or_else(|| serde::de::Error::missing_field("roles"))?,
                         account_permissions: account_permissions.ok_or_else(|| {
                             serde::de::Error::missing_field("account_permissions")
                         })?,
                         account_roles: account_roles
                             .ok_or_else(|| serde::de::Error::missing_field("account_roles"))?,
+                        // Snapshots taken before role templates were introduced have none
+                        role_templates: role_templates.unwrap_or_default(),
+                        // Snapshots taken before anchoring was introduced have no anchors
+                        anchors: anchors.unwrap_or_default(),
+                        // Movements made before the index was introduced are not reconstructed
+                        asset_movements: asset_movements.unwrap_or_default(),
+                        // Changes made before the audit log was introduced are not reconstructed
+                        permission_grants: permission_grants.unwrap_or_default(),
+                        // Snapshots taken before revocation conditions were introduced have none
+                        permission_revocations: permission_revocations.unwrap_or_default(),
+                        // Snapshots taken before domain validators were introduced have none
+                        domain_validators: domain_validators.unwrap_or_default(),
+                        // Snapshots taken before redaction was introduced have no redactable values
+                        redactions: redactions.unwrap_or_default(),
+                        // Snapshots taken before domain transfers had to be accepted have no offers
+                        domain_transfer_offers: domain_transfer_offers.unwrap_or_default(),
+                        // Snapshots taken before spending counters were kept in state have none
+                        spending_counters: spending_counters.unwrap_or_default(),
                         triggers: triggers
                             .ok_or_else(|| serde::de::Error::missing_field("triggers"))?,
                         executor: executor
@@ -2271,6 +3189,11 @@ pub(crate) mod deserialize {
                         executor_data_model: executor_data_model.ok_or_else(|| {
                             serde::de::Error::missing_field("executor_data_model")
                         })?,
+                        // Snapshots taken before upgrades could be delayed have none pending
+                        pending_executor_upgrade: pending_executor_upgrade.unwrap_or_default(),
+                        // Snapshots taken before policy changes could be delayed have none pending
+                        pending_executor_upgrade_policy: pending_executor_upgrade_policy
+                            .unwrap_or_default(),
                         external_event_buf: external_event_buf
                             .ok_or_else(|| serde::de::Error::missing_field("external_event_buf"))?,
                     })
@@ -2286,9 +3209,20 @@ pub(crate) mod deserialize {
                     "roles",
                     "account_permissions",
                     "account_roles",
+                    "role_templates",
+                    "anchors",
+                    "asset_movements",
+                    "permission_grants",
+                    "permission_revocations",
+                    "domain_validators",
+                    "redactions",
+                    "domain_transfer_offers",
+                    "spending_counters",
                     "triggers",
                     "executor",
                     "executor_data_model",
+                    "pending_executor_upgrade",
+                    "pending_executor_upgrade_policy",
                 ],
                 WorldVisitor { loader: &self },
             )
@@ -2375,6 +3309,8 @@ pub(crate) mod deserialize {
                         })?,
                         kura: self.loader.kura,
                         query_handle: self.loader.query_handle,
+                        signature_cache: SignatureCache::default(),
+                        redaction_secret: None,
                         #[cfg(feature = "telemetry")]
                         telemetry: self.loader.telemetry,
                         engine,
@@ -0,0 +1,193 @@
+//! Copies of the state made from its views.
+//!
+//! Taking a [`StateView`] only briefly holds the lock which keeps blocks from being
+//! committed. Everything here works on the view afterwards, so serializing or copying
+//! the state doesn't hold up consensus, however big the state is.
+
+use std::sync::Arc;
+
+use mv::{
+    cell::{Cell, View as CellView},
+    storage::{Storage, StorageReadOnly},
+};
+use serde::{ser::SerializeStruct as _, Serialize, Serializer};
+
+#[cfg(feature = "telemetry")]
+use super::StateTelemetry;
+use super::{SignatureCache, State, StateView, World, WorldView};
+
+/// Copy the entries of a storage `view` into a new storage.
+pub(crate) fn storage<K: mv::Key, V: mv::Value>(
+    view: &impl StorageReadOnly<K, V>,
+) -> Storage<K, V> {
+    view.iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+/// Copy the value of a cell `view` into a new cell.
+pub(crate) fn cell<T: mv::Value>(view: &CellView<'_, T>) -> Cell<T> {
+    Cell::new((**view).clone())
+}
+
+impl State {
+    /// Copy the latest state into a new one, which blocks can be applied to without
+    /// waiting for or holding up the blocks applied to `self`.
+    ///
+    /// The world is copied, while the transactions of earlier blocks are shared with `self`,
+    /// so blocks applied to the copy must never be committed.
+    pub(crate) fn detached(&self) -> Self {
+        let view = self.view();
+
+        Self {
+            world: view.world.detached(),
+            block_hashes: cell(&view.block_hashes),
+            transactions: self.transactions.detached(&view.transactions),
+            commit_topology: cell(&view.commit_topology),
+            prev_commit_topology: cell(&view.prev_commit_topology),
+            // Smart contracts can only be run by the engine they are compiled with
+            engine: self.engine.clone(),
+            kura: Arc::clone(&self.kura),
+            query_handle: self.query_handle.clone(),
+            signature_cache: SignatureCache::default(),
+            redaction_secret: self.redaction_secret.clone(),
+            #[cfg(feature = "telemetry")]
+            telemetry: StateTelemetry::default(),
+            view_lock: parking_lot::RwLock::new(()),
+        }
+    }
+}
+
+impl WorldView<'_> {
+    /// Copy the world seen by the view into a new one.
+    pub(crate) fn detached(&self) -> World {
+        World {
+            parameters: cell(&self.parameters),
+            peers: cell(&self.peers),
+            domains: storage(&self.domains),
+            accounts: storage(&self.accounts),
+            asset_definitions: storage(&self.asset_definitions),
+            assets: storage(&self.assets),
+            nfts: storage(&self.nfts),
+            nft_content_index: storage(&self.nft_content_index),
+            roles: storage(&self.roles),
+            account_permissions: storage(&self.account_permissions),
+            account_roles: storage(&self.account_roles),
+            role_templates: storage(&self.role_templates),
+            anchors: storage(&self.anchors),
+            asset_movements: storage(&self.asset_movements),
+            permission_grants: storage(&self.permission_grants),
+            permission_revocations: storage(&self.permission_revocations),
+            domain_validators: storage(&self.domain_validators),
+            redactions: storage(&self.redactions),
+            domain_transfer_offers: storage(&self.domain_transfer_offers),
+            spending_counters: storage(&self.spending_counters),
+            triggers: self.triggers.detached(),
+            executor: cell(&self.executor),
+            executor_data_model: cell(&self.executor_data_model),
+            pending_executor_upgrade: cell(&self.pending_executor_upgrade),
+            pending_executor_upgrade_policy: cell(&self.pending_executor_upgrade_policy),
+            external_event_buf: Cell::new(Vec::new()),
+        }
+    }
+}
+
+// NB: Serialized exactly as `World`, so it has to be updated together with it.
+// Storages are copied one at a time, so only one of them is held twice in memory.
+impl Serialize for WorldView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut world = serializer.serialize_struct("World", 24)?;
+        world.serialize_field("parameters", &cell(&self.parameters))?;
+        world.serialize_field("peers", &cell(&self.peers))?;
+        world.serialize_field("domains", &storage(&self.domains))?;
+        world.serialize_field("accounts", &storage(&self.accounts))?;
+        world.serialize_field("asset_definitions", &storage(&self.asset_definitions))?;
+        world.serialize_field("assets", &storage(&self.assets))?;
+        world.serialize_field("nfts", &storage(&self.nfts))?;
+        world.serialize_field("roles", &storage(&self.roles))?;
+        world.serialize_field("account_permissions", &storage(&self.account_permissions))?;
+        world.serialize_field("account_roles", &storage(&self.account_roles))?;
+        world.serialize_field("role_templates", &storage(&self.role_templates))?;
+        world.serialize_field("anchors", &storage(&self.anchors))?;
+        world.serialize_field("asset_movements", &storage(&self.asset_movements))?;
+        world.serialize_field("permission_grants", &storage(&self.permission_grants))?;
+        world.serialize_field(
+            "permission_revocations",
+            &storage(&self.permission_revocations),
+        )?;
+        world.serialize_field("domain_validators", &storage(&self.domain_validators))?;
+        world.serialize_field("redactions", &storage(&self.redactions))?;
+        world.serialize_field(
+            "domain_transfer_offers",
+            &storage(&self.domain_transfer_offers),
+        )?;
+        world.serialize_field("spending_counters", &storage(&self.spending_counters))?;
+        world.serialize_field("triggers", &self.triggers)?;
+        world.serialize_field("executor", &cell(&self.executor))?;
+        world.serialize_field("executor_data_model", &cell(&self.executor_data_model))?;
+        world.serialize_field(
+            "pending_executor_upgrade",
+            &cell(&self.pending_executor_upgrade),
+        )?;
+        world.serialize_field(
+            "pending_executor_upgrade_policy",
+            &cell(&self.pending_executor_upgrade_policy),
+        )?;
+        // Events are only buffered while a block is applied, so none are ever committed
+        world.serialize_field(
+            "external_event_buf",
+            &Cell::new(Vec::<iroha_data_model::events::EventBox>::new()),
+        )?;
+        world.end()
+    }
+}
+
+// NB: Serialized exactly as `State`, so it has to be updated together with it.
+impl Serialize for StateView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("State", 5)?;
+        state.serialize_field("world", &self.world)?;
+        state.serialize_field("block_hashes", &cell(&self.block_hashes))?;
+        state.serialize_field("transactions", &self.transactions)?;
+        state.serialize_field("commit_topology", &cell(&self.commit_topology))?;
+        state.serialize_field("prev_commit_topology", &cell(&self.prev_commit_topology))?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        kura::Kura, query::store::LiveQueryStore, queue::tests::world_with_test_domains,
+        state::State,
+    };
+
+    #[tokio::test]
+    async fn view_is_serialized_as_state() {
+        let state = State::new(
+            world_with_test_domains(),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&state.view()).unwrap(),
+            serde_json::to_value(&state).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn detached_copy_is_equal_to_state() {
+        let state = State::new(
+            world_with_test_domains(),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&state.detached()).unwrap(),
+            serde_json::to_value(&state).unwrap()
+        );
+    }
+}
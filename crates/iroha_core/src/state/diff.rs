@@ -0,0 +1,700 @@
+//! Entity-level comparison of two world states.
+//!
+//! Used to verify that a runtime upgrade (e.g. executor migration) left the
+//! existing entities intact before the network is opened again, and to preview
+//! the effects of a transaction.
+
+use std::{collections::BTreeSet, fmt};
+
+pub use iroha_data_model::dry_run::{BalanceChange, Change, EntityDiff, EntityKind};
+use iroha_data_model::{asset::AssetValue, prelude::*, statement::MovementDirection};
+use mv::storage::StorageReadOnly;
+use serde::Serialize;
+
+use super::WorldReadOnly;
+use crate::smartcontracts::triggers::set::SetReadOnly as _;
+
+/// Entity-level differences between two world states.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WorldDiff {
+    /// Differing entities, grouped by [`EntityKind`]
+    pub entries: Vec<EntityDiff>,
+    /// Changed asset balances, ordered by asset
+    pub balances: Vec<BalanceChange>,
+}
+
+impl WorldDiff {
+    /// Compare `before` and `after` world states entity by entity.
+    pub fn between(before: &impl WorldReadOnly, after: &impl WorldReadOnly) -> Self {
+        let mut diff = Self::default();
+
+        diff.compare_value(
+            EntityKind::Parameters,
+            "parameters",
+            before.parameters(),
+            after.parameters(),
+        );
+        diff.compare_value(
+            EntityKind::Executor,
+            "executor",
+            before.executor(),
+            after.executor(),
+        );
+        diff.compare_value(
+            EntityKind::ExecutorDataModel,
+            "executor_data_model",
+            before.executor_data_model(),
+            after.executor_data_model(),
+        );
+
+        diff.compare_sets(
+            EntityKind::Peer,
+            before.peers().iter().map(ToString::to_string),
+            after.peers().iter().map(ToString::to_string),
+        );
+        diff.compare_storage(
+            EntityKind::Domain,
+            before.domains(),
+            after.domains(),
+            ToString::to_string,
+        );
+        diff.compare_storage(
+            EntityKind::Account,
+            before.accounts(),
+            after.accounts(),
+            ToString::to_string,
+        );
+        diff.compare_storage(
+            EntityKind::AssetDefinition,
+            before.asset_definitions(),
+            after.asset_definitions(),
+            ToString::to_string,
+        );
+        diff.compare_storage(
+            EntityKind::Asset,
+            before.assets(),
+            after.assets(),
+            ToString::to_string,
+        );
+        diff.compare_balances(before.assets(), after.assets());
+        diff.compare_storage(
+            EntityKind::Nft,
+            before.nfts(),
+            after.nfts(),
+            ToString::to_string,
+        );
+        diff.compare_storage(
+            EntityKind::Role,
+            before.roles(),
+            after.roles(),
+            ToString::to_string,
+        );
+        diff.compare_sets(
+            EntityKind::AccountPermission,
+            account_permissions(before),
+            account_permissions(after),
+        );
+        diff.compare_storage(
+            EntityKind::AccountRole,
+            before.account_roles(),
+            after.account_roles(),
+            |role| format!("{}: {}", role.account, role.id),
+        );
+        diff.compare_storage(
+            EntityKind::RoleTemplate,
+            before.role_templates(),
+            after.role_templates(),
+            ToString::to_string,
+        );
+        diff.compare_storage(
+            EntityKind::Anchor,
+            before.anchors(),
+            after.anchors(),
+            ToString::to_string,
+        );
+        diff.compare_triggers(before, after);
+
+        diff
+    }
+
+    /// Compare `before` and `after` world states where the data `events` emitted
+    /// in between show that they may differ.
+    ///
+    /// Unlike [`Self::between`], the cost depends on the entities touched by the events
+    /// rather than on the size of the states. Entities removed together with their domain
+    /// or account are found through it. Anchors and role templates emit no events,
+    /// so they are compared in full.
+    pub fn touched_by<'event>(
+        before: &impl WorldReadOnly,
+        after: &impl WorldReadOnly,
+        events: impl IntoIterator<Item = &'event DataEvent>,
+    ) -> Self {
+        let touched = Touched::new(before, events);
+        let mut diff = Self::default();
+
+        if touched.parameters {
+            diff.compare_value(
+                EntityKind::Parameters,
+                "parameters",
+                before.parameters(),
+                after.parameters(),
+            );
+        }
+        if touched.executor {
+            diff.compare_value(
+                EntityKind::Executor,
+                "executor",
+                before.executor(),
+                after.executor(),
+            );
+            diff.compare_value(
+                EntityKind::ExecutorDataModel,
+                "executor_data_model",
+                before.executor_data_model(),
+                after.executor_data_model(),
+            );
+        }
+        if touched.peers {
+            diff.compare_sets(
+                EntityKind::Peer,
+                before.peers().iter().map(ToString::to_string),
+                after.peers().iter().map(ToString::to_string),
+            );
+        }
+
+        diff.compare_keys(
+            EntityKind::Domain,
+            before.domains(),
+            after.domains(),
+            &touched.domains,
+        );
+        diff.compare_keys(
+            EntityKind::Account,
+            before.accounts(),
+            after.accounts(),
+            &touched.accounts,
+        );
+        diff.compare_keys(
+            EntityKind::AssetDefinition,
+            before.asset_definitions(),
+            after.asset_definitions(),
+            &touched.asset_definitions,
+        );
+        diff.compare_keys(
+            EntityKind::Asset,
+            before.assets(),
+            after.assets(),
+            &touched.assets,
+        );
+        diff.push_balances(touched.assets.iter().map(|id| {
+            (
+                id,
+                balance(before.assets(), id),
+                balance(after.assets(), id),
+            )
+        }));
+        diff.compare_keys(EntityKind::Nft, before.nfts(), after.nfts(), &touched.nfts);
+        diff.compare_keys(
+            EntityKind::Role,
+            before.roles(),
+            after.roles(),
+            &touched.roles,
+        );
+        diff.compare_sets(
+            EntityKind::AccountPermission,
+            touched
+                .accounts
+                .iter()
+                .flat_map(|account| permissions_of(before, account)),
+            touched
+                .accounts
+                .iter()
+                .flat_map(|account| permissions_of(after, account)),
+        );
+        diff.compare_sets(
+            EntityKind::AccountRole,
+            touched
+                .accounts
+                .iter()
+                .flat_map(|account| roles_of(before, account)),
+            touched
+                .accounts
+                .iter()
+                .flat_map(|account| roles_of(after, account)),
+        );
+        diff.compare_storage(
+            EntityKind::RoleTemplate,
+            before.role_templates(),
+            after.role_templates(),
+            ToString::to_string,
+        );
+        diff.compare_storage(
+            EntityKind::Anchor,
+            before.anchors(),
+            after.anchors(),
+            ToString::to_string,
+        );
+        if touched.all_triggers {
+            diff.compare_triggers(before, after);
+        } else {
+            diff.compare_triggers_of(before, after, touched.triggers.iter());
+        }
+
+        diff
+    }
+
+    /// Check that the second state only extends the first one.
+    ///
+    /// Changes of the executor itself are expected during an upgrade and are not taken into account.
+    pub fn is_additive(&self) -> bool {
+        self.entries.iter().all(|entry| {
+            entry.change == Change::Added
+                || matches!(
+                    entry.kind,
+                    EntityKind::Executor | EntityKind::ExecutorDataModel
+                )
+        })
+    }
+
+    /// Check if states are identical.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn push(&mut self, kind: EntityKind, id: String, change: Change) {
+        self.entries.push(EntityDiff { kind, id, change });
+    }
+
+    fn compare_value<T: Serialize>(&mut self, kind: EntityKind, id: &str, before: &T, after: &T) {
+        if !same(before, after) {
+            self.push(kind, id.to_owned(), Change::Modified);
+        }
+    }
+
+    fn compare_sets(
+        &mut self,
+        kind: EntityKind,
+        before: impl Iterator<Item = String>,
+        after: impl Iterator<Item = String>,
+    ) {
+        let before = before.collect::<std::collections::BTreeSet<_>>();
+        let after = after.collect::<std::collections::BTreeSet<_>>();
+
+        for id in before.difference(&after) {
+            self.push(kind, id.clone(), Change::Removed);
+        }
+        for id in after.difference(&before) {
+            self.push(kind, id.clone(), Change::Added);
+        }
+    }
+
+    fn compare_storage<K, V>(
+        &mut self,
+        kind: EntityKind,
+        before: &impl StorageReadOnly<K, V>,
+        after: &impl StorageReadOnly<K, V>,
+        display: impl Fn(&K) -> String,
+    ) where
+        K: mv::Key,
+        V: mv::Value + Serialize,
+    {
+        for (id, value) in before.iter() {
+            match after.get(id) {
+                None => self.push(kind, display(id), Change::Removed),
+                Some(new_value) if !same(value, new_value) => {
+                    self.push(kind, display(id), Change::Modified);
+                }
+                Some(_) => {}
+            }
+        }
+        for (id, _) in after.iter() {
+            if before.get(id).is_none() {
+                self.push(kind, display(id), Change::Added);
+            }
+        }
+    }
+
+    fn compare_keys<'key, K, V>(
+        &mut self,
+        kind: EntityKind,
+        before: &impl StorageReadOnly<K, V>,
+        after: &impl StorageReadOnly<K, V>,
+        ids: impl IntoIterator<Item = &'key K>,
+    ) where
+        K: mv::Key + fmt::Display + 'key,
+        V: mv::Value + Serialize,
+    {
+        for id in ids {
+            match (before.get(id), after.get(id)) {
+                (Some(_), None) => self.push(kind, id.to_string(), Change::Removed),
+                (None, Some(_)) => self.push(kind, id.to_string(), Change::Added),
+                (Some(old), Some(new)) if !same(old, new) => {
+                    self.push(kind, id.to_string(), Change::Modified);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn compare_balances(
+        &mut self,
+        before: &impl StorageReadOnly<AssetId, AssetValue>,
+        after: &impl StorageReadOnly<AssetId, AssetValue>,
+    ) {
+        let balances = before
+            .iter()
+            .map(|(id, value)| (id, value.value, balance(after, id)))
+            .chain(
+                after
+                    .iter()
+                    .filter(|(id, _)| before.get(*id).is_none())
+                    .map(|(id, value)| (id, Numeric::ZERO, value.value)),
+            );
+
+        self.push_balances(balances);
+    }
+
+    fn push_balances<'id>(
+        &mut self,
+        balances: impl Iterator<Item = (&'id AssetId, Numeric, Numeric)>,
+    ) {
+        let changed = balances.filter_map(|(id, old, new)| {
+            let (direction, amount) = if new > old {
+                (MovementDirection::Credit, new.checked_sub(old)?)
+            } else {
+                (MovementDirection::Debit, old.checked_sub(new)?)
+            };
+            (!amount.is_zero()).then(|| BalanceChange {
+                asset: id.clone(),
+                direction,
+                amount,
+                balance: new,
+            })
+        });
+
+        self.balances.extend(changed);
+        self.balances.sort_by(|a, b| a.asset.cmp(&b.asset));
+    }
+
+    fn compare_triggers(&mut self, before: &impl WorldReadOnly, after: &impl WorldReadOnly) {
+        let (before, after) = (before.triggers(), after.triggers());
+
+        for id in before.ids_iter() {
+            let old = before.inspect_by_id(id, |action| action.clone_and_box());
+            match after.inspect_by_id(id, |action| action.clone_and_box()) {
+                None => self.push(EntityKind::Trigger, id.to_string(), Change::Removed),
+                Some(new) if !same(&old, &Some(new)) => {
+                    self.push(EntityKind::Trigger, id.to_string(), Change::Modified);
+                }
+                Some(_) => {}
+            }
+        }
+        for id in after.ids_iter() {
+            if before.ids().get(id).is_none() {
+                self.push(EntityKind::Trigger, id.to_string(), Change::Added);
+            }
+        }
+    }
+
+    fn compare_triggers_of<'id>(
+        &mut self,
+        before: &impl WorldReadOnly,
+        after: &impl WorldReadOnly,
+        ids: impl Iterator<Item = &'id TriggerId>,
+    ) {
+        let (before, after) = (before.triggers(), after.triggers());
+
+        for id in ids {
+            let old = before.inspect_by_id(id, |action| action.clone_and_box());
+            let new = after.inspect_by_id(id, |action| action.clone_and_box());
+            match (&old, &new) {
+                (Some(_), None) => self.push(EntityKind::Trigger, id.to_string(), Change::Removed),
+                (None, Some(_)) => self.push(EntityKind::Trigger, id.to_string(), Change::Added),
+                (Some(_), Some(_)) if !same(&old, &new) => {
+                    self.push(EntityKind::Trigger, id.to_string(), Change::Modified);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl fmt::Display for WorldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+fn account_permissions(world: &impl WorldReadOnly) -> impl Iterator<Item = String> + '_ {
+    world
+        .account_permissions()
+        .iter()
+        .flat_map(|(account, permissions)| {
+            permissions
+                .iter()
+                .map(move |permission| format!("{account}: {permission}"))
+        })
+}
+
+fn permissions_of<'world>(
+    world: &'world impl WorldReadOnly,
+    account: &'world AccountId,
+) -> impl Iterator<Item = String> + 'world {
+    world
+        .account_inherent_permissions(account)
+        .map(move |permission| format!("{account}: {permission}"))
+}
+
+fn roles_of<'world>(
+    world: &'world impl WorldReadOnly,
+    account: &'world AccountId,
+) -> impl Iterator<Item = String> + 'world {
+    world
+        .account_roles_iter(account)
+        .map(move |role| format!("{account}: {role}"))
+}
+
+/// Balance of the asset, which is zero for assets that don't exist.
+fn balance(assets: &impl StorageReadOnly<AssetId, AssetValue>, id: &AssetId) -> Numeric {
+    assets.get(id).map_or(Numeric::ZERO, |asset| asset.value)
+}
+
+/// Entities which the data events show to be touched.
+#[derive(Default)]
+struct Touched {
+    parameters: bool,
+    executor: bool,
+    peers: bool,
+    domains: BTreeSet<DomainId>,
+    accounts: BTreeSet<AccountId>,
+    asset_definitions: BTreeSet<AssetDefinitionId>,
+    assets: BTreeSet<AssetId>,
+    nfts: BTreeSet<NftId>,
+    roles: BTreeSet<RoleId>,
+    triggers: BTreeSet<TriggerId>,
+    /// Triggers are removed together with the domains and accounts of their authorities
+    all_triggers: bool,
+}
+
+impl Touched {
+    fn new<'event>(
+        before: &impl WorldReadOnly,
+        events: impl IntoIterator<Item = &'event DataEvent>,
+    ) -> Self {
+        let mut touched = Self::default();
+        let mut deleted_domains = Vec::new();
+        let mut deleted_accounts = Vec::new();
+
+        for event in events {
+            match event {
+                DataEvent::Peer(_) => touched.peers = true,
+                DataEvent::Configuration(_) => touched.parameters = true,
+                DataEvent::Executor(_) => touched.executor = true,
+                DataEvent::Role(event) => {
+                    touched.roles.insert(event.origin().clone());
+                }
+                DataEvent::Trigger(event) => {
+                    touched.triggers.insert(event.origin().clone());
+                }
+                DataEvent::Domain(event) => {
+                    touched.domains.insert(event.origin().clone());
+                    match event {
+                        DomainEvent::Deleted(domain) => deleted_domains.push(domain),
+                        DomainEvent::AssetDefinition(event) => {
+                            touched.asset_definitions.insert(event.origin().clone());
+                        }
+                        DomainEvent::Nft(event) => {
+                            touched.nfts.insert(event.origin().clone());
+                        }
+                        DomainEvent::Account(event) => {
+                            touched.accounts.insert(event.origin().clone());
+                            match event {
+                                AccountEvent::Deleted(account) => {
+                                    deleted_accounts.push(account.clone());
+                                }
+                                AccountEvent::Asset(event) => {
+                                    touched.assets.insert(event.origin().clone());
+                                }
+                                _ => {}
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Entities removed together with their domain or account emit no events of their own
+        touched.all_triggers = !deleted_domains.is_empty() || !deleted_accounts.is_empty();
+        for domain in deleted_domains {
+            deleted_accounts.extend(
+                before
+                    .accounts_in_domain_iter(domain)
+                    .map(|account| account.id.clone()),
+            );
+            touched.asset_definitions.extend(
+                before
+                    .asset_definitions_in_domain_iter(domain)
+                    .map(|definition| definition.id().clone()),
+            );
+            touched
+                .nfts
+                .extend(before.nfts_in_domain_iter(domain).map(|nft| nft.id.clone()));
+        }
+        for account in &deleted_accounts {
+            touched.assets.extend(
+                before
+                    .assets_in_account_iter(account)
+                    .map(|asset| asset.id.clone()),
+            );
+        }
+        touched.accounts.extend(deleted_accounts);
+
+        touched
+    }
+}
+
+/// Compare values by their serialized representation,
+/// since not all state values implement [`PartialEq`].
+fn same<T: Serialize>(a: &T, b: &T) -> bool {
+    match (serde_json::to_value(a), serde_json::to_value(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::gen_account_in;
+
+    use super::*;
+    use crate::{
+        block::ValidBlock,
+        kura::Kura,
+        query::store::LiveQueryStore,
+        smartcontracts::Execute as _,
+        state::{State, World},
+    };
+
+    fn world() -> World {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let domain = Domain::new("wonderland".parse().unwrap()).build(&account_id);
+        let account = Account::new(account_id.clone()).build(&account_id);
+        World::with([domain], [account], [])
+    }
+
+    #[tokio::test]
+    async fn identical_states_have_no_diff() {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let world = || {
+            World::with(
+                [Domain::new("wonderland".parse().unwrap()).build(&account_id)],
+                [Account::new(account_id.clone()).build(&account_id)],
+                [],
+            )
+        };
+        let before = State::new(
+            world(),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+        let after = State::new(
+            world(),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+
+        let diff = WorldDiff::between(&before.view().world, &after.view().world);
+
+        assert!(diff.is_empty());
+        assert!(diff.is_additive());
+    }
+
+    #[tokio::test]
+    async fn new_domain_is_additive() {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let before = State::new(
+            world(),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+        let after_world = World::with(
+            [
+                Domain::new("wonderland".parse().unwrap()).build(&account_id),
+                Domain::new("looking_glass".parse().unwrap()).build(&account_id),
+            ],
+            [],
+            [],
+        );
+        let after = State::new(
+            after_world,
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+
+        let diff = WorldDiff::between(&before.view().world, &after.view().world);
+
+        assert!(diff.entries.contains(&EntityDiff {
+            kind: EntityKind::Domain,
+            id: "looking_glass".to_owned(),
+            change: Change::Added,
+        }));
+        // The original account is gone and wonderland owner has changed
+        assert!(!diff.is_additive());
+    }
+
+    #[tokio::test]
+    async fn touched_entities_are_compared_as_whole_states() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let rose: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let alice_rose = AssetId::new(rose.clone(), alice_id.clone());
+        let state = State::new(
+            World::with_assets(
+                [Domain::new("wonderland".parse().unwrap()).build(&alice_id)],
+                [alice_id.clone(), bob_id.clone()].map(|id| Account::new(id).build(&alice_id)),
+                [AssetDefinition::numeric(rose).build(&alice_id)],
+                [Asset::new(alice_rose.clone(), 10_u32)],
+                [],
+            ),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+        let header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+
+        let before = state.view();
+        let mut state_block = state.block(header);
+        let mut state_transaction = state_block.transaction();
+        Transfer::asset_numeric(alice_rose, 3_u32, bob_id)
+            .execute(&alice_id, &mut state_transaction)
+            .unwrap();
+        Register::domain(Domain::new("looking_glass".parse().unwrap()))
+            .execute(&alice_id, &mut state_transaction)
+            .unwrap();
+        state_transaction.apply();
+
+        let events = state_block
+            .world
+            .external_event_buf
+            .iter()
+            .filter_map(|event| match event {
+                EventBox::Data(event) => Some(event),
+                _ => None,
+            });
+        let diff = WorldDiff::touched_by(&before.world, &state_block.world, events);
+        let expected = WorldDiff::between(&before.world, &state_block.world);
+
+        assert_eq!(diff.entries.len(), expected.entries.len());
+        assert!(expected
+            .entries
+            .iter()
+            .all(|entry| diff.entries.contains(entry)));
+        assert_eq!(diff.balances, expected.balances);
+        assert_eq!(diff.balances.len(), 2);
+    }
+}
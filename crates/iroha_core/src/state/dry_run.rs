@@ -0,0 +1,258 @@
+//! Trying out transactions against the world state without committing them.
+//!
+//! Lets operators check that the migration of a new executor succeeds, and see
+//! what it changes, before submitting the upgrade to the network. Clients can
+//! also check whether the executor would accept an ordinary transaction, and
+//! preview which entities and balances it would change.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use iroha_data_model::{
+    dry_run::TransactionDryRun, executor::ExecutorUpgradeDryRun, isi::InstructionBox, prelude::*,
+    transaction::error::TransactionRejectionReason,
+};
+use iroha_executor_data_model::permission::executor::CanUpgradeExecutor;
+use parking_lot::Mutex;
+
+use super::{diff::WorldDiff, State};
+use crate::{block::BlockBuilder, prelude::*, smartcontracts::wasm::cache::WasmCache};
+
+/// Minimal interval between executor upgrade dry runs.
+///
+/// Each of them runs the migration of the new executor and compares the whole world states.
+const EXECUTOR_UPGRADE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Error which might occur during [`Staging::upgrade_executor`].
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum Error {
+    /// Transaction must consist of a single `Upgrade` instruction
+    NotAnUpgrade,
+    /// Authority of the transaction isn't permitted to upgrade the executor
+    NotPermitted,
+    /// Executor upgrade can only be tried once in {0:?}
+    Throttled(Duration),
+}
+
+/// Detached copy of the state which transactions are tried out against.
+///
+/// Blocks applied to the copy are dropped instead of being committed, and the node
+/// applies its blocks to the original state, so dry runs neither wait for the node
+/// nor hold it up. The copy is refreshed by the first dry run after a block is
+/// committed, and dry runs are made one at a time.
+pub struct Staging {
+    state: Arc<State>,
+    copy: Mutex<Option<State>>,
+    executor_upgrade_tried_at: Mutex<Option<Instant>>,
+}
+
+impl Staging {
+    /// Construct [`Self`] for trying out transactions against `state`.
+    pub fn new(state: Arc<State>) -> Self {
+        Self {
+            state,
+            copy: Mutex::new(None),
+            executor_upgrade_tried_at: Mutex::new(None),
+        }
+    }
+
+    /// State which the copy is made of.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Validate and execute `tx` as the executor would in the next block,
+    /// without committing anything.
+    ///
+    /// # Errors
+    /// If the transaction would be rejected
+    pub fn validate_transaction(
+        &self,
+        tx: AcceptedTransaction,
+    ) -> Result<(), TransactionRejectionReason> {
+        self.with_copy(|copy| {
+            let header = trial_block_header(copy, &tx);
+            let mut state_block = copy.block(header);
+            let (_, result) = state_block.validate_transaction(tx, &mut WasmCache::new());
+
+            result.map(|_| ())
+        })
+    }
+
+    /// Validate and execute `tx` like [`Self::validate_transaction`] does, and
+    /// report the entities and asset balances it would change.
+    ///
+    /// The changes are found by comparing the entities which the events emitted by `tx` touch,
+    /// see [`WorldDiff::touched_by`].
+    pub fn transaction(&self, tx: AcceptedTransaction) -> TransactionDryRun {
+        self.with_copy(|copy| {
+            let header = trial_block_header(copy, &tx);
+            let before = copy.view();
+            let mut state_block = copy.block(header);
+            let (_, result) = state_block.validate_transaction(tx, &mut WasmCache::new());
+
+            match result {
+                Ok(_) => {
+                    let events =
+                        state_block.world.external_event_buf.iter().filter_map(
+                            |event| match event {
+                                EventBox::Data(event) => Some(event),
+                                _ => None,
+                            },
+                        );
+                    let diff = WorldDiff::touched_by(&before.world, &state_block.world, events);
+                    TransactionDryRun {
+                        rejection: None,
+                        changes: diff.entries,
+                        balances: diff.balances,
+                    }
+                }
+                Err(rejection) => TransactionDryRun {
+                    rejection: Some(rejection),
+                    changes: Vec::new(),
+                    balances: Vec::new(),
+                },
+            }
+        })
+    }
+
+    /// Validate and execute the executor upgrade `tx`, without committing anything.
+    ///
+    /// Only accounts which can upgrade the executor may try it, and not more often than
+    /// once in [`EXECUTOR_UPGRADE_INTERVAL`].
+    ///
+    /// # Errors
+    /// - If `tx` doesn't consist of a single [`Upgrade`] instruction
+    /// - If the authority of `tx` doesn't have [`CanUpgradeExecutor`]
+    /// - If the previous upgrade was tried less than [`EXECUTOR_UPGRADE_INTERVAL`] ago
+    pub fn upgrade_executor(
+        &self,
+        tx: AcceptedTransaction,
+    ) -> Result<ExecutorUpgradeDryRun, Error> {
+        let Executable::Instructions(instructions) = tx.as_ref().instructions() else {
+            return Err(Error::NotAnUpgrade);
+        };
+        if !matches!(&**instructions, [InstructionBox::Upgrade(_)]) {
+            return Err(Error::NotAnUpgrade);
+        }
+        let can_upgrade = Permission::from(CanUpgradeExecutor);
+        if !self
+            .state
+            .view()
+            .world
+            .account_has_permission(tx.as_ref().authority(), &can_upgrade)
+        {
+            return Err(Error::NotPermitted);
+        }
+        {
+            let mut tried_at = self.executor_upgrade_tried_at.lock();
+            let now = Instant::now();
+            if tried_at.is_some_and(|tried_at| now - tried_at < EXECUTOR_UPGRADE_INTERVAL) {
+                return Err(Error::Throttled(EXECUTOR_UPGRADE_INTERVAL));
+            }
+            *tried_at = Some(now);
+        }
+
+        Ok(self.with_copy(|copy| {
+            let header = trial_block_header(copy, &tx);
+            let before = copy.view();
+            let mut state_block = copy.block(header);
+            let (_, result) = state_block.validate_transaction(tx, &mut WasmCache::new());
+
+            ExecutorUpgradeDryRun {
+                rejection: result.err(),
+                data_model: state_block.world.executor_data_model().clone(),
+                changes: WorldDiff::between(&before.world, &state_block.world)
+                    .entries
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            }
+        }))
+    }
+
+    /// Run `f` on the copy of the latest state.
+    fn with_copy<T>(&self, f: impl FnOnce(&State) -> T) -> T {
+        let mut copy = self.copy.lock();
+        let latest_block_hash = self.state.view().latest_block_hash();
+        if copy
+            .as_ref()
+            .is_some_and(|copy| copy.view().latest_block_hash() != latest_block_hash)
+        {
+            *copy = None;
+        }
+
+        f(copy.get_or_insert_with(|| self.state.detached()))
+    }
+}
+
+/// Header of the block following the latest one, which only contains `tx`.
+fn trial_block_header(state: &State, tx: &AcceptedTransaction) -> BlockHeader {
+    BlockBuilder::new(vec![tx.clone()])
+        .chain(0, &state.view())
+        .sign(KeyPair::random().private_key())
+        .unpack(|_| {})
+        .header()
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::gen_account_in;
+
+    use super::*;
+    use crate::{
+        block::ValidBlock, kura::Kura, query::store::LiveQueryStore, smartcontracts::Execute as _,
+    };
+
+    #[tokio::test]
+    async fn executor_upgrade_is_tried_by_permitted_accounts_only() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, bob_keypair) = gen_account_in("wonderland");
+        let state = State::new(
+            World::with(
+                [Domain::new("wonderland".parse().unwrap()).build(&alice_id)],
+                [alice_id.clone(), bob_id.clone()].map(|id| Account::new(id).build(&alice_id)),
+                [],
+            ),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+        {
+            let header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+                .as_ref()
+                .header();
+            let mut state_block = state.block(header);
+            let mut state_transaction = state_block.transaction();
+            Grant::account_permission(CanUpgradeExecutor, alice_id.clone())
+                .execute(&alice_id, &mut state_transaction)
+                .unwrap();
+            state_transaction.apply();
+            state_block.commit();
+        }
+        let staging = Staging::new(Arc::new(state));
+        let upgrade = |authority: &AccountId, key_pair: &KeyPair| {
+            let executor = Executor::new(WasmSmartContract::from_compiled(Vec::new()));
+            let tx = TransactionBuilder::new(
+                ChainId::from("00000000-0000-0000-0000-000000000000"),
+                authority.clone(),
+            )
+            .with_instructions([Upgrade::new(executor)])
+            .sign(key_pair.private_key());
+            AcceptedTransaction::new_unchecked(tx)
+        };
+
+        assert!(matches!(
+            staging.upgrade_executor(upgrade(&bob_id, &bob_keypair)),
+            Err(Error::NotPermitted)
+        ));
+        assert!(staging
+            .upgrade_executor(upgrade(&alice_id, &alice_keypair))
+            .is_ok());
+        assert!(matches!(
+            staging.upgrade_executor(upgrade(&alice_id, &alice_keypair)),
+            Err(Error::Throttled(_))
+        ));
+    }
+}
@@ -0,0 +1,287 @@
+//! Self-contained representation of the world state for external audit.
+//!
+//! [`WorldExport`] is a plain serde structure, so it can be written in any
+//! self-describing format (JSON, CBOR). Entities are exported in the same
+//! shape as they are returned by queries, sorted by their ids.
+
+use iroha_data_model::{parameter::Parameters, permission::Permission, prelude::*, role::RoleId};
+use mv::{cell::Cell, storage::StorageReadOnly};
+use serde::{Deserialize, Serialize};
+
+use super::{StateReadOnly, World, WorldReadOnly};
+use crate::smartcontracts::{
+    triggers::{
+        set::{Error as TriggerSetError, SetReadOnly as _},
+        specialized::LoadedActionTrait as _,
+    },
+    wasm,
+};
+
+/// Version of the [`WorldExport`] format.
+///
+/// Should be increased on every incompatible change of the format.
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// World state at a certain block height.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldExport {
+    /// Version of the format, see [`EXPORT_FORMAT_VERSION`]
+    pub version: u32,
+    /// Height of the latest block applied to the exported state
+    pub height: usize,
+    /// On-chain parameters
+    pub parameters: Parameters,
+    /// Peers registered on chain
+    pub peers: Vec<PeerId>,
+    /// Registered domains
+    pub domains: Vec<Domain>,
+    /// Registered accounts
+    pub accounts: Vec<Account>,
+    /// Registered asset definitions
+    pub asset_definitions: Vec<AssetDefinition>,
+    /// Registered assets
+    pub assets: Vec<Asset>,
+    /// Registered non-fungible tokens
+    pub nfts: Vec<Nft>,
+    /// Registered roles together with their permissions
+    pub roles: Vec<Role>,
+    /// Permissions granted to accounts directly
+    pub account_permissions: Vec<AccountPermissions>,
+    /// Roles granted to accounts
+    pub account_roles: Vec<AccountRole>,
+    /// Registered role templates
+    #[serde(default)]
+    pub role_templates: Vec<RoleTemplate>,
+    /// Anchored content hashes
+    #[serde(default)]
+    pub anchors: Vec<AnchoredHash>,
+    /// Registered triggers with their original executables
+    pub triggers: Vec<Trigger>,
+}
+
+/// Permissions granted directly to an account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountPermissions {
+    /// Account holding the permissions
+    pub account: AccountId,
+    /// Granted permissions
+    pub permissions: Vec<Permission>,
+}
+
+/// Role granted to an account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountRole {
+    /// Account holding the role
+    pub account: AccountId,
+    /// Granted role
+    pub role: RoleId,
+}
+
+/// Error which might occur while importing [`WorldExport`].
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum ImportError {
+    /// Failed to load trigger `{0}`
+    Trigger(TriggerId, #[source] TriggerSetError),
+    /// Trigger `{0}` has invalid filter: {1}
+    TriggerFilter(TriggerId, &'static str),
+    /// Duplicate trigger `{0}`
+    DuplicateTrigger(TriggerId),
+}
+
+impl WorldExport {
+    /// Export the latest world state.
+    pub fn from_state(state: &impl StateReadOnly) -> Self {
+        let world = state.world();
+        let triggers = world.triggers();
+
+        Self {
+            version: EXPORT_FORMAT_VERSION,
+            height: state.height(),
+            parameters: world.parameters().clone(),
+            peers: world.peers().iter().cloned().collect(),
+            domains: world.domains_iter().cloned().collect(),
+            accounts: world.accounts_iter().map(|entry| entry.to_owned()).collect(),
+            asset_definitions: world.asset_definitions_iter().cloned().collect(),
+            assets: world.assets_iter().map(|entry| entry.to_owned()).collect(),
+            nfts: world.nfts_iter().map(|entry| entry.to_owned()).collect(),
+            roles: world.roles().iter().map(|(_, role)| role.clone()).collect(),
+            account_permissions: world
+                .account_permissions()
+                .iter()
+                .map(|(account, permissions)| AccountPermissions {
+                    account: account.clone(),
+                    permissions: permissions.iter().cloned().collect(),
+                })
+                .collect(),
+            account_roles: world
+                .account_roles()
+                .iter()
+                .map(|(role, ())| AccountRole {
+                    account: role.account.clone(),
+                    role: role.id.clone(),
+                })
+                .collect(),
+            role_templates: world
+                .role_templates()
+                .iter()
+                .map(|(_, template)| template.clone())
+                .collect(),
+            anchors: world
+                .anchors()
+                .iter()
+                .map(|(_, anchor)| anchor.clone())
+                .collect(),
+            triggers: triggers
+                .ids_iter()
+                .map(|id| {
+                    let action = triggers
+                        .inspect_by_id(id, |action| action.clone_and_box())
+                        .expect("INTERNAL BUG: Trigger Id is in the list of ids but not in the triggers map");
+
+                    Trigger::new(id.clone(), triggers.get_original_action(action).into())
+                })
+                .collect(),
+        }
+    }
+
+    /// Build a [`World`] from the exported state.
+    ///
+    /// Executor is not part of the export, so the resulting world uses the initial one.
+    /// Useful to bootstrap test fixtures from production data.
+    ///
+    /// # Errors
+    /// If some of the triggers can't be loaded.
+    pub fn into_world(self) -> Result<World, ImportError> {
+        let Self {
+            version: _,
+            height: _,
+            parameters,
+            peers,
+            domains,
+            accounts,
+            asset_definitions,
+            assets,
+            nfts,
+            roles,
+            account_permissions,
+            account_roles,
+            role_templates,
+            anchors,
+            triggers,
+        } = self;
+
+        let mut world = World::with_assets(domains, accounts, asset_definitions, assets, nfts);
+        world.parameters = Cell::new(parameters);
+        world.peers = Cell::new(peers.into_iter().collect::<crate::Peers>());
+        world.roles = roles
+            .into_iter()
+            .map(|role| (role.id.clone(), role))
+            .collect();
+        world.account_permissions = account_permissions
+            .into_iter()
+            .map(|entry| (entry.account, entry.permissions.into_iter().collect()))
+            .collect();
+        world.account_roles = account_roles
+            .into_iter()
+            .map(|entry| {
+                (
+                    crate::role::RoleIdWithOwner::new(entry.account, entry.role),
+                    (),
+                )
+            })
+            .collect();
+        world.role_templates = role_templates
+            .into_iter()
+            .map(|template| (template.id.clone(), template))
+            .collect();
+        world.anchors = anchors
+            .into_iter()
+            .map(|anchor| (anchor.hash, anchor))
+            .collect();
+
+        let engine = wasm::create_engine();
+        let mut triggers_block = world.triggers.block();
+        let mut triggers_transaction = triggers_block.transaction();
+        for trigger in triggers {
+            let id = trigger.id().clone();
+            let added = match &trigger.action().filter {
+                EventFilterBox::Data(_) => triggers_transaction.add_data_trigger(
+                    &engine,
+                    trigger
+                        .try_into()
+                        .map_err(|err| ImportError::TriggerFilter(id.clone(), err))?,
+                ),
+                EventFilterBox::Pipeline(_) => triggers_transaction.add_pipeline_trigger(
+                    &engine,
+                    trigger
+                        .try_into()
+                        .map_err(|err| ImportError::TriggerFilter(id.clone(), err))?,
+                ),
+                EventFilterBox::Time(_) => triggers_transaction.add_time_trigger(
+                    &engine,
+                    trigger
+                        .try_into()
+                        .map_err(|err| ImportError::TriggerFilter(id.clone(), err))?,
+                ),
+                EventFilterBox::ExecuteTrigger(_) => triggers_transaction.add_by_call_trigger(
+                    &engine,
+                    trigger
+                        .try_into()
+                        .map_err(|err| ImportError::TriggerFilter(id.clone(), err))?,
+                ),
+            }
+            .map_err(|err| ImportError::Trigger(id.clone(), err))?;
+
+            if !added {
+                return Err(ImportError::DuplicateTrigger(id));
+            }
+        }
+        triggers_transaction.apply();
+        triggers_block.commit();
+
+        Ok(world)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::gen_account_in;
+
+    use super::*;
+    use crate::{kura::Kura, query::store::LiveQueryStore, state::State};
+
+    #[tokio::test]
+    async fn export_import_roundtrip() {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let domain = Domain::new("wonderland".parse().unwrap()).build(&account_id);
+        let account = Account::new(account_id.clone()).build(&account_id);
+        let asset_definition =
+            AssetDefinition::numeric("rose#wonderland".parse().unwrap()).build(&account_id);
+        let asset = Asset::new(
+            AssetId::new(asset_definition.id().clone(), account_id.clone()),
+            13_u32,
+        );
+        let world = World::with_assets([domain], [account], [asset_definition], [asset], []);
+        let state = State::new(
+            world,
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+
+        let export = WorldExport::from_state(&state.view());
+        let json = serde_json::to_string(&export).unwrap();
+        let import: WorldExport = serde_json::from_str(&json).unwrap();
+        let imported = State::new(
+            import.into_world().unwrap(),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+
+        assert_eq!(export.assets.len(), 1);
+        assert!(crate::state::diff::WorldDiff::between(
+            &state.view().world,
+            &imported.view().world
+        )
+        .is_empty());
+    }
+}
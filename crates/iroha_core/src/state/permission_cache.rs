@@ -0,0 +1,127 @@
+//! Cache of the queries the executor issues to resolve roles and permissions
+
+use std::collections::BTreeMap;
+
+use iroha_data_model::{
+    prelude::*,
+    query::{QueryBox, QueryOutput, QueryWithParams},
+};
+use parity_scale_codec::Encode;
+use parking_lot::Mutex;
+
+/// Maximum number of query outputs kept per block
+const CAPACITY: usize = 4096;
+
+/// Outputs of permission-resolution queries shared by all transactions of a block.
+///
+/// When many transactions in a block come from the same authority, the executor
+/// repeats identical role and permission lookups for each of them.
+/// Entries are dropped once an applied transaction changes roles, permissions or accounts.
+#[derive(Default)]
+pub struct PermissionCache(Mutex<BTreeMap<Vec<u8>, QueryOutput>>);
+
+impl PermissionCache {
+    /// Key under which the output of `query` is cached,
+    /// [`None`] if it is not a permission-resolution query.
+    pub fn key(query: &QueryWithParams) -> Option<Vec<u8>> {
+        matches!(
+            query.query,
+            QueryBox::FindPermissionsByAccountId(_)
+                | QueryBox::FindRolesByAccountId(_)
+                | QueryBox::FindRoles(_)
+        )
+        .then(|| query.encode())
+    }
+
+    /// Cached output of the query with the given key
+    pub fn get(&self, key: &[u8]) -> Option<QueryOutput> {
+        self.0.lock().get(key).cloned()
+    }
+
+    /// Cache `output` unless it has to be fetched in several batches
+    pub fn insert(&self, key: Vec<u8>, output: &QueryOutput) {
+        if output.continue_cursor.is_some() {
+            return;
+        }
+        let mut cache = self.0.lock();
+        if cache.len() < CAPACITY {
+            cache.insert(key, output.clone());
+        }
+    }
+
+    /// Drop all cached outputs
+    pub fn clear(&self) {
+        self.0.lock().clear();
+    }
+}
+
+/// Whether `event` signals a change that may alter cached query outputs
+pub fn affects_permissions(event: &DataEvent) -> bool {
+    matches!(
+        event,
+        DataEvent::Domain(
+            DomainEvent::Deleted(_)
+                | DomainEvent::Account(
+                    AccountEvent::Created(_)
+                        | AccountEvent::Deleted(_)
+                        | AccountEvent::PermissionAdded(_)
+                        | AccountEvent::PermissionRemoved(_)
+                        | AccountEvent::RoleGranted(_)
+                        | AccountEvent::RoleRevoked(_)
+                )
+        ) | DataEvent::Role(_)
+            | DataEvent::Executor(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_data_model::query::{
+        parameters::{ForwardCursor, QueryParams},
+        QueryOutputBatchBoxTuple, QueryWithFilter,
+    };
+    use iroha_test_samples::ALICE_ID;
+
+    use super::*;
+
+    fn output(continue_cursor: Option<ForwardCursor>) -> QueryOutput {
+        QueryOutput {
+            batch: QueryOutputBatchBoxTuple { tuple: Vec::new() },
+            remaining_items: 0,
+            continue_cursor,
+        }
+    }
+
+    #[test]
+    fn caches_only_permission_queries() {
+        let permissions = QueryWithParams::new(
+            QueryWithFilter::new(
+                FindPermissionsByAccountId::new(ALICE_ID.clone()),
+                CompoundPredicate::PASS,
+                SelectorTuple::default(),
+                None,
+            )
+            .into(),
+            QueryParams::default(),
+        );
+        let domains = QueryWithParams::new(
+            QueryWithFilter::new(
+                FindDomains,
+                CompoundPredicate::PASS,
+                SelectorTuple::default(),
+                None,
+            )
+            .into(),
+            QueryParams::default(),
+        );
+        assert!(PermissionCache::key(&domains).is_none());
+
+        let cache = PermissionCache::default();
+        let key = PermissionCache::key(&permissions).expect("permission query");
+        cache.insert(key.clone(), &output(None));
+        assert_eq!(cache.get(&key), Some(output(None)));
+
+        cache.clear();
+        assert_eq!(cache.get(&key), None);
+    }
+}
@@ -29,8 +29,12 @@ pub struct TransactionsStorage {
     /// `None` when there are no blocks yet, otherwise must be not `None`.
     latest_block: ArcSwapOption<BlockInfo>,
     /// Map with aggregated transactions of multiple blocks, EXCEPT for the latest block.
-    blocks: DashMap<Key, Value>,
+    ///
+    /// Shared with the storages detached from this one.
+    blocks: Arc<DashMap<Key, Value>>,
     write_lock: Mutex<()>,
+    /// Whether `blocks` belong to another storage, see [`Self::detached`].
+    detached: bool,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -47,8 +51,22 @@ impl TransactionsStorage {
     pub fn new() -> Self {
         Self {
             latest_block: ArcSwapOption::empty(),
-            blocks: DashMap::new(),
+            blocks: Arc::new(DashMap::new()),
             write_lock: Mutex::new(()),
+            detached: false,
+        }
+    }
+
+    /// Create storage which starts where the `view` of `self` is, without copying
+    /// the transactions of earlier blocks: the map of them is shared with `self`.
+    ///
+    /// Blocks can be applied to the detached storage, but never committed.
+    pub(crate) fn detached(&self, view: &TransactionsView) -> Self {
+        Self {
+            latest_block: ArcSwapOption::from(view.latest_block.clone()),
+            blocks: Arc::clone(&self.blocks),
+            write_lock: Mutex::new(()),
+            detached: true,
         }
     }
 
@@ -76,6 +94,7 @@ impl TransactionsStorage {
             latest_block_ref: &self.latest_block,
             blocks_ref: &self.blocks,
             _guard: guard,
+            detached: self.detached,
             revert,
             current_block: None,
         }
@@ -141,6 +160,7 @@ mod block {
         pub(super) _guard: MutexGuard<'storage, RawMutex, ()>,
 
         /// Own fields
+        pub(super) detached: bool,
         pub(super) revert: bool,
         pub(super) current_block: Option<Arc<BlockInfo>>,
     }
@@ -167,6 +187,10 @@ mod block {
 
         /// Apply aggregated changes to the storage
         pub fn commit(self) {
+            assert!(
+                !self.detached,
+                "Blocks applied to a detached storage must not be committed"
+            );
             let previous_block = &self.latest_block_ref.load();
             let previous_block = previous_block.as_ref();
 
@@ -221,14 +245,20 @@ mod block {
                 }
             }
 
+            let latest_block = self.latest_block_ref.load();
             #[allow(clippy::explicit_deref_methods)]
-            if let Some(latest_block) = self.latest_block_ref.load().deref() {
-                if latest_block.transactions.contains(key) {
-                    return Some(latest_block.height);
-                }
+            let Some(latest_block) = latest_block.deref() else {
+                return None;
+            };
+            if latest_block.transactions.contains(key) {
+                return Some(latest_block.height);
             }
 
-            self.blocks_ref.get(key).map(|h| *h)
+            // Blocks committed to the storage a detached one is made of are in the map too
+            self.blocks_ref
+                .get(key)
+                .map(|h| *h)
+                .filter(|&h| h < latest_block.height)
         }
     }
 }
@@ -280,8 +310,9 @@ mod serialization {
 
             Ok(TransactionsStorage {
                 latest_block: ArcSwapOption::from(storage.latest_block),
-                blocks: storage.blocks,
+                blocks: Arc::new(storage.blocks),
                 write_lock: Mutex::new(()),
+                detached: false,
             })
         }
     }
@@ -399,6 +430,33 @@ mod tests {
         assert_eq!(view2.get(&k0), Some(v1));
     }
 
+    #[test]
+    fn detached() {
+        let [k0, k1, k2, k3] = get_keys();
+        let [v1, v2, v3, v4] = get_values();
+
+        let storage = TransactionsStorage::new();
+        for (key, value) in [(k0, v1), (k1, v2)] {
+            let mut block = storage.block();
+            insert_keys(&mut block, &[key], value);
+            block.commit()
+        }
+        let detached = storage.detached(&storage.view());
+        for (key, value) in [(k2, v3), (k3, v4)] {
+            let mut block = storage.block();
+            insert_keys(&mut block, &[key], value);
+            block.commit()
+        }
+
+        // Blocks committed to the original storage after detaching aren't visible
+        let view = detached.view();
+        let block = detached.block();
+        for (key, value) in [(k0, Some(v1)), (k1, Some(v2)), (k2, None), (k3, None)] {
+            assert_eq!(view.get(&key), value);
+            assert_eq!(block.get(&key), value);
+        }
+    }
+
     #[test]
     fn serialization() {
         fn assert_views_equal(view1: &TransactionsView, view2: &TransactionsView, keys: &[Key]) {
@@ -1,5 +1,9 @@
 //! The main event loop that powers sumeragi.
-use std::{collections::BTreeSet, ops::Deref, sync::mpsc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+    sync::mpsc,
+};
 
 use iroha_crypto::{HashOf, KeyPair};
 use iroha_data_model::{block::*, events::pipeline::PipelineEventBox, peer::PeerId};
@@ -35,8 +39,8 @@ pub struct Sumeragi {
     pub peers_gossiper: PeersGossiperHandle,
     /// Receiver channel, for control flow messages.
     pub control_message_receiver: mpsc::Receiver<ControlFlowMessage>,
-    /// Receiver channel.
-    pub message_receiver: mpsc::Receiver<BlockMessage>,
+    /// Receiver channel, along with the peer each message was received from.
+    pub message_receiver: mpsc::Receiver<(BlockMessage, Option<PeerId>)>,
     /// Only used in testing. Causes the genesis peer to withhold blocks when it
     /// is the proxy tail.
     pub debug_force_soft_fork: bool,
@@ -47,6 +51,9 @@ pub struct Sumeragi {
     /// sumeragi is more dependent on the code that is internal to the
     /// subsystem.
     pub transaction_cache: Vec<TransactionGuard>,
+    /// Block received from the leader as [`BlockCreatedCompact`] which
+    /// is waiting for the transactions missing from the queue.
+    pub pending_compact_block: Option<PendingCompactBlock>,
     /// Telemetry handle to report view changes and block commits
     #[cfg(feature = "telemetry")]
     pub telemetry: Telemetry,
@@ -59,6 +66,36 @@ pub struct Sumeragi {
     pub round_start_time: Instant,
 }
 
+/// Block being reconstructed from [`BlockCreatedCompact`]
+#[derive(Debug)]
+pub struct PendingCompactBlock {
+    block: BlockCreatedCompact,
+    /// Block transactions in the order of the block, `None` for the ones not found yet
+    transactions: Vec<Option<SignedTransaction>>,
+}
+
+impl PendingCompactBlock {
+    fn missing_transactions(&self) -> Vec<HashOf<SignedTransaction>> {
+        self.block
+            .transactions
+            .iter()
+            .zip(&self.transactions)
+            .filter(|(_, tx)| tx.is_none())
+            .map(|(hash, _)| *hash)
+            .collect()
+    }
+
+    /// Reconstruct the block if all of its transactions are found
+    fn try_into_block(self) -> Result<SignedBlock, Self> {
+        if self.transactions.iter().any(Option::is_none) {
+            return Err(self);
+        }
+
+        let transactions = self.transactions.into_iter().flatten().collect();
+        Ok(self.block.into_block(transactions))
+    }
+}
+
 #[allow(clippy::missing_fields_in_debug)]
 impl Debug for Sumeragi {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -132,7 +169,7 @@ impl Sumeragi {
         &self,
         latest_block: HashOf<BlockHeader>,
         view_change_proof_chain: &mut ProofChain,
-    ) -> Result<(Option<BlockMessage>, bool), ReceiveNetworkPacketError> {
+    ) -> Result<(Option<(BlockMessage, Option<PeerId>)>, bool), ReceiveNetworkPacketError> {
         const MAX_CONTROL_MSG_IN_A_ROW: usize = 25;
 
         let mut should_sleep = true;
@@ -169,12 +206,12 @@ impl Sumeragi {
         &self,
         latest_block: HashOf<BlockHeader>,
         view_change_proof_chain: &ProofChain,
-    ) -> Result<Option<BlockMessage>, ReceiveNetworkPacketError> {
+    ) -> Result<Option<(BlockMessage, Option<PeerId>)>, ReceiveNetworkPacketError> {
         let current_view_change_index =
             view_change_proof_chain.verify_with_state(&self.topology, latest_block);
 
         loop {
-            let block_msg = match self.message_receiver.try_recv() {
+            let (block_msg, sender) = match self.message_receiver.try_recv() {
                 Ok(msg) => msg,
                 Err(mpsc::TryRecvError::Disconnected) => {
                     return Err(ReceiveNetworkPacketError::ChannelDisconnected)
@@ -197,13 +234,27 @@ impl Sumeragi {
                         continue;
                     }
                 }
+                BlockMessage::BlockCreatedCompact(compact) => {
+                    if (compact.header.view_change_index as usize) < current_view_change_index {
+                        trace!(
+                            ty="BlockCreatedCompact",
+                            block=%compact.hash(),
+                            "Discarding message due to outdated view change index",
+                        );
+                        // ignore block_message
+                        continue;
+                    }
+                }
                 // Signed and Committed contain no block.
                 // Block sync updates are exempt from early pruning.
+                // Transactions of a compact block are matched against the pending block.
                 BlockMessage::BlockSigned(_)
                 | BlockMessage::BlockCommitted(_)
-                | BlockMessage::BlockSyncUpdate(_) => {}
+                | BlockMessage::BlockSyncUpdate(_)
+                | BlockMessage::BlockTransactionsRequest(_)
+                | BlockMessage::BlockTransactions(_) => {}
             }
-            return Ok(Some(block_msg));
+            return Ok(Some((block_msg, sender)));
         }
     }
 
@@ -227,7 +278,7 @@ impl Sumeragi {
             }
 
             match self.message_receiver.try_recv() {
-                Ok(message) => {
+                Ok((message, _)) => {
                     let block = match message {
                         BlockMessage::BlockCreated(BlockCreated { block })
                         | BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block }) => block,
@@ -452,6 +503,7 @@ impl Sumeragi {
     fn handle_message<'state>(
         &mut self,
         message: BlockMessage,
+        sender: Option<PeerId>,
         state: &'state State,
         voting_block: &mut Option<VotingBlock<'state>>,
         view_change_index: usize,
@@ -553,6 +605,151 @@ impl Sumeragi {
                     }
                 }
             }
+            (
+                BlockMessage::BlockCreatedCompact(block),
+                Role::ValidatingPeer | Role::ObservingPeer | Role::ProxyTail,
+            ) => {
+                info!(
+                    peer_id=%self.peer,
+                    role=%self.role(),
+                    block=%block.hash(),
+                    txns=%block.transactions.len(),
+                    "Compact block received"
+                );
+
+                let transactions = block
+                    .transactions
+                    .iter()
+                    .map(|hash| self.queue.get(hash).map(Into::into))
+                    .collect();
+                let pending = PendingCompactBlock {
+                    block,
+                    transactions,
+                };
+
+                self.pending_compact_block = None;
+                match pending.try_into_block() {
+                    Ok(block) => self.handle_message(
+                        BlockCreated { block }.into(),
+                        None,
+                        state,
+                        voting_block,
+                        view_change_index,
+                        genesis_account,
+                        voting_signatures,
+                        is_genesis_peer,
+                    ),
+                    Err(pending) => {
+                        let missing = pending.missing_transactions();
+                        debug!(
+                            peer_id=%self.peer,
+                            role=%self.role(),
+                            block=%pending.block.hash(),
+                            missing=%missing.len(),
+                            "Requesting missing transactions from leader"
+                        );
+
+                        let msg = BlockTransactionsRequest {
+                            hash: pending.block.hash(),
+                            transactions: missing,
+                        };
+                        self.post_packet_to(msg.into(), self.topology.leader());
+                        self.pending_compact_block = Some(pending);
+                    }
+                }
+            }
+            (
+                BlockMessage::BlockTransactionsRequest(BlockTransactionsRequest {
+                    hash,
+                    transactions,
+                }),
+                Role::Leader,
+            ) => {
+                // Reply only to the peer the request came from, never to a peer named by it
+                let Some(peer) = sender else {
+                    trace!(block=%hash, "Transactions requested without a sending peer");
+                    return;
+                };
+                let Some(voted_block) = voting_block
+                    .as_ref()
+                    .filter(|voted_block| voted_block.block.as_ref().hash() == hash)
+                else {
+                    debug!(
+                        peer_id=%self.peer,
+                        role=%self.role(),
+                        block=%hash,
+                        requested_by=%peer,
+                        "Transactions requested for unknown block"
+                    );
+                    return;
+                };
+
+                let requested = transactions.into_iter().collect::<BTreeSet<_>>();
+                let transactions = voted_block
+                    .block
+                    .as_ref()
+                    .external_transactions()
+                    .filter(|tx| requested.contains(&tx.hash()))
+                    .cloned()
+                    .collect();
+
+                let msg = BlockTransactions { hash, transactions };
+                self.post_packet_to(msg.into(), &peer);
+            }
+            (
+                BlockMessage::BlockTransactions(BlockTransactions { hash, transactions }),
+                Role::ValidatingPeer | Role::ObservingPeer | Role::ProxyTail,
+            ) => {
+                let Some(mut pending) = self
+                    .pending_compact_block
+                    .take_if(|pending| pending.block.hash() == hash)
+                else {
+                    trace!(
+                        peer_id=%self.peer,
+                        role=%self.role(),
+                        block=%hash,
+                        "Received transactions for unknown compact block"
+                    );
+                    return;
+                };
+
+                let mut received = transactions
+                    .into_iter()
+                    .map(|tx| (tx.hash(), tx))
+                    .collect::<BTreeMap<_, _>>();
+                for (hash, tx) in pending
+                    .block
+                    .transactions
+                    .iter()
+                    .zip(&mut pending.transactions)
+                {
+                    if tx.is_none() {
+                        *tx = received.remove(hash);
+                    }
+                }
+
+                match pending.try_into_block() {
+                    Ok(block) => self.handle_message(
+                        BlockCreated { block }.into(),
+                        None,
+                        state,
+                        voting_block,
+                        view_change_index,
+                        genesis_account,
+                        voting_signatures,
+                        is_genesis_peer,
+                    ),
+                    Err(pending) => {
+                        warn!(
+                            peer_id=%self.peer,
+                            role=%self.role(),
+                            block=%hash,
+                            missing=%pending.missing_transactions().len(),
+                            "Leader didn't provide all of the requested transactions"
+                        );
+                    }
+                }
+            }
             (BlockMessage::BlockCreated(BlockCreated { block }), Role::ValidatingPeer) => {
                 info!(
                     peer_id=%self.peer,
@@ -869,6 +1066,71 @@ impl Sumeragi {
         Some(voting_block)
     }
 
+    /// Broadcast the created block before executing it, so that peers validate it in parallel.
+    fn create_block<'state>(
+        &self,
+        unverified_block: WithEvents<NewBlock>,
+        state: &'state State,
+    ) -> (ValidBlock, StateBlock<'state>) {
+        let unverified_block = unverified_block.unpack(|e| self.send_event(e));
+        info!(
+            peer_id=%self.peer,
+            block_hash=%unverified_block.header().hash(),
+            txns=%unverified_block.transactions().len(),
+            view_change_index=%self.topology.view_change_index(),
+            "Block created"
+        );
+
+        if self.topology.is_consensus_required().is_some() {
+            // NOTE: Peers are expected to have most of the transactions in their queue,
+            // so only transaction hashes are sent and missing ones are requested separately
+            let msg = BlockCreatedCompact::from(&unverified_block);
+            self.broadcast_packet(msg);
+        }
+
+        let mut state_block = state.block(unverified_block.header());
+        let block = unverified_block
+            .validate_and_record_transactions(&mut state_block)
+            .unpack(|e| self.send_event(e));
+
+        (block, state_block)
+    }
+
+    /// Execute the created block before broadcasting it, leaving the rejected transactions out.
+    ///
+    /// The left out transactions stay in the queue until the block recording their hashes
+    /// is committed, so they are neither proposed nor gossiped again.
+    fn create_block_excluding_rejected<'state>(
+        &self,
+        unverified_block: WithEvents<NewBlock>,
+        state: &'state State,
+    ) -> (ValidBlock, StateBlock<'state>) {
+        // NOTE: Creation is reported once the header is final
+        let unverified_block = unverified_block.unpack(|_| {});
+        let mut state_block = state.block(unverified_block.header());
+        let block = unverified_block
+            .validate_and_exclude_rejected_transactions(
+                &mut state_block,
+                self.key_pair.private_key(),
+            )
+            .unpack_created(|e| self.send_event(e));
+        info!(
+            peer_id=%self.peer,
+            block_hash=%block.as_ref().hash(),
+            txns=%block.as_ref().external_transactions().len(),
+            excluded=%block.as_ref().excluded_transactions().len(),
+            view_change_index=%self.topology.view_change_index(),
+            "Block created"
+        );
+
+        if self.topology.is_consensus_required().is_some() {
+            let msg = BlockCreatedCompact::from(block.as_ref());
+            self.broadcast_packet(msg);
+        }
+
+        (block, state_block)
+    }
+
     #[allow(clippy::too_many_lines)]
     fn try_create_block<'state>(
         &mut self,
@@ -877,11 +1139,8 @@ impl Sumeragi {
     ) {
         assert_eq!(self.role(), Role::Leader);
 
-        let max_transactions: NonZeroUsize = state
-            .world
-            .view()
-            .parameters
-            .block
+        let block_parameters = state.world.view().parameters.block;
+        let max_transactions: NonZeroUsize = block_parameters
             .max_transactions
             .try_into()
             .expect("INTERNAL BUG: transactions in block exceed usize::MAX");
@@ -906,29 +1165,14 @@ impl Sumeragi {
                 .collect::<Vec<_>>();
 
             let unverified_block = BlockBuilder::new(transactions)
-                .chain(
-                    self.topology.view_change_index(),
-                    state.view().latest_block().as_deref(),
-                )
-                .sign(self.key_pair.private_key())
-                .unpack(|e| self.send_event(e));
-            info!(
-                peer_id=%self.peer,
-                block_hash=%unverified_block.header().hash(),
-                txns=%unverified_block.transactions().len(),
-                view_change_index=%self.topology.view_change_index(),
-                "Block created"
-            );
+                .chain(self.topology.view_change_index(), &state.view())
+                .sign(self.key_pair.private_key());
 
-            if self.topology.is_consensus_required().is_some() {
-                let msg = BlockCreated::from(&unverified_block);
-                self.broadcast_packet(msg);
-            }
-
-            let mut state_block = state.block(unverified_block.header());
-            let block = unverified_block
-                .validate_and_record_transactions(&mut state_block)
-                .unpack(|e| self.send_event(e));
+            let (block, state_block) = if block_parameters.exclude_rejected_transactions {
+                self.create_block_excluding_rejected(unverified_block, state)
+            } else {
+                self.create_block(unverified_block, state)
+            };
 
             *voting_block = if self.topology.is_consensus_required().is_some() {
                 Some(VotingBlock::new(block, state_block))
@@ -1122,11 +1366,14 @@ pub(crate) fn run(
             &mut view_change_time,
         );
         #[cfg(feature = "telemetry")]
-        sumeragi
-            .telemetry
-            .set_view_changes(sumeragi.topology.view_change_index() as u64);
+        {
+            sumeragi
+                .telemetry
+                .set_view_changes(sumeragi.topology.view_change_index() as u64);
+            sumeragi.telemetry.set_leader(sumeragi.topology.leader());
+        }
 
-        if let Some(message) = {
+        if let Some((message, sender)) = {
             let (msg, sleep) = match sumeragi.receive_network_packet(
                 state_view
                     .latest_block_hash()
@@ -1146,6 +1393,7 @@ pub(crate) fn run(
         } {
             sumeragi.handle_message(
                 message,
+                sender,
                 &state,
                 &mut voting_block,
                 view_change_index,
@@ -1257,9 +1505,12 @@ pub(crate) fn run(
             &mut view_change_time,
         );
         #[cfg(feature = "telemetry")]
-        sumeragi
-            .telemetry
-            .set_view_changes(sumeragi.topology.view_change_index() as u64);
+        {
+            sumeragi
+                .telemetry
+                .set_view_changes(sumeragi.topology.view_change_index() as u64);
+            sumeragi.telemetry.set_leader(sumeragi.topology.leader());
+        }
 
         if sumeragi.role() == Role::Leader && voting_block.is_none() {
             sumeragi.try_create_block(&state, &mut voting_block);
@@ -1525,7 +1776,7 @@ mod tests {
 
         // Creating a block of two identical transactions and validating it
         let unverified_genesis = BlockBuilder::new(vec![peers, tx.clone(), tx])
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(0, &state.view())
             .sign(leader_private_key)
             .unpack(|_| {});
 
@@ -1563,7 +1814,7 @@ mod tests {
 
             // Creating a block of two identical transactions and validating it
             BlockBuilder::new(vec![tx1, tx2])
-                .chain(0, state.view().latest_block().as_deref())
+                .chain(0, &state.view())
                 .sign(leader_private_key)
                 .unpack(|_| {})
         };
@@ -1591,6 +1842,32 @@ mod tests {
         assert!(matches!(result, Err((_, BlockSyncError::BlockNotValid(_)))))
     }
 
+    #[test]
+    async fn block_sync_invalid_prev_blocks_root() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+
+        let (leader_public_key, leader_private_key) = KeyPair::random().into_parts();
+        let peer_id = PeerId::new(leader_public_key);
+        let topology = Topology::new(vec![peer_id]);
+        let (state, _, block, genesis_public_key) =
+            create_data_for_test(&chain_id, &topology, &leader_private_key);
+
+        // Root of the previous blocks is covered by the signatures, but isn't the actual one
+        let block = clone_and_modify_header(&block, &leader_private_key, |header| {
+            header.prev_blocks_root = Some(HashOf::from_untyped_unchecked(Hash::new([1; 32])));
+        })
+        .into();
+
+        let result = handle_block_sync(&chain_id, block, &state, &genesis_public_key, &|_| {});
+        assert!(matches!(
+            result,
+            Err((
+                _,
+                BlockSyncError::BlockNotValid(BlockValidationError::PrevBlocksRootMismatch)
+            ))
+        ))
+    }
+
     #[test]
     async fn block_sync_invalid_soft_fork_block() {
         let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
@@ -1856,4 +2133,38 @@ mod tests {
         assert!(matches!(result, Err((_, BlockSyncError::BlockNotValid(_)))));
         assert!(voting_block.is_some());
     }
+
+    #[test]
+    async fn compact_block_reconstruction() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+
+        let (leader_public_key, leader_private_key) = KeyPair::random().into_parts();
+        let peer_id = PeerId::new(leader_public_key);
+        let topology = Topology::new(vec![peer_id]);
+        let (_, _, block, _) = create_data_for_test(&chain_id, &topology, &leader_private_key);
+
+        let compact = BlockCreatedCompact::from(&block);
+        let block = SignedBlock::from(block);
+        let mut transactions = block
+            .external_transactions()
+            .cloned()
+            .map(Some)
+            .collect::<Vec<_>>();
+        let missing = transactions[1].take().unwrap();
+
+        let mut pending = PendingCompactBlock {
+            block: compact,
+            transactions,
+        }
+        .try_into_block()
+        .expect_err("Block must not be reconstructed with missing transactions");
+        assert_eq!(pending.missing_transactions(), vec![missing.hash()]);
+
+        pending.transactions[1] = Some(missing);
+        let reconstructed = pending
+            .try_into_block()
+            .expect("All transactions are found");
+        assert_eq!(reconstructed.hash(), block.hash());
+        assert_eq!(reconstructed, block);
+    }
 }
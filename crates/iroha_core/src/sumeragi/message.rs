@@ -1,6 +1,9 @@
 //! Contains message structures for p2p communication during consensus.
 use iroha_crypto::HashOf;
-use iroha_data_model::block::{BlockHeader, BlockSignature, SignedBlock};
+use iroha_data_model::{
+    block::{BlockHeader, BlockSignature, SignedBlock, TransactionRejection},
+    transaction::SignedTransaction,
+};
 use iroha_macro::*;
 use parity_scale_codec::{Decode, Encode};
 
@@ -19,6 +22,13 @@ pub enum BlockMessage {
     BlockCommitted(BlockCommitted),
     /// This message is sent by `BlockSync` when new block is received
     BlockSyncUpdate(BlockSyncUpdate),
+    /// This message is sent by leader to all validating peers instead of [`BlockCreated`],
+    /// carrying only the hashes of the block transactions.
+    BlockCreatedCompact(BlockCreatedCompact),
+    /// This message is sent by peers to leader to request transactions missing from their queue.
+    BlockTransactionsRequest(BlockTransactionsRequest),
+    /// This message is sent by leader in response to [`BlockTransactionsRequest`].
+    BlockTransactions(BlockTransactions),
 }
 
 /// Specialization of `MessagePacket`
@@ -61,6 +71,92 @@ impl From<&SignedBlock> for BlockCreated {
     }
 }
 
+/// `BlockCreatedCompact` message structure.
+///
+/// Peers are expected to already have most of the block transactions in their queue,
+/// so the block is reconstructed on the receiving side from the transaction hashes.
+#[derive(Debug, Clone, Decode, Encode)]
+pub struct BlockCreatedCompact {
+    /// Header of the block.
+    pub header: BlockHeader,
+    /// Signature of the leader.
+    pub signature: BlockSignature,
+    /// Hashes of the block transactions in the order of the block.
+    pub transactions: Vec<HashOf<SignedTransaction>>,
+    /// Transactions left out of the block.
+    pub excluded_transactions: Vec<TransactionRejection>,
+}
+
+impl BlockCreatedCompact {
+    /// Hash of the block.
+    pub fn hash(&self) -> HashOf<BlockHeader> {
+        self.header.hash()
+    }
+
+    /// Reconstruct the block from its transactions.
+    ///
+    /// Transactions are expected to be in the same order as [`Self::transactions`].
+    pub fn into_block(self, transactions: Vec<SignedTransaction>) -> SignedBlock {
+        SignedBlock::presigned(
+            self.signature,
+            self.header,
+            transactions,
+            self.excluded_transactions,
+        )
+    }
+}
+
+impl From<&NewBlock> for BlockCreatedCompact {
+    fn from(block: &NewBlock) -> Self {
+        Self {
+            header: block.header(),
+            signature: block.signature().clone(),
+            transactions: block
+                .transactions()
+                .iter()
+                .map(|tx| tx.as_ref().hash())
+                .collect(),
+            excluded_transactions: Vec::new(),
+        }
+    }
+}
+
+impl From<&SignedBlock> for BlockCreatedCompact {
+    fn from(block: &SignedBlock) -> Self {
+        Self {
+            header: block.header(),
+            signature: block
+                .signatures()
+                .next()
+                .cloned()
+                .expect("INTERNAL BUG: Created block must be signed by the leader"),
+            transactions: block
+                .external_transactions()
+                .map(SignedTransaction::hash)
+                .collect(),
+            excluded_transactions: block.excluded_transactions().cloned().collect(),
+        }
+    }
+}
+
+/// `BlockTransactionsRequest` message structure.
+#[derive(Debug, Clone, Decode, Encode)]
+pub struct BlockTransactionsRequest {
+    /// Hash of the block being reconstructed.
+    pub hash: HashOf<BlockHeader>,
+    /// Hashes of the missing transactions.
+    pub transactions: Vec<HashOf<SignedTransaction>>,
+}
+
+/// `BlockTransactions` message structure.
+#[derive(Debug, Clone, Decode, Encode)]
+pub struct BlockTransactions {
+    /// Hash of the block being reconstructed.
+    pub hash: HashOf<BlockHeader>,
+    /// Requested transactions.
+    pub transactions: Vec<SignedTransaction>,
+}
+
 /// `BlockSigned` message structure.
 #[derive(Debug, Clone, Decode, Encode)]
 pub struct BlockSigned {
@@ -43,7 +43,7 @@ pub struct SumeragiHandle {
     telemetry: Telemetry,
     // Should be dropped after `_thread_handle` to prevent sumeragi thread from panicking
     control_message_sender: mpsc::SyncSender<ControlFlowMessage>,
-    message_sender: mpsc::SyncSender<BlockMessage>,
+    message_sender: mpsc::SyncSender<(BlockMessage, Option<PeerId>)>,
 }
 
 impl SumeragiHandle {
@@ -64,7 +64,10 @@ impl SumeragiHandle {
     }
 
     /// Deposit a sumeragi network message.
-    pub fn incoming_block_message(&self, msg: impl Into<BlockMessage>) {
+    ///
+    /// `sender` is the peer of the connection the message was received on,
+    /// or `None` if it was relayed by another subsystem.
+    pub fn incoming_block_message(&self, msg: impl Into<BlockMessage>, sender: Option<PeerId>) {
         let msg = msg.into();
         let (ty, block) = match &msg {
             BlockMessage::BlockCommitted(BlockCommitted { hash, .. }) => ("BlockCommitted", *hash),
@@ -73,10 +76,17 @@ impl SumeragiHandle {
             BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block }) => {
                 ("BlockSyncUpdate", block.hash())
             }
+            BlockMessage::BlockCreatedCompact(compact) => ("BlockCreatedCompact", compact.hash()),
+            BlockMessage::BlockTransactionsRequest(BlockTransactionsRequest { hash, .. }) => {
+                ("BlockTransactionsRequest", *hash)
+            }
+            BlockMessage::BlockTransactions(BlockTransactions { hash, .. }) => {
+                ("BlockTransactions", *hash)
+            }
         };
         trace!(ty, %block, "Incoming message");
 
-        if let Err(error) = self.message_sender.try_send(msg) {
+        if let Err(error) = self.message_sender.try_send((msg, sender)) {
             #[cfg(feature = "telemetry")]
             self.telemetry.inc_dropped_messages();
 
@@ -132,6 +142,67 @@ impl SumeragiHandle {
     }
 }
 
+/// Replay blocks stored in [`Kura`] on top of `state` up to `height` (inclusive).
+///
+/// Blocks are validated and executed again, so the state can be restored at any
+/// height not lower than the current one. `trusted_peers` are used as the
+/// initial topology if `state` is empty.
+///
+/// Returns the topology after the latest replayed block.
+///
+/// # Panics
+/// If a block within `height` can't be loaded from [`Kura`] or is invalid.
+pub fn replay_blocks(
+    state: &State,
+    kura: &Kura,
+    height: usize,
+    chain_id: &ChainId,
+    genesis_public_key: &PublicKey,
+    trusted_peers: impl IntoIterator<Item = PeerId>,
+    events_sender: &EventsSender,
+) -> Topology {
+    let blocks_iter;
+    let mut topology;
+
+    {
+        let state_view = state.view();
+        let skip_block_count = state_view.height();
+        blocks_iter =
+            (skip_block_count + 1..=height).map(|block_height| {
+                NonZeroUsize::new(block_height).and_then(|height| kura.get_block(height)).expect(
+                "Sumeragi should be able to load the block that was reported as presented. \
+                If not, the block storage was probably disconnected.",
+            )
+            });
+
+        topology = match state_view.height() {
+            0 => Topology::new(trusted_peers),
+            _height => Topology::new(state_view.commit_topology.clone()),
+        };
+    }
+
+    let genesis_account = AccountId::new(
+        iroha_genesis::GENESIS_DOMAIN_ID.clone(),
+        genesis_public_key.clone(),
+    );
+
+    for block in blocks_iter {
+        let mut state_block = state.block(block.header());
+        SumeragiHandle::replay_block(
+            chain_id,
+            &genesis_account,
+            &block,
+            &mut state_block,
+            events_sender,
+            &mut topology,
+        );
+
+        state_block.commit();
+    }
+
+    topology
+}
+
 impl SumeragiStartArgs {
     /// Start [`main_loop::Sumeragi`] actor and return handle to it.
     ///
@@ -159,50 +230,20 @@ impl SumeragiStartArgs {
         let (control_message_sender, control_message_receiver) = mpsc::sync_channel(100);
         let (message_sender, message_receiver) = mpsc::sync_channel(100);
 
-        let blocks_iter;
-        let mut topology;
-
-        {
-            let state_view = state.view();
-            let skip_block_count = state_view.height();
-            blocks_iter = (skip_block_count + 1..=block_count).map(|block_height| {
-                NonZeroUsize::new(block_height).and_then(|height| kura.get_block(height)).expect(
-                    "Sumeragi should be able to load the block that was reported as presented. \
-                    If not, the block storage was probably disconnected.",
-                )
-            });
-
-            topology = match state_view.height() {
-                0 => Topology::new(
-                    common_config
-                        .trusted_peers
-                        .value()
-                        .clone()
-                        .into_non_empty_vec(),
-                ),
-                _height => Topology::new(state_view.commit_topology.clone()),
-            };
-        }
-
-        let genesis_account = AccountId::new(
-            iroha_genesis::GENESIS_DOMAIN_ID.clone(),
-            genesis_network.public_key.clone(),
+        let topology = replay_blocks(
+            &state,
+            &kura,
+            block_count,
+            &common_config.chain,
+            &genesis_network.public_key,
+            common_config
+                .trusted_peers
+                .value()
+                .clone()
+                .into_non_empty_vec(),
+            &events_sender,
         );
 
-        for block in blocks_iter {
-            let mut state_block = state.block(block.header());
-            SumeragiHandle::replay_block(
-                &common_config.chain,
-                &genesis_account,
-                &block,
-                &mut state_block,
-                &events_sender,
-                &mut topology,
-            );
-
-            state_block.commit();
-        }
-
         info!("Sumeragi has finished loading blocks and setting up the state");
 
         let peer = common_config.peer;
@@ -220,6 +261,7 @@ impl SumeragiStartArgs {
             debug_force_soft_fork,
             topology,
             transaction_cache: Vec::new(),
+            pending_compact_block: None,
             #[cfg(feature = "telemetry")]
             telemetry: metrics.clone(),
             was_commit: false,
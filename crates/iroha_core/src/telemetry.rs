@@ -4,9 +4,10 @@ use std::{num::NonZeroUsize, sync::Arc, time::Duration};
 
 #[cfg(debug_assertions)]
 use iroha_crypto::HashOf;
-use iroha_data_model::block::BlockHeader;
+use iroha_crypto::PublicKey;
+use iroha_data_model::{block::BlockHeader, events::pipeline::QueueDropReason, peer::PeerId};
 use iroha_futures::supervisor::{Child, OnShutdown};
-use iroha_p2p::OnlinePeers;
+use iroha_p2p::{OnlinePeers, PeersTrafficReceiver};
 use iroha_primitives::time::TimeSource;
 use iroha_telemetry::metrics::Metrics;
 use mv::storage::StorageReadOnly;
@@ -36,6 +37,14 @@ impl StateTelemetry {
     pub fn observe_tx_amount(&self, value: f64) {
         self.metrics.tx_amounts.observe(value);
     }
+
+    /// Commit an observation of the time it took to write a state snapshot
+    pub fn observe_snapshot_duration(&self, duration: Duration) {
+        #[allow(clippy::cast_precision_loss)]
+        self.metrics
+            .snapshot_duration_ms
+            .observe(duration.as_millis() as f64);
+    }
 }
 
 const CHANNEL_CAPACITY: usize = 1024;
@@ -49,6 +58,7 @@ enum Message {
 pub struct Telemetry {
     actor: mpsc::Sender<Message>,
     last_reported_block: Arc<RwLock<Option<BlockCommitReport>>>,
+    leader: Arc<std::sync::RwLock<Option<PublicKey>>>,
     metrics: Arc<Metrics>,
     time_source: TimeSource,
 }
@@ -64,6 +74,24 @@ impl Telemetry {
         self.metrics.view_changes.set(value);
     }
 
+    /// Remember the leader of the current round to report it in the status
+    pub fn set_leader(&self, leader: &PeerId) {
+        let is_same = self
+            .leader
+            .read()
+            .is_ok_and(|current| current.as_ref() == Some(leader.public_key()));
+        if !is_same {
+            if let Ok(mut current) = self.leader.write() {
+                *current = Some(leader.public_key().clone());
+            }
+        }
+    }
+
+    /// Leader of the current round, if known
+    pub fn leader(&self) -> Option<PublicKey> {
+        self.leader.read().ok().and_then(|leader| leader.clone())
+    }
+
     /// Report the event of block commit, measuring the block time.
     pub fn report_block_commit_blocking(&self, block_header: BlockHeader) {
         let report = BlockCommitReport::new(block_header, &self.time_source);
@@ -99,6 +127,7 @@ struct Actor {
     last_reported_block: Arc<RwLock<Option<BlockCommitReport>>>,
     last_sync_block: usize,
     online_peers: watch::Receiver<OnlinePeers>,
+    peers_traffic: PeersTrafficReceiver,
     metrics: Arc<Metrics>,
     state: Arc<State>,
     kura: Arc<Kura>,
@@ -123,7 +152,43 @@ impl Actor {
         self.metrics
             .connected_peers
             .set(self.online_peers.borrow().len() as u64);
+        self.metrics.peer_traffic_bytes.reset();
+        self.metrics.peer_traffic_messages.reset();
+        for (peer, traffic) in self.peers_traffic.get() {
+            let peer = peer.public_key().to_string();
+            for (direction, bytes, messages) in [
+                ("sent", traffic.bytes_sent, traffic.messages_sent),
+                (
+                    "received",
+                    traffic.bytes_received,
+                    traffic.messages_received,
+                ),
+            ] {
+                self.metrics
+                    .peer_traffic_bytes
+                    .with_label_values(&[&peer, direction])
+                    .set(bytes);
+                self.metrics
+                    .peer_traffic_messages
+                    .with_label_values(&[&peer, direction])
+                    .set(messages);
+            }
+        }
         self.metrics.queue_size.set(self.queue.tx_len() as u64);
+        for (reason, count) in self.queue.dropped_txs() {
+            let reason = match reason {
+                QueueDropReason::Expired => "expired",
+                QueueDropReason::QueueFull => "queue_full",
+                QueueDropReason::AuthorityQueueFull => "authority_queue_full",
+                QueueDropReason::Duplicate => "duplicate",
+                QueueDropReason::AlreadyInBlockchain => "already_in_blockchain",
+                QueueDropReason::Draining => "draining",
+            };
+            self.metrics
+                .queue_dropped_txs
+                .with_label_values(&[reason])
+                .set(count);
+        }
 
         let last_reported_block = {
             let lock = self.last_reported_block.read().await;
@@ -286,6 +351,7 @@ pub fn start(
     kura: Arc<Kura>,
     queue: Arc<Queue>,
     online_peers: watch::Receiver<OnlinePeers>,
+    peers_traffic: PeersTrafficReceiver,
     time_source: TimeSource,
 ) -> (Telemetry, Child) {
     let (actor, handle) = mpsc::channel(CHANNEL_CAPACITY);
@@ -294,6 +360,7 @@ pub fn start(
         Telemetry {
             actor,
             last_reported_block: last_reported_block.clone(),
+            leader: Arc::default(),
             metrics: metrics.clone(),
             time_source: time_source.clone(),
         },
@@ -308,6 +375,7 @@ pub fn start(
                     last_sync_block: 0,
                     last_reported_block,
                     online_peers,
+                    peers_traffic,
                     time_source,
                 }
                 .run(),
@@ -380,6 +448,7 @@ mod tests {
                     capacity: nonzero!(10usize),
                     capacity_per_user: nonzero!(10usize),
                     transaction_time_to_live: Duration::from_secs(100),
+                    replay_window: Duration::from_secs(100),
                 },
                 &time_source,
             ));
@@ -390,6 +459,7 @@ mod tests {
                 kura.clone(),
                 queue,
                 peers_rx,
+                PeersTrafficReceiver::default(),
                 time_source.clone(),
             );
 
@@ -429,7 +499,7 @@ mod tests {
             let tx =
                 AcceptedTransaction::accept(tx, &chain_id(), max_clock_drift, tx_limits).unwrap();
             let block = BlockBuilder::new_with_time_source(vec![tx], self.time_source.clone())
-                .chain(0, self.state.view().latest_block().as_deref())
+                .chain(0, &self.state.view())
                 .sign(&self.leader_private_key)
                 .unpack(|_| {});
 
@@ -557,7 +627,7 @@ mod tests {
     fn genesis_commit_time_is_zero() {
         let (time_handle, time_source) = TimeSource::new_mock(Duration::from_millis(1500));
         let header = BlockBuilder::new_with_time_source(vec![], time_source.clone())
-            .chain(1, None)
+            .chain_to(1, None, None)
             .sign(KeyPair::random().private_key())
             .unpack(|_| {})
             .header();
@@ -7,22 +7,32 @@
 //! This is also where the actual execution of instructions, as well
 //! as various forms of validation are performed.
 
-use std::time::{Duration, SystemTime};
+use std::{
+    collections::{HashSet, VecDeque},
+    num::NonZeroUsize,
+    time::{Duration, SystemTime},
+};
 
 use eyre::Result;
 pub use iroha_data_model::prelude::*;
 use iroha_data_model::{
     isi::error::Mismatch,
     query::error::FindError,
-    transaction::{error::TransactionLimitError, TransactionPayload},
+    transaction::{
+        error::{TransactionLimitError, TransactionRejectionReason},
+        TransactionPayload,
+    },
 };
 use iroha_logger::{debug, error};
 use iroha_macro::FromVariant;
 use mv::storage::StorageReadOnly;
+use nonzero_ext::nonzero;
+use parking_lot::Mutex;
+use rayon::prelude::*;
 
 use crate::{
     smartcontracts::{wasm, wasm::cache::WasmCache},
-    state::{StateBlock, StateTransaction},
+    state::{dry_run::Staging, StateBlock, StateReadOnly, StateTransaction, WorldReadOnly},
 };
 
 /// `AcceptedTransaction` — a transaction accepted by Iroha peer.
@@ -60,6 +70,10 @@ pub enum AcceptTransactionFail {
     ChainIdMismatch(Mismatch<ChainId>),
     /// Transaction creation time is in the future
     TransactionInTheFuture,
+    /// Transaction authority `{0}` is not registered
+    UnknownAuthority(AccountId),
+    /// Transaction would be rejected: {0}
+    Rejected(#[source] TransactionRejectionReason),
 }
 
 impl AcceptedTransaction {
@@ -118,11 +132,7 @@ impl AcceptedTransaction {
         max_clock_drift: Duration,
         limits: TransactionParameters,
     ) -> Result<(), AcceptTransactionFail> {
-        Self::validate_common(tx, expected_chain_id, max_clock_drift)?;
-
-        if *iroha_genesis::GENESIS_DOMAIN_ID == *tx.authority().domain() {
-            return Err(AcceptTransactionFail::UnexpectedGenesisAccountSignature);
-        }
+        Self::validate_authority(tx, expected_chain_id, max_clock_drift)?;
 
         if let Err(err) = tx.verify_signature() {
             return Err(AcceptTransactionFail::SignatureVerification(
@@ -133,6 +143,43 @@ impl AcceptedTransaction {
             ));
         }
 
+        Self::validate_limits(tx, limits)
+    }
+
+    /// Like [`Self::validate`], but assumes that the transaction signature is already verified,
+    /// e.g. with [`SignedTransaction::verify_signatures`] or found in [`SignatureCache`].
+    ///
+    /// # Errors
+    ///
+    /// See [`AcceptTransactionFail`]
+    pub fn validate_with_verified_signature(
+        tx: &SignedTransaction,
+        expected_chain_id: &ChainId,
+        max_clock_drift: Duration,
+        limits: TransactionParameters,
+    ) -> Result<(), AcceptTransactionFail> {
+        Self::validate_authority(tx, expected_chain_id, max_clock_drift)?;
+        Self::validate_limits(tx, limits)
+    }
+
+    fn validate_authority(
+        tx: &SignedTransaction,
+        expected_chain_id: &ChainId,
+        max_clock_drift: Duration,
+    ) -> Result<(), AcceptTransactionFail> {
+        Self::validate_common(tx, expected_chain_id, max_clock_drift)?;
+
+        if *iroha_genesis::GENESIS_DOMAIN_ID == *tx.authority().domain() {
+            return Err(AcceptTransactionFail::UnexpectedGenesisAccountSignature);
+        }
+
+        Ok(())
+    }
+
+    fn validate_limits(
+        tx: &SignedTransaction,
+        limits: TransactionParameters,
+    ) -> Result<(), AcceptTransactionFail> {
         match &tx.instructions() {
             Executable::Instructions(instructions) => {
                 if instructions.is_empty() {
@@ -223,6 +270,193 @@ impl AcceptedTransaction {
     pub fn new_unchecked(tx: SignedTransaction) -> Self {
         Self(tx)
     }
+
+    /// Reject transactions that would certainly fail during block validation
+    /// by validating them against the current state as the executor would in the next block.
+    ///
+    /// The changes are made to a copy of the state, see [`Staging::validate_transaction`].
+    ///
+    /// # Errors
+    ///
+    /// - [`AcceptTransactionFail::UnknownAuthority`] if the authority account is not registered
+    /// - [`AcceptTransactionFail::Rejected`] if the executor rejects the transaction
+    pub fn validate_ingress(&self, staging: &Staging) -> Result<(), AcceptTransactionFail> {
+        let authority = self.0.authority();
+        if staging
+            .state()
+            .view()
+            .world()
+            .accounts()
+            .get(authority)
+            .is_none()
+        {
+            return Err(AcceptTransactionFail::UnknownAuthority(authority.clone()));
+        }
+
+        staging
+            .validate_transaction(self.clone())
+            .map_err(AcceptTransactionFail::Rejected)
+    }
+
+    /// Check that time-to-live of the transaction is within the bounds set for
+    /// the domain of its authority by the chain [`Parameters`].
+    ///
+    /// Transactions without time-to-live are accepted, they are bounded by the queue configuration.
+    ///
+    /// # Errors
+    ///
+    /// - [`AcceptTransactionFail::TransactionLimit`] if time-to-live is out of the bounds
+    pub fn validate_time_to_live(
+        &self,
+        parameters: &Parameters,
+    ) -> Result<(), AcceptTransactionFail> {
+        let Some(time_to_live) = self.0.time_to_live() else {
+            return Ok(());
+        };
+
+        let bounds = parameters.transaction_ttl_bounds(self.0.authority().domain());
+        if !bounds.contains(time_to_live) {
+            return Err(AcceptTransactionFail::TransactionLimit(
+                TransactionLimitError {
+                    reason: format!(
+                        "Transaction time-to-live must be within {:?}..={:?}, but got {:?}",
+                        bounds.min_time_to_live(),
+                        bounds.max_time_to_live(),
+                        time_to_live
+                    ),
+                },
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Hash transactions across the rayon pool. Hashes are returned in the order of `txs`.
+pub fn hash_in_parallel(txs: &[SignedTransaction]) -> Vec<HashOf<SignedTransaction>> {
+    txs.par_iter().map(SignedTransaction::hash).collect()
+}
+
+/// Number of consecutive transactions whose signatures are verified in one batch
+const SIGNATURE_BATCH_SIZE: usize = 64;
+
+/// Verify signatures of transactions in batches across the rayon pool.
+///
+/// `txs` are split into runs of a fixed size and the signatures of each run are
+/// verified at once with [`Signature::verify_batch`], except for those made over canonical JSON,
+/// which are verified on their own. Runs of transactions which are all `is_verified`, e.g. found
+/// in [`SignatureCache`], are skipped. Runs don't depend on `is_verified`, so all peers decide on
+/// the signatures of a block the same way, whatever they have verified before.
+///
+/// If a run fails, its signatures are verified one by one with
+/// [`SignedTransaction::verify_signature`] to find the invalid one. The outcome doesn't depend
+/// on scheduling: if several signatures are invalid, the error of the earliest one is returned.
+///
+/// # Errors
+///
+/// Fails if any of the signatures is invalid
+pub fn verify_signatures_in_parallel(
+    txs: &[&SignedTransaction],
+    is_verified: impl Fn(usize) -> bool + Sync,
+) -> Result<(), iroha_crypto::Error> {
+    txs.par_chunks(SIGNATURE_BATCH_SIZE)
+        .enumerate()
+        .map(|(run, txs)| {
+            let start = run * SIGNATURE_BATCH_SIZE;
+            if (start..start + txs.len()).all(&is_verified) {
+                return Ok(());
+            }
+
+            verify_signature_batch(txs)
+                .or_else(|_| SignedTransaction::verify_signatures(txs.iter().copied()))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn verify_signature_batch(txs: &[&SignedTransaction]) -> Result<(), iroha_crypto::Error> {
+    let (scale, canonical_json): (Vec<_>, Vec<_>) = txs
+        .iter()
+        .copied()
+        .partition(|tx| tx.signed_form() == SignedPayloadForm::Scale);
+    let hashes = scale
+        .iter()
+        .map(|tx| HashOf::new(tx.payload()))
+        .collect::<Vec<_>>();
+
+    Signature::verify_batch(scale.iter().zip(&hashes).map(|(tx, hash)| {
+        (
+            &*tx.signature().0,
+            tx.authority().signatory(),
+            hash.as_ref().as_slice(),
+        )
+    }))?;
+    SignedTransaction::verify_signatures(canonical_json)
+}
+
+/// Hashes of transactions whose signatures have already been verified.
+///
+/// Transaction signatures are verified when the transaction is accepted into the queue
+/// and once again when the block containing it is validated. Since the transaction hash
+/// covers both the payload and the signature, the latter check can be skipped for
+/// transactions found in this cache.
+///
+/// The cache only saves work: whether a block is valid doesn't depend on the contents of
+/// the cache, see [`verify_signatures_in_parallel`].
+#[derive(Debug)]
+pub struct SignatureCache {
+    capacity: NonZeroUsize,
+    hashes: Mutex<SignatureCacheInner>,
+}
+
+#[derive(Debug, Default)]
+struct SignatureCacheInner {
+    /// Hashes in the order of insertion, used to evict the oldest ones
+    order: VecDeque<HashOf<SignedTransaction>>,
+    set: HashSet<HashOf<SignedTransaction>>,
+}
+
+impl SignatureCache {
+    /// Default number of hashes kept in the cache
+    pub const DEFAULT_CAPACITY: NonZeroUsize = nonzero!(65_536_usize);
+
+    /// Construct [`Self`] which keeps at most `capacity` of the most recently verified hashes.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            hashes: Mutex::default(),
+        }
+    }
+
+    /// Check if signature of the transaction with the given `hash` is already verified.
+    pub fn contains(&self, hash: &HashOf<SignedTransaction>) -> bool {
+        self.hashes.lock().set.contains(hash)
+    }
+
+    /// Remember that signatures of the transactions with the given hashes are verified.
+    pub fn extend(&self, hashes: impl IntoIterator<Item = HashOf<SignedTransaction>>) {
+        let mut inner = self.hashes.lock();
+
+        for hash in hashes {
+            if !inner.set.insert(hash) {
+                continue;
+            }
+            inner.order.push_back(hash);
+
+            if inner.order.len() > self.capacity.get() {
+                if let Some(oldest) = inner.order.pop_front() {
+                    inner.set.remove(&oldest);
+                }
+            }
+        }
+    }
+}
+
+impl Default for SignatureCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
 }
 
 impl From<AcceptedTransaction> for SignedTransaction {
@@ -256,6 +490,7 @@ impl StateBlock<'_> {
         let hash = tx.as_ref().hash_as_entrypoint();
         let result = Self::validate_transaction_internal(tx, &mut state_transaction, wasm_cache);
         if result.is_ok() {
+            state_transaction.index_asset_movements(hash);
             state_transaction.apply();
         }
 
@@ -279,14 +514,15 @@ impl StateBlock<'_> {
         }
 
         debug!(tx=%tx.as_ref().hash(), "Validating transaction");
-        Self::validate_transaction_with_runtime_executor(
-            tx.clone(),
-            state_transaction,
-            wasm_cache,
-        )?;
+        // The executor takes the transaction, so only the smart contract is kept rather than all of it
+        let wasm = match tx.as_ref().instructions() {
+            Executable::Wasm(wasm) => Some(wasm.clone()),
+            Executable::Instructions(_) => None,
+        };
+        Self::validate_transaction_with_runtime_executor(tx, state_transaction, wasm_cache)?;
 
-        if let (authority, Executable::Wasm(bytes)) = tx.into() {
-            Self::validate_wasm(authority, state_transaction, bytes)?
+        if let Some(wasm) = wasm {
+            Self::validate_wasm(authority.clone(), state_transaction, wasm)?
         }
 
         debug!("Transaction validated successfully; processing data triggers");
@@ -356,7 +592,7 @@ impl StateBlock<'_> {
 #[allow(missing_docs)]
 pub mod tests {
     use core::panic;
-    use std::sync::LazyLock;
+    use std::sync::{Arc, LazyLock};
 
     use iroha_data_model::{block::SignedBlock, isi::Instruction, prelude::EventBox};
     use iroha_genesis::GENESIS_DOMAIN_ID;
@@ -369,6 +605,146 @@ pub mod tests {
         state::{State, StateBlock, StateReadOnly, World},
     };
 
+    #[test]
+    fn signature_cache_evicts_oldest_hashes() {
+        let hash = |byte| HashOf::from_untyped_unchecked(iroha_crypto::Hash::prehashed([byte; 32]));
+        let cache = SignatureCache::new(nonzero!(2_usize));
+
+        cache.extend([hash(1), hash(2), hash(1)]);
+        assert!(cache.contains(&hash(1)));
+        assert!(cache.contains(&hash(2)));
+
+        cache.extend([hash(3)]);
+        assert!(!cache.contains(&hash(1)));
+        assert!(cache.contains(&hash(2)));
+        assert!(cache.contains(&hash(3)));
+    }
+
+    #[test]
+    fn parallel_hashing_and_verification() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let txs = (0..3)
+            .map(|_| {
+                let (account_id, key_pair) = gen_account_in("wonderland");
+                TransactionBuilder::new(chain_id.clone(), account_id)
+                    .with_instructions(Vec::<InstructionBox>::new())
+                    .sign(key_pair.private_key())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            hash_in_parallel(&txs),
+            txs.iter().map(SignedTransaction::hash).collect::<Vec<_>>()
+        );
+        assert!(verify_signatures_in_parallel(&txs.iter().collect::<Vec<_>>(), |_| false).is_ok());
+    }
+
+    #[test]
+    fn signatures_are_verified_in_runs() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let (account_id, key_pair) = gen_account_in("wonderland");
+        let (_, other_key_pair) = gen_account_in("wonderland");
+        let sign = |key_pair: &KeyPair| {
+            TransactionBuilder::new(chain_id.clone(), account_id.clone())
+                .with_instructions(Vec::<InstructionBox>::new())
+                .sign(key_pair.private_key())
+        };
+        let mut txs = (0..=SIGNATURE_BATCH_SIZE)
+            .map(|_| sign(&key_pair))
+            .collect::<Vec<_>>();
+        // Not signed by the authority
+        txs.push(sign(&other_key_pair));
+        let txs = txs.iter().collect::<Vec<_>>();
+
+        assert!(verify_signatures_in_parallel(&txs, |_| false).is_err());
+        assert!(verify_signatures_in_parallel(&txs, |i| i < SIGNATURE_BATCH_SIZE).is_err());
+        assert!(verify_signatures_in_parallel(&txs, |i| i != SIGNATURE_BATCH_SIZE).is_err());
+        // Runs of verified transactions are skipped
+        assert!(verify_signatures_in_parallel(&txs, |i| i >= SIGNATURE_BATCH_SIZE).is_ok());
+        assert!(verify_signatures_in_parallel(&txs[..SIGNATURE_BATCH_SIZE], |_| false).is_ok());
+    }
+
+    #[tokio::test]
+    async fn ingress_validation_rejects_failing_transactions() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (mallory_id, mallory_keypair) = gen_account_in("wonderland");
+        let domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let state = Arc::new(State::new(
+            World::with([domain], [account], []),
+            crate::kura::Kura::blank_kura_for_testing(),
+            crate::query::store::LiveQueryStore::start_test(),
+        ));
+        let staging = Staging::new(Arc::clone(&state));
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let accept = |authority: &AccountId,
+                      key_pair: &iroha_crypto::KeyPair,
+                      instructions: Vec<InstructionBox>| {
+            let tx = TransactionBuilder::new(chain_id.clone(), authority.clone())
+                .with_instructions(instructions)
+                .sign(key_pair.private_key());
+            AcceptedTransaction::new_unchecked(tx)
+        };
+
+        assert_eq!(
+            accept(&alice_id, &alice_keypair, Vec::new()).validate_ingress(&staging),
+            Ok(())
+        );
+        assert_eq!(
+            accept(&mallory_id, &mallory_keypair, Vec::new()).validate_ingress(&staging),
+            Err(AcceptTransactionFail::UnknownAuthority(mallory_id))
+        );
+        let unregister_unknown = Unregister::domain("looking_glass".parse().unwrap());
+        assert!(matches!(
+            accept(&alice_id, &alice_keypair, vec![unregister_unknown.into()])
+                .validate_ingress(&staging),
+            Err(AcceptTransactionFail::Rejected(_))
+        ));
+        // Nothing is committed by the check
+        assert_eq!(state.view().height(), 0);
+    }
+
+    #[test]
+    fn time_to_live_is_bounded_per_domain() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (carpenter_id, carpenter_keypair) = gen_account_in("garden_of_live_flowers");
+        let with_ttl = |authority: &AccountId, key_pair: &iroha_crypto::KeyPair, ttl_secs| {
+            let mut tx = TransactionBuilder::new(chain_id.clone(), authority.clone())
+                .with_instructions(Vec::<InstructionBox>::new());
+            tx.set_ttl(Duration::from_secs(ttl_secs));
+            AcceptedTransaction::new_unchecked(tx.sign(key_pair.private_key()))
+        };
+
+        let mut parameters = Parameters::default();
+        parameters.transaction = parameters.transaction.with_time_to_live_bounds(
+            TransactionTtlBounds::new(Duration::from_secs(10), Duration::from_secs(60)).unwrap(),
+        );
+        parameters.set_parameter(Parameter::DomainTransactionTtl(
+            iroha_data_model::parameter::DomainTransactionTtl {
+                domain: "garden_of_live_flowers".parse().unwrap(),
+                bounds: Some(
+                    TransactionTtlBounds::new(Duration::ZERO, Duration::from_secs(3600)).unwrap(),
+                ),
+            },
+        ));
+
+        assert!(with_ttl(&alice_id, &alice_keypair, 30)
+            .validate_time_to_live(&parameters)
+            .is_ok());
+        assert!(matches!(
+            with_ttl(&alice_id, &alice_keypair, 5).validate_time_to_live(&parameters),
+            Err(AcceptTransactionFail::TransactionLimit(_))
+        ));
+        assert!(matches!(
+            with_ttl(&alice_id, &alice_keypair, 600).validate_time_to_live(&parameters),
+            Err(AcceptTransactionFail::TransactionLimit(_))
+        ));
+        assert!(with_ttl(&carpenter_id, &carpenter_keypair, 600)
+            .validate_time_to_live(&parameters)
+            .is_ok());
+    }
+
     mod time_trigger {
         use super::*;
 
@@ -474,6 +850,37 @@ pub mod tests {
             ]);
         }
 
+        /// # Scenario
+        ///
+        /// 1. Transaction: Alice sends a donation to Bob.
+        /// 2. Data triggers: Bob forwards parts of the donation to Carol and to Eve; Eve's trigger has a higher priority, so it runs first despite its ID.
+        #[tokio::test]
+        async fn runs_higher_priority_first() {
+            let mut sandbox = Sandbox::default()
+                .with_data_trigger_transfer_prioritized("bob", 10, "carol", 0)
+                .with_data_trigger_transfer_prioritized("bob", 20, "eve", 1);
+            sandbox.request_transfer("alice", 50, "bob");
+            let mut block = sandbox.block();
+            let (events, _committed_block) = block.apply();
+            block.assert_balances([("alice", 10), ("bob", 30), ("carol", 20), ("eve", 30)]);
+
+            let added_to = |name: &str| {
+                let asset = asset(name);
+                events
+                    .iter()
+                    .position(|event| {
+                        matches!(
+                            event,
+                            EventBox::Data(DataEvent::Domain(DomainEvent::Account(
+                                AccountEvent::Asset(AssetEvent::Added(changed))
+                            ))) if changed.asset == asset
+                        )
+                    })
+                    .unwrap()
+            };
+            assert!(added_to("eve") < added_to("carol"));
+        }
+
         /// # Scenario
         ///
         /// 1. Transaction: Alice sends 50 units to Bob.
@@ -828,12 +1235,37 @@ pub mod tests {
 
         #[must_use]
         pub fn with_data_trigger_transfer(self, src: &str, quantity: u32, dest: &str) -> Self {
-            self.with_data_trigger_transfer_internal(src, quantity, dest, Repeats::Indefinitely, 0)
+            self.with_data_trigger_transfer_internal(
+                src,
+                quantity,
+                dest,
+                Repeats::Indefinitely,
+                0,
+                0,
+            )
         }
 
         #[must_use]
         pub fn with_data_trigger_transfer_once(self, src: &str, quantity: u32, dest: &str) -> Self {
-            self.with_data_trigger_transfer_internal(src, quantity, dest, Repeats::Exactly(1), 0)
+            self.with_data_trigger_transfer_internal(src, quantity, dest, Repeats::Exactly(1), 0, 0)
+        }
+
+        #[must_use]
+        pub fn with_data_trigger_transfer_prioritized(
+            self,
+            src: &str,
+            quantity: u32,
+            dest: &str,
+            priority: u8,
+        ) -> Self {
+            self.with_data_trigger_transfer_internal(
+                src,
+                quantity,
+                dest,
+                Repeats::Indefinitely,
+                0,
+                priority,
+            )
         }
 
         #[must_use]
@@ -850,6 +1282,7 @@ pub mod tests {
                 dest,
                 Repeats::Indefinitely,
                 label,
+                0,
             )
         }
 
@@ -860,6 +1293,7 @@ pub mod tests {
             dest: &str,
             repeats: Repeats,
             label: u32,
+            priority: u8,
         ) -> Self {
             let mut block = self.state.world.triggers.block();
             let mut transaction = block.transaction();
@@ -872,7 +1306,8 @@ pub mod tests {
                     AssetEventFilter::new()
                         .for_events(AssetEventSet::Added)
                         .for_asset(asset(src)),
-                ),
+                )
+                .with_priority(priority),
             )
             .try_into()
             .unwrap();
@@ -921,7 +1356,7 @@ pub mod tests {
                     signed.into_iter().map(AcceptedTransaction).collect()
                 };
                 BlockBuilder::new(transactions)
-                    .chain(0, self.state.view().latest_block().as_deref())
+                    .chain(0, &self.state.view())
                     .sign(&GENESIS_ACCOUNT.key)
                     .unpack(|_| {})
                     .into()
@@ -76,6 +76,32 @@ impl Hash {
 
         Hash::prehashed(hash)
     }
+
+    /// Hash the bytes read from `reader` until its end, without holding them all in memory.
+    ///
+    /// # Errors
+    /// If reading fails
+    #[cfg(feature = "std")]
+    pub fn from_reader(mut reader: impl std::io::Read) -> std::io::Result<Self> {
+        let mut hasher =
+            Blake2bVar::new(Self::LENGTH).expect("Failed to initialize variable size hash");
+        let mut buffer = [0; 8 * 1024];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => hasher.update(&buffer[..read]),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        let mut hash = [0; Self::LENGTH];
+        hasher
+            .finalize_variable(&mut hash)
+            .expect("Buffer has the length of the hash");
+
+        Ok(Hash::prehashed(hash))
+    }
 }
 
 impl From<Hash> for [u8; Hash::LENGTH] {
@@ -334,4 +360,11 @@ mod tests {
                 [..]
         );
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_from_reader_matches_hash_of_bytes() {
+        let bytes = vec![7_u8; 20_000];
+        assert_eq!(Hash::from_reader(&bytes[..]).unwrap(), Hash::new(&bytes));
+    }
 }
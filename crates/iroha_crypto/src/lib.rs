@@ -10,6 +10,7 @@ mod hash;
 #[cfg(not(feature = "ffi_import"))]
 pub mod kex;
 mod merkle;
+mod mmr;
 #[cfg(not(feature = "ffi_import"))]
 mod multihash;
 mod secrecy;
@@ -39,6 +40,7 @@ use iroha_macro::ffi_impl_opaque;
 use iroha_primitives::const_vec::{ConstVec, ToConstVec};
 use iroha_schema::{Declaration, IntoSchema, MetaMap, Metadata, NamedFieldsMeta, TypeId};
 pub use merkle::{MerkleProof, MerkleTree};
+pub use mmr::{MerkleMountainRange, MerkleMountainRangeProof};
 #[cfg(not(feature = "ffi_import"))]
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize, Serializer};
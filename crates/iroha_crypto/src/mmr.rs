@@ -0,0 +1,325 @@
+//! Merkle mountain range implementation for proving inclusion of any of the appended elements
+//! against the latest root, or against the root at any earlier size (a checkpoint).
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use iroha_schema::{IntoSchema, TypeId};
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::{Hash, HashOf};
+
+/// Append-only accumulator of elements of type `T` in the form of a Merkle mountain range:
+/// a list of perfect Merkle trees (mountains) of decreasing height.
+///
+/// Nodes are stored by level, so the accumulator of the first `n` leaves is
+/// the first `n >> level` nodes of every level.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId)]
+pub struct MerkleMountainRange<T> {
+    /// Nodes of every level, leaves first
+    levels: Vec<Vec<HashOf<T>>>,
+}
+
+crate::ffi::ffi_item! {
+    /// Proof of inclusion of a leaf into a [`MerkleMountainRange`] with a given number of leaves.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub struct MerkleMountainRangeProof<T> {
+        /// Zero-based index of the leaf among all leaves.
+        leaf_index: u64,
+        /// Number of leaves in the range the proof was made for.
+        leaf_count: u64,
+        /// Siblings of the nodes leading from the leaf to the peak of its mountain.
+        audit_path: Vec<HashOf<T>>,
+        /// Peaks of the other mountains, from the highest to the lowest.
+        peaks: Vec<HashOf<T>>,
+    }
+}
+
+impl<T> Default for MerkleMountainRange<T> {
+    fn default() -> Self {
+        Self { levels: Vec::new() }
+    }
+}
+
+impl<T> FromIterator<HashOf<T>> for MerkleMountainRange<T> {
+    fn from_iter<I: IntoIterator<Item = HashOf<T>>>(iter: I) -> Self {
+        let mut range = Self::default();
+        for hash in iter {
+            range.add(hash);
+        }
+        range
+    }
+}
+
+// NOTE: Leaf nodes in the order of insertion
+impl<T: IntoSchema> IntoSchema for MerkleMountainRange<T> {
+    fn type_name() -> String {
+        format!("MerkleMountainRange<{}>", T::type_name())
+    }
+    fn update_schema_map(map: &mut iroha_schema::MetaMap) {
+        if !map.contains_key::<Self>() {
+            map.insert::<Self>(iroha_schema::Metadata::Vec(iroha_schema::VecMeta {
+                ty: core::any::TypeId::of::<HashOf<T>>(),
+            }));
+
+            HashOf::<T>::update_schema_map(map);
+        }
+    }
+}
+
+impl<T> MerkleMountainRange<T> {
+    /// Returns the number of leaves appended so far.
+    pub fn leaf_count(&self) -> u64 {
+        self.levels.first().map_or(0, |leaves| leaves.len() as u64)
+    }
+
+    /// Returns the root over all leaves, or `None` if there are no leaves.
+    pub fn root(&self) -> Option<HashOf<Self>> {
+        self.root_at(self.leaf_count())
+    }
+
+    /// Returns the root over the first `leaf_count` leaves, or `None` if there are
+    /// fewer leaves or `leaf_count` is zero.
+    pub fn root_at(&self, leaf_count: u64) -> Option<HashOf<Self>> {
+        if leaf_count > self.leaf_count() {
+            return None;
+        }
+
+        Self::bag(self.peaks(leaf_count).map(|(_, peak)| *peak).collect())
+    }
+
+    /// Constructs a proof of inclusion of the leaf at `leaf_index` into the
+    /// range of the first `leaf_count` leaves.
+    pub fn get_proof(
+        &self,
+        leaf_index: u64,
+        leaf_count: u64,
+    ) -> Option<MerkleMountainRangeProof<T>> {
+        if leaf_index >= leaf_count || leaf_count > self.leaf_count() {
+            return None;
+        }
+
+        let mut audit_path = Vec::new();
+        let mut index = usize::try_from(leaf_index).ok()?;
+        for (level, nodes) in self.levels.iter().enumerate() {
+            let sibling = index ^ 1;
+            if sibling >= Self::level_len(leaf_count, level) {
+                break;
+            }
+            audit_path.push(nodes[sibling]);
+            index >>= 1;
+        }
+
+        let peak_level = audit_path.len();
+        let peaks = self
+            .peaks(leaf_count)
+            .filter(|(level, _)| *level != peak_level)
+            .map(|(_, peak)| *peak)
+            .collect();
+
+        Some(MerkleMountainRangeProof {
+            leaf_index,
+            leaf_count,
+            audit_path,
+            peaks,
+        })
+    }
+
+    /// Appends a leaf hash and updates the peaks it merges with.
+    pub fn add(&mut self, hash: HashOf<T>) {
+        let mut node = hash;
+        for level in 0.. {
+            if self.levels.len() == level {
+                self.levels.push(Vec::new());
+            }
+            let nodes = &mut self.levels[level];
+            nodes.push(node);
+
+            let len = nodes.len();
+            if len % 2 == 1 {
+                break;
+            }
+            node = Self::pair_hash(&nodes[len - 2], &nodes[len - 1]);
+        }
+    }
+
+    /// Removes the last leaf along with the nodes it was merged into.
+    pub fn pop(&mut self) -> Option<HashOf<T>> {
+        let leaf = self.levels.first_mut()?.pop()?;
+
+        let leaf_count = self.levels[0].len();
+        for (level, nodes) in self.levels.iter_mut().enumerate().skip(1) {
+            nodes.truncate(leaf_count >> level);
+        }
+        while self.levels.last().is_some_and(Vec::is_empty) {
+            self.levels.pop();
+        }
+
+        Some(leaf)
+    }
+
+    /// Number of nodes at `level` in the range of the first `leaf_count` leaves.
+    fn level_len(leaf_count: u64, level: usize) -> usize {
+        u32::try_from(level)
+            .ok()
+            .and_then(|level| leaf_count.checked_shr(level))
+            .map_or(0, |len| {
+                usize::try_from(len).expect("leaves are stored in memory")
+            })
+    }
+
+    /// Peaks of the range of the first `leaf_count` leaves along with their levels,
+    /// from the highest to the lowest.
+    fn peaks(&self, leaf_count: u64) -> impl Iterator<Item = (usize, &HashOf<T>)> {
+        self.levels
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(move |(level, nodes)| {
+                let len = Self::level_len(leaf_count, level);
+                (len % 2 == 1).then(|| (level, &nodes[len - 1]))
+            })
+    }
+
+    /// Combines the peaks from the lowest to the highest into the root.
+    fn bag(peaks: Vec<HashOf<T>>) -> Option<HashOf<Self>> {
+        peaks
+            .into_iter()
+            .rev()
+            .reduce(|acc, peak| Self::pair_hash(&peak, &acc))
+            .map(HashOf::transmute)
+    }
+
+    /// Combines two child hashes into a parent hash. The order is essential for index verification.
+    fn pair_hash(l_hash: &HashOf<T>, r_hash: &HashOf<T>) -> HashOf<T> {
+        let mut concat = [0u8; Hash::LENGTH * 2];
+        concat[..Hash::LENGTH].copy_from_slice(l_hash.as_ref());
+        concat[Hash::LENGTH..].copy_from_slice(r_hash.as_ref());
+
+        HashOf::from_untyped_unchecked(Hash::new(concat))
+    }
+}
+
+impl<T> MerkleMountainRangeProof<T> {
+    /// Zero-based index of the leaf among all leaves.
+    pub fn leaf_index(&self) -> u64 {
+        self.leaf_index
+    }
+
+    /// Number of leaves in the range the proof was made for.
+    pub fn leaf_count(&self) -> u64 {
+        self.leaf_count
+    }
+
+    /// Verifies the proof against the given leaf and the root of the range of
+    /// [`Self::leaf_count`] leaves. Returns true if the computed root matches the given root.
+    pub fn verify(self, leaf: &HashOf<T>, root: &HashOf<MerkleMountainRange<T>>) -> bool {
+        let Self {
+            leaf_index,
+            leaf_count,
+            audit_path,
+            mut peaks,
+        } = self;
+
+        // Leaf must be in the mountain whose height is the length of the audit path
+        let Ok(peak_level) = u32::try_from(audit_path.len()) else {
+            return false;
+        };
+        let Some(higher_leaves) = leaf_count
+            .checked_shr(peak_level + 1)
+            .and_then(|higher| higher.checked_shl(peak_level + 1))
+        else {
+            return false;
+        };
+        let Some(mountain_leaves) = 1_u64.checked_shl(peak_level) else {
+            return false;
+        };
+        if leaf_count & mountain_leaves == 0
+            || leaf_index < higher_leaves
+            || leaf_index - higher_leaves >= mountain_leaves
+        {
+            return false;
+        }
+
+        let mut index = leaf_index;
+        let peak = audit_path.into_iter().fold(*leaf, |acc, sibling| {
+            let parent = match index % 2 {
+                0 => MerkleMountainRange::pair_hash(&acc, &sibling),
+                _ => MerkleMountainRange::pair_hash(&sibling, &acc),
+            };
+            index >>= 1;
+            parent
+        });
+
+        let higher_peaks = higher_leaves.count_ones() as usize;
+        if peaks.len() + 1 != leaf_count.count_ones() as usize {
+            return false;
+        }
+        peaks.insert(higher_peaks, peak);
+
+        MerkleMountainRange::bag(peaks).is_some_and(|computed_root| *root == computed_root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_hashes(n_hashes: u8) -> Vec<HashOf<()>> {
+        (0..n_hashes)
+            .map(|i| Hash::prehashed([i; Hash::LENGTH]))
+            .map(HashOf::from_untyped_unchecked)
+            .collect()
+    }
+
+    #[test]
+    fn provides_and_verifies_inclusion_proofs() {
+        let leaves = test_hashes(13);
+        let range: MerkleMountainRange<_> = leaves.iter().copied().collect();
+        let root = range.root().unwrap();
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = range.get_proof(i as u64, 13).unwrap();
+            assert!(proof.clone().verify(leaf, &root), "leaf {i}");
+            // Proof is bound to the index of the leaf
+            assert!(!proof.verify(&leaves[(i + 1) % 13], &root), "leaf {i}");
+        }
+        assert!(range.get_proof(13, 13).is_none());
+    }
+
+    #[test]
+    fn proves_inclusion_against_checkpoints() {
+        let leaves = test_hashes(13);
+        let range: MerkleMountainRange<_> = leaves.iter().copied().collect();
+
+        for leaf_count in 1..=13 {
+            let checkpoint: MerkleMountainRange<_> = leaves[..leaf_count].iter().copied().collect();
+            let root = range.root_at(leaf_count as u64).unwrap();
+            assert_eq!(checkpoint.root(), Some(root));
+
+            for (i, leaf) in leaves[..leaf_count].iter().enumerate() {
+                let proof = range.get_proof(i as u64, leaf_count as u64).unwrap();
+                assert!(proof.verify(leaf, &root), "leaf {i} of {leaf_count}");
+            }
+        }
+    }
+
+    #[test]
+    fn pops_leaves() {
+        let leaves = test_hashes(6);
+        let mut range: MerkleMountainRange<_> = leaves.iter().copied().collect();
+
+        assert_eq!(range.pop(), Some(leaves[5]));
+        let expected: MerkleMountainRange<_> = leaves[..5].iter().copied().collect();
+        assert_eq!(range, expected);
+
+        for _ in 0..5 {
+            range.pop();
+        }
+        assert_eq!(range, MerkleMountainRange::default());
+        assert_eq!(range.root(), None);
+    }
+}
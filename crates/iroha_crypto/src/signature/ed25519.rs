@@ -64,6 +64,18 @@ impl Ed25519Sha512 {
         let s = Signature::try_from(signature).map_err(|e| ParseError(e.to_string()))?;
         pk.verify(message, &s).map_err(|_| Error::BadSignature)
     }
+
+    pub fn verify_batch(
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        pks: &[PublicKey],
+    ) -> Result<(), Error> {
+        let signatures = signatures
+            .iter()
+            .map(|signature| Signature::try_from(*signature).map_err(|e| ParseError(e.to_string())))
+            .collect::<Result<Vec<_>, _>>()?;
+        ed25519_dalek::verify_batch(messages, &signatures, pks).map_err(|_| Error::BadSignature)
+    }
 }
 
 #[cfg(test)]
@@ -111,6 +123,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn ed25519_verify_batch() {
+        let key_pairs = (0..4)
+            .map(|_| Ed25519Sha512::keypair(KeyGenOption::Random))
+            .collect::<Vec<_>>();
+        let messages: [&[u8]; 4] = [MESSAGE_1, b"second", b"third", b"fourth"];
+        let mut signatures = key_pairs
+            .iter()
+            .zip(messages)
+            .map(|((_, sk), message)| Ed25519Sha512::sign(message, sk))
+            .collect::<Vec<_>>();
+        let pks = key_pairs.iter().map(|(pk, _)| *pk).collect::<Vec<_>>();
+
+        let batch = |signatures: &[Vec<u8>]| {
+            let signatures = signatures.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            Ed25519Sha512::verify_batch(&messages, &signatures, &pks)
+        };
+        batch(&signatures).unwrap();
+
+        signatures.swap(1, 2);
+        assert!(batch(&signatures).is_err());
+    }
+
     #[test]
     fn ed25519_verify() {
         let (p, _) = key_pair_factory();
@@ -0,0 +1,68 @@
+//! Structures related to anchoring content hashes on chain (hash commitments).
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
+
+use derive_more::Display;
+use iroha_crypto::Hash;
+use iroha_data_model_derive::model;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::{account::AccountId, name::Name};
+
+#[model]
+mod model {
+    use getset::Getters;
+
+    use super::*;
+
+    /// Content hash recorded on chain by the [`crate::isi::Anchor`] instruction.
+    ///
+    /// Only the first anchoring of a hash is recorded, so the record proves that
+    /// the content existed at [`Self::anchored_at`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{hash}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AnchoredHash {
+        /// Anchored content hash.
+        pub hash: Hash,
+        /// Optional tag grouping related anchors.
+        pub tag: Option<Name>,
+        /// Account which anchored the hash.
+        pub authority: AccountId,
+        /// Creation time of the block which anchored the hash, as Unix time in milliseconds.
+        #[getset(skip)]
+        pub anchored_at_ms: u64,
+    }
+}
+
+impl AnchoredHash {
+    /// Creation time of the block which anchored the hash.
+    pub const fn anchored_at(&self) -> Duration {
+        Duration::from_millis(self.anchored_at_ms)
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::AnchoredHash;
+}
@@ -9,7 +9,9 @@ use core::{fmt::Display, num::NonZeroU64, time::Duration};
 use std::collections::BTreeSet;
 
 use derive_more::{Constructor, Display};
-use iroha_crypto::{HashOf, MerkleProof, MerkleTree, SignatureOf};
+use iroha_crypto::{
+    HashOf, MerkleMountainRange, MerkleMountainRangeProof, MerkleProof, MerkleTree, SignatureOf,
+};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
@@ -18,7 +20,11 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
-use crate::transaction::{error::TransactionRejectionReason, prelude::*};
+use crate::{
+    account::AccountId,
+    query::CommittedTransaction,
+    transaction::{error::TransactionRejectionReason, prelude::*},
+};
 
 #[model]
 mod model {
@@ -53,10 +59,18 @@ mod model {
         /// Hash of the previous block in the chain.
         #[getset(get_copy = "pub")]
         pub prev_block_hash: Option<HashOf<BlockHeader>>,
+        /// Root of the Merkle mountain range of the hashes of all previous blocks.
+        /// None for the genesis block.
+        #[getset(get_copy = "pub")]
+        pub prev_blocks_root: Option<HashOf<MerkleMountainRange<BlockHeader>>>,
         /// Merkle root of this block's transactions.
         /// None if there are no transactions (empty block).
         #[getset(get_copy = "pub")]
         pub merkle_root: Option<HashOf<MerkleTree<TransactionEntrypoint>>>,
+        /// Merkle root of the transactions left out of this block because they were rejected.
+        /// None if no transaction was left out.
+        #[getset(get_copy = "pub")]
+        pub excluded_merkle_root: Option<HashOf<MerkleTree<TransactionRejection>>>,
         /// Merkle root of this block's transaction results.
         /// None if there are no transactions (empty block).
         #[getset(get_copy = "pub")]
@@ -91,6 +105,30 @@ mod model {
         pub header: BlockHeader,
         /// External transactions as source of the state, forming the first half of the transaction entrypoints.
         pub transactions: Vec<SignedTransaction>,
+        /// Transactions rejected and left out of the block, in the order they were proposed in.
+        pub excluded_transactions: Vec<TransactionRejection>,
+    }
+
+    /// Hash and rejection reason of a transaction left out of a block, see
+    /// [`BlockParameters::exclude_rejected_transactions`](crate::parameter::BlockParameters::exclude_rejected_transactions).
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct TransactionRejection {
+        /// Hash of the rejected transaction.
+        pub hash: HashOf<SignedTransaction>,
+        /// Why the transaction was rejected.
+        pub reason: TransactionRejectionReason,
     }
 
     /// The validator index and its corresponding signature on the block header.
@@ -116,7 +154,9 @@ mod model {
     }
 
     /// Block collecting signatures from validators.
-    #[version_with_scale(version = 1, versioned_alias = "SignedBlock")]
+    ///
+    /// Blocks of version 1 can only be read from storage, see [`SignedBlock::decode_stored`].
+    #[version_with_scale(version = 2, versioned_alias = "SignedBlock")]
     #[derive(
         Debug,
         Display,
@@ -133,7 +173,7 @@ mod model {
     )]
     #[display(fmt = "{}", "self.header()")]
     #[ffi_type]
-    pub struct SignedBlockV1 {
+    pub struct SignedBlockV2 {
         /// Signatures of validators who approved this block.
         pub(super) signatures: BTreeSet<BlockSignature>,
         /// Block payload to be signed.
@@ -170,12 +210,62 @@ mod model {
         /// Transaction execution results, with indices aligned to the entrypoint Merkle tree.
         pub transaction_results: Vec<TransactionResult>,
     }
+
+    /// Proof of inclusion of a block into the chain of blocks preceding some block.
+    ///
+    /// `root` is committed in the header of that block as [`BlockHeader::prev_blocks_root`].
+    /// Clients should compare them, having verified the signatures of the header,
+    /// before verifying `proof` against `root`.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct BlockInclusionProof {
+        /// Root of the accumulator of block hashes up to the height of the proof
+        pub root: HashOf<MerkleMountainRange<BlockHeader>>,
+        /// Proof of inclusion of the block hash, made for the chain of
+        /// [`MerkleMountainRangeProof::leaf_count`] blocks
+        pub proof: MerkleMountainRangeProof<BlockHeader>,
+    }
+
+    /// Committed transactions of a single block involving an account, see [`AccountHistory`].
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct AccountHistoryBlock {
+        /// Header of the block, the hash of which is proven by `proof`.
+        pub header: BlockHeader,
+        /// Proof of inclusion of the block into the chain committed to by the latest block,
+        /// `None` for the latest block itself, which is only covered by its signatures.
+        pub proof: Option<BlockInclusionProof>,
+        /// Transactions involving the account in execution order, each with the proofs
+        /// of inclusion of its entrypoint and result into the block.
+        pub transactions: Vec<CommittedTransaction>,
+    }
+
+    /// Every committed transaction involving an account within a range of block heights.
+    ///
+    /// A transaction involves the account if its entrypoint or result refers to the account,
+    /// e.g. as the authority or in the instructions executed by it or by the data triggers it
+    /// caused. Data events aren't recorded in blocks and can be reproduced by replaying the
+    /// transactions. All block proofs are made for the same chain height.
+    ///
+    /// Peers search a limited number of blocks per request, so a long range is
+    /// returned in parts, each pointing to the height the next one starts from.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct AccountHistory {
+        /// The account.
+        pub account: AccountId,
+        /// Height of the first block searched.
+        pub from: NonZeroU64,
+        /// Height of the last block searched.
+        pub to: NonZeroU64,
+        /// Height to continue the search from, `None` if the requested range has been searched.
+        pub next: Option<NonZeroU64>,
+        /// Blocks with transactions involving the account in ascending order of height.
+        pub blocks: Vec<AccountHistoryBlock>,
+    }
 }
 
 #[cfg(any(feature = "ffi_export", feature = "ffi_import"))]
-declare_versioned!(SignedBlock 1..2, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, iroha_ffi::FfiType, IntoSchema);
+declare_versioned!(SignedBlock 2..3, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, iroha_ffi::FfiType, IntoSchema);
 #[cfg(all(not(feature = "ffi_export"), not(feature = "ffi_import")))]
-declare_versioned!(SignedBlock 1..2, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, IntoSchema);
+declare_versioned!(SignedBlock 2..3, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, IntoSchema);
 
 impl BlockHeader {
     /// Checks if it's a header of a genesis block.
@@ -200,6 +290,11 @@ impl BlockHeader {
     }
 
     /// Computes the header hash without including `result_merkle_root`.
+    ///
+    /// Headers without `prev_blocks_root` and `excluded_merkle_root`, i.e. those of genesis blocks
+    /// and of blocks of version 1, are hashed as before these fields were added, so that the
+    /// signatures of the blocks of version 1 stay valid. Both encodings never have the same length,
+    /// so different headers can't be hashed the same.
     #[inline]
     fn hash_without_results(&self) -> HashOf<BlockHeader> {
         /// A view of `BlockHeader` used for consensus hashing, omitting the execution results.
@@ -207,8 +302,10 @@ impl BlockHeader {
         struct BlockHeaderForConsensus {
             height: NonZeroU64,
             prev_block_hash: Option<HashOf<BlockHeader>>,
+            prev_blocks_root: Option<HashOf<MerkleMountainRange<BlockHeader>>>,
             // FIXME #5473: address inconsistency introduced by time-triggered entrypoints
             merkle_root: Option<HashOf<MerkleTree<TransactionEntrypoint>>>,
+            excluded_merkle_root: Option<HashOf<MerkleTree<TransactionRejection>>>,
             creation_time_ms: u64,
             view_change_index: u32,
         }
@@ -218,7 +315,9 @@ impl BlockHeader {
                 let BlockHeader {
                     height,
                     prev_block_hash,
+                    prev_blocks_root,
                     merkle_root,
+                    excluded_merkle_root,
                     result_merkle_root: _,
                     creation_time_ms,
                     view_change_index,
@@ -227,18 +326,31 @@ impl BlockHeader {
                 Self {
                     height,
                     prev_block_hash,
+                    prev_blocks_root,
                     merkle_root,
+                    excluded_merkle_root,
                     creation_time_ms,
                     view_change_index,
                 }
             }
         }
 
+        if self.prev_blocks_root.is_none() && self.excluded_merkle_root.is_none() {
+            return legacy::BlockHeaderV1::from(*self).hash_without_results();
+        }
         HashOf::from_untyped_unchecked(HashOf::new(&BlockHeaderForConsensus::from(self)).into())
     }
 }
 
-impl SignedBlockV1 {
+impl TransactionRejection {
+    /// Hash for this transaction rejection.
+    #[inline]
+    pub fn hash(&self) -> HashOf<Self> {
+        HashOf::new(self)
+    }
+}
+
+impl SignedBlockV2 {
     fn hash(&self) -> HashOf<BlockHeader> {
         self.payload.header.hash()
     }
@@ -249,22 +361,42 @@ impl SignedBlockV1 {
 }
 
 impl SignedBlock {
+    /// Decode a block read from storage, which might have been written by a peer of the
+    /// previous release in version 1 of the format.
+    ///
+    /// Blocks of version 1 are converted into the current version, keeping their hashes.
+    ///
+    /// # Errors
+    ///
+    /// If `input` isn't a block of a supported version or has bytes left after the block
+    pub fn decode_stored(input: &[u8]) -> iroha_version::error::Result<Self> {
+        use iroha_version::scale::DecodeVersioned as _;
+        use parity_scale_codec::DecodeAll as _;
+
+        if let Some((&1, mut block)) = input.split_first() {
+            return Ok(SignedBlockV2::from(legacy::SignedBlockV1::decode_all(&mut block)?).into());
+        }
+        Self::decode_all_versioned(input)
+    }
+
     /// Create new block with a given signature
     ///
     /// # Warning
     ///
     /// All transactions are categorized as valid
-    #[cfg(feature = "transparent_api")]
+    #[cfg(any(test, feature = "transparent_api"))]
     pub fn presigned(
         signature: BlockSignature,
         header: BlockHeader,
         transactions: Vec<SignedTransaction>,
+        excluded_transactions: Vec<TransactionRejection>,
     ) -> SignedBlock {
-        SignedBlockV1 {
+        SignedBlockV2 {
             signatures: [signature].into_iter().collect(),
             payload: BlockPayload {
                 header,
                 transactions,
+                excluded_transactions,
             },
             result: BlockResult::default(),
         }
@@ -276,14 +408,14 @@ impl SignedBlock {
     /// Given a pair of vectors -- transaction hashes and their corresponding results,
     /// - Record Merkle trees and the transaction results outside the block payload.
     /// - Record the Merkle root of the transaction results inside the block header, enabling client verification.
-    #[cfg(feature = "transparent_api")]
+    #[cfg(any(test, feature = "transparent_api"))]
     pub fn set_transaction_results(
         &mut self,
         time_triggers: Vec<TimeTriggerEntrypoint>,
         hashes: Vec<HashOf<TransactionEntrypoint>>,
         results: Vec<TransactionResultInner>,
     ) {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
 
         let result_hashes = results.iter().map(TransactionResult::hash_from_inner);
         block.result.time_triggers = time_triggers;
@@ -294,9 +426,92 @@ impl SignedBlock {
         block.payload.header.result_merkle_root = block.result.result_merkle.root();
     }
 
+    /// Leave the rejected external transactions out of this block, recording only their hashes
+    /// and rejection reasons, and sign the changed header with the leader's `private_key`.
+    ///
+    /// Must be called after [`Self::set_transaction_results`]. Rejected transactions don't change
+    /// the state, so the results of the remaining transactions stay the same.
+    #[cfg(any(test, feature = "transparent_api"))]
+    pub fn exclude_rejected_transactions(&mut self, private_key: &iroha_crypto::PrivateKey) {
+        let SignedBlock::V2(block) = self;
+
+        let mut results = core::mem::take(&mut block.result.transaction_results);
+        let time_trigger_results = results.split_off(block.payload.transactions.len());
+        let mut transactions = Vec::new();
+        let mut hashes = Vec::new();
+        let mut kept_results = Vec::new();
+        for (tx, result) in core::mem::take(&mut block.payload.transactions)
+            .into_iter()
+            .zip(results)
+        {
+            match result.0 {
+                Ok(sequence) => {
+                    hashes.push(tx.hash_as_entrypoint());
+                    kept_results.push(Ok(sequence));
+                    transactions.push(tx);
+                }
+                Err(reason) => block
+                    .payload
+                    .excluded_transactions
+                    .push(TransactionRejection {
+                        hash: tx.hash(),
+                        reason,
+                    }),
+            }
+        }
+
+        let header = &mut block.payload.header;
+        header.merkle_root = hashes.iter().copied().collect::<MerkleTree<_>>().root();
+        header.excluded_merkle_root = block
+            .payload
+            .excluded_transactions
+            .iter()
+            .map(TransactionRejection::hash)
+            .collect::<MerkleTree<_>>()
+            .root();
+        block.payload.transactions = transactions;
+
+        let time_triggers = core::mem::take(&mut block.result.time_triggers);
+        hashes.extend(
+            time_triggers
+                .iter()
+                .map(TimeTriggerEntrypoint::hash_as_entrypoint),
+        );
+        kept_results.extend(time_trigger_results.into_iter().map(|result| result.0));
+        let leader_index = block
+            .signatures
+            .first()
+            .map_or(0, |signature| signature.index);
+        self.set_transaction_results(time_triggers, hashes, kept_results);
+
+        let SignedBlock::V2(block) = self;
+        block.signatures = [BlockSignature::new(
+            leader_index,
+            SignatureOf::from_hash(private_key, block.payload.header.hash()),
+        )]
+        .into_iter()
+        .collect();
+    }
+
+    /// Replace the instructions of the external transactions with the results of `f`, e.g. to
+    /// serve the block with erased data.
+    ///
+    /// The header, and thus the block hash, is left intact, while the signatures of the changed
+    /// transactions no longer verify.
+    #[cfg(feature = "transparent_api")]
+    pub fn map_instructions(
+        &mut self,
+        mut f: impl FnMut(crate::isi::InstructionBox) -> crate::isi::InstructionBox,
+    ) {
+        let SignedBlock::V2(block) = self;
+        for tx in &mut block.payload.transactions {
+            tx.map_instructions(&mut f);
+        }
+    }
+
     /// Return error for the transaction index
     pub fn error(&self, tx: usize) -> Option<&TransactionRejectionReason> {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block
             .result
             .transaction_results
@@ -307,14 +522,14 @@ impl SignedBlock {
     /// Block payload. Used for tests
     #[cfg(feature = "transparent_api")]
     pub fn payload(&self) -> &BlockPayload {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         &block.payload
     }
 
     /// Block header
     #[inline]
     pub fn header(&self) -> BlockHeader {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.header()
     }
 
@@ -323,7 +538,7 @@ impl SignedBlock {
     pub fn signatures(
         &self,
     ) -> impl ExactSizeIterator<Item = &BlockSignature> + DoubleEndedIterator {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.signatures.iter()
     }
 
@@ -333,21 +548,30 @@ impl SignedBlock {
     pub fn external_transactions(
         &self,
     ) -> impl ExactSizeIterator<Item = &SignedTransaction> + DoubleEndedIterator {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.payload.transactions.iter()
     }
 
+    /// Hashes and rejection reasons of the transactions left out of the block.
+    #[inline]
+    pub fn excluded_transactions(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &TransactionRejection> + DoubleEndedIterator {
+        let SignedBlock::V2(block) = self;
+        block.payload.excluded_transactions.iter()
+    }
+
     /// Block transactions, the underlying vector
     #[inline]
     pub fn transactions_vec(&self) -> &Vec<SignedTransaction> {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         &block.payload.transactions
     }
 
     /// Check if block is empty (has no transactions)
     #[inline]
     pub fn is_empty(&self) -> bool {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.payload.transactions.is_empty()
     }
 
@@ -357,7 +581,7 @@ impl SignedBlock {
     pub fn time_triggers(
         &self,
     ) -> impl ExactSizeIterator<Item = &TimeTriggerEntrypoint> + DoubleEndedIterator {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.result.time_triggers.iter()
     }
 
@@ -368,7 +592,7 @@ impl SignedBlock {
         &self,
     ) -> impl ExactSizeIterator<Item = HashOf<TransactionEntrypoint>> + DoubleEndedIterator + '_
     {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.result.merkle.leaves()
     }
 
@@ -379,7 +603,7 @@ impl SignedBlock {
         &self,
     ) -> impl ExactSizeIterator<Item = MerkleProof<TransactionEntrypoint>> + DoubleEndedIterator + '_
     {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         let n_leaves: u32 = block
             .result
             .merkle
@@ -410,7 +634,7 @@ impl SignedBlock {
     pub fn result_hashes(
         &self,
     ) -> impl ExactSizeIterator<Item = HashOf<TransactionResult>> + DoubleEndedIterator + '_ {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.result.result_merkle.leaves()
     }
 
@@ -421,7 +645,7 @@ impl SignedBlock {
         &self,
     ) -> impl ExactSizeIterator<Item = MerkleProof<TransactionResult>> + DoubleEndedIterator + '_
     {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         let n_leaves: u32 = block
             .result
             .result_merkle
@@ -444,10 +668,42 @@ impl SignedBlock {
     pub fn results(
         &self,
     ) -> impl ExactSizeIterator<Item = &TransactionResult> + DoubleEndedIterator {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.result.transaction_results.iter()
     }
 
+    /// Transaction entrypoints (external and time-triggered) with their results and
+    /// Merkle inclusion proofs in execution order.
+    pub fn committed_transactions(
+        &self,
+    ) -> impl ExactSizeIterator<Item = CommittedTransaction> + DoubleEndedIterator + '_ {
+        let block_hash = self.hash();
+
+        self.entrypoint_hashes()
+            .zip(self.entrypoint_proofs())
+            .zip(self.entrypoints_cloned())
+            .zip(self.result_hashes())
+            .zip(self.result_proofs())
+            .zip(self.results().cloned())
+            .map(
+                move |(
+                    (
+                        (((entrypoint_hash, entrypoint_proof), entrypoint), result_hash),
+                        result_proof,
+                    ),
+                    result,
+                )| CommittedTransaction {
+                    block_hash,
+                    entrypoint_hash,
+                    entrypoint_proof,
+                    entrypoint,
+                    result_hash,
+                    result_proof,
+                    result,
+                },
+            )
+    }
+
     /// Successful transaction indices and data trigger sequences.
     pub fn successes(&self) -> impl Iterator<Item = (u64, &DataTriggerSequence)> {
         self.results()
@@ -465,14 +721,14 @@ impl SignedBlock {
     /// Calculate block hash
     #[inline]
     pub fn hash(&self) -> HashOf<BlockHeader> {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.hash()
     }
 
     /// Add additional signature to this block
     #[cfg(feature = "transparent_api")]
     pub fn sign(&mut self, private_key: &iroha_crypto::PrivateKey, signatory: usize) {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
 
         block.signatures.insert(BlockSignature::new(
             signatory as u64,
@@ -493,7 +749,7 @@ impl SignedBlock {
             ));
         }
 
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.signatures.insert(signature);
 
         Ok(())
@@ -526,7 +782,7 @@ impl SignedBlock {
                 Ok(acc)
             })?;
 
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         Ok(core::mem::replace(&mut block.signatures, signatures))
     }
 
@@ -548,7 +804,9 @@ impl SignedBlock {
         let header = BlockHeader {
             height: nonzero!(1_u64),
             prev_block_hash: None,
+            prev_blocks_root: None,
             merkle_root: Some(merkle_root),
+            excluded_merkle_root: None,
             result_merkle_root: None,
             creation_time_ms,
             view_change_index: 0,
@@ -558,9 +816,10 @@ impl SignedBlock {
         let payload = BlockPayload {
             header,
             transactions,
+            excluded_transactions: Vec::new(),
         };
 
-        SignedBlockV1 {
+        SignedBlockV2 {
             signatures: [signature].into_iter().collect(),
             payload,
             result: BlockResult::default(),
@@ -605,7 +864,7 @@ impl Iterator for EntrypointIterator<'_> {
             return None;
         }
 
-        let SignedBlock::V1(block_inner) = self.block;
+        let SignedBlock::V2(block_inner) = self.block;
         let item = if self.index < self.n_external_transactions {
             block_inner.payload.transactions[self.index].clone().into()
         } else {
@@ -628,7 +887,7 @@ impl DoubleEndedIterator for EntrypointIterator<'_> {
         // Decrement the back index lazily.
         self.index_back -= 1;
 
-        let SignedBlock::V1(block_inner) = self.block;
+        let SignedBlock::V2(block_inner) = self.block;
         let item = if self.index_back < self.n_external_transactions {
             block_inner.payload.transactions[self.index_back]
                 .clone()
@@ -651,7 +910,7 @@ impl ExactSizeIterator for EntrypointIterator<'_> {
 
 impl<'a> EntrypointIterator<'a> {
     fn new(block: &'a SignedBlock) -> Self {
-        let SignedBlock::V1(block_inner) = block;
+        let SignedBlock::V2(block_inner) = block;
         let n_external_transactions = block_inner.payload.transactions.len();
         let n_entrypoints = n_external_transactions + block_inner.result.time_triggers.len();
 
@@ -666,11 +925,99 @@ impl<'a> EntrypointIterator<'a> {
 
 impl Display for SignedBlock {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let SignedBlock::V1(block) = self;
+        let SignedBlock::V2(block) = self;
         block.fmt(f)
     }
 }
 
+mod legacy {
+    //! Blocks of version 1, written before [`BlockHeader::prev_blocks_root`] and
+    //! [`BlockHeader::excluded_merkle_root`] were added.
+
+    use super::*;
+
+    /// [`BlockHeader`] of version 1.
+    #[derive(Debug, Clone, Copy, Decode, Encode)]
+    pub struct BlockHeaderV1 {
+        pub height: NonZeroU64,
+        pub prev_block_hash: Option<HashOf<BlockHeader>>,
+        pub merkle_root: Option<HashOf<MerkleTree<TransactionEntrypoint>>>,
+        pub result_merkle_root: Option<HashOf<MerkleTree<TransactionResult>>>,
+        pub creation_time_ms: u64,
+        pub view_change_index: u32,
+    }
+
+    /// [`BlockPayload`] of version 1.
+    #[derive(Debug, Clone, Decode, Encode)]
+    pub struct BlockPayloadV1 {
+        pub header: BlockHeaderV1,
+        pub transactions: Vec<SignedTransaction>,
+    }
+
+    /// [`SignedBlockV2`] of version 1.
+    #[derive(Debug, Clone, Decode, Encode)]
+    pub struct SignedBlockV1 {
+        pub signatures: BTreeSet<BlockSignature>,
+        pub payload: BlockPayloadV1,
+        pub result: BlockResult,
+    }
+
+    impl BlockHeaderV1 {
+        pub fn hash_without_results(&self) -> HashOf<BlockHeader> {
+            let consensus_fields = (
+                self.height,
+                self.prev_block_hash,
+                self.merkle_root,
+                self.creation_time_ms,
+                self.view_change_index,
+            );
+            HashOf::from_untyped_unchecked(HashOf::new(&consensus_fields).into())
+        }
+    }
+
+    impl From<BlockHeader> for BlockHeaderV1 {
+        fn from(header: BlockHeader) -> Self {
+            Self {
+                height: header.height,
+                prev_block_hash: header.prev_block_hash,
+                merkle_root: header.merkle_root,
+                result_merkle_root: header.result_merkle_root,
+                creation_time_ms: header.creation_time_ms,
+                view_change_index: header.view_change_index,
+            }
+        }
+    }
+
+    impl From<BlockHeaderV1> for BlockHeader {
+        fn from(header: BlockHeaderV1) -> Self {
+            Self {
+                height: header.height,
+                prev_block_hash: header.prev_block_hash,
+                prev_blocks_root: None,
+                merkle_root: header.merkle_root,
+                excluded_merkle_root: None,
+                result_merkle_root: header.result_merkle_root,
+                creation_time_ms: header.creation_time_ms,
+                view_change_index: header.view_change_index,
+            }
+        }
+    }
+
+    impl From<SignedBlockV1> for SignedBlockV2 {
+        fn from(block: SignedBlockV1) -> Self {
+            Self {
+                signatures: block.signatures,
+                payload: BlockPayload {
+                    header: block.payload.header.into(),
+                    transactions: block.payload.transactions,
+                    excluded_transactions: Vec::new(),
+                },
+                result: block.result,
+            }
+        }
+    }
+}
+
 #[cfg(feature = "http")]
 pub mod stream {
     //! Blocks for streaming API.
@@ -756,11 +1103,16 @@ pub mod error {
 
 pub mod prelude {
     //! For glob-import
-    pub use super::{error::BlockRejectionReason, BlockHeader, BlockSignature, SignedBlock};
+    pub use super::{
+        error::BlockRejectionReason, AccountHistory, AccountHistoryBlock, BlockHeader,
+        BlockInclusionProof, BlockSignature, SignedBlock, TransactionRejection,
+    };
 }
 
 #[cfg(test)]
 mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
     use core::num::NonZeroU64;
 
     use super::*;
@@ -772,9 +1124,11 @@ mod tests {
             prev_block_hash: Some(HashOf::from_untyped_unchecked(iroha_crypto::Hash::new(
                 b"prev_block_hash",
             ))),
+            prev_blocks_root: None,
             merkle_root: Some(HashOf::from_untyped_unchecked(iroha_crypto::Hash::new(
                 b"merkle_root",
             ))),
+            excluded_merkle_root: None,
             result_merkle_root: None,
             creation_time_ms: 123_456_789_000,
             view_change_index: 123,
@@ -786,4 +1140,60 @@ mod tests {
         let hash1 = header.hash();
         assert_eq!(hash0, hash1);
     }
+
+    #[test]
+    fn stored_block_of_version_1_keeps_its_hash() {
+        use iroha_crypto::{Hash, KeyPair};
+        use iroha_version::scale::{DecodeVersioned as _, EncodeVersioned as _};
+
+        let key_pair = KeyPair::random();
+        let header = legacy::BlockHeaderV1 {
+            height: NonZeroU64::new(2).unwrap(),
+            prev_block_hash: Some(HashOf::from_untyped_unchecked(Hash::new(
+                b"prev_block_hash",
+            ))),
+            merkle_root: None,
+            result_merkle_root: None,
+            creation_time_ms: 123_456_789_000,
+            view_change_index: 1,
+        };
+        // Fields hashed by peers of the previous release
+        let mut consensus_fields = header.height.encode();
+        header.prev_block_hash.encode_to(&mut consensus_fields);
+        header.merkle_root.encode_to(&mut consensus_fields);
+        header.creation_time_ms.encode_to(&mut consensus_fields);
+        header.view_change_index.encode_to(&mut consensus_fields);
+        let hash = HashOf::from_untyped_unchecked(Hash::new(consensus_fields));
+        let stored = legacy::SignedBlockV1 {
+            signatures: [BlockSignature::new(
+                0,
+                SignatureOf::from_hash(key_pair.private_key(), hash),
+            )]
+            .into_iter()
+            .collect(),
+            payload: legacy::BlockPayloadV1 {
+                header,
+                transactions: Vec::new(),
+            },
+            result: BlockResult::default(),
+        };
+        let mut bytes = vec![1_u8];
+        stored.encode_to(&mut bytes);
+
+        let block = SignedBlock::decode_stored(&bytes).unwrap();
+        assert_eq!(block.hash(), hash);
+        assert_eq!(block.header().prev_blocks_root(), None);
+        assert_eq!(block.header().excluded_merkle_root(), None);
+        assert_eq!(block.excluded_transactions().len(), 0);
+        let signature = block.signatures().next().unwrap();
+        signature
+            .signature
+            .verify_hash(key_pair.public_key(), block.hash())
+            .unwrap();
+
+        let restored = SignedBlock::decode_stored(&block.encode_versioned()).unwrap();
+        assert_eq!(restored.encode_versioned()[0], 2);
+        assert_eq!(restored.hash(), hash);
+        assert!(SignedBlock::decode_all_versioned(&bytes).is_err());
+    }
 }
@@ -77,11 +77,24 @@ mod model {
         /// The account that owns this domain. Usually the [`Account`] that registered it.
         #[getset(get = "pub")]
         pub owned_by: AccountId,
+        /// [`Role`] granted to every [`Account`] registered in this `Domain`.
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub default_role: Option<RoleId>,
     }
 
     /// Builder which can be submitted in a transaction to create a new [`Domain`]
     #[derive(
-        Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
+        Debug,
+        Display,
+        Clone,
+        IdEqOrdHash,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
     )]
     #[serde(rename = "Domain")]
     #[display(fmt = "[{id}]")]
@@ -93,6 +106,10 @@ mod model {
         pub logo: Option<IpfsPath>,
         /// Metadata associated with the domain builder.
         pub metadata: Metadata,
+        /// The role granted to every account registered in the domain.
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub default_role: Option<RoleId>,
     }
 }
 
@@ -111,6 +128,7 @@ impl NewDomain {
             id,
             logo: None,
             metadata: Metadata::default(),
+            default_role: None,
         }
     }
 
@@ -127,6 +145,13 @@ impl NewDomain {
         self.metadata = metadata;
         self
     }
+
+    /// Grant the [`Role`] to every account registered in the domain, replacing previously defined value
+    #[must_use]
+    pub fn with_default_role(mut self, role_id: RoleId) -> Self {
+        self.default_role = Some(role_id);
+        self
+    }
 }
 
 impl HasMetadata for Domain {
@@ -150,6 +175,7 @@ impl Registrable for NewDomain {
             metadata: self.metadata,
             logo: self.logo,
             owned_by: authority.clone(),
+            default_role: self.default_role,
         }
     }
 }
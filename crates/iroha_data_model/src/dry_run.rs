@@ -0,0 +1,205 @@
+//! Outcome of trying out a transaction against the world state without committing it.
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use iroha_data_model_derive::model;
+use iroha_primitives::numeric::Numeric;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::{
+    asset::AssetId, statement::MovementDirection, transaction::error::TransactionRejectionReason,
+};
+
+#[model]
+mod model {
+    use getset::Getters;
+
+    use super::*;
+
+    /// Kind of the entity an [`EntityDiff`] refers to.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    #[allow(missing_docs)]
+    pub enum EntityKind {
+        Parameters,
+        Peer,
+        Domain,
+        Account,
+        AssetDefinition,
+        Asset,
+        Nft,
+        Role,
+        AccountPermission,
+        AccountRole,
+        RoleTemplate,
+        Anchor,
+        Trigger,
+        Executor,
+        ExecutorDataModel,
+    }
+
+    /// How an entity changed between two states.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    pub enum Change {
+        /// Entity is present only in the second state
+        Added,
+        /// Entity is present only in the first state
+        Removed,
+        /// Entity is present in both states but its contents differ
+        Modified,
+    }
+
+    /// Change of a single entity.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct EntityDiff {
+        /// Kind of the entity
+        #[getset(skip)]
+        pub kind: EntityKind,
+        /// Human-readable identifier of the entity
+        pub id: String,
+        /// Kind of the change
+        #[getset(skip)]
+        pub change: Change,
+    }
+
+    /// Change of the balance of an asset, e.g. `Debit 200 rose##alice@wonderland`.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct BalanceChange {
+        /// Asset whose balance changed.
+        pub asset: AssetId,
+        /// Whether the balance would be increased or decreased.
+        #[getset(skip)]
+        pub direction: MovementDirection,
+        /// Difference between the balances.
+        pub amount: Numeric,
+        /// Balance of the asset after the transaction, zero if the asset would be removed.
+        pub balance: Numeric,
+    }
+
+    /// Outcome of trying out a transaction without committing it.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct TransactionDryRun {
+        /// Reason the transaction would be rejected for, `None` if it would be committed.
+        pub rejection: Option<TransactionRejectionReason>,
+        /// Entities the transaction would add, remove or modify, grouped by [`EntityKind`].
+        pub changes: Vec<EntityDiff>,
+        /// Asset balances the transaction would change, ordered by asset.
+        pub balances: Vec<BalanceChange>,
+    }
+}
+
+impl EntityDiff {
+    /// Kind of the entity
+    pub const fn kind(&self) -> EntityKind {
+        self.kind
+    }
+
+    /// Kind of the change
+    pub const fn change(&self) -> Change {
+        self.change
+    }
+}
+
+impl fmt::Display for EntityDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = match self.change {
+            Change::Added => '+',
+            Change::Removed => '-',
+            Change::Modified => '~',
+        };
+        write!(f, "{sign} {:?} {}", self.kind, self.id)
+    }
+}
+
+impl BalanceChange {
+    /// Whether the balance would be increased or decreased.
+    pub const fn direction(&self) -> MovementDirection {
+        self.direction
+    }
+}
+
+impl fmt::Display for BalanceChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.direction, self.amount, self.asset)
+    }
+}
+
+impl TransactionDryRun {
+    /// Whether the transaction would be committed.
+    pub fn is_successful(&self) -> bool {
+        self.rejection.is_none()
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{BalanceChange, Change, EntityDiff, EntityKind, TransactionDryRun};
+}
@@ -139,7 +139,8 @@ mod asset {
     mod model {
         use super::*;
 
-        /// Depending on the wrapping event, [`Self`] represents the added or removed asset quantity.
+        /// Depending on the wrapping event, [`Self`] represents the added or removed asset quantity
+        /// together with the resulting balance of the asset.
         #[derive(
             Debug,
             Clone,
@@ -159,6 +160,8 @@ mod asset {
         pub struct AssetChanged {
             pub asset: AssetId,
             pub amount: Numeric,
+            /// Asset quantity after the change, zero if the asset was removed
+            pub balance: Numeric,
         }
 
         /// [`Self`] represents updated total asset quantity.
@@ -298,6 +301,15 @@ mod role {
             /// [`Permission`] were removed from the role.
             #[has_origin(permission_removed => &permission_removed.role)]
             PermissionRemoved(RolePermissionChanged),
+            /// Role was made to inherit another role.
+            #[has_origin(inherited_role_added => &inherited_role_added.role)]
+            InheritedRoleAdded(RoleInheritanceChanged),
+            /// Role no longer inherits another role.
+            #[has_origin(inherited_role_removed => &inherited_role_removed.role)]
+            InheritedRoleRemoved(RoleInheritanceChanged),
+            /// Role was handed over to another owner.
+            #[has_origin(owner_changed => &owner_changed.role)]
+            OwnerChanged(RoleOwnerChanged),
         }
     }
 
@@ -328,6 +340,52 @@ mod role {
             #[getset(skip)]
             pub permission: Permission,
         }
+
+        /// Depending on the wrapping event, [`RoleInheritanceChanged`] represents the added or removed inherited role
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct RoleInheritanceChanged {
+            pub role: RoleId,
+            pub inherited_role: RoleId,
+        }
+
+        /// Event indicates that owner of the [`Role`] is changed
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct RoleOwnerChanged {
+            /// Id of role being updated
+            pub role: RoleId,
+            /// Id of new owning account
+            pub new_owner: AccountId,
+        }
     }
 }
 
@@ -449,6 +507,8 @@ mod domain {
             MetadataRemoved(DomainMetadataChanged),
             #[has_origin(owner_changed => &owner_changed.domain)]
             OwnerChanged(DomainOwnerChanged),
+            #[has_origin(transfer_offer => &transfer_offer.domain)]
+            TransferOffered(DomainTransferOffer),
         }
     }
 
@@ -477,6 +537,32 @@ mod domain {
             pub domain: DomainId,
             pub new_owner: AccountId,
         }
+
+        /// Event indicate that the owner of the [`Domain`] offered it to another account,
+        /// which becomes the owner once it accepts the offer with
+        /// [`AcceptDomainTransfer`](crate::isi::AcceptDomainTransfer)
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct DomainTransferOffer {
+            pub domain: DomainId,
+            pub new_owner: AccountId,
+            /// Unix time in milliseconds after which the offer can no longer be accepted
+            pub expires_at_ms: u64,
+        }
     }
 }
 
@@ -620,10 +706,10 @@ mod executor {
             EventSet,
         )]
         #[ffi_type(opaque)]
-        #[serde(untagged)] // Unaffected by #3330, as single unit variant
-        #[repr(transparent)]
         pub enum ExecutorEvent {
             Upgraded(ExecutorUpgrade),
+            UpgradeScheduled(ExecutorUpgradeScheduled),
+            UpgradeAborted(ExecutorUpgradeAborted),
         }
 
         /// Information about the updated executor data model.
@@ -648,6 +734,54 @@ mod executor {
             /// Updated data model
             pub new_data_model: ExecutorDataModel,
         }
+
+        /// Information about an executor upgrade waiting for its activation.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+            Getters,
+        )]
+        #[ffi_type]
+        #[getset(get = "pub")]
+        pub struct ExecutorUpgradeScheduled {
+            /// Account that submitted the upgrade
+            pub authority: AccountId,
+            /// Height of the block before which the new executor is activated
+            pub activation_height: u64,
+        }
+
+        /// Information about an executor upgrade that was never activated.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+            Getters,
+        )]
+        #[ffi_type]
+        #[getset(get = "pub")]
+        pub struct ExecutorUpgradeAborted {
+            /// Height at which the upgrade was to be activated
+            pub activation_height: u64,
+            /// Account that aborted the upgrade, `None` if the migration to the new executor failed
+            pub aborted_by: Option<AccountId>,
+        }
     }
 }
 
@@ -723,11 +857,17 @@ pub mod prelude {
             AssetEventSet,
         },
         config::{ConfigurationEvent, ConfigurationEventSet, ParameterChanged},
-        domain::{DomainEvent, DomainEventSet, DomainOwnerChanged},
-        executor::{ExecutorEvent, ExecutorEventSet, ExecutorUpgrade},
+        domain::{DomainEvent, DomainEventSet, DomainOwnerChanged, DomainTransferOffer},
+        executor::{
+            ExecutorEvent, ExecutorEventSet, ExecutorUpgrade, ExecutorUpgradeAborted,
+            ExecutorUpgradeScheduled,
+        },
         nft::{NftEvent, NftEventSet, NftOwnerChanged},
         peer::{PeerEvent, PeerEventSet},
-        role::{RoleEvent, RoleEventSet, RolePermissionChanged},
+        role::{
+            RoleEvent, RoleEventSet, RoleInheritanceChanged, RoleOwnerChanged,
+            RolePermissionChanged,
+        },
         trigger::{TriggerEvent, TriggerEventSet, TriggerNumberOfExecutionsChanged},
         DataEvent, HasOrigin, MetadataChanged,
     };
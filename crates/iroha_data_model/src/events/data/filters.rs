@@ -117,6 +117,8 @@ mod model {
     pub struct AccountEventFilter {
         /// If specified matches only events originating from this account
         pub(super) id_matcher: Option<super::AccountId>,
+        /// If specified matches only events originating from accounts of this domain
+        pub(super) domain_matcher: Option<super::DomainId>,
         /// Matches only event from this set
         pub(super) event_set: AccountEventSet,
     }
@@ -139,6 +141,10 @@ mod model {
     pub struct AssetEventFilter {
         /// If specified matches only events originating from this asset
         pub(super) id_matcher: Option<super::AssetId>,
+        /// If specified matches only events originating from assets of this definition
+        pub(super) definition_matcher: Option<super::AssetDefinitionId>,
+        /// If specified matches only events originating from assets defined in this domain
+        pub(super) domain_matcher: Option<super::DomainId>,
         /// Matches only event from this set
         pub(super) event_set: AssetEventSet,
     }
@@ -161,6 +167,8 @@ mod model {
     pub struct AssetDefinitionEventFilter {
         /// If specified matches only events originating from this asset definition
         pub(super) id_matcher: Option<super::AssetDefinitionId>,
+        /// If specified matches only events originating from asset definitions of this domain
+        pub(super) domain_matcher: Option<super::DomainId>,
         /// Matches only event from this set
         pub(super) event_set: AssetDefinitionEventSet,
     }
@@ -183,6 +191,8 @@ mod model {
     pub struct NftEventFilter {
         /// If specified matches only events originating from this NFT
         pub(super) id_matcher: Option<NftId>,
+        /// If specified matches only events originating from NFTs of this domain
+        pub(super) domain_matcher: Option<super::DomainId>,
         /// Matches only event from this set
         pub(super) event_set: NftEventSet,
     }
@@ -376,6 +386,7 @@ impl AccountEventFilter {
     pub const fn new() -> Self {
         Self {
             id_matcher: None,
+            domain_matcher: None,
             event_set: AccountEventSet::all(),
         }
     }
@@ -387,6 +398,16 @@ impl AccountEventFilter {
         self
     }
 
+    /// Modifies a [`AccountEventFilter`] to accept only [`AccountEvent`]s originating from accounts of `domain_matcher`.
+    ///
+    /// Combined with [`AccountEventSet::Created`] and [`AccountEventSet::Deleted`], it matches
+    /// the lifecycle of every account of the domain, e.g. to provision new accounts with a trigger.
+    #[must_use]
+    pub fn for_domain(mut self, domain_matcher: DomainId) -> Self {
+        self.domain_matcher = Some(domain_matcher);
+        self
+    }
+
     /// Modifies a [`AccountEventFilter`] to accept only [`AccountEvent`]s of types contained in `event_set`.
     #[must_use]
     pub const fn for_events(mut self, event_set: AccountEventSet) -> Self {
@@ -412,6 +433,12 @@ impl super::EventFilter for AccountEventFilter {
             }
         }
 
+        if let Some(domain_matcher) = &self.domain_matcher {
+            if domain_matcher != event.origin().domain() {
+                return false;
+            }
+        }
+
         if !self.event_set.matches(event) {
             return false;
         }
@@ -425,6 +452,8 @@ impl AssetEventFilter {
     pub const fn new() -> Self {
         Self {
             id_matcher: None,
+            definition_matcher: None,
+            domain_matcher: None,
             event_set: AssetEventSet::all(),
         }
     }
@@ -436,6 +465,22 @@ impl AssetEventFilter {
         self
     }
 
+    /// Modifies a [`AssetEventFilter`] to accept only [`AssetEvent`]s originating from assets of `definition_matcher`,
+    /// i.e. assets `definition_matcher#*` of any account.
+    #[must_use]
+    pub fn for_asset_definition(mut self, definition_matcher: AssetDefinitionId) -> Self {
+        self.definition_matcher = Some(definition_matcher);
+        self
+    }
+
+    /// Modifies a [`AssetEventFilter`] to accept only [`AssetEvent`]s originating from assets defined in `domain_matcher`,
+    /// i.e. assets `*#domain_matcher#*` of any account.
+    #[must_use]
+    pub fn for_domain(mut self, domain_matcher: DomainId) -> Self {
+        self.domain_matcher = Some(domain_matcher);
+        self
+    }
+
     /// Modifies a [`AssetEventFilter`] to accept only [`AssetEvent`]s of types contained in `event_set`.
     #[must_use]
     pub const fn for_events(mut self, event_set: AssetEventSet) -> Self {
@@ -461,6 +506,18 @@ impl super::EventFilter for AssetEventFilter {
             }
         }
 
+        if let Some(definition_matcher) = &self.definition_matcher {
+            if definition_matcher != event.origin().definition() {
+                return false;
+            }
+        }
+
+        if let Some(domain_matcher) = &self.domain_matcher {
+            if domain_matcher != event.origin().definition().domain() {
+                return false;
+            }
+        }
+
         if !self.event_set.matches(event) {
             return false;
         }
@@ -474,6 +531,7 @@ impl AssetDefinitionEventFilter {
     pub const fn new() -> Self {
         Self {
             id_matcher: None,
+            domain_matcher: None,
             event_set: AssetDefinitionEventSet::all(),
         }
     }
@@ -485,6 +543,14 @@ impl AssetDefinitionEventFilter {
         self
     }
 
+    /// Modifies a [`AssetDefinitionEventFilter`] to accept only [`AssetDefinitionEvent`]s originating from asset definitions of `domain_matcher`,
+    /// i.e. asset definitions `*#domain_matcher`.
+    #[must_use]
+    pub fn for_domain(mut self, domain_matcher: DomainId) -> Self {
+        self.domain_matcher = Some(domain_matcher);
+        self
+    }
+
     /// Modifies a [`AssetDefinitionEventFilter`] to accept only [`AssetDefinitionEvent`]s of types contained in `event_set`.
     #[must_use]
     pub const fn for_events(mut self, event_set: AssetDefinitionEventSet) -> Self {
@@ -510,6 +576,12 @@ impl super::EventFilter for AssetDefinitionEventFilter {
             }
         }
 
+        if let Some(domain_matcher) = &self.domain_matcher {
+            if domain_matcher != event.origin().domain() {
+                return false;
+            }
+        }
+
         if !self.event_set.matches(event) {
             return false;
         }
@@ -523,6 +595,7 @@ impl NftEventFilter {
     pub const fn new() -> Self {
         Self {
             id_matcher: None,
+            domain_matcher: None,
             event_set: NftEventSet::all(),
         }
     }
@@ -534,6 +607,14 @@ impl NftEventFilter {
         self
     }
 
+    /// Modifies a [`NftEventFilter`] to accept only [`NftEvent`]s originating from NFTs of `domain_matcher`,
+    /// i.e. NFTs `*$domain_matcher`.
+    #[must_use]
+    pub fn for_domain(mut self, domain_matcher: DomainId) -> Self {
+        self.domain_matcher = Some(domain_matcher);
+        self
+    }
+
     /// Modifies a [`NftEventFilter`] to accept only [`NftEvent`]s of types contained in `event_set`.
     #[must_use]
     pub const fn for_events(mut self, event_set: NftEventSet) -> Self {
@@ -559,6 +640,12 @@ impl EventFilter for NftEventFilter {
             }
         }
 
+        if let Some(domain_matcher) = &self.domain_matcher {
+            if domain_matcher != event.origin().domain() {
+                return false;
+            }
+        }
+
         if !self.event_set.matches(event) {
             return false;
         }
@@ -754,6 +841,7 @@ impl EventFilter for DataEventFilter {
             (DataEvent::Domain(DomainEvent::AssetDefinition(event)), AssetDefinition(filter)) => {
                 filter.matches(event)
             }
+            (DataEvent::Domain(DomainEvent::Nft(event)), Nft(filter)) => filter.matches(event),
             (DataEvent::Domain(event), Domain(filter)) => filter.matches(event),
 
             (DataEvent::Peer(event), Peer(filter)) => filter.matches(event),
@@ -811,6 +899,7 @@ mod tests {
             logo: None,
             metadata: Metadata::default(),
             owned_by: domain_owner_id,
+            default_role: None,
         };
         let account = Account::new(account_id.clone()).into_account();
         let asset = Asset::new(asset_id.clone(), 0_u32);
@@ -845,4 +934,71 @@ mod tests {
         assert!(!asset_filter.matches(&account_created));
         assert!(asset_filter.matches(&asset_created));
     }
+
+    #[test]
+    #[cfg(feature = "transparent_api")]
+    fn account_lifecycle_in_domain() {
+        let wonderland: DomainId = "wonderland".parse().unwrap();
+        let looking_glass: DomainId = "looking_glass".parse().unwrap();
+        let alice_id = AccountId::new(wonderland.clone(), KeyPair::random().into_parts().0);
+        let bob_id = AccountId::new(looking_glass, KeyPair::random().into_parts().0);
+
+        let filter = DataEventFilter::Account(
+            AccountEventFilter::new()
+                .for_domain(wonderland)
+                .for_events(AccountEventSet::Created | AccountEventSet::Deleted),
+        );
+
+        let alice_created = DomainEvent::Account(AccountEvent::Created(
+            Account::new(alice_id.clone()).into_account(),
+        ))
+        .into();
+        let alice_deleted = DomainEvent::Account(AccountEvent::Deleted(alice_id)).into();
+        let bob_created =
+            DomainEvent::Account(AccountEvent::Created(Account::new(bob_id).into_account())).into();
+
+        assert!(filter.matches(&alice_created));
+        assert!(filter.matches(&alice_deleted));
+        assert!(!filter.matches(&bob_created));
+    }
+
+    #[test]
+    #[cfg(feature = "transparent_api")]
+    fn entity_patterns() {
+        let wonderland: DomainId = "wonderland".parse().unwrap();
+        let looking_glass: DomainId = "looking_glass".parse().unwrap();
+        let alice_id = AccountId::new(looking_glass.clone(), KeyPair::random().into_parts().0);
+        let rose_id: AssetId = format!("rose#wonderland#{alice_id}").parse().unwrap();
+        let tulip_id: AssetId = format!("tulip#looking_glass#{alice_id}").parse().unwrap();
+        let nft_id: NftId = "snowflake$wonderland".parse().unwrap();
+
+        let asset_added = |id: &AssetId| -> DataEvent {
+            DomainEvent::Account(AccountEvent::Asset(AssetEvent::Added(AssetChanged {
+                asset: id.clone(),
+                amount: 1_u32.into(),
+                balance: 1_u32.into(),
+            })))
+            .into()
+        };
+        let nft_deleted =
+            |id: &NftId| -> DataEvent { DomainEvent::Nft(NftEvent::Deleted(id.clone())).into() };
+
+        let assets_of_wonderland =
+            DataEventFilter::Asset(AssetEventFilter::new().for_domain(wonderland.clone()));
+        assert!(assets_of_wonderland.matches(&asset_added(&rose_id)));
+        assert!(!assets_of_wonderland.matches(&asset_added(&tulip_id)));
+
+        let roses = DataEventFilter::Asset(
+            AssetEventFilter::new().for_asset_definition(rose_id.definition().clone()),
+        );
+        assert!(roses.matches(&asset_added(&rose_id)));
+        assert!(!roses.matches(&asset_added(&tulip_id)));
+
+        let nfts_of_wonderland = DataEventFilter::Nft(NftEventFilter::new().for_domain(wonderland));
+        assert!(nfts_of_wonderland.matches(&nft_deleted(&nft_id)));
+        assert!(
+            !DataEventFilter::Nft(NftEventFilter::new().for_domain(looking_glass))
+                .matches(&nft_deleted(&nft_id))
+        );
+    }
 }
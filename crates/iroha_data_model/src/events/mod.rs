@@ -7,7 +7,7 @@ use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
 use parity_scale_codec::{Decode, Encode};
-use pipeline::{BlockEvent, TransactionEvent};
+use pipeline::{BlockEvent, QueueEvent, TransactionEvent};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
@@ -110,6 +110,12 @@ impl From<BlockEvent> for EventBox {
     }
 }
 
+impl From<QueueEvent> for EventBox {
+    fn from(source: QueueEvent) -> Self {
+        Self::Pipeline(source.into())
+    }
+}
+
 impl TryFrom<EventBox> for TransactionEvent {
     type Error = iroha_macro::error::ErrorTryFromEnum<EventBox, Self>;
 
@@ -205,7 +211,7 @@ impl EventFilter for EventFilterBox {
 
 mod conversions {
     use super::{
-        pipeline::{BlockEventFilter, TransactionEventFilter},
+        pipeline::{BlockEventFilter, QueueEventFilter, TransactionEventFilter},
         prelude::*,
     };
 
@@ -248,6 +254,7 @@ mod conversions {
 
         TransactionEventFilter => PipelineEventFilterBox => EventFilterBox,
         BlockEventFilter       => PipelineEventFilterBox => EventFilterBox,
+        QueueEventFilter       => PipelineEventFilterBox => EventFilterBox,
     }
 }
 
@@ -255,12 +262,18 @@ mod conversions {
 pub mod stream {
     //! Structures related to event streaming over HTTP
 
-    use derive_more::Constructor;
+    use core::{
+        num::{NonZeroU32, NonZeroU64},
+        time::Duration,
+    };
+
+    use iroha_crypto::{PrivateKey, SignatureOf};
     use iroha_data_model_derive::model;
     use iroha_version::prelude::*;
 
     pub use self::model::*;
     use super::*;
+    use crate::{account::AccountId, ChainId};
 
     #[model]
     mod model {
@@ -274,9 +287,133 @@ pub mod stream {
 
         /// Message sent by the stream consumer.
         /// Request sent by the client to subscribe to events.
-        #[derive(Debug, Clone, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema)]
-        #[repr(transparent)]
-        pub struct EventSubscriptionRequest(pub Vec<EventFilterBox>);
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct EventSubscriptionRequest {
+            /// Events to subscribe to
+            pub filters: Vec<EventFilterBox>,
+            /// Subscriber authenticated by its signature of the subscription.
+            ///
+            /// Peers may withhold events about accounts from anonymous subscribers.
+            pub subscriber: Option<EventSubscriber>,
+        }
+
+        /// Account subscribing to events.
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct EventSubscriber {
+            /// Account of the subscriber
+            pub account: AccountId,
+            /// Peers reject the subscription after this moment (unix time in milliseconds).
+            pub expires_at_ms: NonZeroU64,
+            /// Random value to tell apart identical subscriptions, which peers otherwise reject as replayed.
+            pub nonce: NonZeroU32,
+            /// Signature of the [`EventSubscriptionPayload`] made by `account`
+            pub signature: SignatureOf<EventSubscriptionPayload>,
+        }
+
+        /// Subscription as signed by the subscriber.
+        ///
+        /// The chain id isn't sent along with the request, peers of other chains
+        /// fail to verify the signature instead.
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct EventSubscriptionPayload {
+            /// Chain the subscription is meant for
+            pub chain: ChainId,
+            /// Account of the subscriber
+            pub account: AccountId,
+            /// Peers reject the subscription after this moment (unix time in milliseconds).
+            pub expires_at_ms: NonZeroU64,
+            /// Random value to tell apart identical subscriptions
+            pub nonce: NonZeroU32,
+            /// Events to subscribe to
+            pub filters: Vec<EventFilterBox>,
+        }
+    }
+
+    impl EventSubscriptionRequest {
+        /// Construct an anonymous subscription request.
+        pub fn new(filters: Vec<EventFilterBox>) -> Self {
+            Self {
+                filters,
+                subscriber: None,
+            }
+        }
+
+        /// Construct a subscription request on behalf of `account` to peers of `chain`,
+        /// signed with its `private_key`.
+        ///
+        /// Peers reject the request after `expires_at` (a duration since unix epoch)
+        /// and when it is sent again with the same `nonce`.
+        pub fn signed(
+            filters: Vec<EventFilterBox>,
+            chain: ChainId,
+            account: AccountId,
+            expires_at: Duration,
+            nonce: NonZeroU32,
+            private_key: &PrivateKey,
+        ) -> Self {
+            let expires_at_ms: u64 = expires_at
+                .as_millis()
+                .try_into()
+                .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX");
+            let expires_at_ms =
+                NonZeroU64::new(expires_at_ms).expect("Subscription must expire after unix epoch");
+            let payload = EventSubscriptionPayload {
+                chain,
+                account,
+                expires_at_ms,
+                nonce,
+                filters,
+            };
+            let signature = SignatureOf::new(private_key, &payload);
+            Self {
+                filters: payload.filters,
+                subscriber: Some(EventSubscriber {
+                    account: payload.account,
+                    expires_at_ms,
+                    nonce,
+                    signature,
+                }),
+            }
+        }
+
+        /// Events to subscribe to
+        pub fn filters(&self) -> &[EventFilterBox] {
+            &self.filters
+        }
+
+        /// Subscriber of a request to peers of `chain`, or [`None`] if the request is anonymous.
+        ///
+        /// The expiry and the nonce are only authenticated here, it's up to the peer
+        /// to reject expired and replayed requests.
+        ///
+        /// # Errors
+        /// If the request isn't signed by the subscriber account for `chain`
+        pub fn authenticate(
+            &self,
+            chain: &ChainId,
+        ) -> Result<Option<&EventSubscriber>, iroha_crypto::Error> {
+            let Some(subscriber) = &self.subscriber else {
+                return Ok(None);
+            };
+            let payload = EventSubscriptionPayload {
+                chain: chain.clone(),
+                account: subscriber.account.clone(),
+                expires_at_ms: subscriber.expires_at_ms,
+                nonce: subscriber.nonce,
+                filters: self.filters.clone(),
+            };
+            subscriber
+                .signature
+                .verify(subscriber.account.signatory(), &payload)?;
+            Ok(Some(subscriber))
+        }
+    }
+
+    impl EventSubscriber {
+        /// Moment after which peers reject the subscription, as a duration since unix epoch
+        pub fn expires_at(&self) -> Duration {
+            Duration::from_millis(self.expires_at_ms.get())
+        }
     }
 
     impl From<EventMessage> for EventBox {
@@ -284,12 +421,74 @@ pub mod stream {
             source.0
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use iroha_crypto::KeyPair;
+
+        use super::*;
+
+        const EXPIRES_AT: Duration = Duration::from_secs(1_000);
+
+        fn chain() -> ChainId {
+            ChainId::from("00000000-0000-0000-0000-000000000000")
+        }
+
+        fn signed(key_pair: &KeyPair) -> EventSubscriptionRequest {
+            let account =
+                AccountId::new("wonderland".parse().unwrap(), key_pair.public_key().clone());
+            EventSubscriptionRequest::signed(
+                vec![EventFilterBox::Data(data::DataEventFilter::Any)],
+                chain(),
+                account,
+                EXPIRES_AT,
+                NonZeroU32::MIN,
+                key_pair.private_key(),
+            )
+        }
+
+        #[test]
+        fn signed_subscription_is_authenticated() {
+            let key_pair = KeyPair::random();
+
+            let anonymous = EventSubscriptionRequest::new(signed(&key_pair).filters);
+            assert!(anonymous.authenticate(&chain()).unwrap().is_none());
+
+            let signed = signed(&key_pair);
+            let subscriber = signed.authenticate(&chain()).unwrap().unwrap();
+            assert_eq!(subscriber.account.signatory(), key_pair.public_key());
+            assert_eq!(subscriber.expires_at(), EXPIRES_AT);
+
+            let mut forged = signed;
+            forged.filters.clear();
+            assert!(forged.authenticate(&chain()).is_err());
+        }
+
+        #[test]
+        fn subscription_is_bound_to_chain_expiry_and_nonce() {
+            let key_pair = KeyPair::random();
+
+            let other_chain = ChainId::from("11111111-1111-1111-1111-111111111111");
+            assert!(signed(&key_pair).authenticate(&other_chain).is_err());
+
+            let mut extended = signed(&key_pair);
+            extended.subscriber.as_mut().unwrap().expires_at_ms =
+                NonZeroU64::new(2_000_000).unwrap();
+            assert!(extended.authenticate(&chain()).is_err());
+
+            let mut renonced = signed(&key_pair);
+            renonced.subscriber.as_mut().unwrap().nonce = NonZeroU32::MAX;
+            assert!(renonced.authenticate(&chain()).is_err());
+        }
+    }
 }
 
 /// Exports common structs and enums from this module.
 pub mod prelude {
     #[cfg(feature = "http")]
-    pub use super::stream::{EventMessage, EventSubscriptionRequest};
+    pub use super::stream::{
+        EventMessage, EventSubscriber, EventSubscriptionPayload, EventSubscriptionRequest,
+    };
     #[cfg(feature = "transparent_api")]
     pub use super::EventFilter;
     pub use super::{
@@ -12,7 +12,7 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
-use crate::{block::BlockHeader, transaction::SignedTransaction};
+use crate::{account::AccountId, block::BlockHeader, transaction::SignedTransaction};
 
 #[model]
 mod model {
@@ -38,6 +38,7 @@ mod model {
     pub enum PipelineEventBox {
         Transaction(TransactionEvent),
         Block(BlockEvent),
+        Queue(QueueEvent),
     }
 
     #[derive(
@@ -86,6 +87,66 @@ mod model {
         pub status: TransactionStatus,
     }
 
+    /// Report of a transaction submitted to this peer which was dropped from the queue,
+    /// or never entered it, so that it won't reach a block through this peer
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        CopyGetters,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct QueueEvent {
+        #[getset(get = "pub")]
+        pub hash: HashOf<SignedTransaction>,
+        #[getset(get = "pub")]
+        pub authority: AccountId,
+        #[getset(get_copy = "pub")]
+        pub reason: QueueDropReason,
+    }
+
+    /// Reason for a transaction to be dropped from the queue
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    #[repr(u8)]
+    pub enum QueueDropReason {
+        /// Transaction waited longer than its time to live
+        Expired,
+        /// Queue reached its capacity
+        QueueFull,
+        /// Authority reached the maximum number of its transactions in the queue
+        AuthorityQueueFull,
+        /// Transaction is already in the queue
+        Duplicate,
+        /// Transaction is already stored in a block
+        AlreadyInBlockchain,
+        /// Peer is draining before shutdown and accepts no new transactions
+        Draining,
+    }
+
     /// Report of block's status in the pipeline
     #[derive(
         Debug,
@@ -159,6 +220,7 @@ mod model {
     pub enum PipelineEventFilterBox {
         Transaction(TransactionEventFilter),
         Block(BlockEventFilter),
+        Queue(QueueEventFilter),
     }
 
     #[derive(
@@ -208,6 +270,30 @@ mod model {
         #[getset(get = "pub")]
         pub status: Option<TransactionStatus>,
     }
+
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Default,
+        CopyGetters,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct QueueEventFilter {
+        #[getset(get = "pub")]
+        pub authority: Option<AccountId>,
+        #[getset(get_copy = "pub")]
+        pub reason: Option<QueueDropReason>,
+    }
 }
 
 impl BlockEventFilter {
@@ -274,6 +360,31 @@ impl TransactionEventFilter {
     }
 }
 
+impl QueueEventFilter {
+    /// Construct new instance
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            authority: None,
+            reason: None,
+        }
+    }
+
+    /// Match only transactions submitted by the given authority
+    #[must_use]
+    pub fn for_authority(mut self, authority: AccountId) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+
+    /// Match only transactions dropped for the given reason
+    #[must_use]
+    pub fn for_reason(mut self, reason: QueueDropReason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+}
+
 #[cfg(feature = "transparent_api")]
 impl TransactionEventFilter {
     fn field_matches<T: Eq>(filter: Option<&T>, event: &T) -> bool {
@@ -324,6 +435,15 @@ impl super::EventFilter for PipelineEventFilterBox {
             ]
             .into_iter()
             .all(core::convert::identity),
+            (Self::Queue(queue_filter), PipelineEventBox::Queue(queue_event)) => {
+                queue_filter
+                    .authority
+                    .as_ref()
+                    .is_none_or(|authority| *authority == queue_event.authority)
+                    && queue_filter
+                        .reason
+                        .is_none_or(|reason| reason == queue_event.reason)
+            }
             _ => false,
         }
     }
@@ -332,8 +452,8 @@ impl super::EventFilter for PipelineEventFilterBox {
 /// Exports common structs and enums from this module.
 pub mod prelude {
     pub use super::{
-        BlockEvent, BlockStatus, PipelineEventBox, PipelineEventFilterBox, TransactionEvent,
-        TransactionStatus,
+        BlockEvent, BlockStatus, PipelineEventBox, PipelineEventFilterBox, QueueDropReason,
+        QueueEvent, TransactionEvent, TransactionStatus,
     };
 }
 
@@ -355,7 +475,9 @@ mod tests {
             Self {
                 height,
                 prev_block_hash: None,
+                prev_blocks_root: None,
                 merkle_root: Some(merkle_root),
+                excluded_merkle_root: None,
                 result_merkle_root: None,
                 creation_time_ms: 0,
                 view_change_index: 0,
@@ -5,12 +5,13 @@ use alloc::{collections::BTreeSet, format, string::String, vec::Vec};
 #[cfg(feature = "std")]
 use std::collections::BTreeSet;
 
+use iroha_crypto::Hash;
 use iroha_data_model_derive::model;
-use iroha_primitives::json::Json;
+use iroha_primitives::json::{to_canonical_string, Json};
 use iroha_schema::{Ident, IntoSchema};
 
 pub use self::model::*;
-use crate::transaction::WasmSmartContract;
+use crate::transaction::{error::TransactionRejectionReason, WasmSmartContract};
 
 #[model]
 mod model {
@@ -89,6 +90,50 @@ mod model {
         pub schema: Json,
     }
 
+    /// Schema of a permission token accepted by the executor, so that clients
+    /// can build tokens without hard-coding their layout.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct PermissionTokenSchema {
+        /// Name of the token, as in [`crate::permission::Permission::name`]
+        pub name: Ident,
+        /// Hash of the canonical form of [`Self::schema`].
+        /// It changes whenever an executor upgrade changes the token definition.
+        pub version: Hash,
+        /// JSON schema of the token payload, `null` for tokens without payload
+        pub schema: Json,
+    }
+
+    /// Outcome of trying out an executor [`Upgrade`](crate::isi::Upgrade) without committing it.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct ExecutorUpgradeDryRun {
+        /// Reason the upgrade transaction would be rejected for, `None` if it would be committed.
+        pub rejection: Option<TransactionRejectionReason>,
+        /// Executor data model after the upgrade, or the current one if the upgrade would be rejected.
+        pub data_model: ExecutorDataModel,
+        /// Entities the upgrade would add (`+`), remove (`-`) or modify (`~`),
+        /// e.g. `~ Account alice@wonderland`.
+        pub changes: Vec<String>,
+    }
+
     // TODO: Client doesn't need structures defined inside this macro. When dynamic linking is
     // implemented use: #[cfg(any(feature = "transparent_api", feature = "ffi_import"))]
 }
@@ -105,6 +150,29 @@ impl ExecutorDataModel {
     pub fn schema(&self) -> &Json {
         &self.schema
     }
+
+    /// Schemas of the permission tokens supported by the executor, ordered by name
+    pub fn permission_schemas(&self) -> Vec<PermissionTokenSchema> {
+        let definitions: serde_json::Value = self.schema.try_into_any().unwrap_or_default();
+        self.permissions
+            .iter()
+            .map(|name| {
+                let schema = definitions.get(name).cloned().unwrap_or_default();
+                PermissionTokenSchema {
+                    name: name.clone(),
+                    version: Hash::new(to_canonical_string(&schema)),
+                    schema: schema.into(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl ExecutorUpgradeDryRun {
+    /// Whether the upgrade would be committed.
+    pub fn is_successful(&self) -> bool {
+        self.rejection.is_none()
+    }
 }
 
 /// Result type that every executor should return.
@@ -112,5 +180,5 @@ pub type Result<T = (), E = crate::ValidationFail> = core::result::Result<T, E>;
 
 pub mod prelude {
     //! The prelude re-exports most commonly used traits, structs and macros from this crate.
-    pub use super::{Executor, ExecutorDataModel};
+    pub use super::{Executor, ExecutorDataModel, ExecutorUpgradeDryRun, PermissionTokenSchema};
 }
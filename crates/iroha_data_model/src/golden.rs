@@ -0,0 +1,228 @@
+//! Golden-file checks guarding the wire format of transactions, queries, blocks and events.
+//!
+//! Golden files live in `docs/source/references/golden` and are shared with SDKs.
+//! `signed_block_v1.scale` was written by the release before blocks of version 2 and is
+//! only decoded.
+//! See [`iroha_test_samples::golden`] for how to update them.
+
+use core::{num::NonZeroU32, time::Duration};
+use std::sync::LazyLock;
+
+use iroha_test_samples::golden::GoldenFiles;
+use nonzero_ext::nonzero;
+
+use crate::{
+    events::{
+        pipeline::{
+            PipelineEventFilterBox, QueueDropReason, QueueEvent, TransactionEvent,
+            TransactionEventFilter, TransactionStatus,
+        },
+        time::{TimeEvent, TimeInterval},
+    },
+    prelude::*,
+    transaction::error::TransactionRejectionReason,
+};
+
+static ALICE_KEYPAIR: LazyLock<KeyPair> = LazyLock::new(|| {
+    KeyPair::new(
+        "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03"
+            .parse()
+            .unwrap(),
+        "802620CCF31D85E3B32A4BEA59987CE0C78E3B8E2DB93881468AB2435FE45D5C9DCD53"
+            .parse()
+            .unwrap(),
+    )
+    .unwrap()
+});
+
+static ALICE_ID: LazyLock<AccountId> = LazyLock::new(|| {
+    format!("{}@wonderland", ALICE_KEYPAIR.public_key())
+        .parse()
+        .unwrap()
+});
+
+static BOB_ID: LazyLock<AccountId> = LazyLock::new(|| {
+    "ed012004FF5B81046DDCCF19E2E451C45DFB6F53759D4EB30FA2EFA807284D1CC33016@wonderland"
+        .parse()
+        .unwrap()
+});
+
+const CHAIN: &str = "00000000-0000-0000-0000-000000000000";
+const CREATION_TIME: Duration = Duration::from_millis(1_700_000_000_000);
+
+fn golden() -> GoldenFiles {
+    GoldenFiles::workspace()
+}
+
+fn transaction() -> SignedTransaction {
+    transaction_with_nonce(nonzero!(42_u32))
+}
+
+fn transaction_with_nonce(nonce: NonZeroU32) -> SignedTransaction {
+    let rose: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+    let alice_rose = AssetId::new(rose.clone(), ALICE_ID.clone());
+    let instructions: [InstructionBox; 4] = [
+        Register::domain(Domain::new("looking_glass".parse().unwrap())).into(),
+        Register::asset_definition(AssetDefinition::numeric(rose)).into(),
+        Mint::asset_numeric(13_u32, alice_rose.clone()).into(),
+        Transfer::asset_numeric(alice_rose, 7_u32, BOB_ID.clone()).into(),
+    ];
+
+    let mut metadata = Metadata::default();
+    metadata.insert("purpose".parse().unwrap(), "golden");
+
+    let mut builder = TransactionBuilder::new(CHAIN.parse().unwrap(), ALICE_ID.clone())
+        .with_instructions(instructions)
+        .with_metadata(metadata);
+    builder
+        .set_creation_time(CREATION_TIME)
+        .set_ttl(Duration::from_secs(60))
+        .set_nonce(nonce);
+    builder.sign(ALICE_KEYPAIR.private_key())
+}
+
+#[test]
+fn transaction_wire_format() {
+    golden().check_versioned("signed_transaction", &transaction());
+}
+
+#[test]
+fn query_wire_format() {
+    let query = QueryRequest::Singular(SingularQueryBox::FindExecutorDataModel(
+        FindExecutorDataModel,
+    ))
+    .with_authority(ALICE_ID.clone())
+    .with_expiry(CREATION_TIME + Duration::from_secs(10))
+    .with_nonce(nonzero!(7_u32))
+    .sign(&ALICE_KEYPAIR);
+
+    golden().check_versioned("signed_query", &query);
+}
+
+#[test]
+fn block_wire_format() {
+    let committed = transaction();
+    let rejected = transaction_with_nonce(nonzero!(43_u32));
+    let hashes = vec![
+        committed.hash_as_entrypoint(),
+        rejected.hash_as_entrypoint(),
+    ];
+    let header = BlockHeader {
+        height: nonzero!(2_u64),
+        prev_block_hash: Some(HashOf::from_untyped_unchecked(Hash::new(b"genesis"))),
+        prev_blocks_root: Some(HashOf::from_untyped_unchecked(Hash::new(b"prev_blocks"))),
+        merkle_root: hashes.iter().copied().collect::<MerkleTree<_>>().root(),
+        excluded_merkle_root: None,
+        result_merkle_root: None,
+        creation_time_ms: (CREATION_TIME + Duration::from_secs(1))
+            .as_millis()
+            .try_into()
+            .unwrap(),
+        view_change_index: 0,
+    };
+    let signature = BlockSignature::new(
+        0,
+        SignatureOf::from_hash(ALICE_KEYPAIR.private_key(), header.hash()),
+    );
+    let mut block =
+        SignedBlock::presigned(signature, header, vec![committed, rejected], Vec::new());
+    block.set_transaction_results(
+        Vec::new(),
+        hashes,
+        vec![
+            Ok(Vec::new()),
+            Err(TransactionRejectionReason::Validation(
+                ValidationFail::NotPermitted("golden".to_owned()),
+            )),
+        ],
+    );
+    block.exclude_rejected_transactions(ALICE_KEYPAIR.private_key());
+
+    golden().check_versioned("signed_block", &block);
+}
+
+#[test]
+fn stored_block_of_version_1_is_read() {
+    let path = golden().dir().join("signed_block_v1.scale");
+    let bytes = std::fs::read(&path)
+        .unwrap_or_else(|err| panic!("failed to read `{}`: {err}", path.display()));
+    let block = SignedBlock::decode_stored(&bytes).unwrap();
+
+    let header = block.header();
+    assert_eq!(header.height().get(), 2);
+    assert_eq!(header.prev_blocks_root(), None);
+    assert_eq!(header.excluded_merkle_root(), None);
+    assert_eq!(block.excluded_transactions().len(), 0);
+    // Signed by the previous release, so the hash must not have changed
+    let signature = block.signatures().next().unwrap();
+    signature
+        .signature
+        .verify_hash(ALICE_KEYPAIR.public_key(), block.hash())
+        .unwrap();
+
+    let tx = block.external_transactions().next().unwrap();
+    tx.verify_signature().unwrap();
+    assert_eq!(tx.authority(), &*ALICE_ID);
+    assert_eq!(
+        block.entrypoint_hashes().collect::<Vec<_>>(),
+        [tx.hash_as_entrypoint()]
+    );
+    assert!(block.results().all(Result::is_ok));
+}
+
+#[test]
+fn event_wire_format() {
+    let hash = transaction().hash();
+    let events: Vec<EventBox> = vec![
+        TransactionEvent {
+            hash,
+            block_height: None,
+            status: TransactionStatus::Queued,
+        }
+        .into(),
+        TransactionEvent {
+            hash,
+            block_height: Some(nonzero!(2_u64)),
+            status: TransactionStatus::Approved,
+        }
+        .into(),
+        TransactionEvent {
+            hash,
+            block_height: Some(nonzero!(3_u64)),
+            status: TransactionStatus::Rejected(Box::new(TransactionRejectionReason::Validation(
+                ValidationFail::NotPermitted("golden".to_owned()),
+            ))),
+        }
+        .into(),
+        EventBox::Pipeline(
+            QueueEvent {
+                hash,
+                authority: ALICE_ID.clone(),
+                reason: QueueDropReason::Expired,
+            }
+            .into(),
+        ),
+        EventBox::Data(DomainEvent::Deleted("looking_glass".parse().unwrap()).into()),
+        EventBox::Time(TimeEvent {
+            interval: TimeInterval::new(CREATION_TIME, Duration::from_secs(1)),
+        }),
+    ];
+
+    golden().check("events", &events);
+}
+
+#[test]
+fn event_filter_wire_format() {
+    let filters: Vec<EventFilterBox> = vec![
+        EventFilterBox::Pipeline(PipelineEventFilterBox::Transaction(
+            TransactionEventFilter::new()
+                .for_hash(transaction().hash())
+                .for_status(TransactionStatus::Approved),
+        )),
+        EventFilterBox::Data(DataEventFilter::Domain(
+            DomainEventFilter::new().for_domain("wonderland".parse().unwrap()),
+        )),
+    ];
+
+    golden().check("event_filters", &filters);
+}
@@ -1,8 +1,10 @@
 //! This library contains basic Iroha Special Instructions.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
 use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
 
 use derive_more::{Constructor, DebugCustom, Display};
 use iroha_data_model_derive::{model, EnumRef};
@@ -12,7 +14,7 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 use strum::EnumDiscriminants;
 
-pub use self::{model::*, transparent::*};
+pub use self::{build::InstructionBuildError, model::*, transparent::*};
 use super::prelude::*;
 use crate::{seal, Level, Registered};
 
@@ -120,6 +122,22 @@ mod model {
 
         #[debug(fmt = "{_0:?}")]
         Custom(CustomInstruction),
+        #[debug(fmt = "{_0:?}")]
+        Anchor(Anchor),
+        #[debug(fmt = "{_0:?}")]
+        InstantiateRoleTemplate(InstantiateRoleTemplate),
+        #[debug(fmt = "{_0:?}")]
+        SetDomainValidator(SetDomainValidator),
+        #[debug(fmt = "{_0:?}")]
+        MarkRedactable(MarkRedactable),
+        #[debug(fmt = "{_0:?}")]
+        Redact(Redact),
+        #[debug(fmt = "{_0:?}")]
+        AcceptDomainTransfer(AcceptDomainTransfer),
+        #[debug(fmt = "{_0:?}")]
+        AbortExecutorUpgrade(AbortExecutorUpgrade),
+        #[debug(fmt = "{_0:?}")]
+        SetSpendingCounter(SetSpendingCounter),
     }
 }
 
@@ -153,6 +171,8 @@ impl_instruction! {
     Register<Nft>,
     Register<Role>,
     Register<Trigger>,
+    RegisterAccounts,
+    Register<RoleTemplate>,
     Unregister<Peer>,
     Unregister<Domain>,
     Unregister<Account>,
@@ -160,6 +180,7 @@ impl_instruction! {
     Unregister<Nft>,
     Unregister<Role>,
     Unregister<Trigger>,
+    Unregister<RoleTemplate>,
     Mint<Numeric, Asset>,
     Mint<u32, Trigger>,
     Burn<Numeric, Asset>,
@@ -168,16 +189,31 @@ impl_instruction! {
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Asset, Numeric, Account>,
     Transfer<Account, NftId, Account>,
+    Transfer<Account, RoleId, Account>,
     Grant<Permission, Account>,
     Grant<RoleId, Account>,
     Grant<Permission, Role>,
+    Grant<RoleId, Role>,
+    Grant<PermissionGroup, Account>,
+    Grant<RevocablePermission, Account>,
     Revoke<Permission, Account>,
     Revoke<RoleId, Account>,
     Revoke<Permission, Role>,
+    RevokeAll,
+    Revoke<RoleId, Role>,
+    Revoke<PermissionGroup, Account>,
     SetParameter,
     Upgrade,
     ExecuteTrigger,
     Log,
+    Anchor,
+    InstantiateRoleTemplate,
+    SetDomainValidator,
+    MarkRedactable,
+    Redact,
+    AcceptDomainTransfer,
+    AbortExecutorUpgrade,
+    SetSpendingCounter,
 }
 
 impl Instruction for InstructionBox {}
@@ -463,6 +499,13 @@ mod transparent {
                 object: new_account,
             }
         }
+
+        /// Constructs a new [`RegisterAccounts`] for multiple [`Account`]s.
+        pub fn accounts(new_accounts: impl IntoIterator<Item = NewAccount>) -> RegisterAccounts {
+            RegisterAccounts {
+                objects: new_accounts.into_iter().collect(),
+            }
+        }
     }
 
     impl Register<AssetDefinition> {
@@ -497,6 +540,13 @@ mod transparent {
         }
     }
 
+    impl Register<RoleTemplate> {
+        /// Constructs a new [`Register`] for a [`RoleTemplate`].
+        pub fn role_template(template: RoleTemplate) -> Self {
+            Self { object: template }
+        }
+    }
+
     impl_display! {
         Register<O>
         where
@@ -507,6 +557,23 @@ mod transparent {
         object,
     }
 
+    isi! {
+        /// Instruction for a registration of multiple accounts at once.
+        ///
+        /// Has the same effect as a sequence of [`Register<Account>`], but is cheaper to
+        /// validate and to store when onboarding many users.
+        pub struct RegisterAccounts {
+            /// Accounts that should be registered.
+            pub objects: Vec<NewAccount>,
+        }
+    }
+
+    impl core::fmt::Display for RegisterAccounts {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "REGISTER {} accounts", self.objects.len())
+        }
+    }
+
     impl_into_box! {
         Register<Peer> |
         Register<Domain> |
@@ -514,7 +581,9 @@ mod transparent {
         Register<AssetDefinition> |
         Register<Nft> |
         Register<Role> |
-        Register<Trigger>
+        Register<Trigger> |
+        RegisterAccounts |
+        Register<RoleTemplate>
     => RegisterBox => InstructionBox[Register],
     => RegisterBoxRef<'a> => InstructionBoxRef<'a>[Register]
     }
@@ -544,7 +613,8 @@ mod transparent {
         Unregister<AssetDefinition> |
         Unregister<Nft> |
         Unregister<Role> |
-        Unregister<Trigger>
+        Unregister<Trigger> |
+        Unregister<RoleTemplate>
     => UnregisterBox => InstructionBox[Unregister],
     => UnregisterBoxRef<'a> => InstructionBoxRef<'a>[Unregister]
     }
@@ -600,6 +670,15 @@ mod transparent {
         }
     }
 
+    impl Unregister<RoleTemplate> {
+        /// Constructs a new [`Unregister`] for a [`RoleTemplate`].
+        pub fn role_template(template_id: RoleId) -> Self {
+            Self {
+                object: template_id,
+            }
+        }
+    }
+
     isi! {
         /// Generic instruction for a mint of an object to the identifiable destination.
         pub struct Mint<O, D: Identifiable> {
@@ -712,6 +791,9 @@ mod transparent {
 
     impl Transfer<Account, DomainId, Account> {
         /// Constructs a new [`Transfer`] for a [`Domain`].
+        ///
+        /// Outside of genesis, the transfer only offers the domain to the destination account,
+        /// which has to take it over with [`AcceptDomainTransfer`] before the offer expires.
         pub fn domain(from: AccountId, domain_id: DomainId, to: AccountId) -> Self {
             Self {
                 source: from,
@@ -762,6 +844,17 @@ mod transparent {
         }
     }
 
+    impl Transfer<Account, RoleId, Account> {
+        /// Constructs a new [`Transfer`] of the ownership of a [`Role`].
+        pub fn role(current_owner: AccountId, role_id: RoleId, new_owner: AccountId) -> Self {
+            Self {
+                source: current_owner,
+                object: role_id,
+                destination: new_owner,
+            }
+        }
+    }
+
     impl_display! {
         Transfer<S, O, D>
         where
@@ -780,7 +873,8 @@ mod transparent {
     impl_into_box! {
         Transfer<Account, DomainId, Account> |
         Transfer<Account, AssetDefinitionId, Account> |
-        Transfer<Asset, Numeric, Account> | Transfer<Account, NftId, Account>
+        Transfer<Asset, Numeric, Account> | Transfer<Account, NftId, Account> |
+        Transfer<Account, RoleId, Account>
     => TransferBox => InstructionBox[Transfer],
     => TransferBoxRef<'a> => InstructionBoxRef<'a>[Transfer]
     }
@@ -825,6 +919,55 @@ mod transparent {
         }
     }
 
+    impl Grant<RoleId, Role> {
+        /// Constructs a new [`Grant`] for making a [`Role`] inherit permissions of another [`Role`].
+        pub fn inherited_role(role_id: RoleId, to: RoleId) -> Self {
+            Self {
+                object: role_id,
+                destination: to,
+            }
+        }
+    }
+
+    impl Grant<PermissionGroup, Account> {
+        /// Constructs a new [`Grant`] for all [`Permission`]s of a [`PermissionGroup`] at once.
+        pub fn account_permission_group(group: PermissionGroup, to: AccountId) -> Self {
+            Self {
+                object: group,
+                destination: to,
+            }
+        }
+    }
+
+    impl Grant<RevocablePermission, Account> {
+        /// Constructs a new [`Grant`] for a [`Permission`] which is revoked automatically
+        /// once the condition of the [`RevocablePermission`] is met.
+        pub fn account_revocable_permission(
+            permission: RevocablePermission,
+            to: AccountId,
+        ) -> Self {
+            Self {
+                object: permission,
+                destination: to,
+            }
+        }
+    }
+
+    impl Grant<Permission, Account> {
+        /// Make the granted [`Permission`] be revoked automatically
+        /// as soon as an event matching `filter` occurs.
+        #[must_use]
+        pub fn revoke_on(
+            self,
+            filter: impl Into<DataEventFilter>,
+        ) -> Grant<RevocablePermission, Account> {
+            Grant::account_revocable_permission(
+                RevocablePermission::new(self.object, filter),
+                self.destination,
+            )
+        }
+    }
+
     impl_display! {
         Grant<O, D>
         where
@@ -840,7 +983,10 @@ mod transparent {
     impl_into_box! {
         Grant<Permission, Account> |
         Grant<RoleId, Account> |
-        Grant<Permission, Role>
+        Grant<Permission, Role> |
+        Grant<RoleId, Role> |
+        Grant<PermissionGroup, Account> |
+        Grant<RevocablePermission, Account>
     => GrantBox => InstructionBox[Grant],
     => GrantBoxRef<'a> => InstructionBoxRef<'a>[Grant]
     }
@@ -885,6 +1031,26 @@ mod transparent {
         }
     }
 
+    impl Revoke<RoleId, Role> {
+        /// Constructs a new [`Revoke`] for removing an inherited [`Role`] from [`Role`].
+        pub fn inherited_role(role_id: RoleId, from: RoleId) -> Self {
+            Self {
+                object: role_id,
+                destination: from,
+            }
+        }
+    }
+
+    impl Revoke<PermissionGroup, Account> {
+        /// Constructs a new [`Revoke`] for all [`Permission`]s of a [`PermissionGroup`] at once.
+        pub fn account_permission_group(group: PermissionGroup, from: AccountId) -> Self {
+            Self {
+                object: group,
+                destination: from,
+            }
+        }
+    }
+
     impl_display! {
         Revoke<O, D>
         where
@@ -897,10 +1063,33 @@ mod transparent {
         destination,
     }
 
+    isi! {
+        /// Instruction for revoking all [`Permission`]s granted directly to an [`Account`].
+        ///
+        /// Has the same effect as a [`Revoke<Permission, Account>`] for each of the account
+        /// permissions. Permissions which the account has through its roles are kept.
+        #[derive(Display)]
+        #[display(fmt = "REVOKE ALL PERMISSIONS FROM `{destination}`")]
+        pub struct RevokeAll {
+            /// Account which is being revoked all permissions from.
+            pub destination: AccountId,
+        }
+    }
+
+    impl RevokeAll {
+        /// Constructs a new [`RevokeAll`] for all permissions of an [`Account`].
+        pub fn account_permissions(from: AccountId) -> Self {
+            Self { destination: from }
+        }
+    }
+
     impl_into_box! {
         Revoke<Permission, Account> |
         Revoke<RoleId, Account> |
-        Revoke<Permission, Role>
+        Revoke<Permission, Role> |
+        RevokeAll |
+        Revoke<RoleId, Role> |
+        Revoke<PermissionGroup, Account>
     => RevokeBox => InstructionBox[Revoke],
     => RevokeBoxRef<'a> => InstructionBoxRef<'a>[Revoke]
     }
@@ -959,6 +1148,195 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Instruction to anchor a content hash on chain, optionally under a tag.
+        #[derive(Display)]
+        #[display(fmt = "ANCHOR `{hash}`")]
+        pub struct Anchor {
+            /// Content hash to anchor.
+            pub hash: Hash,
+            /// Optional tag grouping related anchors.
+            pub tag: Option<Name>,
+        }
+    }
+
+    impl Anchor {
+        /// Constructs a new [`Anchor`] for the given content hash.
+        pub fn new(hash: Hash) -> Self {
+            Self { hash, tag: None }
+        }
+
+        /// Group the anchor under the given tag.
+        #[must_use]
+        pub fn with_tag(mut self, tag: Name) -> Self {
+            self.tag = Some(tag);
+            self
+        }
+    }
+
+    isi! {
+        /// Instruction to register a role from a [`RoleTemplate`], substituting its placeholders
+        /// with `arguments`, and grant the role to `grant_to`.
+        #[derive(Display)]
+        #[display(fmt = "INSTANTIATE `{template}` AS `{role}` FOR `{grant_to}`")]
+        pub struct InstantiateRoleTemplate {
+            /// Template to instantiate.
+            pub template: RoleId,
+            /// Id of the registered role.
+            pub role: RoleId,
+            /// Values of the template placeholders.
+            pub arguments: BTreeMap<Name, String>,
+            /// Account to grant the registered role to.
+            pub grant_to: AccountId,
+        }
+    }
+
+    impl InstantiateRoleTemplate {
+        /// Constructs a new [`InstantiateRoleTemplate`] without arguments.
+        pub fn new(template: RoleId, role: RoleId, grant_to: AccountId) -> Self {
+            Self {
+                template,
+                role,
+                arguments: BTreeMap::new(),
+                grant_to,
+            }
+        }
+
+        /// Substitute the placeholder `name` with `value`.
+        #[must_use]
+        pub fn with_argument(mut self, name: Name, value: impl Into<String>) -> Self {
+            self.arguments.insert(name, value.into());
+            self
+        }
+    }
+
+    isi! {
+        /// Instruction to set or remove the supplementary validator of a domain.
+        ///
+        /// The validator is a Wasm module built like an executor. Its `execute_instruction`
+        /// entrypoint is called, after the global executor, with every instruction of a
+        /// transaction that emits an event of the domain, and may reject it.
+        #[derive(Display)]
+        #[display(fmt = "SET VALIDATOR OF `{domain}`")]
+        pub struct SetDomainValidator {
+            /// Domain to validate instructions of.
+            pub domain: DomainId,
+            /// Validator module, or `None` to remove the current one.
+            pub validator: Option<WasmSmartContract>,
+        }
+    }
+
+    impl SetDomainValidator {
+        /// Constructs a new [`SetDomainValidator`] setting `validator` for `domain`.
+        pub fn new(domain: DomainId, validator: WasmSmartContract) -> Self {
+            Self {
+                domain,
+                validator: Some(validator),
+            }
+        }
+
+        /// Constructs a new [`SetDomainValidator`] removing the validator of `domain`.
+        pub fn remove(domain: DomainId) -> Self {
+            Self {
+                domain,
+                validator: None,
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to mark the value under `key` in the metadata of `account` as
+        /// redactable, allowing [`Redact`] to erase it later.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "MARK `{key}` OF `{account}` REDACTABLE")]
+        pub struct MarkRedactable {
+            /// Account whose metadata holds the value.
+            pub account: AccountId,
+            /// Key of the value.
+            pub key: Name,
+        }
+    }
+
+    isi! {
+        /// Instruction to replace the value under `key` in the metadata of `account`,
+        /// previously marked with [`MarkRedactable`], with its [`Redact::commitment`].
+        ///
+        /// The values set under the key up to the redaction are also replaced with their
+        /// commitments in the blocks and transactions served to clients, while the block hashes
+        /// stay intact. The salt of the commitments is derived with [`Redact::salt`] from the
+        /// `redaction_secret` configured on the peers, which never enters a block, so that
+        /// guessable values can't be recovered from their commitments by anyone but its holders.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REDACT `{key}` OF `{account}`")]
+        pub struct Redact {
+            /// Account whose metadata holds the value.
+            pub account: AccountId,
+            /// Key of the value.
+            pub key: Name,
+            /// [`Redact::secret_id`] of the `redaction_secret` the peers are expected to have.
+            ///
+            /// Peers configured with another secret, or none, fail the instruction.
+            pub secret_id: Hash,
+        }
+    }
+
+    impl Redact {
+        /// Identifier of the redaction `secret`, which doesn't disclose it.
+        pub fn secret_id(secret: &str) -> Hash {
+            Hash::new(secret.encode())
+        }
+
+        /// Salt of the commitments replacing the values under `key` in the metadata of
+        /// `account` redacted by peers with the redaction `secret`.
+        pub fn salt(secret: &str, account: &AccountId, key: &Name) -> Hash {
+            Hash::new((secret, account, key).encode())
+        }
+
+        /// Commitment replacing `value` redacted with `salt`.
+        pub fn commitment(salt: &Hash, value: &Json) -> Hash {
+            let salt: &[u8; Hash::LENGTH] = salt.as_ref();
+            Hash::new([salt.as_slice(), value.get().as_bytes()].concat())
+        }
+    }
+
+    isi! {
+        /// Instruction to accept the offer of `domain` made by its owner with
+        /// [`Transfer::domain`], making the authority the new owner.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ACCEPT TRANSFER OF `{domain}`")]
+        pub struct AcceptDomainTransfer {
+            /// Domain offered to the authority.
+            pub domain: DomainId,
+        }
+    }
+
+    isi! {
+        /// Instruction to abort an [`Upgrade`] that waits for its activation
+        /// as required by [`ExecutorUpgradeParameters::delay_blocks`](crate::parameter::ExecutorUpgradeParameters).
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ABORT EXECUTOR UPGRADE AT HEIGHT {activation_height}")]
+        pub struct AbortExecutorUpgrade {
+            /// Activation height of the upgrade, as reported when it was scheduled.
+            pub activation_height: u64,
+        }
+    }
+
+    isi! {
+        /// Instruction to replace the [`SpendingCounter`] of `account` summing up the same
+        /// transfers as `counter`.
+        ///
+        /// Only the executor maintains the counters, the default one rejects this instruction
+        /// when it is submitted in transactions.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SET SPENDING COUNTER OF `{account}`")]
+        pub struct SetSpendingCounter {
+            /// Account the assets are transferred from.
+            pub account: AccountId,
+            /// New state of the counter.
+            pub counter: SpendingCounter,
+        }
+    }
+
     isi! {
         /// Blockchain specific instruction (defined in the executor).
         /// Can be used to extend instruction set or add expression system.
@@ -988,6 +1366,710 @@ mod transparent {
     }
 }
 
+mod build {
+    //! Fallible constructors which take ids as strings, for scripting.
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{borrow::ToOwned, string::ToString};
+    use core::str::FromStr;
+
+    use iroha_primitives::{json::Json, numeric::NumericSpec};
+
+    use super::*;
+    use crate::metadata::Metadata;
+
+    /// Error of constructing an instruction from string arguments.
+    #[derive(Debug, Display, Clone, PartialEq, Eq)]
+    #[display(fmt = "invalid `{argument}` argument `{input}`: {reason}")]
+    pub struct InstructionBuildError {
+        argument: &'static str,
+        input: String,
+        reason: String,
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for InstructionBuildError {}
+
+    impl InstructionBuildError {
+        /// Name of the constructor argument that failed to parse
+        pub fn argument(&self) -> &'static str {
+            self.argument
+        }
+
+        /// The string that failed to parse
+        pub fn input(&self) -> &str {
+            &self.input
+        }
+
+        /// Why the input is invalid
+        pub fn reason(&self) -> &str {
+            &self.reason
+        }
+    }
+
+    fn parse<T>(argument: &'static str, input: &str) -> Result<T, InstructionBuildError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        input.parse().map_err(|err: T::Err| InstructionBuildError {
+            argument,
+            input: input.to_owned(),
+            reason: err.to_string(),
+        })
+    }
+
+    impl Register<Domain> {
+        /// Constructs a new [`Register`] for a [`Domain`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `domain_id` is not a valid [`DomainId`].
+        pub fn try_domain(domain_id: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::domain(Domain::new(parse("domain_id", domain_id)?)))
+        }
+
+        /// Set metadata of the registered [`Domain`].
+        #[must_use]
+        pub fn with_metadata(self, metadata: Metadata) -> Self {
+            Self::domain(self.object.with_metadata(metadata))
+        }
+    }
+
+    impl Register<Account> {
+        /// Constructs a new [`Register`] for an [`Account`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `account_id` is not a valid [`AccountId`].
+        pub fn try_account(account_id: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account(Account::new(parse(
+                "account_id",
+                account_id,
+            )?)))
+        }
+
+        /// Set metadata of the registered [`Account`].
+        #[must_use]
+        pub fn with_metadata(self, metadata: Metadata) -> Self {
+            Self::account(self.object.with_metadata(metadata))
+        }
+    }
+
+    impl Register<AssetDefinition> {
+        /// Constructs a new [`Register`] for an [`AssetDefinition`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `asset_definition_id` is not a valid [`AssetDefinitionId`].
+        pub fn try_asset_definition(
+            asset_definition_id: &str,
+            spec: NumericSpec,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::asset_definition(AssetDefinition::new(
+                parse("asset_definition_id", asset_definition_id)?,
+                spec,
+            )))
+        }
+
+        /// Set metadata of the registered [`AssetDefinition`].
+        #[must_use]
+        pub fn with_metadata(self, metadata: Metadata) -> Self {
+            Self::asset_definition(self.object.with_metadata(metadata))
+        }
+    }
+
+    impl Register<Nft> {
+        /// Constructs a new [`Register`] for an [`Nft`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `nft_id` is not a valid [`NftId`].
+        pub fn try_nft(nft_id: &str, content: Metadata) -> Result<Self, InstructionBuildError> {
+            Ok(Self::nft(Nft::new(parse("nft_id", nft_id)?, content)))
+        }
+    }
+
+    impl Register<Role> {
+        /// Constructs a new [`Register`] for a [`Role`] granted to `grant_to` from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If `role_id` is not a valid [`RoleId`] or `grant_to` is not a valid [`AccountId`].
+        pub fn try_role(role_id: &str, grant_to: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::role(Role::new(
+                parse("role_id", role_id)?,
+                parse("grant_to", grant_to)?,
+            )))
+        }
+    }
+
+    impl Unregister<Domain> {
+        /// Constructs a new [`Unregister`] for a [`Domain`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `domain_id` is not a valid [`DomainId`].
+        pub fn try_domain(domain_id: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::domain(parse("domain_id", domain_id)?))
+        }
+    }
+
+    impl Unregister<Account> {
+        /// Constructs a new [`Unregister`] for an [`Account`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `account_id` is not a valid [`AccountId`].
+        pub fn try_account(account_id: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account(parse("account_id", account_id)?))
+        }
+    }
+
+    impl Unregister<AssetDefinition> {
+        /// Constructs a new [`Unregister`] for an [`AssetDefinition`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `asset_definition_id` is not a valid [`AssetDefinitionId`].
+        pub fn try_asset_definition(
+            asset_definition_id: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::asset_definition(parse(
+                "asset_definition_id",
+                asset_definition_id,
+            )?))
+        }
+    }
+
+    impl Unregister<Nft> {
+        /// Constructs a new [`Unregister`] for an [`Nft`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `nft_id` is not a valid [`NftId`].
+        pub fn try_nft(nft_id: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::nft(parse("nft_id", nft_id)?))
+        }
+    }
+
+    impl Unregister<Role> {
+        /// Constructs a new [`Unregister`] for a [`Role`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `role_id` is not a valid [`RoleId`].
+        pub fn try_role(role_id: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::role(parse("role_id", role_id)?))
+        }
+    }
+
+    impl Unregister<Trigger> {
+        /// Constructs a new [`Unregister`] for a [`Trigger`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `trigger_id` is not a valid [`TriggerId`].
+        pub fn try_trigger(trigger_id: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::trigger(parse("trigger_id", trigger_id)?))
+        }
+    }
+
+    impl SetKeyValue<Domain> {
+        /// Constructs a new [`SetKeyValue`] for a [`Domain`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `domain_id` is not a valid [`DomainId`] or `key` is not a valid [`Name`].
+        pub fn try_domain(
+            domain_id: &str,
+            key: &str,
+            value: impl Into<Json>,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::domain(
+                parse("domain_id", domain_id)?,
+                parse("key", key)?,
+                value,
+            ))
+        }
+    }
+
+    impl SetKeyValue<Account> {
+        /// Constructs a new [`SetKeyValue`] for an [`Account`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `account_id` is not a valid [`AccountId`] or `key` is not a valid [`Name`].
+        pub fn try_account(
+            account_id: &str,
+            key: &str,
+            value: impl Into<Json>,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account(
+                parse("account_id", account_id)?,
+                parse("key", key)?,
+                value,
+            ))
+        }
+    }
+
+    impl SetKeyValue<AssetDefinition> {
+        /// Constructs a new [`SetKeyValue`] for an [`AssetDefinition`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `asset_definition_id` is not a valid [`AssetDefinitionId`] or `key` is not a
+        /// valid [`Name`].
+        pub fn try_asset_definition(
+            asset_definition_id: &str,
+            key: &str,
+            value: impl Into<Json>,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::asset_definition(
+                parse("asset_definition_id", asset_definition_id)?,
+                parse("key", key)?,
+                value,
+            ))
+        }
+    }
+
+    impl SetKeyValue<Nft> {
+        /// Constructs a new [`SetKeyValue`] for an [`Nft`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `nft_id` is not a valid [`NftId`] or `key` is not a valid [`Name`].
+        pub fn try_nft(
+            nft_id: &str,
+            key: &str,
+            value: impl Into<Json>,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::nft(
+                parse("nft_id", nft_id)?,
+                parse("key", key)?,
+                value,
+            ))
+        }
+    }
+
+    impl SetKeyValue<Trigger> {
+        /// Constructs a new [`SetKeyValue`] for a [`Trigger`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `trigger_id` is not a valid [`TriggerId`] or `key` is not a valid [`Name`].
+        pub fn try_trigger(
+            trigger_id: &str,
+            key: &str,
+            value: impl Into<Json>,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::trigger(
+                parse("trigger_id", trigger_id)?,
+                parse("key", key)?,
+                value,
+            ))
+        }
+    }
+
+    impl RemoveKeyValue<Domain> {
+        /// Constructs a new [`RemoveKeyValue`] for a [`Domain`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `domain_id` is not a valid [`DomainId`] or `key` is not a valid [`Name`].
+        pub fn try_domain(domain_id: &str, key: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::domain(
+                parse("domain_id", domain_id)?,
+                parse("key", key)?,
+            ))
+        }
+    }
+
+    impl RemoveKeyValue<Account> {
+        /// Constructs a new [`RemoveKeyValue`] for an [`Account`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `account_id` is not a valid [`AccountId`] or `key` is not a valid [`Name`].
+        pub fn try_account(account_id: &str, key: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account(
+                parse("account_id", account_id)?,
+                parse("key", key)?,
+            ))
+        }
+    }
+
+    impl RemoveKeyValue<AssetDefinition> {
+        /// Constructs a new [`RemoveKeyValue`] for an [`AssetDefinition`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `asset_definition_id` is not a valid [`AssetDefinitionId`] or `key` is not a
+        /// valid [`Name`].
+        pub fn try_asset_definition(
+            asset_definition_id: &str,
+            key: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::asset_definition(
+                parse("asset_definition_id", asset_definition_id)?,
+                parse("key", key)?,
+            ))
+        }
+    }
+
+    impl RemoveKeyValue<Nft> {
+        /// Constructs a new [`RemoveKeyValue`] for an [`Nft`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `nft_id` is not a valid [`NftId`] or `key` is not a valid [`Name`].
+        pub fn try_nft(nft_id: &str, key: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::nft(parse("nft_id", nft_id)?, parse("key", key)?))
+        }
+    }
+
+    impl RemoveKeyValue<Trigger> {
+        /// Constructs a new [`RemoveKeyValue`] for a [`Trigger`] from string id and key.
+        ///
+        /// # Errors
+        ///
+        /// If `trigger_id` is not a valid [`TriggerId`] or `key` is not a valid [`Name`].
+        pub fn try_trigger(trigger_id: &str, key: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::trigger(
+                parse("trigger_id", trigger_id)?,
+                parse("key", key)?,
+            ))
+        }
+    }
+
+    impl Mint<Numeric, Asset> {
+        /// Constructs a new [`Mint`] for an [`Asset`] of [`Numeric`] type from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `asset_id` is not a valid [`AssetId`].
+        pub fn try_asset_numeric(
+            object: impl Into<Numeric>,
+            asset_id: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::asset_numeric(object, parse("asset_id", asset_id)?))
+        }
+    }
+
+    impl Mint<u32, Trigger> {
+        /// Constructs a new [`Mint`] for repetitions of a [`Trigger`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `trigger_id` is not a valid [`TriggerId`].
+        pub fn try_trigger_repetitions(
+            repetitions: u32,
+            trigger_id: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::trigger_repetitions(
+                repetitions,
+                parse("trigger_id", trigger_id)?,
+            ))
+        }
+    }
+
+    impl Burn<Numeric, Asset> {
+        /// Constructs a new [`Burn`] for an [`Asset`] of [`Numeric`] type from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `asset_id` is not a valid [`AssetId`].
+        pub fn try_asset_numeric(
+            object: impl Into<Numeric>,
+            asset_id: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::asset_numeric(object, parse("asset_id", asset_id)?))
+        }
+    }
+
+    impl Burn<u32, Trigger> {
+        /// Constructs a new [`Burn`] for repetitions of a [`Trigger`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `trigger_id` is not a valid [`TriggerId`].
+        pub fn try_trigger_repetitions(
+            repetitions: u32,
+            trigger_id: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::trigger_repetitions(
+                repetitions,
+                parse("trigger_id", trigger_id)?,
+            ))
+        }
+    }
+
+    impl Transfer<Account, DomainId, Account> {
+        /// Constructs a new [`Transfer`] for a [`Domain`] from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If any of the arguments is not a valid id.
+        pub fn try_domain(
+            from: &str,
+            domain_id: &str,
+            to: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::domain(
+                parse("from", from)?,
+                parse("domain_id", domain_id)?,
+                parse("to", to)?,
+            ))
+        }
+    }
+
+    impl Transfer<Account, AssetDefinitionId, Account> {
+        /// Constructs a new [`Transfer`] for an [`AssetDefinition`] from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If any of the arguments is not a valid id.
+        pub fn try_asset_definition(
+            from: &str,
+            asset_definition_id: &str,
+            to: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::asset_definition(
+                parse("from", from)?,
+                parse("asset_definition_id", asset_definition_id)?,
+                parse("to", to)?,
+            ))
+        }
+    }
+
+    impl Transfer<Asset, Numeric, Account> {
+        /// Constructs a new [`Transfer`] for an [`Asset`] of [`Numeric`] type from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If `asset_id` is not a valid [`AssetId`] or `to` is not a valid [`AccountId`].
+        pub fn try_asset_numeric(
+            asset_id: &str,
+            quantity: impl Into<Numeric>,
+            to: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::asset_numeric(
+                parse("asset_id", asset_id)?,
+                quantity,
+                parse("to", to)?,
+            ))
+        }
+    }
+
+    impl Transfer<Account, NftId, Account> {
+        /// Constructs a new [`Transfer`] for an [`Nft`] from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If any of the arguments is not a valid id.
+        pub fn try_nft(from: &str, nft_id: &str, to: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::nft(
+                parse("from", from)?,
+                parse("nft_id", nft_id)?,
+                parse("to", to)?,
+            ))
+        }
+    }
+
+    impl Transfer<Account, RoleId, Account> {
+        /// Constructs a new [`Transfer`] of the ownership of a [`Role`] from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If any of the arguments is not a valid id.
+        pub fn try_role(
+            current_owner: &str,
+            role_id: &str,
+            new_owner: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::role(
+                parse("current_owner", current_owner)?,
+                parse("role_id", role_id)?,
+                parse("new_owner", new_owner)?,
+            ))
+        }
+    }
+
+    impl Grant<Permission, Account> {
+        /// Constructs a new [`Grant`] of a [`Permission`] to an [`Account`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `to` is not a valid [`AccountId`].
+        pub fn try_account_permission(
+            permission: impl Into<Permission>,
+            to: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account_permission(permission, parse("to", to)?))
+        }
+    }
+
+    impl Grant<RoleId, Account> {
+        /// Constructs a new [`Grant`] of a [`Role`] to an [`Account`] from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If `role_id` is not a valid [`RoleId`] or `to` is not a valid [`AccountId`].
+        pub fn try_account_role(role_id: &str, to: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account_role(
+                parse("role_id", role_id)?,
+                parse("to", to)?,
+            ))
+        }
+    }
+
+    impl Grant<Permission, Role> {
+        /// Constructs a new [`Grant`] of a [`Permission`] to a [`Role`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `to` is not a valid [`RoleId`].
+        pub fn try_role_permission(
+            permission: impl Into<Permission>,
+            to: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::role_permission(permission, parse("to", to)?))
+        }
+    }
+
+    impl Grant<RoleId, Role> {
+        /// Constructs a new [`Grant`] of an inherited [`Role`] to a [`Role`] from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If `role_id` or `to` is not a valid [`RoleId`].
+        pub fn try_inherited_role(role_id: &str, to: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::inherited_role(
+                parse("role_id", role_id)?,
+                parse("to", to)?,
+            ))
+        }
+    }
+
+    impl Revoke<Permission, Account> {
+        /// Constructs a new [`Revoke`] of a [`Permission`] from an [`Account`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `from` is not a valid [`AccountId`].
+        pub fn try_account_permission(
+            permission: impl Into<Permission>,
+            from: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account_permission(permission, parse("from", from)?))
+        }
+    }
+
+    impl Revoke<RoleId, Account> {
+        /// Constructs a new [`Revoke`] of a [`Role`] from an [`Account`] from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If `role_id` is not a valid [`RoleId`] or `from` is not a valid [`AccountId`].
+        pub fn try_account_role(role_id: &str, from: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account_role(
+                parse("role_id", role_id)?,
+                parse("from", from)?,
+            ))
+        }
+    }
+
+    impl Revoke<Permission, Role> {
+        /// Constructs a new [`Revoke`] of a [`Permission`] from a [`Role`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `from` is not a valid [`RoleId`].
+        pub fn try_role_permission(
+            permission: impl Into<Permission>,
+            from: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::role_permission(permission, parse("from", from)?))
+        }
+    }
+
+    impl Revoke<RoleId, Role> {
+        /// Constructs a new [`Revoke`] of an inherited [`Role`] from a [`Role`] from string ids.
+        ///
+        /// # Errors
+        ///
+        /// If `role_id` or `from` is not a valid [`RoleId`].
+        pub fn try_inherited_role(
+            role_id: &str,
+            from: &str,
+        ) -> Result<Self, InstructionBuildError> {
+            Ok(Self::inherited_role(
+                parse("role_id", role_id)?,
+                parse("from", from)?,
+            ))
+        }
+    }
+
+    impl RevokeAll {
+        /// Constructs a new [`RevokeAll`] for all permissions of an [`Account`] from its string id.
+        ///
+        /// # Errors
+        ///
+        /// If `from` is not a valid [`AccountId`].
+        pub fn try_account_permissions(from: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::account_permissions(parse("from", from)?))
+        }
+    }
+
+    impl ExecuteTrigger {
+        /// Constructs a new [`ExecuteTrigger`] from a string trigger id.
+        ///
+        /// # Errors
+        ///
+        /// If `trigger_id` is not a valid [`TriggerId`].
+        pub fn try_new(trigger_id: &str) -> Result<Self, InstructionBuildError> {
+            Ok(Self::new(parse("trigger_id", trigger_id)?))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const ALICE: &str =
+            "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03@wonderland";
+
+        #[test]
+        fn string_ids_are_parsed() {
+            let transfer = Transfer::try_asset_numeric(&format!("rose##{ALICE}"), 7_u32, ALICE)
+                .expect("ids are valid");
+
+            assert_eq!(transfer.destination, ALICE.parse().unwrap());
+            assert_eq!(transfer.source.account, ALICE.parse().unwrap());
+        }
+
+        #[test]
+        fn invalid_argument_is_reported() {
+            let err = Transfer::try_domain(ALICE, "wonderland", "alice@wonderland").unwrap_err();
+
+            assert_eq!(err.argument(), "to");
+            assert_eq!(err.input(), "alice@wonderland");
+        }
+
+        #[test]
+        fn registration_takes_metadata() {
+            let mut metadata = Metadata::default();
+            metadata.insert("key".parse().unwrap(), "value");
+
+            let register = Register::try_domain("wonderland")
+                .unwrap()
+                .with_metadata(metadata.clone());
+
+            assert_eq!(register.object.metadata, metadata);
+        }
+    }
+}
+
 macro_rules! isi_box {
     ($($meta:meta)* $item:item) => {
         #[derive(
@@ -1076,7 +2158,11 @@ isi_box! {
         /// Register [`Role`].
         Role(Register<Role>),
         /// Register [`Trigger`].
-        Trigger(Register<Trigger>)
+        Trigger(Register<Trigger>),
+        /// Register multiple [`Account`]s.
+        Accounts(RegisterAccounts),
+        /// Register [`RoleTemplate`].
+        RoleTemplate(Register<RoleTemplate>),
     }
 }
 
@@ -1101,7 +2187,9 @@ isi_box! {
         /// Unregister [`Role`].
         Role(Unregister<Role>),
         /// Unregister [`Trigger`].
-        Trigger(Unregister<Trigger>)
+        Trigger(Unregister<Trigger>),
+        /// Unregister [`RoleTemplate`].
+        RoleTemplate(Unregister<RoleTemplate>),
     }
 }
 
@@ -1151,6 +2239,8 @@ isi_box! {
         Asset(Transfer<Asset, Numeric, Account>),
         /// Transfer [`Nft`] to another [`Account`].
         Nft(Transfer<Account, NftId, Account>),
+        /// Transfer ownership of a [`Role`] to another [`Account`].
+        Role(Transfer<Account, RoleId, Account>),
     }
 }
 
@@ -1168,6 +2258,12 @@ isi_box! {
         Role(Grant<RoleId, Account>),
         /// Grant [`Permission`] to [`Role`].
         RolePermission(Grant<Permission, Role>),
+        /// Grant [`Role`] to [`Role`], making the latter inherit its permissions.
+        InheritedRole(Grant<RoleId, Role>),
+        /// Grant all [`Permission`]s of [`PermissionGroup`] to [`Account`].
+        PermissionGroup(Grant<PermissionGroup, Account>),
+        /// Grant [`Permission`] to [`Account`] until an event revoking it occurs.
+        RevocablePermission(Grant<RevocablePermission, Account>),
     }
 }
 
@@ -1185,6 +2281,12 @@ isi_box! {
         Role(Revoke<RoleId, Account>),
         /// Revoke [`Permission`] from [`Role`].
         RolePermission(Revoke<Permission, Role>),
+        /// Revoke all [`Permission`]s from [`Account`].
+        AllPermissions(RevokeAll),
+        /// Revoke inherited [`Role`] from [`Role`].
+        InheritedRole(Revoke<RoleId, Role>),
+        /// Revoke all [`Permission`]s of [`PermissionGroup`] from [`Account`].
+        PermissionGroup(Revoke<PermissionGroup, Account>),
     }
 }
 
@@ -1432,6 +2534,8 @@ pub mod error {
             Wasm(String),
             /// Attempt to register a time-trigger with `start` point in the past
             TimeTriggerInThePast,
+            /// Trigger would take part in an endless loop of triggers: {0}
+            TriggerLoop(crate::trigger::TriggerLoop),
         }
 
         /// Repetition of `{instruction}` for id `{id}`
@@ -1485,9 +2589,11 @@ pub mod error {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Burn, BurnBox, CustomInstruction, ExecuteTrigger, Grant, GrantBox, Instruction,
-        InstructionBox, Log, Mint, MintBox, Register, RegisterBox, RemoveKeyValue,
-        RemoveKeyValueBox, Revoke, RevokeBox, SetKeyValue, SetKeyValueBox, SetParameter, Transfer,
-        TransferBox, Unregister, UnregisterBox, Upgrade,
+        AbortExecutorUpgrade, AcceptDomainTransfer, Anchor, Burn, BurnBox, CustomInstruction,
+        ExecuteTrigger, Grant, GrantBox, InstantiateRoleTemplate, Instruction, InstructionBox, Log,
+        MarkRedactable, Mint, MintBox, Redact, Register, RegisterAccounts, RegisterBox,
+        RemoveKeyValue, RemoveKeyValueBox, Revoke, RevokeAll, RevokeBox, SetDomainValidator,
+        SetKeyValue, SetKeyValueBox, SetParameter, SetSpendingCounter, Transfer, TransferBox,
+        Unregister, UnregisterBox, Upgrade,
     };
 }
@@ -32,9 +32,11 @@ pub use self::model::*;
 use crate::name::Name;
 
 pub mod account;
+pub mod anchor;
 pub mod asset;
 pub mod block;
 pub mod domain;
+pub mod dry_run;
 pub mod events;
 pub mod executor;
 pub mod ipfs;
@@ -48,6 +50,8 @@ pub mod permission;
 pub mod query;
 pub mod role;
 pub mod smart_contract;
+pub mod spending;
+pub mod statement;
 pub mod transaction;
 pub mod trigger;
 pub mod visit;
@@ -88,6 +92,8 @@ mod seal {
         Register<Nft>,
         Register<Role>,
         Register<Trigger>,
+        RegisterAccounts,
+        Register<RoleTemplate>,
 
         Unregister<Peer>,
         Unregister<Domain>,
@@ -96,6 +102,7 @@ mod seal {
         Unregister<Nft>,
         Unregister<Role>,
         Unregister<Trigger>,
+        Unregister<RoleTemplate>,
 
         Mint<Numeric, Asset>,
         Mint<u32, Trigger>,
@@ -107,19 +114,34 @@ mod seal {
         Transfer<Account, AssetDefinitionId, Account>,
         Transfer<Asset, Numeric, Account>,
         Transfer<Account, NftId, Account>,
+        Transfer<Account, RoleId, Account>,
 
         Grant<Permission, Account>,
         Grant<RoleId, Account>,
         Grant<Permission, Role>,
+        Grant<RoleId, Role>,
+        Grant<PermissionGroup, Account>,
+        Grant<RevocablePermission, Account>,
 
         Revoke<Permission, Account>,
         Revoke<RoleId, Account>,
         Revoke<Permission, Role>,
+        RevokeAll,
+        Revoke<RoleId, Role>,
+        Revoke<PermissionGroup, Account>,
 
         SetParameter,
         Upgrade,
         ExecuteTrigger,
         Log,
+        Anchor,
+        InstantiateRoleTemplate,
+        SetDomainValidator,
+        MarkRedactable,
+        Redact,
+        AcceptDomainTransfer,
+        AbortExecutorUpgrade,
+        SetSpendingCounter,
 
         // Boxed queries
         SingularQueryBox,
@@ -133,6 +155,7 @@ mod seal {
         FindBlocks,
         FindBlockHeaders,
         FindTransactions,
+        FindExcludedTransactions,
         FindPermissionsByAccountId,
         FindExecutorDataModel,
         FindActiveTriggerIds,
@@ -141,6 +164,15 @@ mod seal {
         FindRoleIds,
         FindRolesByAccountId,
         FindParameters,
+        FindTriggerLoops,
+        FindAnchors,
+        FindAccountStatement,
+        FindRoleTemplate,
+        IsTransactionKnown,
+        FindPermissionGrantHistory,
+        FindPermissionSchemas,
+        AggregateQuery,
+        FindSpendingCounters,
     }
 }
 
@@ -154,6 +186,33 @@ pub struct ParseError {
 #[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
 
+/// Ids with a single canonical string form.
+///
+/// Different strings may parse into the same id: `rose#wonderland#alice@wonderland` and
+/// `rose##alice@wonderland` are the same asset, and public keys parse regardless of the case
+/// of their hex digits. Displaying a parsed id always gives its canonical form, which parses
+/// back into the same id.
+pub trait Normalize: core::str::FromStr + core::fmt::Display {
+    /// Parse `candidate` and return it in canonical form.
+    ///
+    /// # Errors
+    ///
+    /// If `candidate` is not a valid id.
+    fn normalize(candidate: &str) -> Result<String, Self::Err> {
+        candidate.parse::<Self>().map(|id| format!("{id}"))
+    }
+}
+
+impl Normalize for Name {}
+impl Normalize for domain::DomainId {}
+impl Normalize for account::AccountId {}
+impl Normalize for asset::AssetDefinitionId {}
+impl Normalize for asset::AssetId {}
+impl Normalize for nft::NftId {}
+impl Normalize for role::RoleId {}
+impl Normalize for trigger::TriggerId {}
+impl Normalize for peer::PeerId {}
+
 /// Error which occurs when converting an enum reference to a variant reference
 #[derive(Debug, Clone, Copy)]
 #[repr(transparent)]
@@ -409,6 +468,9 @@ impl Decode for ChainId {
     }
 }
 
+#[cfg(test)]
+mod golden;
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -518,11 +580,12 @@ pub mod prelude {
     };
 
     pub use super::{
-        account::prelude::*, asset::prelude::*, block::prelude::*, domain::prelude::*,
-        events::prelude::*, executor::prelude::*, ipfs::IpfsPath, isi::prelude::*,
-        metadata::prelude::*, name::prelude::*, nft::prelude::*, parameter::prelude::*,
-        peer::prelude::*, permission::prelude::*, query::prelude::*, role::prelude::*,
-        transaction::prelude::*, trigger::prelude::*, ChainId, EnumTryAsError, HasMetadata, IdBox,
-        Identifiable, Level, Registrable, ValidationFail,
+        account::prelude::*, anchor::prelude::*, asset::prelude::*, block::prelude::*,
+        domain::prelude::*, dry_run::prelude::*, events::prelude::*, executor::prelude::*,
+        ipfs::IpfsPath, isi::prelude::*, metadata::prelude::*, name::prelude::*, nft::prelude::*,
+        parameter::prelude::*, peer::prelude::*, permission::prelude::*, query::prelude::*,
+        role::prelude::*, spending::prelude::*, statement::prelude::*, transaction::prelude::*,
+        trigger::prelude::*, ChainId, EnumTryAsError, HasMetadata, IdBox, Identifiable, Level,
+        Normalize, Registrable, ValidationFail,
     };
 }
@@ -9,17 +9,20 @@ use iroha_data_model_derive::model;
 use iroha_primitives::json::Json;
 
 pub use self::model::*;
-use crate::{name::Name, Identifiable};
+use crate::{account::AccountId, domain::DomainId, name::Name, role::RoleId, Identifiable};
 
 /// Collection of [`CustomParameter`]s
 pub(crate) type CustomParameters = btree_map::BTreeMap<CustomParameterId, CustomParameter>;
 
+/// Collection of [`TransactionTtlBounds`] overridden for particular domains
+pub(crate) type DomainTransactionTtls = btree_map::BTreeMap<DomainId, TransactionTtlBounds>;
+
 #[model]
 mod model {
     #[cfg(not(feature = "std"))]
-    use alloc::collections::BTreeMap;
+    use alloc::collections::{BTreeMap, BTreeSet};
     #[cfg(feature = "std")]
-    use std::collections::BTreeMap;
+    use std::collections::{BTreeMap, BTreeSet};
 
     use derive_more::{Constructor, Display, FromStr};
     use getset::{CopyGetters, Getters};
@@ -137,6 +140,13 @@ mod model {
         /// A block is created if this limit is reached or [`SumeragiParameters::block_time_ms`] has expired,
         /// whichever comes first. Regardless of the limits, an empty block is never created.
         pub max_transactions: NonZeroU64,
+        /// Whether rejected transactions are left out of blocks.
+        ///
+        /// If set, the leader drops the transactions rejected during block creation,
+        /// recording only their hashes and rejection reasons in the block, and peers
+        /// refuse blocks with rejected transactions. Otherwise rejected transactions
+        /// are stored in blocks along with their rejection reasons.
+        pub exclude_rejected_transactions: bool,
     }
 
     /// Single block parameter
@@ -147,6 +157,7 @@ mod model {
     )]
     pub enum BlockParameter {
         MaxTransactions(NonZeroU64),
+        ExcludeRejectedTransactions(bool),
     }
 
     /// Limits that a transaction must obey to be accepted.
@@ -171,6 +182,14 @@ mod model {
         pub max_instructions: NonZeroU64,
         /// Maximum size of wasm binary in bytes
         pub smart_contract_size: NonZeroU64,
+        /// Minimal time-to-live (in milliseconds) a transaction is allowed to have
+        ///
+        /// Can be overridden for the transactions of a particular domain with [`DomainTransactionTtl`]
+        pub min_time_to_live_ms: u64,
+        /// Maximal time-to-live (in milliseconds) a transaction is allowed to have
+        ///
+        /// Can be overridden for the transactions of a particular domain with [`DomainTransactionTtl`]
+        pub max_time_to_live_ms: NonZeroU64,
     }
 
     /// Single transaction parameter
@@ -182,6 +201,93 @@ mod model {
     pub enum TransactionParameter {
         MaxInstructions(NonZeroU64),
         SmartContractSize(NonZeroU64),
+        MinTimeToLiveMs(u64),
+        MaxTimeToLiveMs(NonZeroU64),
+    }
+
+    /// Bounds of the time-to-live a transaction is allowed to have, in milliseconds.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        CopyGetters,
+        Encode,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{min_time_to_live_ms}..={max_time_to_live_ms}_TTL")]
+    #[getset(get_copy = "pub")]
+    pub struct TransactionTtlBounds {
+        /// Minimal time-to-live (in milliseconds)
+        pub min_time_to_live_ms: u64,
+        /// Maximal time-to-live (in milliseconds)
+        pub max_time_to_live_ms: NonZeroU64,
+    }
+
+    /// Transaction time-to-live bounds applied to transactions of accounts in `domain`
+    /// instead of the ones in [`TransactionParameters`]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    pub struct DomainTransactionTtl {
+        /// Domain whose accounts the bounds apply to
+        pub domain: DomainId,
+        /// Overridden bounds, `None` removes the override
+        pub bounds: Option<TransactionTtlBounds>,
+    }
+
+    /// Restrictions on who can upgrade the executor and when the upgrade takes effect.
+    ///
+    /// The restrictions are enforced by iroha itself, on top of the permission checks of the executor.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Default,
+        Getters,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct ExecutorUpgradeParameters {
+        /// Accounts allowed to upgrade the executor
+        ///
+        /// If both `accounts` and `roles` are empty, any account the executor permits can upgrade it
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub accounts: BTreeSet<AccountId>,
+        /// Roles whose holders are allowed to upgrade the executor
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub roles: BTreeSet<RoleId>,
+        /// Number of blocks between an upgrade and the activation of the new executor,
+        /// during which the upgrade can be aborted with [`AbortExecutorUpgrade`](crate::isi::AbortExecutorUpgrade)
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub delay_blocks: u64,
     }
 
     /// Limits that a smart contract must obey at runtime to considered valid.
@@ -281,6 +387,15 @@ mod model {
         #[serde(default)]
         #[serde(skip_serializing_if = "BTreeMap::is_empty")]
         pub custom: CustomParameters,
+        /// Transaction time-to-live bounds overridden for particular domains
+        #[getset(get = "pub")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        pub domain_transaction_ttl: DomainTransactionTtls,
+        /// Restrictions on upgrading the executor
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub executor_upgrade: ExecutorUpgradeParameters,
     }
 
     /// Single blockchain parameter.
@@ -308,6 +423,8 @@ mod model {
         SmartContract(SmartContractParameter),
         Executor(SmartContractParameter),
         Custom(CustomParameter),
+        DomainTransactionTtl(DomainTransactionTtl),
+        ExecutorUpgrade(ExecutorUpgradeParameters),
     }
 }
 
@@ -319,6 +436,17 @@ impl core::fmt::Display for Parameter {
             Self::Transaction(v) => core::fmt::Display::fmt(&v, f),
             Self::SmartContract(v) | Self::Executor(v) => core::fmt::Display::fmt(&v, f),
             Self::Custom(v) => write!(f, "{}({})", v.id, v.payload),
+            Self::DomainTransactionTtl(v) => match v.bounds {
+                Some(bounds) => write!(f, "{}({bounds})", v.domain),
+                None => write!(f, "{}(_TTL)", v.domain),
+            },
+            Self::ExecutorUpgrade(v) => write!(
+                f,
+                "ExecutorUpgrade({} accounts, {} roles, {} blocks delay)",
+                v.accounts.len(),
+                v.roles.len(),
+                v.delay_blocks
+            ),
         }
     }
 }
@@ -384,6 +512,9 @@ mod defaults {
         pub const fn max_transactions() -> NonZeroU64 {
             nonzero!(2_u64.pow(9))
         }
+        pub const fn exclude_rejected_transactions() -> bool {
+            false
+        }
     }
 
     pub mod transaction {
@@ -397,6 +528,12 @@ mod defaults {
         pub const fn smart_contract_size() -> NonZeroU64 {
             nonzero!(4 * 2_u64.pow(20))
         }
+        pub const fn min_time_to_live_ms() -> u64 {
+            0
+        }
+        pub const fn max_time_to_live_ms() -> NonZeroU64 {
+            NonZeroU64::MAX
+        }
     }
 
     pub mod smart_contract {
@@ -439,6 +576,16 @@ impl Default for TransactionParameters {
     }
 }
 
+impl Default for TransactionTtlBounds {
+    fn default() -> Self {
+        use defaults::transaction::*;
+        Self {
+            min_time_to_live_ms: min_time_to_live_ms(),
+            max_time_to_live_ms: max_time_to_live_ms(),
+        }
+    }
+}
+
 impl Default for SmartContractParameters {
     fn default() -> Self {
         use defaults::smart_contract::*;
@@ -474,6 +621,23 @@ impl Parameters {
                     .map(Parameter::SmartContract),
             )
             .chain(self.custom.values().cloned().map(Parameter::Custom))
+            .chain(self.domain_transaction_ttl.iter().map(|(domain, bounds)| {
+                Parameter::DomainTransactionTtl(DomainTransactionTtl {
+                    domain: domain.clone(),
+                    bounds: Some(*bounds),
+                })
+            }))
+            .chain(core::iter::once(Parameter::ExecutorUpgrade(
+                self.executor_upgrade.clone(),
+            )))
+    }
+
+    /// Time-to-live bounds for transactions of accounts in `domain`
+    pub fn transaction_ttl_bounds(&self, domain: &DomainId) -> TransactionTtlBounds {
+        self.domain_transaction_ttl
+            .get(domain)
+            .copied()
+            .unwrap_or_else(|| self.transaction.time_to_live_bounds())
     }
 
     /// Set `parameter` value to corresponding parameter in `self`
@@ -489,6 +653,17 @@ impl Parameters {
                     Parameter::Custom(next) => {
                         self.custom.insert(next.id.clone(), next);
                     }
+                    Parameter::DomainTransactionTtl(next) => match next.bounds {
+                        Some(bounds) => {
+                            self.domain_transaction_ttl.insert(next.domain, bounds);
+                        }
+                        None => {
+                            self.domain_transaction_ttl.remove(&next.domain);
+                        }
+                    },
+                    Parameter::ExecutorUpgrade(next) => {
+                        self.executor_upgrade = next;
+                    }
                 }
             };
         }
@@ -499,9 +674,12 @@ impl Parameters {
             Sumeragi(sumeragi.commit_time_ms) => SumeragiParameter::CommitTimeMs,
 
             Block(block.max_transactions) => BlockParameter::MaxTransactions,
+            Block(block.exclude_rejected_transactions) => BlockParameter::ExcludeRejectedTransactions,
 
             Transaction(transaction.max_instructions) => TransactionParameter::MaxInstructions,
             Transaction(transaction.smart_contract_size) => TransactionParameter::SmartContractSize,
+            Transaction(transaction.min_time_to_live_ms) => TransactionParameter::MinTimeToLiveMs,
+            Transaction(transaction.max_time_to_live_ms) => TransactionParameter::MaxTimeToLiveMs,
 
             SmartContract(smart_contract.fuel) => SmartContractParameter::Fuel,
             SmartContract(smart_contract.memory) => SmartContractParameter::Memory,
@@ -546,22 +724,61 @@ impl SumeragiParameters {
 
 impl BlockParameters {
     /// Construct [`Self`]
+    ///
+    /// Rejected transactions are kept in blocks, see [`Self::with_exclude_rejected_transactions`].
     pub const fn new(max_transactions: NonZeroU64) -> Self {
-        Self { max_transactions }
+        Self {
+            max_transactions,
+            exclude_rejected_transactions: defaults::block::exclude_rejected_transactions(),
+        }
+    }
+
+    /// Set whether rejected transactions are left out of blocks
+    #[must_use]
+    pub const fn with_exclude_rejected_transactions(
+        mut self,
+        exclude_rejected_transactions: bool,
+    ) -> Self {
+        self.exclude_rejected_transactions = exclude_rejected_transactions;
+        self
     }
 
     /// Convert [`Self`] into iterator of individual parameters
     pub fn parameters(&self) -> impl Iterator<Item = BlockParameter> {
-        [BlockParameter::MaxTransactions(self.max_transactions)].into_iter()
+        [
+            BlockParameter::MaxTransactions(self.max_transactions),
+            BlockParameter::ExcludeRejectedTransactions(self.exclude_rejected_transactions),
+        ]
+        .into_iter()
     }
 }
 
 impl TransactionParameters {
     /// Construct [`Self`]
+    ///
+    /// Transactions are allowed to have any time-to-live, see [`Self::with_time_to_live_bounds`].
     pub const fn new(max_instructions: NonZeroU64, smart_contract_size: NonZeroU64) -> Self {
         Self {
             max_instructions,
             smart_contract_size,
+            min_time_to_live_ms: defaults::transaction::min_time_to_live_ms(),
+            max_time_to_live_ms: defaults::transaction::max_time_to_live_ms(),
+        }
+    }
+
+    /// Set the bounds of the time-to-live a transaction is allowed to have
+    #[must_use]
+    pub const fn with_time_to_live_bounds(mut self, bounds: TransactionTtlBounds) -> Self {
+        self.min_time_to_live_ms = bounds.min_time_to_live_ms;
+        self.max_time_to_live_ms = bounds.max_time_to_live_ms;
+        self
+    }
+
+    /// Bounds of the time-to-live a transaction is allowed to have
+    pub const fn time_to_live_bounds(&self) -> TransactionTtlBounds {
+        TransactionTtlBounds {
+            min_time_to_live_ms: self.min_time_to_live_ms,
+            max_time_to_live_ms: self.max_time_to_live_ms,
         }
     }
 
@@ -570,11 +787,63 @@ impl TransactionParameters {
         [
             TransactionParameter::MaxInstructions(self.max_instructions),
             TransactionParameter::SmartContractSize(self.smart_contract_size),
+            TransactionParameter::MinTimeToLiveMs(self.min_time_to_live_ms),
+            TransactionParameter::MaxTimeToLiveMs(self.max_time_to_live_ms),
         ]
         .into_iter()
     }
 }
 
+impl TransactionTtlBounds {
+    /// Construct [`Self`]
+    ///
+    /// # Errors
+    /// If `min` is greater than `max` or `max` is zero
+    pub fn new(min: Duration, max: Duration) -> Result<Self, &'static str> {
+        let to_ms = |duration: Duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+        let max_time_to_live_ms =
+            NonZeroU64::new(to_ms(max)).ok_or("Maximal time-to-live must not be zero")?;
+
+        candidate::TransactionTtlBoundsCandidate {
+            min_time_to_live_ms: to_ms(min),
+            max_time_to_live_ms,
+        }
+        .validate()
+    }
+
+    /// Minimal time-to-live
+    pub fn min_time_to_live(&self) -> Duration {
+        Duration::from_millis(self.min_time_to_live_ms)
+    }
+
+    /// Maximal time-to-live
+    pub fn max_time_to_live(&self) -> Duration {
+        Duration::from_millis(self.max_time_to_live_ms.get())
+    }
+
+    /// Whether a transaction with the given time-to-live is within the bounds
+    pub fn contains(&self, time_to_live: Duration) -> bool {
+        (self.min_time_to_live()..=self.max_time_to_live()).contains(&time_to_live)
+    }
+}
+
+impl ExecutorUpgradeParameters {
+    /// Whether upgrading the executor is limited to particular accounts and roles
+    pub fn is_restricted(&self) -> bool {
+        !(self.accounts.is_empty() && self.roles.is_empty())
+    }
+
+    /// Whether [`Self`] allows anything `current` doesn't:
+    /// upgrades by other accounts or roles, or a shorter delay
+    pub fn loosens(&self, current: &Self) -> bool {
+        self.delay_blocks < current.delay_blocks
+            || (current.is_restricted()
+                && (!self.is_restricted()
+                    || !self.accounts.is_subset(&current.accounts)
+                    || !self.roles.is_subset(&current.roles)))
+    }
+}
+
 impl SmartContractParameters {
     /// Convert [`Self`] into iterator of individual parameters
     pub fn parameters(&self) -> impl Iterator<Item = SmartContractParameter> {
@@ -621,6 +890,8 @@ mod candidate {
     enum TransactionParameterCandidate {
         MaxInstructions(NonZeroU64),
         SmartContractSize(NonZeroU64),
+        MinTimeToLiveMs(u64),
+        MaxTimeToLiveMs(NonZeroU64),
     }
 
     #[derive(Decode, Deserialize)]
@@ -629,17 +900,32 @@ mod candidate {
         max_instructions: NonZeroU64,
         #[serde(default = "defaults::transaction::smart_contract_size")]
         smart_contract_size: NonZeroU64,
+        #[serde(default = "defaults::transaction::min_time_to_live_ms")]
+        min_time_to_live_ms: u64,
+        #[serde(default = "defaults::transaction::max_time_to_live_ms")]
+        max_time_to_live_ms: NonZeroU64,
+    }
+
+    #[derive(Decode, Deserialize)]
+    pub(super) struct TransactionTtlBoundsCandidate {
+        #[serde(default = "defaults::transaction::min_time_to_live_ms")]
+        pub(super) min_time_to_live_ms: u64,
+        #[serde(default = "defaults::transaction::max_time_to_live_ms")]
+        pub(super) max_time_to_live_ms: NonZeroU64,
     }
 
     #[derive(Decode, Deserialize)]
     enum BlockParameterCandidate {
         MaxTransactions(NonZeroU64),
+        ExcludeRejectedTransactions(bool),
     }
 
     #[derive(Decode, Deserialize)]
     struct BlockParametersCandidate {
         #[serde(default = "super::defaults::block::max_transactions")]
         max_transactions: NonZeroU64,
+        #[serde(default = "super::defaults::block::exclude_rejected_transactions")]
+        exclude_rejected_transactions: bool,
     }
 
     #[derive(Decode, Deserialize)]
@@ -668,6 +954,9 @@ mod candidate {
 
                     BlockParameter::MaxTransactions(max_transactions)
                 }
+                Self::ExcludeRejectedTransactions(exclude) => {
+                    BlockParameter::ExcludeRejectedTransactions(exclude)
+                }
             })
         }
     }
@@ -677,9 +966,8 @@ mod candidate {
             let _ = NonZeroUsize::try_from(self.max_transactions)
                 .map_err(|_| "BlockParameters::max_transactions exceeds usize::MAX")?;
 
-            Ok(BlockParameters {
-                max_transactions: self.max_transactions,
-            })
+            Ok(BlockParameters::new(self.max_transactions)
+                .with_exclude_rejected_transactions(self.exclude_rejected_transactions))
         }
     }
 
@@ -697,6 +985,12 @@ mod candidate {
                     })?;
                     TransactionParameter::SmartContractSize(smart_contract_size)
                 }
+                Self::MinTimeToLiveMs(min_time_to_live_ms) => {
+                    TransactionParameter::MinTimeToLiveMs(min_time_to_live_ms)
+                }
+                Self::MaxTimeToLiveMs(max_time_to_live_ms) => {
+                    TransactionParameter::MaxTimeToLiveMs(max_time_to_live_ms)
+                }
             })
         }
     }
@@ -709,9 +1003,28 @@ mod candidate {
             let _ = NonZeroUsize::try_from(self.smart_contract_size)
                 .map_err(|_| "TransactionParameters::smart_contract_size exceeds usize::MAX")?;
 
-            Ok(TransactionParameters {
-                max_instructions: self.max_instructions,
-                smart_contract_size: self.smart_contract_size,
+            let time_to_live = TransactionTtlBoundsCandidate {
+                min_time_to_live_ms: self.min_time_to_live_ms,
+                max_time_to_live_ms: self.max_time_to_live_ms,
+            }
+            .validate()?;
+
+            Ok(
+                TransactionParameters::new(self.max_instructions, self.smart_contract_size)
+                    .with_time_to_live_bounds(time_to_live),
+            )
+        }
+    }
+
+    impl TransactionTtlBoundsCandidate {
+        pub(super) fn validate(self) -> Result<TransactionTtlBounds, &'static str> {
+            if self.min_time_to_live_ms > self.max_time_to_live_ms.get() {
+                return Err("Minimal transaction time-to-live exceeds the maximal one");
+            }
+
+            Ok(TransactionTtlBounds {
+                min_time_to_live_ms: self.min_time_to_live_ms,
+                max_time_to_live_ms: self.max_time_to_live_ms,
             })
         }
     }
@@ -827,6 +1140,27 @@ mod candidate {
         }
     }
 
+    impl Decode for TransactionTtlBounds {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+            TransactionTtlBoundsCandidate::decode(input)?
+                .validate()
+                .map_err(Into::into)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TransactionTtlBounds {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error as _;
+
+            TransactionTtlBoundsCandidate::deserialize(deserializer)?
+                .validate()
+                .map_err(D::Error::custom)
+        }
+    }
+
     impl Decode for SmartContractParameter {
         fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
             SmartContractParameterCandidate::decode(input)?
@@ -870,5 +1204,7 @@ mod candidate {
 pub mod prelude {
     //! Prelude: re-export of most commonly used traits, structs and macros in this crate.
 
-    pub use super::{Parameter, Parameters, SmartContractParameters, TransactionParameters};
+    pub use super::{
+        Parameter, Parameters, SmartContractParameters, TransactionParameters, TransactionTtlBounds,
+    };
 }
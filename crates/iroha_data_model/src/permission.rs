@@ -9,6 +9,7 @@ use iroha_primitives::json::Json;
 use iroha_schema::{Ident, IntoSchema};
 
 pub use self::model::*;
+use crate::{account::AccountId, events::data::DataEventFilter, role::RoleId};
 
 /// Collection of [`Permission`]s
 pub type Permissions = BTreeSet<Permission>;
@@ -16,6 +17,8 @@ pub type Permissions = BTreeSet<Permission>;
 #[model]
 mod model {
     use derive_more::Display;
+    use getset::Getters;
+    use iroha_macro::FromVariant;
     use parity_scale_codec::{Decode, Encode};
     use serde::{Deserialize, Serialize};
 
@@ -47,6 +50,144 @@ mod model {
         /// the type defined in [`crate::executor::ExecutorDataModel`].
         pub payload: Json,
     }
+
+    /// Bundle of [`Permission`]s which are granted to or revoked from an account atomically.
+    ///
+    /// Unlike [`Role`](crate::role::Role), a group is not a registered entity with an owner:
+    /// the account holds the permissions directly, yet the group is never applied partially.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "GROUP OF {} PERMISSIONS", "self.permissions.len()")]
+    #[serde(transparent)]
+    #[ffi_type]
+    pub struct PermissionGroup {
+        /// Permissions of the group.
+        pub permissions: Permissions,
+    }
+
+    /// [`Permission`] which is revoked from the account automatically
+    /// as soon as an event matching [`revoke_on`](Self::revoke_on) occurs,
+    /// e.g. once the NFT the permission relates to is transferred away from the grantee.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{permission} UNTIL MATCHING EVENT")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct RevocablePermission {
+        /// Granted permission.
+        pub permission: Permission,
+        /// Condition on which the permission is revoked.
+        pub revoke_on: DataEventFilter,
+    }
+
+    /// Entity which holds granted [`Permission`]s.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        FromVariant,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub enum PermissionHolder {
+        /// Permission is granted to the account directly.
+        Account(AccountId),
+        /// Permission is granted to the role.
+        Role(RoleId),
+    }
+
+    /// Whether a [`Permission`] was granted or revoked.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    pub enum PermissionGrantAction {
+        /// Permission was granted.
+        Grant,
+        /// Permission was revoked.
+        Revoke,
+    }
+
+    /// Entry of the on-chain audit log of [`Permission`] changes.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{action} of {permission} to {target} by {author} at height {height}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct PermissionGrantEvent {
+        /// Account which submitted the instruction.
+        pub author: AccountId,
+        /// Account or role the permission was granted to or revoked from.
+        pub target: PermissionHolder,
+        /// Granted or revoked permission.
+        pub permission: Permission,
+        /// Whether the permission was granted or revoked.
+        #[getset(skip)]
+        pub action: PermissionGrantAction,
+        /// Height of the block containing the change.
+        #[getset(skip)]
+        pub height: u64,
+    }
 }
 
 impl Permission {
@@ -70,7 +211,53 @@ impl Permission {
     }
 }
 
+impl PermissionGroup {
+    /// Constructor
+    pub fn new(permissions: impl IntoIterator<Item = impl Into<Permission>>) -> Self {
+        Self {
+            permissions: permissions.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Add a permission to the group
+    #[must_use]
+    pub fn add_permission(mut self, permission: impl Into<Permission>) -> Self {
+        self.permissions.insert(permission.into());
+        self
+    }
+
+    /// Permissions of the group
+    pub fn permissions(&self) -> impl ExactSizeIterator<Item = &Permission> {
+        self.permissions.iter()
+    }
+}
+
+impl RevocablePermission {
+    /// Constructor
+    pub fn new(permission: impl Into<Permission>, revoke_on: impl Into<DataEventFilter>) -> Self {
+        Self {
+            permission: permission.into(),
+            revoke_on: revoke_on.into(),
+        }
+    }
+}
+
+impl PermissionGrantEvent {
+    /// Whether the permission was granted or revoked.
+    pub const fn action(&self) -> PermissionGrantAction {
+        self.action
+    }
+
+    /// Height of the block containing the change.
+    pub const fn height(&self) -> u64 {
+        self.height
+    }
+}
+
 pub mod prelude {
     //! The prelude re-exports most commonly used traits, structs and macros from this crate.
-    pub use super::Permission;
+    pub use super::{
+        Permission, PermissionGrantAction, PermissionGrantEvent, PermissionGroup, PermissionHolder,
+        RevocablePermission,
+    };
 }
@@ -8,6 +8,8 @@ use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use derive_where::derive_where;
+pub use iroha_data_model_derive::FromQueryRow;
+use iroha_primitives::numeric::Numeric;
 pub use iter::QueryIterator;
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
@@ -15,12 +17,12 @@ use serde::{Deserialize, Serialize};
 use crate::query::{
     builder::batch_downcast::HasTypedBatchIter,
     dsl::{
-        BaseProjector, CompoundPredicate, HasPrototype, IntoSelectorTuple, PredicateMarker,
-        SelectorMarker, SelectorTuple,
+        BaseProjector, CompoundPredicate, HasProjection, HasPrototype, IntoSelectorTuple,
+        PredicateMarker, SelectorMarker, SelectorTuple,
     },
-    parameters::{FetchSize, Pagination, QueryParams, Sorting},
-    Query, QueryBox, QueryOutputBatchBoxTuple, QueryWithFilter, QueryWithParams, SingularQueryBox,
-    SingularQueryOutputBox,
+    parameters::{FetchSize, Pagination, QueryParams, SortOrder, Sorting},
+    AggregateQuery, Aggregation, Query, QueryBox, QueryOutputBatchBoxTuple, QueryWithFilter,
+    QueryWithParams, SingularQueryBox, SingularQueryOutputBox,
 };
 
 /// A trait abstracting away concrete backend for executing queries against iroha.
@@ -107,6 +109,7 @@ where
     query: Q,
     filter: CompoundPredicate<Q::Item>,
     selector: SelectorTuple<Q::Item>,
+    sort_key: Option<SelectorTuple<Q::Item>>,
     pagination: Pagination,
     sorting: Sorting,
     fetch_size: FetchSize,
@@ -125,6 +128,7 @@ where
             query,
             filter: CompoundPredicate::PASS,
             selector: SelectorTuple::default(),
+            sort_key: None,
             pagination: Pagination::default(),
             sorting: Sorting::default(),
             fetch_size: FetchSize::default(),
@@ -171,7 +175,10 @@ where
 
     /// Return only the fields of the results specified by the given closure.
     ///
-    /// You can select multiple fields by returning a tuple from the closure.
+    /// You can select multiple fields by returning a tuple from the closure,
+    /// e.g. `.select_with(|asset| (asset.id, asset.value))`.
+    ///
+    /// The selection is applied by iroha, so the rest of the fields (like metadata) are never sent over the network.
     #[must_use]
     pub fn select_with<B, O>(self, f: B) -> QueryBuilder<'a, E, Q, O::SelectedTuple>
     where
@@ -195,6 +202,7 @@ where
             query: self.query,
             filter: self.filter,
             selector: new_selector,
+            sort_key: self.sort_key,
             pagination: self.pagination,
             sorting: self.sorting,
             fetch_size: self.fetch_size,
@@ -208,6 +216,37 @@ where
         Self { sorting, ..self }
     }
 
+    /// Sort the results by the fields specified by the given closure, in the given order.
+    ///
+    /// You can sort by multiple fields by returning a tuple from the closure,
+    /// e.g. `.sort_by(|asset| (asset.value, asset.id), SortOrder::Descending)`.
+    /// The fields are compared in the order of the tuple.
+    ///
+    /// The sorting is applied by iroha before pagination, so paginated results follow the requested order.
+    /// Results lacking a field (like a missing metadata key) are placed last.
+    #[must_use]
+    pub fn sort_by<B, O>(self, f: B, order: SortOrder) -> Self
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelectorTuple<SelectingType = Q::Item>,
+    {
+        Self {
+            sort_key: Some(f(Default::default()).into_selector_tuple()),
+            sorting: self.sorting.with_order(order),
+            ..self
+        }
+    }
+
     /// Only return part of the results specified by the pagination.
     #[must_use]
     pub fn with_pagination(self, pagination: Pagination) -> Self {
@@ -223,20 +262,14 @@ where
     }
 }
 
-impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+impl<'e, E, Q, T> QueryBuilder<'e, E, Q, T>
 where
     Q: Query,
-    E: QueryExecutor,
     QueryBox: From<QueryWithFilter<Q>>,
-    T: HasTypedBatchIter,
 {
-    /// Execute the query, returning an iterator over its results.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the query execution fails.
-    pub fn execute(self) -> Result<QueryIterator<E, T>, E::Error> {
-        let with_filter = QueryWithFilter::new(self.query, self.filter, self.selector);
+    fn into_query_with_params(self) -> (&'e E, QueryWithParams) {
+        let with_filter =
+            QueryWithFilter::new(self.query, self.filter, self.selector, self.sort_key);
         let boxed: QueryBox = with_filter.into();
 
         let query = QueryWithParams {
@@ -248,8 +281,26 @@ where
             },
         };
 
-        let (first_batch, remaining_items, continue_cursor) =
-            self.query_executor.start_query(query)?;
+        (self.query_executor, query)
+    }
+}
+
+impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+where
+    Q: Query,
+    E: QueryExecutor,
+    QueryBox: From<QueryWithFilter<Q>>,
+    T: HasTypedBatchIter,
+{
+    /// Execute the query, returning an iterator over its results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn execute(self) -> Result<QueryIterator<E, T>, E::Error> {
+        let (query_executor, query) = self.into_query_with_params();
+
+        let (first_batch, remaining_items, continue_cursor) = query_executor.start_query(query)?;
 
         let iterator = QueryIterator::<E, T>::new(first_batch, remaining_items, continue_cursor)
             .expect(
@@ -260,6 +311,210 @@ where
     }
 }
 
+impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+where
+    Q: Query,
+    E: QueryExecutor,
+    QueryBox: From<QueryWithFilter<Q>>,
+{
+    /// Execute the query, converting the results into `R`.
+    ///
+    /// Only the fields required by `R` are selected, so the rest are never sent by iroha.
+    /// Any selection made with [`Self::select_with`] is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn execute_as<R>(self) -> Result<impl Iterator<Item = Result<R, E::Error>>, E::Error>
+    where
+        R: FromQueryRow<Q::Item>,
+    {
+        let builder = QueryBuilder::<E, Q, R::Row> {
+            query_executor: self.query_executor,
+            query: self.query,
+            filter: self.filter,
+            selector: R::selector(),
+            sort_key: self.sort_key,
+            pagination: self.pagination,
+            sorting: self.sorting,
+            fetch_size: self.fetch_size,
+            phantom: PhantomData,
+        };
+
+        Ok(builder.execute()?.map(|row| row.map(R::from_row)))
+    }
+
+    /// Execute the query, converting all the results into `R` and collecting them into a vector.
+    ///
+    /// See [`Self::execute_as`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn execute_all_as<R>(self) -> Result<Vec<R>, E::Error>
+    where
+        R: FromQueryRow<Q::Item>,
+    {
+        self.execute_as()?.collect()
+    }
+}
+
+impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+where
+    Q: Query,
+    E: QueryExecutor,
+    QueryBox: From<QueryWithFilter<Q>>,
+{
+    /// Count the results of the query.
+    ///
+    /// The results are counted by iroha, so none of them are sent over the network.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn count(self) -> Result<u64, E::Error> {
+        let count = self
+            .aggregate(Aggregation::Count)?
+            .expect("INTERNAL BUG: iroha returned no count");
+
+        Ok(count
+            .try_into()
+            .expect("INTERNAL BUG: iroha returned fractional count"))
+    }
+
+    /// Sum the numeric field of the results specified by the given closure,
+    /// e.g. `.sum(|asset| asset.value)`.
+    ///
+    /// The sum is computed by iroha, so none of the results are sent over the network.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails or if the sum overflows.
+    pub fn sum<B, O>(self, f: B) -> Result<Numeric, E::Error>
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelectorTuple<SelectingType = Q::Item, SelectedTuple = Numeric>,
+    {
+        let sum = self.select_with(f).aggregate(Aggregation::Sum)?;
+
+        Ok(sum.unwrap_or(Numeric::ZERO))
+    }
+
+    /// Find the smallest value of the numeric field of the results specified by the given closure.
+    ///
+    /// Returns `None` if there are no results. See [`Self::sum`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn min<B, O>(self, f: B) -> Result<Option<Numeric>, E::Error>
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelectorTuple<SelectingType = Q::Item, SelectedTuple = Numeric>,
+    {
+        self.select_with(f).aggregate(Aggregation::Min)
+    }
+
+    /// Find the largest value of the numeric field of the results specified by the given closure.
+    ///
+    /// Returns `None` if there are no results. See [`Self::sum`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn max<B, O>(self, f: B) -> Result<Option<Numeric>, E::Error>
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelectorTuple<SelectingType = Q::Item, SelectedTuple = Numeric>,
+    {
+        self.select_with(f).aggregate(Aggregation::Max)
+    }
+
+    fn aggregate(self, aggregation: Aggregation) -> Result<Option<Numeric>, E::Error> {
+        let (query_executor, query) = self.into_query_with_params();
+        let output = query_executor
+            .execute_singular_query(AggregateQuery::new(query, aggregation).into())?;
+
+        Ok(output.try_into().expect(
+            "INTERNAL BUG: iroha returned unexpected type in aggregate query. Is there a schema mismatch?",
+        ))
+    }
+}
+
+/// A type that can be constructed from a subset of fields of a query result `Item`.
+///
+/// Usually derived with `#[derive(FromQueryRow)]` and used with [`QueryBuilder::execute_as`].
+///
+/// ```ignore
+/// #[derive(FromQueryRow)]
+/// #[query_row(item = Asset)]
+/// struct Balance {
+///     #[query_row(select = id.account)]
+///     owner: AccountId,
+///     value: Numeric,
+/// }
+///
+/// let balances: Vec<Balance> = client.query(FindAssets).execute_all_as()?;
+/// ```
+pub trait FromQueryRow<Item>: Sized
+where
+    Item: HasProjection<SelectorMarker, AtomType = ()>,
+{
+    /// Tuple of the selected fields
+    type Row: HasTypedBatchIter;
+
+    /// Selector of the fields making up [`Self::Row`].
+    fn selector() -> SelectorTuple<Item>;
+
+    /// Construct the value from the selected fields.
+    fn from_row(row: Self::Row) -> Self;
+}
+
+/// Build a selector for [`FromQueryRow::selector`], checking that it selects exactly `Row`.
+///
+/// Used by `#[derive(FromQueryRow)]`.
+pub fn row_selector<Item, Row, B, O>(f: B) -> SelectorTuple<Item>
+where
+    Item: HasPrototype + HasProjection<SelectorMarker, AtomType = ()>,
+    B: FnOnce(
+        <Item as HasPrototype>::Prototype<SelectorMarker, BaseProjector<SelectorMarker, Item>>,
+    ) -> O,
+    <Item as HasPrototype>::Prototype<SelectorMarker, BaseProjector<SelectorMarker, Item>>: Default,
+    O: IntoSelectorTuple<SelectingType = Item, SelectedTuple = Row>,
+{
+    SelectorTuple::build(f)
+}
+
 /// An extension trait for query builders that provides convenience methods to execute queries.
 pub trait QueryBuilderExt<E, Q, T>
 where
@@ -333,5 +588,5 @@ where
 
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::QueryBuilderExt;
+    pub use super::{FromQueryRow, QueryBuilderExt};
 }
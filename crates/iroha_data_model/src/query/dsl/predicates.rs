@@ -8,6 +8,7 @@ use iroha_primitives::{json::Json, numeric::Numeric};
 
 use crate::{
     account::{Account, AccountId},
+    anchor::AnchoredHash,
     asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
@@ -20,11 +21,12 @@ use crate::{
     query::{
         dsl::{
             type_descriptions::{
-                AccountIdPrototype, AccountPrototype, ActionPrototype, AssetDefinitionIdPrototype,
-                AssetDefinitionPrototype, AssetIdPrototype, AssetPrototype,
-                BlockHeaderHashPrototype, BlockHeaderPrototype, CommittedTransactionPrototype,
-                DomainIdPrototype, DomainPrototype, JsonPrototype, MetadataPrototype,
-                NamePrototype, NftIdPrototype, NftPrototype, NumericPrototype, ParameterPrototype,
+                AccountIdPrototype, AccountPrototype, ActionPrototype, AnchoredHashPrototype,
+                AssetDefinitionIdPrototype, AssetDefinitionPrototype, AssetIdPrototype,
+                AssetPrototype, BlockHeaderHashPrototype, BlockHeaderPrototype,
+                CommittedTransactionPrototype, DomainIdPrototype, DomainPrototype,
+                ExcludedTransactionPrototype, JsonPrototype, MetadataPrototype, NamePrototype,
+                NftIdPrototype, NftPrototype, NumericPrototype, ParameterPrototype,
                 PeerIdPrototype, PermissionPrototype, PublicKeyPrototype, RoleIdPrototype,
                 RolePrototype, SignedBlockPrototype, StringPrototype,
                 TransactionEntrypointHashPrototype, TransactionEntrypointPrototype,
@@ -33,7 +35,7 @@ use crate::{
             },
             CompoundPredicate, ObjectProjector, PredicateMarker,
         },
-        CommittedTransaction,
+        CommittedTransaction, ExcludedTransaction,
     },
     role::{Role, RoleId},
     transaction::{TransactionEntrypoint, TransactionResult},
@@ -256,8 +258,9 @@ where
 }
 
 impl_predicate_atom! {
-    MetadataPredicateAtom(_input: Metadata) [MetadataPrototype] {
-        // TODO: populate
+    MetadataPredicateAtom(input: Metadata) [MetadataPrototype] {
+        /// Checks if a string in any of the values of the input contains the expected substring.
+        ValueContains(expected: String) [value_contains] => input.iter().any(|(_, value)| value.string_values().iter().any(|string| string.contains(expected.as_str()))),
     }
     PublicKeyPredicateAtom(input: PublicKey) [PublicKeyPrototype] {
         /// Checks if the input is equal to the expected value.
@@ -266,6 +269,10 @@ impl_predicate_atom! {
     JsonPredicateAtom(input: Json) [JsonPrototype] {
         /// Checks if the input is equal to the expected value.
         Equals(expected: Json) [eq] => input == expected,
+        /// Checks if a string in the input contains the expected substring.
+        Contains(expected: String) [contains] => input.string_values().iter().any(|string| string.contains(expected.as_str())),
+        /// Checks if a string in the input matches the pattern, where `*` stands for any sequence of characters and `?` for any single character.
+        Matches(pattern: String) [matches] => input.string_values().iter().any(|string| matches_wildcard(pattern, string)),
     }
     NumericPredicateAtom(_input: Numeric) [NumericPrototype] {
         // TODO: populate
@@ -326,6 +333,7 @@ impl_predicate_atom! {
         ContainsDataTrigger(expected: TriggerId) [contains_data_trigger] => input.as_ref().is_ok_and(|sequence| sequence.iter().any(|step| step.id == *expected)),
     }
     CommittedTransactionPredicateAtom(_input: CommittedTransaction) [CommittedTransactionPrototype] {}
+    ExcludedTransactionPredicateAtom(_input: ExcludedTransaction) [ExcludedTransactionPrototype] {}
 
     // domain
     DomainPredicateAtom(_input: Domain) [DomainPrototype] {}
@@ -340,6 +348,9 @@ impl_predicate_atom! {
     // permission
     PermissionPredicateAtom(_input: Permission) [PermissionPrototype] {}
 
+    // anchor
+    AnchoredHashPredicateAtom(_input: AnchoredHash) [AnchoredHashPrototype] {}
+
     // parameter
     ParameterPredicateAtom(_input: Parameter) [ParameterPrototype] {}
 
@@ -359,18 +370,93 @@ impl_predicate_atom! {
     ActionPredicateAtom(_input: action::Action) [ActionPrototype] {}
 }
 
+impl MetadataPredicateAtom {
+    /// Substring which a string in the values of every input satisfying the predicate contains, if any.
+    pub fn required_substring(&self) -> Option<&str> {
+        match self {
+            Self::ValueContains(expected) => Some(expected.as_str()),
+        }
+    }
+}
+
+impl JsonPredicateAtom {
+    /// Substring which a string in every input satisfying the predicate contains, if any.
+    pub fn required_substring(&self) -> Option<&str> {
+        match self {
+            Self::Equals(_) => None,
+            Self::Contains(expected) => Some(expected.as_str()),
+            // the longest part of the pattern which is matched literally
+            Self::Matches(pattern) => pattern
+                .split(['*', '?'])
+                .max_by_key(|fragment| fragment.chars().count())
+                .filter(|fragment| !fragment.is_empty()),
+        }
+    }
+}
+
+/// Whether the whole `text` matches `pattern`, where `*` stands for any sequence of characters
+/// and `?` for any single character.
+fn matches_wildcard(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // position of the last `*` in the pattern and of the text it was matched against
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // let the last `*` consume one more character
+                Some((star, matched)) => {
+                    backtrack = Some((star, matched + 1));
+                    p = star + 1;
+                    t = matched + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 pub mod prelude {
     //! Re-export all predicate boxes for a glob import `(::*)`
     pub use super::{
         AccountIdPredicateAtom, AccountPredicateAtom, ActionPredicateAtom,
-        AssetDefinitionIdPredicateAtom, AssetDefinitionPredicateAtom, AssetIdPredicateAtom,
-        AssetPredicateAtom, BlockHeaderHashPredicateAtom, BlockHeaderPredicateAtom,
-        CommittedTransactionPredicateAtom, DomainIdPredicateAtom, DomainPredicateAtom,
-        JsonPredicateAtom, MetadataPredicateAtom, NftIdPredicateAtom, NftPredicateAtom,
-        NumericPredicateAtom, ParameterPredicateAtom, PeerIdPredicateAtom, PermissionPredicateAtom,
+        AnchoredHashPredicateAtom, AssetDefinitionIdPredicateAtom, AssetDefinitionPredicateAtom,
+        AssetIdPredicateAtom, AssetPredicateAtom, BlockHeaderHashPredicateAtom,
+        BlockHeaderPredicateAtom, CommittedTransactionPredicateAtom, DomainIdPredicateAtom,
+        DomainPredicateAtom, ExcludedTransactionPredicateAtom, JsonPredicateAtom,
+        MetadataPredicateAtom, NftIdPredicateAtom, NftPredicateAtom, NumericPredicateAtom,
+        ParameterPredicateAtom, PeerIdPredicateAtom, PermissionPredicateAtom,
         PublicKeyPredicateAtom, RoleIdPredicateAtom, RolePredicateAtom, SignedBlockPredicateAtom,
         StringPredicateAtom, TransactionEntrypointHashPredicateAtom,
         TransactionEntrypointPredicateAtom, TransactionResultHashPredicateAtom,
         TransactionResultPredicateAtom, TriggerIdPredicateAtom, TriggerPredicateAtom,
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::matches_wildcard;
+
+    #[test]
+    fn wildcard_pattern_matches_whole_text() {
+        assert!(matches_wildcard("rare*", "rare sword"));
+        assert!(matches_wildcard("*sword", "rare sword"));
+        assert!(matches_wildcard("r?re*w*d", "rare sword"));
+        assert!(matches_wildcard("*", ""));
+        assert!(!matches_wildcard("rare", "rare sword"));
+        assert!(!matches_wildcard("*shield*", "rare sword"));
+        assert!(!matches_wildcard("?", ""));
+    }
+}
@@ -14,6 +14,7 @@ use crate::query::dsl::{
 };
 use crate::{
     account::{Account, AccountId},
+    anchor::AnchoredHash,
     asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
@@ -25,7 +26,7 @@ use crate::{
     permission::Permission,
     query::{
         error::{FindError, QueryExecutionFail},
-        CommittedTransaction, QueryOutputBatchBox,
+        CommittedTransaction, ExcludedTransaction, QueryOutputBatchBox,
     },
     role::{Role, RoleId},
     transaction::{TransactionEntrypoint, TransactionResult},
@@ -306,6 +307,10 @@ type_descriptions! {
         result_hash(TransactionResultHash, CommittedTransactionResultHashProjector): HashOf<TransactionResult>,
         result(TransactionResult, CommittedTransactionResultProjector): TransactionResult,
     }
+    ExcludedTransaction[ExcludedTransactionProjection, ExcludedTransactionPrototype]: HashOf<BlockHeader>, HashOf<TransactionEntrypoint> {
+        block_hash(BlockHash, ExcludedTransactionBlockHashProjector): HashOf<BlockHeader>,
+        entrypoint_hash(TransactionEntrypointHash, ExcludedTransactionEntrypointHashProjector): HashOf<TransactionEntrypoint>,
+    }
 
     // domain
     Domain[DomainProjection, DomainPrototype]: DomainId, Name, Metadata, Json {
@@ -324,6 +329,9 @@ type_descriptions! {
     // permission
     Permission[PermissionProjection, PermissionPrototype] {}
 
+    // anchor
+    AnchoredHash[AnchoredHashProjection, AnchoredHashPrototype] {}
+
     // parameter
     Parameter[ParameterProjection, ParameterPrototype] {}
 
@@ -624,6 +632,18 @@ where
         MetadataProjection::Atom(atom)
     }
 }
+impl MetadataProjection<PredicateMarker> {
+    /// Substring which a string in the values of every metadata satisfying the predicate contains, if any.
+    pub fn required_substring(&self) -> Option<&str> {
+        match self {
+            MetadataProjection::Atom(atom) => atom.required_substring(),
+            MetadataProjection::Key(proj) => match &proj.projection {
+                JsonProjection::Atom(atom) => atom.required_substring(),
+            },
+        }
+    }
+}
+
 impl EvaluatePredicate<Metadata> for MetadataProjection<PredicateMarker> {
     fn applies(&self, input: &Metadata) -> bool {
         match self {
@@ -9,6 +9,10 @@ use alloc::{
     string::String,
     vec::{self, Vec},
 };
+use core::{
+    num::{NonZeroU32, NonZeroU64},
+    time::Duration,
+};
 #[cfg(feature = "std")]
 use std::vec;
 
@@ -25,11 +29,12 @@ use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
 use self::{
-    account::*, asset::*, block::*, domain::*, dsl::*, executor::*, nft::*, peer::*, permission::*,
-    role::*, transaction::*, trigger::*,
+    account::*, anchor::*, asset::*, block::*, domain::*, dsl::*, executor::*, nft::*, peer::*,
+    permission::*, role::*, transaction::*, trigger::*,
 };
 use crate::{
     account::{Account, AccountId},
+    anchor::AnchoredHash,
     asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
@@ -55,6 +60,7 @@ use crate::{
 pub mod builder;
 pub mod dsl;
 pub mod parameters;
+pub mod read_token;
 
 /// A query that either returns a single value or errors out
 // NOTE: we are planning to remove this class of queries (https://github.com/hyperledger-iroha/iroha/issues/4933)
@@ -82,7 +88,7 @@ mod model {
 
     use super::*;
     use crate::{
-        prelude::{TransactionEntrypoint, TransactionResult},
+        prelude::{TransactionEntrypoint, TransactionRejectionReason, TransactionResult},
         trigger::action,
     };
 
@@ -101,6 +107,11 @@ mod model {
         pub predicate: CompoundPredicate<Q::Item>,
         #[serde(default)]
         pub selector: SelectorTuple<Q::Item>,
+        /// Fields to sort the results by, compared in order of the tuple.
+        ///
+        /// Takes precedence over [`Sorting::sort_by_metadata_key`](parameters::Sorting).
+        #[serde(default)]
+        pub sort_key: Option<SelectorTuple<Q::Item>>,
     }
 
     fn predicate_default<T>() -> CompoundPredicate<T>
@@ -133,6 +144,8 @@ mod model {
         FindTransactions(QueryWithFilter<FindTransactions>),
         FindBlocks(QueryWithFilter<FindBlocks>),
         FindBlockHeaders(QueryWithFilter<FindBlockHeaders>),
+        FindAnchors(QueryWithFilter<FindAnchors>),
+        FindExcludedTransactions(QueryWithFilter<FindExcludedTransactions>),
     }
 
     /// An enum of all possible iterable query batches.
@@ -174,6 +187,8 @@ mod model {
         Block(Vec<SignedBlock>),
         BlockHeader(Vec<BlockHeader>),
         BlockHeaderHash(Vec<HashOf<BlockHeader>>),
+        AnchoredHash(Vec<AnchoredHash>),
+        ExcludedTransaction(Vec<ExcludedTransaction>),
     }
 
     #[derive(
@@ -190,6 +205,14 @@ mod model {
     pub enum SingularQueryBox {
         FindExecutorDataModel(FindExecutorDataModel),
         FindParameters(FindParameters),
+        FindTriggerLoops(FindTriggerLoops),
+        FindAccountStatement(FindAccountStatement),
+        FindRoleTemplate(FindRoleTemplate),
+        IsTransactionKnown(IsTransactionKnown),
+        FindPermissionGrantHistory(FindPermissionGrantHistory),
+        FindPermissionSchemas(FindPermissionSchemas),
+        Aggregate(AggregateQuery),
+        FindSpendingCounters(FindSpendingCounters),
     }
 
     /// An enum of all possible singular query outputs
@@ -199,6 +222,14 @@ mod model {
     pub enum SingularQueryOutputBox {
         ExecutorDataModel(crate::executor::ExecutorDataModel),
         Parameters(Parameters),
+        TriggerLoops(Vec<crate::trigger::TriggerLoop>),
+        AccountStatement(crate::statement::AccountStatement),
+        RoleTemplate(crate::role::RoleTemplate),
+        Bool(bool),
+        PermissionGrantHistory(Vec<crate::permission::PermissionGrantEvent>),
+        PermissionSchemas(Vec<crate::executor::PermissionTokenSchema>),
+        Aggregate(Option<Numeric>),
+        SpendingCounters(Vec<crate::spending::SpendingCounter>),
     }
 
     /// The results of a single iterable query request.
@@ -222,6 +253,32 @@ mod model {
         pub params: QueryParams,
     }
 
+    /// An iterable query whose results are reduced to a single value by the peer.
+    ///
+    /// Except for [`Aggregation::Count`], the query must select a single [`Numeric`] field.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub struct AggregateQuery {
+        pub query: QueryWithParams,
+        pub aggregation: Aggregation,
+    }
+
+    /// A way to reduce the results of an iterable query to a single value
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub enum Aggregation {
+        /// Number of the results
+        Count,
+        /// Sum of the results, zero if there are none
+        Sum,
+        /// Smallest of the results, if any
+        Min,
+        /// Largest of the results, if any
+        Max,
+    }
+
     /// A query request that can be sent to an Iroha peer.
     ///
     /// In case of HTTP API, the query request must also be signed (see [`QueryRequestWithAuthority`] and [`SignedQuery`]).
@@ -251,18 +308,43 @@ mod model {
     pub struct QueryRequestWithAuthority {
         pub authority: AccountId,
         pub request: QueryRequest,
+        /// Peers reject the query after this moment (unix time in milliseconds).
+        pub expires_at_ms: Option<NonZeroU64>,
+        /// Random value to tell apart identical queries, which peers otherwise reject as replayed.
+        pub nonce: Option<NonZeroU32>,
     }
 
-    /// A signature of [`QueryRequestWithAuthority`] to be used in [`SignedQueryV1`]
+    /// A signature of [`QueryRequestWithAuthority`] to be used in [`SignedQueryV2`]
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
     pub struct QuerySignature(pub SignatureOf<QueryRequestWithAuthority>);
 
-    declare_versioned!(SignedQuery 1..2, Debug, Clone, FromVariant, IntoSchema);
+    /// [`QueryRequestWithAuthority`] as signed by clients before queries had an expiry and nonce.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct QueryRequestWithAuthorityV1 {
+        pub authority: AccountId,
+        pub request: QueryRequest,
+    }
+
+    /// A signature of [`QueryRequestWithAuthorityV1`] to be used in [`SignedQueryV1`]
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct QuerySignatureV1(pub SignatureOf<QueryRequestWithAuthorityV1>);
 
-    /// A signed and authorized query request
+    declare_versioned!(SignedQuery 1..3, Debug, Clone, FromVariant, IntoSchema);
+
+    /// A signed and authorized query request in the format of the previous release.
+    ///
+    /// Peers still accept it, so that clients can be upgraded after the network.
     #[derive(Debug, Clone, Encode, Serialize, IntoSchema)]
     #[version_with_scale(version = 1, versioned_alias = "SignedQuery")]
     pub struct SignedQueryV1 {
+        pub signature: QuerySignatureV1,
+        pub payload: QueryRequestWithAuthorityV1,
+    }
+
+    /// A signed and authorized query request
+    #[derive(Debug, Clone, Encode, Serialize, IntoSchema)]
+    #[version_with_scale(version = 2, versioned_alias = "SignedQuery")]
+    pub struct SignedQueryV2 {
         pub signature: QuerySignature,
         pub payload: QueryRequestWithAuthority,
     }
@@ -300,6 +382,32 @@ mod model {
         /// The result of executing the transaction (trigger sequence or rejection).
         pub result: TransactionResult,
     }
+
+    /// Response returned by [`FindExcludedTransactions`] query.
+    #[derive(
+        Debug,
+        Clone,
+        PartialOrd,
+        Ord,
+        PartialEq,
+        Eq,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct ExcludedTransaction {
+        /// Hash of the block the transaction was left out of.
+        pub block_hash: HashOf<BlockHeader>,
+        /// Hash of the transaction entrypoint.
+        pub entrypoint_hash: HashOf<TransactionEntrypoint>,
+        /// Why the transaction was rejected.
+        pub reason: TransactionRejectionReason,
+    }
 }
 
 #[cfg(feature = "fault_injection")]
@@ -382,6 +490,8 @@ impl QueryOutputBatchBox {
             (Self::Block(v1), Self::Block(v2)) => v1.extend(v2),
             (Self::BlockHeader(v1), Self::BlockHeader(v2)) => v1.extend(v2),
             (Self::BlockHeaderHash(v1), Self::BlockHeaderHash(v2)) => v1.extend(v2),
+            (Self::AnchoredHash(v1), Self::AnchoredHash(v2)) => v1.extend(v2),
+            (Self::ExcludedTransaction(v1), Self::ExcludedTransaction(v2)) => v1.extend(v2),
             _ => panic!("Cannot extend different types of IterableQueryOutputBatchBox"),
         }
     }
@@ -422,6 +532,8 @@ impl QueryOutputBatchBox {
             Self::Block(v) => v.len(),
             Self::BlockHeader(v) => v.len(),
             Self::BlockHeaderHash(v) => v.len(),
+            Self::AnchoredHash(v) => v.len(),
+            Self::ExcludedTransaction(v) => v.len(),
         }
     }
 }
@@ -512,18 +624,44 @@ impl QueryRequest {
         QueryRequestWithAuthority {
             authority,
             request: self,
+            expires_at_ms: None,
+            nonce: None,
         }
     }
 }
 
 impl QueryRequestWithAuthority {
+    /// Set the moment after which peers reject this query, as a duration since unix epoch
+    #[must_use]
+    pub fn with_expiry(mut self, expires_at: Duration) -> Self {
+        let expires_at_ms: u64 = expires_at
+            .as_millis()
+            .try_into()
+            .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX");
+        self.expires_at_ms = NonZeroU64::new(expires_at_ms);
+        self
+    }
+
+    /// Set nonce for this query
+    #[must_use]
+    pub fn with_nonce(mut self, nonce: NonZeroU32) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Moment after which peers reject this query, as a duration since unix epoch
+    pub fn expires_at(&self) -> Option<Duration> {
+        self.expires_at_ms
+            .map(|expires_at_ms| Duration::from_millis(expires_at_ms.get()))
+    }
+
     /// Sign this [`QueryRequestWithAuthority`], creating a [`SignedQuery`]
     #[inline]
     #[must_use]
     pub fn sign(self, key_pair: &iroha_crypto::KeyPair) -> SignedQuery {
         let signature = SignatureOf::new(key_pair.private_key(), &self);
 
-        SignedQueryV1 {
+        SignedQueryV2 {
             signature: QuerySignature(signature),
             payload: self,
         }
@@ -531,17 +669,35 @@ impl QueryRequestWithAuthority {
     }
 }
 
+impl From<QueryRequestWithAuthorityV1> for QueryRequestWithAuthority {
+    fn from(query: QueryRequestWithAuthorityV1) -> Self {
+        query.request.with_authority(query.authority)
+    }
+}
+
 impl SignedQuery {
     /// Get authority that has signed this query
     pub fn authority(&self) -> &AccountId {
-        let SignedQuery::V1(query) = self;
-        &query.payload.authority
+        match self {
+            SignedQuery::V1(query) => &query.payload.authority,
+            SignedQuery::V2(query) => &query.payload.authority,
+        }
     }
 
     /// Get the request that was signed
     pub fn request(&self) -> &QueryRequest {
-        let SignedQuery::V1(query) = self;
-        &query.payload.request
+        match self {
+            SignedQuery::V1(query) => &query.payload.request,
+            SignedQuery::V2(query) => &query.payload.request,
+        }
+    }
+
+    /// Get the signed payload, upgrading it from the format of the previous release if needed
+    pub fn into_payload(self) -> QueryRequestWithAuthority {
+        match self {
+            SignedQuery::V1(query) => query.payload.into(),
+            SignedQuery::V2(query) => query.payload,
+        }
     }
 }
 
@@ -550,62 +706,76 @@ mod candidate {
 
     use super::*;
 
-    #[derive(Decode, Deserialize)]
-    struct SignedQueryCandidate {
-        signature: QuerySignature,
-        payload: QueryRequestWithAuthority,
-    }
+    /// Verify the signature of a signed query on decoding
+    macro_rules! signed_query_candidate {
+        ($candidate:ident => $signed:ident { $signature:ident, $payload:ty }) => {
+            #[derive(Decode, Deserialize)]
+            struct $candidate {
+                signature: $signature,
+                payload: $payload,
+            }
 
-    impl SignedQueryCandidate {
-        fn validate(self) -> Result<SignedQueryV1, &'static str> {
-            #[cfg(not(target_family = "wasm"))]
-            {
-                let QuerySignature(signature) = &self.signature;
-                signature
-                    .verify(&self.payload.authority.signatory, &self.payload)
-                    .map_err(|_| "Query request signature is not valid")?;
+            impl $candidate {
+                fn validate(self) -> Result<$signed, &'static str> {
+                    #[cfg(not(target_family = "wasm"))]
+                    {
+                        let $signature(signature) = &self.signature;
+                        signature
+                            .verify(&self.payload.authority.signatory, &self.payload)
+                            .map_err(|_| "Query request signature is not valid")?;
+                    }
+
+                    Ok($signed {
+                        payload: self.payload,
+                        signature: self.signature,
+                    })
+                }
             }
 
-            Ok(SignedQueryV1 {
-                payload: self.payload,
-                signature: self.signature,
-            })
-        }
-    }
+            impl Decode for $signed {
+                fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+                    $candidate::decode(input)?.validate().map_err(Into::into)
+                }
+            }
 
-    impl Decode for SignedQueryV1 {
-        fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
-            SignedQueryCandidate::decode(input)?
-                .validate()
-                .map_err(Into::into)
-        }
+            impl<'de> Deserialize<'de> for $signed {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    use serde::de::Error as _;
+
+                    $candidate::deserialize(deserializer)?
+                        .validate()
+                        .map_err(D::Error::custom)
+                }
+            }
+        };
     }
 
-    impl<'de> Deserialize<'de> for SignedQueryV1 {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: serde::Deserializer<'de>,
-        {
-            use serde::de::Error as _;
-
-            SignedQueryCandidate::deserialize(deserializer)?
-                .validate()
-                .map_err(D::Error::custom)
-        }
-    }
+    signed_query_candidate!(SignedQueryCandidateV1 => SignedQueryV1 {
+        QuerySignatureV1,
+        QueryRequestWithAuthorityV1
+    });
+    signed_query_candidate!(SignedQueryCandidate => SignedQueryV2 {
+        QuerySignature,
+        QueryRequestWithAuthority
+    });
 
     #[cfg(test)]
     mod tests {
         use std::sync::LazyLock;
 
-        use iroha_crypto::KeyPair;
+        use iroha_crypto::{KeyPair, SignatureOf};
+        use iroha_version::prelude::*;
         use parity_scale_codec::{DecodeAll, Encode};
 
         use crate::{
             account::AccountId,
             query::{
                 candidate::SignedQueryCandidate, FindExecutorDataModel, QueryRequest,
-                QuerySignature, SignedQuery, SingularQueryBox,
+                QueryRequestWithAuthorityV1, QuerySignature, QuerySignatureV1, SignedQuery,
+                SignedQueryV1, SingularQueryBox,
             },
         };
 
@@ -640,7 +810,7 @@ mod candidate {
 
         #[test]
         fn valid() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
+            let SignedQuery::V2(signed_query) = QueryRequest::Singular(
                 SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
             )
             .with_authority(ALICE_ID.clone())
@@ -656,7 +826,7 @@ mod candidate {
 
         #[test]
         fn invalid_signature() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
+            let SignedQuery::V2(signed_query) = QueryRequest::Singular(
                 SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
             )
             .with_authority(ALICE_ID.clone())
@@ -681,7 +851,7 @@ mod candidate {
 
         #[test]
         fn mismatching_authority() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
+            let SignedQuery::V2(signed_query) = QueryRequest::Singular(
                 SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
             )
             // signing with a wrong key here
@@ -698,6 +868,31 @@ mod candidate {
                 "Query request signature is not valid"
             );
         }
+
+        #[test]
+        fn previous_version_is_accepted() {
+            let payload = QueryRequestWithAuthorityV1 {
+                authority: ALICE_ID.clone(),
+                request: QueryRequest::Singular(SingularQueryBox::FindExecutorDataModel(
+                    FindExecutorDataModel,
+                )),
+            };
+            let legacy = SignedQuery::from(SignedQueryV1 {
+                signature: QuerySignatureV1(SignatureOf::new(
+                    ALICE_KEYPAIR.private_key(),
+                    &payload,
+                )),
+                payload,
+            });
+
+            let decoded = SignedQuery::decode_all_versioned(&legacy.encode_versioned()).unwrap();
+            assert!(matches!(decoded, SignedQuery::V1(_)));
+
+            let payload = decoded.into_payload();
+            assert_eq!(payload.authority, *ALICE_ID);
+            assert_eq!(payload.expires_at_ms, None);
+            assert_eq!(payload.nonce, None);
+        }
     }
 }
 
@@ -745,14 +940,24 @@ impl_iter_queries! {
     FindActiveTriggerIds => crate::trigger::TriggerId,
     FindTriggers => crate::trigger::Trigger,
     FindTransactions => CommittedTransaction,
+    FindExcludedTransactions => ExcludedTransaction,
     FindAccountsWithAsset => crate::account::Account,
     FindBlockHeaders => crate::block::BlockHeader,
     FindBlocks => SignedBlock,
+    FindAnchors => crate::anchor::AnchoredHash,
 }
 
 impl_singular_queries! {
     FindParameters => crate::parameter::Parameters,
     FindExecutorDataModel => crate::executor::ExecutorDataModel,
+    FindTriggerLoops => Vec<crate::trigger::TriggerLoop>,
+    FindAccountStatement => crate::statement::AccountStatement,
+    FindRoleTemplate => crate::role::RoleTemplate,
+    IsTransactionKnown => bool,
+    FindPermissionGrantHistory => Vec<crate::permission::PermissionGrantEvent>,
+    FindPermissionSchemas => Vec<crate::executor::PermissionTokenSchema>,
+    AggregateQuery => Option<Numeric>,
+    FindSpendingCounters => Vec<crate::spending::SpendingCounter>,
 }
 
 /// A macro reducing boilerplate when defining query types.
@@ -809,11 +1014,22 @@ pub mod role {
             /// `Id` of an account to find.
             pub id: AccountId,
         }
+
+        /// [`FindRoleTemplate`] Iroha Query finds the [`RoleTemplate`] with the given id.
+        #[derive(Display)]
+        #[display(fmt = "Find `{id}` role template")]
+        #[repr(transparent)]
+        // SAFETY: `FindRoleTemplate` has no trap representation in `RoleId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindRoleTemplate {
+            /// `Id` of the role template to find.
+            pub id: RoleId,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this module.
     pub mod prelude {
-        pub use super::{FindRoleIds, FindRoles, FindRolesByAccountId};
+        pub use super::{FindRoleIds, FindRoleTemplate, FindRoles, FindRolesByAccountId};
     }
 }
 
@@ -824,6 +1040,7 @@ pub mod permission {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use iroha_schema::Ident;
 
     use crate::prelude::*;
 
@@ -839,11 +1056,49 @@ pub mod permission {
             /// `Id` of an account to find.
             pub id: AccountId,
         }
+
+        /// [`FindPermissionGrantHistory`] Iroha Query lists [`PermissionGrantEvent`]s, oldest first.
+        ///
+        /// Only the changes made in a limited number of the latest blocks are retained by peers,
+        /// earlier ones are pruned.
+        #[derive(Display)]
+        #[display(fmt = "Find permission grant history")]
+        #[ffi_type]
+        pub struct FindPermissionGrantHistory {
+            /// If set, only changes authored by or targeting this account are listed.
+            pub account: Option<AccountId>,
+            /// If set, only changes of permissions with this name are listed.
+            pub permission: Option<Ident>,
+        }
+    }
+
+    impl FindPermissionGrantHistory {
+        /// History of all permission changes.
+        pub const fn all() -> Self {
+            Self {
+                account: None,
+                permission: None,
+            }
+        }
+
+        /// Only list changes authored by or targeting `account`.
+        #[must_use]
+        pub fn for_account(mut self, account: AccountId) -> Self {
+            self.account = Some(account);
+            self
+        }
+
+        /// Only list changes of permissions named `permission`.
+        #[must_use]
+        pub fn for_permission(mut self, permission: Ident) -> Self {
+            self.permission = Some(permission);
+            self
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this module.
     pub mod prelude {
-        pub use super::FindPermissionsByAccountId;
+        pub use super::{FindPermissionGrantHistory, FindPermissionsByAccountId};
     }
 }
 
@@ -876,11 +1131,67 @@ pub mod account {
             /// `Id` of the definition of the asset which should be stored in founded accounts.
             pub asset_definition: AssetDefinitionId,
         }
+
+        /// [`FindAccountStatement`] Iroha Query produces the [`AccountStatement`] of an account
+        /// for the period from `since_ms` (inclusive) to `until_ms` (exclusive),
+        /// both given as Unix time in milliseconds.
+        ///
+        /// Peers retain the movements of a limited number of the latest blocks only,
+        /// so the period of the statement starts later if earlier movements were pruned.
+        #[derive(Display)]
+        #[display(fmt = "Find statement of `{account}` account")]
+        #[ffi_type]
+        pub struct FindAccountStatement {
+            /// `Id` of the account.
+            pub account: AccountId,
+            /// Start of the period (inclusive), as Unix time in milliseconds.
+            pub since_ms: u64,
+            /// End of the period (exclusive), as Unix time in milliseconds.
+            pub until_ms: u64,
+        }
+
+        /// [`FindSpendingCounters`] Iroha Query finds the [`SpendingCounter`]s of an account.
+        #[derive(Display)]
+        #[display(fmt = "Find spending counters of `{account}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindSpendingCounters` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindSpendingCounters {
+            /// `Id` of the account the assets are transferred from.
+            pub account: AccountId,
+        }
+    }
+
+    impl FindAccountStatement {
+        /// Statement of `account` for the period from `since` (inclusive) to `until` (exclusive).
+        ///
+        /// # Panics
+        ///
+        /// If the given time in milliseconds doesn't fit into [`u64`].
+        pub fn period(
+            account: AccountId,
+            since: core::time::Duration,
+            until: core::time::Duration,
+        ) -> Self {
+            let to_ms = |time: core::time::Duration| {
+                time.as_millis()
+                    .try_into()
+                    .expect("Unix time in milliseconds should fit into u64")
+            };
+
+            Self {
+                account,
+                since_ms: to_ms(since),
+                until_ms: to_ms(until),
+            }
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAccounts, FindAccountsWithAsset};
+        pub use super::{
+            FindAccountStatement, FindAccounts, FindAccountsWithAsset, FindSpendingCounters,
+        };
     }
 }
 
@@ -981,6 +1292,54 @@ pub mod peer {
     }
 }
 
+pub mod anchor {
+    //! Queries related to [`crate::anchor`].
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    use crate::prelude::*;
+
+    queries! {
+        /// [`FindAnchors`] Iroha Query finds anchored content hashes,
+        /// optionally narrowed down to a single hash and/or tag.
+        #[derive(Default, Display)]
+        #[display(fmt = "Find anchored hashes")]
+        #[ffi_type]
+        pub struct FindAnchors {
+            /// Only find the anchor of this hash.
+            pub hash: Option<Hash>,
+            /// Only find anchors with this tag.
+            pub tag: Option<Name>,
+        }
+    }
+
+    impl FindAnchors {
+        /// Find the anchor of the given hash.
+        pub fn by_hash(hash: Hash) -> Self {
+            Self {
+                hash: Some(hash),
+                tag: None,
+            }
+        }
+
+        /// Find all anchors with the given tag.
+        pub fn by_tag(tag: Name) -> Self {
+            Self {
+                hash: None,
+                tag: Some(tag),
+            }
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindAnchors;
+    }
+}
+
 pub mod executor {
     //! Queries related to [`crate::executor`].
 
@@ -1001,11 +1360,18 @@ pub mod executor {
         #[display(fmt = "Find all peers parameters")]
         #[ffi_type]
         pub struct FindParameters;
+
+        /// [`FindPermissionSchemas`] Iroha Query finds the schemas of the permission tokens
+        /// accepted by the current executor, ordered by token name.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find permission schemas")]
+        #[ffi_type]
+        pub struct FindPermissionSchemas;
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindExecutorDataModel, FindParameters};
+        pub use super::{FindExecutorDataModel, FindParameters, FindPermissionSchemas};
     }
 }
 
@@ -1029,11 +1395,17 @@ pub mod trigger {
         #[display(fmt = "Find all triggers")]
         #[ffi_type]
         pub struct FindTriggers;
+
+        /// Find loops of data triggers which may keep triggering each other.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all trigger loops")]
+        #[ffi_type]
+        pub struct FindTriggerLoops;
     }
 
     pub mod prelude {
         //! Prelude Re-exports most commonly used traits, structs and macros from this crate.
-        pub use super::{FindActiveTriggerIds, FindTriggers};
+        pub use super::{FindActiveTriggerIds, FindTriggerLoops, FindTriggers};
     }
 }
 
@@ -1046,18 +1418,44 @@ pub mod transaction {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use iroha_crypto::HashOf;
+
+    use crate::transaction::SignedTransaction;
 
     queries! {
         /// [`FindTransactions`] Iroha Query lists all transactions included in a blockchain
+        ///
+        /// Rejected transactions left out of blocks under
+        /// [`BlockParameters::exclude_rejected_transactions`](crate::parameter::BlockParameters::exclude_rejected_transactions)
+        /// are listed by [`FindExcludedTransactions`] instead.
         #[derive(Copy, Display)]
         #[display(fmt = "Find all transactions")]
         #[ffi_type]
         pub struct FindTransactions;
+
+        /// [`FindExcludedTransactions`] Iroha Query lists the hashes and rejection reasons
+        /// of all transactions left out of blocks
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all excluded transactions")]
+        #[ffi_type]
+        pub struct FindExcludedTransactions;
+
+        /// [`IsTransactionKnown`] Iroha Query checks whether the transaction with the given hash
+        /// is committed, so it can be told apart from the one which is safe to resubmit
+        #[derive(Display)]
+        #[display(fmt = "Is `{hash}` transaction known")]
+        #[repr(transparent)]
+        // SAFETY: `IsTransactionKnown` has no trap representation in `HashOf<SignedTransaction>`
+        #[ffi_type(unsafe {robust})]
+        pub struct IsTransactionKnown {
+            /// Hash of the transaction to look for.
+            pub hash: HashOf<SignedTransaction>,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::FindTransactions;
+        pub use super::{FindExcludedTransactions, FindTransactions, IsTransactionKnown};
     }
 }
 
@@ -1150,6 +1548,8 @@ pub mod error {
             InvalidSingularParameters,
             /// Reached the limit of parallel queries. Either wait for previous queries to complete, or increase the limit in the config.
             CapacityLimit,
+            /// Sum of the query results overflows
+            AggregateOverflow,
         }
 
         /// Type assertion error
@@ -1197,6 +1597,8 @@ pub mod error {
             Permission(Box<Permission>),
             /// Failed to find public key: `{0}`
             PublicKey(PublicKey),
+            /// Role template with id `{0}` not found
+            RoleTemplate(RoleId),
         }
     }
 }
@@ -1205,10 +1607,11 @@ pub mod error {
 #[allow(ambiguous_glob_reexports)]
 pub mod prelude {
     pub use super::{
-        account::prelude::*, asset::prelude::*, block::prelude::*, builder::prelude::*,
-        domain::prelude::*, dsl::prelude::*, executor::prelude::*, nft::prelude::*,
-        parameters::prelude::*, peer::prelude::*, permission::prelude::*, role::prelude::*,
-        transaction::prelude::*, trigger::prelude::*, CommittedTransaction, QueryBox, QueryRequest,
+        account::prelude::*, anchor::prelude::*, asset::prelude::*, block::prelude::*,
+        builder::prelude::*, domain::prelude::*, dsl::prelude::*, executor::prelude::*,
+        nft::prelude::*, parameters::prelude::*, peer::prelude::*, permission::prelude::*,
+        role::prelude::*, transaction::prelude::*, trigger::prelude::*, AggregateQuery,
+        Aggregation, CommittedTransaction, ExcludedTransaction, QueryBox, QueryRequest,
         SingularQueryBox,
     };
 }
@@ -86,6 +86,33 @@ mod model {
     pub struct Sorting {
         /// Sort query result using [`Name`] of the key in metadata.
         pub sort_by_metadata_key: Option<Name>,
+        /// Direction in which the results are sorted.
+        #[serde(default)]
+        pub order: SortOrder,
+    }
+
+    /// Direction of sorting.
+    ///
+    /// Results that lack the sorting key are placed last regardless of the direction.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum SortOrder {
+        /// From the smallest key to the largest
+        #[default]
+        Ascending,
+        /// From the largest key to the smallest
+        Descending,
     }
 
     /// Structure for query fetch size parameter encoding/decoding
@@ -136,11 +163,18 @@ impl Sorting {
     pub fn by_metadata_key(key: Name) -> Self {
         Self {
             sort_by_metadata_key: Some(key),
+            order: SortOrder::default(),
         }
     }
+
+    /// Sets the direction of sorting.
+    #[must_use]
+    pub fn with_order(self, order: SortOrder) -> Self {
+        Self { order, ..self }
+    }
 }
 
 pub mod prelude {
     //! Prelude: re-export most commonly used traits, structs and macros from this module.
-    pub use super::{FetchSize, Pagination, Sorting};
+    pub use super::{FetchSize, Pagination, SortOrder, Sorting};
 }
@@ -0,0 +1,169 @@
+//! Read tokens: queries signed by an account for anyone holding the token to execute.
+//!
+//! A read token is meant to be shared, e.g. as a capability URL, to give a third party
+//! without an account access to exactly one query until the token expires.
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::{fmt, num::NonZeroU64, str::FromStr, time::Duration};
+
+use base64::Engine as _;
+use iroha_crypto::SignatureOf;
+use iroha_data_model_derive::model;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use super::QueryRequest;
+use crate::{account::AccountId, ChainId, ParseError};
+
+#[model]
+mod model {
+    use super::*;
+
+    /// Query signed by an account for anyone holding the token to execute.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct ReadToken {
+        pub signature: SignatureOf<ReadTokenPayload>,
+        pub payload: ReadTokenPayload,
+    }
+
+    /// Payload of [`ReadToken`]
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct ReadTokenPayload {
+        /// Unique id of the blockchain on which the token can be redeemed.
+        pub chain: ChainId,
+        /// Account on behalf of which the query is executed.
+        pub authority: AccountId,
+        /// Query along with its filter, sorting and pagination.
+        pub query: QueryRequest,
+        /// Peers reject the token after this moment (unix time in milliseconds).
+        pub expires_at_ms: NonZeroU64,
+    }
+}
+
+impl ReadToken {
+    /// Sign `query` on behalf of `authority`, to be executed by anyone on `chain`
+    /// until `expires_at`, a duration since unix epoch.
+    ///
+    /// # Panics
+    /// If `expires_at` is zero
+    #[must_use]
+    pub fn new(
+        chain: ChainId,
+        query: QueryRequest,
+        authority: AccountId,
+        expires_at: Duration,
+        key_pair: &iroha_crypto::KeyPair,
+    ) -> Self {
+        let expires_at_ms: u64 = expires_at
+            .as_millis()
+            .try_into()
+            .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX");
+        let payload = ReadTokenPayload {
+            chain,
+            authority,
+            query,
+            expires_at_ms: NonZeroU64::new(expires_at_ms).expect("Expiry must not be zero"),
+        };
+
+        Self {
+            signature: SignatureOf::new(key_pair.private_key(), &payload),
+            payload,
+        }
+    }
+
+    /// Unique id of the blockchain on which the token can be redeemed
+    pub fn chain(&self) -> &ChainId {
+        &self.payload.chain
+    }
+
+    /// Account on behalf of which the query is executed
+    pub fn authority(&self) -> &AccountId {
+        &self.payload.authority
+    }
+
+    /// Query to execute
+    pub fn query(&self) -> &QueryRequest {
+        &self.payload.query
+    }
+
+    /// Moment after which peers reject the token, as a duration since unix epoch
+    pub fn expires_at(&self) -> Duration {
+        Duration::from_millis(self.payload.expires_at_ms.get())
+    }
+
+    /// Verify that the token is signed by its authority.
+    ///
+    /// # Errors
+    /// If the signature is not valid
+    #[cfg(not(target_family = "wasm"))]
+    pub fn verify(&self) -> Result<(), &'static str> {
+        self.signature
+            .verify(&self.payload.authority.signatory, &self.payload)
+            .map_err(|_| "Read token signature is not valid")
+    }
+}
+
+/// Encoded as URL-safe base64, to be embedded into a capability URL.
+impl fmt::Display for ReadToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        write!(
+            f,
+            "{}",
+            base64::display::Base64Display::new(&self.encode(), &engine)
+        )
+    }
+}
+
+impl FromStr for ReadToken {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes: Vec<u8> = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(s)
+            .map_err(|_| ParseError {
+                reason: "Read token must be URL-safe base64",
+            })?;
+
+        Self::decode_all(&mut bytes.as_slice()).map_err(|_| ParseError {
+            reason: "Read token is malformed",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::KeyPair;
+
+    use super::*;
+    use crate::query::{FindExecutorDataModel, SingularQueryBox};
+
+    #[test]
+    fn token_roundtrips_through_string() {
+        let key_pair = KeyPair::random();
+        let authority = format!("{}@wonderland", key_pair.public_key())
+            .parse()
+            .unwrap();
+        let token = ReadToken::new(
+            "00000000-0000-0000-0000-000000000000".parse().unwrap(),
+            QueryRequest::Singular(SingularQueryBox::FindExecutorDataModel(
+                FindExecutorDataModel,
+            )),
+            authority,
+            Duration::from_secs(1_000),
+            &key_pair,
+        );
+
+        let parsed: ReadToken = token.to_string().parse().unwrap();
+        assert_eq!(parsed, token);
+        parsed.verify().unwrap();
+
+        let mut forged = parsed;
+        forged.payload.expires_at_ms = NonZeroU64::new(2_000_000).unwrap();
+        assert!(forged.verify().is_err());
+        assert!("not a token".parse::<ReadToken>().is_err());
+    }
+}
@@ -1,9 +1,19 @@
 //! Structures, traits and impls related to `Role`s.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet};
 
+use derive_more::Display;
 use iroha_data_model_derive::model;
+use iroha_primitives::json::Json;
 
 pub use self::model::*;
 use crate::{
@@ -52,6 +62,8 @@ mod model {
     }
 
     /// Role is a tag for a set of permission tokens.
+    ///
+    /// A role also includes all permissions of the roles it inherits, transitively.
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
     )]
@@ -62,6 +74,28 @@ mod model {
         pub id: RoleId,
         /// Permission tokens.
         pub permissions: Permissions,
+        /// Roles whose permissions are included into this role.
+        #[serde(default)]
+        pub inherited_roles: BTreeSet<RoleId>,
+        /// Account administering the role, initially the one it's granted to on registration.
+        pub owned_by: AccountId,
+    }
+
+    /// Template of a [`Role`] whose permission payloads may contain `{placeholder}`s.
+    ///
+    /// Placeholders are substituted when the template is instantiated with
+    /// [`InstantiateRoleTemplate`](crate::isi::InstantiateRoleTemplate),
+    /// so the same role can be stamped out e.g. for every domain.
+    #[derive(
+        Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    #[display(fmt = "{id}")]
+    #[ffi_type]
+    pub struct RoleTemplate {
+        /// Unique name of the template.
+        pub id: RoleId,
+        /// Permission tokens, possibly containing placeholders.
+        pub permissions: Permissions,
     }
 
     /// Builder for [`Role`]
@@ -85,7 +119,7 @@ mod model {
         #[id(transparent)]
         #[serde(flatten)]
         pub inner: Role,
-        /// First owner
+        /// First holder and owner
         pub grant_to: AccountId,
     }
 }
@@ -102,6 +136,18 @@ impl Role {
     pub fn permissions(&self) -> impl ExactSizeIterator<Item = &Permission> {
         self.permissions.iter()
     }
+
+    /// Get an iterator over ids of the roles directly inherited by the `Role`
+    #[inline]
+    pub fn inherited_roles(&self) -> impl ExactSizeIterator<Item = &RoleId> {
+        self.inherited_roles.iter()
+    }
+
+    /// Account administering the `Role`
+    #[inline]
+    pub fn owned_by(&self) -> &AccountId {
+        &self.owned_by
+    }
 }
 
 impl NewRole {
@@ -110,11 +156,13 @@ impl NewRole {
     #[inline]
     fn new(id: RoleId, grant_to: AccountId) -> Self {
         Self {
-            grant_to,
             inner: Role {
                 id,
                 permissions: Permissions::new(),
+                inherited_roles: BTreeSet::new(),
+                owned_by: grant_to.clone(),
             },
+            grant_to,
         }
     }
 
@@ -125,12 +173,167 @@ impl NewRole {
         self.inner.permissions.insert(perm.into());
         self
     }
+
+    /// Make the [`Role`] inherit permissions of another role
+    #[must_use]
+    #[inline]
+    pub fn inherit(mut self, role_id: RoleId) -> Self {
+        self.inner.inherited_roles.insert(role_id);
+        self
+    }
 }
 
 impl Registered for Role {
     type With = NewRole;
 }
 
+impl RoleTemplate {
+    /// Constructor.
+    #[inline]
+    pub fn new(id: RoleId) -> Self {
+        Self {
+            id,
+            permissions: Permissions::new(),
+        }
+    }
+
+    /// Add permission to the [`RoleTemplate`]
+    #[must_use]
+    #[inline]
+    pub fn add_permission(mut self, perm: impl Into<Permission>) -> Self {
+        self.permissions.insert(perm.into());
+        self
+    }
+
+    /// Get an iterator over [`permissions`](Permission) of the `RoleTemplate`
+    #[inline]
+    pub fn permissions(&self) -> impl ExactSizeIterator<Item = &Permission> {
+        self.permissions.iter()
+    }
+
+    /// Names of the placeholders used in the permission payloads
+    pub fn placeholders(&self) -> BTreeSet<&str> {
+        let mut placeholders = BTreeSet::new();
+        for permission in &self.permissions {
+            substitute_placeholders(permission.payload.as_ref(), |placeholder| {
+                placeholders.insert(placeholder);
+                None
+            });
+        }
+        placeholders
+    }
+
+    /// Build a role which has the permissions of the template with placeholders
+    /// substituted by `arguments`.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no argument for some placeholder
+    /// - If some argument doesn't correspond to any placeholder
+    pub fn instantiate(
+        &self,
+        id: RoleId,
+        grant_to: AccountId,
+        arguments: &BTreeMap<Name, String>,
+    ) -> Result<NewRole, RoleTemplateError> {
+        let placeholders = self.placeholders();
+        if let Some(unknown) = arguments
+            .keys()
+            .find(|argument| !placeholders.contains(argument.as_ref()))
+        {
+            return Err(RoleTemplateError::UnknownArgument(unknown.clone()));
+        }
+        if let Some(missing) = placeholders.into_iter().find(|placeholder| {
+            !arguments
+                .keys()
+                .any(|argument| argument.as_ref() == *placeholder)
+        }) {
+            return Err(RoleTemplateError::MissingArgument(missing.to_owned()));
+        }
+
+        Ok(self
+            .permissions
+            .iter()
+            .fold(Role::new(id, grant_to), |role, permission| {
+                let payload = substitute_placeholders(permission.payload.as_ref(), |placeholder| {
+                    arguments
+                        .iter()
+                        .find(|(argument, _)| argument.as_ref() == placeholder)
+                        .map(|(_, value)| escape_json_string(value))
+                });
+                role.add_permission(Permission::new(
+                    permission.name.clone(),
+                    Json::from_string_unchecked(payload),
+                ))
+            }))
+    }
+}
+
+impl Registered for RoleTemplate {
+    type With = Self;
+}
+
+/// Error which occurs when instantiating a [`RoleTemplate`]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
+pub enum RoleTemplateError {
+    /// No argument is given for the placeholder
+    #[display(fmt = "No argument for placeholder `{_0}`")]
+    MissingArgument(String),
+    /// Template has no placeholder for the argument
+    #[display(fmt = "Template has no placeholder `{_0}`")]
+    UnknownArgument(Name),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RoleTemplateError {}
+
+/// Replace every `{placeholder}` in `text` with the value returned by `substitution`,
+/// leaving the placeholder intact if there is none.
+///
+/// Placeholder names consist of ASCII alphanumeric characters and underscores.
+fn substitute_placeholders<'text>(
+    text: &'text str,
+    mut substitution: impl FnMut(&'text str) -> Option<String>,
+) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after_brace = &rest[start + 1..];
+        let placeholder = after_brace
+            .find('}')
+            .map(|end| &after_brace[..end])
+            .filter(|name| {
+                !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|char| char.is_ascii_alphanumeric() || char == '_')
+            });
+        let Some(placeholder) = placeholder else {
+            result.push('{');
+            rest = after_brace;
+            continue;
+        };
+        match substitution(placeholder) {
+            Some(value) => result.push_str(&value),
+            None => {
+                result.push('{');
+                result.push_str(placeholder);
+                result.push('}');
+            }
+        }
+        rest = &after_brace[placeholder.len() + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Escape `value` to be embedded into a JSON string literal.
+fn escape_json_string(value: &str) -> String {
+    let quoted = serde_json::to_string(value).expect("Serialization of a string can't fail");
+    quoted[1..quoted.len() - 1].to_owned()
+}
+
 impl Registrable for NewRole {
     type Target = Role;
 
@@ -142,5 +345,5 @@ impl Registrable for NewRole {
 
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
-    pub use super::{NewRole, Role, RoleId};
+    pub use super::{NewRole, Role, RoleId, RoleTemplate};
 }
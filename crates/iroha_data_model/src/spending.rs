@@ -0,0 +1,164 @@
+//! Counters of the amounts transferred from accounts, against which executors enforce spending caps.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
+
+use iroha_data_model_derive::model;
+use iroha_primitives::numeric::Numeric;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::{account::AccountId, asset::AssetDefinitionId};
+
+#[model]
+mod model {
+    use getset::Getters;
+
+    use super::*;
+
+    /// Window in which the transferred amounts are summed up.
+    ///
+    /// Windows are aligned to the Unix epoch and measured by block timestamps.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    pub enum SpendingWindow {
+        /// 24 hours
+        Day,
+        /// 7 days
+        Week,
+    }
+
+    /// Amount of an asset transferred from an account during a window.
+    ///
+    /// Counters live in the world state and are only modified by the executor,
+    /// with [`crate::isi::SetSpendingCounter`].
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct SpendingCounter {
+        /// Definition of the transferred asset.
+        pub asset_definition: AssetDefinitionId,
+        /// Account transferring on behalf of the owner, `None` if transfers are counted regardless of who makes them.
+        pub spender: Option<AccountId>,
+        /// Window the amounts are summed up in.
+        #[getset(skip)]
+        pub window: SpendingWindow,
+        /// Maximum amount transferred in a single window, unless the cap is kept elsewhere.
+        pub limit: Option<Numeric>,
+        /// Sequential number of the window `spent` is counted in.
+        #[getset(skip)]
+        pub window_index: u64,
+        /// Amount transferred during the window.
+        pub spent: Numeric,
+    }
+}
+
+impl SpendingWindow {
+    /// Length of the window in seconds
+    pub const fn duration_secs(self) -> u64 {
+        const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+        match self {
+            Self::Day => SECS_PER_DAY,
+            Self::Week => 7 * SECS_PER_DAY,
+        }
+    }
+
+    /// Sequential number of the window containing the moment `secs` since the Unix epoch
+    pub const fn index_at(self, secs: u64) -> u64 {
+        secs / self.duration_secs()
+    }
+}
+
+impl SpendingCounter {
+    /// Construct a counter with nothing spent.
+    pub fn new(
+        asset_definition: AssetDefinitionId,
+        spender: Option<AccountId>,
+        window: SpendingWindow,
+    ) -> Self {
+        Self {
+            asset_definition,
+            spender,
+            window,
+            limit: None,
+            window_index: 0,
+            spent: Numeric::ZERO,
+        }
+    }
+
+    /// Window the amounts are summed up in.
+    pub const fn window(&self) -> SpendingWindow {
+        self.window
+    }
+
+    /// Sequential number of the window `spent` is counted in.
+    pub const fn window_index(&self) -> u64 {
+        self.window_index
+    }
+
+    /// Whether the counter sums up the transfers of `asset_definition` made by `spender` in `window`.
+    pub fn counts(
+        &self,
+        asset_definition: &AssetDefinitionId,
+        spender: Option<&AccountId>,
+        window: SpendingWindow,
+    ) -> bool {
+        self.asset_definition == *asset_definition
+            && self.spender.as_ref() == spender
+            && self.window == window
+    }
+
+    /// Add `amount` to the amount spent in the window containing `now`,
+    /// starting over from zero once a new window begins.
+    ///
+    /// Returns the total spent in the window, or `None` if it overflows.
+    pub fn spend(&mut self, amount: Numeric, now: Duration) -> Option<Numeric> {
+        let window_index = self.window.index_at(now.as_secs());
+        let spent = if self.window_index == window_index {
+            self.spent
+        } else {
+            Numeric::ZERO
+        };
+
+        self.spent = spent.checked_add(amount)?;
+        self.window_index = window_index;
+        Some(self.spent)
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{SpendingCounter, SpendingWindow};
+}
@@ -0,0 +1,173 @@
+//! Account statements: movements of account balances over a period of time.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
+
+use derive_more::Display;
+use iroha_crypto::HashOf;
+use iroha_data_model_derive::model;
+use iroha_primitives::numeric::Numeric;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::{account::AccountId, asset::AssetDefinitionId, transaction::TransactionEntrypoint};
+
+#[model]
+mod model {
+    use getset::Getters;
+
+    use super::*;
+
+    /// Direction of an [`AssetMovement`] relative to the account.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    pub enum MovementDirection {
+        /// Balance was increased by a mint or an incoming transfer.
+        Credit,
+        /// Balance was decreased by a burn or an outgoing transfer.
+        Debit,
+    }
+
+    /// Change of the balance of an asset held by an account.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AssetMovement {
+        /// Definition of the moved asset.
+        pub asset_definition: AssetDefinitionId,
+        /// Whether the balance was increased or decreased.
+        #[getset(skip)]
+        pub direction: MovementDirection,
+        /// Moved quantity.
+        pub amount: Numeric,
+        /// Balance of the asset right after the movement.
+        pub balance: Numeric,
+        /// The other side of a transfer, `None` for mints and burns.
+        pub counterparty: Option<AccountId>,
+        /// Hash of the transaction (or time trigger) which caused the movement.
+        pub transaction: HashOf<TransactionEntrypoint>,
+        /// Creation time of the block containing the movement, as Unix time in milliseconds.
+        #[getset(skip)]
+        pub timestamp_ms: u64,
+    }
+
+    /// Movements of a single asset of an account over the statement period.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AssetStatement {
+        /// Definition of the asset.
+        pub asset_definition: AssetDefinitionId,
+        /// Balance at the start of the period.
+        pub opening_balance: Numeric,
+        /// Movements within the period, oldest first.
+        pub movements: Vec<AssetMovement>,
+        /// Balance at the end of the period.
+        pub closing_balance: Numeric,
+    }
+
+    /// Statement of the asset balances of an account over a period of time.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AccountStatement {
+        /// Account the statement is produced for.
+        pub account: AccountId,
+        /// Start of the period (inclusive), as Unix time in milliseconds.
+        ///
+        /// Later than requested if the movements made before it are no longer retained.
+        #[getset(skip)]
+        pub since_ms: u64,
+        /// End of the period (exclusive), as Unix time in milliseconds.
+        #[getset(skip)]
+        pub until_ms: u64,
+        /// Statements of the assets which were held or moved within the period, ordered by definition.
+        pub assets: Vec<AssetStatement>,
+    }
+}
+
+impl AssetMovement {
+    /// Whether the balance was increased or decreased.
+    pub const fn direction(&self) -> MovementDirection {
+        self.direction
+    }
+
+    /// Creation time of the block containing the movement.
+    pub const fn timestamp(&self) -> Duration {
+        Duration::from_millis(self.timestamp_ms)
+    }
+}
+
+impl AccountStatement {
+    /// Start of the period (inclusive).
+    pub const fn since(&self) -> Duration {
+        Duration::from_millis(self.since_ms)
+    }
+
+    /// End of the period (exclusive).
+    pub const fn until(&self) -> Duration {
+        Duration::from_millis(self.until_ms)
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{AccountStatement, AssetMovement, AssetStatement, MovementDirection};
+}
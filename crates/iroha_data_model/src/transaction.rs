@@ -9,7 +9,7 @@ use core::{
 };
 
 use derive_more::{DebugCustom, Deref, Display, From, TryInto};
-use iroha_crypto::{HashOf, Signature, SignatureOf};
+use iroha_crypto::{Hash, HashOf, Signature, SignatureOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 #[cfg(feature = "std")]
@@ -135,12 +135,40 @@ mod model {
     )]
     pub struct TransactionSignature(pub SignatureOf<TransactionPayload>);
 
+    /// Form of the transaction payload the [`TransactionSignature`] is made over.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    pub enum SignedPayloadForm {
+        /// SCALE encoding of the payload
+        #[default]
+        Scale,
+        /// [Canonical JSON](TransactionBuilder::canonical_json) form of the payload
+        CanonicalJson,
+    }
+
     /// Transaction that contains a signature
     ///
     /// `Iroha` and its clients use [`Self`] to send transactions over the network.
     /// After a transaction is signed and before it can be processed any further,
     /// the transaction must be accepted by the `Iroha` peer.
     /// The peer verifies the signature and checks the limits.
+    ///
+    /// The signature is made over the SCALE encoding of [`Self::payload`].
     #[version(version = 1, versioned_alias = "SignedTransaction")]
     #[derive(
         Debug,
@@ -165,6 +193,36 @@ mod model {
         pub(super) payload: TransactionPayload,
     }
 
+    /// Transaction that contains a signature made over the given form of its payload.
+    ///
+    /// Transactions signed over the SCALE encoding are still sent as [`SignedTransactionV1`],
+    /// so that their encoding and hashes stay the same.
+    #[version(version = 2, versioned_alias = "SignedTransaction")]
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{}", "self.hash()")]
+    #[ffi_type]
+    pub struct SignedTransactionV2 {
+        /// Signature of [`Self::payload`].
+        pub(super) signature: TransactionSignature,
+        /// Payload of the transaction.
+        pub(super) payload: TransactionPayload,
+        /// Form of [`Self::payload`] the signature is made over.
+        pub(super) signed_form: SignedPayloadForm,
+    }
+
     /// Structure that represents the initial state of a transaction before the transaction receives any signatures.
     #[derive(Debug, Clone)]
     #[repr(transparent)]
@@ -339,51 +397,75 @@ impl WasmSmartContract {
 }
 
 #[cfg(any(feature = "ffi_export", feature = "ffi_import"))]
-declare_versioned!(SignedTransaction 1..2, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, iroha_ffi::FfiType, IntoSchema);
+declare_versioned!(SignedTransaction 1..3, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, iroha_ffi::FfiType, IntoSchema);
 #[cfg(all(not(feature = "ffi_export"), not(feature = "ffi_import")))]
-declare_versioned!(SignedTransaction 1..2, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, IntoSchema);
+declare_versioned!(SignedTransaction 1..3, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, IntoSchema);
 
 impl SignedTransaction {
     /// Transaction payload. Used for tests
     #[cfg(feature = "transparent_api")]
     pub fn payload(&self) -> &TransactionPayload {
-        let SignedTransaction::V1(tx) = self;
-        &tx.payload
+        self.payload_ref()
+    }
+
+    fn payload_ref(&self) -> &TransactionPayload {
+        match self {
+            SignedTransaction::V1(tx) => &tx.payload,
+            SignedTransaction::V2(tx) => &tx.payload,
+        }
+    }
+
+    #[cfg(any(feature = "transparent_api", feature = "fault_injection"))]
+    fn payload_mut(&mut self) -> &mut TransactionPayload {
+        match self {
+            SignedTransaction::V1(tx) => &mut tx.payload,
+            SignedTransaction::V2(tx) => &mut tx.payload,
+        }
     }
 
     /// Return transaction instructions
     #[inline]
     pub fn instructions(&self) -> &Executable {
-        let SignedTransaction::V1(tx) = self;
-        &tx.payload.instructions
+        &self.payload_ref().instructions
+    }
+
+    /// Replace the instructions of the transaction with the results of `f`, e.g. to serve
+    /// the transaction with erased data.
+    ///
+    /// The signature is left as is, so it no longer verifies if any instruction changes.
+    #[cfg(feature = "transparent_api")]
+    pub fn map_instructions(&mut self, f: impl FnMut(InstructionBox) -> InstructionBox) {
+        if let Executable::Instructions(instructions) = &mut self.payload_mut().instructions {
+            let taken = core::mem::replace(
+                instructions,
+                iroha_primitives::const_vec::ConstVec::new_empty(),
+            );
+            *instructions = taken.into_iter().map(f).collect();
+        }
     }
 
     /// Return transaction authority
     #[inline]
     pub fn authority(&self) -> &AccountId {
-        let SignedTransaction::V1(tx) = self;
-        &tx.payload.authority
+        &self.payload_ref().authority
     }
 
     /// Return transaction metadata.
     #[inline]
     pub fn metadata(&self) -> &Metadata {
-        let SignedTransaction::V1(tx) = self;
-        &tx.payload.metadata
+        &self.payload_ref().metadata
     }
 
     /// Creation timestamp as [`core::time::Duration`]
     #[inline]
     pub fn creation_time(&self) -> Duration {
-        let SignedTransaction::V1(tx) = self;
-        Duration::from_millis(tx.payload.creation_time_ms)
+        Duration::from_millis(self.payload_ref().creation_time_ms)
     }
 
     /// If transaction is not committed by this time it will be dropped.
     #[inline]
     pub fn time_to_live(&self) -> Option<Duration> {
-        let SignedTransaction::V1(tx) = self;
-        tx.payload
+        self.payload_ref()
             .time_to_live_ms
             .map(|ttl| Duration::from_millis(ttl.into()))
     }
@@ -391,22 +473,22 @@ impl SignedTransaction {
     /// Transaction nonce
     #[inline]
     pub fn nonce(&self) -> Option<NonZeroU32> {
-        let SignedTransaction::V1(tx) = self;
-        tx.payload.nonce
+        self.payload_ref().nonce
     }
 
     /// Transaction chain id
     #[inline]
     pub fn chain(&self) -> &ChainId {
-        let SignedTransaction::V1(tx) = self;
-        &tx.payload.chain
+        &self.payload_ref().chain
     }
 
     /// Return the transaction signature
     #[inline]
     pub fn signature(&self) -> &TransactionSignature {
-        let SignedTransaction::V1(tx) = self;
-        &tx.signature
+        match self {
+            SignedTransaction::V1(tx) => &tx.signature,
+            SignedTransaction::V2(tx) => &tx.signature,
+        }
     }
 
     /// Hash for this external transaction.
@@ -429,8 +511,7 @@ impl SignedTransaction {
         &mut self,
         extra_instructions: impl IntoIterator<Item = impl Into<InstructionBox>>,
     ) {
-        let SignedTransaction::V1(tx) = self;
-        let Executable::Instructions(instructions) = &mut tx.payload.instructions else {
+        let Executable::Instructions(instructions) = &mut self.payload_mut().instructions else {
             unimplemented!("Wasm executables are not subject to fault injection")
         };
         let mut modified = instructions.clone().into_vec();
@@ -438,26 +519,91 @@ impl SignedTransaction {
         *instructions = modified.into();
     }
 
+    /// Canonical JSON form of the transaction payload.
+    ///
+    /// See [`TransactionBuilder::sign_canonical_json`].
+    pub fn canonical_json(&self) -> String {
+        self.payload_ref().canonical_json()
+    }
+
+    /// Form of the payload the transaction signature is made over.
+    #[inline]
+    pub fn signed_form(&self) -> SignedPayloadForm {
+        match self {
+            SignedTransaction::V1(_) => SignedPayloadForm::Scale,
+            SignedTransaction::V2(tx) => tx.signed_form,
+        }
+    }
+
     /// Verify transaction signature.
     ///
+    /// The signature is verified over the SCALE encoding or over the canonical JSON
+    /// form of the payload, depending on [`Self::signed_form`].
+    ///
     /// # Errors
     ///
-    /// Returns an error if signature verification fails.
+    /// Returns an error if signature verification fails, or if the signature is made
+    /// over canonical JSON which doesn't determine the payload byte for byte.
     #[inline]
     pub fn verify_signature(&self) -> Result<(), iroha_crypto::Error> {
-        let SignedTransaction::V1(tx) = self;
+        let TransactionSignature(signature) = self.signature();
+        let payload = self.payload_ref();
+        let signatory = &payload.authority.signatory;
+
+        match self.signed_form() {
+            SignedPayloadForm::Scale => signature.verify(signatory, payload),
+            SignedPayloadForm::CanonicalJson => {
+                let canonical_json = payload.canonical_json();
+                // `Json` values are embedded as is, so canonicalization might erase differences
+                // between payloads (e.g. key order or whitespace) and make the signature malleable
+                if serde_json::from_str::<TransactionPayload>(&canonical_json)
+                    .map_or(true, |restored| restored != *payload)
+                {
+                    return Err(iroha_crypto::Error::BadSignature);
+                }
+
+                signature.verify_hash(
+                    signatory,
+                    HashOf::from_untyped_unchecked(Hash::new(canonical_json)),
+                )
+            }
+        }
+    }
 
-        let TransactionSignature(signature) = &tx.signature;
+    /// Verify signatures of multiple transactions.
+    ///
+    /// Each signature is checked with [`Self::verify_signature`], so a transaction
+    /// is accepted here if and only if it would be accepted on its own.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if verification of any of the signatures fails.
+    pub fn verify_signatures(
+        txs: impl IntoIterator<Item = &Self>,
+    ) -> Result<(), iroha_crypto::Error> {
+        txs.into_iter().try_for_each(Self::verify_signature)
+    }
+}
+
+impl TransactionPayload {
+    fn canonical_json(&self) -> String {
+        let value = serde_json::to_value(self).expect("transaction payload serializes to JSON");
+        iroha_primitives::json::to_canonical_string(&value)
+    }
 
-        signature.verify(&tx.payload.authority.signatory, &tx.payload)
+    fn canonical_json_hash(&self) -> HashOf<Self> {
+        HashOf::from_untyped_unchecked(Hash::new(self.canonical_json()))
     }
 }
 
 #[cfg(feature = "transparent_api")]
 impl From<SignedTransaction> for (AccountId, Executable) {
     fn from(source: SignedTransaction) -> Self {
-        let SignedTransaction::V1(tx) = source;
-        (tx.payload.authority, tx.payload.instructions)
+        let payload = match source {
+            SignedTransaction::V1(tx) => tx.payload,
+            SignedTransaction::V2(tx) => tx.payload,
+        };
+        (payload.authority, payload.instructions)
     }
 }
 
@@ -467,6 +613,12 @@ impl SignedTransactionV1 {
     }
 }
 
+impl SignedTransactionV2 {
+    fn hash(&self) -> HashOf<SignedTransaction> {
+        HashOf::from_untyped_unchecked(HashOf::new(self).into())
+    }
+}
+
 impl TransactionSignature {
     /// Signature itself
     pub fn payload(&self) -> &Signature {
@@ -589,6 +741,38 @@ impl TransactionBuilder {
         }
         .into()
     }
+
+    /// Canonical JSON form of the transaction payload.
+    ///
+    /// It is the JSON serialization of the payload, written as described in
+    /// [`iroha_primitives::json::to_canonical_string`].
+    pub fn canonical_json(&self) -> String {
+        self.payload.canonical_json()
+    }
+
+    /// Sign transaction over the [canonical JSON](Self::canonical_json) form of its payload
+    /// instead of its SCALE encoding.
+    ///
+    /// The signed message is the [`Hash`] of the canonical JSON bytes, so clients without a
+    /// SCALE codec can produce signatures that peers accept.
+    ///
+    /// Peers reject the transaction if the payload can't be restored from its canonical JSON
+    /// exactly, e.g. if [`Json`](iroha_primitives::json::Json) values in the metadata or in
+    /// the instructions aren't written in canonical form themselves.
+    #[must_use]
+    pub fn sign_canonical_json(self, private_key: &iroha_crypto::PrivateKey) -> SignedTransaction {
+        let signature = TransactionSignature(SignatureOf::from_hash(
+            private_key,
+            self.payload.canonical_json_hash(),
+        ));
+
+        SignedTransactionV2 {
+            signature,
+            payload: self.payload,
+            signed_form: SignedPayloadForm::CanonicalJson,
+        }
+        .into()
+    }
 }
 
 impl TransactionEntrypoint {
@@ -883,8 +1067,8 @@ pub mod error {
 pub mod prelude {
     pub use super::{
         error::prelude::*, DataTriggerSequence, DataTriggerStep, Executable, ExecutionStep,
-        SignedTransaction, TimeTriggerEntrypoint, TransactionBuilder, TransactionEntrypoint,
-        TransactionResult, TransactionResultInner, WasmSmartContract,
+        SignedPayloadForm, SignedTransaction, TimeTriggerEntrypoint, TransactionBuilder,
+        TransactionEntrypoint, TransactionResult, TransactionResultInner, WasmSmartContract,
     };
 }
 
@@ -900,4 +1084,109 @@ mod tests {
         let contract = WasmSmartContract::from_compiled(vec![0, 1, 2, 3, 4]);
         assert_eq!(format!("{contract:?}"), "WASM binary(len = 5)");
     }
+
+    #[cfg(feature = "std")]
+    mod canonical_json {
+        use iroha_crypto::KeyPair;
+        use iroha_primitives::json::Json;
+
+        use super::*;
+        use crate::isi::Log;
+
+        fn builder(key_pair: &KeyPair) -> TransactionBuilder {
+            let authority: AccountId = format!("{}@wonderland", key_pair.public_key())
+                .parse()
+                .unwrap();
+            TransactionBuilder::new(
+                "00000000-0000-0000-0000-000000000000".parse().unwrap(),
+                authority,
+            )
+            .with_instructions([Log::new(crate::Level::INFO, "canonical".to_owned())])
+        }
+
+        #[test]
+        fn payload_is_written_in_canonical_form() {
+            let json = builder(&KeyPair::random()).canonical_json();
+
+            let keys: Vec<_> = serde_json::from_str::<serde_json::Value>(&json)
+                .unwrap()
+                .as_object()
+                .unwrap()
+                .keys()
+                .cloned()
+                .collect();
+            let mut sorted = keys.clone();
+            sorted.sort();
+            assert_eq!(keys, sorted);
+            assert!(json.starts_with(r#"{"authority":""#));
+            assert!(!json.contains('\n'));
+        }
+
+        #[test]
+        fn signature_over_canonical_json_is_verified() {
+            let key_pair = KeyPair::random();
+            let tx = builder(&key_pair).sign_canonical_json(key_pair.private_key());
+
+            tx.verify_signature().unwrap();
+            SignedTransaction::verify_signatures([&tx]).unwrap();
+            SignedTransaction::verify_signatures([
+                &tx,
+                &builder(&key_pair).sign(key_pair.private_key()),
+            ])
+            .unwrap();
+        }
+
+        #[test]
+        fn signature_by_another_key_is_rejected() {
+            let key_pair = KeyPair::random();
+            let tx = builder(&key_pair).sign_canonical_json(KeyPair::random().private_key());
+
+            assert!(tx.verify_signature().is_err());
+            assert!(SignedTransaction::verify_signatures([&tx]).is_err());
+        }
+
+        #[test]
+        fn signature_is_checked_only_in_signed_form() {
+            let key_pair = KeyPair::random();
+            let SignedTransaction::V2(mut tx) =
+                builder(&key_pair).sign_canonical_json(key_pair.private_key())
+            else {
+                panic!("transactions signed over canonical JSON are of version 2");
+            };
+
+            tx.signed_form = SignedPayloadForm::Scale;
+            assert!(SignedTransaction::from(tx).verify_signature().is_err());
+        }
+
+        #[test]
+        fn scale_signed_transaction_keeps_version_1_encoding() {
+            let key_pair = KeyPair::random();
+            let tx = builder(&key_pair).sign(key_pair.private_key());
+            let SignedTransaction::V1(inner) = &tx else {
+                panic!("transactions signed over SCALE are of version 1");
+            };
+
+            // Version byte followed by the fields as they were encoded before version 2
+            let mut expected = vec![1_u8];
+            inner.signature.encode_to(&mut expected);
+            inner.payload.encode_to(&mut expected);
+            assert_eq!(tx.encode(), expected);
+            assert_eq!(SignedTransaction::decode(&mut &expected[..]).unwrap(), tx);
+        }
+
+        #[test]
+        fn non_canonical_json_values_are_rejected() {
+            let key_pair = KeyPair::random();
+            let mut metadata = Metadata::default();
+            metadata.insert(
+                "key".parse().unwrap(),
+                Json::from_string_unchecked(r#"{ "b": 1.0, "a": 2 }"#.to_owned()),
+            );
+            let tx = builder(&key_pair)
+                .with_metadata(metadata)
+                .sign_canonical_json(key_pair.private_key());
+
+            assert!(tx.verify_signature().is_err());
+        }
+    }
 }
@@ -65,6 +65,32 @@ mod model {
         /// Defines when, who initiates what execution and includes persistent storage.
         pub action: action::Action,
     }
+
+    /// Data triggers which may keep triggering each other, found by inspecting
+    /// the events their instructions emit and the events their filters accept.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct TriggerLoop {
+        /// Triggers taking part in the loop, ordered by id.
+        #[getset(get = "pub")]
+        pub triggers: Vec<TriggerId>,
+        /// Whether some of the triggers fire a limited number of times,
+        /// so that the loop stops eventually.
+        pub bounded: bool,
+    }
 }
 
 #[ffi_impl_opaque]
@@ -86,6 +112,24 @@ impl Trigger {
     }
 }
 
+impl TriggerLoop {
+    /// Whether some of the triggers fire a limited number of times,
+    /// so that the loop stops eventually.
+    pub fn is_bounded(&self) -> bool {
+        self.bounded
+    }
+}
+
+impl core::fmt::Display for TriggerLoop {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut triggers = self.triggers.iter();
+        if let Some(first) = triggers.next() {
+            write!(f, "{first}")?;
+        }
+        triggers.try_for_each(|trigger| write!(f, ", {trigger}"))
+    }
+}
+
 impl Registered for Trigger {
     type With = Self;
 }
@@ -117,6 +161,12 @@ pub mod action {
             pub filter: EventFilterBox,
             /// Arbitrary metadata stored for this trigger.
             pub metadata: Metadata,
+            /// Order in which triggers matching the same event are executed.
+            ///
+            /// Triggers with higher priority are executed first, ties are executed
+            /// in the ascending order of trigger ids. Triggers fired by events
+            /// of an executed trigger are executed before its remaining siblings.
+            pub priority: u8,
         }
 
         /// Repetition policy for a trigger action.
@@ -158,6 +208,10 @@ pub mod action {
         pub fn filter(&self) -> &EventFilterBox {
             &self.filter
         }
+        /// Order of execution among triggers matching the same event
+        pub fn priority(&self) -> u8 {
+            self.priority
+        }
     }
 
     impl Action {
@@ -180,6 +234,7 @@ pub mod action {
                 authority,
                 filter: filter.into(),
                 metadata: Metadata::default(),
+                priority: 0,
             };
 
             action.validate().unwrap()
@@ -191,6 +246,13 @@ pub mod action {
             self.metadata = metadata;
             self
         }
+
+        /// Set the priority of the trigger, see [`Action::priority`]. Defaults to `0`.
+        #[must_use]
+        pub fn with_priority(mut self, priority: u8) -> Self {
+            self.priority = priority;
+            self
+        }
     }
 
     impl PartialOrd for Action {
@@ -255,6 +317,8 @@ pub mod action {
             pub authority: AccountId,
             pub filter: EventFilterBox,
             pub metadata: Metadata,
+            #[serde(default)]
+            pub priority: u8,
         }
 
         impl ActionCandidate {
@@ -269,6 +333,7 @@ pub mod action {
                     authority: self.authority,
                     filter: self.filter,
                     metadata: self.metadata,
+                    priority: self.priority,
                 })
             }
         }
@@ -303,7 +368,7 @@ pub mod action {
 pub mod prelude {
     //! Re-exports of commonly used types.
 
-    pub use super::{action::prelude::*, Trigger, TriggerId};
+    pub use super::{action::prelude::*, Trigger, TriggerId, TriggerLoop};
 }
 
 #[cfg(test)]
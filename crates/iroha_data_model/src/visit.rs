@@ -7,7 +7,8 @@ use crate::{
     isi::Log,
     prelude::*,
     query::{
-        trigger::FindTriggers, AnyQueryBox, QueryWithFilter, QueryWithParams, SingularQueryBox,
+        trigger::FindTriggers, AggregateQuery, AnyQueryBox, QueryWithFilter, QueryWithParams,
+        SingularQueryBox,
     },
 };
 
@@ -33,6 +34,7 @@ pub trait Visit {
         visit_query(&AnyQueryBox),
         visit_singular_query(&SingularQueryBox),
         visit_iter_query(&QueryWithParams),
+        visit_event_subscription(&AccountId),
 
         // Visit InstructionBox
         visit_burn(&BurnBox),
@@ -49,11 +51,27 @@ pub trait Visit {
         visit_execute_trigger(&ExecuteTrigger),
         visit_set_parameter(&SetParameter),
         visit_log(&Log),
+        visit_anchor(&Anchor),
+        visit_instantiate_role_template(&InstantiateRoleTemplate),
+        visit_set_domain_validator(&SetDomainValidator),
+        visit_mark_redactable(&MarkRedactable),
+        visit_redact(&Redact),
+        visit_accept_domain_transfer(&AcceptDomainTransfer),
+        visit_abort_executor_upgrade(&AbortExecutorUpgrade),
+        visit_set_spending_counter(&SetSpendingCounter),
         visit_custom_instruction(&CustomInstruction),
 
         // Visit SingularQueryBox
         visit_find_executor_data_model(&FindExecutorDataModel),
         visit_find_parameters(&FindParameters),
+        visit_find_trigger_loops(&FindTriggerLoops),
+        visit_find_account_statement(&FindAccountStatement),
+        visit_find_role_template(&FindRoleTemplate),
+        visit_is_transaction_known(&IsTransactionKnown),
+        visit_find_permission_grant_history(&FindPermissionGrantHistory),
+        visit_find_permission_schemas(&FindPermissionSchemas),
+        visit_aggregate_query(&AggregateQuery),
+        visit_find_spending_counters(&FindSpendingCounters),
 
         // Visit IterableQueryBox
         visit_find_domains(&QueryWithFilter<FindDomains>),
@@ -70,8 +88,10 @@ pub trait Visit {
         visit_find_active_trigger_ids(&QueryWithFilter<FindActiveTriggerIds>),
         visit_find_triggers(&QueryWithFilter<FindTriggers>),
         visit_find_transactions(&QueryWithFilter<FindTransactions>),
+        visit_find_excluded_transactions(&QueryWithFilter<FindExcludedTransactions>),
         visit_find_blocks(&QueryWithFilter<FindBlocks>),
         visit_find_block_headers(&QueryWithFilter<FindBlockHeaders>),
+        visit_find_anchors(&QueryWithFilter<FindAnchors>),
 
         // Visit RegisterBox
         visit_register_peer(&Register<Peer>),
@@ -81,6 +101,8 @@ pub trait Visit {
         visit_register_nft(&Register<Nft>),
         visit_register_role(&Register<Role>),
         visit_register_trigger(&Register<Trigger>),
+        visit_register_accounts(&RegisterAccounts),
+        visit_register_role_template(&Register<RoleTemplate>),
 
         // Visit UnregisterBox
         visit_unregister_peer(&Unregister<Peer>),
@@ -88,9 +110,9 @@ pub trait Visit {
         visit_unregister_account(&Unregister<Account>),
         visit_unregister_asset_definition(&Unregister<AssetDefinition>),
         visit_unregister_nft(&Unregister<Nft>),
-        // TODO: Need to allow role creator to unregister it somehow
         visit_unregister_role(&Unregister<Role>),
         visit_unregister_trigger(&Unregister<Trigger>),
+        visit_unregister_role_template(&Unregister<RoleTemplate>),
 
         // Visit MintBox
         visit_mint_asset_numeric(&Mint<Numeric, Asset>),
@@ -105,6 +127,7 @@ pub trait Visit {
         visit_transfer_asset_numeric(&Transfer<Asset, Numeric, Account>),
         visit_transfer_nft(&Transfer<Account, NftId, Account>),
         visit_transfer_domain(&Transfer<Account, DomainId, Account>),
+        visit_transfer_role(&Transfer<Account, RoleId, Account>),
 
         // Visit SetKeyValueBox
         visit_set_domain_key_value(&SetKeyValue<Domain>),
@@ -124,11 +147,17 @@ pub trait Visit {
         visit_grant_account_permission(&Grant<Permission, Account>),
         visit_grant_account_role(&Grant<RoleId, Account>),
         visit_grant_role_permission(&Grant<Permission, Role>),
+        visit_grant_inherited_role(&Grant<RoleId, Role>),
+        visit_grant_account_permission_group(&Grant<PermissionGroup, Account>),
+        visit_grant_account_revocable_permission(&Grant<RevocablePermission, Account>),
 
         // Visit RevokeBox
         visit_revoke_account_permission(&Revoke<Permission, Account>),
         visit_revoke_account_role(&Revoke<RoleId, Account>),
         visit_revoke_role_permission(&Revoke<Permission, Role>),
+        visit_revoke_all_account_permissions(&RevokeAll),
+        visit_revoke_inherited_role(&Revoke<RoleId, Role>),
+        visit_revoke_account_permission_group(&Revoke<PermissionGroup, Account>),
     }
 }
 
@@ -155,9 +184,22 @@ pub fn visit_singular_query<V: Visit + ?Sized>(visitor: &mut V, query: &Singular
     singular_query_visitors! {
         visit_find_executor_data_model(FindExecutorDataModel),
         visit_find_parameters(FindParameters),
+        visit_find_trigger_loops(FindTriggerLoops),
+        visit_find_account_statement(FindAccountStatement),
+        visit_find_role_template(FindRoleTemplate),
+        visit_is_transaction_known(IsTransactionKnown),
+        visit_find_permission_grant_history(FindPermissionGrantHistory),
+        visit_find_permission_schemas(FindPermissionSchemas),
+        visit_aggregate_query(Aggregate),
+        visit_find_spending_counters(FindSpendingCounters),
     }
 }
 
+/// Aggregating results of a query requires the same permissions as reading them
+pub fn visit_aggregate_query<V: Visit + ?Sized>(visitor: &mut V, query: &AggregateQuery) {
+    visitor.visit_iter_query(&query.query);
+}
+
 pub fn visit_iter_query<V: Visit + ?Sized>(visitor: &mut V, query: &QueryWithParams) {
     macro_rules! iterable_query_visitors {
         ( $($visitor:ident($query:ident)),+ $(,)? ) => {
@@ -182,8 +224,10 @@ pub fn visit_iter_query<V: Visit + ?Sized>(visitor: &mut V, query: &QueryWithPar
         visit_find_active_trigger_ids(FindActiveTriggerIds),
         visit_find_triggers(FindTriggers),
         visit_find_transactions(FindTransactions),
+        visit_find_excluded_transactions(FindExcludedTransactions),
         visit_find_block_headers(FindBlockHeaders),
         visit_find_blocks(FindBlocks),
+        visit_find_anchors(FindAnchors),
     }
 }
 
@@ -208,6 +252,26 @@ pub fn visit_instruction<V: Visit + ?Sized>(visitor: &mut V, isi: &InstructionBo
             visitor.visit_execute_trigger(variant_value)
         }
         InstructionBox::Log(variant_value) => visitor.visit_log(variant_value),
+        InstructionBox::Anchor(variant_value) => visitor.visit_anchor(variant_value),
+        InstructionBox::InstantiateRoleTemplate(variant_value) => {
+            visitor.visit_instantiate_role_template(variant_value)
+        }
+        InstructionBox::SetDomainValidator(variant_value) => {
+            visitor.visit_set_domain_validator(variant_value)
+        }
+        InstructionBox::MarkRedactable(variant_value) => {
+            visitor.visit_mark_redactable(variant_value)
+        }
+        InstructionBox::Redact(variant_value) => visitor.visit_redact(variant_value),
+        InstructionBox::AcceptDomainTransfer(variant_value) => {
+            visitor.visit_accept_domain_transfer(variant_value)
+        }
+        InstructionBox::AbortExecutorUpgrade(variant_value) => {
+            visitor.visit_abort_executor_upgrade(variant_value)
+        }
+        InstructionBox::SetSpendingCounter(variant_value) => {
+            visitor.visit_set_spending_counter(variant_value)
+        }
         InstructionBox::Burn(variant_value) => visitor.visit_burn(variant_value),
         InstructionBox::Grant(variant_value) => visitor.visit_grant(variant_value),
         InstructionBox::Mint(variant_value) => visitor.visit_mint(variant_value),
@@ -233,6 +297,8 @@ pub fn visit_register<V: Visit + ?Sized>(visitor: &mut V, isi: &RegisterBox) {
         RegisterBox::Nft(obj) => visitor.visit_register_nft(obj),
         RegisterBox::Role(obj) => visitor.visit_register_role(obj),
         RegisterBox::Trigger(obj) => visitor.visit_register_trigger(obj),
+        RegisterBox::Accounts(obj) => visitor.visit_register_accounts(obj),
+        RegisterBox::RoleTemplate(obj) => visitor.visit_register_role_template(obj),
     }
 }
 
@@ -245,6 +311,7 @@ pub fn visit_unregister<V: Visit + ?Sized>(visitor: &mut V, isi: &UnregisterBox)
         UnregisterBox::Nft(obj) => visitor.visit_unregister_nft(obj),
         UnregisterBox::Role(obj) => visitor.visit_unregister_role(obj),
         UnregisterBox::Trigger(obj) => visitor.visit_unregister_trigger(obj),
+        UnregisterBox::RoleTemplate(obj) => visitor.visit_unregister_role_template(obj),
     }
 }
 
@@ -268,6 +335,7 @@ pub fn visit_transfer<V: Visit + ?Sized>(visitor: &mut V, isi: &TransferBox) {
         TransferBox::AssetDefinition(obj) => visitor.visit_transfer_asset_definition(obj),
         TransferBox::Asset(obj) => visitor.visit_transfer_asset_numeric(obj),
         TransferBox::Nft(obj) => visitor.visit_transfer_nft(obj),
+        TransferBox::Role(obj) => visitor.visit_transfer_role(obj),
     }
 }
 
@@ -298,6 +366,9 @@ pub fn visit_grant<V: Visit + ?Sized>(visitor: &mut V, isi: &GrantBox) {
         GrantBox::Permission(obj) => visitor.visit_grant_account_permission(obj),
         GrantBox::Role(obj) => visitor.visit_grant_account_role(obj),
         GrantBox::RolePermission(obj) => visitor.visit_grant_role_permission(obj),
+        GrantBox::InheritedRole(obj) => visitor.visit_grant_inherited_role(obj),
+        GrantBox::PermissionGroup(obj) => visitor.visit_grant_account_permission_group(obj),
+        GrantBox::RevocablePermission(obj) => visitor.visit_grant_account_revocable_permission(obj),
     }
 }
 
@@ -306,6 +377,9 @@ pub fn visit_revoke<V: Visit + ?Sized>(visitor: &mut V, isi: &RevokeBox) {
         RevokeBox::Permission(obj) => visitor.visit_revoke_account_permission(obj),
         RevokeBox::Role(obj) => visitor.visit_revoke_account_role(obj),
         RevokeBox::RolePermission(obj) => visitor.visit_revoke_role_permission(obj),
+        RevokeBox::AllPermissions(obj) => visitor.visit_revoke_all_account_permissions(obj),
+        RevokeBox::InheritedRole(obj) => visitor.visit_revoke_inherited_role(obj),
+        RevokeBox::PermissionGroup(obj) => visitor.visit_revoke_account_permission_group(obj),
     }
 }
 
@@ -320,9 +394,12 @@ macro_rules! leaf_visitors {
 leaf_visitors! {
     // Instruction visitors
     visit_register_account(&Register<Account>),
+    visit_register_accounts(&RegisterAccounts),
     visit_unregister_account(&Unregister<Account>),
     visit_set_account_key_value(&SetKeyValue<Account>),
     visit_remove_account_key_value(&RemoveKeyValue<Account>),
+    visit_mark_redactable(&MarkRedactable),
+    visit_redact(&Redact),
     visit_register_nft(&Register<Nft>),
     visit_unregister_nft(&Unregister<Nft>),
     visit_mint_asset_numeric(&Mint<Numeric, Asset>),
@@ -341,31 +418,56 @@ leaf_visitors! {
     visit_register_domain(&Register<Domain>),
     visit_unregister_domain(&Unregister<Domain>),
     visit_transfer_domain(&Transfer<Account, DomainId, Account>),
+    visit_accept_domain_transfer(&AcceptDomainTransfer),
     visit_set_domain_key_value(&SetKeyValue<Domain>),
     visit_remove_domain_key_value(&RemoveKeyValue<Domain>),
+    visit_set_domain_validator(&SetDomainValidator),
     visit_register_peer(&Register<Peer>),
     visit_unregister_peer(&Unregister<Peer>),
     visit_grant_account_permission(&Grant<Permission, Account>),
     visit_revoke_account_permission(&Revoke<Permission, Account>),
+    visit_revoke_all_account_permissions(&RevokeAll),
+    visit_grant_account_permission_group(&Grant<PermissionGroup, Account>),
+    visit_grant_account_revocable_permission(&Grant<RevocablePermission, Account>),
+    visit_revoke_account_permission_group(&Revoke<PermissionGroup, Account>),
     visit_register_role(&Register<Role>),
     visit_unregister_role(&Unregister<Role>),
+    visit_transfer_role(&Transfer<Account, RoleId, Account>),
     visit_grant_account_role(&Grant<RoleId, Account>),
     visit_revoke_account_role(&Revoke<RoleId, Account>),
     visit_grant_role_permission(&Grant<Permission, Role>),
     visit_revoke_role_permission(&Revoke<Permission, Role>),
+    visit_grant_inherited_role(&Grant<RoleId, Role>),
+    visit_revoke_inherited_role(&Revoke<RoleId, Role>),
+    visit_register_role_template(&Register<RoleTemplate>),
+    visit_unregister_role_template(&Unregister<RoleTemplate>),
+    visit_instantiate_role_template(&InstantiateRoleTemplate),
     visit_register_trigger(&Register<Trigger>),
     visit_unregister_trigger(&Unregister<Trigger>),
     visit_mint_trigger_repetitions(&Mint<u32, Trigger>),
     visit_burn_trigger_repetitions(&Burn<u32, Trigger>),
     visit_upgrade(&Upgrade),
+    visit_abort_executor_upgrade(&AbortExecutorUpgrade),
+    visit_set_spending_counter(&SetSpendingCounter),
     visit_set_parameter(&SetParameter),
     visit_execute_trigger(&ExecuteTrigger),
     visit_log(&Log),
+    visit_anchor(&Anchor),
     visit_custom_instruction(&CustomInstruction),
 
+    // Event subscription visitors
+    visit_event_subscription(&AccountId),
+
     // Singular Query visitors
     visit_find_executor_data_model(&FindExecutorDataModel),
     visit_find_parameters(&FindParameters),
+    visit_find_trigger_loops(&FindTriggerLoops),
+    visit_find_account_statement(&FindAccountStatement),
+    visit_find_role_template(&FindRoleTemplate),
+    visit_is_transaction_known(&IsTransactionKnown),
+    visit_find_permission_grant_history(&FindPermissionGrantHistory),
+    visit_find_permission_schemas(&FindPermissionSchemas),
+    visit_find_spending_counters(&FindSpendingCounters),
 
     // Iterable Query visitors
     visit_find_domains(&QueryWithFilter<FindDomains>),
@@ -382,6 +484,8 @@ leaf_visitors! {
     visit_find_active_trigger_ids(&QueryWithFilter<FindActiveTriggerIds>),
     visit_find_triggers(&QueryWithFilter<FindTriggers>),
     visit_find_transactions(&QueryWithFilter<FindTransactions>),
+    visit_find_excluded_transactions(&QueryWithFilter<FindExcludedTransactions>),
     visit_find_blocks(&QueryWithFilter<FindBlocks>),
     visit_find_block_headers(&QueryWithFilter<FindBlockHeaders>),
+    visit_find_anchors(&QueryWithFilter<FindAnchors>),
 }
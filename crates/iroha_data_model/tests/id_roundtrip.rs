@@ -0,0 +1,148 @@
+#![allow(missing_docs)]
+
+use core::{fmt::Debug, str::FromStr};
+
+use iroha_data_model::{peer::PeerId, prelude::*};
+
+const ALICE_KEY: &str = "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03";
+
+/// Fragments the fuzzer glues together: delimiters, whitespace, unicode and key prefixes.
+const FRAGMENTS: &[&str] = &[
+    "a",
+    "rose",
+    "wonderland",
+    "@",
+    "#",
+    "##",
+    "$",
+    " ",
+    "\t",
+    "\n",
+    "é",
+    "e\u{301}",
+    "日本",
+    "🦀",
+    "\u{0}",
+    "\u{200b}",
+    "\u{202e}",
+    "%",
+    "\\",
+    "\"",
+    ".",
+    "ed0120",
+    ALICE_KEY,
+    "ED0120",
+    "ed01",
+    "ff",
+    "",
+];
+
+/// Deterministic xorshift generator, so failures are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    fn string(&mut self) -> String {
+        (0..self.below(6))
+            .map(|_| FRAGMENTS[self.below(FRAGMENTS.len())])
+            .collect()
+    }
+}
+
+/// Parsing never panics, and an id that parses survives a display roundtrip.
+fn check<T>(candidate: &str)
+where
+    T: FromStr + Normalize + Debug + PartialEq,
+{
+    let Ok(id) = candidate.parse::<T>() else {
+        return;
+    };
+    let displayed = id.to_string();
+    let reparsed = displayed
+        .parse::<T>()
+        .unwrap_or_else(|_| panic!("`{displayed}` (from `{candidate}`) doesn't parse back"));
+    assert_eq!(id, reparsed, "`{candidate}` doesn't roundtrip");
+
+    let Ok(normalized) = T::normalize(candidate) else {
+        panic!("`{candidate}` parses but doesn't normalize");
+    };
+    assert_eq!(normalized, displayed);
+    assert_eq!(
+        T::normalize(&normalized).ok().as_deref(),
+        Some(normalized.as_str()),
+        "normalization of `{candidate}` is not idempotent"
+    );
+}
+
+fn check_all(candidate: &str) {
+    check::<Name>(candidate);
+    check::<DomainId>(candidate);
+    check::<AccountId>(candidate);
+    check::<AssetDefinitionId>(candidate);
+    check::<AssetId>(candidate);
+    check::<NftId>(candidate);
+    check::<RoleId>(candidate);
+    check::<TriggerId>(candidate);
+    check::<PeerId>(candidate);
+}
+
+#[test]
+fn fuzzed_ids_roundtrip() {
+    let mut rng = Rng(0x5EED_1D5);
+
+    for _ in 0..20_000 {
+        check_all(&rng.string());
+    }
+}
+
+#[test]
+fn unicode_names_roundtrip() {
+    for name in ["é", "e\u{301}", "日本", "🦀", "a\u{200b}b"] {
+        let account = format!("{ALICE_KEY}@{name}");
+        let asset = format!("{name}##{account}");
+        let nft = format!("{name}${name}");
+
+        check_all(name);
+        check_all(&account);
+        check_all(&asset);
+        check_all(&nft);
+        assert!(asset.parse::<AssetId>().is_ok(), "`{asset}` doesn't parse");
+    }
+}
+
+#[test]
+fn delimiters_are_rejected_in_names() {
+    for name in ["a@b", "a#b", "a$b", "a b", "", "a\tb"] {
+        assert!(name.parse::<Name>().is_err(), "`{name:?}` parses");
+        assert!(name.parse::<RoleId>().is_err(), "`{name:?}` parses");
+        assert!(name.parse::<TriggerId>().is_err(), "`{name:?}` parses");
+    }
+}
+
+#[test]
+fn ids_are_normalized() {
+    let lowercase_key = ALICE_KEY.to_lowercase();
+
+    assert_eq!(
+        AssetId::normalize(&format!("rose#wonderland#{lowercase_key}@wonderland")).unwrap(),
+        format!("rose##{ALICE_KEY}@wonderland")
+    );
+    assert_eq!(
+        AssetId::normalize(&format!("rose#garden#{ALICE_KEY}@wonderland")).unwrap(),
+        format!("rose#garden#{ALICE_KEY}@wonderland")
+    );
+    assert_eq!(
+        AccountId::normalize(&format!("{lowercase_key}@wonderland")).unwrap(),
+        format!("{ALICE_KEY}@wonderland")
+    );
+}
@@ -5,6 +5,7 @@ mod has_origin;
 mod id;
 mod model;
 mod partially_tagged;
+mod query_row;
 
 use iroha_macro_utils::Emitter;
 use manyhow::{emit, manyhow, Result};
@@ -567,3 +568,51 @@ pub fn event_set_derive(input: TokenStream) -> TokenStream {
 
     emitter.finish_token_stream_with(result)
 }
+
+/// Derive `iroha_data_model::query::builder::FromQueryRow` for a struct, allowing to
+/// collect query results into it while only selecting the fields it needs.
+///
+/// The `#[query_row(item = ...)]` attribute specifies the type of the query results.
+/// Each field is selected from the item field of the same name, unless another path
+/// is given with `#[query_row(select = ...)]`. Up to 8 fields are supported.
+///
+/// ```
+/// use iroha_data_model::{
+///     prelude::*,
+///     query::{builder::FromQueryRow, dsl::SelectorTuple},
+/// };
+/// use iroha_data_model_derive::FromQueryRow;
+///
+/// #[derive(FromQueryRow)]
+/// #[query_row(item = Asset)]
+/// struct Balance {
+///     #[query_row(select = id.account)]
+///     owner: AccountId,
+///     value: Numeric,
+/// }
+///
+/// // Only the account and the value are returned by the query
+/// assert_eq!(
+///     <Balance as FromQueryRow<Asset>>::selector(),
+///     SelectorTuple::<Asset>::build(|asset| (asset.id.account, asset.value))
+/// );
+/// ```
+///
+/// It can then be used with the query builder:
+///
+/// ```ignore
+/// let balances: Vec<Balance> = client.query(FindAssets).execute_all_as()?;
+/// ```
+#[manyhow]
+#[proc_macro_derive(FromQueryRow, attributes(query_row))]
+pub fn from_query_row_derive(input: TokenStream) -> TokenStream {
+    let mut emitter = Emitter::new();
+
+    let Some(input) = emitter.handle(syn::parse2(input)) else {
+        return emitter.finish_token_stream();
+    };
+
+    let result = query_row::impl_from_query_row(&mut emitter, &input);
+
+    emitter.finish_token_stream_with(result)
+}
@@ -0,0 +1,142 @@
+use darling::{FromDeriveInput, FromField};
+use iroha_macro_utils::{attr_struct, parse_single_list_attr, parse_single_list_attr_opt, Emitter};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, Ident, Token, Type};
+
+mod kw {
+    syn::custom_keyword!(item);
+    syn::custom_keyword!(select);
+}
+
+const QUERY_ROW_ATTR: &str = "query_row";
+/// Number of fields supported by selector tuples
+const MAX_FIELDS: usize = 8;
+
+pub struct QueryRowStruct {
+    ident: Ident,
+    generics: syn::Generics,
+    fields: Vec<QueryRowField>,
+    item: Type,
+}
+
+impl FromDeriveInput for QueryRowStruct {
+    fn from_derive_input(input: &syn::DeriveInput) -> darling::Result<Self> {
+        let ident = input.ident.clone();
+        let generics = input.generics.clone();
+
+        let Some(fields) =
+            darling::ast::Data::<(), QueryRowField>::try_from(&input.data)?.take_struct()
+        else {
+            return Err(darling::Error::custom("Expected struct"));
+        };
+        if !fields.style.is_struct() {
+            return Err(darling::Error::custom("Expected struct with named fields"));
+        }
+        if fields.is_empty() || fields.len() > MAX_FIELDS {
+            return Err(darling::Error::custom(format!(
+                "Expected from 1 to {MAX_FIELDS} fields"
+            )));
+        }
+
+        let item = parse_single_list_attr::<ItemAttr>(QUERY_ROW_ATTR, &input.attrs)?.ty;
+
+        Ok(Self {
+            ident,
+            generics,
+            fields: fields.fields,
+            item,
+        })
+    }
+}
+
+pub struct QueryRowField {
+    ident: Ident,
+    ty: Type,
+    /// Path to the selected field of the item, the field name by default
+    select: Expr,
+}
+
+impl FromField for QueryRowField {
+    fn from_field(field: &syn::Field) -> darling::Result<Self> {
+        let ident = field
+            .ident
+            .clone()
+            .ok_or_else(|| darling::Error::custom("Expected named field"))?;
+        let select = match parse_single_list_attr_opt::<SelectAttr>(QUERY_ROW_ATTR, &field.attrs)? {
+            Some(attr) => {
+                check_field_path(&attr.path)?;
+                attr.path
+            }
+            None => syn::parse_quote!(#ident),
+        };
+
+        Ok(Self {
+            ident,
+            ty: field.ty.clone(),
+            select,
+        })
+    }
+}
+
+/// Check that `path` is a chain of field accesses, like `id.account.domain`
+fn check_field_path(path: &Expr) -> darling::Result<()> {
+    match path {
+        Expr::Path(path) if path.path.get_ident().is_some() => Ok(()),
+        Expr::Field(field) => check_field_path(&field.base),
+        _ => Err(
+            darling::Error::custom("Expected path to a field, like `id.account`").with_span(path),
+        ),
+    }
+}
+
+attr_struct! {
+    pub struct ItemAttr {
+        _kw: kw::item,
+        _eq: Token![=],
+        ty: Type,
+    }
+}
+
+attr_struct! {
+    pub struct SelectAttr {
+        _kw: kw::select,
+        _eq: Token![=],
+        path: Expr,
+    }
+}
+
+pub fn impl_from_query_row(emitter: &mut Emitter, input: &syn::DeriveInput) -> TokenStream {
+    let Some(row) = emitter.handle(QueryRowStruct::from_derive_input(input)) else {
+        return quote!();
+    };
+
+    let ident = &row.ident;
+    let item = &row.item;
+    let prototype = format_ident!("item");
+    let field_idents = row
+        .fields
+        .iter()
+        .map(|field| &field.ident)
+        .collect::<Vec<_>>();
+    let field_types = row.fields.iter().map(|field| &field.ty);
+    let selects = row.fields.iter().map(|field| &field.select);
+
+    let (impl_generics, ty_generics, where_clause) = row.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::iroha_data_model::query::builder::FromQueryRow<#item> for #ident #ty_generics #where_clause {
+            type Row = (#(#field_types,)*);
+
+            fn selector() -> ::iroha_data_model::query::dsl::SelectorTuple<#item> {
+                ::iroha_data_model::query::builder::row_selector::<#item, Self::Row, _, _>(
+                    |#prototype| (#(#prototype.#selects,)*)
+                )
+            }
+
+            fn from_row((#(#field_idents,)*): Self::Row) -> Self {
+                Self { #(#field_idents),* }
+            }
+        }
+    }
+}
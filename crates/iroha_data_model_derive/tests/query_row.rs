@@ -0,0 +1,53 @@
+#![allow(missing_docs)]
+
+use iroha_data_model::{
+    prelude::*,
+    query::{builder::FromQueryRow, dsl::SelectorTuple},
+};
+use iroha_data_model_derive::FromQueryRow;
+
+#[derive(Debug, PartialEq, Eq, FromQueryRow)]
+#[query_row(item = Asset)]
+struct Balance {
+    #[query_row(select = id.definition)]
+    definition: AssetDefinitionId,
+    value: Numeric,
+}
+
+#[derive(Debug, PartialEq, Eq, FromQueryRow)]
+#[query_row(item = Account)]
+struct AccountDomain {
+    #[query_row(select = id.domain.name)]
+    domain: Name,
+}
+
+#[test]
+fn selects_only_requested_fields() {
+    assert_eq!(
+        <Balance as FromQueryRow<Asset>>::selector(),
+        SelectorTuple::<Asset>::build(|asset| (asset.id.definition, asset.value))
+    );
+    assert_eq!(
+        <AccountDomain as FromQueryRow<Account>>::selector(),
+        SelectorTuple::<Account>::build(|account| account.id.domain.name)
+    );
+}
+
+#[test]
+fn constructs_from_row() {
+    let definition: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+
+    assert_eq!(
+        Balance::from_row((definition.clone(), Numeric::from(13_u32))),
+        Balance {
+            definition,
+            value: Numeric::from(13_u32),
+        }
+    );
+    assert_eq!(
+        AccountDomain::from_row(("wonderland".parse().unwrap(),)),
+        AccountDomain {
+            domain: "wonderland".parse().unwrap(),
+        }
+    );
+}
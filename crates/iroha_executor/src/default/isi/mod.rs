@@ -1,4 +1,6 @@
-use iroha_executor_data_model::isi::multisig::MultisigInstructionBox;
+use iroha_executor_data_model::isi::{
+    multisig::MultisigInstructionBox, spending_limit::SpendingLimitInstructionBox,
+};
 
 use super::*;
 use crate::prelude::{Execute, Vec, Visit};
@@ -10,6 +12,9 @@ pub fn visit_custom_instruction<V: Execute + Visit + ?Sized>(
     if let Ok(instruction) = MultisigInstructionBox::try_from(instruction.payload()) {
         return instruction.visit_execute(executor);
     }
+    if let Ok(instruction) = SpendingLimitInstructionBox::try_from(instruction.payload()) {
+        return instruction.visit_execute(executor);
+    }
 
     deny!(executor, "unexpected custom instruction");
 }
@@ -46,4 +51,5 @@ macro_rules! visit_seq {
     };
 }
 
-mod multisig;
+pub(super) mod multisig;
+pub(super) mod spending_limit;
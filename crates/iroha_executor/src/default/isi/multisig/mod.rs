@@ -1,7 +1,12 @@
+use alloc::string::String;
+
 use iroha_executor_data_model::isi::multisig::*;
 
 use super::*;
-use crate::smart_contract::{DebugExpectExt as _, DebugUnwrapExt};
+use crate::{
+    data_model::query::error::QueryExecutionFail,
+    smart_contract::{DebugExpectExt as _, DebugUnwrapExt},
+};
 
 mod account;
 mod transaction;
@@ -30,6 +35,56 @@ fn proposal_key(hash: &HashOf<Vec<InstructionBox>>) -> Name {
         .unwrap()
 }
 
+/// Metadata key of the domain listing the names of the permissions which can be granted
+/// to its accounts only by multisig accounts, i.e. through approved proposals
+fn multisig_grants_key() -> Name {
+    format!("{MULTISIG}{DELIMITER}grants").parse().unwrap()
+}
+
+/// Check that the grant of `permission` to `account` either isn't required to go through
+/// a multisig proposal by the account's domain or is made by a multisig account
+pub(in crate::default) fn validate_grant<V: Execute + Visit + ?Sized>(
+    executor: &V,
+    account: &AccountId,
+    permission: &Permission,
+) -> Result<(), ValidationFail> {
+    if executor.context().curr_block.is_genesis() {
+        return Ok(());
+    }
+    let Some(guarded) =
+        crate::state::domain_metadata(account.domain(), &multisig_grants_key(), executor.host())?
+    else {
+        return Ok(());
+    };
+    let guarded: Vec<String> = guarded.try_into_any().map_err(|err| {
+        ValidationFail::QueryFailed(QueryExecutionFail::Conversion(format!(
+            "multisig grants of the domain are malformed:\n{err}"
+        )))
+    })?;
+
+    if !guarded.iter().any(|name| name == permission.name())
+        || is_multisig(&executor.context().authority, executor)
+    {
+        return Ok(());
+    }
+
+    Err(ValidationFail::NotPermitted(format!(
+        "{} can be granted to accounts of domain `{}` only through a multisig proposal",
+        permission.name(),
+        account.domain()
+    )))
+}
+
+fn is_multisig<V: Execute + Visit + ?Sized>(account: &AccountId, executor: &V) -> bool {
+    executor
+        .host()
+        .query(FindRoleIds)
+        .filter_with(|role_id| role_id.eq(multisig_role_for(account)))
+        .execute_single_opt()
+        .dbg_unwrap()
+        .is_some()
+}
+
 fn multisig_role_for(account: &AccountId) -> RoleId {
     format!(
         "{MULTISIG_SIGNATORY}{DELIMITER}{}{DELIMITER}{}",
@@ -131,16 +131,6 @@ fn deploy_relayer<V: Execute + Visit + ?Sized>(
     Ok(())
 }
 
-fn is_multisig<V: Execute + Visit + ?Sized>(account: &AccountId, executor: &V) -> bool {
-    executor
-        .host()
-        .query(FindRoleIds)
-        .filter_with(|role_id| role_id.eq(multisig_role_for(account)))
-        .execute_single_opt()
-        .dbg_unwrap()
-        .is_some()
-}
-
 fn multisig_spec<V: Execute + Visit + ?Sized>(
     multisig_account: AccountId,
     executor: &V,
@@ -0,0 +1,134 @@
+//! Validation and execution logic of instructions capping the amounts transferred from accounts
+
+use iroha_executor_data_model::isi::spending_limit::*;
+
+use super::*;
+use crate::{permission::domain::is_domain_owner, Iroha};
+
+impl VisitExecute for SpendingLimitInstructionBox {
+    fn visit_execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) {
+        match self {
+            SpendingLimitInstructionBox::Set(instruction) => instruction.visit_execute(executor),
+            SpendingLimitInstructionBox::Remove(instruction) => instruction.visit_execute(executor),
+        }
+    }
+}
+
+impl VisitExecute for SetSpendingLimit {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        visit_limited_account(&self.account, executor);
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let mut counter = spending_counter(
+            &self.account,
+            &self.asset_definition,
+            None,
+            self.window,
+            executor.host(),
+        )?;
+        counter.limit = Some(self.limit);
+
+        executor
+            .host()
+            .submit(&SetSpendingCounter::new(self.account, counter))
+    }
+}
+
+impl VisitExecute for RemoveSpendingLimit {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        visit_limited_account(&self.account, executor);
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let mut counter = spending_counter(
+            &self.account,
+            &self.asset_definition,
+            None,
+            self.window,
+            executor.host(),
+        )?;
+        if counter.limit.take().is_none() {
+            return Ok(());
+        }
+
+        executor
+            .host()
+            .submit(&SetSpendingCounter::new(self.account, counter))
+    }
+}
+
+/// Only the owner of the account's domain may manage its limits, the account itself may not
+fn visit_limited_account<V: Execute + Visit + ?Sized>(account: &AccountId, executor: &mut V) {
+    if executor.context().curr_block.is_genesis() {
+        return;
+    }
+    match is_domain_owner(
+        account.domain(),
+        &executor.context().authority,
+        executor.host(),
+    ) {
+        Err(err) => deny!(executor, err),
+        Ok(true) => {}
+        Ok(false) => deny!(
+            executor,
+            "Only the domain owner can manage spending limits of its accounts"
+        ),
+    }
+}
+
+/// Add the transferred amount to what the source account spent in the current windows,
+/// failing if it exceeds any of the account's limits for the asset.
+pub(in crate::default) fn spend<V: Execute + Visit + ?Sized>(
+    executor: &mut V,
+    isi: &Transfer<Asset, Numeric, Account>,
+) -> Result<(), ValidationFail> {
+    let asset_id = isi.source();
+    let amount = *isi.object();
+    let now = executor.context().curr_block.creation_time();
+
+    let limited = crate::state::spending_counters(asset_id.account(), executor.host())?
+        .into_iter()
+        .filter(|counter| {
+            counter.spender.is_none() && counter.asset_definition == *asset_id.definition()
+        })
+        .filter_map(|counter| counter.limit.map(|limit| (counter, limit)))
+        .collect::<Vec<_>>();
+    for (mut counter, limit) in limited {
+        if counter.spend(amount, now).is_none_or(|spent| spent > limit) {
+            let window = match counter.window() {
+                SpendingWindow::Day => "daily",
+                SpendingWindow::Week => "weekly",
+            };
+            return Err(ValidationFail::NotPermitted(format!(
+                "Transfer exceeds the {window} spending limit of {limit} for the account"
+            )));
+        }
+
+        executor.host().submit(&SetSpendingCounter::new(
+            asset_id.account().clone(),
+            counter,
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Counter of the transfers of `asset_definition` from `account` made by `spender` in `window`,
+/// with nothing spent if the executor hasn't counted any yet
+pub(in crate::default) fn spending_counter(
+    account: &AccountId,
+    asset_definition: &AssetDefinitionId,
+    spender: Option<&AccountId>,
+    window: SpendingWindow,
+    host: &Iroha,
+) -> Result<SpendingCounter, ValidationFail> {
+    let counter = crate::state::spending_counters(account, host)?
+        .into_iter()
+        .find(|counter| counter.counts(asset_definition, spender, window))
+        .unwrap_or_else(|| {
+            SpendingCounter::new(asset_definition.clone(), spender.cloned(), window)
+        });
+
+    Ok(counter)
+}
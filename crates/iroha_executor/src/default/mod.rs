@@ -1,12 +1,14 @@
 //! Definition of Iroha default executor and accompanying execute functions
 #![allow(missing_docs, clippy::missing_errors_doc)]
 
-use alloc::format;
+use alloc::{collections::BTreeSet, format, vec::Vec};
 
 pub use account::{
-    visit_register_account, visit_remove_account_key_value, visit_set_account_key_value,
+    visit_event_subscription, visit_mark_redactable, visit_redact, visit_register_account,
+    visit_register_accounts, visit_remove_account_key_value, visit_set_account_key_value,
     visit_unregister_account,
 };
+pub use anchor::visit_anchor;
 pub use asset::{visit_burn_asset_numeric, visit_mint_asset_numeric, visit_transfer_asset_numeric};
 pub use asset_definition::{
     visit_register_asset_definition, visit_remove_asset_definition_key_value,
@@ -14,10 +16,11 @@ pub use asset_definition::{
     visit_unregister_asset_definition,
 };
 pub use domain::{
-    visit_register_domain, visit_remove_domain_key_value, visit_set_domain_key_value,
-    visit_transfer_domain, visit_unregister_domain,
+    visit_accept_domain_transfer, visit_register_domain, visit_remove_domain_key_value,
+    visit_set_domain_key_value, visit_set_domain_validator, visit_transfer_domain,
+    visit_unregister_domain,
 };
-pub use executor::visit_upgrade;
+pub use executor::{visit_abort_executor_upgrade, visit_upgrade};
 use iroha_smart_contract::data_model::{prelude::*, visit::Visit};
 pub use isi::visit_custom_instruction;
 pub use log::visit_log;
@@ -27,11 +30,22 @@ pub use nft::{
 };
 pub use parameter::visit_set_parameter;
 pub use peer::{visit_register_peer, visit_unregister_peer};
-pub use permission::{visit_grant_account_permission, visit_revoke_account_permission};
+pub use permission::{
+    visit_grant_account_permission, visit_grant_account_permission_group,
+    visit_grant_account_revocable_permission, visit_revoke_account_permission,
+    visit_revoke_account_permission_group, visit_revoke_all_account_permissions,
+};
+pub use query::{
+    visit_find_account_statement, visit_find_accounts_with_asset, visit_find_excluded_transactions,
+    visit_find_spending_counters, visit_find_transactions,
+};
 pub use role::{
-    visit_grant_account_role, visit_grant_role_permission, visit_register_role,
-    visit_revoke_account_role, visit_revoke_role_permission, visit_unregister_role,
+    visit_grant_account_role, visit_grant_inherited_role, visit_grant_role_permission,
+    visit_instantiate_role_template, visit_register_role, visit_register_role_template,
+    visit_revoke_account_role, visit_revoke_inherited_role, visit_revoke_role_permission,
+    visit_transfer_role, visit_unregister_role, visit_unregister_role_template,
 };
+pub use spending::visit_set_spending_counter;
 pub use trigger::{
     visit_burn_trigger_repetitions, visit_execute_trigger, visit_mint_trigger_repetitions,
     visit_register_trigger, visit_remove_trigger_key_value, visit_set_trigger_key_value,
@@ -85,6 +99,24 @@ pub fn visit_instruction<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &In
         InstructionBox::Log(isi) => {
             executor.visit_log(isi);
         }
+        InstructionBox::Anchor(isi) => {
+            executor.visit_anchor(isi);
+        }
+        InstructionBox::InstantiateRoleTemplate(isi) => {
+            executor.visit_instantiate_role_template(isi);
+        }
+        InstructionBox::SetDomainValidator(isi) => {
+            executor.visit_set_domain_validator(isi);
+        }
+        InstructionBox::MarkRedactable(isi) => {
+            executor.visit_mark_redactable(isi);
+        }
+        InstructionBox::Redact(isi) => {
+            executor.visit_redact(isi);
+        }
+        InstructionBox::AcceptDomainTransfer(isi) => {
+            executor.visit_accept_domain_transfer(isi);
+        }
         InstructionBox::ExecuteTrigger(isi) => {
             executor.visit_execute_trigger(isi);
         }
@@ -118,6 +150,12 @@ pub fn visit_instruction<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &In
         InstructionBox::Upgrade(isi) => {
             executor.visit_upgrade(isi);
         }
+        InstructionBox::AbortExecutorUpgrade(isi) => {
+            executor.visit_abort_executor_upgrade(isi);
+        }
+        InstructionBox::SetSpendingCounter(isi) => {
+            executor.visit_set_spending_counter(isi);
+        }
         InstructionBox::Custom(isi) => {
             executor.visit_custom_instruction(isi);
         }
@@ -176,11 +214,23 @@ pub mod domain {
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
-        if CanRegisterDomain.is_owned_by(&executor.context().authority, executor.host()) {
-            execute!(executor, isi);
+        if !CanRegisterDomain.is_owned_by(&executor.context().authority, executor.host()) {
+            deny!(executor, "Can't register domain");
         }
 
-        deny!(executor, "Can't register domain");
+        // The default role is granted on behalf of the domain, so it must be grantable by its registrar
+        if let Some(default_role) = isi.object().default_role() {
+            let authority_roles =
+                crate::permission::account_roles(&executor.context().authority, executor.host());
+            if !authority_roles.iter().any(|role| role.id() == default_role) {
+                deny!(
+                    executor,
+                    "Can't set a role not held by the authority as default"
+                );
+            }
+        }
+
+        execute!(executor, isi);
     }
 
     pub fn visit_unregister_domain<V: Execute + Visit + ?Sized>(
@@ -238,6 +288,14 @@ pub mod domain {
         deny!(executor, "Can't transfer domain of another account");
     }
 
+    /// Only the account the domain was offered to can accept the offer, which is checked on execution
+    pub fn visit_accept_domain_transfer<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &AcceptDomainTransfer,
+    ) {
+        execute!(executor, isi);
+    }
+
     pub fn visit_set_domain_key_value<V: Execute + Visit + ?Sized>(
         executor: &mut V,
         isi: &SetKeyValue<Domain>,
@@ -290,6 +348,23 @@ pub mod domain {
         deny!(executor, "Can't remove key value in domain metadata");
     }
 
+    /// Only the domain owner may set the validator of a domain
+    pub fn visit_set_domain_validator<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &SetDomainValidator,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_domain_owner(isi.domain(), &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't set validator of another account's domain");
+    }
+
     #[allow(clippy::too_many_lines)]
     fn is_permission_domain_associated(permission: &Permission, domain_id: &DomainId) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
@@ -329,16 +404,32 @@ pub mod domain {
                 permission.asset.definition().domain() == domain_id
                     || permission.asset.account().domain() == domain_id
             }
+            AnyPermission::CannotTransferAsset(permission) => {
+                permission.asset.definition().domain() == domain_id
+                    || permission.asset.account().domain() == domain_id
+            }
+            AnyPermission::CannotTransferAssetWithDefinition(permission) => {
+                permission.asset_definition.domain() == domain_id
+            }
             AnyPermission::CanRegisterNft(permission) => &permission.domain == domain_id,
             AnyPermission::CanUnregisterNft(permission) => permission.nft.domain() == domain_id,
             AnyPermission::CanTransferNft(permission) => permission.nft.domain() == domain_id,
             AnyPermission::CanModifyNftMetadata(permission) => permission.nft.domain() == domain_id,
+            AnyPermission::CanTransferAssetInDomain(permission) => &permission.domain == domain_id,
+            AnyPermission::CanModifyAnyNftInDomain(permission) => &permission.domain == domain_id,
+            AnyPermission::CanRegisterTriggerInDomain(permission) => {
+                &permission.domain == domain_id
+            }
+            AnyPermission::CanQueryAssetsInDomain(permission) => &permission.domain == domain_id,
             AnyPermission::CanUnregisterAccount(permission) => {
                 permission.account.domain() == domain_id
             }
             AnyPermission::CanModifyAccountMetadata(permission) => {
                 permission.account.domain() == domain_id
             }
+            AnyPermission::CanSubscribeToAccountEvents(permission) => {
+                permission.account.domain() == domain_id
+            }
             AnyPermission::CanRegisterTrigger(permission) => {
                 permission.authority.domain() == domain_id
             }
@@ -349,6 +440,7 @@ pub mod domain {
             | AnyPermission::CanManagePeers(_)
             | AnyPermission::CanRegisterDomain(_)
             | AnyPermission::CanSetParameters(_)
+            | AnyPermission::CanQueryTransactions(_)
             | AnyPermission::CanManageRoles(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
@@ -357,41 +449,125 @@ pub mod domain {
 
 pub mod account {
     use iroha_executor_data_model::permission::account::{
-        CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
+        CanModifyAccountMetadata, CanRegisterAccount, CanSubscribeToAccountEvents,
+        CanUnregisterAccount,
     };
 
     use super::*;
     use crate::permission::{account::is_account_owner, revoke_permissions};
 
+    /// Events about an account are delivered to the account itself
+    /// and to the accounts it granted [`CanSubscribeToAccountEvents`] to
+    pub fn visit_event_subscription<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        account: &AccountId,
+    ) {
+        if *account == executor.context().authority {
+            return;
+        }
+        let can_subscribe_token = CanSubscribeToAccountEvents {
+            account: account.clone(),
+        };
+        if can_subscribe_token.is_owned_by(&executor.context().authority, executor.host()) {
+            return;
+        }
+
+        deny!(
+            executor,
+            "Can't subscribe to events of another account without permission"
+        );
+    }
+
     pub fn visit_register_account<V: Execute + Visit + ?Sized>(
         executor: &mut V,
         isi: &Register<Account>,
     ) {
-        let domain_id = isi.object().id().domain();
+        let account_id = isi.object().id();
+        let domain_id = account_id.domain();
 
-        match crate::permission::domain::is_domain_owner(
+        let can_register = match crate::permission::domain::is_domain_owner(
             domain_id,
             &executor.context().authority,
             executor.host(),
         ) {
             Err(err) => deny!(executor, err),
-            Ok(true) => execute!(executor, isi),
-            Ok(false) => {}
+            Ok(is_domain_owner) => {
+                is_domain_owner
+                    || CanRegisterAccount {
+                        domain: domain_id.clone(),
+                    }
+                    .is_owned_by(&executor.context().authority, executor.host())
+            }
+        };
+        if !can_register {
+            deny!(
+                executor,
+                "Can't register account in a domain owned by another account"
+            );
         }
 
-        let can_register_account_in_domain = CanRegisterAccount {
-            domain: domain_id.clone(),
-        };
-        if can_register_account_in_domain
-            .is_owned_by(&executor.context().authority, executor.host())
-        {
-            execute!(executor, isi);
+        if let Err(err) = executor.host().submit(isi) {
+            deny!(executor, err);
         }
+        if let Err(err) = grant_default_role(account_id, executor.host()) {
+            deny!(executor, err);
+        }
+    }
 
-        deny!(
-            executor,
-            "Can't register account in a domain owned by another account"
-        );
+    pub fn visit_register_accounts<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RegisterAccounts,
+    ) {
+        // Accounts usually share a few domains, so each domain is only checked once
+        let domain_ids = isi
+            .objects()
+            .iter()
+            .map(|account| account.id().domain())
+            .collect::<BTreeSet<_>>();
+
+        for domain_id in domain_ids {
+            match crate::permission::domain::is_domain_owner(
+                domain_id,
+                &executor.context().authority,
+                executor.host(),
+            ) {
+                Err(err) => deny!(executor, err),
+                Ok(true) => continue,
+                Ok(false) => {}
+            }
+
+            let can_register_account_in_domain = CanRegisterAccount {
+                domain: domain_id.clone(),
+            };
+            if !can_register_account_in_domain
+                .is_owned_by(&executor.context().authority, executor.host())
+            {
+                deny!(
+                    executor,
+                    "Can't register account in a domain owned by another account"
+                );
+            }
+        }
+
+        if let Err(err) = executor.host().submit(isi) {
+            deny!(executor, err);
+        }
+        for account in isi.objects() {
+            if let Err(err) = grant_default_role(account.id(), executor.host()) {
+                deny!(executor, err);
+            }
+        }
+    }
+
+    /// Grant the default role of the account's domain, if it has one.
+    ///
+    /// The role was checked to be grantable when it was set on the domain.
+    fn grant_default_role(account_id: &AccountId, host: &Iroha) -> Result<(), ValidationFail> {
+        if let Some(role_id) = crate::state::domain_default_role(account_id.domain(), host)? {
+            host.submit(&Grant::account_role(role_id, account_id.clone()))?;
+        }
+
+        Ok(())
     }
 
     pub fn visit_unregister_account<V: Execute + Visit + ?Sized>(
@@ -483,6 +659,56 @@ pub mod account {
         );
     }
 
+    pub fn visit_mark_redactable<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &MarkRedactable,
+    ) {
+        let account_id = isi.account();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_modify_account_metadata_token = CanModifyAccountMetadata {
+            account: account_id.clone(),
+        };
+        if can_modify_account_metadata_token
+            .is_owned_by(&executor.context().authority, executor.host())
+        {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't mark values in the metadata of another account redactable"
+        );
+    }
+
+    /// Redaction is administrative, so only the owner of the account's domain may redact its values
+    pub fn visit_redact<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Redact) {
+        let account_id = isi.account();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match crate::permission::domain::is_domain_owner(
+            account_id.domain(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Only the domain owner can redact values in the metadata of its accounts"
+            ),
+        }
+    }
+
     fn is_permission_account_associated(permission: &Permission, account_id: &AccountId) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
             return false;
@@ -492,9 +718,15 @@ pub mod account {
             AnyPermission::CanModifyAccountMetadata(permission) => {
                 permission.account == *account_id
             }
+            AnyPermission::CanSubscribeToAccountEvents(permission) => {
+                permission.account == *account_id
+            }
             AnyPermission::CanMintAsset(permission) => permission.asset.account() == account_id,
             AnyPermission::CanBurnAsset(permission) => permission.asset.account() == account_id,
             AnyPermission::CanTransferAsset(permission) => permission.asset.account() == account_id,
+            AnyPermission::CannotTransferAsset(permission) => {
+                permission.asset.account() == account_id
+            }
             AnyPermission::CanRegisterTrigger(permission) => permission.authority == *account_id,
             AnyPermission::CanUnregisterTrigger(_)
             | AnyPermission::CanExecuteTrigger(_)
@@ -511,10 +743,16 @@ pub mod account {
             | AnyPermission::CanMintAssetWithDefinition(_)
             | AnyPermission::CanBurnAssetWithDefinition(_)
             | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CannotTransferAssetWithDefinition(_)
             | AnyPermission::CanRegisterNft(_)
             | AnyPermission::CanUnregisterNft(_)
             | AnyPermission::CanTransferNft(_)
             | AnyPermission::CanModifyNftMetadata(_)
+            | AnyPermission::CanTransferAssetInDomain(_)
+            | AnyPermission::CanModifyAnyNftInDomain(_)
+            | AnyPermission::CanRegisterTriggerInDomain(_)
+            | AnyPermission::CanQueryAssetsInDomain(_)
+            | AnyPermission::CanQueryTransactions(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
@@ -731,8 +969,15 @@ pub mod asset_definition {
             AnyPermission::CanTransferAsset(permission) => {
                 permission.asset.definition() == asset_definition_id
             }
+            AnyPermission::CannotTransferAsset(permission) => {
+                permission.asset.definition() == asset_definition_id
+            }
+            AnyPermission::CannotTransferAssetWithDefinition(permission) => {
+                &permission.asset_definition == asset_definition_id
+            }
             AnyPermission::CanUnregisterAccount(_)
             | AnyPermission::CanModifyAccountMetadata(_)
+            | AnyPermission::CanSubscribeToAccountEvents(_)
             | AnyPermission::CanRegisterTrigger(_)
             | AnyPermission::CanUnregisterTrigger(_)
             | AnyPermission::CanExecuteTrigger(_)
@@ -748,6 +993,11 @@ pub mod asset_definition {
             | AnyPermission::CanUnregisterNft(_)
             | AnyPermission::CanTransferNft(_)
             | AnyPermission::CanModifyNftMetadata(_)
+            | AnyPermission::CanTransferAssetInDomain(_)
+            | AnyPermission::CanModifyAnyNftInDomain(_)
+            | AnyPermission::CanRegisterTriggerInDomain(_)
+            | AnyPermission::CanQueryAssetsInDomain(_)
+            | AnyPermission::CanQueryTransactions(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
@@ -758,7 +1008,8 @@ pub mod asset_definition {
 pub mod asset {
     use iroha_executor_data_model::permission::asset::{
         CanBurnAsset, CanBurnAssetWithDefinition, CanMintAsset, CanMintAssetWithDefinition,
-        CanTransferAsset, CanTransferAssetWithDefinition,
+        CanTransferAsset, CanTransferAssetInDomain, CanTransferAssetWithDefinition,
+        CannotTransferAsset, CannotTransferAssetWithDefinition,
     };
     use iroha_smart_contract::data_model::isi::BuiltInInstruction;
     use iroha_smart_contract_utils::Encode;
@@ -870,6 +1121,15 @@ pub mod asset {
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
+        if is_transfer_denied(asset_id, &executor.context().authority, executor.host()) {
+            deny!(
+                executor,
+                "Transfer of this asset is denied for the authority"
+            );
+        }
+        if let Err(err) = super::isi::spending_limit::spend(executor, isi) {
+            deny!(executor, err);
+        }
         match is_asset_owner(asset_id, &executor.context().authority, executor.host()) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
@@ -884,6 +1144,14 @@ pub mod asset {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
+        let can_transfer_assets_in_domain_token = CanTransferAssetInDomain {
+            domain: asset_id.definition().domain().clone(),
+        };
+        if can_transfer_assets_in_domain_token
+            .is_owned_by(&executor.context().authority, executor.host())
+        {
+            execute!(executor, isi);
+        }
         let can_transfer_assets_with_definition_token = CanTransferAssetWithDefinition {
             asset_definition: asset_id.definition().clone(),
         };
@@ -892,21 +1160,93 @@ pub mod asset {
         {
             execute!(executor, isi);
         }
-        let can_transfer_user_asset_token = CanTransferAsset {
+        match spend_transfer_allowance(executor, isi) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't transfer assets of another account");
+    }
+
+    /// Check if `authority` holds a deny permission for transferring the asset.
+    ///
+    /// Deny permissions are checked before any allowing rule, so they win over ownership too.
+    fn is_transfer_denied(asset_id: &AssetId, authority: &AccountId, host: &Iroha) -> bool {
+        let asset_token = CannotTransferAsset {
             asset: asset_id.clone(),
         };
-        if can_transfer_user_asset_token.is_owned_by(&executor.context().authority, executor.host())
+        let asset_definition_token = CannotTransferAssetWithDefinition {
+            asset_definition: asset_id.definition().clone(),
+        };
+
+        asset_token.is_owned_by(authority, host)
+            || asset_definition_token.is_owned_by(authority, host)
+    }
+
+    /// Check if the authority can make the transfer under one of its [`CanTransferAsset`]
+    /// permissions and count the amount against the daily limit if all of them have one.
+    fn spend_transfer_allowance<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Transfer<Asset, Numeric, Account>,
+    ) -> Result<bool, ValidationFail> {
+        let asset_id = isi.source();
+        let amount = *isi.object();
+        let authority = executor.context().authority.clone();
+
+        let permissions =
+            crate::permission::owned_permissions::<CanTransferAsset>(&authority, executor.host())
+                .into_iter()
+                .filter(|permission| permission.asset == *asset_id)
+                .filter(|permission| {
+                    permission
+                        .max_amount_per_transaction
+                        .is_none_or(|max_amount| amount <= max_amount)
+                })
+                .collect::<Vec<_>>();
+        if permissions.is_empty() {
+            return Ok(false);
+        }
+        if permissions
+            .iter()
+            .any(|permission| permission.max_amount_per_day.is_none())
         {
-            execute!(executor, isi);
+            return Ok(true);
         }
 
-        deny!(executor, "Can't transfer assets of another account");
+        // The daily amount is counted per holder of the permissions
+        let mut counter = super::isi::spending_limit::spending_counter(
+            asset_id.account(),
+            asset_id.definition(),
+            Some(&authority),
+            SpendingWindow::Day,
+            executor.host(),
+        )?;
+        let Some(total) = counter.spend(amount, executor.context().curr_block.creation_time())
+        else {
+            return Ok(false);
+        };
+        if !permissions
+            .iter()
+            .filter_map(|permission| permission.max_amount_per_day)
+            .any(|max_amount| total <= max_amount)
+        {
+            return Ok(false);
+        }
+
+        executor.host().submit(&SetSpendingCounter::new(
+            asset_id.account().clone(),
+            counter,
+        ))?;
+
+        Ok(true)
     }
 }
 
 pub mod nft {
     use iroha_executor_data_model::permission::nft::{
-        CanModifyNftMetadata, CanRegisterNft, CanTransferNft, CanUnregisterNft,
+        CanModifyAnyNftInDomain, CanModifyNftMetadata, CanRegisterNft, CanTransferNft,
+        CanUnregisterNft,
     };
     use iroha_smart_contract_utils::Encode;
 
@@ -1053,6 +1393,14 @@ pub mod nft {
             Ok(false) => {}
         }
 
+        let can_modify_any_nft_in_domain_token = CanModifyAnyNftInDomain {
+            domain: nft_id.domain().clone(),
+        };
+        if can_modify_any_nft_in_domain_token
+            .is_owned_by(&executor.context().authority, executor.host())
+        {
+            execute!(executor, isi);
+        }
         let can_modify_nft_token = CanModifyNftMetadata {
             nft: nft_id.clone(),
         };
@@ -1087,27 +1435,186 @@ pub mod parameter {
     }
 }
 
+pub mod query {
+    use iroha_executor_data_model::permission::query::{
+        CanQueryAssetsInDomain, CanQueryTransactions,
+    };
+    use iroha_smart_contract::data_model::query::QueryWithFilter;
+
+    use super::*;
+    use crate::permission::domain::is_domain_owner;
+
+    pub fn visit_find_transactions<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        query: &QueryWithFilter<FindTransactions>,
+    ) {
+        if is_scoped_to_authority(&query.predicate, &executor.context().authority) {
+            return;
+        }
+        if CanQueryTransactions.is_owned_by(&executor.context().authority, executor.host()) {
+            return;
+        }
+
+        deny!(
+            executor,
+            "Can't query transactions of other accounts without permission"
+        );
+    }
+
+    pub fn visit_find_excluded_transactions<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        query: &QueryWithFilter<FindExcludedTransactions>,
+    ) {
+        // Excluded transactions don't record their authority, so only a known hash scopes the query
+        if matches!(
+            query.predicate,
+            CompoundPredicate::Atom(ExcludedTransactionProjection::TransactionEntrypointHash(
+                TransactionEntrypointHashProjection::Atom(
+                    TransactionEntrypointHashPredicateAtom::Equals(_),
+                ),
+            ))
+        ) {
+            return;
+        }
+        if CanQueryTransactions.is_owned_by(&executor.context().authority, executor.host()) {
+            return;
+        }
+
+        deny!(
+            executor,
+            "Can't query excluded transactions of other accounts without permission"
+        );
+    }
+
+    pub fn visit_find_accounts_with_asset<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        query: &QueryWithFilter<FindAccountsWithAsset>,
+    ) {
+        visit_assets_in_domain(executor, query.query.asset_definition.domain());
+    }
+
+    pub fn visit_find_account_statement<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        query: &FindAccountStatement,
+    ) {
+        if query.account == executor.context().authority {
+            return;
+        }
+
+        visit_assets_in_domain(executor, query.account.domain());
+    }
+
+    pub fn visit_find_spending_counters<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        query: &FindSpendingCounters,
+    ) {
+        if query.account == executor.context().authority {
+            return;
+        }
+
+        visit_assets_in_domain(executor, query.account.domain());
+    }
+
+    /// Accounts may read assets of their own domain and of the domains they own,
+    /// other domains have to be opened to them with [`CanQueryAssetsInDomain`]
+    fn visit_assets_in_domain<V: Execute + Visit + ?Sized>(executor: &mut V, domain: &DomainId) {
+        let authority = &executor.context().authority;
+        if authority.domain() == domain {
+            return;
+        }
+        match is_domain_owner(domain, authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => return,
+            Ok(false) => {}
+        }
+        let can_query_assets_token = CanQueryAssetsInDomain {
+            domain: domain.clone(),
+        };
+        if can_query_assets_token.is_owned_by(authority, executor.host()) {
+            return;
+        }
+
+        deny!(
+            executor,
+            "Can't query assets of a domain owned by another account"
+        );
+    }
+
+    /// Whether the predicate only lets through transactions submitted by `authority`
+    /// or the transaction with a known entrypoint hash
+    fn is_scoped_to_authority(
+        predicate: &CompoundPredicate<CommittedTransaction>,
+        authority: &AccountId,
+    ) -> bool {
+        match predicate {
+            CompoundPredicate::Atom(CommittedTransactionProjection::TransactionEntrypoint(
+                TransactionEntrypointProjection::Authority(AccountIdProjection::Atom(
+                    AccountIdPredicateAtom::Equals(expected),
+                )),
+            )) => expected == authority,
+            CompoundPredicate::Atom(CommittedTransactionProjection::TransactionEntrypointHash(
+                TransactionEntrypointHashProjection::Atom(
+                    TransactionEntrypointHashPredicateAtom::Equals(_),
+                ),
+            )) => true,
+            CompoundPredicate::And(predicates) => predicates
+                .iter()
+                .any(|predicate| is_scoped_to_authority(predicate, authority)),
+            CompoundPredicate::Atom(_) | CompoundPredicate::Not(_) | CompoundPredicate::Or(_) => {
+                false
+            }
+        }
+    }
+}
+
 pub mod role {
     use iroha_executor_data_model::permission::role::CanManageRoles;
-    use iroha_smart_contract::{data_model::role::Role, Iroha};
+    use iroha_smart_contract::{
+        data_model::role::{Role, RoleTemplate},
+        Iroha,
+    };
 
     use super::*;
+    use crate::permission::role::is_role_owner;
 
     macro_rules! impl_execute_grant_revoke_account_role {
         ($executor:ident, $isi:ident) => {
             let role_id = $isi.object();
 
-            if $executor.context().curr_block.is_genesis()
-                || find_account_roles($executor.context().authority.clone(), $executor.host())
-                    .any(|authority_role_id| authority_role_id == *role_id)
-            {
+            if $executor.context().curr_block.is_genesis() {
                 execute!($executor, $isi)
             }
+            match can_administer_role(role_id, &$executor.context().authority, $executor.host()) {
+                Err(err) => deny!($executor, err),
+                Ok(true) => execute!($executor, $isi),
+                Ok(false) => {}
+            }
 
             deny!($executor, "Can't grant or revoke role to another account");
         };
     }
 
+    macro_rules! impl_execute_grant_revoke_inherited_role {
+        ($executor:ident, $isi:ident) => {
+            let role_id = $isi.destination();
+            let inherited_role_id = $isi.object();
+
+            if $executor.context().curr_block.is_genesis() {
+                execute!($executor, $isi)
+            }
+            let has_inherited_role =
+                find_account_roles($executor.context().authority.clone(), $executor.host())
+                    .any(|authority_role_id| authority_role_id == *inherited_role_id);
+            match can_administer_role(role_id, &$executor.context().authority, $executor.host()) {
+                Err(err) => deny!($executor, err),
+                Ok(true) if has_inherited_role => execute!($executor, $isi),
+                Ok(_) => {}
+            }
+
+            deny!($executor, "Can't modify role");
+        };
+    }
+
     macro_rules! impl_execute_grant_revoke_role_permission {
         ($executor:ident, $isi:ident, $method:ident, $isi_type:ty) => {
             let role_id = $isi.destination().clone();
@@ -1115,10 +1622,14 @@ pub mod role {
 
             if let Ok(any_permission) = AnyPermission::try_from(permission) {
                 if !$executor.context().curr_block.is_genesis() {
-                    if !find_account_roles($executor.context().authority.clone(), $executor.host())
-                        .any(|authority_role_id| authority_role_id == role_id)
-                    {
-                        deny!($executor, "Can't modify role");
+                    match can_administer_role(
+                        &role_id,
+                        &$executor.context().authority,
+                        $executor.host(),
+                    ) {
+                        Err(err) => deny!($executor, err),
+                        Ok(true) => {}
+                        Ok(false) => deny!($executor, "Can't modify role"),
                     }
 
                     if let Err(error) = crate::permission::ValidateGrantRevoke::$method(
@@ -1142,13 +1653,26 @@ pub mod role {
         };
     }
 
+    /// Roles of the account, including the ones inherited by its roles
     fn find_account_roles(account_id: AccountId, host: &Iroha) -> impl Iterator<Item = RoleId> {
-        use iroha_smart_contract::DebugExpectExt as _;
+        crate::permission::account_roles(&account_id, host)
+            .into_iter()
+            .map(|role| role.id().clone())
+    }
 
-        host.query(FindRolesByAccountId::new(account_id))
-            .execute()
-            .dbg_expect("INTERNAL BUG: `FindRolesByAccountId` must never fail")
-            .map(|role| role.dbg_expect("Failed to get role from cursor"))
+    /// Role can be administered by its owner and by the accounts holding it
+    fn can_administer_role(
+        role_id: &RoleId,
+        authority: &AccountId,
+        host: &Iroha,
+    ) -> Result<bool, ValidationFail> {
+        if find_account_roles(authority.clone(), host)
+            .any(|authority_role_id| authority_role_id == *role_id)
+        {
+            return Ok(true);
+        }
+
+        is_role_owner(role_id, authority, host)
     }
 
     pub fn visit_register_role<V: Execute + Visit + ?Sized>(
@@ -1224,6 +1748,28 @@ pub mod role {
             new_role = new_role.add_permission(any_permission);
         }
 
+        if role.inner().inherited_roles().next().is_some()
+            && !executor.context().curr_block.is_genesis()
+        {
+            let authority_roles =
+                find_account_roles(executor.context().authority.clone(), executor.host())
+                    .collect::<Vec<_>>();
+
+            if role
+                .inner()
+                .inherited_roles()
+                .any(|inherited_role_id| !authority_roles.contains(inherited_role_id))
+            {
+                deny!(
+                    executor,
+                    "Can't inherit role which the authority doesn't have"
+                );
+            }
+        }
+        for inherited_role_id in role.inner().inherited_roles() {
+            new_role = new_role.inherit(inherited_role_id.clone());
+        }
+
         if executor.context().curr_block.is_genesis()
             || CanManageRoles.is_owned_by(&executor.context().authority, executor.host())
         {
@@ -1245,6 +1791,11 @@ pub mod role {
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
+        match is_role_owner(isi.object(), &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
         if CanManageRoles.is_owned_by(&executor.context().authority, executor.host()) {
             execute!(executor, isi);
         }
@@ -1252,6 +1803,73 @@ pub mod role {
         deny!(executor, "Can't unregister role");
     }
 
+    pub fn visit_transfer_role<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Transfer<Account, RoleId, Account>,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_role_owner(isi.object(), &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        if CanManageRoles.is_owned_by(&executor.context().authority, executor.host()) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't transfer role of another account");
+    }
+
+    pub fn visit_register_role_template<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Register<RoleTemplate>,
+    ) {
+        if executor.context().curr_block.is_genesis()
+            || CanManageRoles.is_owned_by(&executor.context().authority, executor.host())
+        {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't register role template");
+    }
+
+    pub fn visit_unregister_role_template<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Unregister<RoleTemplate>,
+    ) {
+        if executor.context().curr_block.is_genesis()
+            || CanManageRoles.is_owned_by(&executor.context().authority, executor.host())
+        {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't unregister role template");
+    }
+
+    /// Instantiated role is validated the same way as a role registered directly
+    pub fn visit_instantiate_role_template<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &InstantiateRoleTemplate,
+    ) {
+        let template = match executor
+            .host()
+            .query_single(FindRoleTemplate::new(isi.template().clone()))
+        {
+            Ok(template) => template,
+            Err(err) => deny!(executor, err),
+        };
+        let new_role =
+            match template.instantiate(isi.role().clone(), isi.grant_to().clone(), isi.arguments())
+            {
+                Ok(new_role) => new_role,
+                Err(err) => deny!(executor, ValidationFail::NotPermitted(format!("{err}"))),
+            };
+
+        visit_register_role(executor, &Register::role(new_role));
+    }
+
     pub fn visit_grant_account_role<V: Execute + Visit + ?Sized>(
         executor: &mut V,
         isi: &Grant<RoleId, Account>,
@@ -1279,12 +1897,26 @@ pub mod role {
     ) {
         impl_execute_grant_revoke_role_permission!(executor, isi, validate_revoke, Revoke<Permission, Role>);
     }
+
+    pub fn visit_grant_inherited_role<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Grant<RoleId, Role>,
+    ) {
+        impl_execute_grant_revoke_inherited_role!(executor, isi);
+    }
+
+    pub fn visit_revoke_inherited_role<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Revoke<RoleId, Role>,
+    ) {
+        impl_execute_grant_revoke_inherited_role!(executor, isi);
+    }
 }
 
 pub mod trigger {
     use iroha_executor_data_model::permission::trigger::{
         CanExecuteTrigger, CanModifyTrigger, CanModifyTriggerMetadata, CanRegisterTrigger,
-        CanUnregisterTrigger,
+        CanRegisterTriggerInDomain, CanUnregisterTrigger,
     };
     use iroha_smart_contract::data_model::trigger::Trigger;
 
@@ -1318,6 +1950,13 @@ pub mod trigger {
                 can_register_user_trigger_token
                     .is_owned_by(&executor.context().authority, executor.host())
             }
+            || {
+                let can_register_trigger_in_domain_token = CanRegisterTriggerInDomain {
+                    domain: trigger.action().authority().domain().clone(),
+                };
+                can_register_trigger_in_domain_token
+                    .is_owned_by(&executor.context().authority, executor.host())
+            }
         {
             execute!(executor, isi)
         }
@@ -1515,6 +2154,7 @@ pub mod trigger {
             | AnyPermission::CanRegisterAssetDefinition(_)
             | AnyPermission::CanUnregisterAccount(_)
             | AnyPermission::CanModifyAccountMetadata(_)
+            | AnyPermission::CanSubscribeToAccountEvents(_)
             | AnyPermission::CanUnregisterAssetDefinition(_)
             | AnyPermission::CanModifyAssetDefinitionMetadata(_)
             | AnyPermission::CanMintAssetWithDefinition(_)
@@ -1523,12 +2163,19 @@ pub mod trigger {
             | AnyPermission::CanMintAsset(_)
             | AnyPermission::CanBurnAsset(_)
             | AnyPermission::CanTransferAsset(_)
+            | AnyPermission::CannotTransferAsset(_)
+            | AnyPermission::CannotTransferAssetWithDefinition(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
             | AnyPermission::CanRegisterNft(_)
             | AnyPermission::CanUnregisterNft(_)
             | AnyPermission::CanTransferNft(_)
             | AnyPermission::CanModifyNftMetadata(_)
+            | AnyPermission::CanTransferAssetInDomain(_)
+            | AnyPermission::CanModifyAnyNftInDomain(_)
+            | AnyPermission::CanRegisterTriggerInDomain(_)
+            | AnyPermission::CanQueryAssetsInDomain(_)
+            | AnyPermission::CanQueryTransactions(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
     }
@@ -1538,7 +2185,7 @@ pub mod permission {
     use super::*;
 
     macro_rules! impl_execute {
-        ($executor:ident, $isi:ident, $method:ident, $isi_type:ty) => {
+        ($executor:ident, $isi:ident, $method:ident, $isi_type:ty $(, $prepare:ident)?) => {
             let account_id = $isi.destination().clone();
             let permission = $isi.object();
 
@@ -1553,6 +2200,11 @@ pub mod permission {
                         deny!($executor, error);
                     }
                 }
+                $(
+                    if let Err(error) = $prepare($executor, &account_id, &any_permission) {
+                        deny!($executor, error);
+                    }
+                )?
 
                 let isi = &<$isi_type>::account_permission(any_permission, account_id);
                 execute!($executor, isi);
@@ -1569,7 +2221,13 @@ pub mod permission {
         executor: &mut V,
         isi: &Grant<Permission, Account>,
     ) {
-        impl_execute!(executor, isi, validate_grant, Grant<Permission, Account>);
+        impl_execute!(
+            executor,
+            isi,
+            validate_grant,
+            Grant<Permission, Account>,
+            prepare_grant
+        );
     }
 
     pub fn visit_revoke_account_permission<V: Execute + Visit + ?Sized>(
@@ -1578,6 +2236,226 @@ pub mod permission {
     ) {
         impl_execute!(executor, isi, validate_revoke, Revoke<Permission, Account>);
     }
+
+    /// The permission is validated as if it was granted unconditionally
+    pub fn visit_grant_account_revocable_permission<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Grant<RevocablePermission, Account>,
+    ) {
+        let account_id = isi.destination().clone();
+        let permission = isi.object().permission();
+
+        let Ok(any_permission) = AnyPermission::try_from(permission) else {
+            deny!(
+                executor,
+                ValidationFail::NotPermitted(format!("{permission:?}: Unknown permission"))
+            );
+        };
+
+        if !executor.context().curr_block.is_genesis() {
+            if let Err(error) = crate::permission::ValidateGrantRevoke::validate_grant(
+                &any_permission,
+                &executor.context().authority,
+                executor.context(),
+                executor.host(),
+            ) {
+                deny!(executor, error);
+            }
+        }
+        if let Err(error) = prepare_grant(executor, &account_id, &any_permission) {
+            deny!(executor, error);
+        }
+
+        let isi = &Grant::account_revocable_permission(
+            RevocablePermission::new(any_permission, isi.object().revoke_on().clone()),
+            account_id,
+        );
+        execute!(executor, isi);
+    }
+
+    macro_rules! impl_execute_group {
+        ($executor:ident, $isi:ident, $method:ident, $isi_type:ty $(, $prepare:ident)?) => {
+            let account_id = $isi.destination().clone();
+            let mut group = PermissionGroup::default();
+
+            // The group is valid only if each of its permissions could have been granted
+            // or revoked individually
+            for permission in $isi.object().permissions() {
+                let Ok(any_permission) = AnyPermission::try_from(permission) else {
+                    deny!(
+                        $executor,
+                        ValidationFail::NotPermitted(format!("{permission:?}: Unknown permission"))
+                    );
+                };
+
+                if !$executor.context().curr_block.is_genesis() {
+                    if let Err(error) = crate::permission::ValidateGrantRevoke::$method(
+                        &any_permission,
+                        &$executor.context().authority,
+                        $executor.context(),
+                        $executor.host(),
+                    ) {
+                        deny!($executor, error);
+                    }
+                }
+                $(
+                    if let Err(error) = $prepare($executor, &account_id, &any_permission) {
+                        deny!($executor, error);
+                    }
+                )?
+
+                group = group.add_permission(any_permission);
+            }
+
+            let isi = &<$isi_type>::account_permission_group(group, account_id);
+            execute!($executor, isi);
+        };
+    }
+
+    pub fn visit_grant_account_permission_group<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Grant<PermissionGroup, Account>,
+    ) {
+        impl_execute_group!(
+            executor,
+            isi,
+            validate_grant,
+            Grant<PermissionGroup, Account>,
+            prepare_grant
+        );
+    }
+
+    pub fn visit_revoke_account_permission_group<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Revoke<PermissionGroup, Account>,
+    ) {
+        impl_execute_group!(
+            executor,
+            isi,
+            validate_revoke,
+            Revoke<PermissionGroup, Account>
+        );
+    }
+
+    pub fn visit_revoke_all_account_permissions<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RevokeAll,
+    ) {
+        use iroha_smart_contract::DebugExpectExt as _;
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+
+        // Only allowed if every permission could have been revoked individually
+        let permissions = match executor
+            .host()
+            .query(FindPermissionsByAccountId::new(isi.destination().clone()))
+            .execute()
+        {
+            Ok(permissions) => permissions
+                .map(|permission| permission.dbg_expect("Failed to get permission from cursor"))
+                .collect::<alloc::vec::Vec<_>>(),
+            Err(error) => deny!(executor, error),
+        };
+
+        for permission in permissions {
+            let Ok(any_permission) = AnyPermission::try_from(&permission) else {
+                deny!(
+                    executor,
+                    ValidationFail::NotPermitted(format!("{permission:?}: Unknown permission"))
+                );
+            };
+
+            if let Err(error) = crate::permission::ValidateGrantRevoke::validate_revoke(
+                &any_permission,
+                &executor.context().authority,
+                executor.context(),
+                executor.host(),
+            ) {
+                deny!(executor, error);
+            }
+        }
+
+        execute!(executor, isi);
+    }
+
+    /// Check the multisig requirement of the account's domain before unifying the permission
+    /// with the domain-wide ones of the account
+    fn prepare_grant<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        account_id: &AccountId,
+        permission: &AnyPermission,
+    ) -> Result<(), ValidationFail> {
+        super::isi::multisig::validate_grant(
+            executor,
+            account_id,
+            &Permission::from(permission.clone()),
+        )?;
+        unify_with_domain_wide(executor, account_id, permission)
+    }
+
+    /// Keep at most one of a domain-wide permission and the narrower permissions it covers.
+    ///
+    /// Granting a narrower permission to an account which already has the domain-wide one is
+    /// rejected as a duplicate, while granting a domain-wide permission revokes the narrower
+    /// ones it subsumes.
+    fn unify_with_domain_wide<V: Execute + ?Sized>(
+        executor: &mut V,
+        account_id: &AccountId,
+        permission: &AnyPermission,
+    ) -> Result<(), ValidationFail> {
+        use iroha_smart_contract::DebugExpectExt as _;
+
+        let owned = executor
+            .host()
+            .query(FindPermissionsByAccountId::new(account_id.clone()))
+            .execute()
+            .dbg_expect("INTERNAL BUG: `FindPermissionsByAccountId` must never fail")
+            .map(|permission| permission.dbg_expect("Failed to get permission from cursor"))
+            .collect::<alloc::vec::Vec<_>>();
+
+        for owned in owned {
+            let Ok(owned_permission) = AnyPermission::try_from(&owned) else {
+                continue;
+            };
+            if is_subsumed_by(permission, &owned_permission) {
+                return Err(ValidationFail::NotPermitted(format!(
+                    "{:?}: already covered by {owned:?}",
+                    Permission::from(permission.clone())
+                )));
+            }
+            if is_subsumed_by(&owned_permission, permission) {
+                let isi = Revoke::account_permission(owned, account_id.clone());
+                executor.host().submit(&isi)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check if `domain_wide` grants everything `narrow` does.
+    fn is_subsumed_by(narrow: &AnyPermission, domain_wide: &AnyPermission) -> bool {
+        match (narrow, domain_wide) {
+            (
+                AnyPermission::CanTransferAsset(narrow),
+                AnyPermission::CanTransferAssetInDomain(domain_wide),
+            ) => !narrow.delegatable && narrow.asset.definition().domain() == &domain_wide.domain,
+            (
+                AnyPermission::CanTransferAssetWithDefinition(narrow),
+                AnyPermission::CanTransferAssetInDomain(domain_wide),
+            ) => narrow.asset_definition.domain() == &domain_wide.domain,
+            (
+                AnyPermission::CanModifyNftMetadata(narrow),
+                AnyPermission::CanModifyAnyNftInDomain(domain_wide),
+            ) => narrow.nft.domain() == &domain_wide.domain,
+            (
+                AnyPermission::CanRegisterTrigger(narrow),
+                AnyPermission::CanRegisterTriggerInDomain(domain_wide),
+            ) => narrow.authority.domain() == &domain_wide.domain,
+            _ => false,
+        }
+    }
 }
 
 pub mod executor {
@@ -1595,6 +2473,19 @@ pub mod executor {
 
         deny!(executor, "Can't upgrade executor");
     }
+
+    /// Aborting an upgrade requires the same permission as upgrading,
+    /// iroha additionally checks the restrictions of [`ExecutorUpgradeParameters`](iroha_smart_contract::data_model::parameter::ExecutorUpgradeParameters)
+    pub fn visit_abort_executor_upgrade<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &AbortExecutorUpgrade,
+    ) {
+        if CanUpgradeExecutor.is_owned_by(&executor.context().authority, executor.host()) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't abort executor upgrade");
+    }
 }
 
 pub mod log {
@@ -1604,3 +2495,28 @@ pub mod log {
         execute!(executor, isi)
     }
 }
+
+pub mod anchor {
+    use super::*;
+
+    pub fn visit_anchor<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Anchor) {
+        execute!(executor, isi)
+    }
+}
+
+pub mod spending {
+    use super::*;
+
+    /// Counters are maintained by the executor itself while validating transfers,
+    /// so that accounts can't reset what they spent
+    pub fn visit_set_spending_counter<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &SetSpendingCounter,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Spending counters can't be modified directly");
+    }
+}
@@ -19,6 +19,7 @@ pub use smart_contract::{data_model, Iroha};
 pub mod default;
 pub mod permission;
 pub mod runtime;
+pub mod state;
 
 pub mod log {
     //! WASM logging utilities
@@ -80,6 +81,20 @@ pub mod utils {
         decode_with_length_prefix_from_raw(context)
     }
 
+    /// Get context for `validate_event_subscription()` entrypoint.
+    ///
+    /// # Safety
+    ///
+    /// It's safe to call this function as long as it's safe to construct, from the given
+    /// pointer, byte array of prefix length and `Box<[u8]>` containing the encoded object
+    #[doc(hidden)]
+    #[cfg(not(test))]
+    pub unsafe fn __decode_validate_event_subscription_context(
+        context: *const u8,
+    ) -> payloads::Validate<AccountId> {
+        decode_with_length_prefix_from_raw(context)
+    }
+
     /// Get context for `migrate()` entrypoint.
     ///
     /// # Safety
@@ -1,6 +1,6 @@
 //! Module with permission related functionality.
 
-use alloc::{borrow::ToOwned as _, vec::Vec};
+use alloc::{borrow::ToOwned as _, collections::BTreeSet, vec::Vec};
 
 use iroha_executor_data_model::permission::Permission;
 
@@ -97,6 +97,7 @@ declare_permissions! {
     iroha_executor_data_model::permission::account::{CanRegisterAccount},
     iroha_executor_data_model::permission::account::{CanUnregisterAccount},
     iroha_executor_data_model::permission::account::{CanModifyAccountMetadata},
+    iroha_executor_data_model::permission::account::{CanSubscribeToAccountEvents},
 
     iroha_executor_data_model::permission::asset_definition::{CanRegisterAssetDefinition},
     iroha_executor_data_model::permission::asset_definition::{CanUnregisterAssetDefinition},
@@ -108,16 +109,24 @@ declare_permissions! {
     iroha_executor_data_model::permission::asset::{CanMintAsset},
     iroha_executor_data_model::permission::asset::{CanBurnAsset},
     iroha_executor_data_model::permission::asset::{CanTransferAsset},
+    iroha_executor_data_model::permission::asset::{CanTransferAssetInDomain},
+    iroha_executor_data_model::permission::asset::{CannotTransferAsset},
+    iroha_executor_data_model::permission::asset::{CannotTransferAssetWithDefinition},
 
     iroha_executor_data_model::permission::nft::{CanRegisterNft},
     iroha_executor_data_model::permission::nft::{CanUnregisterNft},
     iroha_executor_data_model::permission::nft::{CanTransferNft},
     iroha_executor_data_model::permission::nft::{CanModifyNftMetadata},
+    iroha_executor_data_model::permission::nft::{CanModifyAnyNftInDomain},
 
     iroha_executor_data_model::permission::parameter::{CanSetParameters},
     iroha_executor_data_model::permission::role::{CanManageRoles},
 
+    iroha_executor_data_model::permission::query::{CanQueryAssetsInDomain},
+    iroha_executor_data_model::permission::query::{CanQueryTransactions},
+
     iroha_executor_data_model::permission::trigger::{CanRegisterTrigger},
+    iroha_executor_data_model::permission::trigger::{CanRegisterTriggerInDomain},
     iroha_executor_data_model::permission::trigger::{CanUnregisterTrigger},
     iroha_executor_data_model::permission::trigger::{CanModifyTrigger},
     iroha_executor_data_model::permission::trigger::{CanExecuteTrigger},
@@ -144,28 +153,78 @@ pub trait ExecutorPermission: Permission + PartialEq {
             return true;
         }
 
-        // build a big OR predicate over all roles we are interested in
-        let role_predicate = host
-            .query(FindRolesByAccountId::new(authority.clone()))
-            .execute()
-            .expect("INTERNAL BUG: `FindRolesByAccountId` must never fail")
-            .map(|role_id| role_id.dbg_expect("Failed to get role from cursor"))
-            .fold(CompoundPredicate::Or(Vec::new()), |predicate, role_id| {
-                predicate.or(CompoundPredicate::<Role>::build(|role| role.id.eq(role_id)))
-            });
+        // check if any of the roles, including the inherited ones, have the permission we need
+        account_roles(authority, host).into_iter().any(|role| {
+            role.permissions()
+                .filter_map(|permission| Self::try_from(permission).ok())
+                .any(|permission| *self == permission)
+        })
+    }
+}
 
-        // check if any of the roles have the permission we need
-        host.query(FindRoles)
+/// Permissions of type `P` owned by the account, either directly or through its roles
+pub(crate) fn owned_permissions<P>(authority: &AccountId, host: &Iroha) -> Vec<P>
+where
+    for<'a> P: TryFrom<&'a PermissionObject>,
+{
+    let mut permissions = host
+        .query(FindPermissionsByAccountId::new(authority.clone()))
+        .execute()
+        .expect("INTERNAL BUG: `FindPermissionsByAccountId` must never fail")
+        .map(|res| res.dbg_expect("Failed to get permission from cursor"))
+        .filter_map(|permission| P::try_from(&permission).ok())
+        .collect::<Vec<_>>();
+
+    for role in account_roles(authority, host) {
+        permissions.extend(
+            role.permissions()
+                .filter_map(|permission| P::try_from(permission).ok()),
+        );
+    }
+
+    permissions
+}
+
+/// Roles of the account together with all the roles they inherit, transitively
+pub(crate) fn account_roles(account_id: &AccountId, host: &Iroha) -> Vec<Role> {
+    let mut roles = Vec::new();
+    let mut visited = BTreeSet::new();
+    let mut pending = host
+        .query(FindRolesByAccountId::new(account_id.clone()))
+        .execute()
+        .expect("INTERNAL BUG: `FindRolesByAccountId` must never fail")
+        .map(|role_id| role_id.dbg_expect("Failed to get role from cursor"))
+        .collect::<Vec<_>>();
+
+    // resolve one level of inheritance per query
+    while !pending.is_empty() {
+        visited.extend(pending.iter().cloned());
+
+        // build a big OR predicate over all roles we are interested in
+        let role_predicate =
+            pending
+                .drain(..)
+                .fold(CompoundPredicate::Or(Vec::new()), |predicate, role_id| {
+                    predicate.or(CompoundPredicate::<Role>::build(|role| role.id.eq(role_id)))
+                });
+
+        for role in host
+            .query(FindRoles)
             .filter(role_predicate)
             .execute()
             .expect("INTERNAL BUG: `FindRoles` must never fail")
             .map(|role| role.dbg_expect("Failed to get role from cursor"))
-            .any(|role| {
-                role.permissions()
-                    .filter_map(|permission| Self::try_from(permission).ok())
-                    .any(|permission| *self == permission)
-            })
+        {
+            pending.extend(
+                role.inherited_roles()
+                    .filter(|role_id| !visited.contains(*role_id))
+                    .cloned(),
+            );
+            roles.push(role);
+        }
     }
+
+    roles
 }
 
 impl<T: Permission + PartialEq> ExecutorPermission for T {}
@@ -225,11 +284,37 @@ mod peer {
     }
 }
 
-mod role {
+pub mod role {
+    //! Module with pass conditions for role related tokens
     use iroha_executor_data_model::permission::role::CanManageRoles;
+    use iroha_smart_contract::data_model::{
+        isi::error::InstructionExecutionError,
+        query::{builder::SingleQueryError, error::FindError},
+    };
 
     use super::*;
 
+    /// Check if `authority` is owner of role
+    ///
+    /// # Errors
+    /// Fails if query fails
+    pub fn is_role_owner(role_id: &RoleId, authority: &AccountId, host: &Iroha) -> Result<bool> {
+        host.query(FindRoles)
+            .filter_with(|role| role.id.eq(role_id.clone()))
+            .execute_single()
+            .map(|role| role.owned_by() == authority)
+            .map_err(|e| match e {
+                SingleQueryError::QueryError(e) => e,
+                SingleQueryError::ExpectedOneGotNone => {
+                    // assuming this can only happen due to such a role not existing
+                    ValidationFail::InstructionFailed(InstructionExecutionError::Find(
+                        FindError::Role(role_id.clone()),
+                    ))
+                }
+                _ => unreachable!(),
+            })
+    }
+
     impl ValidateGrantRevoke for CanManageRoles {
         fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
             OnlyGenesis::from(self).validate(authority, host, context)
@@ -286,12 +371,54 @@ mod parameter {
     }
 }
 
+mod query {
+    //! Module with pass conditions for query related tokens
+    use iroha_executor_data_model::permission::query::CanQueryTransactions;
+
+    use super::*;
+
+    impl ValidateGrantRevoke for CanQueryTransactions {
+        fn validate_grant(
+            &self,
+            authority: &AccountId,
+            _context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            if CanQueryTransactions.is_owned_by(authority, host) {
+                return Ok(());
+            }
+
+            Err(ValidationFail::NotPermitted(
+                "Current authority doesn't have the permission to query transactions, therefore it can't grant it to another account"
+                    .to_owned()
+            ))
+        }
+
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            _context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            if CanQueryTransactions.is_owned_by(authority, host) {
+                return Ok(());
+            }
+
+            Err(ValidationFail::NotPermitted(
+                "Current authority doesn't have the permission to query transactions, therefore it can't revoke it from another account"
+                    .to_owned()
+            ))
+        }
+    }
+}
+
 pub mod asset {
     //! Module with pass conditions for asset related tokens
 
     use iroha_executor_data_model::permission::asset::{
         CanBurnAsset, CanBurnAssetWithDefinition, CanMintAsset, CanMintAssetWithDefinition,
-        CanTransferAsset, CanTransferAssetWithDefinition,
+        CanTransferAsset, CanTransferAssetWithDefinition, CannotTransferAsset,
+        CannotTransferAssetWithDefinition,
     };
 
     use super::*;
@@ -400,6 +527,22 @@ pub mod asset {
 
     impl ValidateGrantRevoke for CanTransferAsset {
         fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            if !self.delegatable
+                && owned_permissions::<CanTransferAsset>(authority, host)
+                    .iter()
+                    .any(|delegator| {
+                        delegator.delegatable
+                            && delegator.asset == self.asset
+                            && is_within(
+                                self.max_amount_per_transaction,
+                                delegator.max_amount_per_transaction,
+                            )
+                            && is_within(self.max_amount_per_day, delegator.max_amount_per_day)
+                    })
+            {
+                return Ok(());
+            }
+
             Owner::from(self).validate(authority, host, context)
         }
         fn validate_revoke(
@@ -412,6 +555,34 @@ pub mod asset {
         }
     }
 
+    impl ValidateGrantRevoke for CannotTransferAsset {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl ValidateGrantRevoke for CannotTransferAssetWithDefinition {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     macro_rules! impl_froms {
         ($($name:ty),+ $(,)?) => {$(
             impl<'t> From<&'t $name> for Owner<'t> {
@@ -423,6 +594,15 @@ pub mod asset {
     }
 
     impl_froms!(CanMintAsset, CanBurnAsset, CanTransferAsset);
+
+    /// Check if a `limit` is no looser than the `bound`, where `None` means no limit
+    fn is_within(limit: Option<Numeric>, bound: Option<Numeric>) -> bool {
+        match (limit, bound) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(limit), Some(bound)) => limit <= bound,
+        }
+    }
 }
 
 pub mod asset_definition {
@@ -557,7 +737,18 @@ pub mod asset_definition {
         iroha_executor_data_model::permission::asset::CanMintAssetWithDefinition,
         iroha_executor_data_model::permission::asset::CanBurnAssetWithDefinition,
         iroha_executor_data_model::permission::asset::CanTransferAssetWithDefinition,
+        iroha_executor_data_model::permission::asset::CannotTransferAssetWithDefinition,
     );
+
+    impl<'t> From<&'t iroha_executor_data_model::permission::asset::CannotTransferAsset> for Owner<'t> {
+        fn from(
+            value: &'t iroha_executor_data_model::permission::asset::CannotTransferAsset,
+        ) -> Self {
+            Self {
+                asset_definition: value.asset.definition(),
+            }
+        }
+    }
 }
 
 /// Module with pass conditions for NFT related tokens
@@ -712,7 +903,8 @@ pub mod account {
     //! Module with pass conditions for asset related tokens
 
     use iroha_executor_data_model::permission::account::{
-        CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
+        CanModifyAccountMetadata, CanRegisterAccount, CanSubscribeToAccountEvents,
+        CanUnregisterAccount,
     };
 
     use super::*;
@@ -799,6 +991,20 @@ pub mod account {
         }
     }
 
+    impl ValidateGrantRevoke for CanSubscribeToAccountEvents {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     macro_rules! impl_froms {
         ($($name:ty),+ $(,)?) => {$(
             impl<'t> From<&'t $name> for Owner<'t> {
@@ -809,7 +1015,11 @@ pub mod account {
         };
     }
 
-    impl_froms!(CanUnregisterAccount, CanModifyAccountMetadata,);
+    impl_froms!(
+        CanUnregisterAccount,
+        CanModifyAccountMetadata,
+        CanSubscribeToAccountEvents,
+    );
 }
 
 pub mod trigger {
@@ -979,8 +1189,11 @@ pub mod trigger {
 pub mod domain {
     //! Module with pass conditions for domain related tokens
     use iroha_executor_data_model::permission::{
+        asset::CanTransferAssetInDomain,
         domain::{CanModifyDomainMetadata, CanRegisterDomain, CanUnregisterDomain},
-        nft::CanRegisterNft,
+        nft::{CanModifyAnyNftInDomain, CanRegisterNft},
+        query::CanQueryAssetsInDomain,
+        trigger::CanRegisterTriggerInDomain,
     };
     use iroha_smart_contract::data_model::{
         isi::error::InstructionExecutionError,
@@ -1074,6 +1287,62 @@ pub mod domain {
         }
     }
 
+    impl ValidateGrantRevoke for CanTransferAssetInDomain {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl ValidateGrantRevoke for CanModifyAnyNftInDomain {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl ValidateGrantRevoke for CanRegisterTriggerInDomain {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl ValidateGrantRevoke for CanQueryAssetsInDomain {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     macro_rules! impl_froms {
         ($($name:ty),+ $(,)?) => {$(
             impl<'t> From<&'t $name> for Owner<'t> {
@@ -1090,6 +1359,10 @@ pub mod domain {
         iroha_executor_data_model::permission::account::CanRegisterAccount,
         iroha_executor_data_model::permission::asset_definition::CanRegisterAssetDefinition,
         CanRegisterNft,
+        CanTransferAssetInDomain,
+        CanModifyAnyNftInDomain,
+        CanRegisterTriggerInDomain,
+        CanQueryAssetsInDomain,
     );
 }
 
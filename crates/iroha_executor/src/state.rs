@@ -0,0 +1,157 @@
+//! Read access to the world state for validation rules.
+//!
+//! Executor entrypoints may query any entity, not only the ones referenced by the validated
+//! instruction. Functions of this module wrap the most common lookups, so that rules like
+//! "transfer is allowed only if the recipient holds a KYC NFT" fit in a single call.
+
+use crate::smart_contract::{
+    data_model::{
+        executor::Result,
+        isi::error::InstructionExecutionError,
+        query::{builder::SingleQueryError, error::FindError},
+    },
+    prelude::*,
+};
+
+/// Get the balance of the asset, treating a nonexistent asset as an empty one.
+///
+/// # Errors
+///
+/// Fails if query execution fails
+pub fn asset_balance(asset_id: &AssetId, host: &Iroha) -> Result<Numeric> {
+    let balance = host
+        .query(FindAssets)
+        .filter_with(|asset| asset.id.eq(asset_id.clone()))
+        .select_with(|asset| asset.value)
+        .execute_single_opt()
+        .map_err(single_query_error)?;
+
+    Ok(balance.unwrap_or(Numeric::ZERO))
+}
+
+/// Check if `account_id` owns at least one NFT registered in `domain_id`.
+///
+/// # Errors
+///
+/// Fails if query execution fails
+pub fn owns_nft_in_domain(
+    account_id: &AccountId,
+    domain_id: &DomainId,
+    host: &Iroha,
+) -> Result<bool> {
+    let mut owned = host
+        .query(FindNfts)
+        .filter_with(|nft| {
+            nft.owned_by.eq(account_id.clone()) & nft.id.domain.eq(domain_id.clone())
+        })
+        .execute()?;
+
+    Ok(owned.next().transpose()?.is_some())
+}
+
+/// Get the value stored under `key` in the metadata of the account.
+///
+/// # Errors
+///
+/// Fails if the account doesn't exist or if query execution fails
+pub fn account_metadata(account_id: &AccountId, key: &Name, host: &Iroha) -> Result<Option<Json>> {
+    let account = host
+        .query(FindAccounts)
+        .filter_with(|account| account.id.eq(account_id.clone()))
+        .execute_single_opt()
+        .map_err(single_query_error)?
+        .ok_or_else(|| not_found(FindError::Account(account_id.clone())))?;
+
+    Ok(account.metadata().get(key).cloned())
+}
+
+/// Get the counters of the amounts transferred from the account.
+///
+/// # Errors
+///
+/// Fails if the account doesn't exist or if query execution fails
+pub fn spending_counters(account_id: &AccountId, host: &Iroha) -> Result<Vec<SpendingCounter>> {
+    host.query_single(FindSpendingCounters::new(account_id.clone()))
+}
+
+/// Get the value stored under `key` in the metadata of the domain.
+///
+/// # Errors
+///
+/// Fails if the domain doesn't exist or if query execution fails
+pub fn domain_metadata(domain_id: &DomainId, key: &Name, host: &Iroha) -> Result<Option<Json>> {
+    let domain = host
+        .query(FindDomains)
+        .filter_with(|domain| domain.id.eq(domain_id.clone()))
+        .execute_single_opt()
+        .map_err(single_query_error)?
+        .ok_or_else(|| not_found(FindError::Domain(domain_id.clone())))?;
+
+    Ok(domain.metadata().get(key).cloned())
+}
+
+/// Get the role granted to every account registered in the domain.
+///
+/// # Errors
+///
+/// Fails if the domain doesn't exist or if query execution fails
+pub fn domain_default_role(domain_id: &DomainId, host: &Iroha) -> Result<Option<RoleId>> {
+    let domain = host
+        .query(FindDomains)
+        .filter_with(|domain| domain.id.eq(domain_id.clone()))
+        .execute_single_opt()
+        .map_err(single_query_error)?
+        .ok_or_else(|| not_found(FindError::Domain(domain_id.clone())))?;
+
+    Ok(domain.default_role().clone())
+}
+
+/// Get the value stored under `key` in the metadata of the asset definition.
+///
+/// # Errors
+///
+/// Fails if the asset definition doesn't exist or if query execution fails
+pub fn asset_definition_metadata(
+    asset_definition_id: &AssetDefinitionId,
+    key: &Name,
+    host: &Iroha,
+) -> Result<Option<Json>> {
+    let asset_definition = host
+        .query(FindAssetsDefinitions)
+        .filter_with(|asset_definition| asset_definition.id.eq(asset_definition_id.clone()))
+        .execute_single_opt()
+        .map_err(single_query_error)?
+        .ok_or_else(|| not_found(FindError::AssetDefinition(asset_definition_id.clone())))?;
+
+    Ok(asset_definition.metadata().get(key).cloned())
+}
+
+/// Get the value stored under `key` in the content of the NFT.
+///
+/// # Errors
+///
+/// Fails if the NFT doesn't exist or if query execution fails
+pub fn nft_content(nft_id: &NftId, key: &Name, host: &Iroha) -> Result<Option<Json>> {
+    let nft = host
+        .query(FindNfts)
+        .filter_with(|nft| nft.id.eq(nft_id.clone()))
+        .execute_single_opt()
+        .map_err(single_query_error)?
+        .ok_or_else(|| not_found(FindError::Nft(nft_id.clone())))?;
+
+    Ok(nft.content().get(key).cloned())
+}
+
+fn not_found(err: FindError) -> ValidationFail {
+    ValidationFail::InstructionFailed(InstructionExecutionError::Find(err))
+}
+
+fn single_query_error(err: SingleQueryError<ValidationFail>) -> ValidationFail {
+    match err {
+        SingleQueryError::QueryError(err) => err,
+        // entities are looked up by their unique id
+        SingleQueryError::ExpectedOneOrZeroGotMany
+        | SingleQueryError::ExpectedOneGotMany
+        | SingleQueryError::ExpectedOneGotNone => unreachable!(),
+    }
+}
@@ -175,3 +175,50 @@ pub mod multisig {
         }
     }
 }
+
+/// Types for spending limit instructions
+pub mod spending_limit {
+    pub use iroha_data_model::spending::SpendingWindow;
+
+    use super::*;
+
+    /// Instructions managing the caps on the amounts transferred from accounts
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, From)]
+    pub enum SpendingLimitInstructionBox {
+        /// Cap the amount of an asset transferred from an account per window
+        Set(SetSpendingLimit),
+        /// Lift the cap set by [`SetSpendingLimit`]
+        Remove(RemoveSpendingLimit),
+    }
+
+    /// Cap the amount of an asset transferred from an account per window.
+    ///
+    /// Replaces the previous cap with the same window, keeping the amount already spent in it.
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct SetSpendingLimit {
+        /// Account the assets are transferred from
+        pub account: AccountId,
+        /// Definition of the capped asset
+        pub asset_definition: AssetDefinitionId,
+        /// Window the cumulative amount is counted in
+        pub window: SpendingWindow,
+        /// Maximum cumulative amount transferred in a single window
+        pub limit: Numeric,
+    }
+
+    /// Lift the cap set by [`SetSpendingLimit`]
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct RemoveSpendingLimit {
+        /// Account the assets are transferred from
+        pub account: AccountId,
+        /// Definition of the capped asset
+        pub asset_definition: AssetDefinitionId,
+        /// Window of the cap
+        pub window: SpendingWindow,
+    }
+
+    impl_custom_instruction!(
+        SpendingLimitInstructionBox,
+        SetSpendingLimit | RemoveSpendingLimit
+    );
+}
@@ -103,6 +103,12 @@ pub mod account {
             pub account: AccountId,
         }
     }
+
+    permission! {
+        pub struct CanSubscribeToAccountEvents {
+            pub account: AccountId,
+        }
+    }
 }
 
 pub mod asset {
@@ -139,8 +145,48 @@ pub mod asset {
     }
 
     permission! {
+        /// Transfer `asset` on behalf of its owner.
+        ///
+        /// Holder of a `delegatable` permission can also grant a non-delegatable copy of it
+        /// to other accounts, with limits no looser than its own.
+        ///
+        /// Transfers can be limited in amount per transfer and in total amount per day (UTC).
+        /// The daily amount is counted per holder.
         pub struct CanTransferAsset {
             pub asset: AssetId,
+            #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+            pub delegatable: bool,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub max_amount_per_transaction: Option<Numeric>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub max_amount_per_day: Option<Numeric>,
+        }
+    }
+
+    permission! {
+        /// Transfer any asset whose definition is registered in `domain`.
+        ///
+        /// Subsumes [`CanTransferAsset`] and [`CanTransferAssetWithDefinition`] in that domain.
+        pub struct CanTransferAssetInDomain {
+            pub domain: DomainId,
+        }
+    }
+
+    permission! {
+        /// Deny transferring `asset`, even if it is allowed otherwise.
+        ///
+        /// Deny permissions win over every permission or ownership allowing the action.
+        pub struct CannotTransferAsset {
+            pub asset: AssetId,
+        }
+    }
+
+    permission! {
+        /// Deny transferring any asset of `asset_definition`, even if it is allowed otherwise.
+        ///
+        /// Deny permissions win over every permission or ownership allowing the action.
+        pub struct CannotTransferAssetWithDefinition {
+            pub asset_definition: AssetDefinitionId,
         }
     }
 }
@@ -171,6 +217,15 @@ pub mod nft {
             pub nft: NftId,
         }
     }
+
+    permission! {
+        /// Modify metadata of any NFT registered in `domain`.
+        ///
+        /// Subsumes [`CanModifyNftMetadata`] in that domain.
+        pub struct CanModifyAnyNftInDomain {
+            pub domain: DomainId,
+        }
+    }
 }
 
 pub mod trigger {
@@ -182,6 +237,15 @@ pub mod trigger {
         }
     }
 
+    permission! {
+        /// Register triggers executed on behalf of any account of `domain`.
+        ///
+        /// Subsumes [`CanRegisterTrigger`] for the accounts of that domain.
+        pub struct CanRegisterTriggerInDomain {
+            pub domain: DomainId,
+        }
+    }
+
     permission! {
         pub struct CanUnregisterTrigger {
             pub trigger: TriggerId,
@@ -216,6 +280,23 @@ pub mod parameter {
     }
 }
 
+pub mod query {
+    use super::*;
+
+    permission! {
+        /// Allows querying holders and statements of assets whose definitions belong to the domain
+        pub struct CanQueryAssetsInDomain {
+            pub domain: DomainId,
+        }
+    }
+
+    permission! {
+        /// Allows querying transactions of any authority
+        #[derive(Copy)]
+        pub struct CanQueryTransactions;
+    }
+}
+
 pub mod role {
     use super::*;
 
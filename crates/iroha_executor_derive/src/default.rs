@@ -89,6 +89,19 @@ pub fn impl_derive_entrypoints(emitter: &mut Emitter, input: &syn::DeriveInput)
                 executor.verdict
             }
         },
+        parse_quote! {
+            #[::iroha_executor::entrypoint]
+            pub fn validate_event_subscription(
+                account: ::iroha_executor::data_model::account::AccountId,
+                host: ::iroha_executor::prelude::Iroha,
+                context: ::iroha_executor::prelude::Context,
+            ) -> ::iroha_executor::prelude::Result {
+                let mut executor = #ident {host, context, verdict: Ok(()), #(#custom_idents),*};
+                executor.visit_event_subscription(&account);
+                ::core::mem::forget(account);
+                executor.verdict
+            }
+        },
     ];
     if let Some(custom) = custom {
         entrypoint_fns.retain(|entrypoint| {
@@ -118,12 +131,17 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_register_domain(operation: &Register<Domain>)",
         "fn visit_unregister_domain(operation: &Unregister<Domain>)",
         "fn visit_transfer_domain(operation: &Transfer<Account, DomainId, Account>)",
+        "fn visit_accept_domain_transfer(operation: &AcceptDomainTransfer)",
         "fn visit_set_domain_key_value(operation: &SetKeyValue<Domain>)",
         "fn visit_remove_domain_key_value(operation: &RemoveKeyValue<Domain>)",
+        "fn visit_set_domain_validator(operation: &SetDomainValidator)",
         "fn visit_register_account(operation: &Register<Account>)",
+        "fn visit_register_accounts(operation: &RegisterAccounts)",
         "fn visit_unregister_account(operation: &Unregister<Account>)",
         "fn visit_set_account_key_value(operation: &SetKeyValue<Account>)",
         "fn visit_remove_account_key_value(operation: &RemoveKeyValue<Account>)",
+        "fn visit_mark_redactable(operation: &MarkRedactable)",
+        "fn visit_redact(operation: &Redact)",
         "fn visit_register_nft(operation: &Register<Nft>)",
         "fn visit_unregister_nft(operation: &Unregister<Nft>)",
         "fn visit_mint_asset_numeric(operation: &Mint<Numeric, Asset>)",
@@ -141,12 +159,22 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_remove_asset_definition_key_value(operation: &RemoveKeyValue<AssetDefinition>)",
         "fn visit_grant_account_permission(operation: &Grant<Permission, Account>)",
         "fn visit_revoke_account_permission(operation: &Revoke<Permission, Account>)",
+        "fn visit_revoke_all_account_permissions(operation: &RevokeAll)",
+        "fn visit_grant_account_permission_group(operation: &Grant<PermissionGroup, Account>)",
+        "fn visit_grant_account_revocable_permission(operation: &Grant<RevocablePermission, Account>)",
+        "fn visit_revoke_account_permission_group(operation: &Revoke<PermissionGroup, Account>)",
         "fn visit_register_role(operation: &Register<Role>)",
         "fn visit_unregister_role(operation: &Unregister<Role>)",
+        "fn visit_transfer_role(operation: &Transfer<Account, RoleId, Account>)",
+        "fn visit_register_role_template(operation: &Register<RoleTemplate>)",
+        "fn visit_unregister_role_template(operation: &Unregister<RoleTemplate>)",
+        "fn visit_instantiate_role_template(operation: &InstantiateRoleTemplate)",
         "fn visit_grant_account_role(operation: &Grant<RoleId, Account>)",
         "fn visit_revoke_account_role(operation: &Revoke<RoleId, Account>)",
         "fn visit_grant_role_permission(operation: &Grant<Permission, Role>)",
         "fn visit_revoke_role_permission(operation: &Revoke<Permission, Role>)",
+        "fn visit_grant_inherited_role(operation: &Grant<RoleId, Role>)",
+        "fn visit_revoke_inherited_role(operation: &Revoke<RoleId, Role>)",
         "fn visit_register_trigger(operation: &Register<Trigger>)",
         "fn visit_unregister_trigger(operation: &Unregister<Trigger>)",
         "fn visit_mint_trigger_repetitions(operation: &Mint<u32, Trigger>)",
@@ -154,8 +182,17 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_execute_trigger(operation: &ExecuteTrigger)",
         "fn visit_set_parameter(operation: &SetParameter)",
         "fn visit_upgrade(operation: &Upgrade)",
+        "fn visit_abort_executor_upgrade(operation: &AbortExecutorUpgrade)",
         "fn visit_log(operation: &Log)",
+        "fn visit_anchor(operation: &Anchor)",
+        "fn visit_set_spending_counter(operation: &SetSpendingCounter)",
         "fn visit_custom_instruction(operation: &CustomInstruction)",
+        "fn visit_find_accounts_with_asset(operation: &::iroha_executor::data_model::query::QueryWithFilter<FindAccountsWithAsset>)",
+        "fn visit_find_account_statement(operation: &FindAccountStatement)",
+        "fn visit_find_spending_counters(operation: &FindSpendingCounters)",
+        "fn visit_find_transactions(operation: &::iroha_executor::data_model::query::QueryWithFilter<FindTransactions>)",
+        "fn visit_find_excluded_transactions(operation: &::iroha_executor::data_model::query::QueryWithFilter<FindExcludedTransactions>)",
+        "fn visit_event_subscription(operation: &::iroha_executor::data_model::account::AccountId)",
     ]
     .into_iter()
     .map(|item| {
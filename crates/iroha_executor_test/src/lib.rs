@@ -0,0 +1,285 @@
+//! Harness for unit-testing runtime executors without running a network.
+//!
+//! [`ExecutorHarness`] keeps an in-memory [`State`] with the executor under test installed
+//! and runs crafted transactions through it, so verdicts can be asserted in a plain `#[test]`:
+//!
+//! ```
+//! use iroha_core::state::World;
+//! use iroha_data_model::prelude::*;
+//! use iroha_executor_test::ExecutorHarness;
+//! use iroha_test_samples::{load_sample_wasm, ALICE_ID, BOB_ID};
+//!
+//! let domain = Domain::new("wonderland".parse().unwrap()).build(&ALICE_ID);
+//! let alice = Account::new(ALICE_ID.clone()).build(&ALICE_ID);
+//! let bob = Account::new(BOB_ID.clone()).build(&ALICE_ID);
+//! let harness = ExecutorHarness::new(World::with([domain], [alice, bob], []))
+//!     .with_executor(Executor::new(load_sample_wasm("executor_with_admin")))
+//!     .unwrap();
+//!
+//! harness.assert_allowed(
+//!     &ALICE_ID,
+//!     [Unregister::domain("wonderland".parse().unwrap())],
+//! );
+//! harness.assert_denied(&BOB_ID, [Unregister::domain("wonderland".parse().unwrap())]);
+//! ```
+//!
+//! Wasm triggers can be tested in a similar way with [`trigger::TriggerHarness`].
+
+pub mod trigger;
+
+use iroha_core::{
+    block::BlockBuilder,
+    kura::Kura,
+    query::store::LiveQueryStore,
+    smartcontracts::{wasm::cache::WasmCache, Execute as _},
+    state::{State, World},
+    tx::AcceptedTransaction,
+};
+use iroha_crypto::KeyPair;
+use iroha_data_model::{
+    block::SignedBlock,
+    isi::{error::InstructionExecutionError, Instruction},
+    prelude::*,
+};
+use iroha_genesis::GENESIS_DOMAIN_ID;
+
+/// In-memory state with the executor under test.
+pub struct ExecutorHarness {
+    state: State,
+    /// Latest block the harness state is based on
+    latest_block: SignedBlock,
+    key_pair: KeyPair,
+    chain: ChainId,
+    // Live query store spawns a background task, so it needs a runtime
+    _runtime: tokio::runtime::Runtime,
+}
+
+impl ExecutorHarness {
+    /// Create harness over the given `world`.
+    ///
+    /// Until [`Self::with_executor`] is called, the initial executor is used, which allows everything.
+    ///
+    /// # Panics
+    /// If failed to build the runtime for the live query store.
+    pub fn new(world: World) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("failed to build tokio runtime");
+        let state = {
+            let _guard = runtime.enter();
+            State::new(
+                world,
+                Kura::blank_kura_for_testing(),
+                LiveQueryStore::start_test(),
+            )
+        };
+        let key_pair = KeyPair::random();
+        let latest_block = BlockBuilder::new(Vec::new())
+            .chain(0, None)
+            .sign(key_pair.private_key())
+            .unpack(|_| {})
+            .into();
+
+        Self {
+            state,
+            latest_block,
+            key_pair,
+            chain: ChainId::from("00000000-0000-0000-0000-000000000000"),
+            _runtime: runtime,
+        }
+    }
+
+    /// Install `executor` the same way genesis does, calling its `migrate()` entrypoint.
+    ///
+    /// # Errors
+    /// If the executor fails to load or its migration fails.
+    pub fn with_executor(self, executor: Executor) -> Result<Self, InstructionExecutionError> {
+        let authority = AccountId::new(
+            GENESIS_DOMAIN_ID.clone(),
+            self.key_pair.public_key().clone(),
+        );
+        let mut block = self.state.block(self.latest_block.header());
+        let mut transaction = block.transaction();
+        Upgrade::new(executor).execute(&authority, &mut transaction)?;
+        transaction.apply();
+        block.commit();
+
+        Ok(self)
+    }
+
+    /// Current state, e.g. to inspect the outcome of [`Self::submit`].
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Run `instructions` on behalf of `authority` through the executor and discard the changes.
+    ///
+    /// # Errors
+    /// If the transaction is rejected.
+    pub fn validate<I: Instruction>(
+        &self,
+        authority: &AccountId,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<(), TransactionRejectionReason> {
+        // Executors treat genesis block specially, so checks are never run in it
+        let mut block = self.state.block(self.next_block().header());
+        let (_hash, result) = block.validate_transaction(
+            self.transaction(authority, instructions),
+            &mut WasmCache::new(),
+        );
+        // The block is dropped without commit, so the state is left intact
+        result.map(|_trigger_sequence| ())
+    }
+
+    /// Run `instructions` on behalf of `authority` through the executor and keep the changes,
+    /// so that subsequent checks observe them.
+    ///
+    /// # Errors
+    /// If the transaction is rejected, the state is left unchanged in this case.
+    pub fn submit<I: Instruction>(
+        &mut self,
+        authority: &AccountId,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<(), TransactionRejectionReason> {
+        let next_block = self.next_block();
+        let mut block = self.state.block(next_block.header());
+        let (_hash, result) = block.validate_transaction(
+            self.transaction(authority, instructions),
+            &mut WasmCache::new(),
+        );
+        block.commit();
+        self.latest_block = next_block;
+
+        result.map(|_trigger_sequence| ())
+    }
+
+    /// Assert that the executor allows `authority` to execute `instructions`.
+    ///
+    /// # Panics
+    /// If the transaction is rejected.
+    #[track_caller]
+    pub fn assert_allowed<I: Instruction>(
+        &self,
+        authority: &AccountId,
+        instructions: impl IntoIterator<Item = I>,
+    ) {
+        if let Err(reason) = self.validate(authority, instructions) {
+            panic!("Expected transaction of `{authority}` to be allowed, but it was rejected: {reason:?}");
+        }
+    }
+
+    /// Assert that the executor denies `authority` to execute `instructions`.
+    ///
+    /// Returns the reason of the rejection for further inspection.
+    ///
+    /// # Panics
+    /// If the transaction is allowed.
+    #[track_caller]
+    pub fn assert_denied<I: Instruction>(
+        &self,
+        authority: &AccountId,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> TransactionRejectionReason {
+        match self.validate(authority, instructions) {
+            Ok(()) => {
+                panic!("Expected transaction of `{authority}` to be denied, but it was allowed")
+            }
+            Err(reason) => reason,
+        }
+    }
+
+    fn transaction<I: Instruction>(
+        &self,
+        authority: &AccountId,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> AcceptedTransaction {
+        // Signature isn't checked by the executor, so any key will do
+        let transaction = TransactionBuilder::new(self.chain.clone(), authority.clone())
+            .with_instructions(instructions)
+            .sign(self.key_pair.private_key());
+
+        AcceptedTransaction::new_unchecked(transaction)
+    }
+
+    fn next_block(&self) -> SignedBlock {
+        BlockBuilder::new(Vec::new())
+            .chain(0, Some(&self.latest_block))
+            .sign(self.key_pair.private_key())
+            .unpack(|_| {})
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_core::state::{StateReadOnly, WorldReadOnly};
+    use iroha_test_samples::{load_sample_wasm, ALICE_ID, BOB_ID};
+
+    use super::*;
+
+    fn harness() -> ExecutorHarness {
+        let domain = Domain::new("wonderland".parse().unwrap()).build(&ALICE_ID);
+        let alice = Account::new(ALICE_ID.clone()).build(&ALICE_ID);
+        ExecutorHarness::new(World::with([domain], [alice], []))
+    }
+
+    #[test]
+    fn validate_discards_changes() {
+        let harness = harness();
+        let domain_id: DomainId = "garden".parse().unwrap();
+
+        harness.assert_allowed(
+            &ALICE_ID,
+            [Register::domain(Domain::new(domain_id.clone()))],
+        );
+
+        assert!(harness.state().view().world().domain(&domain_id).is_err());
+    }
+
+    #[test]
+    fn submit_keeps_changes() {
+        let mut harness = harness();
+        let domain_id: DomainId = "garden".parse().unwrap();
+
+        harness
+            .submit(
+                &ALICE_ID,
+                [Register::domain(Domain::new(domain_id.clone()))],
+            )
+            .unwrap();
+
+        assert!(harness.state().view().world().domain(&domain_id).is_ok());
+        harness.assert_denied(&ALICE_ID, [Register::domain(Domain::new(domain_id))]);
+    }
+
+    #[test]
+    fn unknown_authority_is_denied() {
+        let reason = harness().assert_denied(&BOB_ID, [Log::new(Level::INFO, "hi".to_owned())]);
+
+        assert!(matches!(
+            reason,
+            TransactionRejectionReason::AccountDoesNotExist(_)
+        ));
+    }
+
+    #[test]
+    fn executor_denies_non_owner() {
+        let domain = Domain::new("wonderland".parse().unwrap()).build(&ALICE_ID);
+        let alice = Account::new(ALICE_ID.clone()).build(&ALICE_ID);
+        let bob = Account::new(BOB_ID.clone()).build(&ALICE_ID);
+        // Falls back to the default validation for everyone but the admin
+        let harness = ExecutorHarness::new(World::with([domain], [alice, bob], []))
+            .with_executor(Executor::new(load_sample_wasm("executor_with_admin")))
+            .unwrap();
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+
+        harness.assert_allowed(&ALICE_ID, [Unregister::domain(domain_id.clone())]);
+        let reason = harness.assert_denied(&BOB_ID, [Unregister::domain(domain_id)]);
+
+        assert!(matches!(
+            reason,
+            TransactionRejectionReason::Validation(ValidationFail::NotPermitted(_))
+        ));
+    }
+}
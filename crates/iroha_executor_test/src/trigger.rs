@@ -0,0 +1,235 @@
+//! Harness for unit-testing Wasm triggers against synthetic events.
+//!
+//! ```ignore
+//! let harness = TriggerHarness::new(World::with([domain], [alice], []));
+//! let run = harness
+//!     .run(&trigger_id, &ALICE_ID, &load_sample_wasm("mint_rose_trigger"), event)
+//!     .unwrap();
+//!
+//! run.assert_snapshot(expect_file!["snapshots/mint_rose.json"]);
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+pub use expect_test::{expect, expect_file, Expect};
+use iroha_core::{
+    smartcontracts::wasm::{self, error::Error as WasmError},
+    state::{State, World, WorldReadOnly as _},
+};
+use iroha_data_model::{
+    isi::{error::InstructionExecutionError, Instruction},
+    prelude::*,
+    Level,
+};
+use serde::Serialize;
+use tracing_subscriber::{layer::SubscriberExt as _, Layer};
+
+use crate::ExecutorHarness;
+
+/// Target of the logs emitted by Wasm through the host `log` function
+const WASM_LOG_TARGET: &str = "WASM";
+
+/// In-memory state to run triggers in.
+pub struct TriggerHarness {
+    inner: ExecutorHarness,
+}
+
+/// Outcome of a single trigger execution.
+#[derive(Debug, Clone, Serialize)]
+pub struct TriggerRun {
+    /// Instructions successfully executed by the trigger, in order
+    pub instructions: Vec<InstructionBox>,
+    /// Messages logged by the trigger, in order
+    pub logs: Vec<TriggerLog>,
+}
+
+/// Message logged by a trigger.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TriggerLog {
+    /// Log level
+    pub level: Level,
+    /// Log message
+    pub message: String,
+}
+
+impl TriggerHarness {
+    /// Create harness over the given `world`, see [`ExecutorHarness::new`].
+    pub fn new(world: World) -> Self {
+        Self {
+            inner: ExecutorHarness::new(world),
+        }
+    }
+
+    /// Install `executor` which will validate instructions executed by triggers,
+    /// see [`ExecutorHarness::with_executor`].
+    ///
+    /// # Errors
+    /// If the executor fails to load or its migration fails.
+    pub fn with_executor(self, executor: Executor) -> Result<Self, InstructionExecutionError> {
+        self.inner
+            .with_executor(executor)
+            .map(|inner| Self { inner })
+    }
+
+    /// Current state.
+    pub fn state(&self) -> &State {
+        self.inner.state()
+    }
+
+    /// Prepare the state before running triggers, see [`ExecutorHarness::submit`].
+    ///
+    /// # Errors
+    /// If the transaction is rejected.
+    pub fn submit<I: Instruction>(
+        &mut self,
+        authority: &AccountId,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<(), TransactionRejectionReason> {
+        self.inner.submit(authority, instructions)
+    }
+
+    /// Run trigger `contract` registered as `id` by `authority` as if it was activated by `event`.
+    ///
+    /// Changes made by the trigger are discarded, so each run starts from the same state.
+    ///
+    /// # Errors
+    /// If the module fails to load or the trigger fails.
+    pub fn run(
+        &self,
+        id: &TriggerId,
+        authority: &AccountId,
+        contract: &WasmSmartContract,
+        event: impl Into<EventBox>,
+    ) -> Result<TriggerRun, WasmError> {
+        let mut block = self.inner.state.block(self.inner.next_block().header());
+        let mut transaction = block.transaction();
+        let module = wasm::load_module(transaction.engine, contract)?;
+
+        let capture = LogCapture::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+        let instructions = tracing::subscriber::with_default(subscriber, || {
+            wasm::RuntimeBuilder::<wasm::state::Trigger>::new()
+                .with_config(transaction.world.parameters().smart_contract)
+                .with_engine(transaction.engine.clone()) // Cloning engine is cheap
+                .build()
+                .and_then(|mut runtime| {
+                    runtime.execute_trigger_module_recording(
+                        &mut transaction,
+                        id,
+                        authority.clone(),
+                        &module,
+                        event.into(),
+                    )
+                })
+        })?;
+
+        Ok(TriggerRun {
+            instructions,
+            logs: capture.into_logs(),
+        })
+    }
+}
+
+impl TriggerRun {
+    /// Compare the run with a snapshot.
+    ///
+    /// Run tests with `UPDATE_EXPECT=1` to update the snapshots.
+    ///
+    /// # Panics
+    /// If the run doesn't match the snapshot.
+    #[track_caller]
+    pub fn assert_snapshot(&self, expected: Expect) {
+        let actual = serde_json::to_string_pretty(self).expect("run should be serializable");
+        expected.assert_eq(&actual);
+    }
+}
+
+/// Tracing layer which collects logs emitted by Wasm.
+#[derive(Debug, Clone, Default)]
+struct LogCapture(Arc<Mutex<Vec<TriggerLog>>>);
+
+impl LogCapture {
+    fn into_logs(self) -> Vec<TriggerLog> {
+        core::mem::take(&mut self.0.lock().expect("lock shouldn't be poisoned"))
+    }
+}
+
+impl<S: tracing::Subscriber> Layer<S> for LogCapture {
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let metadata = event.metadata();
+        if metadata.target() != WASM_LOG_TARGET {
+            return;
+        }
+
+        let level = match *metadata.level() {
+            tracing::Level::TRACE => Level::TRACE,
+            tracing::Level::DEBUG => Level::DEBUG,
+            tracing::Level::INFO => Level::INFO,
+            tracing::Level::WARN => Level::WARN,
+            _ => Level::ERROR,
+        };
+        let mut message = MessageVisitor::default();
+        event.record(&mut message);
+
+        self.0
+            .lock()
+            .expect("lock shouldn't be poisoned")
+            .push(TriggerLog {
+                level,
+                message: message.0,
+            });
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl tracing::field::Visit for MessageVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        if matches!(field.name(), "message" | "msg") {
+            value.clone_into(&mut self.0);
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn core::fmt::Debug) {
+        if matches!(field.name(), "message" | "msg") {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_only_wasm_logs() {
+        let capture = LogCapture::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let msg = "rose minted".to_owned();
+            tracing::warn!(target: WASM_LOG_TARGET, msg);
+            tracing::info!("not from wasm");
+        });
+
+        let run = TriggerRun {
+            instructions: vec![],
+            logs: capture.into_logs(),
+        };
+
+        run.assert_snapshot(expect![[r#"
+            {
+              "instructions": [],
+              "logs": [
+                {
+                  "level": "WARN",
+                  "message": "rose minted"
+                }
+              ]
+            }"#]]);
+    }
+}
@@ -3,7 +3,8 @@
 use std::{
     fmt::Debug,
     fs::{self, File},
-    io::BufReader,
+    io::{BufRead, BufReader, Write},
+    iter,
     path::{Path, PathBuf},
     sync::LazyLock,
 };
@@ -13,7 +14,7 @@ use eyre::{eyre, Result, WrapErr};
 use iroha_crypto::KeyPair;
 use iroha_data_model::{block::SignedBlock, parameter::Parameter, prelude::*};
 use iroha_schema::IntoSchema;
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, Encode, IoReader, Output};
 use serde::{Deserialize, Serialize};
 
 /// Domain of the genesis account, technically required for the pre-genesis state
@@ -27,6 +28,66 @@ pub static GENESIS_DOMAIN_ID: LazyLock<DomainId> = LazyLock::new(|| "genesis".pa
 #[repr(transparent)]
 pub struct GenesisBlock(pub SignedBlock);
 
+impl GenesisBlock {
+    /// Read the SCALE-encoded genesis block from the file at `path`, see `kagami genesis sign`.
+    ///
+    /// Transactions are decoded as the file is read, so that a large genesis
+    /// isn't held in memory both as bytes and as a block.
+    ///
+    /// # Errors
+    ///
+    /// - file not found or can't be read
+    /// - decoding failed or the file has bytes left after the block
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(&path)
+            .wrap_err_with(|| eyre!("failed to open genesis at {}", path.as_ref().display()))?;
+        let mut reader = BufReader::new(file);
+        let block = SignedBlock::decode(&mut IoReader(&mut reader))
+            .wrap_err("failed to decode genesis block")?;
+        if !reader
+            .fill_buf()
+            .wrap_err("failed to read genesis")?
+            .is_empty()
+        {
+            return Err(eyre!("genesis file has bytes left after the block"));
+        }
+
+        Ok(Self(block))
+    }
+
+    /// Write the SCALE encoding of the genesis block to `writer`,
+    /// one transaction at a time rather than encoding the whole block first.
+    ///
+    /// # Errors
+    ///
+    /// Fails if writing fails
+    pub fn write_to(&self, writer: impl Write) -> Result<()> {
+        /// Keeps the first error, since [`Output`] can't fail
+        struct WriteOutput<W> {
+            writer: W,
+            error: Option<std::io::Error>,
+        }
+
+        impl<W: Write> Output for WriteOutput<W> {
+            fn write(&mut self, bytes: &[u8]) {
+                if self.error.is_none() {
+                    self.error = self.writer.write_all(bytes).err();
+                }
+            }
+        }
+
+        let mut output = WriteOutput {
+            writer,
+            error: None,
+        };
+        self.0.encode_to(&mut output);
+        if let Some(error) = output.error {
+            return Err(error).wrap_err("failed to write genesis");
+        }
+        output.writer.flush().wrap_err("failed to write genesis")
+    }
+}
+
 /// Format of genesis.json user file.
 /// It should be signed, converted to [`GenesisBlock`],
 /// and serialized in SCALE format before supplying to Iroha peer.
@@ -128,66 +189,88 @@ impl RawGenesisTransaction {
             GENESIS_DOMAIN_ID.clone(),
             genesis_key_pair.public_key().clone(),
         );
-        let mut transactions = vec![];
-        for instructions in self.parse()? {
-            let transaction = TransactionBuilder::new(chain.clone(), genesis_account.clone())
-                .with_instructions(instructions)
-                .sign(genesis_key_pair.private_key());
-            transactions.push(transaction);
-        }
+        // Instructions are moved into transactions one chunk at a time
+        let transactions = self
+            .parse()?
+            .map(|instructions| {
+                instructions.map(|instructions| {
+                    TransactionBuilder::new(chain.clone(), genesis_account.clone())
+                        .with_instructions(instructions)
+                        .sign(genesis_key_pair.private_key())
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
         let block = SignedBlock::genesis(transactions, genesis_key_pair.private_key());
 
         Ok(GenesisBlock(block))
     }
 
-    /// Parse [`RawGenesisTransaction`] to the list of source instructions of the genesis transactions
+    /// Parse [`RawGenesisTransaction`] to the source instructions of the genesis transactions
+    ///
+    /// Instructions are split into transactions of at most [`TransactionParameters::max_instructions`]
+    /// instructions, so that very large genesis states are applied in chunks.
+    /// Chunks are produced lazily, as the transactions are built.
     ///
     /// # Errors
     ///
-    /// Fails if `self.executor` path fails to load [`Executor`].
-    fn parse(self) -> Result<Vec<Vec<InstructionBox>>> {
-        let mut instructions_list = vec![];
+    /// - `self.executor` path fails to load [`Executor`]
+    /// - a wasm trigger fails to load its executable
+    fn parse(self) -> Result<impl Iterator<Item = Result<Vec<InstructionBox>>>> {
+        let chunk_size = self.max_instructions_per_transaction();
 
         let upgrade_executor = Upgrade::new(Executor::new(self.executor.try_into()?)).into();
-        instructions_list.push(vec![upgrade_executor]);
 
-        if let Some(parameters) = self.parameters {
-            let instructions = parameters
+        let parameters = self.parameters.map(|parameters| {
+            parameters
                 .parameters()
                 .map(SetParameter::new)
                 .map(InstructionBox::from)
-                .collect();
-            instructions_list.push(instructions);
-        }
-
-        if !self.instructions.is_empty() {
-            instructions_list.push(self.instructions);
-        }
-
-        if !self.wasm_triggers.is_empty() {
-            let instructions = self
-                .wasm_triggers
-                .into_iter()
-                .map(Trigger::try_from)
-                .collect::<Result<Vec<_>>>()?
+                .collect::<Vec<_>>()
+        });
+
+        let mut instructions = self.instructions.into_iter().peekable();
+        let instructions = iter::from_fn(move || {
+            instructions
+                .peek()
+                .is_some()
+                .then(|| instructions.by_ref().take(chunk_size).collect())
+        });
+
+        let wasm_triggers = (!self.wasm_triggers.is_empty()).then(|| {
+            self.wasm_triggers
                 .into_iter()
-                .map(Register::trigger)
-                .map(InstructionBox::from)
-                .collect();
-            instructions_list.push(instructions);
-        }
-
-        if !self.topology.is_empty() {
-            let instructions = self
-                .topology
+                .map(|trigger| {
+                    Trigger::try_from(trigger)
+                        .map(Register::trigger)
+                        .map(InstructionBox::from)
+                })
+                .collect::<Result<Vec<_>>>()
+        });
+
+        let topology = (!self.topology.is_empty()).then(|| {
+            self.topology
                 .into_iter()
                 .map(Register::peer)
                 .map(InstructionBox::from)
-                .collect();
-            instructions_list.push(instructions)
-        }
+                .collect::<Vec<_>>()
+        });
+
+        Ok(iter::once(vec![upgrade_executor])
+            .chain(parameters)
+            .chain(instructions)
+            .map(Ok)
+            .chain(wasm_triggers)
+            .chain(topology.map(Ok)))
+    }
+
+    fn max_instructions_per_transaction(&self) -> usize {
+        let max_instructions = self
+            .parameters
+            .as_ref()
+            .map_or_else(TransactionParameters::default, Parameters::transaction)
+            .max_instructions();
 
-        Ok(instructions_list)
+        usize::try_from(max_instructions.get()).unwrap_or(usize::MAX)
     }
 }
 
@@ -283,6 +366,12 @@ impl GenesisBuilder {
         self
     }
 
+    /// Overwrite the chain id.
+    pub fn set_chain(mut self, chain: ChainId) -> Self {
+        self.chain = chain;
+        self
+    }
+
     /// Finish building, sign, and produce a [`GenesisBlock`].
     ///
     /// # Errors
@@ -598,6 +687,60 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn instructions_are_split_by_max_instructions() -> Result<()> {
+        use core::num::NonZeroU64;
+
+        use iroha_data_model::parameter::TransactionParameter;
+
+        let (_tmp_dir, builder) = test_builder();
+        let genesis = builder
+            .append_parameter(Parameter::Transaction(
+                TransactionParameter::MaxInstructions(NonZeroU64::new(2).unwrap()),
+            ))
+            .domain("wonderland".parse()?)
+            .account(KeyPair::random().into_parts().0)
+            .account(KeyPair::random().into_parts().0)
+            .account(KeyPair::random().into_parts().0)
+            .finish_domain()
+            .build_and_sign(&KeyPair::random())?;
+
+        let lengths = genesis
+            .0
+            .external_transactions()
+            .map(|transaction| match transaction.instructions() {
+                Executable::Instructions(instructions) => instructions.len(),
+                Executable::Wasm(_) => panic!("Expected instructions"),
+            })
+            .collect::<Vec<_>>();
+        // Executor upgrade, parameters, then 4 registrations in chunks of 2
+        assert_eq!(lengths, [1, 1, 2, 2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn genesis_block_is_read_as_written() -> Result<()> {
+        let (tmp_dir, builder) = test_builder();
+        let genesis = builder
+            .domain("wonderland".parse()?)
+            .account(KeyPair::random().into_parts().0)
+            .finish_domain()
+            .build_and_sign(&KeyPair::random())?;
+        let path = tmp_dir.path().join("genesis.signed.scale");
+
+        genesis.write_to(File::create(&path)?)?;
+        assert_eq!(GenesisBlock::from_path(&path)?.0, genesis.0);
+
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)?
+            .write_all(&[0])?;
+        assert!(GenesisBlock::from_path(&path).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn genesis_parameters_deserialization() {
         fn test(parameters: &str) {
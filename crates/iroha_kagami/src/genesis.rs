@@ -5,12 +5,14 @@ use clap::Subcommand;
 use crate::{Outcome, RunArgs};
 
 mod generate;
+mod rebase;
 mod sign;
 
 #[derive(Debug, Clone, Subcommand)]
 pub enum Args {
     Sign(sign::Args),
     Generate(generate::Args),
+    Rebase(rebase::Args),
 }
 
 impl<T: Write> RunArgs<T> for Args {
@@ -18,6 +20,7 @@ impl<T: Write> RunArgs<T> for Args {
         match self {
             Args::Sign(args) => args.run(writer),
             Args::Generate(args) => args.run(writer),
+            Args::Rebase(args) => args.run(writer),
         }
     }
 }
@@ -7,7 +7,7 @@ use clap::{Parser, Subcommand};
 use color_eyre::eyre::WrapErr as _;
 use iroha_data_model::{isi::InstructionBox, parameter::Parameters, prelude::*};
 use iroha_executor_data_model::permission::{
-    domain::CanRegisterDomain, parameter::CanSetParameters,
+    domain::CanRegisterDomain, parameter::CanSetParameters, query::CanQueryTransactions,
 };
 use iroha_genesis::{GenesisBuilder, RawGenesisTransaction, GENESIS_DOMAIN_ID};
 use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID, CARPENTER_ID};
@@ -116,6 +116,8 @@ pub fn generate_default(
         Grant::account_permission(CanSetParameters, ALICE_ID.clone());
     let grant_permission_to_register_domains =
         Grant::account_permission(CanRegisterDomain, ALICE_ID.clone());
+    let grant_permission_to_query_transactions =
+        Grant::account_permission(CanQueryTransactions, ALICE_ID.clone());
     let transfer_rose_ownership = Transfer::asset_definition(
         genesis_account_id.clone(),
         "rose#wonderland".parse()?,
@@ -133,13 +135,14 @@ pub fn generate_default(
         builder = builder.append_parameter(parameter);
     }
 
-    let instructions: [InstructionBox; 6] = [
+    let instructions: [InstructionBox; 7] = [
         mint.into(),
         mint_cabbage.into(),
         transfer_rose_ownership.into(),
         transfer_wonderland_ownership.into(),
         grant_permission_to_set_parameters.into(),
         grant_permission_to_register_domains.into(),
+        grant_permission_to_query_transactions.into(),
     ];
 
     for isi in instructions {
@@ -0,0 +1,222 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::PathBuf,
+};
+
+use clap::{Parser, ValueEnum};
+use color_eyre::eyre::{eyre, WrapErr as _};
+use iroha_core::state::export::WorldExport;
+use iroha_data_model::{asset::Mintable, prelude::*};
+use iroha_genesis::{GenesisBuilder, RawGenesisTransaction, GENESIS_DOMAIN_ID};
+
+use crate::{Outcome, RunArgs};
+
+/// Generate a genesis which recreates the state exported by `irohad export-state`.
+///
+/// Accounts, balances, roles, permissions and triggers are preserved, so a long-lived
+/// network can be restarted from a compact genesis. Sign the output with `kagami genesis sign`.
+#[derive(Parser, Debug, Clone)]
+pub struct Args {
+    /// Path to the exported state
+    export_file: PathBuf,
+    /// Format of the exported state
+    #[clap(long, value_enum, default_value_t)]
+    format: Format,
+    /// Chain id of the new network
+    #[clap(long)]
+    chain: ChainId,
+    /// Relative path from the directory of output file to the executor.wasm file
+    #[clap(long, value_name = "PATH")]
+    executor: PathBuf,
+    /// Relative path from the directory of output file to the directory that contains *.wasm libraries
+    #[clap(long, value_name = "PATH")]
+    wasm_dir: PathBuf,
+    #[clap(long, value_name = "MULTI_HASH")]
+    genesis_public_key: PublicKey,
+    /// Register asset definitions as mintable infinitely if their mintability can't be preserved.
+    ///
+    /// Balances are restored by minting, so the mintability is only preserved
+    /// for definitions minted at most once.
+    #[clap(long)]
+    allow_mintability_downgrade: bool,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default)]
+enum Format {
+    #[default]
+    Json,
+    Cbor,
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        let reader = BufReader::new(
+            File::open(&self.export_file)
+                .wrap_err_with(|| eyre!("failed to open {}", self.export_file.display()))?,
+        );
+        let export: WorldExport = match self.format {
+            Format::Json => serde_json::from_reader(reader)?,
+            Format::Cbor => ciborium::from_reader(reader)?,
+        };
+
+        let builder = GenesisBuilder::new(self.chain, self.executor, self.wasm_dir);
+        let genesis = rebase(
+            builder,
+            self.genesis_public_key,
+            export,
+            self.allow_mintability_downgrade,
+        )?;
+        writeln!(writer, "{}", serde_json::to_string_pretty(&genesis)?)
+            .wrap_err("failed to write serialized genesis to the buffer")
+    }
+}
+
+/// Translate the exported state into genesis instructions.
+///
+/// Entities are registered by the genesis account and then transferred to their owners.
+///
+/// # Errors
+/// If mintability of some asset definition can't be preserved
+/// and `allow_mintability_downgrade` is not set.
+#[allow(clippy::too_many_lines)]
+pub fn rebase(
+    mut builder: GenesisBuilder,
+    genesis_public_key: PublicKey,
+    export: WorldExport,
+    allow_mintability_downgrade: bool,
+) -> color_eyre::Result<RawGenesisTransaction> {
+    let genesis_account = AccountId::new(GENESIS_DOMAIN_ID.clone(), genesis_public_key);
+
+    for parameter in export.parameters.parameters() {
+        builder = builder.append_parameter(parameter);
+    }
+    builder = builder.set_topology(export.peers);
+
+    let mut instructions: Vec<InstructionBox> = Vec::new();
+
+    for domain in export.domains {
+        if *domain.id() == *GENESIS_DOMAIN_ID {
+            continue;
+        }
+        let mut new_domain =
+            Domain::new(domain.id().clone()).with_metadata(domain.metadata().clone());
+        if let Some(logo) = domain.logo() {
+            new_domain = new_domain.with_logo(logo.clone());
+        }
+        instructions.push(Register::domain(new_domain).into());
+        if *domain.owned_by() != genesis_account {
+            instructions.push(
+                Transfer::domain(
+                    genesis_account.clone(),
+                    domain.id().clone(),
+                    domain.owned_by().clone(),
+                )
+                .into(),
+            );
+        }
+    }
+
+    for account in export.accounts {
+        if *account.id() == genesis_account {
+            continue;
+        }
+        let new_account =
+            Account::new(account.id().clone()).with_metadata(account.metadata().clone());
+        instructions.push(Register::account(new_account).into());
+    }
+
+    let mut holders = BTreeMap::<AssetDefinitionId, usize>::new();
+    for asset in &export.assets {
+        *holders.entry(asset.id().definition().clone()).or_default() += 1;
+    }
+    for definition in export.asset_definitions {
+        let mut new_definition = AssetDefinition::new(definition.id().clone(), definition.spec())
+            .with_metadata(definition.metadata().clone());
+        if let Some(logo) = definition.logo() {
+            new_definition = new_definition.with_logo(logo.clone());
+        }
+        // Minting is the only way to restore balances, so mintability can only be
+        // preserved if the definition is minted at most once
+        match (definition.mintable(), holders.get(definition.id()).copied()) {
+            (Mintable::Once, None) | (Mintable::Not, Some(1)) => {
+                new_definition = new_definition.mintable_once();
+            }
+            (mintable @ (Mintable::Once | Mintable::Not), _) if !allow_mintability_downgrade => {
+                return Err(eyre!(
+                    "asset definition `{}` is {mintable:?} but can't be restored as such, \
+                     pass `--allow-mintability-downgrade` to register it as mintable infinitely",
+                    definition.id()
+                ));
+            }
+            _ => {}
+        }
+        instructions.push(Register::asset_definition(new_definition).into());
+        if *definition.owned_by() != genesis_account {
+            instructions.push(
+                Transfer::asset_definition(
+                    genesis_account.clone(),
+                    definition.id().clone(),
+                    definition.owned_by().clone(),
+                )
+                .into(),
+            );
+        }
+    }
+
+    for asset in export.assets {
+        instructions.push(Mint::asset_numeric(*asset.value(), asset.id().clone()).into());
+    }
+
+    for nft in export.nfts {
+        instructions.push(Register::nft(Nft::new(nft.id().clone(), nft.content().clone())).into());
+        if *nft.owned_by() != genesis_account {
+            instructions.push(
+                Transfer::nft(
+                    genesis_account.clone(),
+                    nft.id().clone(),
+                    nft.owned_by().clone(),
+                )
+                .into(),
+            );
+        }
+    }
+
+    // Roles are granted to the genesis account on registration
+    let mut ungranted_roles = Vec::new();
+    for role in export.roles {
+        let mut new_role = Role::new(role.id().clone(), genesis_account.clone());
+        for permission in role.permissions() {
+            new_role = new_role.add_permission(permission.clone());
+        }
+        instructions.push(Register::role(new_role).into());
+        ungranted_roles.push(role.id().clone());
+    }
+    for account_role in export.account_roles {
+        if account_role.account == genesis_account {
+            ungranted_roles.retain(|role| *role != account_role.role);
+        } else {
+            instructions.push(Grant::account_role(account_role.role, account_role.account).into());
+        }
+    }
+    for role in ungranted_roles {
+        instructions.push(Revoke::account_role(role, genesis_account.clone()).into());
+    }
+
+    for entry in export.account_permissions {
+        for permission in entry.permissions {
+            instructions.push(Grant::account_permission(permission, entry.account.clone()).into());
+        }
+    }
+
+    for trigger in export.triggers {
+        instructions.push(Register::trigger(trigger).into());
+    }
+
+    for isi in instructions {
+        builder = builder.append_instruction(isi);
+    }
+
+    Ok(builder.build_raw())
+}
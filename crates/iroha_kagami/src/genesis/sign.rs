@@ -10,7 +10,6 @@ use color_eyre::eyre;
 use iroha_crypto::{KeyPair, PrivateKey};
 use iroha_data_model::prelude::*;
 use iroha_genesis::RawGenesisTransaction;
-use parity_scale_codec::Encode;
 
 use crate::{Outcome, RunArgs};
 
@@ -55,12 +54,11 @@ impl<T: Write> RunArgs<T> for Args {
         }
         let genesis_block = builder.build_and_sign(&genesis_key_pair)?;
 
-        let mut writer: Box<dyn Write> = match self.out_file {
+        let writer: Box<dyn Write> = match self.out_file {
             None => Box::new(writer),
             Some(path) => Box::new(BufWriter::new(File::create(path)?)),
         };
-        let bytes = genesis_block.0.encode();
-        writer.write_all(&bytes)?;
+        genesis_block.write_to(writer)?;
 
         Ok(())
     }
@@ -7,7 +7,6 @@ use clap::{Args as ClapArgs, Subcommand};
 use color_eyre::eyre::{eyre, WrapErr as _};
 use iroha_core::kura::{BlockIndex, BlockStore};
 use iroha_data_model::block::SignedBlock;
-use iroha_version::scale::DecodeVersioned;
 
 use crate::{Outcome, RunArgs};
 
@@ -135,7 +134,7 @@ fn print_blockchain(
         block_store
             .read_block_data(idx.start, &mut block_buf)
             .wrap_err(format!("failed to read block № {} data.", meta_index + 1))?;
-        let block = SignedBlock::decode_all_versioned(&block_buf)
+        let block = SignedBlock::decode_stored(&block_buf)
             .wrap_err(format!("Failed to decode block № {}", meta_index + 1))?;
         writeln!(writer, "Block#{} :", meta_index + 1)?;
         writeln!(writer, "{block:#?}")?;
@@ -12,6 +12,7 @@ mod crypto;
 mod genesis;
 mod kura;
 mod schema;
+mod snapshot;
 mod swarm;
 mod wasm;
 
@@ -50,6 +51,9 @@ enum Args {
     Codec(codec::Args),
     /// Commands related to block inspection
     Kura(kura::Args),
+    /// Commands related to state snapshot inspection
+    #[clap(subcommand)]
+    Snapshot(snapshot::Args),
     /// Commands related to Docker Compose configuration generation
     Swarm(swarm::Args),
     /// Commands related to building wasm smartcontracts
@@ -69,6 +73,7 @@ impl<T: Write> RunArgs<T> for Args {
             Genesis(args) => args.run(writer),
             Codec(args) => args.run(writer),
             Kura(args) => args.run(writer),
+            Snapshot(args) => args.run(writer),
             Swarm(args) => args.run(writer),
             Wasm(args) => args.run(writer),
             MarkdownHelp(args) => args.run(writer),
@@ -165,6 +170,26 @@ mod tests {
         .is_err())
     }
 
+    #[test]
+    fn genesis_rebase_command() {
+        parse(
+            "kagami genesis rebase ./state.cbor \
+            --format cbor \
+            --chain 00000000-0000-0000-0000-000000000000 \
+            --executor executor.wasm \
+            --wasm-dir libs \
+            --genesis-public-key ed0120A98BAFB0663CE08D75EBD506FEC38A84E576A7C9B0897693ED4B04FD9EF2D18D",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn snapshot_diff_command() {
+        parse("kagami snapshot diff ./before ./after").unwrap();
+        parse("kagami snapshot diff ./before ./after --json --additive-only").unwrap();
+        assert!(parse("kagami snapshot diff ./before").is_err());
+    }
+
     #[test]
     fn wasm_command() {
         parse("kagami wasm build ./test --out-file 1").unwrap();
@@ -0,0 +1,82 @@
+use std::{
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::{Args as ClapArgs, Subcommand};
+use color_eyre::eyre::{eyre, WrapErr as _};
+use iroha_core::{
+    query::store::LiveQueryStore,
+    snapshot::read_snapshot_unchecked,
+    state::{diff::WorldDiff, State},
+};
+
+use crate::{Outcome, RunArgs};
+
+/// State snapshot inspector
+#[derive(Debug, Clone, Subcommand)]
+pub enum Args {
+    /// Report entity-level differences between two snapshots,
+    /// e.g. taken before and after an executor upgrade
+    Diff(DiffArgs),
+}
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct DiffArgs {
+    /// Snapshot directory (containing `snapshot.data`) taken before the upgrade
+    before: PathBuf,
+    /// Snapshot directory (containing `snapshot.data`) taken after the upgrade
+    after: PathBuf,
+    /// Output the difference in JSON format
+    #[clap(long)]
+    json: bool,
+    /// Fail if the second snapshot doesn't purely extend the first one
+    #[clap(long)]
+    additive_only: bool,
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        match self {
+            Args::Diff(args) => args.run(writer),
+        }
+    }
+}
+
+impl<T: Write> RunArgs<T> for DiffArgs {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        // Live query store spawns a background task, so it needs a runtime
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()?;
+        let _guard = runtime.enter();
+
+        let before = read(&self.before)?;
+        let after = read(&self.after)?;
+        let diff = WorldDiff::between(&before.view().world, &after.view().world);
+
+        if self.json {
+            writeln!(writer, "{}", serde_json::to_string_pretty(&diff)?)?;
+        } else if diff.is_empty() {
+            writeln!(writer, "Snapshots are identical")?;
+        } else {
+            write!(writer, "{diff}")?;
+        }
+        writer.flush()?;
+
+        if self.additive_only && !diff.is_additive() {
+            return Err(eyre!(
+                "Snapshot {:?} removes or modifies entities of snapshot {:?}",
+                self.after,
+                self.before
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn read(store_dir: &Path) -> color_eyre::Result<State> {
+    read_snapshot_unchecked(store_dir, LiveQueryStore::start_test)
+        .wrap_err_with(|| format!("failed to read snapshot from {store_dir:?}"))
+}
@@ -80,6 +80,16 @@ pub enum NumericError {
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
 pub struct TryFromNumericError;
 
+/// Error occurred while converting [`Numeric`] into integer minor units
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum MinorUnitsError {
+    /// Number has more fractional digits than the requested scale allows
+    PrecisionLoss,
+    /// Number of minor units doesn't fit into `u128`
+    Overflow,
+}
+
 /// Error occurred while checking if number satisfy given spec
 #[derive(Clone, Copy, Debug, displaydoc::Display)]
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
@@ -155,6 +165,45 @@ impl Numeric {
         Ok(Self { inner })
     }
 
+    /// Create numeric from an integer amount of minor units with a fixed scale,
+    /// e.g. 1050 cents with scale 2 is 10.50
+    ///
+    /// Unlike parsing a decimal string or converting a float, this never rounds.
+    ///
+    /// # Errors
+    /// - if `units` exceeds 96bits
+    /// - if scale is greater than 28
+    #[inline]
+    pub const fn from_minor_units(units: u128, scale: u32) -> Result<Self, NumericError> {
+        Self::try_new(units, scale)
+    }
+
+    /// Return number as an integer amount of minor units with the given scale,
+    /// e.g. 10.5 with scale 2 is 1050 cents
+    ///
+    /// # Errors
+    /// - if number has more fractional digits than `scale`, instead of silently rounding
+    /// - if the amount doesn't fit into `u128`
+    pub fn to_minor_units(&self, scale: u32) -> Result<u128, MinorUnitsError> {
+        let mantissa = self.mantissa();
+        let own_scale = self.scale();
+
+        if scale >= own_scale {
+            return 10_u128
+                .checked_pow(scale - own_scale)
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .ok_or(MinorUnitsError::Overflow);
+        }
+
+        // Scale of numeric is at most 28, so divisor fits into `u128`
+        let divisor = 10_u128.pow(own_scale - scale);
+        if mantissa % divisor != 0 {
+            return Err(MinorUnitsError::PrecisionLoss);
+        }
+
+        Ok(mantissa / divisor)
+    }
+
     /// Return mantissa of number
     /// E.g.
     /// - 100 (scale 0) mantissa is 100
@@ -511,6 +560,43 @@ mod tests {
         assert_eq!(a.checked_add(b), Some(Numeric::new(1000, 2)));
     }
 
+    #[test]
+    fn minor_units_roundtrip() {
+        let amount = Numeric::from_minor_units(1050, 2).unwrap();
+
+        assert_eq!(amount, "10.50".parse().unwrap());
+        assert_eq!(amount.to_minor_units(2), Ok(1050));
+        assert_eq!(amount.to_minor_units(1), Ok(105));
+        assert_eq!(amount.to_minor_units(4), Ok(105_000));
+        assert_eq!(Numeric::ZERO.to_minor_units(28), Ok(0));
+    }
+
+    #[test]
+    fn minor_units_errors() {
+        assert!(matches!(
+            Numeric::from_minor_units(1 << 96, 0),
+            Err(NumericError::MantissaTooLarge)
+        ));
+        assert!(matches!(
+            Numeric::from_minor_units(1, 29),
+            Err(NumericError::ScaleTooLarge)
+        ));
+
+        let amount = Numeric::new(1055, 2);
+        assert_eq!(
+            amount.to_minor_units(1),
+            Err(MinorUnitsError::PrecisionLoss)
+        );
+        assert_eq!(
+            Numeric::MAX.to_minor_units(28),
+            Err(MinorUnitsError::Overflow)
+        );
+        assert_eq!(
+            Numeric::ONE.to_minor_units(u32::MAX),
+            Err(MinorUnitsError::Overflow)
+        );
+    }
+
     #[test]
     fn check_serde() {
         let num1 = Numeric::new(1002, 2);
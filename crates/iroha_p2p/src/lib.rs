@@ -11,6 +11,7 @@ use iroha_crypto::{
     encryption::ChaCha20Poly1305,
     kex::X25519Sha256,
 };
+use iroha_data_model::ChainId;
 pub use network::message::*;
 use parity_scale_codec::{Decode, Encode};
 use thiserror::Error;
@@ -30,8 +31,8 @@ pub mod boilerplate {
     use super::*;
 
     /// Shorthand for traits required for payload
-    pub trait Pload: Encode + Decode + Send + Clone + 'static {}
-    impl<T> Pload for T where T: Encode + Decode + Send + Clone + 'static {}
+    pub trait Pload: Encode + Decode + Send + Clone + Prioritized + 'static {}
+    impl<T> Pload for T where T: Encode + Decode + Send + Clone + Prioritized + 'static {}
 
     /// Shorthand for traits required for key exchange
     pub trait Kex: KeyExchangeScheme + Send + 'static {}
@@ -42,6 +43,41 @@ pub mod boilerplate {
     impl<T> Enc for T where T: Encryptor + Clone + Send + 'static {}
 }
 
+/// Lane of the peer connection a message travels in.
+///
+/// Lanes are multiplexed over the same connection. Messages of the consensus lane are sent
+/// and processed ahead of the bulk ones, so that a peer catching up can't delay votes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Lane {
+    /// Consensus-critical messages
+    Consensus = 0,
+    /// Bulk transfers, e.g. blocks sent to a peer catching up
+    Bulk = 1,
+}
+
+impl Lane {
+    /// All lanes, from the highest priority to the lowest one
+    pub const ALL: [Self; 2] = [Self::Consensus, Self::Bulk];
+}
+
+impl TryFrom<u8> for Lane {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|lane| *lane as u8 == value)
+            .ok_or(Error::Format)
+    }
+}
+
+/// Payload which knows the [`Lane`] it should travel in
+pub trait Prioritized {
+    /// Lane of the message
+    fn lane(&self) -> Lane;
+}
+
 /// Errors used in [`crate`].
 #[derive(Debug, Error, displaydoc::Display)]
 pub enum Error {
@@ -61,6 +97,22 @@ pub enum Error {
     Addr(#[from] AddrParseError),
     /// Connection reset by peer in the middle of message transfer
     ConnectionResetByPeer,
+    /// Peer participates in the `{theirs}` chain, while this peer is in the `{ours}` chain
+    ChainMismatch {
+        /// Chain of this peer
+        ours: ChainId,
+        /// Chain the remote peer attested to
+        theirs: ChainId,
+    },
+    /// Peer of version `{software_version}` supports none of our p2p protocol versions, it supports {versions:?}
+    UnsupportedProtocol {
+        /// Software version the remote peer attested to
+        software_version: String,
+        /// Protocol versions the remote peer supports
+        versions: Vec<u16>,
+    },
+    /// Handshake message of {0} bytes exceeds the limit
+    HandshakeTooLong(usize),
 }
 
 impl From<io::Error> for Error {
@@ -1,8 +1,11 @@
 //! Network formed out of Iroha peers.
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     fmt::Debug,
+    io,
     net::ToSocketAddrs,
+    num::NonZeroU64,
+    sync::Arc,
     time::Duration,
 };
 
@@ -11,14 +14,15 @@ use iroha_config::parameters::actual::Network as Config;
 use iroha_crypto::KeyPair;
 use iroha_data_model::{
     prelude::{Peer, PeerId},
-    Identifiable,
+    ChainId, Identifiable,
 };
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
 use iroha_logger::prelude::*;
-use iroha_primitives::addr::SocketAddr;
+use iroha_primitives::addr::{SocketAddr, SocketAddrHost};
 use tokio::{
     net::{TcpListener, TcpStream},
     sync::{mpsc, watch},
+    task::JoinSet,
 };
 
 use crate::{
@@ -26,10 +30,10 @@ use crate::{
     peer::{
         handles::{connected_from, connecting, PeerHandle},
         message::*,
-        Connection, ConnectionId,
+        Attestation, Connection, ConnectionId, PeerTraffic, TrafficCounters,
     },
-    unbounded_with_len, Broadcast, Error, NetworkMessage, OnlinePeers, Post, UpdatePeers,
-    UpdateTopology,
+    unbounded_with_len, Broadcast, Error, NetworkMessage, OnlinePeers, PeersTrafficReceiver, Post,
+    UpdatePeers, UpdateTopology,
 };
 
 /// `NetworkBase` actor handle.
@@ -43,6 +47,8 @@ pub struct NetworkBaseHandle<T: Pload, K: Kex, E: Enc> {
     subscribe_to_peers_messages_sender: mpsc::UnboundedSender<mpsc::Sender<PeerMessage<T>>>,
     /// Receiver of `OnlinePeer` message
     online_peers_receiver: watch::Receiver<OnlinePeers>,
+    /// Receiver of the traffic exchanged with online peers
+    peers_traffic_receiver: PeersTrafficReceiver,
     /// [`UpdateTopology`] message sender
     update_topology_sender: mpsc::UnboundedSender<UpdateTopology>,
     /// [`UpdatePeers`] message sender
@@ -60,6 +66,7 @@ impl<T: Pload, K: Kex, E: Enc> Clone for NetworkBaseHandle<T, K, E> {
         Self {
             subscribe_to_peers_messages_sender: self.subscribe_to_peers_messages_sender.clone(),
             online_peers_receiver: self.online_peers_receiver.clone(),
+            peers_traffic_receiver: self.peers_traffic_receiver.clone(),
             update_topology_sender: self.update_topology_sender.clone(),
             update_peers_sender: self.update_peers_sender.clone(),
             network_message_sender: self.network_message_sender.clone(),
@@ -70,17 +77,22 @@ impl<T: Pload, K: Kex, E: Enc> Clone for NetworkBaseHandle<T, K, E> {
 }
 
 impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
-    /// Start network peer and return handle to it
+    /// Start network peer and return handle to it.
+    ///
+    /// Only peers participating in the same `chain` are connected to.
     ///
     /// # Errors
     /// - If binding to address fail
     #[log(skip(key_pair, shutdown_signal))]
     pub async fn start(
         key_pair: KeyPair,
+        chain: ChainId,
         Config {
             address: listen_addr,
             public_address,
             idle_timeout,
+            dns_refresh_interval,
+            peer_bandwidth_limit,
         }: Config,
         shutdown_signal: ShutdownSignal,
     ) -> Result<(Self, Child), Error> {
@@ -88,6 +100,7 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
         let listener = TcpListener::bind(listen_addr.value().to_socket_addrs()?.as_slice()).await?;
         iroha_logger::info!("Network bound to listener");
         let (online_peers_sender, online_peers_receiver) = watch::channel(HashSet::new());
+        let (peers_traffic_sender, peers_traffic_receiver) = watch::channel(HashMap::new());
         let (subscribe_to_peers_messages_sender, subscribe_to_peers_messages_receiver) =
             mpsc::unbounded_channel();
         let (update_topology_sender, update_topology_receiver) = mpsc::unbounded_channel();
@@ -103,9 +116,11 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             peers: HashMap::new(),
             connecting_peers: HashMap::new(),
             key_pair,
+            attestation: Attestation::new(chain),
             subscribers_to_peers_messages: Vec::new(),
             subscribe_to_peers_messages_receiver,
             online_peers_sender,
+            peers_traffic_sender,
             update_topology_receiver,
             update_peers_receiver,
             network_message_receiver,
@@ -117,6 +132,11 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             current_topology: HashSet::new(),
             current_peers_addresses: Vec::new(),
             idle_timeout,
+            dns_refresh_interval,
+            peer_bandwidth_limit: peer_bandwidth_limit
+                .and_then(|limit| NonZeroU64::new(limit.get())),
+            resolved_hosts: HashMap::new(),
+            resolving_hosts: JoinSet::new(),
             _key_exchange: core::marker::PhantomData::<K>,
             _encryptor: core::marker::PhantomData::<E>,
         };
@@ -128,6 +148,7 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             Self {
                 subscribe_to_peers_messages_sender,
                 online_peers_receiver,
+                peers_traffic_receiver: PeersTrafficReceiver(peers_traffic_receiver),
                 update_topology_sender,
                 update_peers_sender,
                 network_message_sender,
@@ -185,6 +206,11 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
         self.online_peers_receiver.clone()
     }
 
+    /// Get a receiver of the traffic exchanged with [`OnlinePeers`]
+    pub fn peers_traffic_receiver(&self) -> PeersTrafficReceiver {
+        self.peers_traffic_receiver.clone()
+    }
+
     /// Wait for update of [`OnlinePeers`].
     pub async fn wait_online_peers_update<P>(
         &mut self,
@@ -212,12 +238,16 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     listener: TcpListener,
     /// Our app-level key pair
     key_pair: KeyPair,
+    /// Metadata we attest to during handshakes
+    attestation: Attestation,
     /// Recipients of messages received from other peers in the network.
     subscribers_to_peers_messages: Vec<mpsc::Sender<PeerMessage<T>>>,
     /// Receiver to subscribe for messages received from other peers in the network.
     subscribe_to_peers_messages_receiver: mpsc::UnboundedReceiver<mpsc::Sender<PeerMessage<T>>>,
     /// Sender of `OnlinePeer` message
     online_peers_sender: watch::Sender<OnlinePeers>,
+    /// Sender of the traffic counters of online peers
+    peers_traffic_sender: watch::Sender<HashMap<PeerId, Arc<TrafficCounters>>>,
     /// [`UpdateTopology`] message receiver
     update_topology_receiver: mpsc::UnboundedReceiver<UpdateTopology>,
     /// [`UpdatePeers`] message receiver
@@ -246,6 +276,14 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     current_peers_addresses: Vec<(PeerId, SocketAddr)>,
     /// Duration after which terminate connection with idle peer
     idle_timeout: Duration,
+    /// Interval of re-resolving peers addresses specified by host name
+    dns_refresh_interval: Duration,
+    /// Upper limit of bytes per second sent to and received from each peer
+    peer_bandwidth_limit: Option<NonZeroU64>,
+    /// IP addresses the host names of peers addresses were last resolved to
+    resolved_hosts: HashMap<SocketAddrHost, BTreeSet<std::net::SocketAddr>>,
+    /// Host names being resolved
+    resolving_hosts: JoinSet<(SocketAddrHost, io::Result<BTreeSet<std::net::SocketAddr>>)>,
     /// Key exchange used by network
     _key_exchange: core::marker::PhantomData<K>,
     /// Encryptor used by the network
@@ -258,6 +296,9 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
     async fn run(mut self, shutdown_signal: ShutdownSignal) {
         // TODO: probably should be configuration parameter
         let mut update_topology_interval = tokio::time::interval(Duration::from_millis(1000));
+        // Zero period would make `interval` panic
+        let mut dns_refresh_interval =
+            tokio::time::interval(self.dns_refresh_interval.max(Duration::from_millis(1)));
         loop {
             tokio::select! {
                 // Select is biased because we want to service messages to take priority over data messages.
@@ -277,6 +318,12 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 _ = update_topology_interval.tick() => {
                     self.update_topology()
                 }
+                _ = dns_refresh_interval.tick() => {
+                    self.resolve_hosts()
+                }
+                Some(Ok((host, addresses))) = self.resolving_hosts.join_next() => {
+                    self.host_resolved(host, addresses);
+                }
                 // Every peer produce small amount of service messages so this shouldn't starve other tasks
                 Some(service_message) = self.service_message_receiver.recv() => {
                     match service_message {
@@ -340,9 +387,11 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         connected_from::<T, K, E>(
             self.public_address.clone(),
             self.key_pair.clone(),
+            self.attestation.clone(),
             Connection::new(conn_id, stream),
             service_message_sender,
             self.idle_timeout,
+            self.peer_bandwidth_limit,
         );
     }
 
@@ -394,6 +443,67 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         }
     }
 
+    /// Resolve host names of the peers addresses again, so that peers whose IP address changed
+    /// (e.g. restarted in a container) are reconnected to.
+    fn resolve_hosts(&mut self) {
+        if !self.resolving_hosts.is_empty() {
+            // Previous resolution is still in progress
+            return;
+        }
+
+        let hosts = self
+            .current_peers_addresses
+            .iter()
+            .filter_map(|(_, address)| match address {
+                SocketAddr::Host(host) => Some(host.clone()),
+                SocketAddr::Ipv4(_) | SocketAddr::Ipv6(_) => None,
+            })
+            .collect::<HashSet<_>>();
+        self.resolved_hosts.retain(|host, _| hosts.contains(host));
+
+        for host in hosts {
+            self.resolving_hosts.spawn(async move {
+                let addresses = tokio::net::lookup_host((host.host.as_ref(), host.port))
+                    .await
+                    .map(Iterator::collect);
+                (host, addresses)
+            });
+        }
+    }
+
+    fn host_resolved(
+        &mut self,
+        host: SocketAddrHost,
+        addresses: io::Result<BTreeSet<std::net::SocketAddr>>,
+    ) {
+        let addresses = match addresses {
+            Ok(addresses) => addresses,
+            Err(error) => {
+                iroha_logger::warn!(%host, %error, "Failed to resolve peer host name");
+                return;
+            }
+        };
+        match self.resolved_hosts.insert(host.clone(), addresses.clone()) {
+            Some(previous) if previous != addresses => {}
+            _ => return,
+        }
+
+        let address = SocketAddr::Host(host);
+        let to_reconnect = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| peer.p2p_addr == address)
+            .map(|(peer_id, _)| peer_id.clone())
+            .collect::<Vec<_>>();
+        if !to_reconnect.is_empty() {
+            iroha_logger::info!(%address, ?addresses, "Peer host name resolves to new addresses, reconnecting");
+        }
+        // Connections are established again on the next topology update
+        for peer_id in to_reconnect {
+            self.disconnect_peer(&peer_id);
+        }
+    }
+
     fn connect_peer(&mut self, peer: &Peer) {
         iroha_logger::trace!(
             listen_addr = %self.listen_addr, peer.id.address = %peer.address(),
@@ -408,9 +518,11 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             peer.address().clone(),
             self.public_address.clone(),
             self.key_pair.clone(),
+            self.attestation.clone(),
             conn_id,
             service_message_sender,
             self.idle_timeout,
+            self.peer_bandwidth_limit,
         );
     }
 
@@ -421,7 +533,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         };
         iroha_logger::debug!(listen_addr = %self.listen_addr, %peer.conn_id, "Disconnecting peer");
 
-        Self::remove_online_peer(&self.online_peers_sender, peer_id);
+        self.remove_online_peer(peer_id);
     }
 
     #[log(skip_all, fields(peer=%peer, conn_id=connection_id, disambiguator=disambiguator))]
@@ -433,9 +545,15 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             ready_peer_handle,
             peer_message_sender,
             disambiguator,
+            traffic,
         }: Connected<T>,
     ) {
-        self.connecting_peers.remove(&connection_id);
+        if let Some(expected) = self.connecting_peers.remove(&connection_id) {
+            if expected.id() != peer.id() {
+                iroha_logger::warn!(expected=%expected.id(), "Peer at the address attested to a different key than the one registered");
+                return;
+            }
+        }
 
         if !self.current_topology.contains(peer.id()) {
             iroha_logger::warn!(peer=%peer.id(), topology=?self.current_topology, "Peer not present in topology is trying to connect");
@@ -466,7 +584,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         };
         let _ = peer_message_sender.send(self.peer_message_sender.clone());
         self.peers.insert(peer.id().clone(), ref_peer);
-        Self::add_online_peer(&self.online_peers_sender, peer);
+        self.add_online_peer(peer, traffic);
     }
 
     fn peer_terminated(&mut self, Terminated { peer, conn_id }: Terminated) {
@@ -476,7 +594,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 if ref_peer.conn_id == conn_id {
                     iroha_logger::debug!(conn_id, peer=%peer, "Peer terminated");
                     self.peers.remove(peer.id());
-                    Self::remove_online_peer(&self.online_peers_sender, peer.id());
+                    self.remove_online_peer(peer.id());
                 }
             }
         }
@@ -489,7 +607,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 if ref_peer.handle.post(data).is_err() {
                     iroha_logger::error!(peer=%peer_id, "Failed to send message to peer");
                     self.peers.remove(&peer_id);
-                    Self::remove_online_peer(&self.online_peers_sender, &peer_id);
+                    self.remove_online_peer(&peer_id);
                 }
             }
             None if peer_id.public_key() == self.key_pair.public_key() => {
@@ -502,21 +620,20 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
 
     fn broadcast(&mut self, Broadcast { data }: Broadcast<T>) {
         iroha_logger::trace!("Broadcast message");
-        let Self {
-            peers,
-            online_peers_sender,
-            ..
-        } = self;
-        peers.retain(|public_key, ref_peer| {
+        let mut failed = Vec::new();
+        self.peers.retain(|public_key, ref_peer| {
             if ref_peer.handle.post(data.clone()).is_err() {
                 let peer = Peer::new(ref_peer.p2p_addr.clone(), public_key.clone());
                 iroha_logger::error!(peer=%peer, "Failed to send message to peer");
-                Self::remove_online_peer(online_peers_sender, peer.id());
+                failed.push(public_key.clone());
                 false
             } else {
                 true
             }
         });
+        for peer_id in failed {
+            self.remove_online_peer(&peer_id);
+        }
     }
 
     async fn peer_message(&mut self, msg: PeerMessage<T>) {
@@ -550,12 +667,19 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         );
     }
 
-    fn add_online_peer(online_peers_sender: &watch::Sender<OnlinePeers>, peer: Peer) {
-        online_peers_sender.send_if_modified(|online_peers| online_peers.insert(peer));
+    fn add_online_peer(&self, peer: Peer, traffic: Arc<TrafficCounters>) {
+        self.peers_traffic_sender.send_modify(|peers_traffic| {
+            peers_traffic.insert(peer.id().clone(), traffic);
+        });
+        self.online_peers_sender
+            .send_if_modified(|online_peers| online_peers.insert(peer));
     }
 
-    fn remove_online_peer(online_peers_sender: &watch::Sender<OnlinePeers>, peer_id: &PeerId) {
-        online_peers_sender.send_if_modified(|online_peers| online_peers.remove(peer_id));
+    fn remove_online_peer(&self, peer_id: &PeerId) {
+        self.peers_traffic_sender
+            .send_if_modified(|peers_traffic| peers_traffic.remove(peer_id).is_some());
+        self.online_peers_sender
+            .send_if_modified(|online_peers| online_peers.remove(peer_id));
     }
 
     fn get_conn_id(&mut self) -> ConnectionId {
@@ -575,6 +699,33 @@ pub mod message {
     /// Current online network peers
     pub type OnlinePeers = HashSet<Peer>;
 
+    /// Traffic exchanged with the online network peers over their current connections
+    pub type PeersTraffic = HashMap<PeerId, PeerTraffic>;
+
+    /// Receiver of [`PeersTraffic`]
+    #[derive(Debug, Clone)]
+    pub struct PeersTrafficReceiver(
+        pub(crate) watch::Receiver<HashMap<PeerId, Arc<TrafficCounters>>>,
+    );
+
+    impl PeersTrafficReceiver {
+        /// Traffic exchanged with the online peers so far
+        pub fn get(&self) -> PeersTraffic {
+            self.0
+                .borrow()
+                .iter()
+                .map(|(peer_id, traffic)| (peer_id.clone(), traffic.get()))
+                .collect()
+        }
+    }
+
+    impl Default for PeersTrafficReceiver {
+        /// Receiver of a network without peers
+        fn default() -> Self {
+            Self(watch::channel(HashMap::new()).1)
+        }
+    }
+
     /// The message that is sent to `NetworkBase` to update p2p topology of the network.
     #[derive(Clone, Debug)]
     pub struct UpdateTopology(pub HashSet<PeerId>);
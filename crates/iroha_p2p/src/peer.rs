@@ -1,10 +1,18 @@
 //! Tokio actor Peer
 
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    num::NonZeroU64,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
 use bytes::{Buf, BufMut, BytesMut};
+use iroha_data_model::ChainId;
 use message::*;
-use parity_scale_codec::{DecodeAll, Encode};
+use parity_scale_codec::{Decode, DecodeAll, Encode};
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::{
@@ -12,16 +20,170 @@ use tokio::{
         TcpStream,
     },
     sync::{mpsc, oneshot},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-use crate::{boilerplate::*, Error};
+use crate::{boilerplate::*, Error, Lane, Prioritized};
 
-/// Max length of message handshake in bytes excluding first message length byte.
-pub const MAX_HANDSHAKE_LENGTH: u8 = 255;
+/// Max length of message handshake in bytes excluding first message length bytes.
+pub const MAX_HANDSHAKE_LENGTH: u16 = 1024;
+/// First byte of the attested handshake message.
+///
+/// Legacy handshake messages start with their length instead, which is never zero.
+const ATTESTED_HANDSHAKE_MARKER: u8 = 0;
 /// Default associated data for AEAD
 /// [`Authenticated encryption`](https://en.wikipedia.org/wiki/Authenticated_encryption)
 pub const DEFAULT_AAD: &[u8; 10] = b"Iroha2 AAD";
+/// Versions of the p2p protocol supported by this peer
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[u16] = &[1, 2];
+/// First version of the p2p protocol multiplexing [`Lane`]s over the connection.
+///
+/// Peers speaking earlier versions send all messages in a single lane.
+pub const LANES_PROTOCOL_VERSION: u16 = 2;
+/// Version of the p2p protocol spoken by peers performing the legacy handshake.
+pub const LEGACY_PROTOCOL_VERSION: u16 = 1;
+
+/// Metadata the peer attests to during the handshake.
+///
+/// It is signed together with the exchanged keys, so that peers of another chain
+/// or speaking an incompatible protocol are rejected before any message is exchanged.
+///
+/// Peers released before the attestation send the legacy handshake message without it.
+/// To let them take part in rolling upgrades, the connecting peer always attests,
+/// while the accepting peer reads the message first and answers in the same form.
+/// Legacy peers can't parse an attested message, so connections from the upgraded peer
+/// to them fail, but their own connections to the upgraded peer succeed.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Attestation {
+    /// Chain the peer participates in
+    pub chain: ChainId,
+    /// Version of the peer software
+    pub software_version: String,
+    /// Versions of the p2p protocol the peer supports
+    pub protocol_versions: Vec<u16>,
+}
+
+impl Attestation {
+    /// Attestation of this peer participating in `chain`
+    pub fn new(chain: ChainId) -> Self {
+        Self {
+            chain,
+            software_version: env!("CARGO_PKG_VERSION").to_owned(),
+            protocol_versions: SUPPORTED_PROTOCOL_VERSIONS.to_vec(),
+        }
+    }
+
+    /// Check that the remote peer attested to something we can talk to,
+    /// returning the latest protocol version supported by both peers.
+    ///
+    /// # Errors
+    /// - If the peer participates in another chain
+    /// - If the peer supports none of our protocol versions
+    pub fn check_remote(&self, remote: &Self) -> Result<u16, Error> {
+        if self.chain != remote.chain {
+            return Err(Error::ChainMismatch {
+                ours: self.chain.clone(),
+                theirs: remote.chain.clone(),
+            });
+        }
+        remote
+            .protocol_versions
+            .iter()
+            .filter(|version| self.protocol_versions.contains(version))
+            .max()
+            .copied()
+            .ok_or_else(|| Error::UnsupportedProtocol {
+                software_version: remote.software_version.clone(),
+                versions: remote.protocol_versions.clone(),
+            })
+    }
+}
+
+/// Bytes and messages exchanged over a peer connection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeerTraffic {
+    /// Bytes sent to the peer
+    pub bytes_sent: u64,
+    /// Bytes received from the peer
+    pub bytes_received: u64,
+    /// Messages sent to the peer, including pings
+    pub messages_sent: u64,
+    /// Messages received from the peer, including pings
+    pub messages_received: u64,
+}
+
+/// [`PeerTraffic`] counted by the peer actor while the connection is alive
+#[derive(Debug, Default)]
+pub struct TrafficCounters {
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    messages_sent: AtomicU64,
+    messages_received: AtomicU64,
+}
+
+impl TrafficCounters {
+    /// Traffic counted so far
+    pub fn get(&self) -> PeerTraffic {
+        PeerTraffic {
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+        }
+    }
+
+    fn count(counter: &AtomicU64, value: usize) {
+        counter.fetch_add(value as u64, Ordering::Relaxed);
+    }
+}
+
+/// Token bucket limiting the number of bytes passed per second.
+///
+/// Up to a second worth of bytes may pass at once after the connection was quiet.
+struct Throttle {
+    bytes_per_sec: NonZeroU64,
+    available: u64,
+    refilled_at: Instant,
+}
+
+impl Throttle {
+    fn new(bytes_per_sec: NonZeroU64) -> Self {
+        Self {
+            bytes_per_sec,
+            available: bytes_per_sec.get(),
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let bytes_per_sec = self.bytes_per_sec.get();
+        let refill =
+            u128::from(bytes_per_sec) * self.refilled_at.elapsed().as_nanos() / 1_000_000_000;
+        if refill > 0 {
+            self.available = u64::try_from(refill).map_or(bytes_per_sec, |refill| {
+                self.available.saturating_add(refill).min(bytes_per_sec)
+            });
+            self.refilled_at = Instant::now();
+        }
+    }
+
+    /// Wait until some bytes may pass and return how many of the `wanted` ones may pass now.
+    ///
+    /// Cancellation safe, as the bytes are only deducted by [`Self::consume`].
+    async fn allowance(&mut self, wanted: usize) -> usize {
+        self.refill();
+        while self.available == 0 {
+            let nanos = 1_000_000_000 / self.bytes_per_sec.get() + 1;
+            tokio::time::sleep(Duration::from_nanos(nanos)).await;
+            self.refill();
+        }
+        usize::try_from(self.available).map_or(wanted, |available| wanted.min(available))
+    }
+
+    fn consume(&mut self, bytes: usize) {
+        self.available = self.available.saturating_sub(bytes as u64);
+    }
+}
 
 pub mod handles {
     //! Module with functions to start peer actor and handle to interact with it.
@@ -38,20 +200,24 @@ pub mod handles {
         peer_addr: SocketAddr,
         our_public_address: SocketAddr,
         key_pair: KeyPair,
+        attestation: Attestation,
         connection_id: ConnectionId,
         service_message_sender: mpsc::Sender<ServiceMessage<T>>,
         idle_timeout: Duration,
+        bandwidth_limit: Option<NonZeroU64>,
     ) {
         let peer = state::Connecting {
             peer_addr,
             our_public_address,
             key_pair,
+            attestation,
             connection_id,
         };
         let peer = RunPeerArgs {
             peer,
             service_message_sender,
             idle_timeout,
+            bandwidth_limit,
         };
         tokio::task::spawn(run::run::<T, K, E, _>(peer).in_current_span());
     }
@@ -60,19 +226,23 @@ pub mod handles {
     pub fn connected_from<T: Pload, K: Kex, E: Enc>(
         our_public_address: SocketAddr,
         key_pair: KeyPair,
+        attestation: Attestation,
         connection: Connection,
         service_message_sender: mpsc::Sender<ServiceMessage<T>>,
         idle_timeout: Duration,
+        bandwidth_limit: Option<NonZeroU64>,
     ) {
         let peer = state::ConnectedFrom {
             our_public_address,
             key_pair,
+            attestation,
             connection,
         };
         let peer = RunPeerArgs {
             peer,
             service_message_sender,
             idle_timeout,
+            bandwidth_limit,
         };
         tokio::task::spawn(run::run::<T, K, E, _>(peer).in_current_span());
     }
@@ -99,7 +269,6 @@ mod run {
     //! Module with peer [`run`] function.
 
     use iroha_logger::prelude::*;
-    use parity_scale_codec::Decode;
     use tokio::time::Instant;
 
     use super::{
@@ -118,6 +287,7 @@ mod run {
             peer,
             service_message_sender,
             idle_timeout,
+            bandwidth_limit,
         }: RunPeerArgs<T, P>,
     ) {
         let conn_id = peer.connection_id();
@@ -150,6 +320,8 @@ mod run {
                         ..
                     },
                 cryptographer,
+                attestation,
+                protocol_version,
             } = ready_peer;
             let peer_id = peer_id.insert(new_peer_id);
 
@@ -157,10 +329,16 @@ mod run {
 
             tracing::Span::current().record("peer", peer_id.to_string());
             tracing::Span::current().record("disambiguator", disambiguator);
+            match &attestation {
+                Some(attestation) => iroha_logger::debug!(software_version=%attestation.software_version, protocol_version, "Handshake completed"),
+                None => iroha_logger::debug!(protocol_version, "Legacy handshake without attestation completed"),
+            }
+            let multiplexed = protocol_version >= LANES_PROTOCOL_VERSION;
 
             let (post_sender, mut post_receiver) = unbounded_with_len::unbounded_channel();
             let (peer_message_sender, peer_message_receiver) = oneshot::channel();
             let ready_peer_handle = handles::PeerHandle { post_sender };
+            let traffic = Arc::new(TrafficCounters::default());
             if service_message_sender
                 .send(ServiceMessage::Connected(Connected {
                     connection_id,
@@ -168,6 +346,7 @@ mod run {
                     ready_peer_handle,
                     peer_message_sender,
                     disambiguator,
+                    traffic: Arc::clone(&traffic),
                 }))
                 .await
                 .is_err()
@@ -187,8 +366,20 @@ mod run {
 
             iroha_logger::trace!("Peer connected");
 
-            let mut message_reader = MessageReader::new(read, cryptographer.clone());
-            let mut message_sender = MessageSender::new(write, cryptographer);
+            let mut message_reader = MessageReader::new(
+                read,
+                cryptographer.clone(),
+                multiplexed,
+                Arc::clone(&traffic),
+                bandwidth_limit.map(Throttle::new),
+            );
+            let mut message_sender = MessageSender::new(
+                write,
+                cryptographer,
+                multiplexed,
+                traffic,
+                bandwidth_limit.map(Throttle::new),
+            );
 
             let mut idle_interval = tokio::time::interval_at(Instant::now() + idle_timeout, idle_timeout);
             let mut ping_interval = tokio::time::interval_at(Instant::now() + idle_timeout / 2, idle_timeout / 2);
@@ -296,6 +487,7 @@ mod run {
         pub peer: P,
         pub service_message_sender: mpsc::Sender<ServiceMessage<T>>,
         pub idle_timeout: Duration,
+        pub bandwidth_limit: Option<NonZeroU64>,
     }
 
     /// Trait for peer stages that might be used as starting point for peer's [`run`] function.
@@ -335,54 +527,130 @@ mod run {
         }
     }
 
+    /// Max number of message bytes of a lane sent in one frame.
+    ///
+    /// Bounds the time a consensus message waits for the frame of a bulk one being sent.
+    const FRAME_SIZE: usize = 16 * 1024;
+    /// Size of the frame header: lane and size of the frame
+    const FRAME_HEADER_SIZE: usize = 1 + core::mem::size_of::<u32>();
+
     /// Cancellation-safe way to read messages from tcp stream
     struct MessageReader<E: Enc> {
         read: OwnedReadHalf,
-        buffer: bytes::BytesMut,
+        /// Bytes read from the stream, yet to be split into the frames of lanes
+        buffer: BytesMut,
+        /// Bytes of messages received in each lane, yet to be parsed
+        lanes: [BytesMut; Lane::ALL.len()],
+        /// Whether the stream consists of frames of multiple lanes
+        multiplexed: bool,
         cryptographer: Cryptographer<E>,
+        traffic: Arc<TrafficCounters>,
+        throttle: Option<Throttle>,
     }
 
     impl<E: Enc> MessageReader<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
-
-        fn new(read: OwnedReadHalf, cryptographer: Cryptographer<E>) -> Self {
+        /// Max number of bytes read at once when reading is throttled
+        const THROTTLED_READ_SIZE: usize = 16 * 1024;
+
+        fn new(
+            read: OwnedReadHalf,
+            cryptographer: Cryptographer<E>,
+            multiplexed: bool,
+            traffic: Arc<TrafficCounters>,
+            throttle: Option<Throttle>,
+        ) -> Self {
             Self {
                 read,
                 cryptographer,
                 // TODO: eyeball decision of default buffer size of 1 KB, should be benchmarked and optimized
                 buffer: BytesMut::with_capacity(1024),
+                lanes: Default::default(),
+                multiplexed,
+                traffic,
+                throttle,
             }
         }
 
-        /// Read message by first reading it's size as u32 and then rest of the message
+        /// Read message by first reading it's size as u32 and then rest of the message.
+        ///
+        /// Messages of the higher priority lanes are returned first.
         ///
         /// # Errors
         /// - Fail in case reading from stream fails
         /// - Connection is closed by there is still unfinished message in buffer
-        /// - Forward errors from [`Self::parse_message`]
+        /// - Forward errors from [`Self::split_frames`] and [`Self::parse_message`]
         async fn read_message<T: Pload>(&mut self) -> Result<Option<T>, Error> {
             loop {
+                self.split_frames()?;
                 // Try to get full message
-                if let Some(msg) = self.parse_message()? {
-                    return Ok(Some(msg));
+                for lane in Lane::ALL {
+                    if let Some(msg) = self.parse_message(lane)? {
+                        return Ok(Some(msg));
+                    }
                 }
 
-                if 0 == self.read.read_buf(&mut self.buffer).await? {
-                    if self.buffer.is_empty() {
+                let read = match &mut self.throttle {
+                    Some(throttle) => {
+                        let allowance = throttle.allowance(Self::THROTTLED_READ_SIZE).await;
+                        let read = (&mut self.read)
+                            .take(allowance as u64)
+                            .read_buf(&mut self.buffer)
+                            .await?;
+                        throttle.consume(read);
+                        read
+                    }
+                    None => self.read.read_buf(&mut self.buffer).await?,
+                };
+                if 0 == read {
+                    if self.buffer.is_empty() && self.lanes.iter().all(BytesMut::is_empty) {
                         return Ok(None);
                     }
                     return Err(Error::ConnectionResetByPeer);
                 }
+                TrafficCounters::count(&self.traffic.bytes_received, read);
+            }
+        }
+
+        /// Move the complete frames read so far into the buffers of their lanes
+        ///
+        /// # Errors
+        /// - Frame is of unknown lane or exceeds the max size
+        fn split_frames(&mut self) -> Result<(), Error> {
+            if !self.multiplexed {
+                let bytes = self.buffer.split();
+                self.lanes[Lane::Bulk as usize].unsplit(bytes);
+                return Ok(());
+            }
+
+            loop {
+                let mut buf = &self.buffer[..];
+                if buf.remaining() < FRAME_HEADER_SIZE {
+                    return Ok(());
+                }
+                let lane = Lane::try_from(buf.get_u8())?;
+                let size = buf.get_u32() as usize;
+                if size > FRAME_SIZE {
+                    return Err(Error::Format);
+                }
+                if buf.remaining() < size {
+                    return Ok(());
+                }
+
+                self.buffer.advance(FRAME_HEADER_SIZE);
+                let frame = self.buffer.split_to(size);
+                self.lanes[lane as usize].unsplit(frame);
             }
         }
 
-        /// Parse message
+        /// Parse message of the lane
         ///
         /// # Errors
         /// - Fail to decrypt message
         /// - Fail to decode message
-        fn parse_message<T: Pload>(&mut self) -> Result<Option<T>, Error> {
-            let mut buf = &self.buffer[..];
+        fn parse_message<T: Pload>(&mut self, lane: Lane) -> Result<Option<T>, Error> {
+            let buffer = &mut self.lanes[lane as usize];
+            let mut buf = &buffer[..];
             if buf.remaining() < Self::U32_SIZE {
                 // Not enough data to read u32
                 return Ok(None);
@@ -397,7 +665,8 @@ mod run {
             let decrypted = self.cryptographer.decrypt(data)?;
             let decoded = DecodeAll::decode_all(&mut decrypted.as_slice())?;
 
-            self.buffer.advance(size + Self::U32_SIZE);
+            buffer.advance(size + Self::U32_SIZE);
+            TrafficCounters::count(&self.traffic.messages_received, 1);
 
             Ok(Some(decoded))
         }
@@ -408,24 +677,40 @@ mod run {
         cryptographer: Cryptographer<E>,
         /// Reusable buffer to encode messages
         buffer: Vec<u8>,
-        /// Queue of encrypted messages waiting to be sent
-        queue: BytesMut,
+        /// Queues of encrypted messages waiting to be sent in each lane
+        queues: [BytesMut; Lane::ALL.len()],
+        /// Frame being sent
+        frame: BytesMut,
+        /// Whether frames of multiple lanes are sent, otherwise all messages are sent in one lane
+        multiplexed: bool,
+        traffic: Arc<TrafficCounters>,
+        throttle: Option<Throttle>,
     }
 
     impl<E: Enc> MessageSender<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(write: OwnedWriteHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(
+            write: OwnedWriteHalf,
+            cryptographer: Cryptographer<E>,
+            multiplexed: bool,
+            traffic: Arc<TrafficCounters>,
+            throttle: Option<Throttle>,
+        ) -> Self {
             Self {
                 write,
                 cryptographer,
                 // TODO: eyeball decision of default buffer size of 1 KB, should be benchmarked and optimized
                 buffer: Vec::with_capacity(1024),
-                queue: BytesMut::with_capacity(1024),
+                queues: Default::default(),
+                frame: BytesMut::with_capacity(FRAME_HEADER_SIZE + FRAME_SIZE),
+                multiplexed,
+                traffic,
+                throttle,
             }
         }
 
-        /// Prepare message for the delivery and put it into the queue to be sent later
+        /// Prepare message for the delivery and put it into the queue of its lane to be sent later
         ///
         /// # Errors
         /// - If encryption fail.
@@ -435,34 +720,73 @@ mod run {
             msg.encode_to(&mut self.buffer);
             let encrypted = self.cryptographer.encrypt(&self.buffer)?;
 
+            let lane = if self.multiplexed {
+                msg.lane()
+            } else {
+                Lane::Bulk
+            };
+            let queue = &mut self.queues[lane as usize];
             let size = encrypted.len();
-            self.queue.reserve(size + Self::U32_SIZE);
+            queue.reserve(size + Self::U32_SIZE);
             #[allow(clippy::cast_possible_truncation)]
-            self.queue.put_u32(size as u32);
-            self.queue.put_slice(encrypted.as_slice());
+            queue.put_u32(size as u32);
+            queue.put_slice(encrypted.as_slice());
+            TrafficCounters::count(&self.traffic.messages_sent, 1);
             Ok(())
         }
 
-        /// Send bytes of byte-encoded messages piled up in the message queue so far.
+        /// Take the next frame from the queue of the highest priority lane having anything to send
+        fn next_frame(&mut self) {
+            let Some(lane) = Lane::ALL
+                .into_iter()
+                .find(|lane| !self.queues[*lane as usize].is_empty())
+            else {
+                return;
+            };
+            let queue = &mut self.queues[lane as usize];
+            let bytes = queue.split_to(queue.len().min(FRAME_SIZE));
+
+            if self.multiplexed {
+                self.frame.put_u8(lane as u8);
+                #[allow(clippy::cast_possible_truncation)]
+                self.frame.put_u32(bytes.len() as u32);
+            }
+            self.frame.put_slice(&bytes);
+        }
+
+        /// Send bytes of byte-encoded messages piled up in the message queues so far.
         /// On the other side peer will collect bytes and recreate original messages from them.
         ///
         /// Sends only as much data as the underlying writer will accept in one `.write` call,
         /// so must be called in a loop to ensure everything will get sent.
+        /// A frame is sent completely before the next one is taken from the queues.
         ///
         /// # Errors
         /// - If write to `stream` fail.
         async fn send(&mut self) -> Result<(), Error> {
-            let chunk = self.queue.chunk();
+            if self.frame.is_empty() {
+                self.next_frame();
+            }
+            let chunk = self.frame.chunk();
             if !chunk.is_empty() {
-                let n = self.write.write(chunk).await?;
-                self.queue.advance(n);
+                let n = match &mut self.throttle {
+                    Some(throttle) => {
+                        let allowance = throttle.allowance(chunk.len()).await;
+                        let n = self.write.write(&chunk[..allowance]).await?;
+                        throttle.consume(n);
+                        n
+                    }
+                    None => self.write.write(chunk).await?,
+                };
+                self.frame.advance(n);
+                TrafficCounters::count(&self.traffic.bytes_sent, n);
             }
             Ok(())
         }
 
         /// Check if message sender has data ready to be sent.
         fn ready(&self) -> bool {
-            !self.queue.is_empty()
+            !self.frame.is_empty() || self.queues.iter().any(|queue| !queue.is_empty())
         }
     }
 
@@ -473,6 +797,16 @@ mod run {
         Ping,
         Pong,
     }
+
+    impl<T: Prioritized> Prioritized for Message<T> {
+        fn lane(&self) -> Lane {
+            match self {
+                Self::Data(msg) => msg.lane(),
+                // Keep the connection alive however busy the bulk lane is
+                Self::Ping | Self::Pong => Lane::Consensus,
+            }
+        }
+    }
 }
 
 mod state {
@@ -490,6 +824,7 @@ mod state {
         pub peer_addr: SocketAddr,
         pub our_public_address: SocketAddr,
         pub key_pair: KeyPair,
+        pub attestation: Attestation,
         pub connection_id: ConnectionId,
     }
 
@@ -499,6 +834,7 @@ mod state {
                 peer_addr,
                 our_public_address,
                 key_pair,
+                attestation,
                 connection_id,
             }: Self,
         ) -> Result<ConnectedTo, crate::Error> {
@@ -507,6 +843,7 @@ mod state {
             Ok(ConnectedTo {
                 our_public_address,
                 key_pair,
+                attestation,
                 connection,
             })
         }
@@ -516,6 +853,7 @@ mod state {
     pub(super) struct ConnectedTo {
         our_public_address: SocketAddr,
         key_pair: KeyPair,
+        attestation: Attestation,
         connection: Connection,
     }
 
@@ -525,6 +863,7 @@ mod state {
             Self {
                 our_public_address,
                 key_pair,
+                attestation,
                 mut connection,
             }: Self,
         ) -> Result<SendKey<K, E>, crate::Error> {
@@ -547,6 +886,7 @@ mod state {
             Ok(SendKey {
                 our_public_address,
                 key_pair,
+                attestation,
                 kx_local_pk,
                 kx_remote_pk,
                 connection,
@@ -559,6 +899,7 @@ mod state {
     pub(super) struct ConnectedFrom {
         pub our_public_address: SocketAddr,
         pub key_pair: KeyPair,
+        pub attestation: Attestation,
         pub connection: Connection,
     }
 
@@ -568,10 +909,10 @@ mod state {
             Self {
                 our_public_address,
                 key_pair,
+                attestation,
                 mut connection,
-                ..
             }: Self,
-        ) -> Result<SendKey<K, E>, crate::Error> {
+        ) -> Result<GetKey<K, E>, crate::Error> {
             let key_exchange = K::new();
             let (kx_local_pk, kx_local_sk) = key_exchange.keypair(KeyGenOption::Random);
             let kx_local_pk_raw = K::encode_public_key(&kx_local_pk);
@@ -586,13 +927,17 @@ mod state {
             write_half.write_all(kx_local_pk_raw).await?;
             let shared_key = key_exchange.compute_shared_secret(&kx_local_sk, &kx_remote_pk);
             let cryptographer = Cryptographer::new(&shared_key);
-            Ok(SendKey {
-                our_public_address,
-                key_pair,
+            // Client's public key is read first to answer in the form the client understands
+            Ok(GetKey {
+                connection,
+                attestation,
                 kx_local_pk,
                 kx_remote_pk,
-                connection,
                 cryptographer,
+                reply: Some(Reply {
+                    our_public_address,
+                    key_pair,
+                }),
             })
         }
     }
@@ -601,6 +946,7 @@ mod state {
     pub(super) struct SendKey<K: Kex, E: Enc> {
         our_public_address: SocketAddr,
         key_pair: KeyPair,
+        attestation: Attestation,
         kx_local_pk: K::PublicKey,
         kx_remote_pk: K::PublicKey,
         connection: Connection,
@@ -612,41 +958,48 @@ mod state {
             Self {
                 our_public_address,
                 key_pair,
+                attestation,
                 kx_local_pk,
                 kx_remote_pk,
                 mut connection,
                 cryptographer,
             }: Self,
         ) -> Result<GetKey<K, E>, crate::Error> {
-            let write_half = &mut connection.write;
-
-            let payload = create_payload::<K>(&kx_local_pk, &kx_remote_pk);
-            let signature = Signature::new(key_pair.private_key(), &payload);
-            let data = (key_pair.public_key(), signature, our_public_address).encode();
-
-            let data = &cryptographer.encrypt(data.as_slice())?;
-
-            let mut buf = Vec::<u8>::with_capacity(data.len() + 1);
-            #[allow(clippy::cast_possible_truncation)]
-            buf.push(data.len() as u8);
-            buf.extend_from_slice(data.as_slice());
-
-            write_half.write_all(&buf).await?;
+            write_public_key::<K, E>(
+                &mut connection,
+                &cryptographer,
+                &key_pair,
+                our_public_address,
+                (&kx_local_pk, &kx_remote_pk),
+                Some(&attestation),
+            )
+            .await?;
             Ok(GetKey {
                 connection,
+                attestation,
                 kx_local_pk,
                 kx_remote_pk,
                 cryptographer,
+                reply: None,
             })
         }
     }
 
+    /// Data needed to send our key after getting the peer's one.
+    pub struct Reply {
+        our_public_address: SocketAddr,
+        key_pair: KeyPair,
+    }
+
     /// Peer that needs to get key.
     pub struct GetKey<K: Kex, E: Enc> {
         connection: Connection,
+        attestation: Attestation,
         kx_local_pk: K::PublicKey,
         kx_remote_pk: K::PublicKey,
         cryptographer: Cryptographer<E>,
+        /// `Some` if our key is sent in reply to the peer's one
+        reply: Option<Reply>,
     }
 
     impl<K: Kex, E: Enc> GetKey<K, E> {
@@ -654,28 +1007,77 @@ mod state {
         pub(super) async fn read_their_public_key(
             Self {
                 mut connection,
+                attestation,
                 kx_local_pk,
                 kx_remote_pk,
                 cryptographer,
+                reply,
             }: Self,
         ) -> Result<Ready<E>, crate::Error> {
             let read_half = &mut connection.read;
-            let size = read_half.read_u8().await? as usize;
+            let first_byte = read_half.read_u8().await?;
+            let (size, attested) = if first_byte == ATTESTED_HANDSHAKE_MARKER {
+                let size = read_half.read_u16().await?;
+                if size > MAX_HANDSHAKE_LENGTH {
+                    return Err(Error::HandshakeTooLong(size.into()));
+                }
+                (size, true)
+            } else {
+                (first_byte.into(), false)
+            };
             // Reading public key
-            let mut data = vec![0_u8; size];
+            let mut data = vec![0_u8; size.into()];
             let _ = read_half.read_exact(&mut data).await?;
 
             let data = cryptographer.decrypt(data.as_slice())?;
 
-            let (remote_pub_key, signature, remote_public_address): (
-                PublicKey,
-                Signature,
-                SocketAddr,
-            ) = DecodeAll::decode_all(&mut data.as_slice())?;
+            let (remote_pub_key, signature, remote_public_address, remote_attestation) = if attested
+            {
+                let (remote_pub_key, signature, remote_public_address, remote_attestation): (
+                    PublicKey,
+                    Signature,
+                    SocketAddr,
+                    Attestation,
+                ) = DecodeAll::decode_all(&mut data.as_slice())?;
+                (
+                    remote_pub_key,
+                    signature,
+                    remote_public_address,
+                    Some(remote_attestation),
+                )
+            } else {
+                let (remote_pub_key, signature, remote_public_address): (
+                    PublicKey,
+                    Signature,
+                    SocketAddr,
+                ) = DecodeAll::decode_all(&mut data.as_slice())?;
+                (remote_pub_key, signature, remote_public_address, None)
+            };
 
             // Swap order of keys since we are verifying for other peer order remote/local keys is reversed
-            let payload = create_payload::<K>(&kx_remote_pk, &kx_local_pk);
+            let payload =
+                create_payload::<K>(&kx_remote_pk, &kx_local_pk, remote_attestation.as_ref());
             signature.verify(&remote_pub_key, &payload)?;
+            let protocol_version = match &remote_attestation {
+                Some(remote_attestation) => attestation.check_remote(remote_attestation)?,
+                None => LEGACY_PROTOCOL_VERSION,
+            };
+
+            if let Some(Reply {
+                our_public_address,
+                key_pair,
+            }) = reply
+            {
+                write_public_key::<K, E>(
+                    &mut connection,
+                    &cryptographer,
+                    &key_pair,
+                    our_public_address,
+                    (&kx_local_pk, &kx_remote_pk),
+                    remote_attestation.is_some().then_some(&attestation),
+                )
+                .await?;
+            }
 
             let peer = Peer::new(remote_public_address, remote_pub_key);
 
@@ -683,6 +1085,8 @@ mod state {
                 peer,
                 connection,
                 cryptographer,
+                attestation: remote_attestation,
+                protocol_version,
             })
         }
     }
@@ -693,11 +1097,63 @@ mod state {
         pub peer: Peer,
         pub connection: Connection,
         pub cryptographer: Cryptographer<E>,
+        /// Attestation of the remote peer, `None` if it performed the legacy handshake
+        pub attestation: Option<Attestation>,
+        /// Protocol version the peers talk in
+        pub protocol_version: u16,
     }
 
-    fn create_payload<K: Kex>(kx_local_pk: &K::PublicKey, kx_remote_pk: &K::PublicKey) -> Vec<u8> {
+    /// Send our public key, attested if `attestation` is `Some` and in the legacy form otherwise.
+    async fn write_public_key<K: Kex, E: Enc>(
+        connection: &mut Connection,
+        cryptographer: &Cryptographer<E>,
+        key_pair: &KeyPair,
+        our_public_address: SocketAddr,
+        (kx_local_pk, kx_remote_pk): (&K::PublicKey, &K::PublicKey),
+        attestation: Option<&Attestation>,
+    ) -> Result<(), crate::Error> {
+        let payload = create_payload::<K>(kx_local_pk, kx_remote_pk, attestation);
+        let signature = Signature::new(key_pair.private_key(), &payload);
+        let data = match attestation {
+            Some(attestation) => (
+                key_pair.public_key(),
+                signature,
+                our_public_address,
+                attestation,
+            )
+                .encode(),
+            None => (key_pair.public_key(), signature, our_public_address).encode(),
+        };
+
+        let data = &cryptographer.encrypt(data.as_slice())?;
+        let mut buf = Vec::<u8>::with_capacity(data.len() + 3);
+        if attestation.is_some() {
+            let size = u16::try_from(data.len())
+                .ok()
+                .filter(|size| *size <= MAX_HANDSHAKE_LENGTH)
+                .ok_or(Error::HandshakeTooLong(data.len()))?;
+            buf.put_u8(ATTESTED_HANDSHAKE_MARKER);
+            buf.put_u16(size);
+        } else {
+            let size = u8::try_from(data.len()).map_err(|_| Error::HandshakeTooLong(data.len()))?;
+            buf.put_u8(size);
+        }
+        buf.extend_from_slice(data.as_slice());
+
+        connection.write.write_all(&buf).await?;
+        Ok(())
+    }
+
+    fn create_payload<K: Kex>(
+        kx_local_pk: &K::PublicKey,
+        kx_remote_pk: &K::PublicKey,
+        attestation: Option<&Attestation>,
+    ) -> Vec<u8> {
         let mut payload = Vec::from(K::encode_public_key(kx_local_pk));
         payload.extend_from_slice(K::encode_public_key(kx_remote_pk));
+        if let Some(attestation) = attestation {
+            attestation.encode_to(&mut payload);
+        }
         payload
     }
 }
@@ -740,7 +1196,7 @@ mod handshake {
 
     stage!(connect_to: Connecting => ConnectedTo);
     stage!(send_client_hello::<K, E>: ConnectedTo => SendKey<K, E>);
-    stage!(read_client_hello::<K, E>: ConnectedFrom => SendKey<K, E>);
+    stage!(read_client_hello::<K, E>: ConnectedFrom => GetKey<K, E>);
     stage!(send_our_public_key: SendKey<K, E> => GetKey<K, E>);
     stage!(read_their_public_key: GetKey<K, E> => Ready<E>);
 
@@ -798,6 +1254,8 @@ pub mod message {
         pub peer_message_sender: oneshot::Sender<mpsc::Sender<PeerMessage<T>>>,
         /// Disambiguator of connection (equal for both peers)
         pub disambiguator: u64,
+        /// Traffic exchanged over the connection
+        pub traffic: Arc<TrafficCounters>,
     }
 
     /// Messages received from Peer
@@ -11,11 +11,13 @@ use futures::{prelude::*, stream::FuturesUnordered, task::AtomicWaker};
 use iroha_config::parameters::actual::Network as Config;
 use iroha_config_base::WithOrigin;
 use iroha_crypto::KeyPair;
-use iroha_data_model::{prelude::Peer, Identifiable};
+use iroha_data_model::{prelude::Peer, ChainId, Identifiable};
 use iroha_futures::supervisor::ShutdownSignal;
 use iroha_logger::{prelude::*, test_logger};
-use iroha_p2p::{network::message::*, peer::message::PeerMessage, NetworkHandle};
-use iroha_primitives::addr::socket_addr;
+use iroha_p2p::{
+    network::message::*, peer::message::PeerMessage, Lane, NetworkHandle, Prioritized,
+};
+use iroha_primitives::addr::{socket_addr, SocketAddr, SocketAddrHost};
 use parity_scale_codec::{Decode, Encode};
 use tokio::{
     sync::{mpsc, Barrier},
@@ -25,10 +27,25 @@ use tokio::{
 #[derive(Clone, Debug, Decode, Encode)]
 struct TestMessage(String);
 
+impl Prioritized for TestMessage {
+    /// Short messages stand for votes, long ones for blocks
+    fn lane(&self) -> Lane {
+        if self.0.len() > 1_000 {
+            Lane::Bulk
+        } else {
+            Lane::Consensus
+        }
+    }
+}
+
 fn setup_logger() {
     test_logger();
 }
 
+fn chain_id() -> ChainId {
+    ChainId::from("00000000-0000-0000-0000-000000000000")
+}
+
 /// This test creates a network and one peer.
 /// This peer connects back to our network, emulating some distant peer.
 /// There is no need to create separate networks to check that messages
@@ -46,8 +63,10 @@ async fn network_create() {
         address: WithOrigin::inline(address.clone()),
         public_address: WithOrigin::inline(address.clone()),
         idle_timeout,
+        dns_refresh_interval: Duration::from_secs(30),
+        peer_bandwidth_limit: None,
     };
-    let (network, _) = NetworkHandle::start(key_pair, config, ShutdownSignal::new())
+    let (network, _) = NetworkHandle::start(key_pair, chain_id(), config, ShutdownSignal::new())
         .await
         .unwrap();
     tokio::time::sleep(delay).await;
@@ -159,10 +178,13 @@ async fn two_networks() {
         address: WithOrigin::inline(address1.clone()),
         public_address: WithOrigin::inline(address1.clone()),
         idle_timeout,
+        dns_refresh_interval: Duration::from_secs(30),
+        peer_bandwidth_limit: None,
     };
-    let (mut network1, _) = NetworkHandle::start(key_pair1, config1, ShutdownSignal::new())
-        .await
-        .unwrap();
+    let (mut network1, _) =
+        NetworkHandle::start(key_pair1, chain_id(), config1, ShutdownSignal::new())
+            .await
+            .unwrap();
 
     info!("Starting second network...");
     let address2 = socket_addr!(127.0.0.1:12_010);
@@ -170,8 +192,10 @@ async fn two_networks() {
         address: WithOrigin::inline(address2.clone()),
         public_address: WithOrigin::inline(address2.clone()),
         idle_timeout,
+        dns_refresh_interval: Duration::from_secs(30),
+        peer_bandwidth_limit: None,
     };
-    let (network2, _) = NetworkHandle::start(key_pair2, config2, ShutdownSignal::new())
+    let (network2, _) = NetworkHandle::start(key_pair2, chain_id(), config2, ShutdownSignal::new())
         .await
         .unwrap();
 
@@ -215,6 +239,302 @@ async fn two_networks() {
 
     let connected_peers2 = network2.online_peers(HashSet::len);
     assert_eq!(connected_peers2, 1);
+
+    let traffic1 = network1.peers_traffic_receiver().get()[peer2.id()];
+    let traffic2 = network2.peers_traffic_receiver().get()[peer1.id()];
+    assert!(traffic1.messages_sent >= 1);
+    assert!(traffic2.messages_received >= 1);
+    assert!(traffic1.bytes_sent > 0);
+    assert!(traffic2.bytes_received > 0);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn peer_bandwidth_is_limited() {
+    setup_logger();
+    let mut networks = Vec::new();
+    let mut peers = Vec::new();
+    for port in [12_035, 12_040] {
+        let key_pair = KeyPair::random();
+        let address = socket_addr!(127.0.0.1:port);
+        peers.push(Peer::new(address.clone(), key_pair.public_key().clone()));
+        let config = Config {
+            address: WithOrigin::inline(address.clone()),
+            public_address: WithOrigin::inline(address),
+            idle_timeout: Duration::from_secs(60),
+            dns_refresh_interval: Duration::from_secs(30),
+            peer_bandwidth_limit: Some(2_000.into()),
+        };
+        let (network, _) = NetworkHandle::<TestMessage>::start(
+            key_pair,
+            chain_id(),
+            config,
+            ShutdownSignal::new(),
+        )
+        .await
+        .unwrap();
+        networks.push(network);
+    }
+    let mut messages = WaitForN::new(1);
+    networks[1].subscribe_to_peers_messages(TestActor::start(messages.clone()));
+
+    update_topology_and_peers_addresses(&networks[0], &peers[1..]);
+    update_topology_and_peers_addresses(&networks[1], &peers[..1]);
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = networks[0].wait_online_peers_update(HashSet::len).await;
+        while connections != 1 {
+            connections = networks[0].wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to get all connections");
+
+    // A second worth of bytes passes at once, the rest at the limited rate
+    networks[0].post(Post {
+        data: TestMessage("a".repeat(6_000)),
+        peer_id: peers[1].id().clone(),
+    });
+    assert!(
+        tokio::time::timeout(Duration::from_millis(1000), &mut messages)
+            .await
+            .is_err()
+    );
+    tokio::time::timeout(Duration::from_secs(5), &mut messages)
+        .await
+        .expect("Message should be delivered at the limited rate");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn consensus_lane_overtakes_bulk_transfer() {
+    setup_logger();
+    let mut networks = Vec::new();
+    let mut peers = Vec::new();
+    for port in [12_070, 12_075] {
+        let key_pair = KeyPair::random();
+        let address = socket_addr!(127.0.0.1:port);
+        peers.push(Peer::new(address.clone(), key_pair.public_key().clone()));
+        let config = Config {
+            address: WithOrigin::inline(address.clone()),
+            public_address: WithOrigin::inline(address),
+            idle_timeout: Duration::from_secs(60),
+            dns_refresh_interval: Duration::from_secs(30),
+            peer_bandwidth_limit: Some(20_000.into()),
+        };
+        let (network, _) = NetworkHandle::<TestMessage>::start(
+            key_pair,
+            chain_id(),
+            config,
+            ShutdownSignal::new(),
+        )
+        .await
+        .unwrap();
+        networks.push(network);
+    }
+    let (sender, mut received) = mpsc::channel(10);
+    networks[1].subscribe_to_peers_messages(sender);
+
+    update_topology_and_peers_addresses(&networks[0], &peers[1..]);
+    update_topology_and_peers_addresses(&networks[1], &peers[..1]);
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = networks[0].wait_online_peers_update(HashSet::len).await;
+        while connections != 1 {
+            connections = networks[0].wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to get all connections");
+
+    // Block takes a couple of seconds to pass at the limited rate, the vote doesn't wait for it
+    for data in ["b".repeat(50_000), "vote".to_owned()] {
+        networks[0].post(Post {
+            data: TestMessage(data),
+            peer_id: peers[1].id().clone(),
+        });
+    }
+    let mut delivered = Vec::new();
+    while delivered.len() < 2 {
+        let PeerMessage(_, TestMessage(data)) =
+            tokio::time::timeout(Duration::from_secs(5), received.recv())
+                .await
+                .expect("Message should be delivered")
+                .expect("Network shouldn't drop the subscriber");
+        delivered.push(data);
+    }
+    assert_eq!(delivered[0], "vote");
+    assert_eq!(delivered[1].len(), 50_000);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn networks_connect_by_host_name() {
+    setup_logger();
+    let mut networks = Vec::new();
+    let mut peers = Vec::new();
+    for port in [12_015, 12_020] {
+        let key_pair = KeyPair::random();
+        let address = SocketAddr::Host(SocketAddrHost {
+            host: "localhost".into(),
+            port,
+        });
+        peers.push(Peer::new(address.clone(), key_pair.public_key().clone()));
+        let config = Config {
+            address: WithOrigin::inline(address.clone()),
+            public_address: WithOrigin::inline(address),
+            idle_timeout: Duration::from_secs(60),
+            dns_refresh_interval: Duration::from_millis(100),
+            peer_bandwidth_limit: None,
+        };
+        let (network, _) = NetworkHandle::<TestMessage>::start(
+            key_pair,
+            chain_id(),
+            config,
+            ShutdownSignal::new(),
+        )
+        .await
+        .unwrap();
+        networks.push(network);
+    }
+
+    update_topology_and_peers_addresses(&networks[0], &peers[1..]);
+    update_topology_and_peers_addresses(&networks[1], &peers[..1]);
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = networks[0].wait_online_peers_update(HashSet::len).await;
+        while connections != 1 {
+            connections = networks[0].wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to connect by host name");
+
+    // Resolving the host name to the same addresses again keeps the connection
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(networks[0].online_peers(HashSet::len), 1);
+    assert_eq!(networks[1].online_peers(HashSet::len), 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn networks_of_different_chains_do_not_connect() {
+    setup_logger();
+    let mut networks = Vec::new();
+    let mut peers = Vec::new();
+    for (port, chain) in [(12_025, chain_id()), (12_030, ChainId::from("other-chain"))] {
+        let key_pair = KeyPair::random();
+        let address = socket_addr!(127.0.0.1:port);
+        peers.push(Peer::new(address.clone(), key_pair.public_key().clone()));
+        let config = Config {
+            address: WithOrigin::inline(address.clone()),
+            public_address: WithOrigin::inline(address),
+            idle_timeout: Duration::from_secs(60),
+            dns_refresh_interval: Duration::from_secs(30),
+            peer_bandwidth_limit: None,
+        };
+        let (network, _) =
+            NetworkHandle::<TestMessage>::start(key_pair, chain, config, ShutdownSignal::new())
+                .await
+                .unwrap();
+        networks.push(network);
+    }
+
+    update_topology_and_peers_addresses(&networks[0], &peers[1..]);
+    update_topology_and_peers_addresses(&networks[1], &peers[..1]);
+
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+    assert_eq!(networks[0].online_peers(HashSet::len), 0);
+    assert_eq!(networks[1].online_peers(HashSet::len), 0);
+}
+
+/// Peer released before the handshake attestation connects to an upgraded network.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn legacy_peer_handshake_is_accepted() {
+    use iroha_crypto::{
+        encryption::{ChaCha20Poly1305, SymmetricEncryptor},
+        kex::{KeyExchangeScheme, X25519Sha256},
+        KeyGenOption, PublicKey, Signature,
+    };
+    use parity_scale_codec::DecodeAll;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    const AAD: &[u8] = b"Iroha2 AAD";
+
+    setup_logger();
+    let key_pair = KeyPair::random();
+    let address = socket_addr!(127.0.0.1:12_080);
+    let config = Config {
+        address: WithOrigin::inline(address.clone()),
+        public_address: WithOrigin::inline(address.clone()),
+        idle_timeout: Duration::from_secs(60),
+        dns_refresh_interval: Duration::from_secs(30),
+        peer_bandwidth_limit: None,
+    };
+    let (network, _) = NetworkHandle::<TestMessage>::start(
+        key_pair.clone(),
+        chain_id(),
+        config,
+        ShutdownSignal::new(),
+    )
+    .await
+    .unwrap();
+
+    let legacy_key_pair = KeyPair::random();
+    let legacy_address = socket_addr!(127.0.0.1:12_085);
+    let legacy_peer = Peer::new(legacy_address.clone(), legacy_key_pair.public_key().clone());
+    update_topology_and_peers_addresses(&network, &[legacy_peer]);
+
+    let stream = tokio::net::TcpStream::connect(address.to_string())
+        .await
+        .unwrap();
+    let (mut read, mut write) = stream.into_split();
+    let key_exchange = X25519Sha256::new();
+    let (kx_local_pk, kx_local_sk) = key_exchange.keypair(KeyGenOption::Random);
+    write
+        .write_all(X25519Sha256::encode_public_key(&kx_local_pk))
+        .await
+        .unwrap();
+    let mut key = vec![0_u8; 32];
+    read.read_exact(&mut key).await.unwrap();
+    let kx_remote_pk = X25519Sha256::decode_public_key(key).unwrap();
+    let shared_key = key_exchange.compute_shared_secret(&kx_local_sk, &kx_remote_pk);
+    let encryptor = SymmetricEncryptor::<ChaCha20Poly1305>::new_from_session_key(&shared_key);
+
+    // Legacy handshake message: a single length byte and keys signed without attestation
+    let payload = [
+        X25519Sha256::encode_public_key(&kx_local_pk),
+        X25519Sha256::encode_public_key(&kx_remote_pk),
+    ]
+    .concat();
+    let signature = Signature::new(legacy_key_pair.private_key(), &payload);
+    let data = (legacy_key_pair.public_key(), signature, legacy_address).encode();
+    let data = encryptor.encrypt_easy(AAD, &data).unwrap();
+    write
+        .write_u8(data.len().try_into().unwrap())
+        .await
+        .unwrap();
+    write.write_all(&data).await.unwrap();
+
+    // Network answers in the legacy form
+    let size = read.read_u8().await.unwrap();
+    assert_ne!(size, 0);
+    let mut data = vec![0_u8; size.into()];
+    read.read_exact(&mut data).await.unwrap();
+    let data = encryptor.decrypt_easy(AAD, &data).unwrap();
+    let (public_key, signature, remote_address): (PublicKey, Signature, SocketAddr) =
+        DecodeAll::decode_all(&mut data.as_slice()).unwrap();
+    let payload = [
+        X25519Sha256::encode_public_key(&kx_remote_pk),
+        X25519Sha256::encode_public_key(&kx_local_pk),
+    ]
+    .concat();
+    signature.verify(&public_key, &payload).unwrap();
+    assert_eq!(&public_key, key_pair.public_key());
+    assert_eq!(remote_address, address);
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network.wait_online_peers_update(HashSet::len).await;
+        while connections != 1 {
+            connections = network.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Legacy peer didn't connect");
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
@@ -305,8 +625,10 @@ async fn start_network(
         address: WithOrigin::inline(address.clone()),
         public_address: WithOrigin::inline(address.clone()),
         idle_timeout,
+        dns_refresh_interval: Duration::from_secs(30),
+        peer_bandwidth_limit: None,
     };
-    let (mut network, _) = NetworkHandle::start(key_pair, config, shutdown_signal)
+    let (mut network, _) = NetworkHandle::start(key_pair, chain_id(), config, shutdown_signal)
         .await
         .unwrap();
     network.subscribe_to_peers_messages(actor);
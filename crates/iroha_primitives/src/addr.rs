@@ -113,6 +113,11 @@ impl Ipv4Addr {
     /// [`Self::LOCALHOST`] but might be configured to resolve to
     /// something else.
     pub const UNSPECIFIED: Self = Self([0, 0, 0, 0]);
+
+    /// Whether this is [`Self::UNSPECIFIED`], i.e. `0.0.0.0`
+    pub fn is_unspecified(&self) -> bool {
+        *self == Self::UNSPECIFIED
+    }
 }
 
 ffi::ffi_item! {
@@ -157,6 +162,16 @@ impl Ipv6Addr {
     pub const fn new(segments: [u16; 8]) -> Self {
         Self(segments)
     }
+
+    /// Whether this is [`Self::UNSPECIFIED`], i.e. `::`
+    pub fn is_unspecified(&self) -> bool {
+        *self == Self::UNSPECIFIED
+    }
+
+    /// Whether this is [`Self::LOOPBACK`], i.e. `::1`
+    pub fn is_loopback(&self) -> bool {
+        *self == Self::LOOPBACK
+    }
 }
 
 impl core::ops::Index<usize> for Ipv6Addr {
@@ -180,31 +195,47 @@ impl core::str::FromStr for Ipv6Addr {
         if s.rfind("::") != shorthand_pos {
             return Err(ParseError::UnexpectedAbbreviation);
         }
+        // A single colon may not start or end the address
+        if s.starts_with(':') && !s.starts_with("::") || s.ends_with(':') && !s.ends_with("::") {
+            return Err(ParseError::InvalidSegment);
+        }
 
+        let mut head = 0;
         for word in &mut words {
             let group = iter.next().ok_or(Self::Err::NotEnoughSegments)?;
 
             if group.is_empty() {
+                if shorthand_pos.is_none() {
+                    return Err(ParseError::InvalidSegment);
+                }
                 break;
             }
 
-            *word = u16::from_str_radix(group, 16).map_err(|_| ParseError::InvalidSegment)?;
+            *word = parse_segment(group)?;
+            head += 1;
         }
 
         if shorthand_pos.is_some() {
             let mut rev_iter = s.rsplit(':');
+            let mut tail = 0;
 
-            for word in words.iter_mut().rev() {
+            for word in words[head..].iter_mut().rev() {
                 let group = rev_iter.next().unwrap();
 
                 if group.is_empty() {
-                    return Ok(Self(words));
+                    break;
                 }
 
-                *word = u16::from_str_radix(group, 16).map_err(|_| ParseError::InvalidSegment)?;
+                *word = parse_segment(group)?;
+                tail += 1;
             }
 
-            return Err(ParseError::TooManySegments);
+            // The abbreviation stands for at least one zero segment
+            return if head + tail < words.len() {
+                Ok(Self(words))
+            } else {
+                Err(ParseError::TooManySegments)
+            };
         }
 
         if iter.next().is_some() {
@@ -215,13 +246,47 @@ impl core::str::FromStr for Ipv6Addr {
     }
 }
 
+/// Parse a hexadecimal segment of up to 4 digits, `u16::from_str_radix` alone accepts a sign
+fn parse_segment(group: &str) -> Result<u16, ParseError> {
+    if group.len() > 4 || !group.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseError::InvalidSegment);
+    }
+    u16::from_str_radix(group, 16).map_err(|_| ParseError::InvalidSegment)
+}
+
 impl core::fmt::Display for Ipv6Addr {
+    /// Format the address in the canonical form of RFC 5952: the longest run of two or more
+    /// zero segments (the first one if there is a tie) is replaced with `::`
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        // TODO: Implement omission of zeroes.
-        for i in 0_usize..7_usize {
-            write!(f, "{:x}:", self[i])?; // Need hexadecimal
+        fn write_segments(f: &mut core::fmt::Formatter<'_>, segments: &[u16]) -> core::fmt::Result {
+            for (i, segment) in segments.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(":")?;
+                }
+                write!(f, "{segment:x}")?;
+            }
+            Ok(())
+        }
+
+        let (mut zeroes_start, mut zeroes_len) = (0, 0);
+        let mut i = 0;
+        while i < self.0.len() {
+            let start = i;
+            while i < self.0.len() && self.0[i] == 0 {
+                i += 1;
+            }
+            if i - start > zeroes_len {
+                (zeroes_start, zeroes_len) = (start, i - start);
+            }
+            i += 1;
         }
-        write!(f, "{:x}", self[7])
+
+        if zeroes_len < 2 {
+            return write_segments(f, &self.0);
+        }
+        write_segments(f, &self.0[..zeroes_start])?;
+        f.write_str("::")?;
+        write_segments(f, &self.0[zeroes_start + zeroes_len..])
     }
 }
 
@@ -345,7 +410,7 @@ impl core::str::FromStr for SocketAddrV6 {
     type Err = ParseError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let value = value.trim_start_matches('[');
+        let value = value.strip_prefix('[').ok_or(ParseError::InvalidSegment)?;
         let (ip, port) = value.split_once("]:").ok_or(ParseError::NoPort)?;
         Ok(Self {
             ip: ip.parse()?,
@@ -436,6 +501,16 @@ impl SocketAddr {
         }
     }
 
+    /// Whether the IP address is unspecified (`0.0.0.0` or `::`). Such an address may be
+    /// bound to listen on all interfaces, but other hosts can't connect to it.
+    pub fn is_unspecified(&self) -> bool {
+        match self {
+            SocketAddr::Ipv4(addr) => addr.ip.is_unspecified(),
+            SocketAddr::Ipv6(addr) => addr.ip.is_unspecified(),
+            SocketAddr::Host(_) => false,
+        }
+    }
+
     /// Extracts port from [`Self`]
     pub fn port(&self) -> u16 {
         match self {
@@ -678,6 +753,40 @@ mod test {
             "2001:db8:0:1:2:3:4:5:6".parse::<Ipv6Addr>().unwrap_err(),
             ParseError::TooManySegments
         );
+
+        assert_eq!(
+            "2001:db8:0:1:2:3:4:".parse::<Ipv6Addr>().unwrap_err(),
+            ParseError::InvalidSegment
+        );
+
+        assert_eq!(
+            "2001:db8::+1".parse::<Ipv6Addr>().unwrap_err(),
+            ParseError::InvalidSegment
+        );
+
+        assert_eq!(
+            "2001:db8::1:2:3:4:5:6".parse::<Ipv6Addr>().unwrap_err(),
+            ParseError::TooManySegments
+        );
+
+        assert_eq!("::".parse::<Ipv6Addr>().unwrap(), Ipv6Addr::UNSPECIFIED);
+    }
+
+    #[test]
+    fn ipv6_display() {
+        for (segments, expected) in [
+            ([0, 0, 0, 0, 0, 0, 0, 0], "::"),
+            ([0, 0, 0, 0, 0, 0, 0, 1], "::1"),
+            ([0x2001, 0xdb8, 0, 0, 0, 0, 0, 0], "2001:db8::"),
+            ([0x2001, 0xdb8, 0, 0, 1, 0, 0, 1], "2001:db8::1:0:0:1"),
+            ([0x2001, 0xdb8, 0, 1, 0, 0, 0, 1], "2001:db8:0:1::1"),
+            ([0x2001, 0xdb8, 0, 1, 1, 1, 1, 1], "2001:db8:0:1:1:1:1:1"),
+            ([0xfe80, 0, 0, 0, 0xabcd, 0, 0, 0], "fe80::abcd:0:0:0"),
+        ] {
+            let addr = Ipv6Addr(segments);
+            assert_eq!(addr.to_string(), expected);
+            assert_eq!(expected.parse::<Ipv6Addr>().unwrap(), addr);
+        }
     }
 
     #[test]
@@ -720,6 +829,25 @@ mod test {
             "[2001:0db8::]:FOO".parse::<SocketAddrV6>().unwrap_err(),
             ParseError::InvalidPort
         );
+
+        assert_eq!(
+            SocketAddrV6::from(([0, 0, 0, 0, 0, 0, 0, 1], 1337)).to_string(),
+            "[::1]:1337"
+        );
+    }
+
+    #[test]
+    fn unspecified() {
+        assert!("0.0.0.0:1337"
+            .parse::<SocketAddr>()
+            .unwrap()
+            .is_unspecified());
+        assert!("[::]:1337".parse::<SocketAddr>().unwrap().is_unspecified());
+        assert!(!"[::1]:1337".parse::<SocketAddr>().unwrap().is_unspecified());
+        assert!(!"localhost:1337"
+            .parse::<SocketAddr>()
+            .unwrap()
+            .is_unspecified());
     }
 
     #[test]
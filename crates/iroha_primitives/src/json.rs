@@ -55,6 +55,26 @@ impl Json {
     pub fn get(&self) -> &String {
         &self.0
     }
+
+    /// String values nested anywhere inside [`Self`], not including the keys of objects.
+    pub fn string_values(&self) -> Vec<String> {
+        fn collect(value: Value, strings: &mut Vec<String>) {
+            match value {
+                Value::String(string) => strings.push(string),
+                Value::Array(items) => items.into_iter().for_each(|item| collect(item, strings)),
+                Value::Object(fields) => fields
+                    .into_iter()
+                    .for_each(|(_, field)| collect(field, strings)),
+                Value::Null | Value::Bool(_) | Value::Number(_) => {}
+            }
+        }
+
+        let mut strings = Vec::new();
+        if let Ok(value) = self.try_into_any() {
+            collect(value, &mut strings);
+        }
+        strings
+    }
 }
 
 impl<'de> serde::de::Deserialize<'de> for Json {
@@ -140,6 +160,68 @@ impl FromStr for Json {
     }
 }
 
+/// Serialize `value` in canonical form.
+///
+/// The canonical form has no insignificant whitespace, object keys are sorted by their
+/// UTF-16 code units, and floating-point numbers with no fractional part that are exactly
+/// representable as integers are written as integers. Equal JSON values always
+/// have the same canonical form, so it can be signed and verified by implementations
+/// which don't share a serializer.
+pub fn to_canonical_string(value: &Value) -> String {
+    let mut out = String::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_string(key, out);
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::String(string) => write_canonical_string(string, out),
+        Value::Number(number) => out.push_str(&canonical_number(number)),
+        Value::Bool(_) | Value::Null => out.push_str(&value.to_string()),
+    }
+}
+
+fn write_canonical_string(string: &str, out: &mut String) {
+    out.push_str(&serde_json::to_string(string).expect("strings serialize to JSON"));
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn canonical_number(number: &serde_json::Number) -> String {
+    /// Integers of larger magnitude can't be represented exactly by `f64`
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+    if let Some(float) = number.as_f64().filter(|_| number.is_f64()) {
+        if float.fract() == 0.0 && float.abs() < MAX_SAFE_INTEGER {
+            return format!("{}", float as i64);
+        }
+    }
+    number.to_string()
+}
+
 impl Default for Json {
     fn default() -> Self {
         // NOTE: empty string isn't valid JSON
@@ -172,3 +254,46 @@ mod candidate {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn canonical_form_sorts_keys_and_strips_whitespace() {
+        let value = json!({
+            "b": [1, {"z": null, "a": true}],
+            "a": "text",
+            "\u{e9}": 0,
+            "B": 1,
+        });
+
+        assert_eq!(
+            to_canonical_string(&value),
+            r#"{"B":1,"a":"text","b":[1,{"a":true,"z":null}],"é":0}"#
+        );
+    }
+
+    #[test]
+    fn canonical_form_normalizes_numbers() {
+        let value = json!([1.0, -0.0, 1.5, 1e300, u64::MAX, -7]);
+
+        assert_eq!(
+            to_canonical_string(&value),
+            format!("[1,0,1.5,1e300,{},-7]", u64::MAX)
+        );
+    }
+
+    #[test]
+    fn string_values_skip_keys_and_other_values() {
+        let value = Json::new(json!({
+            "name": "rare sword",
+            "tags": ["weapon", 7, {"rarity": "rare"}],
+            "broken": false,
+        }));
+
+        assert_eq!(value.string_values(), ["rare sword", "weapon", "rare"]);
+    }
+}
@@ -2,7 +2,10 @@
 //! `build_schemas` `fn`, which is the function which decides which
 //! types are included in the schema.
 use iroha_data_model::{
-    block::stream::{BlockMessage, BlockSubscriptionRequest},
+    block::{
+        stream::{BlockMessage, BlockSubscriptionRequest},
+        AccountHistory, BlockInclusionProof,
+    },
     query::{QueryResponse, SignedQuery},
 };
 use iroha_schema::prelude::*;
@@ -34,7 +37,10 @@ macro_rules! types {
 /// shall be included recursively.
 pub fn build_schemas() -> MetaMap {
     use iroha_data_model::prelude::*;
-    use iroha_executor_data_model::{isi::multisig, permission};
+    use iroha_executor_data_model::{
+        isi::{multisig, spending_limit},
+        permission,
+    };
 
     macro_rules! schemas {
         ($($t:ty),* $(,)?) => {{
@@ -58,6 +64,8 @@ pub fn build_schemas() -> MetaMap {
         // Block stream
         BlockMessage,
         BlockSubscriptionRequest,
+        BlockInclusionProof,
+        AccountHistory,
 
         // Never referenced, but present in type signature. Like `PhantomData<X>`
         MerkleTree<SignedTransaction>,
@@ -72,6 +80,7 @@ pub fn build_schemas() -> MetaMap {
         permission::account::CanRegisterAccount,
         permission::account::CanUnregisterAccount,
         permission::account::CanModifyAccountMetadata,
+        permission::account::CanSubscribeToAccountEvents,
 
         permission::asset_definition::CanRegisterAssetDefinition,
         permission::asset_definition::CanUnregisterAssetDefinition,
@@ -83,16 +92,24 @@ pub fn build_schemas() -> MetaMap {
         permission::asset::CanMintAsset,
         permission::asset::CanBurnAsset,
         permission::asset::CanTransferAsset,
+        permission::asset::CanTransferAssetInDomain,
+        permission::asset::CannotTransferAsset,
+        permission::asset::CannotTransferAssetWithDefinition,
 
         permission::nft::CanRegisterNft,
         permission::nft::CanUnregisterNft,
         permission::nft::CanTransferNft,
         permission::nft::CanModifyNftMetadata,
+        permission::nft::CanModifyAnyNftInDomain,
 
         permission::parameter::CanSetParameters,
         permission::role::CanManageRoles,
 
+        permission::query::CanQueryAssetsInDomain,
+        permission::query::CanQueryTransactions,
+
         permission::trigger::CanRegisterTrigger,
+        permission::trigger::CanRegisterTriggerInDomain,
         permission::trigger::CanExecuteTrigger,
         permission::trigger::CanUnregisterTrigger,
         permission::trigger::CanModifyTrigger,
@@ -106,6 +123,9 @@ pub fn build_schemas() -> MetaMap {
         multisig::MultisigSpec,
         multisig::MultisigProposalValue,
 
+        // Spending limit operations
+        spending_limit::SpendingLimitInstructionBox,
+
         // Genesis file - used by SDKs to generate the genesis block
         // TODO: IMO it could/should be removed from the schema
         iroha_genesis::RawGenesisTransaction,
@@ -116,10 +136,14 @@ pub fn build_schemas() -> MetaMap {
 }
 
 types!(
+    AbortExecutorUpgrade,
+    AcceptDomainTransfer,
     Account,
     AccountEvent,
     AccountEventFilter,
     AccountEventSet,
+    AccountHistory,
+    AccountHistoryBlock,
     AccountId,
     AccountIdPredicateAtom,
     AccountIdProjection<PredicateMarker>,
@@ -129,11 +153,19 @@ types!(
     AccountProjection<PredicateMarker>,
     AccountProjection<SelectorMarker>,
     AccountRoleChanged,
+    AccountStatement,
     Action,
     ActionPredicateAtom,
     ActionProjection<PredicateMarker>,
     ActionProjection<SelectorMarker>,
+    AggregateQuery,
+    Aggregation,
     Algorithm,
+    Anchor,
+    AnchoredHash,
+    AnchoredHashPredicateAtom,
+    AnchoredHashProjection<PredicateMarker>,
+    AnchoredHashProjection<SelectorMarker>,
     Asset,
     AssetChanged,
     AssetDefinition,
@@ -156,14 +188,19 @@ types!(
     AssetIdPredicateAtom,
     AssetIdProjection<PredicateMarker>,
     AssetIdProjection<SelectorMarker>,
+    AssetMovement,
     AssetPredicateAtom,
     AssetProjection<PredicateMarker>,
     AssetProjection<SelectorMarker>,
+    AssetStatement,
     BTreeMap<AccountId, u8>,
     BTreeMap<CustomParameterId, CustomParameter>,
+    BTreeMap<DomainId, TransactionTtlBounds>,
     BTreeMap<Name, Json>,
+    BTreeMap<Name, String>,
     BTreeSet<AccountId>,
     BTreeSet<Permission>,
+    BTreeSet<RoleId>,
     BTreeSet<BlockSignature>,
     BTreeSet<String>,
     BlockEvent,
@@ -175,6 +212,7 @@ types!(
     BlockHeaderPredicateAtom,
     BlockHeaderProjection<PredicateMarker>,
     BlockHeaderProjection<SelectorMarker>,
+    BlockInclusionProof,
     BlockMessage,
     BlockParameter,
     BlockParameters,
@@ -186,11 +224,13 @@ types!(
     BlockSubscriptionRequest,
     Box<AssetId>,
     Box<CompoundPredicate<Account>>,
+    Box<CompoundPredicate<AnchoredHash>>,
     Box<CompoundPredicate<AssetDefinition>>,
     Box<CompoundPredicate<Asset>>,
     Box<CompoundPredicate<BlockHeader>>,
     Box<CompoundPredicate<CommittedTransaction>>,
     Box<CompoundPredicate<Domain>>,
+    Box<CompoundPredicate<ExcludedTransaction>>,
     Box<CompoundPredicate<Nft>>,
     Box<CompoundPredicate<PeerId>>,
     Box<CompoundPredicate<Permission>>,
@@ -212,11 +252,13 @@ types!(
     CommittedTransactionProjection<PredicateMarker>,
     CommittedTransactionProjection<SelectorMarker>,
     CompoundPredicate<Account>,
+    CompoundPredicate<AnchoredHash>,
     CompoundPredicate<AssetDefinition>,
     CompoundPredicate<Asset>,
     CompoundPredicate<BlockHeader>,
     CompoundPredicate<CommittedTransaction>,
     CompoundPredicate<Domain>,
+    CompoundPredicate<ExcludedTransaction>,
     CompoundPredicate<Nft>,
     CompoundPredicate<PeerId>,
     CompoundPredicate<Permission>,
@@ -249,9 +291,13 @@ types!(
     DomainPredicateAtom,
     DomainProjection<PredicateMarker>,
     DomainProjection<SelectorMarker>,
+    DomainTransactionTtl,
+    DomainTransferOffer,
     EventBox,
     EventFilterBox,
     EventMessage,
+    EventSubscriber,
+    EventSubscriptionPayload,
     EventSubscriptionRequest,
     Executable,
     ExecuteTrigger,
@@ -259,6 +305,10 @@ types!(
     ExecuteTriggerEventFilter,
     ExecutionStep,
     ExecutionTime,
+    ExcludedTransaction,
+    ExcludedTransactionPredicateAtom,
+    ExcludedTransactionProjection<PredicateMarker>,
+    ExcludedTransactionProjection<SelectorMarker>,
     Executor,
     ExecutorDataModel,
     ExecutorEvent,
@@ -266,42 +316,60 @@ types!(
     ExecutorEventSet,
     WasmPath,
     ExecutorUpgrade,
+    ExecutorUpgradeAborted,
+    ExecutorUpgradeParameters,
+    ExecutorUpgradeScheduled,
     FetchSize,
+    FindAccountStatement,
     FindAccounts,
     FindAccountsWithAsset,
     FindActiveTriggerIds,
+    FindAnchors,
     FindAssets,
     FindAssetsDefinitions,
     FindBlockHeaders,
     FindBlocks,
     FindDomains,
+    FindExcludedTransactions,
     FindError,
     FindExecutorDataModel,
     FindNfts,
     FindParameters,
     FindPeers,
+    FindPermissionGrantHistory,
+    FindPermissionSchemas,
     FindPermissionsByAccountId,
     FindRoleIds,
+    FindRoleTemplate,
     FindRoles,
     FindRolesByAccountId,
+    FindSpendingCounters,
     FindTransactions,
+    FindTriggerLoops,
     FindTriggers,
     ForwardCursor,
     GenesisWasmAction,
     GenesisWasmTrigger,
     Grant<Permission, Account>,
+    Grant<PermissionGroup, Account>,
     Grant<Permission, Role>,
+    Grant<RevocablePermission, Account>,
     Grant<RoleId, Account>,
+    Grant<RoleId, Role>,
     GrantBox,
     Hash,
     HashOf<BlockHeader>,
+    HashOf<MerkleMountainRange<BlockHeader>>,
     HashOf<MerkleTree<TransactionEntrypoint>>,
+    HashOf<MerkleTree<TransactionRejection>>,
     HashOf<MerkleTree<TransactionResult>>,
     HashOf<SignedTransaction>,
     HashOf<TransactionEntrypoint>,
+    HashOf<TransactionRejection>,
     HashOf<TransactionResult>,
     HashOf<Vec<InstructionBox>>,
     IdBox,
+    InstantiateRoleTemplate,
     InstructionBox,
     InstructionEvaluationError,
     InstructionExecutionError,
@@ -311,17 +379,21 @@ types!(
     IpfsPath,
     Ipv6Addr,
     Ipv4Addr,
+    IsTransactionKnown,
     Json,
     JsonPredicateAtom,
     JsonProjection<PredicateMarker>,
     JsonProjection<SelectorMarker>,
     Level,
     Log,
+    MarkRedactable,
     MathError,
+    MerkleMountainRangeProof<BlockHeader>,
     MerkleProof<TransactionEntrypoint>,
     MerkleProof<TransactionResult>,
     MerkleTree<SignedTransaction>,
     MerkleTree<TransactionEntrypoint>,
+    MerkleTree<TransactionRejection>,
     MerkleTree<TransactionResult>,
     Metadata,
     MetadataChanged<AccountId>,
@@ -340,6 +412,7 @@ types!(
     MintabilityError,
     Mintable,
     Mismatch<NumericSpec>,
+    MovementDirection,
     Name,
     NameProjection<PredicateMarker>,
     NameProjection<SelectorMarker>,
@@ -371,11 +444,16 @@ types!(
     Option<AccountId>,
     Option<AssetDefinitionId>,
     Option<AssetId>,
+    Option<BlockInclusionProof>,
     Option<BlockStatus>,
     Option<DomainId>,
+    Option<EventSubscriber>,
     Option<ForwardCursor>,
+    Option<Hash>,
     Option<HashOf<BlockHeader>>,
+    Option<HashOf<MerkleMountainRange<BlockHeader>>>,
     Option<HashOf<MerkleTree<TransactionEntrypoint>>>,
+    Option<HashOf<MerkleTree<TransactionRejection>>>,
     Option<HashOf<MerkleTree<TransactionResult>>>,
     Option<HashOf<SignedTransaction>>,
     Option<HashOf<TransactionEntrypoint>>,
@@ -385,13 +463,35 @@ types!(
     Option<NftId>,
     Option<NonZeroU32>,
     Option<NonZeroU64>,
+    Option<Numeric>,
     Option<Option<NonZeroU64>>,
     Option<Parameters>,
     Option<PeerId>,
+    Option<PublicKey>,
+    Option<QueueDropReason>,
     Option<RoleId>,
+    Option<SelectorTuple<Account>>,
+    Option<SelectorTuple<AnchoredHash>>,
+    Option<SelectorTuple<AssetDefinition>>,
+    Option<SelectorTuple<Asset>>,
+    Option<SelectorTuple<BlockHeader>>,
+    Option<SelectorTuple<CommittedTransaction>>,
+    Option<SelectorTuple<Domain>>,
+    Option<SelectorTuple<ExcludedTransaction>>,
+    Option<SelectorTuple<Nft>>,
+    Option<SelectorTuple<PeerId>>,
+    Option<SelectorTuple<Permission>>,
+    Option<SelectorTuple<RoleId>>,
+    Option<SelectorTuple<Role>>,
+    Option<SelectorTuple<SignedBlock>>,
+    Option<SelectorTuple<TriggerId>>,
+    Option<SelectorTuple<Trigger>>,
+    Option<String>,
     Option<TransactionStatus>,
+    Option<TransactionTtlBounds>,
     Option<TriggerCompletedOutcomeType>,
     Option<TriggerId>,
+    Option<WasmSmartContract>,
     Option<bool>,
     Option<u32>,
     Option<u64>,
@@ -408,9 +508,14 @@ types!(
     PeerIdProjection<PredicateMarker>,
     PeerIdProjection<SelectorMarker>,
     Permission,
+    PermissionGrantAction,
+    PermissionGrantEvent,
+    PermissionGroup,
+    PermissionHolder,
     PermissionPredicateAtom,
     PermissionProjection<PredicateMarker>,
     PermissionProjection<SelectorMarker>,
+    PermissionTokenSchema,
     PipelineEventBox,
     PipelineEventFilterBox,
     PublicKey,
@@ -425,16 +530,20 @@ types!(
     QueryParams,
     QueryRequest,
     QueryRequestWithAuthority,
+    QueryRequestWithAuthorityV1,
     QueryResponse,
     QuerySignature,
+    QuerySignatureV1,
     QueryWithFilter<FindAccounts>,
     QueryWithFilter<FindAccountsWithAsset>,
     QueryWithFilter<FindActiveTriggerIds>,
+    QueryWithFilter<FindAnchors>,
     QueryWithFilter<FindAssets>,
     QueryWithFilter<FindAssetsDefinitions>,
     QueryWithFilter<FindBlockHeaders>,
     QueryWithFilter<FindBlocks>,
     QueryWithFilter<FindDomains>,
+    QueryWithFilter<FindExcludedTransactions>,
     QueryWithFilter<FindNfts>,
     QueryWithFilter<FindPeers>,
     QueryWithFilter<FindPermissionsByAccountId>,
@@ -444,13 +553,21 @@ types!(
     QueryWithFilter<FindTransactions>,
     QueryWithFilter<FindTriggers>,
     QueryWithParams,
+    QueueDropReason,
+    QueueEvent,
+    QueueEventFilter,
+    ReadToken,
+    ReadTokenPayload,
+    Redact,
     Register<Account>,
     Register<AssetDefinition>,
     Register<Domain>,
     Register<Nft>,
     Register<Peer>,
     Register<Role>,
+    Register<RoleTemplate>,
     Register<Trigger>,
+    RegisterAccounts,
     RegisterBox,
     RemoveKeyValue<Account>,
     RemoveKeyValue<AssetDefinition>,
@@ -461,9 +578,13 @@ types!(
     Repeats,
     RepetitionError,
     Result<DataTriggerSequence, TransactionRejectionReason>,
+    RevocablePermission,
     Revoke<Permission, Account>,
+    Revoke<PermissionGroup, Account>,
     Revoke<Permission, Role>,
     Revoke<RoleId, Account>,
+    Revoke<RoleId, Role>,
+    RevokeAll,
     RevokeBox,
     Role,
     RoleEvent,
@@ -473,16 +594,21 @@ types!(
     RoleIdPredicateAtom,
     RoleIdProjection<PredicateMarker>,
     RoleIdProjection<SelectorMarker>,
+    RoleInheritanceChanged,
+    RoleOwnerChanged,
     RolePermissionChanged,
     RolePredicateAtom,
     RoleProjection<PredicateMarker>,
     RoleProjection<SelectorMarker>,
+    RoleTemplate,
     SelectorTuple<Account>,
+    SelectorTuple<AnchoredHash>,
     SelectorTuple<AssetDefinition>,
     SelectorTuple<Asset>,
     SelectorTuple<BlockHeader>,
     SelectorTuple<CommittedTransaction>,
     SelectorTuple<Domain>,
+    SelectorTuple<ExcludedTransaction>,
     SelectorTuple<Nft>,
     SelectorTuple<PeerId>,
     SelectorTuple<Permission>,
@@ -491,6 +617,7 @@ types!(
     SelectorTuple<SignedBlock>,
     SelectorTuple<TriggerId>,
     SelectorTuple<Trigger>,
+    SetDomainValidator,
     SetKeyValue<Account>,
     SetKeyValue<AssetDefinition>,
     SetKeyValue<Domain>,
@@ -498,19 +625,26 @@ types!(
     SetKeyValue<Trigger>,
     SetKeyValueBox,
     SetParameter,
+    SetSpendingCounter,
     Signature,
     SignatureOf<BlockHeader>,
+    SignatureOf<EventSubscriptionPayload>,
     SignatureOf<QueryRequestWithAuthority>,
+    SignatureOf<QueryRequestWithAuthorityV1>,
+    SignatureOf<ReadTokenPayload>,
     SignatureOf<TransactionPayload>,
     SignedBlock,
     SignedBlockPredicateAtom,
     SignedBlockProjection<PredicateMarker>,
     SignedBlockProjection<SelectorMarker>,
-    SignedBlockV1,
+    SignedBlockV2,
+    SignedPayloadForm,
     SignedQuery,
     SignedQueryV1,
+    SignedQueryV2,
     SignedTransaction,
     SignedTransactionV1,
+    SignedTransactionV2,
     SingularQueryBox,
     SingularQueryOutputBox,
     SmartContractParameter,
@@ -519,7 +653,10 @@ types!(
     SocketAddrHost,
     SocketAddrV4,
     SocketAddrV6,
+    SortOrder,
     Sorting,
+    SpendingCounter,
+    SpendingWindow,
     Status,
     String,
     StringPredicateAtom,
@@ -544,6 +681,7 @@ types!(
     TransactionParameters,
     TransactionPayload,
     TransactionRejectionReason,
+    TransactionRejection,
     TransactionResult,
     TransactionResultHashPredicateAtom,
     TransactionResultHashProjection<PredicateMarker>,
@@ -553,9 +691,11 @@ types!(
     TransactionResultProjection<SelectorMarker>,
     TransactionSignature,
     TransactionStatus,
+    TransactionTtlBounds,
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Account, DomainId, Account>,
     Transfer<Account, NftId, Account>,
+    Transfer<Account, RoleId, Account>,
     Transfer<Asset, Numeric, Account>,
     TransferBox,
     Trigger,
@@ -571,6 +711,7 @@ types!(
     TriggerIdPredicateAtom,
     TriggerIdProjection<PredicateMarker>,
     TriggerIdProjection<SelectorMarker>,
+    TriggerLoop,
     TriggerNumberOfExecutionsChanged,
     TriggerPredicateAtom,
     TriggerProjection<PredicateMarker>,
@@ -583,26 +724,33 @@ types!(
     Unregister<Nft>,
     Unregister<Peer>,
     Unregister<Role>,
+    Unregister<RoleTemplate>,
     Unregister<Trigger>,
     UnregisterBox,
     Upgrade,
     Uptime,
     ValidationFail,
     Vec<Account>,
+    Vec<AccountHistoryBlock>,
     Vec<AccountId>,
     Vec<Action>,
+    Vec<AnchoredHash>,
     Vec<Asset>,
     Vec<AssetId>,
     Vec<AssetDefinition>,
     Vec<AssetDefinitionId>,
+    Vec<AssetMovement>,
+    Vec<AssetStatement>,
     Vec<BlockHeader>,
     Vec<CommittedTransaction>,
     Vec<CompoundPredicate<Account>>,
+    Vec<CompoundPredicate<AnchoredHash>>,
     Vec<CompoundPredicate<AssetDefinition>>,
     Vec<CompoundPredicate<Asset>>,
     Vec<CompoundPredicate<BlockHeader>>,
     Vec<CompoundPredicate<CommittedTransaction>>,
     Vec<CompoundPredicate<Domain>>,
+    Vec<CompoundPredicate<ExcludedTransaction>>,
     Vec<CompoundPredicate<Nft>>,
     Vec<CompoundPredicate<PeerId>>,
     Vec<CompoundPredicate<Permission>>,
@@ -613,6 +761,7 @@ types!(
     Vec<CompoundPredicate<Trigger>>,
     Vec<Domain>,
     Vec<DomainId>,
+    Vec<ExcludedTransaction>,
     Vec<EventFilterBox>,
     Vec<GenesisWasmTrigger>,
     Vec<InstructionBox>,
@@ -623,22 +772,29 @@ types!(
     Vec<Parameter>,
     Vec<PeerId>,
     Vec<Permission>,
+    Vec<PermissionGrantEvent>,
+    Vec<PermissionTokenSchema>,
     Vec<QueryOutputBatchBox>,
     Vec<Role>,
     Vec<RoleId>,
     Vec<SignedBlock>,
     Vec<SignedTransaction>,
+    Vec<TransactionRejection>,
     Vec<AccountProjection<SelectorMarker>>,
+    Vec<AnchoredHashProjection<SelectorMarker>>,
     Vec<AssetDefinitionProjection<SelectorMarker>>,
     Vec<AssetProjection<SelectorMarker>>,
     Vec<BlockHeaderProjection<SelectorMarker>>,
     Vec<CommittedTransactionProjection<SelectorMarker>>,
     Vec<DomainProjection<SelectorMarker>>,
+    Vec<ExcludedTransactionProjection<SelectorMarker>>,
     Vec<HashOf<BlockHeader>>,
     Vec<HashOf<TransactionEntrypoint>>,
+    Vec<HashOf<TransactionRejection>>,
     Vec<HashOf<TransactionResult>>,
     Vec<Metadata>,
     Vec<Name>,
+    Vec<NewAccount>,
     Vec<Numeric>,
     Vec<Option<HashOf<TransactionEntrypoint>>>,
     Vec<Option<HashOf<TransactionResult>>>,
@@ -648,6 +804,7 @@ types!(
     Vec<RoleIdProjection<SelectorMarker>>,
     Vec<RoleProjection<SelectorMarker>>,
     Vec<SignedBlockProjection<SelectorMarker>>,
+    Vec<SpendingCounter>,
     Vec<String>,
     Vec<TransactionEntrypoint>,
     Vec<TransactionResult>,
@@ -656,6 +813,7 @@ types!(
     Vec<TriggerProjection<SelectorMarker>>,
     Vec<Trigger>,
     Vec<TriggerId>,
+    Vec<TriggerLoop>,
     Vec<u8>,
     WasmExecutionFail,
     WasmSmartContract,
@@ -686,10 +844,11 @@ pub mod complete_data_model {
         block::{
             error::BlockRejectionReason,
             stream::{BlockMessage, BlockSubscriptionRequest},
-            BlockHeader, BlockPayload, BlockResult, BlockSignature, SignedBlock, SignedBlockV1,
+            AccountHistory, AccountHistoryBlock, BlockHeader, BlockInclusionProof, BlockPayload,
+            BlockResult, BlockSignature, SignedBlock, SignedBlockV2,
         },
         domain::NewDomain,
-        events::pipeline::{BlockEventFilter, TransactionEventFilter},
+        events::pipeline::{BlockEventFilter, QueueEventFilter, TransactionEventFilter},
         executor::{Executor, ExecutorDataModel},
         ipfs::IpfsPath,
         isi::{
@@ -700,22 +859,25 @@ pub mod complete_data_model {
             InstructionType,
         },
         parameter::{
-            BlockParameter, BlockParameters, CustomParameter, CustomParameterId, Parameter,
-            Parameters, SmartContractParameter, SmartContractParameters, SumeragiParameter,
-            SumeragiParameters, TransactionParameter, TransactionParameters,
+            BlockParameter, BlockParameters, CustomParameter, CustomParameterId,
+            DomainTransactionTtl, ExecutorUpgradeParameters, Parameter, Parameters,
+            SmartContractParameter, SmartContractParameters, SumeragiParameter, SumeragiParameters,
+            TransactionParameter, TransactionParameters, TransactionTtlBounds,
         },
         prelude::*,
         query::{
             dsl::{CompoundPredicate, PredicateMarker, SelectorMarker},
             error::{FindError, QueryExecutionFail},
             parameters::{ForwardCursor, QueryParams},
+            read_token::{ReadToken, ReadTokenPayload},
             CommittedTransaction, QueryOutput, QueryOutputBatchBox, QueryOutputBatchBoxTuple,
-            QueryRequestWithAuthority, QueryResponse, QuerySignature, QueryWithFilter,
-            QueryWithParams, SignedQuery, SignedQueryV1, SingularQueryOutputBox,
+            QueryRequestWithAuthority, QueryRequestWithAuthorityV1, QueryResponse, QuerySignature,
+            QuerySignatureV1, QueryWithFilter, QueryWithParams, SignedQuery, SignedQueryV1,
+            SignedQueryV2, SingularQueryOutputBox,
         },
         transaction::{
-            error::TransactionLimitError, SignedTransactionV1, TransactionPayload,
-            TransactionSignature,
+            error::TransactionLimitError, SignedTransactionV1, SignedTransactionV2,
+            TransactionPayload, TransactionSignature,
         },
         Level,
     };
@@ -773,6 +935,9 @@ mod tests {
         insert_into_test_map!(
             iroha_executor_data_model::permission::account::CanModifyAccountMetadata
         );
+        insert_into_test_map!(
+            iroha_executor_data_model::permission::account::CanSubscribeToAccountEvents
+        );
         insert_into_test_map!(
             iroha_executor_data_model::permission::asset_definition::CanRegisterAssetDefinition
         );
@@ -792,16 +957,30 @@ mod tests {
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanMintAsset);
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanBurnAsset);
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanTransferAsset);
+        insert_into_test_map!(
+            iroha_executor_data_model::permission::asset::CanTransferAssetInDomain
+        );
+        insert_into_test_map!(iroha_executor_data_model::permission::asset::CannotTransferAsset);
+        insert_into_test_map!(
+            iroha_executor_data_model::permission::asset::CannotTransferAssetWithDefinition
+        );
 
         insert_into_test_map!(iroha_executor_data_model::permission::nft::CanRegisterNft);
         insert_into_test_map!(iroha_executor_data_model::permission::nft::CanUnregisterNft);
         insert_into_test_map!(iroha_executor_data_model::permission::nft::CanTransferNft);
         insert_into_test_map!(iroha_executor_data_model::permission::nft::CanModifyNftMetadata);
+        insert_into_test_map!(iroha_executor_data_model::permission::nft::CanModifyAnyNftInDomain);
 
         insert_into_test_map!(iroha_executor_data_model::permission::parameter::CanSetParameters);
         insert_into_test_map!(iroha_executor_data_model::permission::role::CanManageRoles);
 
+        insert_into_test_map!(iroha_executor_data_model::permission::query::CanQueryAssetsInDomain);
+        insert_into_test_map!(iroha_executor_data_model::permission::query::CanQueryTransactions);
+
         insert_into_test_map!(iroha_executor_data_model::permission::trigger::CanRegisterTrigger);
+        insert_into_test_map!(
+            iroha_executor_data_model::permission::trigger::CanRegisterTriggerInDomain
+        );
         insert_into_test_map!(iroha_executor_data_model::permission::trigger::CanExecuteTrigger);
         insert_into_test_map!(iroha_executor_data_model::permission::trigger::CanUnregisterTrigger);
         insert_into_test_map!(iroha_executor_data_model::permission::trigger::CanModifyTrigger);
@@ -816,6 +995,11 @@ mod tests {
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigApprove);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigSpec);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigProposalValue);
+        insert_into_test_map!(
+            iroha_executor_data_model::isi::spending_limit::SpendingLimitInstructionBox
+        );
+        insert_into_test_map!(iroha_executor_data_model::isi::spending_limit::SetSpendingLimit);
+        insert_into_test_map!(iroha_executor_data_model::isi::spending_limit::RemoveSpendingLimit);
 
         map
     }
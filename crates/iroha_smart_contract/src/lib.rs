@@ -271,6 +271,7 @@ mod tests {
                 FindAssets,
                 CompoundPredicate::<Asset>::build(|asset| asset.id.eq(asset_id)),
                 SelectorTuple::<Asset>::build(|asset| asset.value),
+                None,
             )),
             QueryParams::default(),
         )
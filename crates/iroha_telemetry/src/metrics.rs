@@ -2,6 +2,7 @@
 
 use std::{ops::Deref, time::Duration};
 
+use iroha_crypto::PublicKey;
 use iroha_schema::{Ident, IntoSchema, MetaMap, Metadata, TypeId, UnnamedFieldsMeta};
 use parity_scale_codec::{Compact, Decode, Encode};
 use prometheus::{
@@ -73,7 +74,7 @@ impl IntoSchema for Uptime {
 }
 
 /// Response body for GET status request
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, Encode, Decode, IntoSchema)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Encode, Decode, IntoSchema)]
 pub struct Status {
     /// Number of currently connected peers excluding the reporting peer
     #[codec(compact)]
@@ -101,6 +102,8 @@ pub struct Status {
     /// Number of the transactions in the queue
     #[codec(compact)]
     pub queue_size: u64,
+    /// Public key of the peer leading the current round, if known
+    pub leader: Option<PublicKey>,
 }
 
 impl<T: Deref<Target = Metrics>> From<&T> for Status {
@@ -120,6 +123,7 @@ impl<T: Deref<Target = Metrics>> From<&T> for Status {
                 .try_into()
                 .expect("INTERNAL BUG: Number of view changes exceeds u32::MAX"),
             queue_size: val.queue_size.get(),
+            leader: None,
         }
     }
 }
@@ -139,6 +143,10 @@ pub struct Metrics {
     pub commit_time_ms: Histogram,
     /// Number of currently connected peers excluding the reporting peer
     pub connected_peers: GenericGauge<AtomicU64>,
+    /// Bytes exchanged with each of the connected peers over the current connection, per direction
+    pub peer_traffic_bytes: GenericGaugeVec<AtomicU64>,
+    /// Messages exchanged with each of the connected peers over the current connection, per direction
+    pub peer_traffic_messages: GenericGaugeVec<AtomicU64>,
     /// Uptime of the network, starting from commit of the genesis block
     pub uptime_since_genesis_ms: GenericGauge<AtomicU64>,
     /// Number of domains.
@@ -155,8 +163,14 @@ pub struct Metrics {
     pub view_changes: ViewChangesGauge,
     /// Number of transactions in the queue
     pub queue_size: GenericGauge<AtomicU64>,
+    /// Number of transactions dropped from the queue, or rejected by it, per reason
+    pub queue_dropped_txs: GenericGaugeVec<AtomicU64>,
     /// Number of sumeragi dropped messages
     pub dropped_messages: DroppedMessagesCounter,
+    /// Time it took to write state snapshots
+    pub snapshot_duration_ms: Histogram,
+    /// Estimated bytes held per subsystem
+    pub memory_bytes: GenericGaugeVec<AtomicU64>,
     /// Internal use only. Needed for generating the response.
     registry: Registry,
 }
@@ -224,6 +238,22 @@ impl Default for Metrics {
             "Total number of currently connected peers",
         )
         .expect("Infallible");
+        let peer_traffic_bytes = GenericGaugeVec::new(
+            Opts::new(
+                "peer_traffic_bytes",
+                "Bytes exchanged with the connected peer over the current connection",
+            ),
+            &["peer", "direction"],
+        )
+        .expect("Infallible");
+        let peer_traffic_messages = GenericGaugeVec::new(
+            Opts::new(
+                "peer_traffic_messages",
+                "Messages exchanged with the connected peer over the current connection",
+            ),
+            &["peer", "direction"],
+        )
+        .expect("Infallible");
         let uptime_since_genesis_ms = GenericGauge::new(
             "uptime_since_genesis_ms",
             "Network up-time, from creation of the genesis block",
@@ -242,8 +272,29 @@ impl Default for Metrics {
         .expect("Infallible");
         let queue_size = GenericGauge::new("queue_size", "Number of the transactions in the queue")
             .expect("Infallible");
+        let queue_dropped_txs = GenericGaugeVec::new(
+            Opts::new(
+                "queue_dropped_txs",
+                "Transactions submitted to this peer which were dropped from the queue",
+            ),
+            &["reason"],
+        )
+        .expect("Infallible");
         let dropped_messages =
             IntCounter::new("dropped_messages", "Sumeragi dropped messages").expect("Infallible");
+        let snapshot_duration_ms = Histogram::with_opts(
+            HistogramOpts::new(
+                "snapshot_duration_ms",
+                "Time it took to write a state snapshot on this peer",
+            )
+            .buckets(prometheus::exponential_buckets(100.0, 4.0, 7).expect("inputs are valid")),
+        )
+        .expect("Infallible");
+        let memory_bytes = GenericGaugeVec::new(
+            Opts::new("memory_bytes", "Estimated bytes held by peer subsystems"),
+            &["subsystem"],
+        )
+        .expect("Infallible");
         let registry = Registry::new();
 
         macro_rules! register {
@@ -264,6 +315,8 @@ impl Default for Metrics {
             last_commit_time_ms,
             commit_time_ms,
             connected_peers,
+            peer_traffic_bytes,
+            peer_traffic_messages,
             uptime_since_genesis_ms,
             domains,
             accounts,
@@ -271,7 +324,10 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
-            dropped_messages
+            queue_dropped_txs,
+            dropped_messages,
+            snapshot_duration_ms,
+            memory_bytes
         );
 
         Self {
@@ -281,6 +337,8 @@ impl Default for Metrics {
             last_commit_time_ms,
             commit_time_ms,
             connected_peers,
+            peer_traffic_bytes,
+            peer_traffic_messages,
             uptime_since_genesis_ms,
             domains,
             accounts,
@@ -289,7 +347,10 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
+            queue_dropped_txs,
             dropped_messages,
+            snapshot_duration_ms,
+            memory_bytes,
             registry,
         }
     }
@@ -340,6 +401,7 @@ mod test {
             uptime: Uptime(Duration::new(5, 937_000_000)),
             view_changes: 2,
             queue_size: 18,
+            leader: None,
         }
     }
 
@@ -363,7 +425,8 @@ mod test {
                 "nanos": 937000000
               },
               "view_changes": 2,
-              "queue_size": 18
+              "queue_size": 18,
+              "leader": null
             }"#]];
         expected.assert_eq(&actual);
     }
@@ -376,7 +439,7 @@ mod test {
         let actual = hex::encode_upper(bytes);
         // CAUTION: if this is outdated, make sure to update the documentation:
         // https://docs.iroha.tech/reference/torii-endpoints.html#status
-        let expected = expect_test::expect!["10140C09027C0C14407CD9370848"];
+        let expected = expect_test::expect!["10140C09027C0C14407CD937084800"];
         expected.assert_eq(&actual);
     }
 }
@@ -5,7 +5,8 @@ use std::path::Path;
 use iroha_config::base::toml::WriteExt;
 use iroha_data_model::{
     asset::AssetDefinitionId,
-    isi::{Grant, Instruction},
+    executor::Executor,
+    isi::{Grant, Instruction, Upgrade},
     peer::PeerId,
     ChainId,
 };
@@ -15,16 +16,14 @@ use iroha_executor_data_model::permission::{
 };
 use iroha_genesis::{GenesisBlock, RawGenesisTransaction};
 use iroha_primitives::unique_vec::UniqueVec;
-use iroha_test_samples::{ALICE_ID, SAMPLE_GENESIS_ACCOUNT_KEYPAIR};
+use iroha_test_samples::{load_sample_wasm, ALICE_ID, SAMPLE_GENESIS_ACCOUNT_KEYPAIR};
 use toml::Table;
 
-pub fn chain_id() -> ChainId {
-    ChainId::from("00000000-0000-0000-0000-000000000000")
-}
+use crate::ExecutorPolicy;
 
-pub fn base_iroha_config() -> Table {
+pub fn base_iroha_config(chain: &ChainId) -> Table {
     Table::new()
-        .write("chain", chain_id())
+        .write("chain", chain)
         .write(
             ["genesis", "public_key"],
             SAMPLE_GENESIS_ACCOUNT_KEYPAIR.public_key(),
@@ -38,8 +37,18 @@ pub fn base_iroha_config() -> Table {
 }
 
 pub fn genesis<T: Instruction>(
+    chain: ChainId,
     extra_isi: impl IntoIterator<Item = T>,
     topology: UniqueVec<PeerId>,
+) -> GenesisBlock {
+    genesis_with_policy(chain, extra_isi, topology, ExecutorPolicy::default())
+}
+
+pub fn genesis_with_policy<T: Instruction>(
+    chain: ChainId,
+    extra_isi: impl IntoIterator<Item = T>,
+    topology: UniqueVec<PeerId>,
+    policy: ExecutorPolicy,
 ) -> GenesisBlock {
     // TODO: Fix this somehow. Probably we need to make `kagami` a library (#3253).
     let json_path = Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -67,23 +76,32 @@ pub fn genesis<T: Instruction>(
     );
     let grant_upgrade_executor_permission =
         Grant::account_permission(CanUpgradeExecutor, ALICE_ID.clone());
-    for isi in [
-        grant_modify_rose_permission,
-        grant_manage_peers_permission,
-        grant_manage_roles_permission,
-        grant_unregister_wonderland_domain,
-        grant_upgrade_executor_permission,
-    ] {
-        builder = builder.append_instruction(isi);
+    if policy != ExecutorPolicy::Strict {
+        for isi in [
+            grant_modify_rose_permission,
+            grant_manage_peers_permission,
+            grant_manage_roles_permission,
+            grant_unregister_wonderland_domain,
+            grant_upgrade_executor_permission,
+        ] {
+            builder = builder.append_instruction(isi);
+        }
     }
 
     for isi in extra_isi {
         builder = builder.append_instruction(isi);
     }
 
+    if policy == ExecutorPolicy::Permissive {
+        builder = builder.append_instruction(Upgrade::new(Executor::new(load_sample_wasm(
+            "executor_permissive",
+        ))));
+    }
+
     let genesis_key_pair = SAMPLE_GENESIS_ACCOUNT_KEYPAIR.clone();
 
     builder
+        .set_chain(chain)
         .set_topology(topology.into())
         .build_and_sign(&genesis_key_pair)
         .unwrap_or_else(|err| {
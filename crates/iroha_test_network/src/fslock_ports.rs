@@ -1,13 +1,15 @@
 //! [`fslock`]-based socket ports locking for test network peers,
 //! supporting inter-process and intra-process test execution scenarios.
 //!
+//! Each port is registered along with the process which allocated it, so that ports
+//! left behind by test processes which didn't exit normally are reclaimed.
+//!
 //! Known issues:
 //! - `.lock` file persists and is not deleted
 //! - locking and recreating JSON file for each port each time is suboptimal, could be optimised
-//! - _sometimes_ locked ports aren't cleaned after test execution (_usually_ on failures)
 
 use std::{
-    collections::BTreeSet,
+    collections::BTreeMap,
     fs::OpenOptions,
     io::{Read, Write},
 };
@@ -17,6 +19,7 @@ use color_eyre::{
     Result,
 };
 use derive_more::{Deref, Display};
+use nix::{errno::Errno, sys::signal, unistd::Pid};
 use serde::{Deserialize, Serialize};
 
 const DATA_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/.iroha_test_network_run.json");
@@ -27,10 +30,17 @@ const LOCK_FILE: &str = concat!(
 
 #[derive(Serialize, Deserialize, Default)]
 struct LockContent {
-    ports_in_use: BTreeSet<u16>,
+    /// Ports in use along with the ids of processes that allocated them
+    #[serde(default)]
+    ports: BTreeMap<u16, u32>,
 }
 
 impl LockContent {
+    /// Release ports of processes which are no longer running
+    fn release_stale(&mut self) {
+        self.ports.retain(|_, pid| is_alive(*pid));
+    }
+
     fn read() -> Result<Self> {
         let value = if std::fs::exists(DATA_FILE)? {
             OpenOptions::new()
@@ -60,7 +70,7 @@ impl LockContent {
         if std::fs::exists(DATA_FILE)? {
             std::fs::remove_file(DATA_FILE)?;
         }
-        if self.ports_in_use.is_empty() {
+        if self.ports.is_empty() {
             return Ok(());
         };
         let mut file = OpenOptions::new()
@@ -84,18 +94,19 @@ impl AllocatedPort {
         lock.lock().expect("this handle doesn't own the file yet");
 
         let mut value = LockContent::read().expect("should be able to read the data");
+        value.release_stale();
 
         let mut i = 0;
         let port = loop {
             let port = unique_port::get_unique_free_port().unwrap();
-            if !value.ports_in_use.contains(&port) {
+            if !value.ports.contains_key(&port) {
                 break port;
             }
             i += 1;
             assert!(i < 1000, "cannot find a free port");
         };
 
-        value.ports_in_use.insert(port);
+        value.ports.insert(port, std::process::id());
 
         value.write().expect("should be able to write the data");
         lock.unlock().expect("this handle still holds the lock");
@@ -109,8 +120,16 @@ impl Drop for AllocatedPort {
         let mut lock = fslock::LockFile::open(LOCK_FILE).expect("path is valid");
         lock.lock().expect("doesn't hold it yet");
         let mut value = LockContent::read().expect("should read fine");
-        value.ports_in_use.remove(&self.0);
+        value.ports.remove(&self.0);
         value.write().expect("should save the result filne");
         lock.unlock().expect("still holds it");
     }
 }
+
+fn is_alive(pid: u32) -> bool {
+    let Ok(pid) = i32::try_from(pid) else {
+        return false;
+    };
+    // Sending no signal only checks whether the process exists
+    !matches!(signal::kill(Pid::from_raw(pid), None), Err(Errno::ESRCH))
+}
@@ -6,6 +6,7 @@ pub mod fslock_ports;
 use core::{fmt::Debug, time::Duration};
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     iter,
     num::NonZero,
     ops::Deref,
@@ -13,16 +14,21 @@ use std::{
     process::{ExitStatus, Stdio},
     sync::{
         atomic::{AtomicBool, AtomicUsize, Ordering},
-        Arc, OnceLock,
+        Arc, OnceLock, RwLock,
     },
 };
 
 use backoff::ExponentialBackoffBuilder;
 use color_eyre::eyre::{eyre, Context, Result};
-pub use config::chain_id;
 use fslock_ports::AllocatedPort;
-use futures::{prelude::*, stream::FuturesUnordered};
-use iroha::{client::Client, data_model::prelude::*};
+use futures::{
+    prelude::*,
+    stream::{self, FuturesUnordered},
+};
+use iroha::{
+    client::{Client, DrainPhase},
+    data_model::prelude::*,
+};
 use iroha_config::base::{
     read::ConfigReader,
     toml::{TomlSource, WriteExt as _, Writer as TomlWriter},
@@ -31,7 +37,7 @@ use iroha_crypto::{Algorithm, ExposedPrivateKey, KeyPair, PrivateKey};
 use iroha_data_model::{
     isi::InstructionBox,
     parameter::{SmartContractParameter, SumeragiParameter, SumeragiParameters},
-    ChainId,
+    ChainId, Level,
 };
 use iroha_genesis::GenesisBlock;
 use iroha_primitives::{
@@ -43,6 +49,7 @@ use iroha_test_samples::{ALICE_ID, ALICE_KEYPAIR, PEER_KEYPAIR, SAMPLE_GENESIS_A
 use nonzero_ext::nonzero;
 use parity_scale_codec::Encode;
 use rand::{prelude::IteratorRandom, thread_rng};
+use regex::Regex;
 use tokio::{
     fs::File,
     io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
@@ -125,6 +132,9 @@ fn generate_and_keep_temp_dir() -> PathBuf {
 pub struct Environment {
     /// Working directory
     dir: PathBuf,
+    /// Chain id, unique for each environment so that concurrently running networks
+    /// never accept each other's transactions or blocks
+    chain: ChainId,
 }
 
 /// Programs to work with
@@ -181,7 +191,14 @@ impl Environment {
     fn new() -> Self {
         init_logger_once();
         let dir = generate_and_keep_temp_dir();
-        Self { dir }
+        // Temporary directory names are unique, which also links the chain to its artifacts
+        let chain = ChainId::from(
+            dir.file_name()
+                .expect("temporary directory has a name")
+                .to_string_lossy()
+                .into_owned(),
+        );
+        Self { dir, chain }
     }
 }
 
@@ -194,6 +211,8 @@ pub struct Network {
     commit_time: Duration,
 
     genesis_isi: Vec<InstructionBox>,
+    executor_policy: ExecutorPolicy,
+    initial_leader: Option<usize>,
     config_layers: Vec<Table>,
 }
 
@@ -256,6 +275,63 @@ impl Network {
         self
     }
 
+    /// Start all peers without genesis, waiting until their servers are up.
+    ///
+    /// Peers stay before genesis, with their API available, until the genesis is submitted
+    /// with [`Self::submit_genesis`] or [`Self::resume`].
+    ///
+    /// # Panics
+    /// - If some peer was already started
+    /// - If some peer exits early
+    pub async fn start_all_paused(&self) -> &Self {
+        timeout(
+            PEER_START_TIMEOUT,
+            self.peers
+                .iter()
+                .map(|peer| async move {
+                    peer.start_checked(self.config_layers(), None)
+                        .await
+                        .expect("peer failed to start");
+                })
+                .collect::<FuturesUnordered<_>>()
+                .collect::<Vec<_>>(),
+        )
+        .await
+        .expect("expected peers to start within timeout");
+        self
+    }
+
+    /// Submit genesis to a network started with [`Self::start_all_paused`],
+    /// without waiting for it to be committed.
+    ///
+    /// The first peer is restarted with the genesis, and the rest receive it from that peer.
+    ///
+    /// # Panics
+    /// If the first peer is not running.
+    pub async fn submit_genesis(&self) -> &Self {
+        let peer = &self.peers[0];
+        peer.shutdown().await;
+        peer.start(self.config_layers(), Some(&self.genesis()))
+            .await;
+        self
+    }
+
+    /// Submit genesis to a network started with [`Self::start_all_paused`] and wait until
+    /// all peers have committed it.
+    ///
+    /// # Errors
+    /// If the genesis isn't committed within [`Self::peer_startup_timeout`].
+    pub async fn resume(&self) -> Result<&Self> {
+        self.submit_genesis().await;
+        timeout(
+            self.peer_startup_timeout(),
+            once_blocks_sync(self.peers.iter(), BlockHeight::predicate_non_empty(1)),
+        )
+        .await
+        .wrap_err("Peers didn't commit genesis within timeout")??;
+        Ok(self)
+    }
+
     /// Pipeline time of the network.
     ///
     /// Is relevant only if users haven't submitted [`SumeragiParameter`] changing it.
@@ -277,9 +353,9 @@ impl Network {
         self.peer().client()
     }
 
-    /// Chain ID of the network
+    /// Chain ID of the network, unique for each network
     pub fn chain_id(&self) -> ChainId {
-        config::chain_id()
+        self.env.chain.clone()
     }
 
     /// Base configuration of all peers.
@@ -297,14 +373,113 @@ impl Network {
     /// Network genesis block.
     ///
     /// It uses the basic [`genesis_factory`] with [`Self::genesis_isi`] +
-    /// topology of the network peers.
+    /// topology of the network peers, set up according to [`Self::executor_policy`].
     pub fn genesis(&self) -> GenesisBlock {
-        genesis_factory(
+        config::genesis_with_policy(
+            self.chain_id(),
             self.genesis_isi.clone(),
-            self.peers.iter().map(NetworkPeer::id).collect(),
+            self.topology(),
+            self.executor_policy,
         )
     }
 
+    /// Initial topology, set in genesis.
+    ///
+    /// Peers follow the order of [`Self::peers`], unless the initial leader is pinned with
+    /// [`NetworkBuilder::with_initial_leader`].
+    fn topology(&self) -> UniqueVec<PeerId> {
+        let mut peers: Vec<_> = self.peers.iter().map(NetworkPeer::id).collect();
+        if let Some(leader) = self.initial_leader {
+            let leader = peers.remove(leader);
+            // Genesis commit rotates the first peer of the set A to its end,
+            // so the leader of the next round goes second
+            peers.insert(1.min(peers.len()), leader);
+        }
+        peers.into_iter().collect()
+    }
+
+    /// Peer leading the current round, as reported by a running peer
+    ///
+    /// # Errors
+    /// If no running peer reports a leader from this network.
+    pub async fn leader(&self) -> Result<&NetworkPeer> {
+        for peer in self.peers.iter().filter(|peer| peer.is_running()) {
+            let Ok(Status {
+                leader: Some(leader),
+                ..
+            }) = peer.status().await
+            else {
+                continue;
+            };
+            if let Some(leader) = self
+                .peers
+                .iter()
+                .find(|peer| peer.id().public_key() == &leader)
+            {
+                return Ok(leader);
+            }
+        }
+
+        Err(eyre!("No running peer knows the leader"))
+    }
+
+    /// Force a view change by suspending the current leader until the rest of the network
+    /// moves on to another one.
+    ///
+    /// A transaction is submitted to another peer, so that the leader is expected to create
+    /// a block. The suspended leader is resumed afterwards.
+    ///
+    /// # Errors
+    /// - If the leader is unknown
+    /// - If the view change doesn't happen within [`Self::sync_timeout`] plus the time
+    ///   the network has to create a block
+    pub async fn force_view_change(&self) -> Result<&Self> {
+        let leader = self.leader().await?;
+        let others: Vec<_> = self
+            .peers
+            .iter()
+            .filter(|peer| *peer != leader && peer.is_running())
+            .collect();
+        let peer = others
+            .first()
+            .ok_or_else(|| eyre!("View change needs more than one running peer"))?;
+
+        leader.suspend().await?;
+        let result = async {
+            let client = peer.client();
+            spawn_blocking(move || {
+                client.submit(Log::new(Level::INFO, "Forcing view change".to_owned()))
+            })
+            .await??;
+
+            // The suspended leader can't be asked
+            timeout(self.pipeline_time() * 2 + self.sync_timeout(), async {
+                loop {
+                    match peer.status().await {
+                        Ok(Status {
+                            leader: Some(current),
+                            ..
+                        }) if current != *leader.id().public_key() => break,
+                        _ => tokio::time::sleep(self.block_time).await,
+                    }
+                }
+            })
+            .await
+            .wrap_err("View change didn't happen within timeout")
+        }
+        .await;
+        leader.resume().await?;
+        result?;
+
+        info!(leader = leader.mnemonic(), "forced view change");
+        Ok(self)
+    }
+
+    /// Permission model the network was set up with
+    pub fn executor_policy(&self) -> ExecutorPolicy {
+        self.executor_policy
+    }
+
     /// Genesis block instructions
     pub fn genesis_isi(&self) -> &Vec<InstructionBox> {
         &self.genesis_isi
@@ -352,6 +527,49 @@ impl Network {
 
         Ok(self)
     }
+
+    /// Restart the peer with another `irohad` binary, e.g. to test rolling upgrades.
+    ///
+    /// Resolves when the restarted peer catches up with the height the rest of the network
+    /// had before the restart. The peer is started without genesis.
+    ///
+    /// # Errors
+    /// If the peer fails to start or doesn't catch up within [`Self::sync_timeout`].
+    ///
+    /// # Panics
+    /// If the peer is not running.
+    pub async fn upgrade_peer(
+        &self,
+        peer: &NetworkPeer,
+        irohad: impl Into<PathBuf>,
+    ) -> Result<&Self> {
+        let height = self
+            .peers
+            .iter()
+            .filter_map(|x| *x.blocks().borrow())
+            .map(|height| height.total)
+            .max()
+            .unwrap_or_default();
+
+        peer.shutdown().await;
+        peer.set_irohad(irohad);
+        timeout(
+            self.peer_startup_timeout(),
+            peer.start_checked(self.config_layers(), None),
+        )
+        .await
+        .wrap_err("Upgraded peer didn't start within timeout")??;
+        timeout(
+            self.sync_timeout(),
+            peer.once_block_with(|x| x.total >= height),
+        )
+        .await
+        .wrap_err_with(|| eyre!("Upgraded peer didn't catch up with height={height}"))?;
+
+        info!(peer = peer.mnemonic(), %height, "peer upgraded");
+
+        Ok(self)
+    }
 }
 
 /// Determines how [`NetworkBuilder`] configures [`SmartContractParameter::Fuel`] in the genesis.
@@ -370,6 +588,55 @@ pub enum WasmFuelConfig {
     Auto,
 }
 
+/// Determines which permission model [`NetworkBuilder`] sets up in the genesis.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutorPolicy {
+    /// Default executor, with Alice granted a few extra permissions over `wonderland`
+    #[default]
+    Default,
+    /// Any account may execute any instruction
+    Permissive,
+    /// Default executor, with no permissions granted beyond those in the default genesis
+    Strict,
+}
+
+/// IP protocol stack a peer binds its P2P and API listeners to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IpStack {
+    /// Bind to and advertise `127.0.0.1`
+    #[default]
+    V4,
+    /// Bind to and advertise `[::1]`
+    V6,
+    /// Bind to `[::]`, accepting both IPv4 and IPv6 connections, and advertise `127.0.0.1`.
+    /// Clients connect to the peer over IPv6.
+    DualStack,
+}
+
+impl IpStack {
+    fn bind_address(self, port: u16) -> SocketAddr {
+        match self {
+            Self::V4 => socket_addr!(127.0.0.1:port),
+            Self::V6 => socket_addr!([::1]:port),
+            Self::DualStack => socket_addr!([::]:port),
+        }
+    }
+
+    fn public_address(self, port: u16) -> SocketAddr {
+        match self {
+            Self::V4 | Self::DualStack => socket_addr!(127.0.0.1:port),
+            Self::V6 => socket_addr!([::1]:port),
+        }
+    }
+
+    fn client_address(self, port: u16) -> SocketAddr {
+        match self {
+            Self::V4 => socket_addr!(127.0.0.1:port),
+            Self::V6 | Self::DualStack => socket_addr!([::1]:port),
+        }
+    }
+}
+
 /// Builder of [`Network`]
 pub struct NetworkBuilder {
     env: Environment,
@@ -379,6 +646,10 @@ pub struct NetworkBuilder {
     wasm_fuel: WasmFuelConfig,
     genesis_isi: Vec<InstructionBox>,
     seed: Option<String>,
+    irohad: BTreeMap<usize, PathBuf>,
+    ip_stacks: BTreeMap<usize, IpStack>,
+    executor_policy: ExecutorPolicy,
+    initial_leader: Option<usize>,
 }
 
 impl Default for NetworkBuilder {
@@ -399,9 +670,25 @@ impl NetworkBuilder {
             wasm_fuel: WasmFuelConfig::default(),
             genesis_isi: vec![],
             seed: None,
+            irohad: BTreeMap::new(),
+            ip_stacks: BTreeMap::new(),
+            executor_policy: ExecutorPolicy::default(),
+            initial_leader: None,
         }
     }
 
+    /// Network where any account may execute any instruction, for scenarios
+    /// unrelated to permissions. See [`ExecutorPolicy::Permissive`].
+    pub fn permissive() -> Self {
+        Self::new().with_executor_policy(ExecutorPolicy::Permissive)
+    }
+
+    /// Network where accounts have only the permissions of the default genesis.
+    /// See [`ExecutorPolicy::Strict`].
+    pub fn strict() -> Self {
+        Self::new().with_executor_policy(ExecutorPolicy::Strict)
+    }
+
     /// Set the number of peers in the network.
     ///
     /// One by default.
@@ -469,6 +756,40 @@ impl NetworkBuilder {
         self
     }
 
+    /// Run the peer with the given index using another `irohad` binary,
+    /// e.g. one of a previous version.
+    ///
+    /// Relative paths are resolved from the repository root. By default, peers use
+    /// the binary resolved by [`Program::Irohad`].
+    pub fn with_peer_irohad(mut self, peer: usize, irohad: impl Into<PathBuf>) -> Self {
+        self.irohad.insert(peer, irohad.into());
+        self
+    }
+
+    /// Make the peer with the given index listen on the given [`IpStack`].
+    ///
+    /// [`IpStack::V4`] by default.
+    pub fn with_peer_ip_stack(mut self, peer: usize, ip_stack: IpStack) -> Self {
+        self.ip_stacks.insert(peer, ip_stack);
+        self
+    }
+
+    /// Make the peer with the given index lead the first round after genesis.
+    ///
+    /// By default, the leader depends on the randomly generated peer keys.
+    pub fn with_initial_leader(mut self, peer: usize) -> Self {
+        self.initial_leader = Some(peer);
+        self
+    }
+
+    /// Set [`ExecutorPolicy`].
+    ///
+    /// [`ExecutorPolicy::Default`] by default.
+    pub fn with_executor_policy(mut self, policy: ExecutorPolicy) -> Self {
+        self.executor_policy = policy;
+        self
+    }
+
     /// Set [`WasmFuelConfig`].
     ///
     /// [`WasmFuelConfig::Auto`] by default.
@@ -478,12 +799,35 @@ impl NetworkBuilder {
     }
 
     /// Build the [`Network`]. Doesn't start it.
-    pub fn build(self) -> Network {
+    pub fn build(mut self) -> Network {
+        if let Some(leader) = self.initial_leader {
+            assert!(
+                leader < self.n_peers,
+                "initial leader is peer {leader}, but there are only {} peers",
+                self.n_peers
+            );
+        }
+        if let Some(&peer) = self.irohad.keys().next_back() {
+            assert!(
+                peer < self.n_peers,
+                "`irohad` is set for peer {peer}, but there are only {} peers",
+                self.n_peers
+            );
+        }
+        if let Some(&peer) = self.ip_stacks.keys().next_back() {
+            assert!(
+                peer < self.n_peers,
+                "IP stack is set for peer {peer}, but there are only {} peers",
+                self.n_peers
+            );
+        }
         let peers: Vec<_> = (0..self.n_peers)
             .map(|i| {
                 let seed = self.seed.as_ref().map(|x| format!("{x}-peer-{i}"));
                 NetworkPeerBuilder::new()
                     .with_seed(seed.as_ref().map(|x| x.as_bytes()))
+                    .with_irohad(self.irohad.remove(&i))
+                    .with_ip_stack(self.ip_stacks.remove(&i).unwrap_or_default())
                     .build(&self.env)
             })
             .collect();
@@ -537,7 +881,9 @@ impl NetworkBuilder {
             block_time,
             commit_time,
             genesis_isi,
-            config_layers: Some(config::base_iroha_config().write(
+            executor_policy: self.executor_policy,
+            initial_leader: self.initial_leader,
+            config_layers: Some(config::base_iroha_config(&self.env.chain).write(
                 ["network", "block_gossip_period_ms"],
                 block_sync_gossip_period.as_millis() as u64,
             ))
@@ -570,6 +916,15 @@ impl NetworkBuilder {
         Ok(network)
     }
 
+    /// Build the network and start its peers without genesis.
+    ///
+    /// See [`Network::start_all_paused`].
+    pub async fn start_paused(self) -> Result<Network> {
+        let network = self.build();
+        network.start_all_paused().await;
+        Ok(network)
+    }
+
     /// Combination of [`Self::build_blocking`] and [`Self::start`].
     pub fn start_blocking(self) -> Result<(Network, Runtime)> {
         let (network, rt) = self.build_blocking();
@@ -612,6 +967,7 @@ impl Signatory {
 struct PeerRun {
     tasks: JoinSet<()>,
     shutdown: oneshot::Sender<()>,
+    pid: Option<u32>,
 }
 
 /// Lifecycle events of a peer
@@ -627,6 +983,8 @@ pub enum PeerLifecycleEvent {
     Killed,
     /// Caught a related pipeline event
     BlockApplied { height: u64 },
+    /// Peer reported the next phase of draining, see [`NetworkPeer::drain`]
+    Draining { phase: DrainPhase },
 }
 
 /// Controls execution of `irohad` child process.
@@ -642,12 +1000,16 @@ pub struct NetworkPeer {
     mnemonic: String,
     span: tracing::Span,
     key_pair: KeyPair,
+    chain: ChainId,
     dir: PathBuf,
+    irohad: Arc<RwLock<Option<PathBuf>>>,
+    logs: Arc<RwLock<PeerLogs>>,
     run: Arc<Mutex<Option<PeerRun>>>,
     runs_count: Arc<AtomicUsize>,
     is_running: Arc<AtomicBool>,
     events: broadcast::Sender<PeerLifecycleEvent>,
     block_height: watch::Sender<Option<BlockHeight>>,
+    ip_stack: IpStack,
     // dropping these the last
     port_p2p: Arc<AllocatedPort>,
     port_api: Arc<AllocatedPort>,
@@ -687,7 +1049,8 @@ impl NetworkPeer {
             .await
             .expect("fatal failure");
 
-        let mut cmd = tokio::process::Command::new(Program::Irohad.resolve().unwrap());
+        self.logs.write().expect("not poisoned").lines.clear();
+        let mut cmd = tokio::process::Command::new(self.irohad().unwrap());
         cmd.stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .kill_on_drop(true)
@@ -696,6 +1059,7 @@ impl NetworkPeer {
             .arg("--terminal-colors=true");
         cmd.current_dir(&self.dir);
         let mut child = cmd.spawn().expect("spawn failure is abnormal");
+        let pid = child.id();
         self.is_running.store(true, Ordering::Relaxed);
         let _ = self.events.send(PeerLifecycleEvent::Spawned);
 
@@ -706,9 +1070,15 @@ impl NetworkPeer {
             let mut file = File::create(self.dir.join(format!("run-{run_num}-stdout.log")))
                 .await
                 .unwrap();
+            let logs = self.logs.clone();
+            let events = self.events.clone();
             tasks.spawn(async move {
                 let mut lines = BufReader::new(output).lines();
                 while let Ok(Some(line)) = lines.next_line().await {
+                    let phase = parse_drain_phase(logs.write().expect("not poisoned").push(&line));
+                    if let Some(phase) = phase {
+                        let _ = events.send(PeerLifecycleEvent::Draining { phase });
+                    }
                     file.write_all(line.as_bytes())
                         .await
                         .expect("writing logs to file shouldn't fail");
@@ -725,6 +1095,7 @@ impl NetworkPeer {
             let span = span.clone();
             let output = child.stderr.take().unwrap();
             let path = self.dir.join(format!("run-{run_num}-stderr.log"));
+            let logs = self.logs.clone();
             tasks.spawn(async move {
                 let mut in_memory = PeerStderrBuffer {
                     span,
@@ -732,6 +1103,7 @@ impl NetworkPeer {
                 };
                 let mut lines = BufReader::new(output).lines();
                 while let Ok(Some(line)) = lines.next_line().await {
+                    logs.write().expect("not poisoned").push(&line);
                     in_memory.buffer.push_str(&line);
                     in_memory.buffer.push('\n');
                 }
@@ -792,7 +1164,7 @@ impl NetworkPeer {
                     )
                     .await
                     .expect("there is no max elapsed time");
-                    let mut block_height = BlockHeight::from(status);
+                    let mut block_height = BlockHeight::from(status.clone());
                     let _ = events_tx.send(PeerLifecycleEvent::ServerStarted);
                     let _ = block_height_tx.send_replace(Some(block_height));
                     info!(?status, "server started");
@@ -850,6 +1222,7 @@ impl NetworkPeer {
         *run_guard = Some(PeerRun {
             tasks,
             shutdown: shutdown_tx,
+            pid,
         });
     }
 
@@ -871,6 +1244,68 @@ impl NetworkPeer {
         }
     }
 
+    /// Request the peer to drain and wait until it exits by itself.
+    ///
+    /// The phases the peer passes are emitted as [`PeerLifecycleEvent::Draining`].
+    ///
+    /// # Errors
+    /// If the drain request fails or the peer doesn't exit within a timeout.
+    pub async fn drain(&self) -> Result<ExitStatus> {
+        const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+        let mut events = self.events();
+        let client = self.client();
+        spawn_blocking(move || client.drain()).await??;
+        let status = timeout(DRAIN_TIMEOUT, async {
+            loop {
+                if let Ok(PeerLifecycleEvent::Terminated { status }) = events.recv().await {
+                    break status;
+                }
+            }
+        })
+        .await
+        .wrap_err("Peer didn't exit after draining")?;
+        // The process has exited, let the run tasks process the rest of its output
+        if let Some(run) = self.run.lock().await.take() {
+            timeout(PEER_SHUTDOWN_TIMEOUT, run.tasks.join_all())
+                .await
+                .wrap_err("run-related tasks should exit within timeout")?;
+        }
+        Ok(status)
+    }
+
+    /// Suspend the peer process, so that it stops responding without terminating.
+    ///
+    /// # Errors
+    /// If the peer is not running.
+    pub async fn suspend(&self) -> Result<()> {
+        self.signal(nix::sys::signal::Signal::SIGSTOP).await?;
+        self.span.in_scope(|| info!("Suspended"));
+        Ok(())
+    }
+
+    /// Resume the peer process suspended with [`Self::suspend`].
+    ///
+    /// # Errors
+    /// If the peer is not running.
+    pub async fn resume(&self) -> Result<()> {
+        self.signal(nix::sys::signal::Signal::SIGCONT).await?;
+        self.span.in_scope(|| info!("Resumed"));
+        Ok(())
+    }
+
+    async fn signal(&self, signal: nix::sys::signal::Signal) -> Result<()> {
+        let pid = self
+            .run
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|run| run.pid)
+            .ok_or_else(|| eyre!("peer is not running"))?;
+        nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid as i32), signal)
+            .wrap_err_with(|| eyre!("failed to send {signal}"))
+    }
+
     /// Like [`Self::start`], but also ensures that server starts.
     ///
     /// If genesis is given, also ensures that the genesis block is committed.
@@ -904,6 +1339,28 @@ impl NetworkPeer {
         }
     }
 
+    /// Path of the `irohad` binary the peer is started with.
+    ///
+    /// # Errors
+    /// If the path is not found.
+    pub fn irohad(&self) -> Result<PathBuf> {
+        let Some(path) = self.irohad.read().expect("not poisoned").clone() else {
+            return Program::Irohad.resolve();
+        };
+
+        repo_root()
+            .join(&path)
+            .canonicalize()
+            .wrap_err_with(|| eyre!("Could not resolve `irohad` path: {}", path.display()))
+    }
+
+    /// Use another `irohad` binary starting from the next run of the peer.
+    ///
+    /// Relative paths are resolved from the repository root.
+    pub fn set_irohad(&self, irohad: impl Into<PathBuf>) {
+        *self.irohad.write().expect("not poisoned") = Some(irohad.into());
+    }
+
     /// Subscribe on peer lifecycle events.
     pub fn events(&self) -> broadcast::Receiver<PeerLifecycleEvent> {
         self.events.subscribe()
@@ -979,8 +1436,14 @@ impl NetworkPeer {
         PeerId::new(self.key_pair.public_key().clone())
     }
 
+    /// Address the peer advertises to other peers
     pub fn p2p_address(&self) -> SocketAddr {
-        socket_addr!(127.0.0.1:**self.port_p2p)
+        self.ip_stack.public_address(**self.port_p2p)
+    }
+
+    /// [`IpStack`] the peer listens on
+    pub fn ip_stack(&self) -> IpStack {
+        self.ip_stack
     }
 
     /// Check whether the peer is running
@@ -993,7 +1456,7 @@ impl NetworkPeer {
         let config = ConfigReader::new()
             .with_toml_source(TomlSource::inline(
                 Table::new()
-                    .write("chain", config::chain_id())
+                    .write("chain", &self.chain)
                     .write(["account", "domain"], account_id.domain())
                     .write(["account", "public_key"], account_id.signatory())
                     .write(
@@ -1004,7 +1467,10 @@ impl NetworkPeer {
                         ["transaction", "status_timeout_ms"],
                         u64::try_from(CLIENT_TX_STATUS_TIMEOUT.as_millis()).expect("must fit"),
                     )
-                    .write("torii_url", format!("http://127.0.0.1:{}", self.port_api)),
+                    .write(
+                        "torii_url",
+                        format!("http://{}", self.ip_stack.client_address(**self.port_api)),
+                    ),
             ))
             .read_and_complete::<iroha::config::UserConfig>()
             .expect("peer client config should be valid")
@@ -1030,6 +1496,51 @@ impl NetworkPeer {
         self.block_height.subscribe()
     }
 
+    /// Stream of log lines of the current (or the last) peer run, both from `stdout` and
+    /// `stderr`, with terminal colors stripped.
+    ///
+    /// Yields the lines logged so far first, then waits for new ones. The stream doesn't end
+    /// by itself, so use it with a timeout.
+    pub fn logs(&self) -> impl Stream<Item = String> + Send + 'static {
+        let (logged, live) = {
+            let logs = self.logs.read().expect("not poisoned");
+            (logs.lines.clone(), logs.live.subscribe())
+        };
+
+        stream::iter(logged).chain(stream::unfold(live, |mut live| async move {
+            loop {
+                match live.recv().await {
+                    Ok(line) => return Some((line, live)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        }))
+    }
+
+    /// Wait until the peer logs a line matching `pattern`, see [`Self::logs`].
+    ///
+    /// Returns the matching line.
+    ///
+    /// # Panics
+    /// If `pattern` is not a valid regex or no line matches it within `timeout`.
+    pub async fn assert_log_matches(&self, pattern: &str, timeout: Duration) -> String {
+        let regex = Regex::new(pattern).expect("pattern should be a valid regex");
+        let mut matching = std::pin::pin!(self
+            .logs()
+            .filter(move |line| future::ready(regex.is_match(line))));
+
+        tokio::time::timeout(timeout, matching.next())
+            .await
+            .unwrap_or_else(|_| {
+                panic!(
+                    "peer {} didn't log a line matching `{pattern}` within {timeout:?}",
+                    self.mnemonic
+                )
+            })
+            .expect("log stream doesn't end")
+    }
+
     fn write_base_config(&self) {
         let cfg = Table::new()
             .write("public_key", self.key_pair.public_key())
@@ -1037,11 +1548,14 @@ impl NetworkPeer {
                 "private_key",
                 ExposedPrivateKey(self.key_pair.private_key().clone()),
             )
-            .write(["network", "address"], self.p2p_address())
+            .write(
+                ["network", "address"],
+                self.ip_stack.bind_address(**self.port_p2p),
+            )
             .write(["network", "public_address"], self.p2p_address())
             .write(
                 ["torii", "address"],
-                socket_addr!(127.0.0.1:**self.port_api),
+                self.ip_stack.bind_address(**self.port_api),
             );
         std::fs::write(
             self.dir.join("config.base.toml"),
@@ -1094,6 +1608,8 @@ impl PartialEq for NetworkPeer {
 pub struct NetworkPeerBuilder {
     mnemonic: String,
     seed: Option<Vec<u8>>,
+    irohad: Option<PathBuf>,
+    ip_stack: IpStack,
 }
 
 impl NetworkPeerBuilder {
@@ -1102,6 +1618,8 @@ impl NetworkPeerBuilder {
         Self {
             mnemonic: petname::petname(2, "_").unwrap(),
             seed: None,
+            irohad: None,
+            ip_stack: IpStack::default(),
         }
     }
 
@@ -1110,6 +1628,18 @@ impl NetworkPeerBuilder {
         self
     }
 
+    /// Use another `irohad` binary instead of the one resolved by [`Program::Irohad`].
+    pub fn with_irohad(mut self, irohad: Option<PathBuf>) -> Self {
+        self.irohad = irohad;
+        self
+    }
+
+    /// Listen on the given [`IpStack`] instead of [`IpStack::V4`].
+    pub fn with_ip_stack(mut self, ip_stack: IpStack) -> Self {
+        self.ip_stack = ip_stack;
+        self
+    }
+
     pub fn build(self, env: &Environment) -> NetworkPeer {
         let key_pair = self
             .seed
@@ -1130,6 +1660,7 @@ impl NetworkPeerBuilder {
                 dir=%dir.display(),
                 port_p2p=%port_p2p,
                 port_api=%port_api,
+                ip_stack=?self.ip_stack,
                 "Build peer",
             )
         });
@@ -1138,12 +1669,16 @@ impl NetworkPeerBuilder {
             mnemonic: self.mnemonic,
             span,
             key_pair,
+            chain: env.chain.clone(),
             dir,
+            irohad: Arc::new(RwLock::new(self.irohad)),
+            logs: Arc::new(RwLock::new(PeerLogs::new())),
             run: Default::default(),
             runs_count: Default::default(),
             is_running: Default::default(),
             events,
             block_height,
+            ip_stack: self.ip_stack,
             port_p2p: Arc::new(port_p2p),
             port_api: Arc::new(port_api),
         };
@@ -1152,6 +1687,48 @@ impl NetworkPeerBuilder {
     }
 }
 
+/// Log lines of a peer run, see [`NetworkPeer::logs`]
+#[derive(Debug)]
+struct PeerLogs {
+    lines: Vec<String>,
+    live: broadcast::Sender<String>,
+}
+
+impl PeerLogs {
+    fn new() -> Self {
+        let (live, _rx) = broadcast::channel(1024);
+        Self {
+            lines: Vec::new(),
+            live,
+        }
+    }
+
+    /// Store the line with terminal colors stripped and return it
+    fn push(&mut self, line: &str) -> &str {
+        static TERMINAL_COLORS: OnceLock<Regex> = OnceLock::new();
+        let line = TERMINAL_COLORS
+            .get_or_init(|| Regex::new("\x1b\\[[0-9;]*m").expect("valid regex"))
+            .replace_all(line, "")
+            .into_owned();
+        let _ = self.live.send(line.clone());
+        self.lines.push(line);
+        self.lines.last().expect("just pushed")
+    }
+}
+
+/// Parse the drain phase out of a `Drain phase: {phase}` log line
+fn parse_drain_phase(line: &str) -> Option<DrainPhase> {
+    static DRAIN_PHASE: OnceLock<Regex> = OnceLock::new();
+    let phase = DRAIN_PHASE
+        .get_or_init(|| Regex::new(r"Drain phase: (\w+)").expect("valid regex"))
+        .captures(line)?
+        .get(1)?
+        .as_str();
+    DrainPhase::ALL
+        .into_iter()
+        .find(|candidate| candidate.to_string() == phase)
+}
+
 /// Prints collected STDERR on drop.
 ///
 /// Used to avoid loss of useful data in case of task abortion before it is printed directly.
@@ -1202,11 +1779,10 @@ impl PeerExit {
             .store(true, Ordering::Relaxed);
 
         self.span.in_scope(|| info!("sending SIGTERM"));
-        signal::kill(
-            Pid::from_raw(self.child.id().ok_or(eyre!("race condition"))? as i32),
-            signal::Signal::SIGTERM,
-        )
-        .wrap_err("failed to send SIGTERM")?;
+        let pid = Pid::from_raw(self.child.id().ok_or(eyre!("race condition"))? as i32);
+        // The peer might be suspended
+        signal::kill(pid, signal::Signal::SIGCONT).wrap_err("failed to send SIGCONT")?;
+        signal::kill(pid, signal::Signal::SIGTERM).wrap_err("failed to send SIGTERM")?;
 
         if let Ok(status) = timeout(TIMEOUT, self.child.wait()).await {
             self.span.in_scope(|| info!("exited gracefully"));
@@ -0,0 +1,204 @@
+//! Golden-file checks for the wire format of data model types.
+//!
+//! Every checked value is encoded with SCALE and JSON and compared against `<name>.scale`
+//! and `<name>.json` files in a committed directory. A missing file or any difference fails
+//! the check. Set [`GoldenFiles::UPDATE_ENV`] to write the files for a new check.
+//!
+//! Existing golden files are never rewritten, so any change to an encoding they cover fails.
+//! Versioned types are checked against the files of their version, e.g.
+//! `signed_transaction_v1.scale`, so a format change comes with a new version written to new
+//! files, while the files of the earlier versions stay and can still be decoded by SDKs.
+//!
+//! The files in [`GoldenFiles::workspace`] are also meant to be consumed by SDKs in other
+//! languages, which can decode them to verify compatibility with this version of Iroha.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use iroha_version::scale::{DecodeVersioned, EncodeVersioned};
+use parity_scale_codec::{DecodeAll, Encode};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// Directory with golden files for data model types.
+#[derive(Debug, Clone)]
+pub struct GoldenFiles {
+    dir: PathBuf,
+    update: bool,
+}
+
+impl GoldenFiles {
+    /// Environment variable which, when set, makes checks write missing golden files instead of
+    /// failing.
+    pub const UPDATE_ENV: &'static str = "IROHA_GOLDEN_UPDATE";
+
+    /// Golden files stored in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            update: env::var_os(Self::UPDATE_ENV).is_some(),
+        }
+    }
+
+    /// Golden files committed to the Iroha repository, next to the schema reference.
+    pub fn workspace() -> Self {
+        Self::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("../../docs/source/references/golden"))
+    }
+
+    /// Write missing golden files instead of failing.
+    #[must_use]
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Directory the golden files are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Check the SCALE and JSON encodings of `value` against the `name` golden files.
+    ///
+    /// Golden files are also decoded and re-encoded, so that the check fails when an
+    /// old encoding can no longer be read.
+    ///
+    /// # Panics
+    ///
+    /// If a golden file is missing, an encoding differs from its golden file or the golden file
+    /// can't be decoded.
+    pub fn check<T: Encode + DecodeAll + Serialize + DeserializeOwned>(
+        &self,
+        name: &str,
+        value: &T,
+    ) {
+        self.check_scale(name, &value.encode(), |mut bytes| {
+            T::decode_all(&mut bytes)
+                .map(|decoded| decoded.encode())
+                .map_err(|err| err.to_string())
+        });
+        self.check_json(name, value, |json| {
+            serde_json::from_value::<T>(json).map_err(|err| err.to_string())
+        });
+    }
+
+    /// Same as [`Self::check`], but for types that are sent over the wire with a version
+    /// prefix. The golden files of `name` are suffixed with the version of `value`.
+    ///
+    /// # Panics
+    ///
+    /// If a golden file is missing, an encoding differs from its golden file or the golden file
+    /// can't be decoded.
+    pub fn check_versioned<T>(&self, name: &str, value: &T)
+    where
+        T: EncodeVersioned + DecodeVersioned + Serialize + DeserializeOwned,
+    {
+        let name = &format!("{name}_v{}", value.version());
+        self.check_scale(name, &value.encode_versioned(), |bytes| {
+            T::decode_all_versioned(bytes)
+                .map(|decoded| decoded.encode_versioned())
+                .map_err(|err| err.to_string())
+        });
+        self.check_json(name, value, |json| {
+            serde_json::from_value::<T>(json).map_err(|err| err.to_string())
+        });
+    }
+
+    fn check_scale(
+        &self,
+        name: &str,
+        actual: &[u8],
+        reencode: impl FnOnce(&[u8]) -> Result<Vec<u8>, String>,
+    ) {
+        let path = self.dir.join(format!("{name}.scale"));
+        let Some(expected) = self.read_or_write(&path, actual) else {
+            return;
+        };
+
+        assert!(
+            expected == actual,
+            "SCALE encoding of `{name}` changed\n  golden: {}\n  actual: {}\n{FROZEN_HINT}",
+            to_hex(&expected),
+            to_hex(actual),
+        );
+        match reencode(&expected) {
+            Ok(reencoded) => assert!(
+                reencoded == expected,
+                "SCALE golden file `{}` doesn't round-trip\n  golden: {}\n  actual: {}",
+                path.display(),
+                to_hex(&expected),
+                to_hex(&reencoded),
+            ),
+            Err(err) => panic!("failed to decode `{}`: {err}", path.display()),
+        }
+    }
+
+    fn check_json<T: Serialize>(
+        &self,
+        name: &str,
+        value: &T,
+        decode: impl FnOnce(Value) -> Result<T, String>,
+    ) {
+        let path = self.dir.join(format!("{name}.json"));
+        let actual = serde_json::to_value(value).expect("data model types serialize to JSON");
+        let mut pretty =
+            serde_json::to_string_pretty(&actual).expect("JSON value serializes to string");
+        pretty.push('\n');
+        let Some(expected) = self.read_or_write(&path, pretty.as_bytes()) else {
+            return;
+        };
+        let expected: Value = serde_json::from_slice(&expected)
+            .unwrap_or_else(|err| panic!("`{}` is not valid JSON: {err}", path.display()));
+
+        assert!(
+            expected == actual,
+            "JSON encoding of `{name}` changed\n  golden: {expected}\n  actual: {actual}\n{FROZEN_HINT}",
+        );
+        match decode(expected.clone()) {
+            Ok(decoded) => {
+                let reencoded =
+                    serde_json::to_value(decoded).expect("data model types serialize to JSON");
+                assert!(
+                    reencoded == expected,
+                    "JSON golden file `{}` doesn't round-trip\n  golden: {expected}\n  actual: {reencoded}",
+                    path.display(),
+                );
+            }
+            Err(err) => panic!("failed to deserialize `{}`: {err}", path.display()),
+        }
+    }
+
+    /// Read the golden file at `path`, or write it with `actual` and return `None` if it's
+    /// missing and golden files are updated.
+    ///
+    /// # Panics
+    ///
+    /// If the golden file can't be read, e.g. because it wasn't committed.
+    fn read_or_write(&self, path: &Path, actual: &[u8]) -> Option<Vec<u8>> {
+        match fs::read(path) {
+            Ok(expected) => Some(expected),
+            Err(err) if self.update && err.kind() == io::ErrorKind::NotFound => {
+                fs::create_dir_all(&self.dir).unwrap_or_else(|err| {
+                    panic!("failed to create `{}`: {err}", self.dir.display())
+                });
+                fs::write(path, actual)
+                    .unwrap_or_else(|err| panic!("failed to write `{}`: {err}", path.display()));
+                None
+            }
+            Err(err) => panic!(
+                "failed to read golden file `{0}`: {err}\n\
+                 If the check is new, rerun with `{1}=1` and commit the written `{0}`",
+                path.display(),
+                Self::UPDATE_ENV,
+            ),
+        }
+    }
+}
+
+const FROZEN_HINT: &str = "Golden files are never rewritten, as SDKs rely on them: \
+     keep the encoding or introduce a new version of the type";
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -1,5 +1,7 @@
 //! Utility crate for standardized and random signatories.
 
+pub mod golden;
+
 use std::{
     fs,
     io::Read,
@@ -1,6 +1,10 @@
 use std::{num::NonZeroU64, sync::Arc};
 
-use iroha_core::kura::Kura;
+use iroha_core::{
+    kura::Kura,
+    redaction::redacted_block,
+    state::{State, StateReadOnly},
+};
 use iroha_data_model::block::{
     stream::{BlockMessage, BlockSubscriptionRequest},
     SignedBlock,
@@ -32,6 +36,7 @@ pub struct Consumer<'ws> {
     pub stream: &'ws mut WebSocketScale,
     height: NonZeroU64,
     kura: Arc<Kura>,
+    state: Arc<State>,
 }
 
 impl<'ws> Consumer<'ws> {
@@ -40,12 +45,17 @@ impl<'ws> Consumer<'ws> {
     /// # Errors
     /// Can fail due to timeout or without message at websocket or during decoding request
     #[iroha_futures::telemetry_future]
-    pub async fn new(stream: &'ws mut WebSocketScale, kura: Arc<Kura>) -> Result<Self> {
+    pub async fn new(
+        stream: &'ws mut WebSocketScale,
+        kura: Arc<Kura>,
+        state: Arc<State>,
+    ) -> Result<Self> {
         let BlockSubscriptionRequest(height) = stream.recv().await?;
         Ok(Consumer {
             stream,
             height,
             kura,
+            state,
         })
     }
 
@@ -60,6 +70,7 @@ impl<'ws> Consumer<'ws> {
                 .try_into()
                 .expect("INTERNAL BUG: Number of blocks exceeds usize::MAX"),
         ) {
+            let block = redacted_block(block, &self.state.view());
             // TODO: to avoid clone `BlockMessage` could be split into sending and receiving parts
             self.stream
                 .send(BlockMessage(SignedBlock::clone(&block)))
@@ -0,0 +1,30 @@
+//! Serving connections accepted by the listeners `axum::serve` doesn't support.
+
+use hyper::server::conn::http1;
+use hyper_util::{rt::TokioIo, service::TowerToHyperService};
+use iroha_futures::supervisor::ShutdownSignal;
+use tokio::io::{AsyncRead, AsyncWrite};
+
+/// Serve HTTP/1 requests on the connection until it is closed or the shutdown signal is
+/// received, letting the requests in progress complete.
+pub(crate) async fn serve_connection<I>(
+    io: I,
+    router: axum::Router,
+    shutdown_signal: ShutdownSignal,
+) -> Result<(), hyper::Error>
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let connection = http1::Builder::new()
+        .serve_connection(TokioIo::new(io), TowerToHyperService::new(router))
+        .with_upgrades();
+    tokio::pin!(connection);
+
+    tokio::select! {
+        result = connection.as_mut() => result,
+        () = shutdown_signal.receive() => {
+            connection.as_mut().graceful_shutdown();
+            connection.await
+        }
+    }
+}
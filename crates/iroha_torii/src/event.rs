@@ -2,9 +2,15 @@
 //! This module contains descriptions of such an events and
 //! utility Iroha Special Instructions to work with them.
 
-use iroha_data_model::events::prelude::*;
+use std::{collections::HashMap, sync::Arc};
 
-use crate::stream::{self, WebSocketScale};
+use iroha_core::state::{State, StateReadOnly, WorldReadOnly};
+use iroha_data_model::{account::AccountId, events::prelude::*, ChainId};
+
+use crate::{
+    query_replay::{QueryReplayGuard, Rejection},
+    stream::{self, WebSocketScale},
+};
 
 /// Type of error for `Consumer`
 #[derive(thiserror::Error, Debug)]
@@ -12,6 +18,12 @@ pub enum Error {
     /// Error from provided stream/websocket
     #[error("Stream error: {0}")]
     Stream(Box<stream::Error>),
+    /// Subscription request is not signed by the subscriber
+    #[error("Failed to authenticate the subscriber: {0}")]
+    Authentication(#[from] iroha_crypto::Error),
+    /// Subscription request is expired or replayed
+    #[error("Subscription request is rejected: {0}")]
+    Rejected(#[from] Rejection),
 }
 
 impl From<stream::Error> for Error {
@@ -29,17 +41,48 @@ pub type Result<T> = core::result::Result<T, Error>;
 pub struct Consumer<'ws> {
     pub stream: &'ws mut WebSocketScale,
     filters: Vec<EventFilterBox>,
+    /// Authenticated subscriber, [`None`] if anonymous
+    subscriber: Option<AccountId>,
+    /// State to check subscriber permissions against if events about accounts are restricted
+    restricted_by: Option<Arc<State>>,
+    /// Whether the executor permits events about an account, as of the given block height
+    verdicts: HashMap<AccountId, (usize, bool)>,
 }
 
 impl<'ws> Consumer<'ws> {
     /// Constructs [`Consumer`], which consumes `Event`s and forwards it through the `stream`.
     ///
+    /// If `restricted_by` is set, events about an account are only forwarded to the account
+    /// itself and to subscribers the executor permits, which by default are the ones with
+    /// `CanSubscribeToAccountEvents` for it. Executors predating
+    /// the `validate_event_subscription()` entrypoint deny them all.
+    ///
     /// # Errors
-    /// Can fail due to timeout or without message at websocket or during decoding request
+    /// - Can fail due to timeout or without message at websocket or during decoding request
+    /// - If the request is not signed by the subscriber for `chain_id`
+    /// - If the request has expired or has already been accepted
     #[iroha_futures::telemetry_future]
-    pub async fn new(stream: &'ws mut WebSocketScale) -> Result<Self> {
-        let EventSubscriptionRequest(filters) = stream.recv::<EventSubscriptionRequest>().await?;
-        Ok(Consumer { stream, filters })
+    pub async fn new(
+        stream: &'ws mut WebSocketScale,
+        chain_id: &ChainId,
+        replay_guard: &QueryReplayGuard,
+        restricted_by: Option<Arc<State>>,
+    ) -> Result<Self> {
+        let request = stream.recv::<EventSubscriptionRequest>().await?;
+        let subscriber = match request.authenticate(chain_id)? {
+            Some(subscriber) => {
+                replay_guard.check_subscription(subscriber)?;
+                Some(subscriber.account.clone())
+            }
+            None => None,
+        };
+        Ok(Consumer {
+            stream,
+            filters: request.filters().to_vec(),
+            subscriber,
+            restricted_by,
+            verdicts: HashMap::new(),
+        })
     }
 
     /// Forwards the `event` over the `stream` if it matches the `filter`.
@@ -48,7 +91,7 @@ impl<'ws> Consumer<'ws> {
     /// Can fail due to timeout or sending event. Also receiving might fail
     #[iroha_futures::telemetry_future]
     pub async fn consume(&mut self, event: EventBox) -> Result<()> {
-        if !self.filters.iter().any(|filter| filter.matches(&event)) {
+        if !self.filters.iter().any(|filter| filter.matches(&event)) || !self.is_permitted(&event) {
             return Ok(());
         }
 
@@ -58,3 +101,45 @@ impl<'ws> Consumer<'ws> {
             .map_err(Into::into)
     }
 }
+
+impl Consumer<'_> {
+    /// Whether the subscriber may receive `event`.
+    ///
+    /// The executor is asked again after every block, so that permissions revoked during
+    /// the subscription take effect as soon as they are committed.
+    fn is_permitted(&mut self, event: &EventBox) -> bool {
+        let Some(state) = &self.restricted_by else {
+            return true;
+        };
+        let EventBox::Data(DataEvent::Domain(DomainEvent::Account(event))) = event else {
+            return true;
+        };
+        let Some(subscriber) = &self.subscriber else {
+            return false;
+        };
+
+        let account = event.origin();
+        if subscriber == account {
+            return true;
+        }
+
+        let state_view = state.view();
+        let height = state_view.height();
+        if let Some(&(checked_at, permitted)) = self.verdicts.get(account) {
+            if checked_at == height {
+                return permitted;
+            }
+        }
+        let verdict = state_view.world().executor().validate_event_subscription(
+            &state_view,
+            subscriber,
+            account,
+        );
+        if let Err(error) = &verdict {
+            iroha_logger::debug!(%subscriber, %account, ?error, "Withholding account events");
+        }
+        let permitted = verdict.is_ok();
+        self.verdicts.insert(account.clone(), (height, permitted));
+        permitted
+    }
+}
@@ -17,7 +17,7 @@ use axum::{
 use error_stack::ResultExt;
 use iroha_config::{
     base::{util::Bytes, WithOrigin},
-    parameters::actual::Torii as Config,
+    parameters::actual::{Torii as Config, ToriiTls, ToriiUnixSocket},
 };
 #[cfg(feature = "telemetry")]
 use iroha_core::telemetry::Telemetry;
@@ -27,26 +27,38 @@ use iroha_core::{
     prelude::*,
     query::store::LiveQueryStoreHandle,
     queue::{self, Queue},
-    state::State,
+    state::{dry_run::Staging, State},
     EventsSender,
 };
 use iroha_data_model::{peer::Peer, ChainId};
 use iroha_futures::supervisor::ShutdownSignal;
+use iroha_p2p::PeersTrafficReceiver;
 use iroha_primitives::addr::SocketAddr;
-use iroha_torii_shared::uri;
+use iroha_torii_shared::{uri, DrainPhase, StartupReport};
+use query_pool::QueryPool;
+use query_replay::QueryReplayGuard;
 use tokio::{net::TcpListener, sync::watch};
 use tower_http::{
     timeout::TimeoutLayer,
     trace::{DefaultMakeSpan, TraceLayer},
 };
-use utils::{extractors::ScaleVersioned, Scale};
+use utils::{
+    extractors::{ExtractStreamCompression, ScaleVersioned},
+    Scale,
+};
 
 #[macro_use]
 pub(crate) mod utils;
 mod block;
+mod connection;
 mod event;
+mod query_pool;
+mod query_replay;
 mod routing;
 mod stream;
+mod tls;
+#[cfg(unix)]
+mod unix_socket;
 
 const SERVER_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(60);
 
@@ -57,13 +69,24 @@ pub struct Torii {
     queue: Arc<Queue>,
     events: EventsSender,
     query_service: LiveQueryStoreHandle,
+    query_pool: QueryPool,
+    query_replay_guard: QueryReplayGuard,
+    read_token_max_ttl: Duration,
     kura: Arc<Kura>,
     transaction_max_content_len: Bytes<u64>,
+    ingress_validation: bool,
+    restrict_account_events: bool,
     address: WithOrigin<SocketAddr>,
+    tls: Option<ToriiTls>,
+    unix_socket: Option<ToriiUnixSocket>,
     state: Arc<State>,
+    staging: Arc<Staging>,
     #[cfg(feature = "telemetry")]
     telemetry: Telemetry,
     online_peers: OnlinePeersProvider,
+    peers_traffic: PeersTrafficReceiver,
+    startup: Arc<StartupReport>,
+    drain: watch::Sender<Option<DrainPhase>>,
 }
 
 impl Torii {
@@ -79,6 +102,9 @@ impl Torii {
         kura: Arc<Kura>,
         state: Arc<State>,
         online_peers: OnlinePeersProvider,
+        peers_traffic: PeersTrafficReceiver,
+        startup: StartupReport,
+        drain: watch::Sender<Option<DrainPhase>>,
         #[cfg(feature = "telemetry")] telemetry: Telemetry,
     ) -> Self {
         Self {
@@ -87,13 +113,27 @@ impl Torii {
             queue,
             events,
             query_service,
+            query_pool: QueryPool::new(config.query_threads),
+            query_replay_guard: QueryReplayGuard::new(
+                config.query_max_ttl,
+                config.require_query_expiry,
+            ),
+            read_token_max_ttl: config.read_token_max_ttl,
             kura,
+            staging: Arc::new(Staging::new(Arc::clone(&state))),
             state,
             online_peers,
+            peers_traffic,
+            startup: Arc::new(startup),
+            drain,
             #[cfg(feature = "telemetry")]
             telemetry,
             address: config.address,
+            tls: config.tls,
+            unix_socket: config.unix_socket,
             transaction_max_content_len: config.max_content_len,
+            ingress_validation: config.ingress_validation,
+            restrict_account_events: config.restrict_account_events,
         }
     }
 
@@ -122,6 +162,13 @@ impl Torii {
                     let peers = self.online_peers.clone();
                     move || async move { routing::handle_peers(&peers) }
                 }),
+            )
+            .route(
+                uri::PEERS_TRAFFIC,
+                get({
+                    let peers_traffic = self.peers_traffic.clone();
+                    move || core::future::ready(routing::handle_peers_traffic(&peers_traffic))
+                }),
             );
 
         #[cfg(feature = "telemetry")]
@@ -190,8 +237,78 @@ impl Torii {
                     let chain_id = self.chain_id.clone();
                     let queue = self.queue.clone();
                     let state = self.state.clone();
+                    let ingress_validation = self
+                        .ingress_validation
+                        .then(|| (self.query_pool.clone(), self.staging.clone()));
+                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::handle_transaction(
+                            chain_id,
+                            queue,
+                            state,
+                            ingress_validation,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
+            .route(
+                uri::TRANSACTION_VALIDATE,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let query_pool = self.query_pool.clone();
+                    let staging = self.staging.clone();
+                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::handle_transaction_validate(
+                            chain_id,
+                            query_pool,
+                            staging,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
+            .route(
+                uri::TRANSACTION_DRY_RUN,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let query_pool = self.query_pool.clone();
+                    let staging = self.staging.clone();
+                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::handle_transaction_dry_run(
+                            chain_id,
+                            query_pool,
+                            staging,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
+            .route(
+                uri::EXECUTOR_DRY_RUN,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let query_pool = self.query_pool.clone();
+                    let staging = self.staging.clone();
                     move |ScaleVersioned(transaction): ScaleVersioned<_>| {
-                        routing::handle_transaction(chain_id, queue, state, transaction)
+                        routing::handle_executor_dry_run(chain_id, query_pool, staging, transaction)
                     }
                 })
                 .layer(DefaultBodyLimit::max(
@@ -201,13 +318,48 @@ impl Torii {
                         .expect("should't exceed usize"),
                 )),
             )
+            .route(
+                uri::EXECUTOR_SCHEMA,
+                get({
+                    let state = self.state.clone();
+                    move || core::future::ready(routing::handle_executor_schema(&state))
+                }),
+            )
             .route(
                 uri::QUERY,
                 post({
                     let query_service = self.query_service.clone();
+                    let query_pool = self.query_pool.clone();
+                    let query_replay_guard = self.query_replay_guard.clone();
                     let state = self.state.clone();
                     move |ScaleVersioned(query_request): ScaleVersioned<_>| {
-                        routing::handle_queries(query_service, state, query_request)
+                        routing::handle_queries(
+                            query_service,
+                            query_pool.clone(),
+                            query_replay_guard.clone(),
+                            state,
+                            query_request,
+                        )
+                    }
+                }),
+            )
+            .route(
+                &format!("{}/:token", uri::READ_TOKEN),
+                get({
+                    let chain_id = self.chain_id.clone();
+                    let query_service = self.query_service.clone();
+                    let query_pool = self.query_pool.clone();
+                    let state = self.state.clone();
+                    let max_ttl = self.read_token_max_ttl;
+                    move |axum::extract::Path(token): axum::extract::Path<String>| {
+                        routing::handle_read_token(
+                            chain_id,
+                            query_service,
+                            query_pool,
+                            state,
+                            max_ttl,
+                            token,
+                        )
                     }
                 }),
             )
@@ -224,10 +376,20 @@ impl Torii {
                 uri::SUBSCRIPTION,
                 get({
                     let events = self.events.clone();
-                    move |ws: WebSocketUpgrade| {
-                        core::future::ready(ws.on_upgrade(|ws| async move {
-                            if let Err(error) =
-                                routing::event::handle_events_stream(events, ws).await
+                    let chain_id = self.chain_id.clone();
+                    let replay_guard = self.query_replay_guard.clone();
+                    let restricted_by = self.restrict_account_events.then(|| self.state.clone());
+                    move |ExtractStreamCompression(compression), ws: WebSocketUpgrade| {
+                        core::future::ready(ws.on_upgrade(move |ws| async move {
+                            if let Err(error) = routing::event::handle_events_stream(
+                                events,
+                                chain_id,
+                                replay_guard,
+                                restricted_by,
+                                ws,
+                                compression,
+                            )
+                            .await
                             {
                                 iroha_logger::error!(%error, "Failure during event streaming");
                             }
@@ -235,13 +397,60 @@ impl Torii {
                     }
                 }),
             )
+            .route(
+                &format!("{}/:height", uri::BLOCKS),
+                get({
+                    let kura = self.kura.clone();
+                    let state = self.state.clone();
+                    move |axum::extract::Path(height): axum::extract::Path<_>,
+                          headers: axum::http::HeaderMap| {
+                        routing::handle_block(kura, state, height, headers)
+                    }
+                }),
+            )
+            .route(
+                &format!("{}/:height{}", uri::BLOCKS, uri::BLOCK_PROOF),
+                get({
+                    let kura = self.kura.clone();
+                    move |axum::extract::Path(height): axum::extract::Path<_>,
+                          axum::extract::Query(at): axum::extract::Query<_>| {
+                        routing::handle_block_proof(kura, height, at)
+                    }
+                }),
+            )
+            .route(
+                &format!("{}/:account{}", uri::ACCOUNTS, uri::ACCOUNT_HISTORY),
+                get({
+                    let kura = self.kura.clone();
+                    let query_pool = self.query_pool.clone();
+                    let state = self.state.clone();
+                    move |axum::extract::Path(account): axum::extract::Path<_>,
+                          axum::extract::Query(range): axum::extract::Query<_>| {
+                        routing::handle_account_history(kura, query_pool, state, account, range)
+                    }
+                }),
+            )
+            .route(
+                uri::BLOCKS,
+                get({
+                    let kura = self.kura.clone();
+                    let state = self.state.clone();
+                    move |axum::extract::Query(range): axum::extract::Query<_>,
+                          headers: axum::http::HeaderMap| {
+                        routing::handle_blocks(kura, state, range, headers)
+                    }
+                }),
+            )
             .route(
                 uri::BLOCKS_STREAM,
                 get({
                     let kura = self.kura.clone();
-                    move |ws: WebSocketUpgrade| {
-                        core::future::ready(ws.on_upgrade(|ws| async move {
-                            if let Err(error) = routing::block::handle_blocks_stream(kura, ws).await
+                    let state = self.state.clone();
+                    move |ExtractStreamCompression(compression), ws: WebSocketUpgrade| {
+                        core::future::ready(ws.on_upgrade(move |ws| async move {
+                            if let Err(error) =
+                                routing::block::handle_blocks_stream(kura, state, ws, compression)
+                                    .await
                             {
                                 iroha_logger::error!(%error, "Failure during block streaming");
                             }
@@ -250,10 +459,25 @@ impl Torii {
                 }),
             );
 
-        let router = router.route(
-            uri::SERVER_VERSION,
-            get(move || async move { routing::handle_server_version() }),
-        );
+        let router = router
+            .route(
+                uri::SERVER_VERSION,
+                get(move || async move { routing::handle_server_version() }),
+            )
+            .route(
+                uri::STARTUP,
+                get({
+                    let startup = self.startup.clone();
+                    move || core::future::ready(routing::handle_startup(&startup))
+                }),
+            )
+            .route(
+                uri::DRAIN,
+                post({
+                    let drain = self.drain.clone();
+                    move || core::future::ready(routing::handle_drain(&drain))
+                }),
+            );
 
         router.layer((
             TraceLayer::new_for_http()
@@ -282,10 +506,51 @@ impl Torii {
         .attach_printable_lazy(|| self.address.clone().into_attachment())?;
         let api_router = self.create_api_router();
 
-        axum::serve(listener, api_router)
-            .with_graceful_shutdown(async move { shutdown_signal.receive().await })
-            .await
-            .change_context(Error::FailedExit)
+        let serve_tcp = {
+            let tls = self.tls;
+            let api_router = api_router.clone();
+            let shutdown_signal = shutdown_signal.clone();
+            async move {
+                if let Some(tls) = tls {
+                    return tls::serve(listener, api_router, tls, shutdown_signal).await;
+                }
+
+                axum::serve(listener, api_router)
+                    .with_graceful_shutdown(async move { shutdown_signal.receive().await })
+                    .await
+                    .change_context(Error::FailedExit)
+            }
+        };
+
+        match self.unix_socket {
+            Some(unix_socket) => {
+                let (tcp, unix) = tokio::join!(
+                    serve_tcp,
+                    Self::serve_unix_socket(unix_socket, api_router, shutdown_signal)
+                );
+                tcp.and(unix)
+            }
+            None => serve_tcp.await,
+        }
+    }
+
+    #[cfg(unix)]
+    async fn serve_unix_socket(
+        config: ToriiUnixSocket,
+        api_router: axum::Router,
+        shutdown_signal: ShutdownSignal,
+    ) -> error_stack::Result<(), Error> {
+        unix_socket::serve(config, api_router, shutdown_signal).await
+    }
+
+    #[cfg(not(unix))]
+    async fn serve_unix_socket(
+        _config: ToriiUnixSocket,
+        _api_router: axum::Router,
+        _shutdown_signal: ShutdownSignal,
+    ) -> error_stack::Result<(), Error> {
+        Err(error_stack::Report::new(Error::StartServer)
+            .attach_printable("Unix domain sockets are not supported on this platform"))
     }
 }
 
@@ -300,6 +565,8 @@ pub enum Error {
     Config(#[source] eyre::Report),
     /// Failed to push into queue
     PushIntoQueue(#[from] Box<queue::Error>),
+    /// Failed to dry run executor upgrade
+    ExecutorDryRun(#[from] iroha_core::state::dry_run::Error),
     #[cfg(feature = "telemetry")]
     /// Failed to get Prometheus metrics
     Prometheus(#[source] eyre::Report),
@@ -313,6 +580,10 @@ pub enum Error {
     ConfigurationFailure(#[from] KisoError),
     /// Failed to find status segment by provided path
     StatusSegmentNotFound(#[source] eyre::Report),
+    /// Block with the requested height isn't committed
+    BlockNotFound,
+    /// Failed to collect account history
+    AccountHistory(#[from] iroha_core::kura::AccountHistoryError),
     /// Failed to start Torii
     StartServer,
     /// Torii server terminated with an error
@@ -335,16 +606,28 @@ impl Error {
         match self {
             Query(e) => Self::query_status_code(e),
             AcceptTransaction(_) => StatusCode::BAD_REQUEST,
-            Config(_) | StatusSegmentNotFound(_) => StatusCode::NOT_FOUND,
+            Config(_) | StatusSegmentNotFound(_) | BlockNotFound => StatusCode::NOT_FOUND,
             PushIntoQueue(err) => match **err {
                 queue::Error::Full => StatusCode::INTERNAL_SERVER_ERROR,
+                queue::Error::Closed => StatusCode::SERVICE_UNAVAILABLE,
                 _ => StatusCode::BAD_REQUEST,
             },
+            ExecutorDryRun(err) => match err {
+                iroha_core::state::dry_run::Error::NotAnUpgrade => StatusCode::BAD_REQUEST,
+                iroha_core::state::dry_run::Error::NotPermitted => StatusCode::FORBIDDEN,
+                iroha_core::state::dry_run::Error::Throttled(_) => StatusCode::TOO_MANY_REQUESTS,
+            },
             #[cfg(feature = "telemetry")]
             Prometheus(_) | StatusFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
             #[cfg(feature = "profiling")]
             Pprof(_) => StatusCode::INTERNAL_SERVER_ERROR,
             ConfigurationFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AccountHistory(err) => match err {
+                iroha_core::kura::AccountHistoryError::BlockNotFound => StatusCode::NOT_FOUND,
+                iroha_core::kura::AccountHistoryError::MissingBlock(_) => {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            },
             StartServer | FailedExit => unreachable!("these never occur during request handling"),
         }
     }
@@ -365,7 +648,8 @@ impl Error {
                 | CursorDone
                 | NotFound
                 | FetchSizeTooBig
-                | InvalidSingularParameters => StatusCode::BAD_REQUEST,
+                | InvalidSingularParameters
+                | AggregateOverflow => StatusCode::BAD_REQUEST,
                 Find(_) => StatusCode::NOT_FOUND,
                 CapacityLimit => StatusCode::TOO_MANY_REQUESTS,
             },
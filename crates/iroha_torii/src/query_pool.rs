@@ -0,0 +1,137 @@
+//! Dedicated thread pool for query execution.
+
+use std::{
+    num::NonZeroUsize,
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+};
+
+use tokio::sync::oneshot;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Thread pool which evaluates queries apart from the async runtime
+/// and the blocking pool shared with the rest of the peer.
+///
+/// Threads exit once every handle to the pool is dropped.
+#[derive(Clone)]
+pub struct QueryPool {
+    jobs: mpsc::Sender<Job>,
+}
+
+impl QueryPool {
+    /// Spawn a pool with the given number of threads.
+    ///
+    /// # Panics
+    /// If the OS fails to spawn a thread.
+    pub fn new(threads: NonZeroUsize) -> Self {
+        let (jobs, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for i in 0..threads.get() {
+            let receiver = Arc::clone(&receiver);
+            thread::Builder::new()
+                .name(format!("query-{i}"))
+                .spawn(move || loop {
+                    let Ok(Ok(job)) = receiver.lock().map(|receiver| receiver.recv()) else {
+                        break;
+                    };
+                    // A panicking query must not take the worker down with it
+                    let _ = std::panic::catch_unwind(AssertUnwindSafe(job));
+                })
+                .expect("Failed to spawn query thread");
+        }
+
+        Self { jobs }
+    }
+
+    /// Run `f` on the pool and wait for its result.
+    ///
+    /// If the returned future is dropped, e.g. because the client disconnected,
+    /// the job is skipped if it hasn't started yet, and `f` can observe the
+    /// cancellation via [`Cancellation::is_cancelled`] otherwise.
+    ///
+    /// Returns [`None`] if `f` panicked.
+    pub async fn run<T, F>(&self, f: F) -> Option<T>
+    where
+        F: FnOnce(&Cancellation) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let cancellation = Cancellation::default();
+        let _guard = CancelOnDrop(cancellation.clone());
+        let (sender, receiver) = oneshot::channel();
+
+        let job = Box::new(move || {
+            if cancellation.is_cancelled() {
+                return;
+            }
+            let _ = sender.send(f(&cancellation));
+        });
+        self.jobs.send(job).ok()?;
+
+        receiver.await.ok()
+    }
+}
+
+/// Flag set once the caller is no longer interested in the result of a job.
+#[derive(Debug, Default, Clone)]
+pub struct Cancellation(Arc<AtomicBool>);
+
+impl Cancellation {
+    /// Whether the caller has given up on the job.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+struct CancelOnDrop(Cancellation);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0 .0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use nonzero_ext::nonzero;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_jobs_and_survives_panics() {
+        let pool = QueryPool::new(nonzero!(1_usize));
+
+        assert_eq!(pool.run(|_| 2 + 2).await, Some(4));
+        assert_eq!(pool.run(|_| -> u32 { panic!("query failed") }).await, None);
+        assert_eq!(pool.run(|_| 42).await, Some(42));
+    }
+
+    #[tokio::test]
+    async fn dropped_jobs_are_cancelled() {
+        let pool = QueryPool::new(nonzero!(1_usize));
+        let (started_tx, started_rx) = oneshot::channel();
+        let (observed_tx, observed_rx) = std::sync::mpsc::channel();
+
+        let job = tokio::spawn(async move {
+            pool.run(move |cancellation| {
+                let _ = started_tx.send(());
+                while !cancellation.is_cancelled() {
+                    thread::sleep(Duration::from_millis(1));
+                }
+                let _ = observed_tx.send(());
+            })
+            .await
+        });
+        started_rx.await.unwrap();
+        job.abort();
+
+        observed_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    }
+}
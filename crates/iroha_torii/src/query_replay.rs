@@ -0,0 +1,241 @@
+//! Protection of signed queries and event subscriptions against replays.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use iroha_crypto::{Hash, HashOf};
+use iroha_data_model::{events::stream::EventSubscriber, query::QueryRequestWithAuthority};
+
+/// Reason to reject a signed query or event subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error, displaydoc::Display)]
+pub enum Rejection {
+    /// Request must have an expiry time
+    MissingExpiry,
+    /// Request has expired
+    Expired,
+    /// Request expires too far in the future, the limit is {0:?} from now
+    ExpiryTooFar(Duration),
+    /// Request has already been accepted
+    Replayed,
+}
+
+/// Remembers signed queries and event subscriptions until they expire,
+/// so that each one is accepted at most once.
+///
+/// Queries without an expiry time can't be remembered for a bounded time
+/// and are either rejected or let through, depending on the configuration.
+/// Signed subscriptions always have one.
+#[derive(Debug, Clone)]
+pub struct QueryReplayGuard {
+    max_ttl: Duration,
+    require_expiry: bool,
+    seen: Arc<Mutex<Seen>>,
+}
+
+#[derive(Debug, Default)]
+struct Seen {
+    expires_at: HashMap<Hash, Duration>,
+    /// Earliest moment when some of the remembered requests expire
+    next_prune: Option<Duration>,
+}
+
+impl QueryReplayGuard {
+    /// Create the guard.
+    pub fn new(max_ttl: Duration, require_expiry: bool) -> Self {
+        Self {
+            max_ttl,
+            require_expiry,
+            seen: Arc::default(),
+        }
+    }
+
+    /// Check that `query` hasn't expired and hasn't been seen before, and remember it.
+    ///
+    /// # Errors
+    /// If the query must be rejected.
+    pub fn check(&self, query: &QueryRequestWithAuthority) -> Result<(), Rejection> {
+        self.check_at(query, now())
+    }
+
+    /// Check that the subscription of `subscriber` hasn't expired and hasn't been seen before,
+    /// and remember it.
+    ///
+    /// # Errors
+    /// If the subscription must be rejected.
+    pub fn check_subscription(&self, subscriber: &EventSubscriber) -> Result<(), Rejection> {
+        self.check_subscription_at(subscriber, now())
+    }
+
+    fn check_at(&self, query: &QueryRequestWithAuthority, now: Duration) -> Result<(), Rejection> {
+        let Some(expires_at) = query.expires_at() else {
+            return if self.require_expiry {
+                Err(Rejection::MissingExpiry)
+            } else {
+                Ok(())
+            };
+        };
+        self.remember(HashOf::new(query).into(), expires_at, now)
+    }
+
+    fn check_subscription_at(
+        &self,
+        subscriber: &EventSubscriber,
+        now: Duration,
+    ) -> Result<(), Rejection> {
+        self.remember(HashOf::new(subscriber).into(), subscriber.expires_at(), now)
+    }
+
+    fn remember(
+        &self,
+        request: Hash,
+        expires_at: Duration,
+        now: Duration,
+    ) -> Result<(), Rejection> {
+        if expires_at <= now {
+            return Err(Rejection::Expired);
+        }
+        if expires_at - now > self.max_ttl {
+            return Err(Rejection::ExpiryTooFar(self.max_ttl));
+        }
+
+        let mut seen = self.seen.lock().expect("Replay guard lock is poisoned");
+        if seen.next_prune.is_some_and(|next_prune| next_prune <= now) {
+            seen.expires_at.retain(|_, expires_at| *expires_at > now);
+            seen.next_prune = seen.expires_at.values().min().copied();
+        }
+        if seen.expires_at.insert(request, expires_at).is_some() {
+            return Err(Rejection::Replayed);
+        }
+        seen.next_prune = Some(
+            seen.next_prune
+                .map_or(expires_at, |next| next.min(expires_at)),
+        );
+
+        Ok(())
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Failed to get the current system time")
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::KeyPair;
+    use iroha_data_model::{
+        account::AccountId,
+        events::stream::EventSubscriptionRequest,
+        query::{FindExecutorDataModel, QueryRequest, SingularQueryBox},
+        ChainId,
+    };
+    use nonzero_ext::nonzero;
+
+    use super::*;
+
+    const NOW: Duration = Duration::from_secs(1_000);
+    const MAX_TTL: Duration = Duration::from_secs(60);
+
+    fn query() -> QueryRequestWithAuthority {
+        QueryRequest::Singular(SingularQueryBox::FindExecutorDataModel(
+            FindExecutorDataModel,
+        ))
+        .with_authority(
+            "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03@wonderland"
+                .parse()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn query_is_executed_once() {
+        let guard = QueryReplayGuard::new(MAX_TTL, true);
+        let query = query()
+            .with_expiry(NOW + MAX_TTL)
+            .with_nonce(nonzero!(1_u32));
+
+        assert_eq!(guard.check_at(&query, NOW), Ok(()));
+        assert_eq!(guard.check_at(&query, NOW), Err(Rejection::Replayed));
+        assert_eq!(
+            guard.check_at(&query.clone().with_nonce(nonzero!(2_u32)), NOW),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn expiry_is_enforced() {
+        let guard = QueryReplayGuard::new(MAX_TTL, true);
+
+        assert_eq!(guard.check_at(&query(), NOW), Err(Rejection::MissingExpiry));
+        assert_eq!(
+            guard.check_at(&query().with_expiry(NOW), NOW),
+            Err(Rejection::Expired)
+        );
+        assert_eq!(
+            guard.check_at(&query().with_expiry(NOW + MAX_TTL * 2), NOW),
+            Err(Rejection::ExpiryTooFar(MAX_TTL))
+        );
+        assert_eq!(
+            QueryReplayGuard::new(MAX_TTL, false).check_at(&query(), NOW),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn expired_queries_are_forgotten() {
+        let guard = QueryReplayGuard::new(MAX_TTL, true);
+        let short = query().with_expiry(NOW + Duration::from_secs(1));
+        let long = query().with_expiry(NOW + MAX_TTL);
+
+        guard.check_at(&short, NOW).unwrap();
+        guard.check_at(&long, NOW).unwrap();
+        guard
+            .check_at(&query().with_expiry(NOW + MAX_TTL / 2), NOW + MAX_TTL / 4)
+            .unwrap();
+
+        let seen = guard.seen.lock().unwrap();
+        assert_eq!(seen.expires_at.len(), 2);
+        assert!(!seen.expires_at.contains_key(&HashOf::new(&short).into()));
+    }
+
+    #[test]
+    fn subscription_is_accepted_once() {
+        let guard = QueryReplayGuard::new(MAX_TTL, false);
+        let key_pair = KeyPair::random();
+        let subscribe = |expires_at, nonce| {
+            EventSubscriptionRequest::signed(
+                Vec::new(),
+                ChainId::from("00000000-0000-0000-0000-000000000000"),
+                AccountId::new("wonderland".parse().unwrap(), key_pair.public_key().clone()),
+                expires_at,
+                nonce,
+                key_pair.private_key(),
+            )
+            .subscriber
+            .unwrap()
+        };
+
+        let subscriber = subscribe(NOW + MAX_TTL, nonzero!(1_u32));
+        assert_eq!(guard.check_subscription_at(&subscriber, NOW), Ok(()));
+        assert_eq!(
+            guard.check_subscription_at(&subscriber, NOW),
+            Err(Rejection::Replayed)
+        );
+        assert_eq!(
+            guard.check_subscription_at(&subscribe(NOW + MAX_TTL, nonzero!(2_u32)), NOW),
+            Ok(())
+        );
+        assert_eq!(
+            guard.check_subscription_at(&subscribe(NOW, nonzero!(3_u32)), NOW),
+            Err(Rejection::Expired)
+        );
+        assert_eq!(
+            guard.check_subscription_at(&subscribe(NOW + MAX_TTL * 2, nonzero!(4_u32)), NOW),
+            Err(Rejection::ExpiryTooFar(MAX_TTL))
+        );
+    }
+}
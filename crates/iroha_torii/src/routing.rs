@@ -2,22 +2,38 @@
 //! Iroha you should add it here by creating a `handle_*` function,
 //! and add it to impl Torii.
 
-use axum::extract::ws::WebSocket;
+use std::num::NonZeroUsize;
+
+use axum::{
+    extract::ws::WebSocket,
+    http::{header::IF_NONE_MATCH, HeaderMap},
+};
 #[cfg(feature = "telemetry")]
 use eyre::{eyre, WrapErr};
 use iroha_config::client_api::{ConfigGetDTO, ConfigUpdateDTO};
 #[cfg(feature = "telemetry")]
 use iroha_core::telemetry::Telemetry;
-use iroha_core::{query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest};
+use iroha_core::{
+    query::store::LiveQueryStoreHandle,
+    redaction::{redacted_block, redacted_transaction},
+    smartcontracts::query::ValidQueryRequest,
+    state::dry_run::Staging,
+};
 use iroha_data_model::{
     self,
     prelude::*,
-    query::{QueryRequestWithAuthority, QueryResponse, SignedQuery},
+    query::{
+        read_token::ReadToken, QueryOutput, QueryRequestWithAuthority, QueryResponse, SignedQuery,
+    },
+    transaction::error::TransactionRejectionReason,
 };
 #[cfg(feature = "telemetry")]
 use iroha_telemetry::metrics::Status;
-use iroha_torii_shared::Version;
-use tokio::task;
+use iroha_torii_shared::{
+    compression::Compression, DrainPhase, ExecutorSchema, PeerTraffic, PermissionSchema,
+    StartupReport, Version,
+};
+use parity_scale_codec::Encode as _;
 
 use super::*;
 
@@ -26,16 +42,29 @@ pub async fn handle_transaction(
     chain_id: Arc<ChainId>,
     queue: Arc<Queue>,
     state: Arc<State>,
+    ingress_validation: Option<(QueryPool, Arc<Staging>)>,
     tx: SignedTransaction,
 ) -> Result<()> {
-    let (max_clock_drift, tx_limits) = {
-        let state_view = state.world.view();
-        let params = state_view.parameters();
-        (params.sumeragi.max_clock_drift(), params.transaction)
-    };
+    let params = state.world.view().parameters().clone();
 
-    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+    let accepted_tx = AcceptedTransaction::accept(
+        tx,
+        &chain_id,
+        params.sumeragi.max_clock_drift(),
+        params.transaction,
+    )
+    .map_err(Error::AcceptTransaction)?;
+    accepted_tx
+        .validate_time_to_live(&params)
         .map_err(Error::AcceptTransaction)?;
+    let accepted_tx = match ingress_validation {
+        Some((query_pool, staging)) => query_pool
+            .run(move |_| accepted_tx.validate_ingress(&staging).map(|()| accepted_tx))
+            .await
+            .expect("Ingress validation task panicked")
+            .map_err(Error::AcceptTransaction)?,
+        None => accepted_tx,
+    };
 
     queue
         .push(accepted_tx, state.view())
@@ -50,31 +79,317 @@ pub async fn handle_transaction(
         .map_err(Error::PushIntoQueue)
 }
 
+/// Check whether the executor would accept the transaction against the current state.
+///
+/// The transaction is validated as it would be in the next block, but nothing
+/// gets into the queue or is committed. The rejection reason, if any, is returned
+/// with a successful response.
+#[iroha_futures::telemetry_future]
+pub async fn handle_transaction_validate(
+    chain_id: Arc<ChainId>,
+    query_pool: QueryPool,
+    staging: Arc<Staging>,
+    tx: SignedTransaction,
+) -> Result<Scale<Result<(), TransactionRejectionReason>>> {
+    let (max_clock_drift, tx_limits) = {
+        let state_view = staging.state().world.view();
+        let params = state_view.parameters();
+        (params.sumeragi.max_clock_drift(), params.transaction)
+    };
+
+    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+        .map_err(Error::AcceptTransaction)?;
+
+    let result = query_pool
+        .run(move |_| staging.validate_transaction(accepted_tx))
+        .await
+        .expect("Transaction validation task panicked");
+    Ok(Scale(result))
+}
+
+/// Dry run a transaction against the current state and report the changes it would make.
+///
+/// The transaction is validated as it would be in the next block, but nothing
+/// gets into the queue or is committed.
+#[iroha_futures::telemetry_future]
+pub async fn handle_transaction_dry_run(
+    chain_id: Arc<ChainId>,
+    query_pool: QueryPool,
+    staging: Arc<Staging>,
+    tx: SignedTransaction,
+) -> Result<Scale<TransactionDryRun>> {
+    let (max_clock_drift, tx_limits) = {
+        let state_view = staging.state().world.view();
+        let params = state_view.parameters();
+        (params.sumeragi.max_clock_drift(), params.transaction)
+    };
+
+    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+        .map_err(Error::AcceptTransaction)?;
+
+    let dry_run = query_pool
+        .run(move |_| staging.transaction(accepted_tx))
+        .await
+        .expect("Transaction dry run task panicked");
+    Ok(Scale(dry_run))
+}
+
+/// Dry run an executor upgrade transaction against a copy of the current state.
+///
+/// The transaction is validated as it would be in the next block, but nothing
+/// gets into the queue or is committed.
+#[iroha_futures::telemetry_future]
+pub async fn handle_executor_dry_run(
+    chain_id: Arc<ChainId>,
+    query_pool: QueryPool,
+    staging: Arc<Staging>,
+    tx: SignedTransaction,
+) -> Result<Scale<ExecutorUpgradeDryRun>> {
+    let (max_clock_drift, tx_limits) = {
+        let state_view = staging.state().world.view();
+        let params = state_view.parameters();
+        (params.sumeragi.max_clock_drift(), params.transaction)
+    };
+
+    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+        .map_err(Error::AcceptTransaction)?;
+
+    query_pool
+        .run(move |_| staging.upgrade_executor(accepted_tx))
+        .await
+        .expect("Executor dry run task panicked")
+        .map(Scale)
+        .map_err(Error::ExecutorDryRun)
+}
+
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
+    query_pool: QueryPool,
+    replay_guard: QueryReplayGuard,
     state: Arc<State>,
     query: SignedQuery,
 ) -> Result<Scale<QueryResponse>> {
-    let handle = task::spawn_blocking(move || {
+    let query: QueryRequestWithAuthority = query.into_payload();
+    replay_guard
+        .check(&query)
+        .map_err(|rejection| ValidationFail::NotPermitted(rejection.to_string()))?;
+
+    let response = query_pool.run(move |cancellation| {
         let state_view = state.view();
 
-        let SignedQuery::V1(query) = query;
-        let query: QueryRequestWithAuthority = query.payload;
         let authority = query.authority.clone();
 
         let valid_query = ValidQueryRequest::validate_for_client(query, &state_view)?;
         let response = valid_query.execute(&live_query_store, &state_view, &authority)?;
 
+        // The client is gone, don't keep its cursor until it expires
+        if cancellation.is_cancelled() {
+            if let QueryResponse::Iterable(QueryOutput {
+                continue_cursor: Some(cursor),
+                ..
+            }) = &response
+            {
+                live_query_store.drop_query(&cursor.query);
+            }
+        }
+
         Ok::<_, ValidationFail>(response)
     });
-    handle
+    response
         .await
-        .expect("Failed to join query handling task")
+        .expect("Query handling task panicked")
         .map(Scale)
         .map_err(Into::into)
 }
 
+#[iroha_futures::telemetry_future]
+pub async fn handle_read_token(
+    chain_id: Arc<ChainId>,
+    live_query_store: LiveQueryStoreHandle,
+    query_pool: QueryPool,
+    state: Arc<State>,
+    max_ttl: Duration,
+    token: String,
+) -> Result<Scale<QueryResponse>> {
+    let token: ReadToken = token.parse().map_err(|err: iroha_data_model::ParseError| {
+        ValidationFail::NotPermitted(err.to_string())
+    })?;
+    token
+        .verify()
+        .map_err(|err| ValidationFail::NotPermitted(err.to_owned()))?;
+    if token.chain() != &*chain_id {
+        return Err(ValidationFail::NotPermitted(
+            "Read token was minted for another chain".to_owned(),
+        )
+        .into());
+    }
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .expect("Failed to get the current system time");
+    if token.expires_at() <= now {
+        return Err(ValidationFail::NotPermitted("Read token has expired".to_owned()).into());
+    }
+    if token.expires_at() > now + max_ttl {
+        return Err(ValidationFail::NotPermitted(format!(
+            "Read token must not expire later than {max_ttl:?} from now"
+        ))
+        .into());
+    }
+    if matches!(token.query(), QueryRequest::Continue(_)) {
+        return Err(ValidationFail::NotPermitted(
+            "Read token must start a query, not continue it".to_owned(),
+        )
+        .into());
+    }
+
+    let response = query_pool.run(move |_| {
+        let state_view = state.view();
+        let authority = token.authority().clone();
+        let query = token.query().clone().with_authority(authority.clone());
+
+        let valid_query = ValidQueryRequest::validate_for_client(query, &state_view)?;
+        let response = valid_query.execute(&live_query_store, &state_view, &authority)?;
+
+        // Holders of a token can't continue the query, so only the first batch is returned
+        let response = match response {
+            QueryResponse::Iterable(output) => {
+                let (batch, remaining_items, cursor) = output.into_parts();
+                if let Some(cursor) = cursor {
+                    live_query_store.drop_query(&cursor.query);
+                }
+                QueryResponse::Iterable(QueryOutput::new(batch, remaining_items, None))
+            }
+            singular @ QueryResponse::Singular(_) => singular,
+        };
+
+        Ok::<_, ValidationFail>(response)
+    });
+    response
+        .await
+        .expect("Query handling task panicked")
+        .map(Scale)
+        .map_err(Into::into)
+}
+
+/// Maximum number of blocks returned or searched for a single range request
+const MAX_BLOCKS_PER_REQUEST: usize = 100;
+
+/// Range of block heights, both ends inclusive
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub struct BlockRange {
+    /// Height of the first block, the genesis block by default
+    from: Option<NonZeroUsize>,
+    /// Height of the last block, the latest block by default
+    to: Option<NonZeroUsize>,
+}
+
+/// `block` as served to clients, see [`redacted_block`], along with its entity tag.
+///
+/// Blocks are identified by their hashes, unless some of their values are redacted.
+fn served_block(block: Arc<SignedBlock>, state: &impl StateReadOnly) -> (Arc<SignedBlock>, Hash) {
+    let served = redacted_block(Arc::clone(&block), state);
+    let etag = if Arc::ptr_eq(&served, &block) {
+        block.hash().into()
+    } else {
+        Hash::new(served.encode())
+    };
+    (served, etag)
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_block(
+    kura: Arc<Kura>,
+    state: Arc<State>,
+    height: NonZeroUsize,
+    headers: HeaderMap,
+) -> Result<Response> {
+    let block = kura.get_block(height).ok_or(Error::BlockNotFound)?;
+    let (block, etag) = served_block(block, &state.view());
+
+    Ok(utils::conditional_scale(
+        headers.get(IF_NONE_MATCH),
+        etag,
+        block,
+    ))
+}
+
+/// Height of the block committing to the root of the block inclusion proof
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub struct ProofHeight {
+    /// The latest block by default
+    at: Option<NonZeroUsize>,
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_block_proof(
+    kura: Arc<Kura>,
+    height: NonZeroUsize,
+    proof_height: ProofHeight,
+) -> Result<Scale<BlockInclusionProof>> {
+    kura.get_block_inclusion_proof(height, proof_height.at)
+        .map(Scale)
+        .ok_or(Error::BlockNotFound)
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_account_history(
+    kura: Arc<Kura>,
+    query_pool: QueryPool,
+    state: Arc<State>,
+    account: AccountId,
+    range: BlockRange,
+) -> Result<Scale<AccountHistory>> {
+    let from = range.from.unwrap_or(NonZeroUsize::MIN);
+    let max_blocks = NonZeroUsize::new(MAX_BLOCKS_PER_REQUEST)
+        .expect("INTERNAL BUG: limit of blocks per request is zero");
+
+    // Blocks may have to be read from disk, which mustn't block the async runtime
+    let history = query_pool.run(move |_| {
+        let mut history = kura.account_history(&account, from, range.to, max_blocks)?;
+        let state_view = state.view();
+        for block in &mut history.blocks {
+            let height = block.header.height();
+            block.transactions = core::mem::take(&mut block.transactions)
+                .into_iter()
+                .map(|tx| redacted_transaction(tx, height, &state_view))
+                .collect();
+        }
+
+        Ok::<_, Error>(history)
+    });
+    history
+        .await
+        .expect("Account history task panicked")
+        .map(Scale)
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_blocks(
+    kura: Arc<Kura>,
+    state: Arc<State>,
+    range: BlockRange,
+    headers: HeaderMap,
+) -> Result<Response> {
+    let from = range.from.map_or(1, NonZeroUsize::get);
+    let to = range.to.map_or(usize::MAX, NonZeroUsize::get);
+
+    let state_view = state.view();
+    let (blocks, etags): (Vec<_>, Vec<_>) = (from..=to)
+        .take(MAX_BLOCKS_PER_REQUEST)
+        .map_while(|height| NonZeroUsize::new(height).and_then(|height| kura.get_block(height)))
+        .map(|block| served_block(block, &state_view))
+        .unzip();
+    // Ranges of blocks are identified by the blocks in them
+    let etag = Hash::new(etags.encode());
+
+    Ok(utils::conditional_scale(
+        headers.get(IF_NONE_MATCH),
+        etag,
+        blocks,
+    ))
+}
+
 pub async fn handle_health() -> &'static str {
     "Healthy"
 }
@@ -129,10 +444,15 @@ pub mod block {
     type Result<T> = core::result::Result<T, Error>;
 
     #[iroha_futures::telemetry_future]
-    pub async fn handle_blocks_stream(kura: Arc<Kura>, stream: WebSocket) -> eyre::Result<()> {
-        let mut stream = WebSocketScale(stream);
+    pub async fn handle_blocks_stream(
+        kura: Arc<Kura>,
+        state: Arc<State>,
+        stream: WebSocket,
+        compression: Option<Compression>,
+    ) -> eyre::Result<()> {
+        let mut stream = WebSocketScale::new(stream, compression);
         let init_and_subscribe = async {
-            let mut consumer = block::Consumer::new(&mut stream, kura).await?;
+            let mut consumer = block::Consumer::new(&mut stream, kura, state).await?;
             subscribe_forever(&mut consumer).await
         };
 
@@ -201,10 +521,18 @@ pub mod event {
     /// Subscribes `stream` for `events` filtered by filter that is
     /// received through the `stream`
     #[iroha_futures::telemetry_future]
-    pub async fn handle_events_stream(events: EventsSender, stream: WebSocket) -> eyre::Result<()> {
-        let mut stream = WebSocketScale(stream);
+    pub async fn handle_events_stream(
+        events: EventsSender,
+        chain_id: Arc<ChainId>,
+        replay_guard: QueryReplayGuard,
+        restricted_by: Option<Arc<State>>,
+        stream: WebSocket,
+        compression: Option<Compression>,
+    ) -> eyre::Result<()> {
+        let mut stream = WebSocketScale::new(stream, compression);
         let init_and_subscribe = async {
-            let mut consumer = event::Consumer::new(&mut stream).await?;
+            let mut consumer =
+                event::Consumer::new(&mut stream, &chain_id, &replay_guard, restricted_by).await?;
             subscribe_forever(events, &mut consumer).await
         };
 
@@ -303,6 +631,25 @@ pub fn handle_peers(online_peers: &OnlinePeersProvider) -> Response {
     axum::Json(data).into_response()
 }
 
+/// Report the traffic exchanged with each of the connected peers, busiest first
+pub fn handle_peers_traffic(peers_traffic: &PeersTrafficReceiver) -> Response {
+    let mut traffic = peers_traffic
+        .get()
+        .into_iter()
+        .map(|(peer, traffic)| PeerTraffic {
+            peer: peer.public_key().to_string(),
+            bytes_sent: traffic.bytes_sent,
+            bytes_received: traffic.bytes_received,
+            messages_sent: traffic.messages_sent,
+            messages_received: traffic.messages_received,
+        })
+        .collect::<Vec<_>>();
+    traffic.sort_by_key(|traffic| {
+        core::cmp::Reverse(traffic.bytes_sent.saturating_add(traffic.bytes_received))
+    });
+    axum::Json(traffic).into_response()
+}
+
 #[cfg(feature = "telemetry")]
 #[allow(clippy::unnecessary_wraps)]
 pub async fn handle_status(
@@ -312,7 +659,8 @@ pub async fn handle_status(
 ) -> Result<Response> {
     use eyre::ContextCompat;
 
-    let status = Status::from(&telemetry.metrics().await);
+    let mut status = Status::from(&telemetry.metrics().await);
+    status.leader = telemetry.leader();
 
     if let Some(tail) = tail {
         // TODO: This probably can be optimised to elide the full
@@ -426,3 +774,48 @@ pub fn handle_server_version() -> axum::http::Response<axum::body::Body> {
     };
     axum::Json(version).into_response()
 }
+
+pub fn handle_startup(startup: &StartupReport) -> axum::http::Response<axum::body::Body> {
+    axum::Json(startup).into_response()
+}
+
+/// Request the peer to drain, unless it already does, and report the current drain phase.
+pub fn handle_drain(
+    drain: &watch::Sender<Option<DrainPhase>>,
+) -> axum::http::Response<axum::body::Body> {
+    let mut current = DrainPhase::RejectingTransactions;
+    drain.send_if_modified(|phase| match phase {
+        Some(phase) => {
+            current = *phase;
+            false
+        }
+        None => {
+            *phase = Some(current);
+            true
+        }
+    });
+    (StatusCode::ACCEPTED, axum::Json(current)).into_response()
+}
+
+/// Report the permission tokens supported by the installed executor, each with its schema
+/// and the version derived from it, so that clients can check tokens before submitting them.
+pub fn handle_executor_schema(state: &State) -> axum::http::Response<axum::body::Body> {
+    let (permissions, types) = {
+        let world = state.world.view();
+        let data_model = world.executor_data_model();
+        (data_model.permission_schemas(), data_model.schema().clone())
+    };
+
+    let permissions = permissions
+        .into_iter()
+        .map(|token| {
+            let schema = PermissionSchema {
+                version: token.version.to_string(),
+                schema: token.schema,
+            };
+            (token.name, schema)
+        })
+        .collect();
+
+    axum::Json(ExecutorSchema { permissions, types }).into_response()
+}
@@ -4,6 +4,7 @@ use core::{result::Result, time::Duration};
 
 use axum::extract::ws::{Message, WebSocket};
 use futures::{SinkExt, StreamExt};
+use iroha_torii_shared::compression::Compression;
 use iroha_version::prelude::*;
 use parity_scale_codec::DecodeAll;
 
@@ -24,18 +25,34 @@ pub enum Error {
     WebSocket(#[source] axum::Error),
     /// Error during versioned message decoding
     Decode(#[from] parity_scale_codec::Error),
+    /// Failed to compress message: {_0}
+    Compress(#[source] std::io::Error),
     /// Connection is closed
     Closed,
 }
 
 /// Wrapper to send/receive scale encoded messages
 #[derive(Debug)]
-pub struct WebSocketScale(pub(crate) WebSocket);
+pub struct WebSocketScale {
+    ws: WebSocket,
+    /// Compression of sent messages requested by the client
+    compression: Option<Compression>,
+}
 
 impl WebSocketScale {
+    /// Wrap `ws`, compressing sent messages with `compression` if any
+    pub fn new(ws: WebSocket, compression: Option<Compression>) -> Self {
+        Self { ws, compression }
+    }
+
     /// Send message encoded in scale
     pub async fn send<M: Encode + Send>(&mut self, message: M) -> Result<(), Error> {
-        tokio::time::timeout(TIMEOUT, self.0.send(Message::Binary(message.encode())))
+        let mut message = message.encode();
+        if let Some(compression) = self.compression {
+            message = compression.compress(&message).map_err(Error::Compress)?;
+        }
+
+        tokio::time::timeout(TIMEOUT, self.ws.send(Message::Binary(message)))
             .await
             .map_err(|_err| Error::SendTimeout)?
             .map_err(extract_ws_closed)
@@ -45,7 +62,7 @@ impl WebSocketScale {
     pub async fn recv<M: Decode>(&mut self) -> Result<M, Error> {
         // NOTE: ignore non binary messages
         loop {
-            let message = tokio::time::timeout(TIMEOUT, self.0.next())
+            let message = tokio::time::timeout(TIMEOUT, self.ws.next())
                 .await
                 .map_err(|_err| Error::ReadTimeout)?
                 // NOTE: `None` is the same as `ConnectionClosed` or `AlreadyClosed`
@@ -69,7 +86,7 @@ impl WebSocketScale {
     /// Discard messages and wait for close message
     pub async fn closed(&mut self) -> Result<(), Error> {
         loop {
-            match self.0.next().await {
+            match self.ws.next().await {
                 // NOTE: `None` is the same as `ConnectionClosed` or `AlreadyClosed`
                 None => return Ok(()),
                 Some(Ok(_)) => {}
@@ -86,7 +103,7 @@ impl WebSocketScale {
     /// Close websocket
     pub async fn close(mut self) -> Result<(), Error> {
         // NOTE: use `SinkExt::close` because it's not trying to write to closed socket
-        match <_ as SinkExt<_>>::close(&mut self.0)
+        match <_ as SinkExt<_>>::close(&mut self.ws)
             .await
             .map_err(extract_ws_closed)
         {
@@ -0,0 +1,288 @@
+//! Native TLS termination of the Torii listener.
+//!
+//! Certificates are picked by the server name the client asks for (SNI), falling back to the
+//! one configured without a server name. Certificate files are watched for changes, so that
+//! renewed certificates are served without restarting the peer.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::BufReader,
+    path::Path,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use error_stack::{Report, ResultExt};
+use iroha_config::parameters::actual::{ToriiTls as Config, ToriiTlsCertificate};
+use iroha_futures::supervisor::ShutdownSignal;
+use tokio::net::TcpListener;
+use tokio_rustls::{
+    rustls::{
+        crypto::ring::{default_provider, sign::any_supported_type},
+        server::{ClientHello, ResolvesServerCert},
+        sign::CertifiedKey,
+        ServerConfig,
+    },
+    TlsAcceptor,
+};
+use tokio_util::task::TaskTracker;
+
+use crate::{connection::serve_connection, Error};
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Error of loading a certificate
+#[derive(Debug, thiserror::Error, Copy, Clone)]
+pub enum CertificateError {
+    /// Failed to read the certificate chain
+    #[error("Failed to read the certificate chain")]
+    Chain,
+    /// Failed to read the private key
+    #[error("Failed to read the private key")]
+    Key,
+    /// Configuration doesn't contain any certificate
+    #[error("At least one certificate should be configured")]
+    Empty,
+}
+
+/// Certificates by the server name
+#[derive(Debug, Default)]
+struct Certificates {
+    default: Option<Arc<CertifiedKey>>,
+    by_server_name: HashMap<String, Arc<CertifiedKey>>,
+}
+
+impl Certificates {
+    fn load(config: &[ToriiTlsCertificate]) -> error_stack::Result<Self, CertificateError> {
+        if config.is_empty() {
+            return Err(Report::new(CertificateError::Empty));
+        }
+
+        let mut certificates = Self::default();
+        for certificate in config {
+            let key = Arc::new(load_certified_key(certificate)?);
+            match &certificate.server_name {
+                Some(name) => {
+                    certificates
+                        .by_server_name
+                        .insert(name.to_ascii_lowercase(), key);
+                }
+                None => certificates.default = Some(key),
+            }
+        }
+
+        Ok(certificates)
+    }
+}
+
+fn load_certified_key(
+    certificate: &ToriiTlsCertificate,
+) -> error_stack::Result<CertifiedKey, CertificateError> {
+    let chain = File::open(&certificate.cert_file)
+        .map(BufReader::new)
+        .and_then(|mut reader| rustls_pemfile::certs(&mut reader).collect::<Result<Vec<_>, _>>())
+        .change_context(CertificateError::Chain)
+        .attach_printable_lazy(|| format!("path: {}", certificate.cert_file.display()))?;
+    if chain.is_empty() {
+        return Err(
+            Report::new(CertificateError::Chain).attach_printable(format!(
+                "no certificates in {}",
+                certificate.cert_file.display()
+            )),
+        );
+    }
+
+    let key = File::open(&certificate.key_file)
+        .map(BufReader::new)
+        .and_then(|mut reader| rustls_pemfile::private_key(&mut reader))
+        .change_context(CertificateError::Key)
+        .attach_printable_lazy(|| format!("path: {}", certificate.key_file.display()))?
+        .ok_or_else(|| {
+            Report::new(CertificateError::Key).attach_printable(format!(
+                "no private key in {}",
+                certificate.key_file.display()
+            ))
+        })?;
+    let key = any_supported_type(&key)
+        .change_context(CertificateError::Key)
+        .attach_printable_lazy(|| format!("path: {}", certificate.key_file.display()))?;
+
+    Ok(CertifiedKey::new(chain, key))
+}
+
+/// Resolver of the certificate served on a handshake
+#[derive(Debug)]
+struct CertificateResolver {
+    config: Vec<ToriiTlsCertificate>,
+    certificates: RwLock<Certificates>,
+}
+
+impl ResolvesServerCert for CertificateResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let certificates = self
+            .certificates
+            .read()
+            .expect("certificates are never replaced while panicking");
+
+        client_hello
+            .server_name()
+            .and_then(|name| certificates.by_server_name.get(&name.to_ascii_lowercase()))
+            .or(certificates.default.as_ref())
+            .cloned()
+    }
+}
+
+impl CertificateResolver {
+    fn new(config: Vec<ToriiTlsCertificate>) -> error_stack::Result<Self, CertificateError> {
+        let certificates = Certificates::load(&config)?;
+
+        Ok(Self {
+            config,
+            certificates: RwLock::new(certificates),
+        })
+    }
+
+    /// Latest modification time of the certificate files
+    fn modified(&self) -> Option<SystemTime> {
+        fn modified(path: &Path) -> Option<SystemTime> {
+            std::fs::metadata(path)
+                .and_then(|meta| meta.modified())
+                .ok()
+        }
+
+        self.config
+            .iter()
+            .flat_map(|certificate| [&certificate.cert_file, &certificate.key_file])
+            .filter_map(|path| modified(path))
+            .max()
+    }
+
+    /// Reload the certificates whenever their files change.
+    ///
+    /// If the new files can't be loaded, the previous certificates are kept.
+    async fn reload_periodically(self: Arc<Self>, interval: Duration) {
+        let mut last_modified = self.modified();
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick completes immediately
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let modified = self.modified();
+            if modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+
+            match Certificates::load(&self.config) {
+                Ok(certificates) => {
+                    *self
+                        .certificates
+                        .write()
+                        .expect("certificates are never replaced while panicking") = certificates;
+                    iroha_logger::info!("Reloaded Torii TLS certificates");
+                }
+                Err(report) => {
+                    iroha_logger::error!(
+                        ?report,
+                        "Failed to reload Torii TLS certificates, keeping the previous ones"
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Serve `router` over TLS until the shutdown signal is received.
+pub(crate) async fn serve(
+    listener: TcpListener,
+    router: axum::Router,
+    config: Config,
+    shutdown_signal: ShutdownSignal,
+) -> error_stack::Result<(), Error> {
+    let resolver = Arc::new(
+        CertificateResolver::new(config.certificates)
+            .change_context(Error::StartServer)
+            .attach_printable("failed to load TLS certificates")?,
+    );
+    let mut server_config = ServerConfig::builder_with_provider(Arc::new(default_provider()))
+        .with_safe_default_protocol_versions()
+        .change_context(Error::StartServer)?
+        .with_no_client_auth()
+        .with_cert_resolver(resolver.clone());
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    let acceptor = TlsAcceptor::from(Arc::new(server_config));
+
+    let reload = tokio::spawn(resolver.reload_periodically(config.reload_interval));
+    let connections = TaskTracker::new();
+
+    loop {
+        let (stream, peer_address) = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(error) => {
+                    iroha_logger::warn!(%error, "Failed to accept a connection");
+                    continue;
+                }
+            },
+            () = shutdown_signal.receive() => break,
+        };
+
+        let acceptor = acceptor.clone();
+        let router = router.clone();
+        let shutdown_signal = shutdown_signal.clone();
+        connections.spawn(async move {
+            let stream =
+                match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                    Ok(Ok(stream)) => stream,
+                    Ok(Err(error)) => {
+                        iroha_logger::debug!(%error, %peer_address, "TLS handshake failed");
+                        return;
+                    }
+                    Err(_) => {
+                        iroha_logger::debug!(%peer_address, "TLS handshake timed out");
+                        return;
+                    }
+                };
+
+            if let Err(error) = serve_connection(stream, router, shutdown_signal).await {
+                iroha_logger::debug!(%error, %peer_address, "Failed to serve a connection");
+            }
+        });
+    }
+
+    reload.abort();
+    connections.close();
+    connections.wait().await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn empty_config_is_rejected() {
+        let report = CertificateResolver::new(Vec::new()).unwrap_err();
+
+        assert!(matches!(report.current_context(), CertificateError::Empty));
+    }
+
+    #[test]
+    fn missing_files_are_reported() {
+        let report = CertificateResolver::new(vec![ToriiTlsCertificate {
+            server_name: None,
+            cert_file: PathBuf::from("/nonexistent/torii.crt"),
+            key_file: PathBuf::from("/nonexistent/torii.key"),
+        }])
+        .unwrap_err();
+
+        assert!(matches!(report.current_context(), CertificateError::Chain));
+    }
+}
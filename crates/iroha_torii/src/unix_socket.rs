@@ -0,0 +1,127 @@
+//! Unix domain socket listener of Torii.
+//!
+//! Services running on the same host, e.g. an indexer, can reach the API without the TCP
+//! overhead and without exposing it to the network. Who can connect is controlled by the
+//! permissions of the socket file.
+
+use std::{
+    fs, io,
+    os::unix::fs::{FileTypeExt as _, PermissionsExt as _},
+};
+
+use error_stack::ResultExt;
+use iroha_config::parameters::actual::ToriiUnixSocket as Config;
+use iroha_futures::supervisor::ShutdownSignal;
+use tokio::net::UnixListener;
+use tokio_util::task::TaskTracker;
+
+use crate::{connection::serve_connection, Error};
+
+/// Serve `router` on the Unix domain socket until the shutdown signal is received.
+pub(crate) async fn serve(
+    config: Config,
+    router: axum::Router,
+    shutdown_signal: ShutdownSignal,
+) -> error_stack::Result<(), Error> {
+    let listener = bind(&config)
+        .change_context(Error::StartServer)
+        .attach_printable_lazy(|| {
+            format!(
+                "failed to bind to the Unix socket {}",
+                config.path.display()
+            )
+        })?;
+    let connections = TaskTracker::new();
+
+    loop {
+        let stream = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => stream,
+                Err(error) => {
+                    iroha_logger::warn!(%error, "Failed to accept a Unix socket connection");
+                    continue;
+                }
+            },
+            () = shutdown_signal.receive() => break,
+        };
+
+        let router = router.clone();
+        let shutdown_signal = shutdown_signal.clone();
+        connections.spawn(async move {
+            if let Err(error) = serve_connection(stream, router, shutdown_signal).await {
+                iroha_logger::debug!(%error, "Failed to serve a Unix socket connection");
+            }
+        });
+    }
+
+    connections.close();
+    connections.wait().await;
+    if let Err(error) = fs::remove_file(&config.path) {
+        iroha_logger::warn!(%error, path = %config.path.display(), "Failed to remove the Unix socket");
+    }
+
+    Ok(())
+}
+
+fn bind(config: &Config) -> io::Result<UnixListener> {
+    match fs::symlink_metadata(&config.path) {
+        // Left by a previous run which didn't shut down gracefully
+        Ok(metadata) if metadata.file_type().is_socket() => fs::remove_file(&config.path)?,
+        Ok(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "the path is occupied by a file which isn't a socket",
+            ))
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => return Err(error),
+    }
+
+    // Bind under a temporary name and expose the socket only once its permissions are set,
+    // so that nobody can connect while it has the default ones
+    let mut temporary_name = config.path.as_os_str().to_owned();
+    temporary_name.push(format!(".{}.tmp", std::process::id()));
+    let _ = fs::remove_file(&temporary_name);
+    let listener = UnixListener::bind(&temporary_name)?;
+    fs::set_permissions(&temporary_name, fs::Permissions::from_mode(config.mode))?;
+    fs::rename(&temporary_name, &config.path)?;
+
+    Ok(listener)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn config(path: PathBuf) -> Config {
+        Config { path, mode: 0o600 }
+    }
+
+    #[tokio::test]
+    async fn socket_is_created_with_configured_permissions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("torii.sock");
+        // A socket left by a previous run is replaced
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+        let _listener = bind(&config(path.clone())).unwrap();
+
+        let metadata = fs::symlink_metadata(&path).unwrap();
+        assert!(metadata.file_type().is_socket());
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+    }
+
+    #[tokio::test]
+    async fn regular_file_is_not_replaced() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("torii.sock");
+        fs::write(&path, "data").unwrap();
+
+        let error = bind(&config(path.clone())).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "data");
+    }
+}
@@ -1,7 +1,11 @@
 use axum::{
-    http::{header::CONTENT_TYPE, HeaderValue},
+    http::{
+        header::{CONTENT_TYPE, ETAG},
+        HeaderValue, StatusCode,
+    },
     response::{IntoResponse, Response},
 };
+use iroha_crypto::Hash;
 use iroha_version::prelude::*;
 
 /// MIME used in Torii for SCALE encoding
@@ -23,6 +27,36 @@ impl<T: Encode + Send> IntoResponse for Scale<T> {
     }
 }
 
+/// Reply with SCALE encoded immutable data tagged with an `ETag`, or with `304 Not Modified`
+/// if the `If-None-Match` header of the request shows that the client already has it
+pub fn conditional_scale<T: Encode + Send>(
+    if_none_match: Option<&HeaderValue>,
+    etag: Hash,
+    body: T,
+) -> Response {
+    let etag = format!("\"{etag}\"");
+    let mut res = if if_none_match.is_some_and(|value| etag_matches(value, &etag)) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        Scale(body).into_response()
+    };
+    res.headers_mut().insert(
+        ETAG,
+        HeaderValue::from_str(&etag).expect("hex encoded hash is a valid header value"),
+    );
+    res
+}
+
+/// Check if any of the entity tags listed in `If-None-Match` is `etag`
+fn etag_matches(if_none_match: &HeaderValue, etag: &str) -> bool {
+    if_none_match.to_str().is_ok_and(|value| {
+        value
+            .split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+    })
+}
+
 pub mod extractors {
     use axum::{
         async_trait,
@@ -30,6 +64,7 @@ pub mod extractors {
         extract::{FromRequest, FromRequestParts, Request},
         http::StatusCode,
     };
+    use iroha_torii_shared::compression::{self, Compression};
 
     use super::*;
 
@@ -86,4 +121,55 @@ pub mod extractors {
                 .ok_or((StatusCode::BAD_REQUEST, "`Accept` header is missing"))
         }
     }
+
+    /// Extractor of the stream compression requested by the client, see [`compression`]
+    #[derive(Clone, Copy, Debug)]
+    pub struct ExtractStreamCompression(pub Option<Compression>);
+
+    #[async_trait]
+    impl<S> FromRequestParts<S> for ExtractStreamCompression
+    where
+        S: Send + Sync,
+    {
+        type Rejection = Response;
+
+        async fn from_request_parts(
+            parts: &mut axum::http::request::Parts,
+            _state: &S,
+        ) -> Result<Self, Self::Rejection> {
+            let Some(value) = parts.headers.get(compression::HEADER) else {
+                return Ok(Self(None));
+            };
+
+            value
+                .to_str()
+                .map_err(|err| err.to_string())
+                .and_then(|value| value.parse::<Compression>().map_err(|err| err.to_string()))
+                .map(|compression| Self(Some(compression)))
+                .map_err(|err| (StatusCode::BAD_REQUEST, err).into_response())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn etag_matches_any_listed_tag() {
+        let etag = "\"abcd\"";
+
+        for matching in ["\"abcd\"", "W/\"abcd\"", "\"1234\", \"abcd\"", "*"] {
+            assert!(
+                etag_matches(&HeaderValue::from_static(matching), etag),
+                "{matching}"
+            );
+        }
+        for other in ["\"1234\"", "abcd", ""] {
+            assert!(
+                !etag_matches(&HeaderValue::from_static(other), etag),
+                "{other}"
+            );
+        }
+    }
 }
@@ -1,4 +1,7 @@
 //! Constant values used in Torii that might be re-used by client libraries as well.
+use std::collections::BTreeMap;
+
+use iroha_primitives::json::Json;
 use serde::{Deserialize, Serialize};
 
 pub mod uri {
@@ -6,16 +9,39 @@ pub mod uri {
 
     /// Query URI is used to handle incoming Query requests.
     pub const QUERY: &str = "/query";
+    /// Read token URI is used to execute queries shared as read tokens.
+    pub const READ_TOKEN: &str = "/query/token";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "/transaction";
+    /// URI for checking whether the executor would accept a transaction, without submitting it.
+    pub const TRANSACTION_VALIDATE: &str = "/transaction/validate";
+    /// URI for previewing the changes a transaction would make, without submitting it.
+    pub const TRANSACTION_DRY_RUN: &str = "/transaction/dry_run";
+    /// URI for trying out an executor upgrade transaction without committing it.
+    pub const EXECUTOR_DRY_RUN: &str = "/executor/dry_run";
+    /// URI for getting the schema of permission tokens supported by the installed executor.
+    pub const EXECUTOR_SCHEMA: &str = "/executor/schema";
     /// Health URI is used to handle incoming Healthcheck requests.
     pub const HEALTH: &str = "/health";
     /// Peers URI is used to find all peers in the network
     pub const PEERS: &str = "/peers";
+    /// URI for getting the traffic exchanged with each of the connected peers
+    pub const PEERS_TRAFFIC: &str = "/peers/traffic";
     /// The web socket uri used to subscribe to block and transactions statuses.
     pub const SUBSCRIPTION: &str = "/events";
     /// The web socket uri used to subscribe to blocks stream.
     pub const BLOCKS_STREAM: &str = "/block/stream";
+    /// URI for fetching SCALE encoded committed blocks, either `/blocks/{height}` or a range
+    /// with `/blocks?from={height}&to={height}`.
+    pub const BLOCKS: &str = "/blocks";
+    /// URI suffix for fetching the proof of inclusion of a block into the chain committed to
+    /// by the latest block, `/blocks/{height}/proof`, or by an earlier one with `?at={height}`.
+    pub const BLOCK_PROOF: &str = "/proof";
+    /// URI prefix for account resources.
+    pub const ACCOUNTS: &str = "/accounts";
+    /// URI suffix for fetching the SCALE encoded history of an account with proofs,
+    /// `/accounts/{id}/history`, optionally bounded with `?from={height}&to={height}`.
+    pub const ACCOUNT_HISTORY: &str = "/history";
     /// The URI for local config changing inspecting
     pub const CONFIGURATION: &str = "/configuration";
     /// URI to report status for administration
@@ -31,6 +57,10 @@ pub mod uri {
     pub const PROFILE: &str = "/debug/pprof/profile";
     /// URI for getting the server version
     pub const SERVER_VERSION: &str = "/server_version";
+    /// URI for getting the results of the peer startup phases
+    pub const STARTUP: &str = "/startup";
+    /// URI for requesting the peer to drain and shut down gracefully
+    pub const DRAIN: &str = "/drain";
 }
 
 /// Response body for GET server version request
@@ -41,3 +71,260 @@ pub struct Version {
     /// The git commit SHA
     pub git_sha: String,
 }
+
+/// Response body for GET executor schema request
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExecutorSchema {
+    /// Permission tokens supported by the installed executor, by name
+    pub permissions: BTreeMap<String, PermissionSchema>,
+    /// Schema of all types defined by the executor, the payload schemas refer to them
+    pub types: Json,
+}
+
+/// Schema of a permission token supported by the executor
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PermissionSchema {
+    /// Version of the token, the hash of its canonical schema.
+    /// It changes whenever an executor upgrade changes the token definition.
+    pub version: String,
+    /// Definition of the token type, `null` for tokens without payload
+    pub schema: Json,
+}
+
+/// Traffic exchanged with a connected peer over its current connection,
+/// item of the response body for GET peers traffic request
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PeerTraffic {
+    /// Public key of the peer
+    pub peer: String,
+    /// Bytes sent to the peer
+    pub bytes_sent: u64,
+    /// Bytes received from the peer
+    pub bytes_received: u64,
+    /// Messages sent to the peer
+    pub messages_sent: u64,
+    /// Messages received from the peer
+    pub messages_received: u64,
+}
+
+/// Response body for GET startup report request
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StartupReport {
+    /// Config files merged into the configuration, in the order they were applied.
+    /// Environment variables are applied on top of them.
+    pub config_sources: Vec<String>,
+    /// Number of blocks found by Kura in the block store
+    pub kura_block_count: usize,
+    /// Outcome of loading the state snapshot
+    pub snapshot: SnapshotOutcome,
+    /// Outcome of the genesis phase
+    pub genesis: GenesisOutcome,
+}
+
+/// Outcome of loading the state snapshot at startup
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SnapshotOutcome {
+    /// The state was loaded from the snapshot
+    Loaded {
+        /// Height of the state stored in the snapshot
+        height: usize,
+        /// Time spent loading the snapshot, in milliseconds
+        load_time_ms: u64,
+    },
+    /// There was no snapshot, the state was created empty
+    NotFound,
+    /// The snapshot was found but couldn't be loaded, the state was created empty
+    Failed {
+        /// Reason of the failure
+        error: String,
+        /// Time spent before the failure, in milliseconds
+        load_time_ms: u64,
+    },
+}
+
+/// Outcome of the genesis phase at startup
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum GenesisOutcome {
+    /// The genesis block from the configuration is applied, since the block store was empty
+    Applied {
+        /// Hash of the genesis block
+        hash: String,
+    },
+    /// The block store already contains the genesis block, the configured one (if any) is ignored
+    Skipped {
+        /// Hash of the genesis block found in the block store
+        hash: String,
+    },
+    /// The block store is empty and no genesis is configured, the genesis is received from peers
+    AwaitingFromPeers,
+}
+
+/// Phase of a peer draining before shutdown, response body for POST drain request.
+///
+/// Phases are passed in order, and each of them is logged as `Drain phase: {phase}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DrainPhase {
+    /// New transactions are rejected, while those in the queue are still committed
+    RejectingTransactions,
+    /// The queue is empty or the drain timed out, the peer shuts down,
+    /// flushing blocks to the storage and saving the snapshot
+    ShuttingDown,
+    /// All the peer services are stopped, the process is about to exit
+    Stopped,
+}
+
+impl DrainPhase {
+    /// All the phases in the order they are passed
+    pub const ALL: [Self; 3] = [
+        Self::RejectingTransactions,
+        Self::ShuttingDown,
+        Self::Stopped,
+    ];
+}
+
+impl std::fmt::Display for DrainPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::RejectingTransactions => "rejecting_transactions",
+            Self::ShuttingDown => "shutting_down",
+            Self::Stopped => "stopped",
+        })
+    }
+}
+
+pub mod compression {
+    //! Compression of messages sent by Torii over event and block streams.
+    //!
+    //! Compression is requested by the client with the [`HEADER`] header when opening a stream.
+    //! Every binary message Torii sends over the stream is then compressed as a whole, while
+    //! messages sent by the client stay uncompressed.
+
+    use std::{
+        fmt,
+        io::{self, Read as _, Write as _},
+        str::FromStr,
+    };
+
+    /// Header with which the client requests compression of a stream
+    pub const HEADER: &str = "x-iroha-stream-compression";
+
+    /// Compression algorithm applied to stream messages
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Compression {
+        /// Raw DEFLATE, as used by `permessage-deflate`
+        Deflate,
+        /// Gzip
+        Gzip,
+        /// Zstandard
+        Zstd,
+    }
+
+    impl Compression {
+        /// Compress a single stream message.
+        ///
+        /// # Errors
+        /// If the encoder fails
+        pub fn compress(self, message: &[u8]) -> io::Result<Vec<u8>> {
+            match self {
+                Self::Deflate => {
+                    let mut encoder = flate2::write::DeflateEncoder::new(
+                        Vec::new(),
+                        flate2::Compression::default(),
+                    );
+                    encoder.write_all(message)?;
+                    encoder.finish()
+                }
+                Self::Gzip => {
+                    let mut encoder =
+                        flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                    encoder.write_all(message)?;
+                    encoder.finish()
+                }
+                Self::Zstd => zstd::encode_all(message, 0),
+            }
+        }
+
+        /// Decompress a single stream message.
+        ///
+        /// # Errors
+        /// If the message is not compressed with this algorithm
+        pub fn decompress(self, message: &[u8]) -> io::Result<Vec<u8>> {
+            let mut decompressed = Vec::new();
+            match self {
+                Self::Deflate => {
+                    flate2::read::DeflateDecoder::new(message).read_to_end(&mut decompressed)?;
+                }
+                Self::Gzip => {
+                    flate2::read::GzDecoder::new(message).read_to_end(&mut decompressed)?;
+                }
+                Self::Zstd => decompressed = zstd::decode_all(message)?,
+            }
+            Ok(decompressed)
+        }
+    }
+
+    impl fmt::Display for Compression {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(match self {
+                Self::Deflate => "deflate",
+                Self::Gzip => "gzip",
+                Self::Zstd => "zstd",
+            })
+        }
+    }
+
+    /// Error returned when parsing an unsupported [`Compression`]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct UnsupportedCompression(pub String);
+
+    impl fmt::Display for UnsupportedCompression {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "unsupported stream compression `{}`, expected one of: deflate, gzip, zstd",
+                self.0
+            )
+        }
+    }
+
+    impl std::error::Error for UnsupportedCompression {}
+
+    impl FromStr for Compression {
+        type Err = UnsupportedCompression;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.trim().to_ascii_lowercase().as_str() {
+                "deflate" => Ok(Self::Deflate),
+                "gzip" => Ok(Self::Gzip),
+                "zstd" => Ok(Self::Zstd),
+                _ => Err(UnsupportedCompression(s.to_owned())),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn roundtrip() {
+            let message = b"block block block block block block block block".repeat(16);
+
+            for compression in [Compression::Deflate, Compression::Gzip, Compression::Zstd] {
+                let compressed = compression.compress(&message).unwrap();
+                assert!(compressed.len() < message.len());
+                assert_eq!(compression.decompress(&compressed).unwrap(), message);
+                assert_eq!(compression.to_string().parse(), Ok(compression));
+            }
+        }
+
+        #[test]
+        fn unsupported() {
+            assert!("brotli".parse::<Compression>().is_err());
+            assert_eq!(" ZSTD ".parse(), Ok(Compression::Zstd));
+        }
+    }
+}
@@ -92,10 +92,26 @@ pub fn encode_into_memory<T: Encode>(
     obj: &T,
     memory: &wasmtime::Memory,
     alloc_fn: &wasmtime::TypedFunc<WasmUsize, WasmUsize>,
-    mut context: impl wasmtime::AsContextMut,
+    context: impl wasmtime::AsContextMut,
 ) -> Result<WasmUsize> {
-    let bytes = encode_with_length_prefix(obj);
+    write_into_memory(&encode_with_length_prefix(obj), memory, alloc_fn, context)
+}
 
+/// Write `bytes` encoded with [`encode_with_length_prefix`] to the given `memory`
+/// with the given `alloc_fn` and `context`.
+///
+/// Returns the offset of the written bytes.
+///
+/// # Errors
+///
+/// - If failed to call `alloc_fn`
+/// - If failed to write into the `memory`
+pub fn write_into_memory(
+    bytes: &[u8],
+    memory: &wasmtime::Memory,
+    alloc_fn: &wasmtime::TypedFunc<WasmUsize, WasmUsize>,
+    mut context: impl wasmtime::AsContextMut,
+) -> Result<WasmUsize> {
     let len = bytes
         .len()
         .try_into()
@@ -106,7 +122,7 @@ pub fn encode_into_memory<T: Encode>(
         .try_into()
         .expect("`u32` should always fit in `usize`");
 
-    memory.write(&mut context, offset_usize, &bytes)?;
+    memory.write(&mut context, offset_usize, bytes)?;
 
     Ok(offset)
 }
@@ -0,0 +1,274 @@
+//! Remote providers of the peer configuration.
+//!
+//! Each provider serves a TOML document which is merged into the configuration as one more
+//! layer, the same way the files listed in `extends` are.
+
+use std::{fmt, path::PathBuf, str::FromStr, time::Duration};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use error_stack::{Report, Result, ResultExt};
+use iroha_config::base::toml::TomlSource;
+use serde::Deserialize;
+use thiserror::Error;
+use url::Url;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Remote provider of a configuration layer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProvider {
+    url: Url,
+    kind: ProviderKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProviderKind {
+    /// Any endpoint responding to `GET` with the TOML document
+    Http,
+    /// Consul KV store, the document is the value of the key
+    Consul { tls: bool },
+    /// etcd KV store accessed with the v3 JSON API, the document is the value of the key
+    Etcd { tls: bool },
+}
+
+/// Error of parsing [`ConfigProvider`] from a URL
+#[derive(Debug, Error)]
+#[allow(missing_docs)]
+pub enum ParseError {
+    #[error(transparent)]
+    Url(#[from] url::ParseError),
+    #[error("unsupported scheme `{0}`, expected one of: http, https, consul, consul+https, etcd, etcd+https")]
+    UnsupportedScheme(String),
+    #[error("host of the KV store is missing")]
+    MissingHost,
+    #[error("key of the KV store is missing")]
+    MissingKey,
+}
+
+/// Error of fetching the configuration from a [`ConfigProvider`]
+#[derive(Debug, Error, Copy, Clone)]
+#[allow(missing_docs)]
+pub enum FetchError {
+    #[error("Failed to request the configuration provider")]
+    Request,
+    #[error("The configuration provider doesn't have the document")]
+    NotFound,
+    #[error("Failed to decode the response of the configuration provider")]
+    Decode,
+}
+
+impl FromStr for ConfigProvider {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let url: Url = s.parse()?;
+        let kind = match url.scheme() {
+            "http" | "https" => ProviderKind::Http,
+            "consul" => ProviderKind::Consul { tls: false },
+            "consul+https" => ProviderKind::Consul { tls: true },
+            "etcd" => ProviderKind::Etcd { tls: false },
+            "etcd+https" => ProviderKind::Etcd { tls: true },
+            other => return Err(ParseError::UnsupportedScheme(other.to_owned())),
+        };
+        let provider = Self { url, kind };
+
+        if !matches!(kind, ProviderKind::Http) {
+            if provider.url.host_str().is_none() {
+                return Err(ParseError::MissingHost);
+            }
+            if provider.key().is_empty() {
+                return Err(ParseError::MissingKey);
+            }
+        }
+
+        Ok(provider)
+    }
+}
+
+impl fmt::Display for ConfigProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
+
+impl ConfigProvider {
+    /// Fetch the configuration layer.
+    ///
+    /// # Errors
+    /// - If the provider is unreachable or responds with an error
+    /// - If the document is missing or isn't a valid TOML
+    pub fn fetch(&self) -> Result<TomlSource, FetchError> {
+        let raw = match self.kind {
+            ProviderKind::Http => Self::get(self.url.as_str())?,
+            ProviderKind::Consul { tls } => {
+                Self::get(&format!("{}/v1/kv/{}?raw", self.store_url(tls), self.key()))?
+            }
+            ProviderKind::Etcd { tls } => self.etcd_range(tls)?,
+        };
+        let table = raw
+            .parse::<toml::Table>()
+            .change_context(FetchError::Decode)
+            .attach_printable("expected a TOML document")?;
+
+        Ok(TomlSource::new(PathBuf::from(self.to_string()), table))
+    }
+
+    fn get(url: &str) -> Result<String, FetchError> {
+        let response = attohttpc::get(url)
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .change_context(FetchError::Request)?;
+
+        if response.status() == attohttpc::StatusCode::NOT_FOUND {
+            return Err(Report::new(FetchError::NotFound));
+        }
+        if !response.is_success() {
+            return Err(Report::new(FetchError::Request)
+                .attach_printable(format!("response status: {}", response.status())));
+        }
+
+        response.text().change_context(FetchError::Decode)
+    }
+
+    fn etcd_range(&self, tls: bool) -> Result<String, FetchError> {
+        #[derive(Deserialize)]
+        struct RangeResponse {
+            #[serde(default)]
+            kvs: Vec<KeyValue>,
+        }
+
+        #[derive(Deserialize)]
+        struct KeyValue {
+            value: String,
+        }
+
+        let request = serde_json::json!({ "key": BASE64.encode(self.key()) });
+        let response = attohttpc::post(format!("{}/v3/kv/range", self.store_url(tls)))
+            .timeout(REQUEST_TIMEOUT)
+            .header(attohttpc::header::CONTENT_TYPE, "application/json")
+            .text(request.to_string())
+            .send()
+            .change_context(FetchError::Request)?;
+
+        if !response.is_success() {
+            return Err(Report::new(FetchError::Request)
+                .attach_printable(format!("response status: {}", response.status())));
+        }
+
+        let range: RangeResponse = response
+            .text()
+            .change_context(FetchError::Decode)
+            .and_then(|text| serde_json::from_str(&text).change_context(FetchError::Decode))?;
+        let value = range
+            .kvs
+            .into_iter()
+            .next()
+            .ok_or_else(|| Report::new(FetchError::NotFound))?
+            .value;
+        let value = BASE64.decode(value).change_context(FetchError::Decode)?;
+
+        String::from_utf8(value).change_context(FetchError::Decode)
+    }
+
+    /// Base URL of the KV store API
+    fn store_url(&self, tls: bool) -> String {
+        let scheme = if tls { "https" } else { "http" };
+        let host = self.url.host_str().expect("checked when parsed");
+        match self.url.port() {
+            Some(port) => format!("{scheme}://{host}:{port}"),
+            None => format!("{scheme}://{host}"),
+        }
+    }
+
+    /// Key of the document in the KV store
+    fn key(&self) -> &str {
+        self.url.path().trim_matches('/')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead as _, BufReader, Write as _},
+        net::TcpListener,
+    };
+
+    use iroha_config::base::ParameterId;
+
+    use super::*;
+
+    /// Serve a single HTTP request with the given response body, returning the request line
+    fn serve_once(body: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request_line = String::new();
+            BufReader::new(&stream)
+                .read_line(&mut request_line)
+                .unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request_line
+        });
+
+        (address.to_string(), handle)
+    }
+
+    #[test]
+    fn parse_providers() {
+        let provider: ConfigProvider = "https://config.example/peer-1.toml".parse().unwrap();
+        assert_eq!(provider.kind, ProviderKind::Http);
+
+        let provider: ConfigProvider = "consul://consul:8500/iroha/peer-1".parse().unwrap();
+        assert_eq!(provider.kind, ProviderKind::Consul { tls: false });
+        assert_eq!(provider.key(), "iroha/peer-1");
+        assert_eq!(provider.store_url(false), "http://consul:8500");
+
+        let provider: ConfigProvider = "etcd+https://etcd/iroha/peer-1".parse().unwrap();
+        assert_eq!(provider.kind, ProviderKind::Etcd { tls: true });
+        assert_eq!(provider.store_url(true), "https://etcd");
+
+        assert!(matches!(
+            "ftp://config.example/peer-1.toml".parse::<ConfigProvider>(),
+            Err(ParseError::UnsupportedScheme(_))
+        ));
+        assert!(matches!(
+            "consul://consul:8500/".parse::<ConfigProvider>(),
+            Err(ParseError::MissingKey)
+        ));
+    }
+
+    #[test]
+    fn fetch_from_consul() {
+        let (address, server) = serve_once("[logger]\nlevel = \"DEBUG\"\n");
+        let provider: ConfigProvider = format!("consul://{address}/iroha/peer-1").parse().unwrap();
+
+        let source = provider.fetch().unwrap();
+
+        assert_eq!(
+            source.fetch(&ParameterId::from(["logger", "level"])),
+            Some(toml::Value::String("DEBUG".to_owned()))
+        );
+        assert_eq!(source.path(), &PathBuf::from(provider.to_string()));
+        assert!(server
+            .join()
+            .unwrap()
+            .starts_with("GET /v1/kv/iroha/peer-1?raw "));
+    }
+
+    #[test]
+    fn invalid_document_is_rejected() {
+        let (address, server) = serve_once("not = toml = at all");
+        let provider: ConfigProvider = format!("http://{address}/peer.toml").parse().unwrap();
+
+        let report = provider.fetch().unwrap_err();
+
+        assert!(matches!(report.current_context(), FetchError::Decode));
+        server.join().unwrap();
+    }
+}
@@ -3,34 +3,39 @@
 use std::{
     env,
     future::Future,
+    num::NonZeroUsize,
     path::{Path, PathBuf},
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use clap::Parser;
 use error_stack::{IntoReportCompat, Report, Result, ResultExt};
 use iroha_config::{
     base::{read::ConfigReader, util::Emitter, WithOrigin},
+    client_api::{ConfigUpdateDTO, Logger as LoggerDTO},
     parameters::{actual::Root as Config, user::Root as UserConfig},
 };
 #[cfg(feature = "telemetry")]
 use iroha_core::telemetry::StateTelemetry;
 use iroha_core::{
+    backup::{self, BackupMaker},
     block_sync::{BlockSynchronizer, BlockSynchronizerHandle},
     gossiper::{TransactionGossiper, TransactionGossiperHandle},
     kiso::KisoHandle,
-    kura::Kura,
+    kura::{BlockCount, Kura},
+    memory::MemoryAccountant,
     peers_gossiper::{PeersGossiper, PeersGossiperHandle},
+    plugin::{CommitPlugin, PluginHost},
     query::store::LiveQueryStore,
     queue::Queue,
     smartcontracts::isi::Registrable as _,
     snapshot::{try_read_snapshot, SnapshotMaker, TryReadError as TryReadSnapshotError},
-    state::{State, StateReadOnly, World},
-    sumeragi::{GenesisWithPubKey, SumeragiHandle, SumeragiStartArgs},
+    state::{export::WorldExport, State, StateReadOnly, World},
+    sumeragi::{replay_blocks, GenesisWithPubKey, SumeragiHandle, SumeragiStartArgs},
     IrohaNetwork,
 };
-use iroha_data_model::{block::SignedBlock, prelude::*};
+use iroha_data_model::prelude::*;
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal, Supervisor};
 use iroha_genesis::GenesisBlock;
 use iroha_logger::{actor::LoggerHandle, InitConfig as LoggerInitConfig};
@@ -38,13 +43,17 @@ use iroha_primitives::addr::SocketAddr;
 #[cfg(feature = "telemetry")]
 use iroha_primitives::time::TimeSource;
 use iroha_torii::Torii;
-use iroha_version::scale::DecodeVersioned;
+use iroha_torii_shared::{DrainPhase, GenesisOutcome, SnapshotOutcome, StartupReport};
 use thiserror::Error;
 use tokio::{
-    sync::{broadcast, mpsc},
+    sync::{broadcast, mpsc, watch},
     task,
 };
 
+use crate::config_provider::ConfigProvider;
+
+mod config_provider;
+
 #[allow(missing_docs)]
 pub fn is_coloring_supported() -> bool {
     supports_color::on(supports_color::Stream::Stdout).is_some()
@@ -65,6 +74,22 @@ pub struct Args {
     /// Path to the configuration file
     #[arg(long, short, value_name("PATH"), value_hint(clap::ValueHint::FilePath))]
     pub config: Option<PathBuf>,
+    /// Remote configuration provider, might be repeated.
+    ///
+    /// Providers are applied in the given order on top of the configuration file,
+    /// environment variables are applied on top of everything.
+    /// Supported URLs:
+    /// - `http(s)://HOST/PATH` responding with a TOML document
+    /// - `consul(+https)://HOST:PORT/KEY`, a TOML document stored in Consul KV
+    /// - `etcd(+https)://HOST:PORT/KEY`, a TOML document stored in etcd
+    #[arg(long = "config-provider", value_name("URL"))]
+    pub config_providers: Vec<ConfigProvider>,
+    /// Re-read the configuration periodically, e.g. `30s`.
+    ///
+    /// Changes of `logger.level` and `logger.filter` are applied on the fly,
+    /// changes of other parameters are ignored until restart.
+    #[arg(long, value_name("DURATION"), value_parser(humantime::parse_duration))]
+    pub config_reload_interval: Option<Duration>,
     /// Enables trace logs of configuration reading & parsing.
     ///
     /// Might be useful for configuration troubleshooting.
@@ -85,6 +110,63 @@ pub struct Args {
         num_args(0..=1),
     )]
     pub terminal_colors: bool,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Commands to run instead of starting the peer
+#[derive(clap::Subcommand, Debug)]
+pub enum Command {
+    /// Export the world state at a certain height for external audit.
+    ///
+    /// The state is restored from the block store (and a snapshot, if present)
+    /// of the configured peer, which must not be running at the moment.
+    ExportState(ExportStateArgs),
+    /// Restore the block store and the state snapshot of the configured peer from a backup.
+    ///
+    /// The backup is verified against its manifest before anything is copied.
+    /// The peer must not be running at the moment.
+    RestoreBackup(RestoreBackupArgs),
+}
+
+/// Arguments of [`Command::ExportState`]
+#[derive(clap::Args, Debug)]
+pub struct ExportStateArgs {
+    /// Path to the output file
+    #[arg(long, short, value_name("PATH"), value_hint(clap::ValueHint::FilePath))]
+    pub out_file: PathBuf,
+    /// Height of the block to export the state at. Defaults to the latest block
+    #[arg(long)]
+    pub height: Option<usize>,
+    /// Format of the output file
+    #[arg(long, value_enum, default_value_t)]
+    pub format: ExportFormat,
+}
+
+/// Arguments of [`Command::RestoreBackup`]
+#[derive(clap::Args, Debug)]
+pub struct RestoreBackupArgs {
+    /// Path to the backup directory.
+    /// Defaults to the most recent backup in the configured backup directory
+    #[arg(long, value_name("PATH"), value_hint(clap::ValueHint::DirPath))]
+    pub from: Option<PathBuf>,
+    /// Download the most recent backup from the configured object store
+    /// into the configured backup directory and restore it
+    #[arg(long, conflicts_with("from"))]
+    pub from_s3: bool,
+    /// Overwrite the existing block store and snapshot
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Format of the exported state, see [`WorldExport`]
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default)]
+pub enum ExportFormat {
+    /// Human-readable JSON
+    #[default]
+    Json,
+    /// Compact binary CBOR (RFC 8949)
+    Cbor,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -99,6 +181,10 @@ enum MainError {
     IrohaStart,
     #[error("Error occured while running Iroha")]
     IrohaRun,
+    #[error("Failed to export the state")]
+    ExportState,
+    #[error("Failed to restore from a backup")]
+    RestoreBackup,
 }
 
 const EVENTS_BUFFER_CAPACITY: usize = 10_000;
@@ -154,7 +240,8 @@ impl NetworkRelay {
 
         match msg {
             SumeragiBlock(data) => {
-                self.sumeragi.incoming_block_message(*data);
+                self.sumeragi
+                    .incoming_block_message(*data, Some(peer.id().clone()));
             }
             SumeragiControlFlow(data) => {
                 self.sumeragi.incoming_control_flow_message(*data);
@@ -180,6 +267,8 @@ impl Iroha {
     #[iroha_logger::log(name = "start", skip_all)] // This is actually easier to understand as a linear sequence of init statements.
     pub async fn start(
         config: Config,
+        config_sources: Vec<PathBuf>,
+        config_reload: Option<ConfigReload>,
         genesis: Option<GenesisBlock>,
         logger: LoggerHandle,
         shutdown_signal: ShutdownSignal,
@@ -207,30 +296,47 @@ impl Iroha {
             (metrics.clone(), StateTelemetry::new(metrics))
         };
 
-        let state = match try_read_snapshot(
+        let snapshot_load_started = Instant::now();
+        let snapshot_load_time_ms = || {
+            snapshot_load_started
+                .elapsed()
+                .as_millis()
+                .try_into()
+                .unwrap_or(u64::MAX)
+        };
+        let (state, snapshot) = match try_read_snapshot(
             config.snapshot.store_dir.resolve_relative_path(),
             &kura,
             || live_query_store.clone(),
             block_count,
             #[cfg(feature = "telemetry")]
-            state_telemetry.clone()
+            state_telemetry.clone(),
         ) {
             Ok(state) => {
                 iroha_logger::info!(
                     at_height = state.view().height(),
                     "Successfully loaded the state from a snapshot"
                 );
-                Some(state)
+                let snapshot = SnapshotOutcome::Loaded {
+                    height: state.view().height(),
+                    load_time_ms: snapshot_load_time_ms(),
+                };
+                (Some(state), snapshot)
             }
             Err(TryReadSnapshotError::NotFound) => {
                 iroha_logger::info!("Didn't find a state snapshot; creating an empty state");
-                None
+                (None, SnapshotOutcome::NotFound)
             }
             Err(error) => {
                 iroha_logger::warn!(%error, "Failed to load the state from a snapshot; creating an empty state");
-                None
+                let snapshot = SnapshotOutcome::Failed {
+                    error: error.to_string(),
+                    load_time_ms: snapshot_load_time_ms(),
+                };
+                (None, snapshot)
             }
-        }.unwrap_or_else(|| {
+        };
+        let state = state.unwrap_or_else(|| {
             let world = World::with(
                 [genesis_domain(config.genesis.public_key.clone())],
                 [genesis_account(config.genesis.public_key.clone())],
@@ -242,16 +348,27 @@ impl Iroha {
                 Arc::clone(&kura),
                 live_query_store.clone(),
                 #[cfg(feature = "telemetry")]
-                state_telemetry
+                state_telemetry,
             )
         });
-        let state = Arc::new(state);
+        let state = Arc::new(state.with_redaction_secret(config.common.redaction_secret.clone()));
+
+        let startup = StartupReport {
+            config_sources: config_sources
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect(),
+            kura_block_count: block_count.0,
+            snapshot,
+            genesis: genesis_outcome(&kura, block_count, genesis.as_ref()),
+        };
 
         let (events_sender, _) = broadcast::channel(EVENTS_BUFFER_CAPACITY);
         let queue = Arc::new(Queue::from_config(config.queue, events_sender.clone()));
 
         let (network, child) = IrohaNetwork::start(
             config.common.key_pair.clone(),
+            config.common.chain.clone(),
             config.network.clone(),
             supervisor.shutdown_signal(),
         )
@@ -266,11 +383,12 @@ impl Iroha {
         #[cfg(feature = "telemetry")]
         let telemetry = {
             let (metrics_reporter, child) = iroha_core::telemetry::start(
-                metrics,
+                metrics.clone(),
                 Arc::clone(&state),
                 kura.clone(),
                 queue.clone(),
                 network.online_peers_receiver(),
+                network.peers_traffic_receiver(),
                 TimeSource::new_system(),
             );
             supervisor.monitor(child);
@@ -333,9 +451,44 @@ impl Iroha {
             supervisor.monitor(snapshot_maker.start(supervisor.shutdown_signal()));
         }
 
+        if let Some(backup_maker) =
+            BackupMaker::from_config(&config.backup, Arc::clone(&state), Arc::clone(&kura))
+        {
+            supervisor.monitor(backup_maker.start(supervisor.shutdown_signal()));
+        }
+
+        supervisor.monitor(
+            MemoryAccountant::from_config(
+                &config.memory,
+                Arc::clone(&state),
+                Arc::clone(&queue),
+                live_query_store.clone(),
+                events_sender.clone(),
+                #[cfg(feature = "telemetry")]
+                metrics,
+            )
+            .start(supervisor.shutdown_signal()),
+        );
+
+        if let Some(plugin_host) = PluginHost::new(
+            commit_plugins(),
+            Arc::clone(&state),
+            Arc::clone(&kura),
+            &events_sender,
+        ) {
+            supervisor.monitor(plugin_host.start(supervisor.shutdown_signal()));
+        }
+
         let (kiso, child) = KisoHandle::start(config.clone());
         supervisor.monitor(child);
 
+        let (drain, _) = watch::channel(None);
+        supervisor.monitor(tokio::task::spawn(drain_relay(
+            drain.clone(),
+            queue.clone(),
+            supervisor.shutdown_signal(),
+        )));
+
         let torii_run = Torii::new(
             config.common.chain.clone(),
             kiso.clone(),
@@ -346,6 +499,9 @@ impl Iroha {
             kura.clone(),
             state.clone(),
             iroha_torii::OnlinePeersProvider::new(network.online_peers_receiver()),
+            network.peers_traffic_receiver(),
+            startup,
+            drain.clone(),
             #[cfg(feature = "telemetry")]
             telemetry,
         )
@@ -373,6 +529,9 @@ impl Iroha {
             .run(),
         ));
 
+        if let Some(config_reload) = config_reload {
+            supervisor.monitor(tokio::task::spawn(config_reload.run(kiso.clone())));
+        }
         supervisor.monitor(tokio::task::spawn(config_updates_relay(kiso, logger)));
 
         supervisor
@@ -383,6 +542,9 @@ impl Iroha {
 
         Ok((Self { kura, state }, async move {
             supervisor.start().await?;
+            if drain.borrow().is_some() {
+                log_drain_phase(DrainPhase::Stopped);
+            }
             iroha_logger::info!("Iroha shutdown normally");
             Ok(())
         }))
@@ -449,6 +611,15 @@ async fn start_telemetry(
 /// Spawns a task which subscribes on updates from the configuration actor
 /// and broadcasts them further to interested actors. This way, neither the config actor nor other ones know
 /// about each other, achieving loose coupling of code and system.
+/// Commit plugins compiled into this binary, see [`CommitPlugin`]
+fn commit_plugins() -> Vec<Box<dyn CommitPlugin>> {
+    #[allow(unused_mut)]
+    let mut plugins: Vec<Box<dyn CommitPlugin>> = Vec::new();
+    #[cfg(feature = "log-committed-blocks")]
+    plugins.push(Box::new(iroha_core::plugin::LogCommittedBlocks));
+    plugins
+}
+
 async fn config_updates_relay(kiso: KisoHandle, logger: LoggerHandle) {
     let mut log_level_update = kiso
         .subscribe_on_logger_updates()
@@ -475,6 +646,141 @@ async fn config_updates_relay(kiso: KisoHandle, logger: LoggerHandle) {
     }
 }
 
+/// How long a draining peer waits for the transactions in its queue to be committed
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(60);
+/// How often a draining peer checks whether its queue is empty
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The phases are logged in this format so that they can be followed from the peer output
+fn log_drain_phase(phase: DrainPhase) {
+    iroha_logger::info!("Drain phase: {phase}");
+}
+
+/// Waits for a drain request from Torii, then stops accepting transactions,
+/// lets those already in the queue be committed and shuts the peer down.
+///
+/// Blocks and the final snapshot are flushed by Kura and the snapshot maker
+/// as part of the regular shutdown.
+async fn drain_relay(
+    drain: watch::Sender<Option<DrainPhase>>,
+    queue: Arc<Queue>,
+    shutdown_signal: ShutdownSignal,
+) {
+    let mut requested = drain.subscribe();
+    if requested.wait_for(Option::is_some).await.is_err() {
+        return;
+    }
+    queue.close();
+    log_drain_phase(DrainPhase::RejectingTransactions);
+
+    let flushed = tokio::time::timeout(DRAIN_TIMEOUT, async {
+        while queue.tx_len() > 0 {
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+    })
+    .await;
+    if flushed.is_err() {
+        iroha_logger::warn!(
+            remaining = queue.tx_len(),
+            "Drain timed out, shutting down with transactions left in the queue"
+        );
+    }
+
+    drain.send_replace(Some(DrainPhase::ShuttingDown));
+    log_drain_phase(DrainPhase::ShuttingDown);
+    shutdown_signal.send();
+}
+
+/// Periodic re-reading of the configuration, see [`Args::config_reload_interval`]
+pub struct ConfigReload {
+    path: Option<PathBuf>,
+    providers: Vec<ConfigProvider>,
+    interval: Duration,
+}
+
+impl ConfigReload {
+    /// Create from the CLI arguments, if the reload is enabled
+    pub fn from_args(args: &Args) -> Option<Self> {
+        args.config_reload_interval.map(|interval| Self {
+            path: args.config.clone(),
+            providers: args.config_providers.clone(),
+            interval,
+        })
+    }
+
+    async fn run(self, kiso: KisoHandle) {
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick completes immediately, while the config was just read
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let path = self.path.clone();
+            let providers = self.providers.clone();
+            let config = match task::spawn_blocking(move || {
+                read_config(path.as_deref(), &providers)
+            })
+            .await
+            {
+                Ok(Ok((config, _))) => config,
+                Ok(Err(report)) => {
+                    iroha_logger::warn!(?report, "Failed to reload the configuration");
+                    continue;
+                }
+                Err(error) => {
+                    iroha_logger::error!(%error, "Configuration reading task failed");
+                    continue;
+                }
+            };
+
+            let Ok(current) = kiso.get_dto().await else {
+                iroha_logger::debug!("Exiting the configuration reload");
+                break;
+            };
+            let reloaded = LoggerDTO::from(&config.logger);
+            if current.logger.level == reloaded.level && current.logger.filter == reloaded.filter {
+                continue;
+            }
+
+            iroha_logger::info!(
+                level = %reloaded.level,
+                "Applying the reloaded logger configuration"
+            );
+            if kiso
+                .update_with_dto(ConfigUpdateDTO { logger: reloaded })
+                .await
+                .is_err()
+            {
+                iroha_logger::debug!("Exiting the configuration reload");
+                break;
+            }
+        }
+    }
+}
+
+fn genesis_outcome(
+    kura: &Kura,
+    block_count: BlockCount,
+    genesis: Option<&GenesisBlock>,
+) -> GenesisOutcome {
+    if block_count.0 > 0 {
+        let stored_genesis = kura
+            .get_block(NonZeroUsize::MIN)
+            .expect("INTERNAL BUG: Kura reported blocks but doesn't have the first one");
+        return GenesisOutcome::Skipped {
+            hash: stored_genesis.hash().to_string(),
+        };
+    }
+
+    genesis.map_or(GenesisOutcome::AwaitingFromPeers, |genesis| {
+        GenesisOutcome::Applied {
+            hash: genesis.0.hash().to_string(),
+        }
+    })
+}
+
 fn genesis_account(public_key: PublicKey) -> Account {
     let genesis_account_id = AccountId::new(iroha_genesis::GENESIS_DOMAIN_ID.clone(), public_key);
     Account::new(genesis_account_id.clone()).build(&genesis_account_id)
@@ -491,6 +797,8 @@ fn genesis_domain(public_key: PublicKey) -> Domain {
 pub enum ConfigError {
     #[error("Error occurred while reading configuration from file(s) and environment")]
     ReadConfig,
+    #[error("Error occurred while fetching configuration from a remote provider")]
+    FetchConfig,
     #[error("Error occurred while validating configuration integrity")]
     ParseConfig,
     #[error("Error occurred while reading genesis block")]
@@ -505,33 +813,32 @@ pub enum ConfigError {
     TelemetryOutFileIsDir,
     #[error("Torii and Network addresses are the same, but should be different")]
     SameNetworkAndToriiAddrs,
+    #[error(
+        "Network public address is unspecified, but other peers should be able to connect to it"
+    )]
+    UnspecifiedPublicAddress,
     #[error("Invalid directory path found")]
     InvalidDirPath,
+    #[error(
+        "Backups can't be uploaded to an object store without `backup.store_dir`, where they are created"
+    )]
+    BackupS3WithoutStoreDir,
     #[error("Network error: cannot listen to address `{addr}`")]
     CannotBindAddress { addr: SocketAddr },
 }
 
 /// Read the configuration and then a genesis block if specified.
 ///
+/// Along with the configuration, returns the paths of the config files and the URLs of the
+/// remote providers it was merged from.
+///
 /// # Errors
 /// - If failed to read the config
 /// - If failed to load the genesis block
 pub fn read_config_and_genesis(
     args: &Args,
-) -> Result<(Config, LoggerInitConfig, Option<GenesisBlock>), ConfigError> {
-    let mut config = ConfigReader::new();
-
-    if let Some(path) = &args.config {
-        config = config
-            .read_toml_with_extends(path)
-            .change_context(ConfigError::ReadConfig)?;
-    }
-
-    let config = config
-        .read_and_complete::<UserConfig>()
-        .change_context(ConfigError::ReadConfig)?
-        .parse()
-        .change_context(ConfigError::ParseConfig)?;
+) -> Result<(Config, Vec<PathBuf>, LoggerInitConfig, Option<GenesisBlock>), ConfigError> {
+    let (config, config_sources) = read_config(args.config.as_deref(), &args.config_providers)?;
 
     let genesis = if let Some(signed_file) = &config.genesis.file {
         let genesis = read_genesis(&signed_file.resolve_relative_path())
@@ -545,14 +852,49 @@ pub fn read_config_and_genesis(
 
     let logger_config = LoggerInitConfig::new(config.logger.clone(), args.terminal_colors);
 
-    Ok((config, logger_config, genesis))
+    Ok((config, config_sources, logger_config, genesis))
+}
+
+fn read_config(
+    path: Option<&Path>,
+    providers: &[ConfigProvider],
+) -> Result<(Config, Vec<PathBuf>), ConfigError> {
+    // fetched before the reader is created, since it must not be dropped unfinished
+    let remote_sources = providers
+        .iter()
+        .map(|provider| {
+            provider
+                .fetch()
+                .attach_printable_lazy(|| format!("provider: {provider}"))
+                .change_context(ConfigError::FetchConfig)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut config = ConfigReader::new();
+
+    if let Some(path) = path {
+        config = config
+            .read_toml_with_extends(path)
+            .change_context(ConfigError::ReadConfig)?;
+    }
+    for source in remote_sources {
+        config = config.with_toml_source(source);
+    }
+    let config_sources = config.source_paths().map(Path::to_path_buf).collect();
+
+    let config = config
+        .read_and_complete::<UserConfig>()
+        .change_context(ConfigError::ReadConfig)?
+        .parse()
+        .change_context(ConfigError::ParseConfig)?;
+
+    Ok((config, config_sources))
 }
 
 fn read_genesis(path: &Path) -> Result<GenesisBlock, ConfigError> {
-    let bytes = std::fs::read(path).change_context(ConfigError::ReadGenesis)?;
-    let genesis =
-        SignedBlock::decode_all_versioned(&bytes).change_context(ConfigError::ReadGenesis)?;
-    Ok(GenesisBlock(genesis))
+    GenesisBlock::from_path(path)
+        .into_report()
+        .map_err(|report| report.change_context(ConfigError::ReadGenesis))
 }
 
 fn validate_config(config: &Config) -> Result<(), ConfigError> {
@@ -569,6 +911,11 @@ fn validate_config(config: &Config) -> Result<(), ConfigError> {
     validate_directory_path(&mut emitter, &config.kura.store_dir);
     // maybe validate only if snapshot mode is enabled
     validate_directory_path(&mut emitter, &config.snapshot.store_dir);
+    if let Some(store_dir) = &config.backup.store_dir {
+        validate_directory_path(&mut emitter, store_dir);
+    } else if config.backup.s3.is_some() {
+        emitter.emit(Report::new(ConfigError::BackupS3WithoutStoreDir));
+    }
 
     if config.genesis.file.is_none()
         && !config
@@ -585,7 +932,7 @@ fn validate_config(config: &Config) -> Result<(), ConfigError> {
         ").attach_printable(config.common.trusted_peers.clone().into_attachment().display_as_debug()));
     }
 
-    if config.network.address.value() == config.torii.address.value() {
+    if addresses_overlap(config.network.address.value(), config.torii.address.value()) {
         emitter.emit(
             Report::new(ConfigError::SameNetworkAndToriiAddrs)
                 .attach_printable(config.network.address.clone().into_attachment())
@@ -593,6 +940,14 @@ fn validate_config(config: &Config) -> Result<(), ConfigError> {
         );
     }
 
+    if config.network.public_address.value().is_unspecified() {
+        emitter.emit(
+            Report::new(ConfigError::UnspecifiedPublicAddress)
+                .attach_printable(config.network.public_address.clone().into_attachment())
+                .attach_printable("Hint: set it to an address other peers can reach this peer at"),
+        );
+    }
+
     #[cfg(not(feature = "telemetry"))]
     if config.telemetry.is_some() {
         // TODO: use a centralized configuration logging
@@ -628,6 +983,13 @@ fn validate_config(config: &Config) -> Result<(), ConfigError> {
     Ok(())
 }
 
+/// Whether listeners bound to the addresses would conflict.
+///
+/// An unspecified address, e.g. `[::]` which is dual-stack, takes the port on all interfaces.
+fn addresses_overlap(a: &SocketAddr, b: &SocketAddr) -> bool {
+    a == b || a.port() == b.port() && (a.is_unspecified() || b.is_unspecified())
+}
+
 fn validate_directory_path(emitter: &mut Emitter<ConfigError>, path: &WithOrigin<PathBuf>) {
     #[derive(Debug, Error)]
     #[error(
@@ -663,6 +1025,177 @@ fn validate_try_bind_address(emitter: &mut Emitter<ConfigError>, value: &WithOri
     }
 }
 
+/// Error of [`export_state`]
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum ExportError {
+    #[error("Unable to initialize Kura (block storage)")]
+    InitKura,
+    #[error("Requested height is above the latest block height")]
+    HeightAboveLatest,
+    #[error("Failed to write the exported state")]
+    Write,
+}
+
+/// Restore the world state at the requested height from the block store and write it to a file.
+///
+/// # Errors
+/// - If failed to read the block store
+/// - If the requested height is not yet reached
+/// - If failed to write the output file
+pub fn export_state(config: &Config, args: &ExportStateArgs) -> Result<(), ExportError> {
+    let (kura, BlockCount(block_count)) =
+        Kura::new(&config.kura).change_context(ExportError::InitKura)?;
+    let height = args.height.unwrap_or(block_count);
+    if height > block_count {
+        return Err(Report::new(ExportError::HeightAboveLatest)
+            .attach_printable(format!("requested: {height}, latest: {block_count}")));
+    }
+
+    let (live_query_store, _child) =
+        LiveQueryStore::from_config(config.live_query_store, ShutdownSignal::new()).start();
+    #[cfg(feature = "telemetry")]
+    let state_telemetry = StateTelemetry::default();
+
+    // Snapshot can only be used if it wasn't made after the requested height
+    let state = try_read_snapshot(
+        config.snapshot.store_dir.resolve_relative_path(),
+        &kura,
+        || live_query_store.clone(),
+        BlockCount(block_count),
+        #[cfg(feature = "telemetry")]
+        state_telemetry.clone(),
+    )
+    .ok()
+    .filter(|state| state.view().height() <= height)
+    .unwrap_or_else(|| {
+        let world = World::with(
+            [genesis_domain(config.genesis.public_key.clone())],
+            [genesis_account(config.genesis.public_key.clone())],
+            [],
+        );
+
+        State::new(
+            world,
+            Arc::clone(&kura),
+            live_query_store,
+            #[cfg(feature = "telemetry")]
+            state_telemetry,
+        )
+    })
+    .with_redaction_secret(config.common.redaction_secret.clone());
+
+    let (events_sender, _) = broadcast::channel(1);
+    replay_blocks(
+        &state,
+        &kura,
+        height,
+        &config.common.chain,
+        &config.genesis.public_key,
+        config
+            .common
+            .trusted_peers
+            .value()
+            .clone()
+            .into_non_empty_vec(),
+        &events_sender,
+    );
+
+    let export = WorldExport::from_state(&state.view());
+    let file = std::fs::File::create(&args.out_file)
+        .change_context(ExportError::Write)
+        .attach_printable_lazy(|| args.out_file.display().to_string())?;
+    let writer = std::io::BufWriter::new(file);
+    match args.format {
+        ExportFormat::Json => {
+            serde_json::to_writer_pretty(writer, &export).change_context(ExportError::Write)?;
+        }
+        ExportFormat::Cbor => {
+            ciborium::into_writer(&export, writer).change_context(ExportError::Write)?;
+        }
+    }
+
+    iroha_logger::info!(
+        height,
+        path = %args.out_file.display(),
+        "Exported the world state"
+    );
+
+    Ok(())
+}
+
+/// Error of [`restore_backup`]
+#[derive(Error, Debug, Copy, Clone)]
+#[allow(missing_docs)]
+pub enum RestoreError {
+    #[error("Backup directory is neither specified nor configured")]
+    NoBackupDir,
+    #[error("Failed to find the most recent backup")]
+    FindLatest,
+    #[error("Object store for backups isn't configured")]
+    NoBucket,
+    #[error("Failed to download the backup")]
+    Download,
+    #[error("Failed to restore the backup")]
+    Restore,
+}
+
+/// Restore the block store and the state snapshot from a backup.
+///
+/// # Errors
+/// - If no backup is specified and none can be found in the configured directory
+/// - If the backup can't be downloaded from the configured object store
+/// - If the backup doesn't match its manifest
+/// - If the peer already has data and `--force` isn't set
+/// - If failed to copy files
+pub fn restore_backup(config: &Config, args: &RestoreBackupArgs) -> Result<(), RestoreError> {
+    let path = match &args.from {
+        Some(path) => path.clone(),
+        None => {
+            let store_dir = config
+                .backup
+                .store_dir
+                .as_ref()
+                .ok_or_else(|| Report::new(RestoreError::NoBackupDir))?
+                .resolve_relative_path();
+            if args.from_s3 {
+                let bucket = config
+                    .backup
+                    .s3
+                    .clone()
+                    .map(backup::s3::Bucket::new)
+                    .ok_or_else(|| Report::new(RestoreError::NoBucket))?;
+                let name = bucket
+                    .latest_backup()
+                    .change_context(RestoreError::FindLatest)?;
+                bucket
+                    .download_backup(&name, &store_dir)
+                    .change_context(RestoreError::Download)
+                    .attach_printable(name)?
+            } else {
+                backup::latest_backup(&store_dir).change_context(RestoreError::FindLatest)?
+            }
+        }
+    };
+
+    let manifest = backup::restore_backup(
+        &path,
+        &config.kura.store_dir.resolve_relative_path(),
+        &config.snapshot.store_dir.resolve_relative_path(),
+        args.force,
+    )
+    .change_context(RestoreError::Restore)
+    .attach_printable_lazy(|| path.display().to_string())?;
+
+    iroha_logger::info!(
+        path = %path.display(),
+        block_count = manifest.block_count,
+        "Restored from a backup"
+    );
+
+    Ok(())
+}
+
 /// Configures globals of [`error_stack::Report`]
 fn configure_reports(args: &Args) {
     use std::panic::Location;
@@ -691,7 +1224,7 @@ async fn main() -> error_stack::Result<(), MainError> {
             .attach_printable("was enabled by `--trace-config` argument")?;
     }
 
-    let (config, logger_config, genesis) =
+    let (config, config_sources, logger_config, genesis) =
         read_config_and_genesis(&args).change_context(MainError::Config).attach_printable_lazy(|| {
             args.config.as_ref().map_or_else(
                 || "`--config` arg was not set, therefore configuration relies fully on environment variables".to_owned(),
@@ -712,6 +1245,16 @@ async fn main() -> error_stack::Result<(), MainError> {
         "Hyperledgerいろは2にようこそ！(translation) Welcome to Hyperledger Iroha!"
     );
 
+    match &args.command {
+        Some(Command::ExportState(export_args)) => {
+            return export_state(&config, export_args).change_context(MainError::ExportState);
+        }
+        Some(Command::RestoreBackup(restore_args)) => {
+            return restore_backup(&config, restore_args).change_context(MainError::RestoreBackup);
+        }
+        None => {}
+    }
+
     if genesis.is_some() {
         iroha_logger::debug!("Submitting genesis.");
     }
@@ -725,9 +1268,17 @@ async fn main() -> error_stack::Result<(), MainError> {
         default_hook(info);
     }));
 
-    let (_iroha, supervisor_fut) = Iroha::start(config, genesis, logger, shutdown_on_panic)
-        .await
-        .change_context(MainError::IrohaStart)?;
+    let config_reload = ConfigReload::from_args(&args);
+    let (_iroha, supervisor_fut) = Iroha::start(
+        config,
+        config_sources,
+        config_reload,
+        genesis,
+        logger,
+        shutdown_on_panic,
+    )
+    .await
+    .change_context(MainError::IrohaStart)?;
     supervisor_fut.await.change_context(MainError::IrohaRun)
 }
 
@@ -803,10 +1354,13 @@ mod tests {
 
             // When
 
-            let (config, _logger, genesis) = read_config_and_genesis(&Args {
+            let (config, config_sources, _logger, genesis) = read_config_and_genesis(&Args {
                 config: Some(config_path),
+                config_providers: Vec::new(),
+                config_reload_interval: None,
                 terminal_colors: false,
                 trace_config: false,
+                command: None,
             })
             .map_err(|report| eyre::eyre!("{report:?}"))?;
 
@@ -814,6 +1368,7 @@ mod tests {
 
             // No need to check whether genesis.file is resolved - if not, genesis wouldn't be read
             assert!(genesis.is_some());
+            assert_eq!(config_sources, [dir.path().join("config/config.toml")]);
 
             assert_eq!(
                 config.kura.store_dir.resolve_relative_path().absolutize()?,
@@ -857,8 +1412,11 @@ mod tests {
 
             let report = read_config_and_genesis(&Args {
                 config: Some(config_path),
+                config_providers: Vec::new(),
+                config_reload_interval: None,
                 terminal_colors: false,
                 trace_config: false,
+                command: None,
             })
             .unwrap_err();
 
@@ -869,6 +1427,45 @@ mod tests {
 
             Ok(())
         }
+
+        #[test]
+        fn fails_with_unspecified_public_address() -> eyre::Result<()> {
+            // Given
+
+            let genesis_key_pair = KeyPair::random();
+            let mut config = config_factory(genesis_key_pair.public_key());
+            iroha_config::base::toml::Writer::new(&mut config)
+                .write(["network", "address"], socket_addr!([::]:1337))
+                .write(["network", "public_address"], socket_addr!([::]:1337))
+                .write(["torii", "address"], socket_addr!(127.0.0.1:1337));
+
+            let dir = tempfile::tempdir()?;
+            std::fs::write(dir.path().join("config.toml"), toml::to_string(&config)?)?;
+            let config_path = dir.path().join("config.toml");
+
+            // When & Then
+
+            let report = read_config_and_genesis(&Args {
+                config: Some(config_path),
+                config_providers: Vec::new(),
+                config_reload_interval: None,
+                terminal_colors: false,
+                trace_config: false,
+                command: None,
+            })
+            .unwrap_err();
+
+            assert_contains!(
+                format!("{report:#}"),
+                "Network public address is unspecified"
+            );
+            assert_contains!(
+                format!("{report:#}"),
+                "Torii and Network addresses are the same"
+            );
+
+            Ok(())
+        }
     }
 
     #[test]
@@ -181,14 +181,14 @@ fn find_rate_and_make_exchange_isi_should_succeed() {
     let alice_can_transfer_asset = |asset_id: AssetId, owner_key_pair: KeyPair| {
         let permission = CanTransferAsset {
             asset: asset_id.clone(),
+            delegatable: false,
+            max_amount_per_transaction: None,
+            max_amount_per_day: None,
         };
         let instruction = Grant::account_permission(permission, alice_id.clone());
-        let transaction = TransactionBuilder::new(
-            ChainId::from("00000000-0000-0000-0000-000000000000"),
-            asset_id.account().clone(),
-        )
-        .with_instructions([instruction])
-        .sign(owner_key_pair.private_key());
+        let transaction = TransactionBuilder::new(network.chain_id(), asset_id.account().clone())
+            .with_instructions([instruction])
+            .sign(owner_key_pair.private_key());
 
         test_client
             .submit_transaction_blocking(&transaction)
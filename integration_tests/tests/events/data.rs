@@ -3,7 +3,10 @@ use std::fmt::Write as _;
 use assert_matches::assert_matches;
 use eyre::Result;
 use futures_util::StreamExt;
-use iroha::data_model::{prelude::*, transaction::WasmSmartContract};
+use iroha::{
+    client::StreamCompression,
+    data_model::{prelude::*, transaction::WasmSmartContract},
+};
 use iroha_executor_data_model::permission::{
     account::CanModifyAccountMetadata, domain::CanModifyDomainMetadata,
 };
@@ -162,6 +165,39 @@ async fn transaction_execution_should_produce_events(
     Ok(())
 }
 
+#[tokio::test]
+async fn compressed_streams_produce_events() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+
+    for compression in [
+        StreamCompression::Deflate,
+        StreamCompression::Gzip,
+        StreamCompression::Zstd,
+    ] {
+        let mut client = network.client();
+        client.stream_compression = Some(compression);
+        let mut events_stream = client
+            .listen_for_events_async([DataEventFilter::Any])
+            .await?;
+
+        let domain_id: DomainId = compression.to_string().parse()?;
+        let register = Register::domain(Domain::new(domain_id.clone()));
+        spawn_blocking(move || client.submit_blocking(register)).await??;
+
+        let event = events_stream
+            .next()
+            .await
+            .expect("domain registration produces an event")?;
+        let domain = assert_matches!(
+            event,
+            EventBox::Data(DataEvent::Domain(DomainEvent::Created(domain))) => domain
+        );
+        assert_eq!(domain.id(), &domain_id);
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 #[allow(clippy::too_many_lines)]
 async fn produce_multiple_events() -> Result<()> {
@@ -2,13 +2,17 @@ use std::time::Duration;
 
 use eyre::Result;
 use futures_util::StreamExt;
-use iroha::data_model::{
-    events::pipeline::{TransactionEventFilter, TransactionStatus},
-    isi::error::InstructionExecutionError,
-    prelude::*,
-    query::error::FindError,
-    transaction::error::TransactionRejectionReason,
-    ValidationFail,
+use iroha::{
+    client::TransactionOutcome,
+    data_model::{
+        events::pipeline::{TransactionEventFilter, TransactionStatus},
+        isi::error::InstructionExecutionError,
+        parameter::BlockParameter,
+        prelude::*,
+        query::error::FindError,
+        transaction::error::TransactionRejectionReason,
+        ValidationFail,
+    },
 };
 use iroha_test_network::*;
 use tokio::{task::spawn_blocking, time::timeout};
@@ -73,6 +77,98 @@ async fn test_with_instruction_and_status(
     Ok(())
 }
 
+#[tokio::test]
+async fn wait_for_commit_reports_location_and_rejection() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+
+    spawn_blocking(move || -> Result<()> {
+        let ok = client.submit(Register::domain(Domain::new("looking_glass".parse()?)))?;
+        let outcome = client.wait_for_commit(ok, Duration::from_secs(5))?;
+        let TransactionOutcome::Committed(location) = outcome else {
+            panic!("Expected transaction to be committed: {outcome:?}");
+        };
+        assert_eq!(location.index, 0);
+
+        let unknown_domain_id = "dummy".parse::<DomainId>()?;
+        let fail = client.submit(Unregister::domain(unknown_domain_id.clone()))?;
+        let outcome = client.wait_for_commit(fail, Duration::from_secs(5))?;
+        assert!(outcome.location().block_height > location.block_height);
+        assert_eq!(
+            outcome.into_result(),
+            Err(TransactionRejectionReason::Validation(
+                ValidationFail::InstructionFailed(InstructionExecutionError::Find(
+                    FindError::Domain(unknown_domain_id),
+                )),
+            ))
+        );
+
+        // Already committed transactions are resolved as well
+        assert_eq!(
+            client.wait_for_commit(ok, Duration::from_secs(5))?,
+            TransactionOutcome::Committed(location)
+        );
+
+        Ok(())
+    })
+    .await??;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wait_for_commit_reports_excluded_rejection() -> Result<()> {
+    let network = NetworkBuilder::new()
+        .with_genesis_instruction(SetParameter::new(Parameter::Block(
+            BlockParameter::ExcludeRejectedTransactions(true),
+        )))
+        .start()
+        .await?;
+    let client = network.client();
+
+    spawn_blocking(move || -> Result<()> {
+        let unknown_domain_id = "dummy".parse::<DomainId>()?;
+        let fail = client.submit(Unregister::domain(unknown_domain_id.clone()))?;
+        let outcome = client.wait_for_commit(fail, Duration::from_secs(5))?;
+        let TransactionOutcome::Excluded { location, reason } = outcome.clone() else {
+            panic!("Expected transaction to be excluded: {outcome:?}");
+        };
+        assert_eq!(location.index, 0);
+        assert_eq!(
+            *reason,
+            TransactionRejectionReason::Validation(ValidationFail::InstructionFailed(
+                InstructionExecutionError::Find(FindError::Domain(unknown_domain_id)),
+            ))
+        );
+
+        // Only the hash of the transaction is recorded
+        let entrypoint_hash: HashOf<TransactionEntrypoint> =
+            HashOf::from_untyped_unchecked(fail.into());
+        assert!(client
+            .query(FindTransactions::new())
+            .filter_with(|tx| tx.entrypoint_hash.eq(entrypoint_hash))
+            .execute_single_opt()?
+            .is_none());
+        let excluded = client
+            .query(FindExcludedTransactions::new())
+            .filter_with(|tx| tx.entrypoint_hash.eq(entrypoint_hash))
+            .execute_single()?;
+        assert_eq!(*excluded.reason(), *reason);
+        assert!(client.query_single(IsTransactionKnown::new(fail))?);
+
+        // Already excluded transactions are resolved as well
+        assert_eq!(
+            client.wait_for_commit(fail, Duration::from_secs(5))?,
+            outcome
+        );
+
+        Ok(())
+    })
+    .await??;
+
+    Ok(())
+}
+
 #[test]
 #[ignore = "TODO: implement with the help of Kura Inspector, "]
 fn applied_block_must_be_available_in_kura() {
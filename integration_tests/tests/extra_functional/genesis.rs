@@ -52,3 +52,22 @@ async fn multiple_genesis_peers(n_peers: usize, n_genesis_peers: usize) -> eyre:
 
     Ok(())
 }
+
+#[tokio::test]
+async fn peers_serve_api_before_genesis() -> eyre::Result<()> {
+    let network = NetworkBuilder::new().with_peers(2).start_paused().await?;
+
+    for peer in network.peers() {
+        let status = peer.status().await?;
+        assert_eq!(status.blocks, 0);
+    }
+
+    network.resume().await?;
+
+    for peer in network.peers() {
+        let status = peer.status().await?;
+        assert_eq!(status.blocks_non_empty, 1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_test_network::*;
+
+#[test]
+fn mixed_ipv4_and_ipv6_network_reaches_consensus() -> Result<()> {
+    let (network, rt) = NetworkBuilder::new()
+        .with_peers(4)
+        .with_peer_ip_stack(1, IpStack::V6)
+        .with_peer_ip_stack(2, IpStack::DualStack)
+        .with_peer_ip_stack(3, IpStack::V6)
+        .start_blocking()?;
+
+    let domain_id: DomainId = "dual_stack".parse()?;
+    network.peers()[1]
+        .client()
+        .submit_blocking(Register::domain(Domain::new(domain_id.clone())))?;
+    rt.block_on(network.ensure_blocks(2))?;
+
+    // Every peer, whatever stack its client connects over, sees the domain
+    for peer in network.peers() {
+        peer.client()
+            .query(FindDomains::new())
+            .filter_with(|domain| domain.id.eq(domain_id.clone()))
+            .execute_single()?;
+    }
+
+    Ok(())
+}
@@ -1,8 +1,11 @@
 mod connected_peers;
 mod genesis;
+mod ip_stack;
 mod multiple_blocks_created;
 mod normal;
 mod offline_peers;
 mod restart_peer;
+mod rolling_upgrade;
+mod topology;
 mod unregister_peer;
 mod unstable_network;
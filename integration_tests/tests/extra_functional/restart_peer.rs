@@ -1,5 +1,5 @@
 use eyre::Result;
-use iroha::data_model::prelude::*;
+use iroha::{client::DrainPhase, data_model::prelude::*};
 use iroha_test_network::*;
 use iroha_test_samples::ALICE_ID;
 use tokio::{task::spawn_blocking, time::timeout};
@@ -63,3 +63,37 @@ async fn restarted_peer_should_restore_its_state() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn drained_peer_restarts_cleanly() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let peer = network.peer();
+
+    let client = peer.client();
+    spawn_blocking(move || {
+        client.submit_blocking(Register::domain(Domain::new("drained".parse().unwrap())))
+    })
+    .await??;
+    network.ensure_blocks(2).await?;
+
+    let mut events = peer.events();
+    let status = peer.drain().await?;
+    assert!(status.success());
+
+    let mut phases = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let PeerLifecycleEvent::Draining { phase } = event {
+            phases.push(phase);
+        }
+    }
+    assert_eq!(phases, DrainPhase::ALL);
+
+    let config = network.config_layers();
+    timeout(network.peer_startup_timeout(), async move {
+        peer.start(config, None).await;
+        peer.once_block(2).await;
+    })
+    .await?;
+
+    Ok(())
+}
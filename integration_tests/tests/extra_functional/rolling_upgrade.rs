@@ -0,0 +1,35 @@
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_test_network::*;
+use tokio::task::spawn_blocking;
+
+/// Path to the `irohad` binary of the version being upgraded from
+const PREVIOUS_IROHAD_ENV: &str = "TEST_NETWORK_BIN_IROHAD_PREVIOUS";
+
+#[tokio::test]
+#[ignore = "requires a previous `irohad` build, provided via `TEST_NETWORK_BIN_IROHAD_PREVIOUS`"]
+async fn network_stays_live_during_rolling_upgrade() -> Result<()> {
+    let previous = std::env::var(PREVIOUS_IROHAD_ENV)?;
+    let current = Program::Irohad.resolve()?;
+
+    let n_peers = 4;
+    let network = (0..n_peers)
+        .fold(NetworkBuilder::new().with_peers(n_peers), |builder, i| {
+            builder.with_peer_irohad(i, &previous)
+        })
+        .start()
+        .await?;
+
+    for (i, peer) in network.peers().iter().enumerate() {
+        network.upgrade_peer(peer, &current).await?;
+
+        // Transactions keep being committed by a network with mixed versions
+        let client = network.client();
+        let domain: DomainId = format!("upgraded_{i}").parse()?;
+        spawn_blocking(move || client.submit_blocking(Register::domain(Domain::new(domain))))
+            .await??;
+        network.ensure_blocks(i as u64 + 2).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use eyre::Result;
+use iroha_test_network::*;
+
+#[tokio::test]
+async fn pinned_peer_leads_first_round() -> Result<()> {
+    for leader in [0, 3] {
+        let network = NetworkBuilder::new()
+            .with_peers(4)
+            .with_initial_leader(leader)
+            .start()
+            .await?;
+
+        assert_eq!(network.leader().await?, &network.peers()[leader]);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn forced_view_change_replaces_leader() -> Result<()> {
+    let network = NetworkBuilder::new()
+        .with_peers(4)
+        .with_initial_leader(1)
+        .start()
+        .await?;
+    let leader = &network.peers()[1];
+
+    network.force_view_change().await?;
+
+    assert_ne!(network.leader().await?, leader);
+    // The network stays live with the former leader back
+    network.ensure_blocks(2).await?;
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use iroha_data_model::{
     asset::AssetDefinition, isi::Register, parameter::BlockParameter, prelude::*, Level,
 };
 use iroha_primitives::addr::socket_addr;
-use iroha_test_network::{genesis_factory, once_blocks_sync, BlockHeight, Network, NetworkBuilder};
+use iroha_test_network::{once_blocks_sync, BlockHeight, Network, NetworkBuilder};
 use iroha_test_samples::ALICE_ID;
 use nonzero_ext::nonzero;
 use rand::{
@@ -371,16 +371,7 @@ async fn start_network_under_relay(
                     GenesisPeer::Whichever => i == 0,
                     GenesisPeer::Nth(n) => i == n,
                 }
-                .then(|| {
-                    genesis_factory(
-                        network.genesis_isi().clone(),
-                        network
-                            .peers()
-                            .iter()
-                            .map(iroha_test_network::NetworkPeer::id)
-                            .collect(),
-                    )
-                });
+                .then(|| network.genesis());
 
                 async move {
                     peer.start_checked(config, genesis.as_ref())
@@ -7,7 +7,7 @@ use iroha::{
     crypto::{ExposedPrivateKey, KeyPair},
 };
 use iroha_config_base::toml::WriteExt;
-use iroha_data_model::prelude::AccountId;
+use iroha_data_model::{prelude::AccountId, ChainId};
 use iroha_test_network::NetworkBuilder;
 use iroha_test_samples::sample_wasm_path;
 use reqwest::Url;
@@ -17,6 +17,7 @@ fn program() -> PathBuf {
 }
 
 struct ProgramConfig {
+    chain: ChainId,
     torii_url: Url,
     account: AccountId,
     key: KeyPair,
@@ -24,10 +25,12 @@ struct ProgramConfig {
 
 impl From<&Client> for ProgramConfig {
     fn from(value: &Client) -> Self {
+        let chain = value.chain.clone();
         let torii_url = value.torii_url.clone();
         let account = value.account.clone();
         let key = value.key_pair.clone();
         Self {
+            chain,
             torii_url,
             account,
             key,
@@ -38,7 +41,7 @@ impl From<&Client> for ProgramConfig {
 impl ProgramConfig {
     fn envs(&self) -> impl IntoIterator<Item = (&str, String)> {
         [
-            ("CHAIN", iroha_test_network::chain_id().to_string()),
+            ("CHAIN", self.chain.to_string()),
             ("TORII_URL", self.torii_url.to_string()),
             ("ACCOUNT_DOMAIN", self.account.domain().to_string()),
             ("ACCOUNT_PUBLIC_KEY", self.key.public_key().to_string()),
@@ -51,7 +54,7 @@ impl ProgramConfig {
 
     fn toml(&self) -> toml::Table {
         toml::Table::new()
-            .write("chain", iroha_test_network::chain_id())
+            .write("chain", &self.chain)
             .write("torii_url", &self.torii_url)
             .write(["account", "domain"], self.account.domain())
             .write(
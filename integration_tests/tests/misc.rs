@@ -4,6 +4,7 @@ use eyre::Result;
 use iroha::{client, data_model::prelude::*};
 use iroha_telemetry::metrics::Status;
 use iroha_test_network::*;
+use serde_json::json;
 use tokio::task::spawn_blocking;
 
 fn status_eq_excluding_uptime_and_queue(lhs: &Status, rhs: &Status) -> bool {
@@ -66,3 +67,215 @@ async fn get_server_version() -> eyre::Result<()> {
     assert!(!response.git_sha.is_empty());
     Ok(())
 }
+
+#[tokio::test]
+async fn get_startup_report() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+    let report = spawn_blocking(move || client.get_startup_report()).await??;
+
+    // the base layer is extended by the run config
+    assert!(report.config_sources[0].ends_with("config.base.toml"));
+    assert!(report
+        .config_sources
+        .last()
+        .is_some_and(|path| path.ends_with("run-0-config.toml")));
+    assert_eq!(report.kura_block_count, 0);
+    assert_eq!(report.snapshot, client::SnapshotOutcome::NotFound);
+    assert!(matches!(
+        report.genesis,
+        client::GenesisOutcome::Applied { .. }
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_executor_schema() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+    let (schema, supported, unknown) = spawn_blocking(move || {
+        let supported = client.validate_permission(&Permission::new(
+            "CanModifyDomainMetadata".to_owned(),
+            json!({ "domain": "wonderland" }),
+        ));
+        let unknown = client.validate_permission(&Permission::new(
+            "CanModifyDomainMetadata".to_owned(),
+            json!({ "domain_id": "wonderland" }),
+        ));
+        client
+            .get_executor_schema()
+            .map(|schema| (schema, supported, unknown))
+    })
+    .await??;
+
+    let token = &schema.permissions["CanSetParameters"];
+    assert_eq!(token.schema, Json::default());
+    assert!(!token.version.is_empty());
+    supported?;
+    let _err = unknown.expect_err("payload doesn't match the token definition");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_permission_schemas() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+    let (schemas, torii_schema) = spawn_blocking(move || -> Result<_> {
+        let schemas = client.query_single(FindPermissionSchemas)?;
+        Ok((schemas, client.get_executor_schema()?))
+    })
+    .await??;
+
+    assert_eq!(schemas.len(), torii_schema.permissions.len());
+    let token = schemas
+        .iter()
+        .find(|token| token.name() == "CanModifyDomainMetadata")
+        .expect("default executor accepts the token");
+    assert_ne!(*token.schema(), Json::default());
+    assert_eq!(
+        token.version().to_string(),
+        torii_schema.permissions["CanModifyDomainMetadata"].version
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fetch_blocks_with_conditional_requests() -> Result<()> {
+    use parity_scale_codec::DecodeAll;
+    use reqwest::{header, StatusCode};
+
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+    {
+        let client = client.clone();
+        spawn_blocking(move || {
+            client.submit_blocking(Register::domain(Domain::new("looking_glass".parse()?)))
+        })
+    }
+    .await??;
+    network.ensure_blocks(2).await?;
+
+    let http = reqwest::Client::new();
+    let response = http
+        .get(client.torii_url.join("/blocks/2").unwrap())
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()[header::ETAG].clone();
+    let block = SignedBlock::decode_all(&mut response.bytes().await?.as_ref())?;
+    assert_eq!(block.header().height().get(), 2);
+
+    let response = http
+        .get(client.torii_url.join("/blocks/2").unwrap())
+        .header(header::IF_NONE_MATCH, etag)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert!(response.bytes().await?.is_empty());
+
+    let response = http
+        .get(client.torii_url.join("/blocks?from=1&to=5").unwrap())
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let blocks = Vec::<SignedBlock>::decode_all(&mut response.bytes().await?.as_ref())?;
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[1], block);
+
+    let response = http
+        .get(client.torii_url.join("/blocks/3").unwrap())
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fetch_and_verify_block_inclusion_proofs() -> Result<()> {
+    use parity_scale_codec::DecodeAll;
+    use reqwest::StatusCode;
+
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+    {
+        let client = client.clone();
+        spawn_blocking(move || {
+            client.submit_blocking(Register::domain(Domain::new("looking_glass".parse()?)))?;
+            client.submit_blocking(Register::domain(Domain::new("mirror".parse()?)))
+        })
+    }
+    .await??;
+    network.ensure_blocks(3).await?;
+
+    let http = reqwest::Client::new();
+    let block_header = |height: u64| {
+        let http = http.clone();
+        let url = client.torii_url.join(&format!("/blocks/{height}")).unwrap();
+        async move {
+            let bytes = http.get(url).send().await?.bytes().await?;
+            Ok::<_, eyre::Report>(SignedBlock::decode_all(&mut bytes.as_ref())?.header())
+        }
+    };
+    let genesis = block_header(1).await?;
+    let second = block_header(2).await?;
+    let latest = block_header(3).await?;
+    assert_eq!(genesis.prev_blocks_root(), None);
+    let proof = |path: &str| {
+        let http = http.clone();
+        let url = client.torii_url.join(path).unwrap();
+        async move {
+            let bytes = http.get(url).send().await?.bytes().await?;
+            Ok::<_, eyre::Report>(BlockInclusionProof::decode_all(&mut bytes.as_ref())?)
+        }
+    };
+
+    // Roots are committed to by the signed headers of the following blocks
+    let BlockInclusionProof { root, proof: path } = proof("/blocks/1/proof").await?;
+    assert_eq!(latest.prev_blocks_root(), Some(root));
+    assert_eq!(path.leaf_count(), 2);
+    assert!(path.verify(&genesis.hash(), &root));
+    let BlockInclusionProof { proof: path, .. } = proof("/blocks/2/proof").await?;
+    assert!(path.verify(&second.hash(), &root));
+
+    // Proof against the root committed to by an earlier block
+    let BlockInclusionProof { root, proof: path } = proof("/blocks/1/proof?at=2").await?;
+    assert_eq!(second.prev_blocks_root(), Some(root));
+    assert_eq!(path.leaf_count(), 1);
+    assert!(path.clone().verify(&genesis.hash(), &root));
+    assert!(!path.verify(&second.hash(), &root));
+
+    // Blocks can only be proven against the roots of the following blocks
+    for path in [
+        "/blocks/2/proof?at=2",
+        "/blocks/3/proof",
+        "/blocks/1/proof?at=4",
+    ] {
+        let response = http
+            .get(client.torii_url.join(path).unwrap())
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{path}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_peers_traffic() -> Result<()> {
+    let network = NetworkBuilder::new().with_peers(2).start().await?;
+    let client = network.peers()[0].client();
+    let traffic = spawn_blocking(move || client.get_peers_traffic()).await??;
+
+    let [peer] = traffic.as_slice() else {
+        panic!("expected traffic of exactly one connected peer, got: {traffic:?}");
+    };
+    assert_eq!(peer.peer, network.peers()[1].id().public_key().to_string());
+    assert!(peer.bytes_sent > 0 && peer.bytes_received > 0);
+    assert!(peer.messages_sent > 0 && peer.messages_received > 0);
+
+    Ok(())
+}
@@ -14,7 +14,7 @@ use iroha::{
     data_model::{prelude::*, Level},
     executor_data_model::isi::multisig::*,
 };
-use iroha_executor_data_model::permission::account::CanRegisterAccount;
+use iroha_executor_data_model::permission::{account::CanRegisterAccount, domain::*};
 use iroha_test_network::*;
 use iroha_test_samples::{
     gen_account_in, ALICE_ID, BOB_ID, BOB_KEYPAIR, CARPENTER_ID, CARPENTER_KEYPAIR,
@@ -128,6 +128,8 @@ fn multisig_base(suite: TestSuite) -> Result<()> {
         Transfer::domain(ALICE_ID.clone(), domain.clone(), BOB_ID.clone()).into(),
     ];
     test_client.submit_all_blocking(register_and_transfer_kingdom)?;
+    alt_client((BOB_ID.clone(), BOB_KEYPAIR.clone()), &test_client)
+        .submit_blocking(AcceptDomainTransfer::new(domain.clone()))?;
 
     // Populate residents in the domain
     let mut residents = core::iter::repeat_with(|| gen_account_in(&domain))
@@ -535,6 +537,105 @@ fn multisig_recursion_base(suite: TestSuite) -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn multisig_grants() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let kingdom: DomainId = "kingdom".parse().unwrap();
+    let (multisig_account_id, _) = gen_account_in(&kingdom);
+    let (grantee, _) = gen_account_in(&kingdom);
+    let signatories = core::iter::repeat_with(|| gen_account_in(&kingdom))
+        .take(2)
+        .collect::<BTreeMap<AccountId, KeyPair>>();
+
+    test_client.submit_all_blocking::<InstructionBox>(
+        [
+            Register::domain(Domain::new(kingdom.clone())).into(),
+            SetKeyValue::domain(
+                kingdom.clone(),
+                "multisig/grants".parse().unwrap(),
+                Json::new(["CanUnregisterDomain"]),
+            )
+            .into(),
+        ]
+        .into_iter()
+        .chain(
+            signatories
+                .keys()
+                .chain([&grantee])
+                .cloned()
+                .map(Account::new)
+                .map(Register::account)
+                .map(Into::into),
+        ),
+    )?;
+    test_client.submit_blocking(MultisigRegister::new(
+        multisig_account_id.clone(),
+        MultisigSpec::new(
+            signatories.keys().cloned().map(|id| (id, 1)).collect(),
+            NonZeroU16::new(2).unwrap(),
+            NonZeroU64::MAX,
+        ),
+    ))?;
+
+    // Permissions not listed by the domain are granted as usual
+    test_client.submit_blocking(Grant::account_permission(
+        CanModifyDomainMetadata {
+            domain: kingdom.clone(),
+        },
+        grantee.clone(),
+    ))?;
+
+    let grant: InstructionBox = Grant::account_permission(
+        CanUnregisterDomain {
+            domain: kingdom.clone(),
+        },
+        grantee.clone(),
+    )
+    .into();
+    let _err = test_client
+        .submit_blocking(grant.clone())
+        .expect_err("listed permission shouldn't be granted by a single signer");
+
+    test_client.submit_blocking(Transfer::domain(
+        ALICE_ID.clone(),
+        kingdom.clone(),
+        multisig_account_id.clone(),
+    ))?;
+    let mut signatories = signatories.into_iter();
+    let (proposer, approver) = (signatories.next().unwrap(), signatories.next().unwrap());
+
+    // The multisig account accepts ownership of the domain
+    let accept: Vec<InstructionBox> = vec![AcceptDomainTransfer::new(kingdom.clone()).into()];
+    let approve = MultisigApprove::new(multisig_account_id.clone(), HashOf::new(&accept));
+    alt_client(proposer.clone(), &test_client).submit_blocking(MultisigPropose::new(
+        multisig_account_id.clone(),
+        accept,
+        None,
+    ))?;
+    alt_client(approver.clone(), &test_client).submit_blocking(approve)?;
+
+    let instructions = vec![grant];
+    let approve = MultisigApprove::new(multisig_account_id.clone(), HashOf::new(&instructions));
+    alt_client(proposer, &test_client).submit_blocking(MultisigPropose::new(
+        multisig_account_id,
+        instructions,
+        None,
+    ))?;
+    alt_client(approver, &test_client).submit_blocking(approve)?;
+
+    let permissions = test_client
+        .query(FindPermissionsByAccountId::new(grantee))
+        .execute_all()?;
+    assert!(permissions
+        .iter()
+        .any(|permission| CanUnregisterDomain::try_from(permission)
+            .is_ok_and(|permission| permission.domain == kingdom)));
+
+    Ok(())
+}
+
 #[test]
 fn reserved_roles() {
     let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
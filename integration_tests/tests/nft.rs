@@ -150,3 +150,82 @@ fn nft_owner_can_transfer_nft() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn find_nfts_by_content_substring() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let description: Name = "description".parse()?;
+    let tags: Name = "tags".parse()?;
+    let sword: NftId = "sword$wonderland".parse()?;
+    let shield: NftId = "shield$wonderland".parse()?;
+    let gem: NftId = "gem$wonderland".parse()?;
+    let nft = |id: &NftId, key: &Name, value: serde_json::Value| {
+        let mut content = Metadata::default();
+        content.insert(key.clone(), value);
+        Register::nft(Nft::new(id.clone(), content))
+    };
+    client.submit_all_blocking([
+        nft(&sword, &description, "legendary sword".into()),
+        nft(&shield, &description, "common shield".into()),
+        nft(&gem, &tags, serde_json::json!(["legendary", "red"])),
+    ])?;
+
+    let legendary = client
+        .query(FindNfts::new())
+        .filter_with(|nft| {
+            nft.content
+                .key(description.clone())
+                .contains("legendary".into())
+        })
+        .select_with(|nft| nft.id)
+        .execute_all()?;
+    assert_eq!(legendary, [sword.clone()]);
+
+    let legendary_anywhere = client
+        .query(FindNfts::new())
+        .filter_with(|nft| nft.content.value_contains("legendary".into()))
+        .select_with(|nft| nft.id)
+        .execute_all()?;
+    assert_eq!(legendary_anywhere, [gem.clone(), sword.clone()]);
+
+    let common = client
+        .query(FindNfts::new())
+        .filter_with(|nft| {
+            nft.content
+                .key(description.clone())
+                .matches("c?mmon*".into())
+        })
+        .select_with(|nft| nft.id)
+        .execute_all()?;
+    assert_eq!(common, [shield]);
+
+    // The index follows changes of the content
+    client.submit_blocking(RemoveKeyValue::nft(sword, description))?;
+    let legendary_anywhere = client
+        .query(FindNfts::new())
+        .filter_with(|nft| nft.content.value_contains("legendary".into()))
+        .select_with(|nft| nft.id)
+        .execute_all()?;
+    assert_eq!(legendary_anywhere, [gem.clone()]);
+
+    // Content with too many trigrams to index is still searched
+    let scroll: NftId = "scroll$wonderland".parse()?;
+    let runes = (0x4E00..0x4F00)
+        .filter_map(char::from_u32)
+        .collect::<String>();
+    client.submit_blocking(nft(
+        &scroll,
+        &description,
+        format!("{runes} legendary scroll").into(),
+    ))?;
+    let legendary_anywhere = client
+        .query(FindNfts::new())
+        .filter_with(|nft| nft.content.value_contains("legendary".into()))
+        .select_with(|nft| nft.id)
+        .execute_all()?;
+    assert_eq!(legendary_anywhere, [gem, scroll]);
+
+    Ok(())
+}
@@ -67,6 +67,7 @@ fn fetch_size_should_work() -> Result<()> {
             FindAssetsDefinitions::new(),
             CompoundPredicate::PASS,
             SelectorTuple::default(),
+            None,
         )
         .into(),
         QueryParams::new(
@@ -12,10 +12,14 @@ use iroha::{
     },
 };
 use iroha_executor_data_model::permission::{
-    asset::CanTransferAsset, domain::CanModifyDomainMetadata, nft::CanModifyNftMetadata,
+    account::CanModifyAccountMetadata,
+    asset::{CanTransferAsset, CanTransferAssetInDomain, CannotTransferAsset},
+    domain::CanModifyDomainMetadata,
+    nft::CanModifyNftMetadata,
+    query::{CanQueryAssetsInDomain, CanQueryTransactions},
 };
 use iroha_test_network::*;
-use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID, BOB_KEYPAIR};
+use iroha_test_samples::{gen_account_in, load_sample_wasm, ALICE_ID, BOB_ID, BOB_KEYPAIR};
 use tokio::{join, time::timeout};
 
 #[tokio::test]
@@ -38,11 +42,14 @@ async fn genesis_transactions_are_validated_by_executor() {
             peer.once(|event| matches!(event, PeerLifecycleEvent::ServerStarted)),
             // ...but it should shortly exit with an error
             peer.once(|event| match event {
-                // TODO: handle "Invalid genesis" in a more granular way
-                //       https://github.com/hyperledger-iroha/iroha/issues/5423
                 PeerLifecycleEvent::Terminated { status } => !status.success(),
                 _ => false,
-            })
+            }),
+            // ...for the specific reason
+            peer.assert_log_matches(
+                "Genesis contains invalid transactions",
+                Duration::from_secs(5)
+            )
         )
     })
     .await
@@ -60,9 +67,8 @@ fn get_assets(iroha: &Client, id: &AccountId) -> Vec<Asset> {
 #[test]
 #[ignore = "ignore, more in #2851"]
 fn permissions_disallow_asset_transfer() {
-    let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
-
     let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let chain_id = network.chain_id();
     let iroha = network.client();
 
     // Given
@@ -115,9 +121,8 @@ fn permissions_disallow_asset_transfer() {
 #[test]
 #[ignore = "ignore, more in #2851"]
 fn permissions_disallow_asset_burn() {
-    let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
-
     let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let chain_id = network.chain_id();
     let iroha = network.client();
 
     let alice_id = ALICE_ID.clone();
@@ -166,6 +171,41 @@ fn permissions_disallow_asset_burn() {
     assert_eq!(alice_assets, alice_start_assets);
 }
 
+#[test]
+fn transaction_check_reports_validation_fail() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let chain_id = network.chain_id();
+    let iroha = network.client();
+
+    let asset_definition_id: AssetDefinitionId = "xor#wonderland".parse()?;
+    let alice_asset = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    iroha.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(100u32, alice_asset.clone()).into(),
+    ])?;
+    let alice_start_assets = get_assets(&iroha, &ALICE_ID);
+
+    let transfer = Transfer::asset_numeric(alice_asset, 10u32, BOB_ID.clone());
+    let transfer_as_bob = TransactionBuilder::new(chain_id, BOB_ID.clone())
+        .with_instructions([transfer.clone()])
+        .sign(BOB_KEYPAIR.private_key());
+    let rejection_reason = iroha
+        .check_transaction(&transfer_as_bob)?
+        .expect_err("Bob can't transfer Alice's asset");
+    assert!(matches!(
+        rejection_reason,
+        TransactionRejectionReason::Validation(ValidationFail::NotPermitted(_))
+    ));
+
+    let transfer_as_alice = iroha.build_transaction([transfer], Metadata::default());
+    iroha
+        .check_transaction(&transfer_as_alice)?
+        .map_err(|reason| eyre::eyre!("{reason}"))?;
+    assert_eq!(get_assets(&iroha, &ALICE_ID), alice_start_assets);
+
+    Ok(())
+}
+
 #[test]
 #[ignore = "ignore, more in #2851"]
 fn account_can_query_only_its_own_domain() -> Result<()> {
@@ -196,10 +236,61 @@ fn account_can_query_only_its_own_domain() -> Result<()> {
 }
 
 #[test]
-fn permissions_differ_not_only_by_names() {
-    let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+fn query_permissions_open_read_access_selectively() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let (mouse_id, mouse_keypair) = gen_account_in("outfit");
+    client.submit_all_blocking::<InstructionBox>([
+        Register::domain(Domain::new("outfit".parse()?)).into(),
+        Register::account(Account::new(mouse_id.clone())).into(),
+    ])?;
+    let mouse = network
+        .peer()
+        .client_for(&mouse_id, mouse_keypair.private_key().clone());
+    let rose: AssetDefinitionId = "rose#wonderland".parse()?;
+
+    // Mouse sees its own transactions, but neither those of others nor holders of `wonderland` assets
+    mouse
+        .query(FindTransactions::new())
+        .filter_with(|tx| tx.entrypoint.authority.eq(mouse_id.clone()))
+        .execute_all()?;
+    let _err = mouse
+        .query(FindTransactions::new())
+        .execute_all()
+        .expect_err("mouse shouldn't see transactions of other accounts");
+    let _err = mouse
+        .query(FindAccountsWithAsset::new(rose.clone()))
+        .execute_all()
+        .expect_err("mouse shouldn't see holders of assets from another domain");
+
+    client.submit_all_blocking::<InstructionBox>([
+        Grant::account_permission(
+            CanQueryAssetsInDomain {
+                domain: "wonderland".parse()?,
+            },
+            mouse_id.clone(),
+        )
+        .into(),
+        Grant::account_permission(CanQueryTransactions, mouse_id).into(),
+    ])?;
+
+    assert!(!mouse
+        .query(FindTransactions::new())
+        .execute_all()?
+        .is_empty());
+    let holders = mouse
+        .query(FindAccountsWithAsset::new(rose))
+        .execute_all()?;
+    assert!(holders.iter().any(|account| account.id() == &*ALICE_ID));
 
+    Ok(())
+}
+
+#[test]
+fn permissions_differ_not_only_by_names() {
     let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let chain_id = network.chain_id();
     let client = network.client();
 
     let submit_with_authority = |isi: InstructionBox,
@@ -231,7 +322,8 @@ fn permissions_differ_not_only_by_names() {
     // Registering NFT
     let hat_nft_id: NftId = "hat$outfit".parse().expect("Valid");
     let register_hat_nft = Register::nft(Nft::new(hat_nft_id.clone(), Metadata::default()));
-    let transfer_shoes_domain = Transfer::domain(alice_id.clone(), outfit_domain, mouse_id.clone());
+    let transfer_shoes_domain =
+        Transfer::domain(alice_id.clone(), outfit_domain.clone(), mouse_id.clone());
     let shoes_nft_id: NftId = "shoes$outfit".parse().expect("Valid");
     let register_shoes_nft = Register::nft(Nft::new(shoes_nft_id.clone(), Metadata::default()));
     client
@@ -241,6 +333,12 @@ fn permissions_differ_not_only_by_names() {
             transfer_shoes_domain.into(),
         ])
         .expect("Failed to register new NFTs");
+    submit_with_authority(
+        AcceptDomainTransfer::new(outfit_domain).into(),
+        &mouse_id,
+        &mouse_keypair,
+    )
+    .expect("Failed to accept outfit domain");
 
     // Granting permission to Bob to modify metadata in Mouse's hats
     let mouse_hat_permission = CanModifyNftMetadata {
@@ -292,9 +390,8 @@ fn permissions_differ_not_only_by_names() {
 
 #[test]
 fn stored_vs_granted_permission_payload() {
-    let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
-
     let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let chain_id = network.chain_id();
     let iroha = network.client();
 
     // Given
@@ -347,11 +444,17 @@ fn permissions_are_unified() {
 
     let permission1 = CanTransferAsset {
         asset: format!("rose#wonderland#{alice_id}").parse().unwrap(),
+        delegatable: false,
+        max_amount_per_transaction: None,
+        max_amount_per_day: None,
     };
     let allow_alice_to_transfer_rose_1 = Grant::account_permission(permission1, alice_id.clone());
 
     let permission2 = CanTransferAsset {
         asset: format!("rose##{alice_id}").parse().unwrap(),
+        delegatable: false,
+        max_amount_per_transaction: None,
+        max_amount_per_day: None,
     };
     let allow_alice_to_transfer_rose_2 = Grant::account_permission(permission2, alice_id);
 
@@ -364,6 +467,310 @@ fn permissions_are_unified() {
         .expect_err("should reject due to duplication");
 }
 
+#[test]
+fn domain_wide_permissions_subsume_narrower_ones() {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let iroha = network.client();
+
+    let bob_id = BOB_ID.clone();
+    let narrow = CanTransferAsset {
+        asset: format!("rose##{}", ALICE_ID.clone()).parse().unwrap(),
+        delegatable: false,
+        max_amount_per_transaction: None,
+        max_amount_per_day: None,
+    };
+    let domain_wide = CanTransferAssetInDomain {
+        domain: "wonderland".parse().unwrap(),
+    };
+
+    iroha
+        .submit_blocking(Grant::account_permission(narrow.clone(), bob_id.clone()))
+        .expect("failed to grant narrow permission");
+    iroha
+        .submit_blocking(Grant::account_permission(
+            domain_wide.clone(),
+            bob_id.clone(),
+        ))
+        .expect("failed to grant domain-wide permission");
+
+    let permissions = iroha
+        .query(FindPermissionsByAccountId::new(bob_id.clone()))
+        .execute_all()
+        .expect("failed to get permissions for bob");
+    assert!(permissions
+        .iter()
+        .any(|permission| CanTransferAssetInDomain::try_from(permission)
+            .is_ok_and(|permission| permission == domain_wide)));
+    assert!(!permissions
+        .iter()
+        .any(|permission| CanTransferAsset::try_from(permission).is_ok()));
+
+    let _ = iroha
+        .submit_blocking(Grant::account_permission(narrow, bob_id))
+        .expect_err("should reject as already covered by the domain-wide permission");
+}
+
+#[test]
+fn delegatable_permission_can_be_regranted() {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let chain_id = network.chain_id();
+    let client = network.client();
+
+    let submit_as_bob = |isi: InstructionBox| -> Result<HashOf<SignedTransaction>> {
+        let tx = TransactionBuilder::new(chain_id.clone(), BOB_ID.clone())
+            .with_instructions([isi])
+            .sign(BOB_KEYPAIR.private_key());
+        client.submit_transaction_blocking(&tx)
+    };
+
+    let (mouse_id, _mouse_keypair) = gen_account_in("wonderland");
+    client
+        .submit_blocking(Register::account(Account::new(mouse_id.clone())))
+        .expect("Failed to register mouse");
+
+    let asset: AssetId = format!("rose##{}", ALICE_ID.clone()).parse().unwrap();
+    let delegatable = CanTransferAsset {
+        asset: asset.clone(),
+        delegatable: true,
+        max_amount_per_transaction: None,
+        max_amount_per_day: None,
+    };
+    let regranted = CanTransferAsset {
+        asset,
+        delegatable: false,
+        max_amount_per_transaction: None,
+        max_amount_per_day: None,
+    };
+
+    let _err = submit_as_bob(Grant::account_permission(regranted.clone(), mouse_id.clone()).into())
+        .expect_err("Bob shouldn't be able to grant a permission without holding it");
+
+    client
+        .submit_blocking(Grant::account_permission(
+            delegatable.clone(),
+            BOB_ID.clone(),
+        ))
+        .expect("Failed to grant delegatable permission to Bob");
+
+    submit_as_bob(Grant::account_permission(regranted, mouse_id.clone()).into())
+        .expect("Bob should be able to re-grant a non-delegatable copy");
+    let _err = submit_as_bob(Grant::account_permission(delegatable, mouse_id).into())
+        .expect_err("Bob shouldn't be able to re-grant a delegatable copy");
+}
+
+#[test]
+fn permission_group_is_never_applied_partially() {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let client = network.client();
+
+    let modify_metadata = CanModifyAccountMetadata {
+        account: ALICE_ID.clone(),
+    };
+    let transfer_roses = CanTransferAsset {
+        asset: format!("rose##{}", ALICE_ID.clone()).parse().unwrap(),
+        delegatable: false,
+        max_amount_per_transaction: None,
+        max_amount_per_day: None,
+    };
+    let bob_permissions = || {
+        client
+            .query(FindPermissionsByAccountId::new(BOB_ID.clone()))
+            .execute_all()
+            .expect("failed to get permissions for bob")
+    };
+
+    client
+        .submit_blocking(Grant::account_permission(
+            modify_metadata.clone(),
+            BOB_ID.clone(),
+        ))
+        .expect("Failed to grant permission to Bob");
+    let group = PermissionGroup::new([
+        Permission::from(modify_metadata.clone()),
+        Permission::from(transfer_roses.clone()),
+    ]);
+
+    let _err = client
+        .submit_blocking(Grant::account_permission_group(
+            group.clone(),
+            BOB_ID.clone(),
+        ))
+        .expect_err("Bob already has one of the permissions");
+    assert!(!bob_permissions()
+        .iter()
+        .any(|permission| CanTransferAsset::try_from(permission).is_ok()));
+
+    client
+        .submit_blocking(Revoke::account_permission(modify_metadata, BOB_ID.clone()))
+        .expect("Failed to revoke permission from Bob");
+    client
+        .submit_blocking(Grant::account_permission_group(
+            group.clone(),
+            BOB_ID.clone(),
+        ))
+        .expect("Failed to grant permission group to Bob");
+    assert_eq!(bob_permissions().len(), 2);
+
+    client
+        .submit_blocking(Revoke::account_permission_group(group, BOB_ID.clone()))
+        .expect("Failed to revoke permission group from Bob");
+    assert!(bob_permissions().is_empty());
+}
+
+#[test]
+fn deny_permission_wins_over_allowing_ones() {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let chain_id = network.chain_id();
+    let client = network.client();
+
+    let asset: AssetId = format!("rose##{}", ALICE_ID.clone()).parse().unwrap();
+    let transfer_as_bob = || -> Result<HashOf<SignedTransaction>> {
+        let tx = TransactionBuilder::new(chain_id.clone(), BOB_ID.clone())
+            .with_instructions([Transfer::asset_numeric(
+                asset.clone(),
+                1_u32,
+                BOB_ID.clone(),
+            )])
+            .sign(BOB_KEYPAIR.private_key());
+        client.submit_transaction_blocking(&tx)
+    };
+    let deny = CannotTransferAsset {
+        asset: asset.clone(),
+    };
+
+    client
+        .submit_blocking(Grant::account_permission(
+            CanTransferAssetInDomain {
+                domain: "wonderland".parse().unwrap(),
+            },
+            BOB_ID.clone(),
+        ))
+        .expect("Failed to grant domain-wide permission to Bob");
+    client
+        .submit_blocking(Grant::account_permission(deny.clone(), BOB_ID.clone()))
+        .expect("Failed to grant deny permission to Bob");
+
+    let _err = transfer_as_bob().expect_err("deny permission should win");
+
+    client
+        .submit_blocking(Revoke::account_permission(deny, BOB_ID.clone()))
+        .expect("Failed to revoke deny permission from Bob");
+
+    transfer_as_bob().expect("transfer is allowed by the domain-wide permission");
+}
+
+#[test]
+fn transfer_permission_limits_are_enforced() {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let chain_id = network.chain_id();
+    let client = network.client();
+
+    let asset: AssetId = format!("rose##{}", ALICE_ID.clone()).parse().unwrap();
+    let transfer_as_bob = |amount: u32| -> Result<HashOf<SignedTransaction>> {
+        let tx = TransactionBuilder::new(chain_id.clone(), BOB_ID.clone())
+            .with_instructions([Transfer::asset_numeric(
+                asset.clone(),
+                amount,
+                BOB_ID.clone(),
+            )])
+            .sign(BOB_KEYPAIR.private_key());
+        client.submit_transaction_blocking(&tx)
+    };
+
+    client
+        .submit_blocking(Grant::account_permission(
+            CanTransferAsset {
+                asset: asset.clone(),
+                delegatable: false,
+                max_amount_per_transaction: Some(5_u32.into()),
+                max_amount_per_day: Some(8_u32.into()),
+            },
+            BOB_ID.clone(),
+        ))
+        .expect("Failed to grant limited permission to Bob");
+
+    let _err = transfer_as_bob(6).expect_err("transfer exceeds the limit per transaction");
+    transfer_as_bob(5).expect("transfer is within the limits");
+    let _err = transfer_as_bob(4).expect_err("transfer exceeds the daily limit");
+    transfer_as_bob(3).expect("transfer is within the daily limit");
+
+    let bob_roses = client
+        .query(FindAssets)
+        .filter_with(|asset| asset.id.account.eq(BOB_ID.clone()))
+        .execute_all()
+        .unwrap()
+        .into_iter()
+        .find(|bob_asset| bob_asset.id().definition() == asset.definition())
+        .expect("Bob should have received roses");
+    assert_eq!(*bob_roses.value(), Numeric::from(8_u32));
+
+    // The daily amount is counted in the state, per holder of the permission
+    let counter = client
+        .query_single(FindSpendingCounters::new(ALICE_ID.clone()))
+        .unwrap()
+        .into_iter()
+        .find(|counter| counter.spender.as_ref() == Some(&*BOB_ID))
+        .expect("Bob's transfers should be counted");
+    assert_eq!(counter.window(), SpendingWindow::Day);
+    assert_eq!(counter.spent, Numeric::from(8_u32));
+
+    // Bob can't reset the spent amount
+    let mut reset = counter;
+    reset.spent = Numeric::ZERO;
+    let tx = TransactionBuilder::new(chain_id.clone(), BOB_ID.clone())
+        .with_instructions([SetSpendingCounter::new(ALICE_ID.clone(), reset)])
+        .sign(BOB_KEYPAIR.private_key());
+    let _err = client
+        .submit_transaction_blocking(&tx)
+        .expect_err("spending counters can't be modified directly");
+}
+
+#[test]
+fn revoke_all_account_permissions() {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let chain_id = network.chain_id();
+    let client = network.client();
+
+    let bob_id = BOB_ID.clone();
+    let grants = [
+        Grant::account_permission(
+            CanTransferAsset {
+                asset: format!("rose##{}", ALICE_ID.clone()).parse().unwrap(),
+                delegatable: false,
+                max_amount_per_transaction: None,
+                max_amount_per_day: None,
+            },
+            bob_id.clone(),
+        ),
+        Grant::account_permission(
+            CanModifyDomainMetadata {
+                domain: "wonderland".parse().unwrap(),
+            },
+            bob_id.clone(),
+        ),
+    ];
+    client
+        .submit_all_blocking(grants)
+        .expect("Failed to grant permissions to Bob");
+
+    // Only permissions which could be revoked one by one can be revoked at once
+    let tx = TransactionBuilder::new(chain_id, bob_id.clone())
+        .with_instructions([RevokeAll::account_permissions(ALICE_ID.clone())])
+        .sign(BOB_KEYPAIR.private_key());
+    let _err = client
+        .submit_transaction_blocking(&tx)
+        .expect_err("Bob shouldn't be able to revoke permissions of Alice");
+
+    client
+        .submit_blocking(RevokeAll::account_permissions(bob_id.clone()))
+        .expect("Failed to revoke all permissions from Bob");
+    let permissions = client
+        .query(FindPermissionsByAccountId::new(bob_id))
+        .execute_all()
+        .expect("Failed to get permissions of Bob");
+    assert!(permissions.is_empty());
+}
+
 #[test]
 fn associated_permissions_removed_on_unregister() {
     let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
@@ -467,3 +874,137 @@ fn associated_permissions_removed_from_role_on_unregister() {
                 .is_ok_and(|permission| permission == set_kv_in_domain)
         }));
 }
+
+#[test]
+fn permissive_network_allows_anyone_to_register_anything() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::permissive().start_blocking()?;
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+
+    // `wonderland` is owned by Alice
+    let asset_definition_id: AssetDefinitionId = "xor#wonderland".parse()?;
+    bob.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        asset_definition_id.clone(),
+    )))?;
+    bob.submit_blocking(Register::domain(Domain::new("kingdom".parse()?)))?;
+
+    bob.query(FindAssetsDefinitions::new())
+        .filter_with(|definition| definition.id.eq(asset_definition_id))
+        .execute_single()?;
+
+    Ok(())
+}
+
+#[test]
+fn strict_network_grants_no_extra_permissions() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::strict().start_blocking()?;
+    let iroha = network.client();
+
+    let upgrade = Upgrade::new(Executor::new(load_sample_wasm("executor_permissive")));
+    let _err = iroha
+        .submit_blocking(upgrade)
+        .expect_err("Alice shouldn't be able to upgrade the executor");
+
+    Ok(())
+}
+
+#[test]
+fn permission_grants_are_recorded_in_history() -> Result<()> {
+    use iroha_executor_data_model::permission::Permission as _;
+
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let permission = CanModifyDomainMetadata {
+        domain: "wonderland".parse()?,
+    };
+    client.submit_blocking(Grant::account_permission(
+        permission.clone(),
+        BOB_ID.clone(),
+    ))?;
+    client.submit_blocking(Revoke::account_permission(
+        permission.clone(),
+        BOB_ID.clone(),
+    ))?;
+
+    let history = client.query_single(
+        FindPermissionGrantHistory::all()
+            .for_account(BOB_ID.clone())
+            .for_permission(CanModifyDomainMetadata::name()),
+    )?;
+    let actions = history
+        .iter()
+        .map(PermissionGrantEvent::action)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actions,
+        [PermissionGrantAction::Grant, PermissionGrantAction::Revoke]
+    );
+    assert!(history.iter().all(|event| event.author() == &*ALICE_ID
+        && *event.target() == PermissionHolder::Account(BOB_ID.clone())
+        && *event.permission() == Permission::from(permission.clone())));
+    assert!(history[0].height() < history[1].height());
+
+    Ok(())
+}
+
+#[test]
+fn permission_is_revoked_on_matching_event() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let chain_id = network.chain_id();
+    let client = network.client();
+
+    let nft_id: NftId = "ticket$wonderland".parse()?;
+    let permission = CanModifyDomainMetadata {
+        domain: "wonderland".parse()?,
+    };
+    let nft_transferred = NftEventFilter::new()
+        .for_nft(nft_id.clone())
+        .for_events(NftEventSet::OwnerChanged);
+    client.submit_all_blocking::<InstructionBox>([
+        Register::nft(Nft::new(nft_id.clone(), Metadata::default())).into(),
+        Transfer::nft(ALICE_ID.clone(), nft_id.clone(), BOB_ID.clone()).into(),
+        Grant::account_permission(permission.clone(), BOB_ID.clone())
+            .revoke_on(nft_transferred)
+            .into(),
+    ])?;
+    let bob_permissions = || -> Result<Vec<Permission>> {
+        Ok(client
+            .query(FindPermissionsByAccountId::new(BOB_ID.clone()))
+            .execute_all()?)
+    };
+    assert!(bob_permissions()?.contains(&permission.clone().into()));
+
+    // Bob keeps the permission only while holding the ticket
+    let tx = TransactionBuilder::new(chain_id, BOB_ID.clone())
+        .with_instructions([Transfer::nft(BOB_ID.clone(), nft_id, ALICE_ID.clone())])
+        .sign(BOB_KEYPAIR.private_key());
+    client.submit_transaction_blocking(&tx)?;
+    assert!(!bob_permissions()?.contains(&permission.into()));
+
+    Ok(())
+}
+
+#[test]
+fn ingress_validation_rejects_denied_transactions_on_submission() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config_layer(|c| {
+            c.write(["torii", "ingress_validation"], true);
+        })
+        .start_blocking()?;
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+
+    // `wonderland` is owned by Alice, so the executor denies it to Bob
+    let asset_definition_id: AssetDefinitionId = "xor#wonderland".parse()?;
+    let register = Register::asset_definition(AssetDefinition::numeric(asset_definition_id));
+    let _err = bob
+        .submit(register.clone())
+        .expect_err("transaction should be rejected without being queued");
+
+    network.client().submit_blocking(register)?;
+
+    Ok(())
+}
@@ -73,3 +73,43 @@ fn find_accounts_with_asset() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn find_account_statement() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let test_client = network.client();
+
+    let definition_id = "test_coin#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let alice_coin = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+    let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(definition_id.clone())).into(),
+        Register::account(Account::new(bob_id.clone())).into(),
+        Mint::asset_numeric(100_u32, alice_coin.clone()).into(),
+    ])?;
+    test_client.submit_blocking(Transfer::asset_numeric(alice_coin, 30_u32, bob_id.clone()))?;
+
+    let statement =
+        test_client.query_single(FindAccountStatement::new(ALICE_ID.clone(), 0, u64::MAX))?;
+    let coin = statement
+        .assets
+        .iter()
+        .find(|asset| asset.asset_definition == definition_id)
+        .expect("Statement should include the moved asset");
+    assert_eq!(coin.opening_balance, Numeric::ZERO);
+    assert_eq!(coin.closing_balance, Numeric::from(70_u32));
+    assert_eq!(coin.movements.len(), 2);
+    assert_eq!(coin.movements[0].direction(), MovementDirection::Credit);
+    assert_eq!(coin.movements[0].counterparty, None);
+    assert_eq!(coin.movements[1].direction(), MovementDirection::Debit);
+    assert_eq!(coin.movements[1].counterparty, Some(bob_id.clone()));
+    assert_ne!(coin.movements[0].transaction, coin.movements[1].transaction);
+
+    // The period ends before any movement, so nothing is reported
+    let statement = test_client.query_single(FindAccountStatement::new(bob_id, 0, 1))?;
+    assert!(statement.assets.is_empty());
+
+    Ok(())
+}
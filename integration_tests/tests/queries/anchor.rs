@@ -0,0 +1,50 @@
+use eyre::Result;
+use iroha::data_model::{isi::error::InstructionExecutionError, prelude::*};
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+
+#[test]
+fn find_anchors() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let test_client = network.client();
+
+    let tag: Name = "invoices".parse()?;
+    let [first, second, untagged] = ["invoice #1", "invoice #2", "receipt"].map(Hash::new);
+    test_client.submit_all_blocking([
+        Anchor::new(first).with_tag(tag.clone()),
+        Anchor::new(second).with_tag(tag.clone()),
+        Anchor::new(untagged),
+    ])?;
+
+    let anchors = test_client
+        .query(FindAnchors::by_hash(first))
+        .execute_all()?;
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(anchors[0].tag, Some(tag.clone()));
+    assert_eq!(anchors[0].authority, *ALICE_ID);
+
+    let tagged = test_client
+        .query(FindAnchors::by_tag(tag))
+        .execute_all()?
+        .into_iter()
+        .map(|anchor| anchor.hash)
+        .collect::<Vec<_>>();
+    assert_eq!(tagged.len(), 2);
+    assert!(tagged.contains(&first) && tagged.contains(&second));
+
+    // Anchoring the same hash again must not overwrite the original record
+    let err = test_client
+        .submit_blocking(Anchor::new(first))
+        .expect_err("Should fail");
+    let rejection_reason = err
+        .downcast_ref::<TransactionRejectionReason>()
+        .unwrap_or_else(|| panic!("Error {err} is not TransactionRejectionReason"));
+    assert!(matches!(
+        rejection_reason,
+        TransactionRejectionReason::Validation(ValidationFail::InstructionFailed(
+            InstructionExecutionError::InvariantViolation(_)
+        ))
+    ));
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use iroha::{
     data_model::{prelude::*, query::builder::SingleQueryError},
 };
 use iroha_test_network::*;
-use iroha_test_samples::{gen_account_in, ALICE_ID};
+use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID};
 
 #[test]
 #[allow(clippy::too_many_lines)]
@@ -142,3 +142,72 @@ fn test_total_quantity(
 
     Ok(())
 }
+
+#[test]
+fn select_asset_fields() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let assets = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.account.eq(ALICE_ID.clone()))
+        .execute_all()?;
+    assert!(!assets.is_empty());
+
+    let selected = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.account.eq(ALICE_ID.clone()))
+        .select_with(|asset| (asset.id, asset.value))
+        .execute_all()?;
+
+    let expected = assets
+        .into_iter()
+        .map(|asset| (asset.id().clone(), *asset.value()))
+        .collect::<Vec<_>>();
+    assert_eq!(selected, expected);
+
+    Ok(())
+}
+
+#[test]
+fn aggregate_asset_values() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let definition_id: AssetDefinitionId = "gold#wonderland".parse()?;
+    test_client.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        definition_id.clone(),
+    )))?;
+    let holders = [
+        ALICE_ID.clone(),
+        gen_account_in("wonderland").0,
+        gen_account_in("wonderland").0,
+    ];
+    test_client.submit_all_blocking::<InstructionBox>(
+        holders
+            .iter()
+            .skip(1)
+            .map(|holder| Register::account(Account::new(holder.clone())).into())
+            .chain(holders.iter().zip([3_u32, 5, 10]).map(|(holder, value)| {
+                Mint::asset_numeric(value, AssetId::new(definition_id.clone(), holder.clone()))
+                    .into()
+            })),
+    )?;
+
+    let gold = || {
+        test_client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.definition.eq(definition_id.clone()))
+    };
+    assert_eq!(gold().count()?, 3);
+    assert_eq!(gold().sum(|asset| asset.value)?, numeric!(18));
+    assert_eq!(gold().min(|asset| asset.value)?, Some(numeric!(3)));
+    assert_eq!(gold().max(|asset| asset.value)?, Some(numeric!(10)));
+
+    let none = || gold().filter_with(|asset| asset.id.account.eq(BOB_ID.clone()));
+    assert_eq!(none().count()?, 0);
+    assert_eq!(none().sum(|asset| asset.value)?, Numeric::ZERO);
+    assert_eq!(none().max(|asset| asset.value)?, None);
+
+    Ok(())
+}
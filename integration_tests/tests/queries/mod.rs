@@ -8,6 +8,7 @@ use iroha::{
 use iroha_test_network::*;
 
 mod account;
+mod anchor;
 mod asset;
 mod metadata;
 mod query_errors;
@@ -0,0 +1,95 @@
+#![allow(missing_docs)]
+
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+const REDACTION_SECRET: &str = "pepper";
+
+#[test]
+fn redaction_replaces_value_in_state_and_history() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config_layer(|c| {
+            c.write("redaction_secret", REDACTION_SECRET);
+        })
+        .start_blocking()?;
+    let chain_id = network.chain_id();
+    let client = network.client();
+
+    let key: Name = "email".parse()?;
+    let value = Json::new("alice@wonderland.org");
+    let secret_id = Redact::secret_id(REDACTION_SECRET);
+    let salt = Redact::salt(REDACTION_SECRET, &ALICE_ID, &key);
+    let redacted = Json::new(Redact::commitment(&salt, &value));
+    assert_ne!(redacted, Json::new(Hash::new(value.get())));
+
+    let _err = client
+        .submit_blocking(Redact::new(ALICE_ID.clone(), key.clone(), secret_id))
+        .expect_err("value not marked redactable can't be redacted");
+    client.submit_all_blocking::<InstructionBox>([
+        SetKeyValue::account(ALICE_ID.clone(), key.clone(), value.clone()).into(),
+        MarkRedactable::new(ALICE_ID.clone(), key.clone()).into(),
+    ])?;
+
+    let redact_as_bob = TransactionBuilder::new(chain_id, BOB_ID.clone())
+        .with_instructions([Redact::new(ALICE_ID.clone(), key.clone(), secret_id)])
+        .sign(BOB_KEYPAIR.private_key());
+    let _err = client
+        .submit_transaction_blocking(&redact_as_bob)
+        .expect_err("only the domain owner can redact");
+
+    let other_secret_id = Redact::secret_id("salt");
+    let _err = client
+        .submit_blocking(Redact::new(ALICE_ID.clone(), key.clone(), other_secret_id))
+        .expect_err("value can't be redacted with a secret the peers don't have");
+
+    client.submit_blocking(Redact::new(ALICE_ID.clone(), key.clone(), secret_id))?;
+
+    let stored = client
+        .query(FindAccounts)
+        .filter_with(|account| account.id.eq(ALICE_ID.clone()))
+        .select_with(|account| account.metadata.key(key.clone()))
+        .execute_single()?;
+    assert_eq!(stored, redacted);
+
+    let instructions = client
+        .query(FindTransactions::new())
+        .execute_all()?
+        .into_iter()
+        .filter_map(|tx| match tx.entrypoint() {
+            TransactionEntrypoint::External(entrypoint) => match entrypoint.instructions() {
+                Executable::Instructions(instructions) => Some(instructions.to_vec()),
+                Executable::Wasm(_) => None,
+            },
+            TransactionEntrypoint::Time(_) => None,
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+    let set_values = instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            InstructionBox::SetKeyValue(SetKeyValueBox::Account(isi)) if isi.key == key => {
+                Some(isi.value.clone())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(set_values, vec![redacted]);
+
+    let block_hashes = client
+        .query(FindBlocks)
+        .execute_all()?
+        .iter()
+        .map(SignedBlock::hash)
+        .collect::<Vec<_>>();
+    let header_hashes = client
+        .query(FindBlockHeaders)
+        .execute_all()?
+        .iter()
+        .map(BlockHeader::hash)
+        .collect::<Vec<_>>();
+    assert_eq!(block_hashes, header_hashes);
+
+    Ok(())
+}
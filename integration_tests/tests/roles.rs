@@ -117,6 +117,157 @@ fn unregistered_role_removed_from_account() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn default_role_is_granted_on_account_registration() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let role_id: RoleId = "MEMBER".parse()?;
+    let domain_id: DomainId = "club".parse()?;
+    let (member_id, _member_keypair) = gen_account_in("club");
+
+    test_client.submit_blocking(Register::role(Role::new(role_id.clone(), ALICE_ID.clone())))?;
+    test_client.submit_blocking(Register::domain(
+        Domain::new(domain_id.clone()).with_default_role(role_id.clone()),
+    ))?;
+    test_client.submit_blocking(Register::account(Account::new(member_id.clone())))?;
+
+    let member_roles = test_client
+        .query(FindRolesByAccountId::new(member_id))
+        .execute_all()?;
+    assert_eq!(member_roles, [role_id.clone()]);
+
+    // Unregistering the role stops it from being granted
+    test_client.submit_blocking(Unregister::role(role_id))?;
+    let domain = test_client
+        .query(FindDomains)
+        .filter_with(|domain| domain.id.eq(domain_id))
+        .execute_single()?;
+    assert!(domain.default_role().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn inherited_role_permissions_are_resolved_transitively() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let alice_id = ALICE_ID.clone();
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(mouse_id.clone())))?;
+
+    // admin -> operator -> viewer
+    let viewer_id: RoleId = "viewer".parse()?;
+    let operator_id: RoleId = "operator".parse()?;
+    let admin_id: RoleId = "admin".parse()?;
+    test_client.submit_all_blocking([
+        Register::role(
+            Role::new(viewer_id.clone(), alice_id.clone()).add_permission(
+                CanModifyAccountMetadata {
+                    account: alice_id.clone(),
+                },
+            ),
+        ),
+        Register::role(Role::new(operator_id.clone(), alice_id.clone()).inherit(viewer_id.clone())),
+        Register::role(Role::new(admin_id.clone(), alice_id.clone())),
+    ])?;
+    test_client.submit_all_blocking([
+        InstructionBox::from(Grant::inherited_role(operator_id.clone(), admin_id.clone())),
+        Grant::account_role(admin_id.clone(), mouse_id.clone()).into(),
+    ])?;
+
+    let set_key_value = |key: &str| {
+        let set_key_value = SetKeyValue::account(
+            alice_id.clone(),
+            key.parse::<Name>().unwrap(),
+            "value".parse::<Json>().unwrap(),
+        );
+        TransactionBuilder::new(network.chain_id(), mouse_id.clone())
+            .with_instructions([set_key_value])
+            .sign(mouse_keypair.private_key())
+    };
+
+    // Mouse modifies Alice's metadata through the permission of the viewer role
+    test_client.submit_transaction_blocking(&set_key_value("key"))?;
+
+    // Inheritance can't form a cycle
+    let _err = test_client
+        .submit_blocking(Grant::inherited_role(admin_id.clone(), viewer_id.clone()))
+        .expect_err("viewer role must not inherit admin role");
+
+    // Unregistering the middle role cuts the chain off
+    test_client.submit_blocking(Unregister::role(operator_id))?;
+    let admin = test_client
+        .query(FindRoles)
+        .filter_with(|role| role.id.eq(admin_id))
+        .execute_single()?;
+    assert_eq!(admin.inherited_roles().count(), 0);
+    let _err = test_client
+        .submit_transaction_blocking(&set_key_value("another_key"))
+        .expect_err("mouse must no longer be able to modify alice's metadata");
+
+    Ok(())
+}
+
+#[test]
+fn role_instantiated_from_template() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let alice_id = ALICE_ID.clone();
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(mouse_id.clone())))?;
+
+    let template_id: RoleId = "ACCOUNT_METADATA_ACCESS".parse()?;
+    let template = RoleTemplate::new(template_id.clone()).add_permission(Permission::new(
+        "CanModifyAccountMetadata".to_owned(),
+        json!({ "account": "{account}" }),
+    ));
+    test_client.submit_blocking(Register::role_template(template))?;
+    assert_eq!(
+        test_client
+            .query_single(FindRoleTemplate::new(template_id.clone()))?
+            .placeholders()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        ["account"]
+    );
+
+    // Every placeholder must be given a value
+    let role_id: RoleId = "ACCESS_TO_MOUSE_METADATA".parse()?;
+    let instantiate =
+        InstantiateRoleTemplate::new(template_id.clone(), role_id.clone(), mouse_id.clone());
+    let _err = test_client
+        .submit_blocking(instantiate.clone())
+        .expect_err("template must not be instantiated without arguments");
+
+    test_client
+        .submit_blocking(instantiate.with_argument("account".parse()?, mouse_id.to_string()))?;
+    let role = test_client
+        .query(FindRoles)
+        .filter_with(|role| role.id.eq(role_id.clone()))
+        .execute_single()?;
+    assert!(role.permissions().any(|permission| *permission
+        == Permission::from(CanModifyAccountMetadata {
+            account: mouse_id.clone()
+        })));
+
+    // Mouse grants the instantiated role to Alice, who then modifies Mouse's metadata
+    let grant_role = Grant::account_role(role_id, alice_id);
+    let grant_role_tx = TransactionBuilder::new(network.chain_id(), mouse_id.clone())
+        .with_instructions([grant_role])
+        .sign(mouse_keypair.private_key());
+    test_client.submit_transaction_blocking(&grant_role_tx)?;
+    test_client.submit_blocking(SetKeyValue::account(
+        mouse_id,
+        "key".parse::<Name>()?,
+        "value".parse::<Json>()?,
+    ))?;
+
+    Ok(())
+}
+
 #[test]
 fn role_with_invalid_permissions_is_not_accepted() -> Result<()> {
     let (network, _rt) = NetworkBuilder::new().start_blocking()?;
@@ -203,8 +354,12 @@ fn grant_revoke_role_permissions() -> Result<()> {
 
     // Transfer domain ownership to Mouse
     let domain_id = "wonderland".parse::<DomainId>()?;
-    let transfer_domain = Transfer::domain(alice_id.clone(), domain_id, mouse_id.clone());
+    let transfer_domain = Transfer::domain(alice_id.clone(), domain_id.clone(), mouse_id.clone());
     test_client.submit_blocking(transfer_domain)?;
+    let accept_domain_tx = TransactionBuilder::new(network.chain_id(), mouse_id.clone())
+        .with_instructions([AcceptDomainTransfer::new(domain_id)])
+        .sign(mouse_keypair.private_key());
+    test_client.submit_transaction_blocking(&accept_domain_tx)?;
 
     // Mouse grants role to Alice
     let grant_role = Grant::account_role(role_id.clone(), alice_id.clone());
@@ -289,3 +444,58 @@ async fn grant_unexisting_role_in_genesis_fail() {
         .await
         .expect("must terminate immediately");
 }
+
+#[test]
+fn role_ownership_is_transferred() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    let (rabbit_id, rabbit_keypair) = gen_account_in("wonderland");
+    test_client.submit_all_blocking([
+        Register::account(Account::new(mouse_id.clone())),
+        Register::account(Account::new(rabbit_id.clone())),
+    ])?;
+
+    // Mouse is the first owner of the role
+    let role_id = "TEAM_LEAD".parse::<RoleId>()?;
+    test_client.submit_blocking(Register::role(Role::new(role_id.clone(), mouse_id.clone())))?;
+    let find_owner = || -> Result<AccountId> {
+        let role = test_client
+            .query(FindRoles)
+            .filter_with(|role| role.id.eq(role_id.clone()))
+            .execute_single()?;
+        Ok(role.owned_by().clone())
+    };
+    assert_eq!(find_owner()?, mouse_id);
+
+    // Mouse hands the role over to Rabbit
+    let transfer_role = Transfer::role(mouse_id.clone(), role_id.clone(), rabbit_id.clone());
+    let transfer_role_tx = TransactionBuilder::new(network.chain_id(), mouse_id.clone())
+        .with_instructions([transfer_role])
+        .sign(mouse_keypair.private_key());
+    test_client.submit_transaction_blocking(&transfer_role_tx)?;
+    assert_eq!(find_owner()?, rabbit_id);
+
+    // Rabbit administers the role without holding it
+    let revoke_role = Revoke::account_role(role_id.clone(), mouse_id.clone());
+    let revoke_role_tx = TransactionBuilder::new(network.chain_id(), rabbit_id.clone())
+        .with_instructions([revoke_role])
+        .sign(rabbit_keypair.private_key());
+    test_client.submit_transaction_blocking(&revoke_role_tx)?;
+    assert!(!test_client
+        .query(FindRolesByAccountId::new(mouse_id.clone()))
+        .execute_all()?
+        .contains(&role_id));
+
+    // Mouse can't take the role back
+    let transfer_back = Transfer::role(rabbit_id, role_id, mouse_id.clone());
+    let transfer_back_tx = TransactionBuilder::new(network.chain_id(), mouse_id)
+        .with_instructions([transfer_back])
+        .sign(mouse_keypair.private_key());
+    let _ = test_client
+        .submit_transaction_blocking(&transfer_back_tx)
+        .expect_err("only the owner can transfer the role");
+
+    Ok(())
+}
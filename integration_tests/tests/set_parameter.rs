@@ -1,9 +1,15 @@
 #![allow(missing_docs)]
 
-use eyre::Result;
+use std::time::Duration;
+
+use eyre::{eyre, Result};
 use iroha::data_model::{
-    parameter::{BlockParameter, Parameter, Parameters},
+    parameter::{
+        BlockParameter, DomainTransactionTtl, Parameter, Parameters, TransactionParameter,
+        TransactionTtlBounds,
+    },
     prelude::*,
+    Level,
 };
 use iroha_test_network::*;
 use nonzero_ext::nonzero;
@@ -30,3 +36,38 @@ fn can_change_parameter_value() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn transaction_ttl_is_bounded_at_admission() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_genesis_instruction(SetParameter::new(Parameter::Transaction(
+            TransactionParameter::MaxTimeToLiveMs(nonzero!(300_000_u64)),
+        )))
+        .start_blocking()?;
+    let client = network.client();
+    let mut long_lived_client = client.clone();
+    long_lived_client.transaction_ttl = Some(Duration::from_secs(3600));
+    let log = Log::new(Level::INFO, "long-lived".to_owned());
+
+    let _err = long_lived_client
+        .submit_blocking(log.clone())
+        .expect_err("Transaction time-to-live should exceed the maximal one");
+
+    let wonderland_bounds = TransactionTtlBounds::new(Duration::ZERO, Duration::from_secs(7200))
+        .map_err(|err| eyre!(err))?;
+    client.submit_blocking(SetParameter::new(Parameter::DomainTransactionTtl(
+        DomainTransactionTtl {
+            domain: "wonderland".parse()?,
+            bounds: Some(wonderland_bounds),
+        },
+    )))?;
+    let params = client.query_single(FindParameters::new())?;
+    assert_eq!(
+        params.transaction_ttl_bounds(&"wonderland".parse()?),
+        wonderland_bounds
+    );
+
+    long_lived_client.submit_blocking(log)?;
+
+    Ok(())
+}
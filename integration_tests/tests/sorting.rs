@@ -342,3 +342,47 @@ fn sort_only_elements_which_have_sorting_key() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn sort_by_field_composes_with_pagination() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let definition_id: AssetDefinitionId = "xor#wonderland".parse()?;
+    test_client.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        definition_id.clone(),
+    )))?;
+
+    let domain_id: DomainId = "wonderland".parse()?;
+    let mut quantities = vec![];
+    let mut instructions: Vec<InstructionBox> = vec![];
+    for quantity in [30_u32, 10, 50, 20, 40] {
+        let account_id = AccountId::new(domain_id.clone(), KeyPair::random().into_parts().0);
+        instructions.push(Register::account(Account::new(account_id.clone())).into());
+        instructions.push(
+            Mint::asset_numeric(quantity, AssetId::new(definition_id.clone(), account_id)).into(),
+        );
+        quantities.push(quantity);
+    }
+    test_client
+        .submit_all_blocking(instructions)
+        .wrap_err("Failed to mint assets")?;
+
+    let values = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.definition.eq(definition_id))
+        .sort_by(|asset| asset.value, SortOrder::Descending)
+        .with_pagination(Pagination::new(Some(nonzero!(2_u64)), 1))
+        .select_with(|asset| asset.value)
+        .execute_all()?;
+
+    quantities.sort_unstable_by(|a, b| b.cmp(a));
+    let expected = quantities[1..3]
+        .iter()
+        .copied()
+        .map(Numeric::from)
+        .collect::<Vec<_>>();
+    assert_eq!(values, expected);
+
+    Ok(())
+}
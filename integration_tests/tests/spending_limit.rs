@@ -0,0 +1,73 @@
+#![allow(missing_docs)]
+
+use eyre::Result;
+use iroha::{data_model::prelude::*, executor_data_model::isi::spending_limit::*};
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+#[test]
+fn spending_limit_caps_transfers_per_window() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let chain_id = network.chain_id();
+    let client = network.client();
+
+    let rose: AssetDefinitionId = "rose#wonderland".parse()?;
+    let bob_rose = AssetId::new(rose.clone(), BOB_ID.clone());
+    let submit_as_bob = |instruction: InstructionBox| -> Result<HashOf<SignedTransaction>> {
+        let tx = TransactionBuilder::new(chain_id.clone(), BOB_ID.clone())
+            .with_instructions([instruction])
+            .sign(BOB_KEYPAIR.private_key());
+        client.submit_transaction_blocking(&tx)
+    };
+    let transfer_from_bob = |amount: u32| -> InstructionBox {
+        Transfer::asset_numeric(bob_rose.clone(), amount, ALICE_ID.clone()).into()
+    };
+
+    client.submit_all_blocking::<InstructionBox>([
+        Mint::asset_numeric(20_u32, bob_rose.clone()).into(),
+        SetSpendingLimit::new(
+            BOB_ID.clone(),
+            rose.clone(),
+            SpendingWindow::Day,
+            8_u32.into(),
+        )
+        .into(),
+    ])?;
+
+    submit_as_bob(transfer_from_bob(5))?;
+    let _err = submit_as_bob(transfer_from_bob(4)).expect_err("transfer exceeds the daily limit");
+    submit_as_bob(transfer_from_bob(3))?;
+
+    let counter = client
+        .query_single(FindSpendingCounters::new(BOB_ID.clone()))?
+        .into_iter()
+        .find(|counter| counter.counts(&rose, None, SpendingWindow::Day))
+        .expect("transfers from Bob should be counted");
+    assert_eq!(counter.limit, Some(Numeric::from(8_u32)));
+    assert_eq!(counter.spent, Numeric::from(8_u32));
+
+    // Bob can neither lift his own limit nor reset the amount he spent
+    let _err = submit_as_bob(
+        RemoveSpendingLimit::new(BOB_ID.clone(), rose.clone(), SpendingWindow::Day).into(),
+    )
+    .expect_err("only the domain owner can lift the limit");
+    let mut reset = counter;
+    reset.spent = Numeric::ZERO;
+    let _err = submit_as_bob(SetSpendingCounter::new(BOB_ID.clone(), reset).into())
+        .expect_err("spending counters can't be modified directly");
+
+    client.submit_blocking(RemoveSpendingLimit::new(
+        BOB_ID.clone(),
+        rose,
+        SpendingWindow::Day,
+    ))?;
+    submit_as_bob(transfer_from_bob(4))?;
+
+    let bob_balance = client
+        .query(FindAssets)
+        .filter_with(|asset| asset.id.eq(bob_rose.clone()))
+        .execute_single()?;
+    assert_eq!(*bob_balance.value(), Numeric::from(8_u32));
+
+    Ok(())
+}
@@ -110,3 +110,63 @@ fn should_fail_if_asset_not_found() {
         .chain()
         .any(|e| e.to_string() == format!("Failed to find asset: `{asset_id}`")));
 }
+
+#[test]
+fn transfer_dry_run_reports_balance_changes() {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let iroha = network.client();
+
+    let (alice_id, mouse_id) = generate_two_ids();
+    let asset_definition_id: AssetDefinitionId = "camomile#wonderland".parse().unwrap();
+    let alice_asset = AssetId::new(asset_definition_id.clone(), alice_id);
+    let mouse_asset = AssetId::new(asset_definition_id.clone(), mouse_id.clone());
+    let instructions: [InstructionBox; 3] = [
+        create_mouse(mouse_id.clone()).into(),
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(numeric!(500), alice_asset.clone()).into(),
+    ];
+    iroha
+        .submit_all_blocking(instructions)
+        .expect("Failed to prepare state.");
+
+    let transfer = iroha.build_transaction(
+        [Transfer::asset_numeric(
+            alice_asset.clone(),
+            numeric!(200),
+            mouse_id.clone(),
+        )],
+        Metadata::default(),
+    );
+    let dry_run = iroha.dry_run_transaction(&transfer).unwrap();
+
+    assert!(dry_run.is_successful(), "{:?}", dry_run.rejection());
+    let mut expected = vec![
+        BalanceChange {
+            asset: alice_asset,
+            direction: MovementDirection::Debit,
+            amount: numeric!(200),
+            balance: numeric!(300),
+        },
+        BalanceChange {
+            asset: mouse_asset.clone(),
+            direction: MovementDirection::Credit,
+            amount: numeric!(200),
+            balance: numeric!(200),
+        },
+    ];
+    expected.sort_by(|a, b| a.asset.cmp(&b.asset));
+    assert_eq!(dry_run.balances(), &expected);
+    assert!(dry_run.changes().contains(&EntityDiff {
+        kind: EntityKind::Asset,
+        id: mouse_asset.to_string(),
+        change: Change::Added,
+    }));
+
+    // Nothing was committed
+    assert!(iroha
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.account.eq(mouse_id))
+        .execute_all()
+        .unwrap()
+        .is_empty());
+}
@@ -231,7 +231,12 @@ fn domain_owner_asset_permissions() -> Result<()> {
     test_client.submit_blocking(Transfer::asset_numeric(bob_coin_id.clone(), 5u32, alice_id))?;
 
     // check that "alice@wonderland" as owner of domain can grant and revoke asset related permissions in her domain
-    let permission = CanTransferAsset { asset: bob_coin_id };
+    let permission = CanTransferAsset {
+        asset: bob_coin_id,
+        delegatable: false,
+        max_amount_per_transaction: None,
+        max_amount_per_day: None,
+    };
     test_client.submit_blocking(Grant::account_permission(
         permission.clone(),
         bob_id.clone(),
@@ -353,7 +358,7 @@ fn domain_owner_transfer() -> Result<()> {
 
     let alice_id = ALICE_ID.clone();
     let kingdom_id: DomainId = "kingdom".parse()?;
-    let (bob_id, _bob_keypair) = gen_account_in("kingdom");
+    let (bob_id, bob_keypair) = gen_account_in("kingdom");
 
     // "alice@wonderland" is owner of "kingdom" domain
     let kingdom = Domain::new(kingdom_id.clone());
@@ -371,12 +376,24 @@ fn domain_owner_transfer() -> Result<()> {
 
     test_client
         .submit_blocking(Transfer::domain(
-            alice_id,
+            alice_id.clone(),
             kingdom_id.clone(),
             bob_id.clone(),
         ))
         .expect("Failed to submit transaction");
 
+    // The transfer is only an offer until "bob@kingdom" accepts it
+    let domain = test_client
+        .query(FindDomains::new())
+        .filter_with(|domain| domain.id.eq(kingdom_id.clone()))
+        .execute_single()?;
+    assert_eq!(domain.owned_by(), &alice_id);
+
+    let transaction = TransactionBuilder::new(network.chain_id(), bob_id.clone())
+        .with_instructions([AcceptDomainTransfer::new(kingdom_id.clone())])
+        .sign(bob_keypair.private_key());
+    test_client.submit_transaction_blocking(&transaction)?;
+
     let domain = test_client
         .query(FindDomains::new())
         .filter_with(|domain| domain.id.eq(kingdom_id.clone()))
@@ -386,6 +403,50 @@ fn domain_owner_transfer() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn domain_transfer_accepted_only_by_recipient() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let kingdom_id: DomainId = "kingdom".parse()?;
+    let (bob_id, _bob_keypair) = gen_account_in("kingdom");
+    let (mallory_id, mallory_keypair) = gen_account_in("kingdom");
+
+    test_client.submit_blocking(Register::domain(Domain::new(kingdom_id.clone())))?;
+    test_client.submit_blocking(Register::account(Account::new(bob_id.clone())))?;
+    test_client.submit_blocking(Register::account(Account::new(mallory_id.clone())))?;
+
+    // Nothing has been offered yet
+    let accept = TransactionBuilder::new(network.chain_id(), mallory_id.clone())
+        .with_instructions([AcceptDomainTransfer::new(kingdom_id.clone())])
+        .sign(mallory_keypair.private_key());
+    let _err = test_client
+        .submit_transaction_blocking(&accept)
+        .expect_err("Tx should fail since no transfer was offered");
+
+    test_client.submit_blocking(Transfer::domain(
+        ALICE_ID.clone(),
+        kingdom_id.clone(),
+        bob_id,
+    ))?;
+
+    // The offer is addressed to "bob@kingdom", so nobody else can accept it
+    let accept = TransactionBuilder::new(network.chain_id(), mallory_id)
+        .with_instructions([AcceptDomainTransfer::new(kingdom_id.clone())])
+        .sign(mallory_keypair.private_key());
+    let _err = test_client
+        .submit_transaction_blocking(&accept)
+        .expect_err("Tx should fail since the offer is for another account");
+
+    let domain = test_client
+        .query(FindDomains::new())
+        .filter_with(|domain| domain.id.eq(kingdom_id.clone()))
+        .execute_single()?;
+    assert_eq!(domain.owned_by(), &ALICE_ID.clone());
+
+    Ok(())
+}
+
 #[test]
 fn not_allowed_to_transfer_other_user_domain() -> Result<()> {
     let users_domain: DomainId = "users".parse()?;
@@ -10,7 +10,9 @@ use iroha::{
         transaction::Executable,
     },
 };
-use iroha_executor_data_model::permission::trigger::CanRegisterTrigger;
+use iroha_executor_data_model::permission::trigger::{
+    CanExecuteTrigger, CanRegisterTrigger, CanRegisterTriggerInDomain,
+};
 use iroha_test_network::*;
 use iroha_test_samples::{load_sample_wasm, ALICE_ID};
 use mint_rose_trigger_data_model::MintRoseArgs;
@@ -332,6 +334,58 @@ fn only_account_with_permission_can_register_trigger() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn trigger_registration_and_execution_can_be_delegated() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let domain_id = ALICE_ID.domain().clone();
+    let rabbit_keys = KeyPair::random();
+    let rabbit_account_id = AccountId::new(domain_id.clone(), rabbit_keys.public_key().clone());
+    test_client.submit_blocking(Register::account(Account::new(rabbit_account_id.clone())))?;
+
+    let mut rabbit_client = test_client.clone();
+    rabbit_client.account = rabbit_account_id.clone();
+    rabbit_client.key_pair = rabbit_keys;
+
+    let trigger_id = "delegated_trigger".parse::<TriggerId>()?;
+    let trigger = Trigger::new(
+        trigger_id.clone(),
+        Action::new(
+            Vec::<InstructionBox>::new(),
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            ExecuteTriggerEventFilter::new()
+                .for_trigger(trigger_id.clone())
+                .under_authority(ALICE_ID.clone()),
+        ),
+    );
+
+    // Rabbit doesn't become the trigger owner by registering it on behalf of Alice
+    test_client.submit_blocking(Grant::account_permission(
+        CanRegisterTriggerInDomain { domain: domain_id },
+        rabbit_account_id.clone(),
+    ))?;
+    rabbit_client
+        .submit_blocking(Register::trigger(trigger))
+        .expect("domain-wide permission should allow the registration");
+    let _err = rabbit_client
+        .submit_blocking(ExecuteTrigger::new(trigger_id.clone()))
+        .expect_err("Rabbit shouldn't be able to execute the trigger yet");
+
+    test_client.submit_blocking(Grant::account_permission(
+        CanExecuteTrigger {
+            trigger: trigger_id.clone(),
+        },
+        rabbit_account_id,
+    ))?;
+    rabbit_client
+        .submit_blocking(ExecuteTrigger::new(trigger_id))
+        .expect("Rabbit should be able to execute the trigger");
+
+    Ok(())
+}
+
 #[test]
 fn unregister_trigger() -> Result<()> {
     let (network, _rt) = NetworkBuilder::new().start_blocking()?;
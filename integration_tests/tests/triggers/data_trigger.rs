@@ -104,3 +104,42 @@ fn cat_depth_and_mouse_depth() -> Result<()> {
 
     Ok(())
 }
+
+/// # Scenario
+///
+/// 1. A trigger minting roses whenever roses are added would keep invoking itself, so it's rejected.
+/// 2. The same trigger firing a limited number of times is registered and reported as a bounded loop.
+#[test]
+fn endless_loop_is_rejected() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let asset_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    let trigger_id: TriggerId = "mint_on_mint".parse()?;
+    let trigger = |repeats| {
+        Register::trigger(Trigger::new(
+            trigger_id.clone(),
+            Action::new(
+                [Mint::asset_numeric(1u32, asset_id.clone())],
+                repeats,
+                ALICE_ID.clone(),
+                AssetEventFilter::new()
+                    .for_asset(asset_id.clone())
+                    .for_events(AssetEventSet::Added),
+            ),
+        ))
+    };
+
+    let _ = test_client
+        .submit_blocking(trigger(Repeats::Indefinitely))
+        .expect_err("Trigger should not be registered!");
+    assert!(test_client.query_single(FindTriggerLoops)?.is_empty());
+
+    test_client.submit_blocking(trigger(Repeats::Exactly(3)))?;
+    let loops = test_client.query_single(FindTriggerLoops)?;
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0].triggers(), &vec![trigger_id]);
+    assert!(loops[0].is_bounded());
+
+    Ok(())
+}
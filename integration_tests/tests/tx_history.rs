@@ -3,9 +3,9 @@
 use std::time::Duration;
 
 use eyre::Result;
-use iroha::data_model::{prelude::*, query::parameters::Pagination};
+use iroha::data_model::{prelude::*, query::parameters::Pagination, Level};
 use iroha_test_network::*;
-use iroha_test_samples::ALICE_ID;
+use iroha_test_samples::{gen_account_in, ALICE_ID};
 use nonzero_ext::nonzero;
 
 #[test]
@@ -68,3 +68,72 @@ fn client_has_rejected_and_accepted_txs_should_return_tx_history() -> Result<()>
 
     Ok(())
 }
+
+#[test]
+fn account_history_contains_transactions_referring_to_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+    client.submit_blocking(Register::account(Account::new(carol_id.clone())))?;
+    client.submit_blocking(Log::new(Level::INFO, "unrelated".to_owned()))?;
+
+    let history = client.get_account_history(&carol_id, None, None)?;
+    assert_eq!(history.account, carol_id);
+    assert_eq!(history.from, nonzero!(1_u64));
+    assert_eq!(history.to, nonzero!(3_u64));
+    let [block] = history.blocks.as_slice() else {
+        panic!("expected a single block, got {:?}", history.blocks);
+    };
+    assert_eq!(block.header.height, nonzero!(2_u64));
+    let proof = block
+        .proof
+        .clone()
+        .expect("blocks before the latest one are proven");
+    let latest = client
+        .query(FindBlockHeaders)
+        .execute_all()?
+        .into_iter()
+        .find(|header| header.height == nonzero!(3_u64))
+        .expect("latest block should be committed");
+    assert_eq!(latest.prev_blocks_root(), Some(proof.root));
+    assert!(proof.proof.verify(&block.header.hash(), &proof.root));
+    let [transaction] = block.transactions.as_slice() else {
+        panic!(
+            "expected a single transaction, got {:?}",
+            block.transactions
+        );
+    };
+    assert_eq!(transaction.entrypoint.authority(), &*ALICE_ID);
+    assert_eq!(transaction.block_hash, block.header.hash());
+
+    // Blocks out of the requested range are not searched
+    let history = client.get_account_history(&carol_id, Some(nonzero!(3_u64)), None)?;
+    assert!(history.blocks.is_empty());
+    assert!(client
+        .get_account_history(&carol_id, Some(nonzero!(4_u64)), None)
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn committed_transaction_is_known() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let transaction = client.build_transaction(
+        [Log::new(Level::INFO, "committed".to_owned())],
+        Metadata::default(),
+    );
+    let pending = client.build_transaction(
+        [Log::new(Level::INFO, "never submitted".to_owned())],
+        Metadata::default(),
+    );
+    client.submit_transaction_blocking(&transaction)?;
+
+    assert!(client.query_single(IsTransactionKnown::new(transaction.hash()))?);
+    assert!(!client.query_single(IsTransactionKnown::new(pending.hash()))?);
+
+    Ok(())
+}
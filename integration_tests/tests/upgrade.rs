@@ -3,10 +3,15 @@
 use executor_custom_data_model::{complex_isi::NumericQuery, permissions::CanControlDomainLives};
 use eyre::{Context, Result};
 use futures_util::TryStreamExt as _;
-use iroha::{client::Client, data_model::prelude::*};
-use iroha_executor_data_model::permission::{domain::CanUnregisterDomain, Permission as _};
+use iroha::{
+    client::Client,
+    data_model::{parameter::ExecutorUpgradeParameters, prelude::*},
+};
+use iroha_executor_data_model::permission::{
+    domain::CanUnregisterDomain, parameter::CanSetParameters, Permission as _,
+};
 use iroha_test_network::*;
-use iroha_test_samples::{load_sample_wasm, ALICE_ID, BOB_ID};
+use iroha_test_samples::{load_sample_wasm, ALICE_ID, BOB_ID, BOB_KEYPAIR};
 use nonzero_ext::nonzero;
 
 const ADMIN_PUBLIC_KEY_MULTIHASH: &str =
@@ -16,7 +21,6 @@ const ADMIN_PRIVATE_KEY_MULTIHASH: &str =
 
 #[test]
 fn executor_upgrade_should_work() -> Result<()> {
-    let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
     let admin_id: AccountId = format!("{ADMIN_PUBLIC_KEY_MULTIHASH}@admin")
         .parse()
         .unwrap();
@@ -27,6 +31,7 @@ fn executor_upgrade_should_work() -> Result<()> {
     let (network, _rt) = NetworkBuilder::new()
         .with_wasm_fuel(WasmFuelConfig::Auto)
         .start_blocking()?;
+    let chain_id = network.chain_id();
     let client = network.client();
 
     // Register `admin` domain and account
@@ -351,6 +356,54 @@ fn migration_fail_should_not_cause_any_effects() {
     // been changed, because `executor_with_migration_fail` does not allow any queries
 }
 
+#[test]
+fn executor_upgrade_dry_run_should_not_commit_migration() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_wasm_fuel(WasmFuelConfig::Auto)
+        .start_blocking()?;
+    let client = network.client();
+    let data_model_before = client.query_single(FindExecutorDataModel)?;
+
+    let dry_run = client.dry_run_executor_upgrade(Executor::new(load_sample_wasm(
+        "executor_with_custom_permission",
+    )))?;
+
+    assert!(dry_run.is_successful(), "{:?}", dry_run.rejection());
+    assert!(dry_run
+        .data_model()
+        .permissions()
+        .iter()
+        .any(|permission| CanControlDomainLives::name() == *permission));
+    assert!(dry_run
+        .changes()
+        .iter()
+        .any(|change| change.starts_with("~ ExecutorDataModel")));
+    // Nothing has been committed
+    assert_eq!(
+        client.query_single(FindExecutorDataModel)?,
+        data_model_before
+    );
+
+    Ok(())
+}
+
+#[test]
+fn executor_upgrade_dry_run_should_report_migration_fail() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+    let data_model_before = client.query_single(FindExecutorDataModel)?;
+
+    let dry_run = client.dry_run_executor_upgrade(Executor::new(load_sample_wasm(
+        "executor_with_migration_fail",
+    )))?;
+
+    assert!(!dry_run.is_successful());
+    assert_eq!(*dry_run.data_model(), data_model_before);
+    assert!(dry_run.changes().is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn executor_with_fuel() -> Result<()> {
     let (network, _rt) = NetworkBuilder::new().start_blocking()?;
@@ -542,6 +595,143 @@ fn define_custom_parameter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn executor_can_validate_against_unrelated_state() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_wasm_fuel(WasmFuelConfig::Auto)
+        .start_blocking()?;
+    let client = network.client();
+
+    upgrade_executor(&client, "executor_with_kyc")?;
+
+    let alice_rose: AssetId = format!("rose##{}", ALICE_ID.clone()).parse()?;
+    let transfer_rose = Transfer::asset_numeric(alice_rose, 1u32, BOB_ID.clone());
+    let _err = client
+        .submit_blocking(transfer_rose.clone())
+        .expect_err("recipient hasn't passed KYC yet");
+
+    let kyc_nft: NftId = "passed$kyc".parse()?;
+    client.submit_all_blocking::<InstructionBox>([
+        Register::domain(Domain::new("kyc".parse()?)).into(),
+        Register::nft(Nft::new(kyc_nft.clone(), Metadata::default())).into(),
+        Transfer::nft(ALICE_ID.clone(), kyc_nft, BOB_ID.clone()).into(),
+    ])?;
+    client.submit_blocking(transfer_rose)?;
+
+    Ok(())
+}
+
+#[test]
+fn executor_upgrade_restricted_to_listed_accounts() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_wasm_fuel(WasmFuelConfig::Auto)
+        .start_blocking()?;
+    let client = network.client();
+    let data_model_before = client.query_single(FindExecutorDataModel)?;
+
+    client.submit_blocking(SetParameter::new(Parameter::ExecutorUpgrade(
+        ExecutorUpgradeParameters {
+            accounts: [BOB_ID.clone()].into(),
+            ..ExecutorUpgradeParameters::default()
+        },
+    )))?;
+
+    let _err = upgrade_executor(&client, "executor_with_custom_permission")
+        .expect_err("alice isn't listed as an upgrader");
+    assert_eq!(
+        client.query_single(FindExecutorDataModel)?,
+        data_model_before
+    );
+
+    Ok(())
+}
+
+#[test]
+fn delayed_executor_upgrade_can_be_aborted() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_wasm_fuel(WasmFuelConfig::Auto)
+        .start_blocking()?;
+    let client = network.client();
+    let data_model_before = client.query_single(FindExecutorDataModel)?;
+
+    let delay_blocks = 100;
+    client.submit_blocking(SetParameter::new(Parameter::ExecutorUpgrade(
+        ExecutorUpgradeParameters {
+            delay_blocks,
+            ..ExecutorUpgradeParameters::default()
+        },
+    )))?;
+
+    upgrade_executor(&client, "executor_with_custom_permission")?;
+    let upgrade_height = client
+        .query(FindBlockHeaders)
+        .execute_all()?
+        .first()
+        .expect("upgrade block is committed")
+        .height()
+        .get();
+    let activation_height = upgrade_height + delay_blocks;
+    // The new executor waits for its activation height
+    assert_eq!(
+        client.query_single(FindExecutorDataModel)?,
+        data_model_before
+    );
+
+    let _err = client
+        .submit_blocking(AbortExecutorUpgrade::new(activation_height + 1))
+        .expect_err("no upgrade is scheduled at this height");
+    client.submit_blocking(AbortExecutorUpgrade::new(activation_height))?;
+    let _err = client
+        .submit_blocking(AbortExecutorUpgrade::new(activation_height))
+        .expect_err("upgrade has already been aborted");
+
+    // A new upgrade can be scheduled once the previous one is aborted
+    upgrade_executor(&client, "executor_with_custom_permission")?;
+
+    Ok(())
+}
+
+#[test]
+fn executor_upgrade_policy_changes_are_restricted_and_delayed() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+    let bob_client = Client {
+        account: BOB_ID.clone(),
+        key_pair: BOB_KEYPAIR.clone(),
+        ..client.clone()
+    };
+    client.submit_blocking(Grant::account_permission(CanSetParameters, BOB_ID.clone()))?;
+    let current_policy = || -> Result<ExecutorUpgradeParameters> {
+        Ok(client
+            .query_single(FindParameters::new())?
+            .executor_upgrade()
+            .clone())
+    };
+
+    // Tightening the policy takes effect immediately
+    let restricted = ExecutorUpgradeParameters {
+        accounts: [ALICE_ID.clone()].into(),
+        delay_blocks: 100,
+        ..ExecutorUpgradeParameters::default()
+    };
+    client.submit_blocking(SetParameter::new(Parameter::ExecutorUpgrade(
+        restricted.clone(),
+    )))?;
+    assert_eq!(current_policy()?, restricted);
+
+    // Permission to set parameters isn't enough to change the policy
+    let loosened = Parameter::ExecutorUpgrade(ExecutorUpgradeParameters::default());
+    let _err = bob_client
+        .submit_blocking(SetParameter::new(loosened.clone()))
+        .expect_err("bob isn't allowed to upgrade the executor");
+
+    // Loosening the policy waits for the delay
+    client.submit_blocking(SetParameter::new(loosened))?;
+    assert_eq!(current_policy()?, restricted);
+
+    Ok(())
+}
+
 fn upgrade_executor(client: &Client, executor: impl AsRef<str>) -> Result<()> {
     let upgrade_executor = Upgrade::new(Executor::new(load_sample_wasm(executor)));
     client
@@ -27,8 +27,12 @@ def test_transfer_domain(
             f"--id={GIVEN_registered_domain.name}"
         )
     with allure.step(
-        f"THEN {GIVEN_registered_account} should own {GIVEN_registered_domain}"
+        f"THEN {GIVEN_currently_authorized_account} should still own "
+        f"{GIVEN_registered_domain} until {GIVEN_registered_account} accepts it"
     ):
         iroha.should(
-            have.domain(GIVEN_registered_domain.name, owned_by=GIVEN_registered_account)
+            have.domain(
+                GIVEN_registered_domain.name,
+                owned_by=GIVEN_currently_authorized_account,
+            )
         )
@@ -8,7 +8,9 @@ extern crate panic_halt;
 extern crate alloc;
 
 use dlmalloc::GlobalDlmalloc;
-use iroha_executor::{data_model::block::BlockHeader, prelude::*};
+use iroha_executor::{
+    data_model::block::BlockHeader, permission::domain::is_domain_owner, prelude::*,
+};
 
 #[global_allocator]
 static ALLOC: GlobalDlmalloc = GlobalDlmalloc;
@@ -19,6 +21,7 @@ static ALLOC: GlobalDlmalloc = GlobalDlmalloc;
 ///
 /// The defaults are not guaranteed to be stable.
 #[derive(Debug, Clone, Visit, Execute, Entrypoints)]
+#[visit(custom(visit_register_domain, visit_register_asset_definition))]
 struct Executor {
     host: Iroha,
     context: Context,
@@ -36,6 +39,42 @@ impl Executor {
     }
 }
 
+/// Build-time policy switches, see `[features]` in `Cargo.toml`
+mod policy {
+    /// Any account can register a domain without `CanRegisterDomain`
+    pub const PERMISSIONLESS_DOMAINS: bool = cfg!(feature = "permissionless_domains");
+    /// Only the domain owner can register asset definitions in the domain,
+    /// `CanRegisterAssetDefinition` is not taken into account
+    pub const OWNER_ONLY_ASSET_DEFINITIONS: bool = cfg!(feature = "owner_only_asset_definitions");
+}
+
+fn visit_register_domain(executor: &mut Executor, isi: &Register<Domain>) {
+    if policy::PERMISSIONLESS_DOMAINS {
+        execute!(executor, isi);
+    }
+
+    iroha_executor::default::visit_register_domain(executor, isi);
+}
+
+fn visit_register_asset_definition(executor: &mut Executor, isi: &Register<AssetDefinition>) {
+    if policy::OWNER_ONLY_ASSET_DEFINITIONS && !executor.context().curr_block.is_genesis() {
+        match is_domain_owner(
+            isi.object().id().domain(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Only the domain owner can register asset definitions in the domain"
+            ),
+        }
+    }
+
+    iroha_executor::default::visit_register_asset_definition(executor, isi);
+}
+
 /// Migrate previous executor to the current version.
 /// Called by Iroha once just before upgrading executor.
 ///
@@ -0,0 +1,32 @@
+//! Runtime Executor which allows any instruction to be executed by any account.
+//!
+//! Used by test networks whose scenarios don't depend on permissions.
+
+#![no_std]
+
+#[cfg(not(test))]
+extern crate panic_halt;
+
+use dlmalloc::GlobalDlmalloc;
+use iroha_executor::prelude::*;
+
+#[global_allocator]
+static ALLOC: GlobalDlmalloc = GlobalDlmalloc;
+
+#[derive(Visit, Execute, Entrypoints)]
+#[visit(custom(visit_instruction))]
+struct Executor {
+    host: Iroha,
+    context: Context,
+    verdict: Result,
+}
+
+fn visit_instruction(executor: &mut Executor, isi: &InstructionBox) {
+    execute!(executor, isi);
+}
+
+#[iroha_executor::migrate]
+fn migrate(host: Iroha, _context: Context) {
+    // Keep permissions known, so that they can still be granted
+    DataModelBuilder::with_default_permissions().build_and_set(&host);
+}
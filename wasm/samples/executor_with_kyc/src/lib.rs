@@ -0,0 +1,36 @@
+//! Runtime Executor which allows transferring assets only to accounts holding an NFT from the `kyc` domain.
+//! The rest of validation is the default one.
+
+#![no_std]
+
+#[cfg(not(test))]
+extern crate panic_halt;
+
+use dlmalloc::GlobalDlmalloc;
+use iroha_executor::{prelude::*, state};
+
+#[global_allocator]
+static ALLOC: GlobalDlmalloc = GlobalDlmalloc;
+
+#[derive(Visit, Execute, Entrypoints)]
+#[visit(custom(visit_transfer_asset_numeric))]
+struct Executor {
+    host: Iroha,
+    context: Context,
+    verdict: Result,
+}
+
+fn visit_transfer_asset_numeric(executor: &mut Executor, isi: &Transfer<Asset, Numeric, Account>) {
+    let kyc_domain: DomainId = "kyc".parse().unwrap();
+
+    match state::owns_nft_in_domain(isi.destination(), &kyc_domain, executor.host()) {
+        Ok(true) => {}
+        Ok(false) => deny!(executor, "Recipient hasn't passed KYC"),
+        Err(err) => deny!(executor, err),
+    }
+
+    iroha_executor::default::visit_transfer_asset_numeric(executor, isi);
+}
+
+#[iroha_executor::migrate]
+fn migrate(_host: Iroha, _context: Context) {}
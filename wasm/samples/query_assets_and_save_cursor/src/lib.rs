@@ -36,6 +36,7 @@ fn main(host: Iroha, context: Context) {
                 FindAssets,
                 CompoundPredicate::PASS,
                 SelectorTuple::default(),
+                None,
             )
             .into(),
             QueryParams::new(